@@ -0,0 +1,58 @@
+//! AdmissionReview bodies straight off the wire.
+//!
+//! A panic here would drop the connection and the API server would apply
+//! `failurePolicy: Ignore`, silently admitting the pod.
+
+#![no_main]
+
+use kube_devops::admission;
+use kube_devops::crd::{DevOpsPolicySpec, Severity};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(body) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(request) = admission::parse_admission_review(body) else {
+        return;
+    };
+
+    let policy = DevOpsPolicySpec {
+        forbid_latest_tag: Some(true),
+        require_liveness_probe: Some(true),
+        require_readiness_probe: Some(true),
+        require_resources: Some(true),
+        ..Default::default()
+    };
+
+    let response = match &request.pod {
+        Ok(pod) => {
+            let mut verdict = admission::validate_pod_admission(pod, &policy);
+            let (_, warnings) =
+                admission::validate_pod_admission_with_warnings(pod, &policy, &Severity::High);
+            let warnings: Vec<String> = warnings
+                .iter()
+                .map(admission::format_admission_warning)
+                .collect();
+            if let Some(old) = &request.old_pod {
+                verdict =
+                    verdict.without_existing(&admission::validate_pod_admission(old, &policy));
+            }
+            if let Some(workload) = request.workload() {
+                verdict = verdict.for_workload(&workload);
+            }
+            admission::build_admission_response(
+                &request.uid,
+                verdict.allowed,
+                verdict.message.as_deref(),
+                &warnings,
+            )
+        }
+        Err(_) => admission::build_admission_response(&request.uid, true, None, &[]),
+    };
+
+    // The response must always be valid JSON carrying the request's uid
+    let parsed: serde_json::Value =
+        serde_json::from_str(&response).expect("response is valid JSON");
+    assert_eq!(parsed["response"]["uid"], request.uid.as_str());
+});
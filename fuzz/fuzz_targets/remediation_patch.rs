@@ -0,0 +1,37 @@
+//! Remediation planning and patch generation for adversarial pod specs.
+
+#![no_main]
+
+use k8s_openapi::api::core::v1::Pod;
+use kube_devops::crd::{DevOpsPolicySpec, EnforcementMode};
+use kube_devops::enforcement;
+use libfuzzer_sys::fuzz_target;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct Input {
+    pod: Pod,
+    #[serde(default)]
+    policy: DevOpsPolicySpec,
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(Input { pod, mut policy }) = serde_json::from_slice::<Input>(data) else {
+        return;
+    };
+    policy.enforcement_mode = Some(EnforcementMode::Enforce);
+
+    let Some(plan) = enforcement::plan_remediation(&pod, &policy) else {
+        return;
+    };
+    let containers = pod
+        .spec
+        .as_ref()
+        .map(|s| s.containers.clone())
+        .unwrap_or_default();
+    let patch = enforcement::build_container_patches(&plan.actions, &containers, &policy);
+    serde_json::to_vec(&patch).expect("patch serializes");
+
+    let planned = enforcement::describe_plan(&plan, &pod);
+    assert_eq!(planned.actions.len(), plan.actions.len());
+});
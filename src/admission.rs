@@ -1,10 +1,57 @@
-use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::api::core::v1::{Pod, PodTemplateSpec};
+use serde::Deserialize;
 
 use crate::crd::{DevOpsPolicySpec, Severity};
-use crate::governance;
+use crate::governance::{self, ViolationDetail};
 
 /* ============================= TYPES ============================= */
 
+/// Workload kinds validated through their `spec.template`.
+pub const WORKLOAD_KINDS: &[&str] = &["Deployment", "StatefulSet", "DaemonSet"];
+
+/// What the webhook answers when it cannot evaluate a request: the body
+/// does not parse, the object does not decode, or the policy lookup fails.
+#[cfg_attr(feature = "operator", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailureMode {
+    /// Allow the request. Errors never block the cluster.
+    #[default]
+    Open,
+    /// Deny the request. Nothing is admitted unchecked.
+    Closed,
+}
+
+impl FailureMode {
+    /// Whether requests that could not be evaluated are allowed.
+    pub fn allows(self) -> bool {
+        matches!(self, FailureMode::Open)
+    }
+
+    /// Matching `failurePolicy` for the ValidatingWebhookConfiguration, which
+    /// applies when the API server cannot reach the webhook at all.
+    pub fn failure_policy(self) -> &'static str {
+        match self {
+            FailureMode::Open => "Ignore",
+            FailureMode::Closed => "Fail",
+        }
+    }
+}
+
+/// Default `timeoutSeconds` of the webhook registration (the API server's default).
+pub const DEFAULT_TIMEOUT_SECONDS: u32 = 10;
+
+/// Time the webhook may spend evaluating one request: 80% of the API
+/// server's `timeoutSeconds`, leaving room to send the response.
+pub fn evaluation_budget(timeout_seconds: u32) -> std::time::Duration {
+    std::time::Duration::from_millis(u64::from(timeout_seconds) * 800)
+}
+
+/// Denial message for a request the webhook could not evaluate.
+pub fn format_failure_message(reason: &str) -> String {
+    format!("Denied by kube-devops webhook (failure mode closed): {reason}")
+}
+
 /// Result of evaluating a pod against admission policy checks.
 #[derive(Debug, Clone)]
 pub struct AdmissionVerdict {
@@ -17,12 +64,15 @@ pub struct AdmissionVerdict {
 
 /// Build a copy of the policy with runtime-only checks disabled.
 ///
-/// Admission happens before a pod runs, so `maxRestartCount` and
-/// `forbidPendingDuration` are meaningless at admission time.
+/// Admission happens before a pod runs, so `maxRestartCount`,
+/// `forbidPendingDuration`, and the container state checks are meaningless
+/// at admission time.
 pub fn build_admission_policy_for_validation(policy: &DevOpsPolicySpec) -> DevOpsPolicySpec {
     DevOpsPolicySpec {
         max_restart_count: None,
         forbid_pending_duration: None,
+        forbid_crash_loop: None,
+        forbid_image_pull_errors: None,
         ..policy.clone()
     }
 }
@@ -33,7 +83,8 @@ pub fn build_admission_policy_for_validation(policy: &DevOpsPolicySpec) -> DevOp
 /// or `allowed = false` with a denial message listing all violations.
 ///
 /// Only checks that the policy enables are evaluated. Runtime-only checks
-/// (restart count, pending duration) are automatically skipped.
+/// (restart count, pending duration) are automatically skipped, as are
+/// container checks exempted by a `skip-checks.<container>` annotation.
 pub fn validate_pod_admission(pod: &Pod, policy: &DevOpsPolicySpec) -> AdmissionVerdict {
     let admission_policy = build_admission_policy_for_validation(policy);
     let mut violations = Vec::new();
@@ -49,29 +100,51 @@ pub fn validate_pod_admission(pod: &Pod, policy: &DevOpsPolicySpec) -> Admission
 
     for c in &spec.containers {
         let container_name = &c.name;
+        let skipped = |check| governance::is_check_skipped(pod, container_name, check);
 
-        if admission_policy.forbid_latest_tag.unwrap_or(false)
-            && c.image.as_deref().unwrap_or("").ends_with(":latest")
+        for (check, reason) in governance::image_violations(c, &admission_policy) {
+            if !skipped(check) {
+                violations.push(format!("container '{}' {reason}", container_name));
+            }
+        }
+
+        if admission_policy.require_liveness_probe.unwrap_or(false)
+            && !skipped("missing_liveness")
+            && let Some(reason) = governance::probe_violation(
+                "liveness",
+                c.liveness_probe.as_ref(),
+                &admission_policy,
+            )
         {
-            violations.push(format!("container '{}' uses :latest tag", container_name));
+            violations.push(format!("container '{}' {reason}", container_name));
         }
 
-        if admission_policy.require_liveness_probe.unwrap_or(false) && c.liveness_probe.is_none() {
-            violations.push(format!(
-                "container '{}' missing liveness probe",
-                container_name
-            ));
+        if admission_policy.require_readiness_probe.unwrap_or(false)
+            && !skipped("missing_readiness")
+            && let Some(reason) = governance::probe_violation(
+                "readiness",
+                c.readiness_probe.as_ref(),
+                &admission_policy,
+            )
+        {
+            violations.push(format!("container '{}' {reason}", container_name));
         }
 
-        if admission_policy.require_readiness_probe.unwrap_or(false) && c.readiness_probe.is_none()
+        if admission_policy.require_resources.unwrap_or(false)
+            && !governance::has_resources(c)
+            && !skipped("missing_resources")
         {
             violations.push(format!(
-                "container '{}' missing readiness probe",
+                "container '{}' missing resource requests/limits",
                 container_name
             ));
         }
     }
 
+    if let Some(reason) = governance::metadata_violation(pod, &admission_policy) {
+        violations.push(reason);
+    }
+
     if violations.is_empty() {
         AdmissionVerdict {
             allowed: true,
@@ -93,10 +166,52 @@ pub fn format_denial_message(violations: &[String]) -> String {
     format!("Denied by DevOpsPolicy: {}", violations.join(", "))
 }
 
+/// Format a denial message naming the workload whose pod template failed.
+pub fn format_workload_denial_message(workload: &str, violations: &[String]) -> String {
+    format!(
+        "Denied by DevOpsPolicy: {workload} pod template: {}",
+        violations.join(", ")
+    )
+}
+
+impl AdmissionVerdict {
+    /// Drop violations the previous version of the object already had, so
+    /// an UPDATE is only denied for violations it introduces. Operator
+    /// patches (remediation, scaling) to non-compliant workloads still pass.
+    pub fn without_existing(self, old: &AdmissionVerdict) -> Self {
+        let violations: Vec<String> = self
+            .violations
+            .into_iter()
+            .filter(|v| !old.violations.contains(v))
+            .collect();
+        if violations.is_empty() {
+            AdmissionVerdict {
+                allowed: true,
+                message: None,
+                violations,
+            }
+        } else {
+            AdmissionVerdict {
+                allowed: false,
+                message: Some(format_denial_message(&violations)),
+                violations,
+            }
+        }
+    }
+
+    /// Reword a denial for a workload (`Kind/name`) instead of a pod.
+    pub fn for_workload(mut self, workload: &str) -> Self {
+        if !self.allowed {
+            self.message = Some(format_workload_denial_message(workload, &self.violations));
+        }
+        self
+    }
+}
+
 /* ============================= SEVERITY-AWARE ADMISSION ============================= */
 
 /// Numeric ordering for severity levels (higher = more severe).
-fn severity_rank(severity: &Severity) -> u8 {
+pub fn severity_rank(severity: &Severity) -> u8 {
     match severity {
         Severity::Low => 1,
         Severity::Medium => 2,
@@ -114,17 +229,27 @@ pub fn validate_pod_admission_with_severity(
     policy: &DevOpsPolicySpec,
     min_deny_severity: &Severity,
 ) -> AdmissionVerdict {
+    validate_pod_admission_with_warnings(pod, policy, min_deny_severity).0
+}
+
+/// Like [`validate_pod_admission_with_severity`], but also returns the
+/// violations below `min_deny_severity` so the caller can surface them as
+/// admission warnings instead of dropping them.
+pub fn validate_pod_admission_with_warnings(
+    pod: &Pod,
+    policy: &DevOpsPolicySpec,
+    min_deny_severity: &Severity,
+) -> (AdmissionVerdict, Vec<ViolationDetail>) {
     let admission_policy = build_admission_policy_for_validation(policy);
     let details = governance::detect_violations_detailed(pod, &admission_policy);
 
     let threshold = severity_rank(min_deny_severity);
-    let violations: Vec<String> = details
-        .iter()
-        .filter(|v| severity_rank(&v.severity) >= threshold)
-        .map(|v| v.message.clone())
-        .collect();
+    let (denied, warnings): (Vec<ViolationDetail>, Vec<ViolationDetail>) = details
+        .into_iter()
+        .partition(|v| severity_rank(&v.severity) >= threshold);
+    let violations: Vec<String> = denied.into_iter().map(|v| v.message).collect();
 
-    if violations.is_empty() {
+    let verdict = if violations.is_empty() {
         AdmissionVerdict {
             allowed: true,
             message: None,
@@ -137,9 +262,157 @@ pub fn validate_pod_admission_with_severity(
             message: Some(message),
             violations,
         }
+    };
+    (verdict, warnings)
+}
+
+/// Format a below-threshold violation as an admission warning.
+pub fn format_admission_warning(violation: &ViolationDetail) -> String {
+    format!(
+        "DevOpsPolicy ({}): {}",
+        format!("{:?}", violation.severity).to_lowercase(),
+        violation.message
+    )
+}
+
+/* ============================= ADMISSION REVIEW ============================= */
+
+/// Fields the webhook reads from an `AdmissionReview` request.
+#[derive(Debug)]
+pub struct AdmissionRequest {
+    pub uid: String,
+    pub operation: String,
+    pub namespace: String,
+    /// `request.kind.kind`; `Pod` when absent.
+    pub kind: String,
+    /// `request.name`, falling back to the object's name.
+    pub name: String,
+    /// `request.object` decoded as a Pod, or for a workload kind a Pod
+    /// built from its `spec.template`.
+    pub pod: Result<Pod, serde_json::Error>,
+    /// `request.oldObject` decoded the same way, when present (UPDATE).
+    pub old_pod: Option<Pod>,
+}
+
+impl AdmissionRequest {
+    /// `Kind/name` when the request is for a workload rather than a Pod.
+    pub fn workload(&self) -> Option<String> {
+        WORKLOAD_KINDS
+            .contains(&self.kind.as_str())
+            .then(|| format!("{}/{}", self.kind, self.name))
     }
 }
 
+/// Build a Pod from a workload's `spec.template` so the pod checks apply.
+///
+/// The template's metadata is kept, so `skip-checks` annotations on the
+/// template exempt containers the same way they do on the pods it creates.
+pub fn pod_from_template(object: &serde_json::Value) -> Result<Pod, serde_json::Error> {
+    let template: PodTemplateSpec = serde_json::from_value(object["spec"]["template"].clone())?;
+    Ok(Pod {
+        metadata: template.metadata.unwrap_or_default(),
+        spec: template.spec,
+        status: None,
+    })
+}
+
+/// Parse an `AdmissionReview` body.
+///
+/// Fails only when the body is not JSON. Missing fields fall back to
+/// defaults so the webhook can still answer with the request's uid.
+pub fn parse_admission_review(body: &str) -> Result<AdmissionRequest, serde_json::Error> {
+    let review: serde_json::Value = serde_json::from_str(body)?;
+    let request = &review["request"];
+    let kind = request["kind"]["kind"]
+        .as_str()
+        .unwrap_or("Pod")
+        .to_string();
+    let decode = |object: &serde_json::Value| {
+        if WORKLOAD_KINDS.contains(&kind.as_str()) {
+            pod_from_template(object)
+        } else {
+            serde_json::from_value::<Pod>(object.clone())
+        }
+    };
+    let object = &request["object"];
+    let pod = decode(object);
+    let old_object = &request["oldObject"];
+    let old_pod = if old_object.is_null() {
+        None
+    } else {
+        decode(old_object).ok()
+    };
+    Ok(AdmissionRequest {
+        uid: request["uid"].as_str().unwrap_or("").to_string(),
+        operation: request["operation"]
+            .as_str()
+            .unwrap_or("UNKNOWN")
+            .to_string(),
+        namespace: request["namespace"]
+            .as_str()
+            .unwrap_or("default")
+            .to_string(),
+        name: request["name"]
+            .as_str()
+            .or_else(|| object["metadata"]["name"].as_str())
+            .unwrap_or("")
+            .to_string(),
+        kind,
+        pod,
+        old_pod,
+    })
+}
+
+/// Serialize an `AdmissionReview` response.
+///
+/// `warnings` are shown by kubectl whether or not the request is allowed;
+/// the field is omitted when empty.
+pub fn build_admission_response(
+    uid: &str,
+    allowed: bool,
+    message: Option<&str>,
+    warnings: &[String],
+) -> String {
+    let mut response = serde_json::json!({
+        "apiVersion": "admission.k8s.io/v1",
+        "kind": "AdmissionReview",
+        "response": {
+            "uid": uid,
+            "allowed": allowed
+        }
+    });
+
+    if let Some(msg) = message {
+        response["response"]["status"] = serde_json::json!({
+            "message": msg
+        });
+    }
+    if !warnings.is_empty() {
+        response["response"]["warnings"] = serde_json::json!(warnings);
+    }
+
+    response.to_string()
+}
+
+/// Warning text for a violation read-only mode would have denied.
+pub fn format_read_only_warning(violation: &str) -> String {
+    format!("[read-only] would deny: {violation}")
+}
+
+/// Allow the request but surface would-be denials as admission warnings.
+pub fn build_warning_response(uid: &str, violations: &[String]) -> String {
+    let warnings: Vec<String> = violations
+        .iter()
+        .map(|v| format_read_only_warning(v))
+        .collect();
+    build_allow_with_warnings(uid, &warnings)
+}
+
+/// Allow the request with the given admission warnings, shown by kubectl.
+pub fn build_allow_with_warnings(uid: &str, warnings: &[String]) -> String {
+    build_admission_response(uid, true, None, warnings)
+}
+
 /* ============================= TESTS ============================= */
 
 #[cfg(test)]
@@ -147,6 +420,7 @@ mod tests {
     use super::*;
     use k8s_openapi::api::core::v1::{Container, PodSpec, Probe};
     use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use std::collections::BTreeMap;
 
     use crate::crd::SeverityOverrides;
 
@@ -225,6 +499,55 @@ mod tests {
         assert!(verdict.violations[0].contains(":latest"));
     }
 
+    #[test]
+    fn test_deny_forbidden_tag_pattern() {
+        let pod = make_admission_pod(
+            "dev-pod",
+            vec![container_with("app", "registry/app:dev-12", true, true)],
+        );
+        let policy = DevOpsPolicySpec {
+            forbidden_tag_patterns: Some(vec!["^dev-".to_string()]),
+            ..Default::default()
+        };
+        let verdict = validate_pod_admission(&pod, &policy);
+        assert!(!verdict.allowed);
+        assert_eq!(
+            verdict.violations,
+            vec!["container 'app' image tag 'dev-12' matches forbidden pattern '^dev-'"]
+        );
+    }
+
+    #[test]
+    fn test_deny_missing_required_label() {
+        let mut pod = make_admission_pod(
+            "unowned",
+            vec![container_with("app", "app:1.0", true, true)],
+        );
+        pod.metadata.labels = Some([("app".to_string(), "web".to_string())].into());
+        let policy = DevOpsPolicySpec {
+            required_labels: Some(
+                [
+                    ("app".to_string(), String::new()),
+                    ("team".to_string(), String::new()),
+                ]
+                .into(),
+            ),
+            ..Default::default()
+        };
+        let verdict = validate_pod_admission(&pod, &policy);
+        assert!(!verdict.allowed);
+        assert_eq!(
+            verdict.violations,
+            vec!["pod missing required label 'team'"]
+        );
+
+        let (verdict, _) = validate_pod_admission_with_warnings(&pod, &policy, &Severity::Medium);
+        assert_eq!(
+            verdict.message.as_deref(),
+            Some("Denied by DevOpsPolicy: pod missing required label 'team'")
+        );
+    }
+
     // ── deny missing liveness probe ──
 
     #[test]
@@ -349,6 +672,47 @@ mod tests {
         assert_eq!(verdict.violations.len(), 6);
     }
 
+    // ── missing resources ──
+
+    #[test]
+    fn test_deny_missing_resources_when_required() {
+        let pod = make_admission_pod("p", vec![container_with("app", "app:1.0", true, true)]);
+        let policy = DevOpsPolicySpec {
+            require_resources: Some(true),
+            ..Default::default()
+        };
+        let verdict = validate_pod_admission(&pod, &policy);
+        assert!(!verdict.allowed);
+        assert!(verdict.violations[0].contains("resource requests/limits"));
+        assert!(validate_pod_admission(&pod, &all_enabled_policy()).allowed);
+    }
+
+    // ── per-container skip-checks ──
+
+    #[test]
+    fn test_skip_checks_exempts_only_named_container() {
+        let mut pod = make_admission_pod(
+            "sidecar-pod",
+            vec![
+                container_with("app", "app:1.0", true, true),
+                container_with("agent", "vendor/agent:2.1", false, false),
+            ],
+        );
+        pod.metadata.annotations = Some(BTreeMap::from([(
+            "devops.stochastic.io/skip-checks.agent".to_string(),
+            "missing_readiness".to_string(),
+        )]));
+
+        let verdict = validate_pod_admission(&pod, &all_enabled_policy());
+        assert!(!verdict.allowed);
+        assert_eq!(verdict.violations.len(), 1);
+        assert!(verdict.violations[0].contains("liveness"));
+
+        let verdict =
+            validate_pod_admission_with_severity(&pod, &all_enabled_policy(), &Severity::Low);
+        assert_eq!(verdict.violations.len(), 1);
+    }
+
     // ── pod with no spec ──
 
     #[test]
@@ -464,6 +828,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deny_unconfigured_probe() {
+        let policy = DevOpsPolicySpec {
+            require_liveness_probe: Some(true),
+            require_configured_probes: Some(true),
+            ..Default::default()
+        };
+        let pod = make_admission_pod(
+            "pod",
+            vec![container_with("nginx", "nginx:1.25", true, true)],
+        );
+        let verdict = validate_pod_admission(&pod, &policy);
+        assert!(!verdict.allowed);
+        assert_eq!(
+            verdict.violations,
+            ["container 'nginx' liveness probe has no httpGet, tcpSocket, exec, or grpc handler"]
+        );
+    }
+
     #[test]
     fn test_severity_admission_low_threshold_denies_all() {
         let policy = DevOpsPolicySpec {
@@ -521,6 +904,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_severity_admission_returns_warnings_below_threshold() {
+        // latest_tag is High, missing_readiness is Low
+        let policy = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            require_readiness_probe: Some(true),
+            ..Default::default()
+        };
+        let pod = make_admission_pod(
+            "pod",
+            vec![container_with("nginx", "nginx:latest", true, false)],
+        );
+        let (verdict, warnings) =
+            validate_pod_admission_with_warnings(&pod, &policy, &Severity::High);
+        assert!(!verdict.allowed);
+        assert_eq!(
+            verdict.violations,
+            vec!["container 'nginx' uses :latest tag"]
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].violation_type, "missing_readiness");
+        assert_eq!(
+            format_admission_warning(&warnings[0]),
+            "DevOpsPolicy (low): container 'nginx' missing readiness probe"
+        );
+
+        let (verdict, warnings) =
+            validate_pod_admission_with_warnings(&pod, &policy, &Severity::Critical);
+        assert!(verdict.allowed);
+        assert_eq!(warnings.len(), 2);
+    }
+
     #[test]
     fn test_severity_admission_compliant_pod_all_thresholds() {
         let policy = all_enabled_policy();
@@ -573,4 +988,252 @@ mod tests {
         let admission = build_admission_policy_for_validation(&policy_with_overrides);
         assert!(admission.severity_overrides.is_some());
     }
+
+    // ── failure mode ──
+
+    #[test]
+    fn test_failure_mode() {
+        assert_eq!(FailureMode::default(), FailureMode::Open);
+        assert!(FailureMode::Open.allows());
+        assert!(!FailureMode::Closed.allows());
+        assert_eq!(FailureMode::Open.failure_policy(), "Ignore");
+        assert_eq!(FailureMode::Closed.failure_policy(), "Fail");
+        assert!(format_failure_message("policy lookup failed").ends_with("policy lookup failed"));
+    }
+
+    // ── AdmissionReview parsing ──
+
+    #[test]
+    fn test_parse_admission_review() {
+        let body = serde_json::json!({
+            "apiVersion": "admission.k8s.io/v1",
+            "kind": "AdmissionReview",
+            "request": {
+                "uid": "abc",
+                "operation": "CREATE",
+                "namespace": "prod",
+                "object": {
+                    "apiVersion": "v1",
+                    "kind": "Pod",
+                    "metadata": { "name": "web" },
+                    "spec": { "containers": [{ "name": "app", "image": "app:latest" }] }
+                }
+            }
+        })
+        .to_string();
+        let request = parse_admission_review(&body).unwrap();
+        assert_eq!(request.uid, "abc");
+        assert_eq!(request.operation, "CREATE");
+        assert_eq!(request.namespace, "prod");
+        let pod = request.pod.unwrap();
+        assert!(!validate_pod_admission(&pod, &all_enabled_policy()).allowed);
+    }
+
+    #[test]
+    fn test_parse_admission_review_deployment_template() {
+        let body = serde_json::json!({
+            "request": {
+                "uid": "dep",
+                "kind": { "group": "apps", "version": "v1", "kind": "Deployment" },
+                "name": "web",
+                "operation": "CREATE",
+                "namespace": "prod",
+                "object": {
+                    "apiVersion": "apps/v1",
+                    "kind": "Deployment",
+                    "metadata": { "name": "web" },
+                    "spec": {
+                        "selector": { "matchLabels": { "app": "web" } },
+                        "template": {
+                            "metadata": {
+                                "labels": { "app": "web" },
+                                "annotations": {
+                                    "devops.stochastic.io/skip-checks.app": "missing_readiness"
+                                }
+                            },
+                            "spec": { "containers": [{ "name": "app", "image": "app:latest" }] }
+                        }
+                    }
+                }
+            }
+        })
+        .to_string();
+        let request = parse_admission_review(&body).unwrap();
+        assert_eq!(request.workload().as_deref(), Some("Deployment/web"));
+
+        let pod = request.pod.as_ref().unwrap();
+        let verdict = validate_pod_admission(pod, &all_enabled_policy())
+            .for_workload(&request.workload().unwrap());
+        assert!(!verdict.allowed);
+        // :latest and liveness; readiness is skipped by the template annotation
+        assert_eq!(verdict.violations.len(), 2);
+        assert!(
+            verdict
+                .message
+                .unwrap()
+                .starts_with("Denied by DevOpsPolicy: Deployment/web pod template:")
+        );
+    }
+
+    #[test]
+    fn test_update_only_denies_new_violations() {
+        let template = |image: &str| {
+            serde_json::json!({
+                "spec": { "template": { "spec": { "containers": [{ "name": "app", "image": image }] } } }
+            })
+        };
+        let body = serde_json::json!({
+            "request": {
+                "kind": { "kind": "Deployment" },
+                "operation": "UPDATE",
+                "object": template("app:latest"),
+                "oldObject": template("app:1.0"),
+            }
+        })
+        .to_string();
+        let request = parse_admission_review(&body).unwrap();
+        let policy = all_enabled_policy();
+        let old = validate_pod_admission(request.old_pod.as_ref().unwrap(), &policy);
+        let verdict =
+            validate_pod_admission(request.pod.as_ref().unwrap(), &policy).without_existing(&old);
+        // Missing probes were already there; only the :latest tag is new
+        assert!(!verdict.allowed);
+        assert_eq!(verdict.violations.len(), 1);
+        assert!(verdict.violations[0].contains(":latest"));
+
+        let unchanged = old.clone().without_existing(&old);
+        assert!(unchanged.allowed);
+        assert!(unchanged.message.is_none());
+    }
+
+    #[test]
+    fn test_parse_admission_review_workload_without_template() {
+        let body = r#"{"request": {"kind": {"kind": "StatefulSet"}, "object": {"spec": {}}}}"#;
+        let request = parse_admission_review(body).unwrap();
+        assert!(request.pod.is_err());
+        assert_eq!(request.workload().as_deref(), Some("StatefulSet/"));
+    }
+
+    #[test]
+    fn test_pod_request_has_no_workload() {
+        let request = parse_admission_review(r#"{"request": {"object": {}}}"#).unwrap();
+        assert_eq!(request.kind, "Pod");
+        assert!(request.old_pod.is_none());
+        assert!(request.workload().is_none());
+        let verdict = AdmissionVerdict {
+            allowed: true,
+            message: None,
+            violations: Vec::new(),
+        }
+        .for_workload("Deployment/web");
+        assert!(verdict.message.is_none());
+    }
+
+    #[test]
+    fn test_parse_admission_review_malformed_never_panics() {
+        let deep = "[".repeat(10_000);
+        let inputs = [
+            "",
+            "null",
+            "[]",
+            "\"request\"",
+            r#"{"request": 5}"#,
+            r#"{"request": {"uid": 7, "object": []}}"#,
+            r#"{"request": {"object": {"spec": {"containers": [{"name": 1}]}}}}"#,
+            r#"{"request": {"object": {"spec": {"containers": [{"name": "a", "ports": [{"containerPort": -1}]}]}}}}"#,
+            r#"{"request": {"kind": {"kind": "Deployment"}, "object": {"spec": {"template": 3}}}}"#,
+            r#"{"request": {"kind": "DaemonSet", "object": {"spec": {"template": {"spec": {}}}}}}"#,
+            deep.as_str(),
+        ];
+        for input in inputs {
+            if let Ok(request) = parse_admission_review(input) {
+                assert!(request.uid.is_empty());
+                if let Ok(pod) = request.pod {
+                    validate_pod_admission(&pod, &all_enabled_policy());
+                }
+            }
+        }
+    }
+
+    // ── AdmissionReview responses ──
+
+    #[test]
+    fn test_build_admission_response_allowed() {
+        let resp = build_admission_response("test-uid-123", true, None, &[]);
+        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
+        assert_eq!(v["response"]["uid"], "test-uid-123");
+        assert_eq!(v["response"]["allowed"], true);
+        assert!(v["response"]["status"].is_null());
+        assert!(v["response"].get("warnings").is_none());
+    }
+
+    #[test]
+    fn test_build_admission_response_denied_with_warnings() {
+        let resp = build_admission_response(
+            "uid-7",
+            false,
+            Some("Denied by DevOpsPolicy: container 'nginx' uses :latest tag"),
+            &["DevOpsPolicy (low): container 'nginx' missing readiness probe".to_string()],
+        );
+        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
+        assert_eq!(v["response"]["allowed"], false);
+        assert_eq!(
+            v["response"]["warnings"][0],
+            "DevOpsPolicy (low): container 'nginx' missing readiness probe"
+        );
+    }
+
+    #[test]
+    fn test_build_admission_response_denied() {
+        let resp = build_admission_response(
+            "test-uid-456",
+            false,
+            Some("container 'nginx' uses :latest tag"),
+            &[],
+        );
+        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
+        assert_eq!(v["response"]["uid"], "test-uid-456");
+        assert_eq!(v["response"]["allowed"], false);
+        assert_eq!(
+            v["response"]["status"]["message"],
+            "container 'nginx' uses :latest tag"
+        );
+    }
+
+    #[test]
+    fn test_build_warning_response_allows() {
+        let json =
+            build_warning_response("uid-9", &["container 'app' uses :latest tag".to_string()]);
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["response"]["allowed"], true);
+        assert_eq!(v["response"]["uid"], "uid-9");
+        assert!(v["response"].get("status").is_none());
+        assert_eq!(
+            v["response"]["warnings"][0],
+            "[read-only] would deny: container 'app' uses :latest tag"
+        );
+    }
+
+    #[test]
+    fn test_evaluation_budget() {
+        assert_eq!(
+            evaluation_budget(DEFAULT_TIMEOUT_SECONDS),
+            std::time::Duration::from_secs(8)
+        );
+        assert_eq!(evaluation_budget(1), std::time::Duration::from_millis(800));
+        let v: serde_json::Value =
+            serde_json::from_str(&build_allow_with_warnings("u", &["slow".to_string()])).unwrap();
+        assert_eq!(v["response"]["allowed"], true);
+        assert_eq!(v["response"]["warnings"][0], "slow");
+    }
+
+    #[test]
+    fn test_build_admission_response_preserves_uid() {
+        let uid = "550e8400-e29b-41d4-a716-446655440000";
+        let resp = build_admission_response(uid, true, None, &[]);
+        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
+        assert_eq!(v["response"]["uid"], uid);
+        assert_eq!(v["apiVersion"], "admission.k8s.io/v1");
+        assert_eq!(v["kind"], "AdmissionReview");
+    }
 }
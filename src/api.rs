@@ -0,0 +1,243 @@
+//! Typed client API for the kube-devops CRDs.
+//!
+//! Services that only need to create policies or read audit results can
+//! depend on the crate without the operator (CLI, HTTP servers, metrics):
+//!
+//! ```toml
+//! kube-devops = { version = "0.2", default-features = false }
+//! ```
+//!
+//! Everything exported here follows semver: renaming or removing an item,
+//! or changing a constant's value, is a breaking change.
+//!
+//! ```
+//! use kube_devops::api::{self, DevOpsPolicySpec};
+//!
+//! let policy = api::new_policy(
+//!     "production",
+//!     "devops-policy",
+//!     DevOpsPolicySpec {
+//!         forbid_latest_tag: Some(true),
+//!         ..Default::default()
+//!     },
+//! );
+//! assert_eq!(policy.metadata.namespace.as_deref(), Some("production"));
+//! ```
+
+use kube::CustomResourceExt;
+
+use crate::bundles;
+
+pub use crate::crd::{
+    AuditRetention, AuditViolation, ClusterDevOpsPolicy, ClusterDevOpsPolicySpec,
+    DefaultProbeConfig, DefaultResourceConfig, DevOpsPolicy, DevOpsPolicySpec, DevOpsPolicyStatus,
+    EnforcementMode, EvictionConfig, NotificationChannel, NotificationChannelType,
+    NotificationConfig, PlannedAction, PlannedRemediation, PolicyAuditResult,
+    PolicyAuditResultSpec, PolicyProfile, QuarantineConfig, QuarantineRecord, QuarantineRecordSpec,
+    ScoreTrend, ScoringWeightOverrides, Severity, SeverityOverrides,
+};
+pub use crate::enforcement::{
+    PATCHED_BY_ANNOTATION, PATCHED_BY_POLICY_ANNOTATION, REMEDIATION_BACKUP_ANNOTATION,
+};
+pub use crate::governance::SKIP_CHECKS_ANNOTATION_PREFIX;
+pub use crate::policy_resolution::{DEFAULT_PROFILE_LABEL, WORKLOAD_OVERRIDE_ANNOTATION_PREFIX};
+pub use crate::quarantine::QUARANTINE_APPROVED_ANNOTATION;
+
+/* ============================= CONSTANTS ============================= */
+
+/// API group of every kube-devops CRD.
+pub const API_GROUP: &str = "devops.stochastic.io";
+
+/// Stored CRD version, and the only one served unless the DevOpsPolicy CRD
+/// is installed with a conversion webhook (see `crate::conversion`).
+pub const API_VERSION: &str = "v1";
+
+/// Finalizer the operator adds to each DevOpsPolicy.
+pub const FINALIZER: &str = "devops.stochastic.io/cleanup";
+
+/// Minimum health score for `status.healthy` to be true.
+pub const HEALTHY_SCORE_THRESHOLD: u32 = 80;
+
+/// `status.classification` for scores 80–100 (by default, see
+/// `healthThresholds`).
+pub const CLASSIFICATION_HEALTHY: &str = "Healthy";
+
+/// `status.classification` for scores 60–79.
+pub const CLASSIFICATION_STABLE: &str = "Stable";
+
+/// `status.classification` for scores 40–59.
+pub const CLASSIFICATION_DEGRADED: &str = "Degraded";
+
+/// `status.classification` for scores below 40.
+pub const CLASSIFICATION_CRITICAL: &str = "Critical";
+
+/* ============================= CONSTRUCTORS ============================= */
+
+/// Build a DevOpsPolicy in `namespace`, ready to `create`.
+pub fn new_policy(namespace: &str, name: &str, spec: DevOpsPolicySpec) -> DevOpsPolicy {
+    let mut policy = DevOpsPolicy::new(name, spec);
+    policy.metadata.namespace = Some(namespace.to_string());
+    policy
+}
+
+/// Build a ClusterDevOpsPolicy, ready to `create`.
+pub fn new_cluster_policy(name: &str, spec: ClusterDevOpsPolicySpec) -> ClusterDevOpsPolicy {
+    ClusterDevOpsPolicy::new(name, spec)
+}
+
+/// Build a DevOpsPolicy from a built-in bundle (baseline, restricted, permissive).
+pub fn policy_from_bundle(bundle: &str, namespace: &str, name: &str) -> Option<DevOpsPolicy> {
+    bundles::get_bundle(bundle).map(|b| new_policy(namespace, name, b.spec))
+}
+
+/* ============================= READERS ============================= */
+
+/// Most recent audit result produced by `policy_name`.
+///
+/// Timestamps are RFC 3339 in UTC, so they order lexicographically.
+pub fn latest_audit_result<'a>(
+    results: &'a [PolicyAuditResult],
+    policy_name: &str,
+) -> Option<&'a PolicyAuditResult> {
+    results
+        .iter()
+        .filter(|r| r.spec.policy_name == policy_name)
+        .max_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp))
+}
+
+/// Whether a policy status reports a healthy namespace.
+///
+/// Falls back to the score and the status' `healthThresholds` when
+/// `healthy` is unset.
+pub fn is_healthy(status: &DevOpsPolicyStatus) -> bool {
+    status.healthy.unwrap_or_else(|| {
+        let threshold = status
+            .health_thresholds
+            .as_ref()
+            .and_then(|t| t.healthy)
+            .unwrap_or(HEALTHY_SCORE_THRESHOLD);
+        status.health_score.is_some_and(|score| score >= threshold)
+    })
+}
+
+/// Fully-qualified CRD names (`<plural>.<group>`) this API expects installed.
+pub fn crd_names() -> Vec<String> {
+    [
+        DevOpsPolicy::crd(),
+        ClusterDevOpsPolicy::crd(),
+        PolicyAuditResult::crd(),
+        QuarantineRecord::crd(),
+    ]
+    .into_iter()
+    .filter_map(|crd| crd.metadata.name)
+    .collect()
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn audit(name: &str, policy: &str, timestamp: &str) -> PolicyAuditResult {
+        PolicyAuditResult::new(
+            name,
+            PolicyAuditResultSpec {
+                policy_name: policy.to_string(),
+                cluster_name: None,
+                timestamp: timestamp.to_string(),
+                health_score: 90,
+                total_violations: 0,
+                total_pods: 1,
+                total_workloads: None,
+                classification: CLASSIFICATION_HEALTHY.to_string(),
+                violations: Vec::new(),
+                planned_remediations: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_constants_match_crds() {
+        let crd = DevOpsPolicy::crd();
+        assert_eq!(crd.spec.group, API_GROUP);
+        assert_eq!(crd.spec.versions[0].name, API_VERSION);
+        assert!(FINALIZER.starts_with(API_GROUP));
+    }
+
+    #[test]
+    fn test_classification_constants_match_scoring() {
+        use crate::governance::classify_health;
+        assert_eq!(
+            classify_health(HEALTHY_SCORE_THRESHOLD),
+            CLASSIFICATION_HEALTHY
+        );
+        assert_eq!(classify_health(65), CLASSIFICATION_STABLE);
+        assert_eq!(classify_health(45), CLASSIFICATION_DEGRADED);
+        assert_eq!(classify_health(10), CLASSIFICATION_CRITICAL);
+    }
+
+    #[test]
+    fn test_new_policy_sets_namespace() {
+        let policy = new_policy("prod", "p", DevOpsPolicySpec::default());
+        assert_eq!(policy.metadata.name.as_deref(), Some("p"));
+        assert_eq!(policy.metadata.namespace.as_deref(), Some("prod"));
+        assert!(policy.status.is_none());
+    }
+
+    #[test]
+    fn test_policy_from_bundle() {
+        let policy = policy_from_bundle("restricted", "prod", "strict").unwrap();
+        assert_eq!(policy.spec.forbid_latest_tag, Some(true));
+        assert!(policy_from_bundle("missing", "prod", "x").is_none());
+    }
+
+    #[test]
+    fn test_latest_audit_result_filters_by_policy() {
+        let results = vec![
+            audit("a-1", "a", "2026-03-01T10:00:00Z"),
+            audit("a-2", "a", "2026-03-01T11:00:00Z"),
+            audit("b-1", "b", "2026-03-01T12:00:00Z"),
+        ];
+        let latest = latest_audit_result(&results, "a").unwrap();
+        assert_eq!(latest.metadata.name.as_deref(), Some("a-2"));
+        assert!(latest_audit_result(&results, "c").is_none());
+    }
+
+    #[test]
+    fn test_is_healthy_falls_back_to_score() {
+        let status = DevOpsPolicyStatus {
+            health_score: Some(85),
+            ..Default::default()
+        };
+        assert!(is_healthy(&status));
+        let status = DevOpsPolicyStatus {
+            healthy: Some(false),
+            health_score: Some(85),
+            ..Default::default()
+        };
+        assert!(!is_healthy(&status));
+        let status = DevOpsPolicyStatus {
+            health_score: Some(85),
+            health_thresholds: Some(crate::crd::HealthThresholds {
+                healthy: Some(90),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(!is_healthy(&status));
+    }
+
+    #[test]
+    fn test_crd_names() {
+        assert_eq!(
+            crd_names(),
+            vec![
+                "devopspolicies.devops.stochastic.io",
+                "clusterdevopspolicies.devops.stochastic.io",
+                "policyauditresults.devops.stochastic.io",
+                "quarantinerecords.devops.stochastic.io",
+            ]
+        );
+    }
+}
@@ -1,7 +1,16 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::core::v1::Pod;
+use serde::{Deserialize, Serialize};
+
 use crate::crd::{
     DefaultProbeConfig, DefaultResourceConfig, DevOpsPolicySpec, EnforcementMode, Severity,
     SeverityOverrides,
 };
+use crate::governance;
+use crate::policy_validation::{self, IssueLevel};
 
 /* ============================= TYPES ============================= */
 
@@ -11,8 +20,14 @@ pub struct PolicyBundle {
     pub name: String,
     pub description: String,
     pub spec: DevOpsPolicySpec,
+    /// Where the bundle was loaded from: [`BUILT_IN`], a file path, or
+    /// `configmap/<namespace>/<name>:<key>`.
+    pub source: String,
 }
 
+/// `PolicyBundle::source` of the bundles shipped with kube-devops.
+pub const BUILT_IN: &str = "built-in";
+
 /* ============================= BUNDLES ============================= */
 
 /// Return all built-in policy bundles.
@@ -36,6 +51,7 @@ fn baseline_bundle() -> PolicyBundle {
             enforcement_mode: Some(EnforcementMode::Audit),
             ..Default::default()
         },
+        source: BUILT_IN.to_string(),
     }
 }
 
@@ -45,10 +61,23 @@ fn restricted_bundle() -> PolicyBundle {
         description: "All checks enabled with strict thresholds. Enforce mode.".to_string(),
         spec: DevOpsPolicySpec {
             forbid_latest_tag: Some(true),
+            forbidden_tag_patterns: None,
+            required_tag_pattern: None,
+            require_image_digest: None,
+            forbid_image_pull_policy_always: None,
+            require_image_pull_policy_if_not_present: None,
             require_liveness_probe: Some(true),
             require_readiness_probe: Some(true),
+            require_configured_probes: None,
+            require_resources: Some(true),
+            required_labels: None,
+            required_annotations: None,
             max_restart_count: Some(3),
             forbid_pending_duration: Some(300),
+            forbid_crash_loop: Some(true),
+            forbid_image_pull_errors: Some(true),
+            require_pod_disruption_budget: Some(true),
+            require_spread_constraints: Some(true),
             enforcement_mode: Some(EnforcementMode::Enforce),
             default_probe: Some(DefaultProbeConfig {
                 tcp_port: None,
@@ -67,8 +96,32 @@ fn restricted_bundle() -> PolicyBundle {
                 missing_readiness: Some(Severity::High),
                 high_restarts: Some(Severity::Critical),
                 pending: Some(Severity::High),
+                missing_resources: Some(Severity::High),
+                crash_loop: Some(Severity::Critical),
+                image_pull_error: Some(Severity::Critical),
+                missing_metadata: Some(Severity::High),
+                missing_pdb: Some(Severity::High),
+                no_spread: Some(Severity::High),
+                unpinned_image: Some(Severity::High),
+                pull_policy: Some(Severity::Medium),
             }),
+            scoring_weights: None,
+            health_thresholds: None,
+            cleanup_on_delete: None,
+            evict_crash_looping: None,
+            eviction: None,
+            max_remediations_per_cycle: None,
+            remediation_cooldown_seconds: None,
+            quarantine: None,
+            profiles: None,
+            profile_label: None,
+            min_deny_severity: None,
+            timezone: None,
+            reevaluation_interval: None,
+            audit_retention: None,
+            notifications: None,
         },
+        source: BUILT_IN.to_string(),
     }
 }
 
@@ -82,6 +135,8 @@ fn permissive_bundle() -> PolicyBundle {
             require_readiness_probe: Some(true),
             max_restart_count: Some(10),
             forbid_pending_duration: Some(600),
+            forbid_crash_loop: Some(true),
+            forbid_image_pull_errors: Some(true),
             enforcement_mode: Some(EnforcementMode::Audit),
             severity_overrides: Some(SeverityOverrides {
                 latest_tag: Some(Severity::Low),
@@ -89,10 +144,272 @@ fn permissive_bundle() -> PolicyBundle {
                 missing_readiness: Some(Severity::Low),
                 high_restarts: Some(Severity::Medium),
                 pending: Some(Severity::Low),
+                missing_resources: Some(Severity::Low),
+                crash_loop: Some(Severity::Medium),
+                image_pull_error: Some(Severity::Medium),
+                missing_metadata: Some(Severity::Low),
+                missing_pdb: Some(Severity::Low),
+                no_spread: Some(Severity::Low),
+                unpinned_image: Some(Severity::Low),
+                pull_policy: Some(Severity::Low),
             }),
             ..Default::default()
         },
+        source: BUILT_IN.to_string(),
+    }
+}
+
+/* ============================= CUSTOM BUNDLES ============================= */
+
+/// A user-defined bundle, one per YAML file (or ConfigMap key):
+///
+/// ```yaml
+/// name: team-standard
+/// description: Production defaults for the payments team
+/// spec:
+///   forbidLatestTag: true
+///   requireReadinessProbe: true
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BundleFile {
+    name: String,
+    #[serde(default)]
+    description: String,
+    spec: serde_json::Value,
+}
+
+/// Parse a bundle file, validating its spec like `policy validate` does.
+/// `source` names the file in errors and becomes `PolicyBundle::source`.
+pub fn parse_bundle(content: &str, source: &str) -> anyhow::Result<PolicyBundle> {
+    let file: BundleFile =
+        serde_yaml::from_str(content).with_context(|| format!("Invalid bundle file {source}"))?;
+    let valid_name = !file.name.is_empty()
+        && file
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid_name {
+        anyhow::bail!(
+            "Bundle name '{}' in {source} must be lowercase letters, digits, and '-'",
+            file.name
+        );
     }
+
+    let errors: Vec<String> = policy_validation::validate_spec_value(&file.spec)
+        .into_iter()
+        .filter(|issue| issue.level == IssueLevel::Error)
+        .map(|issue| format!("  {}: {}", issue.field, issue.message))
+        .collect();
+    if !errors.is_empty() {
+        anyhow::bail!(
+            "Bundle '{}' in {source} has an invalid spec:\n{}",
+            file.name,
+            errors.join("\n")
+        );
+    }
+
+    Ok(PolicyBundle {
+        name: file.name,
+        description: file.description,
+        spec: serde_json::from_value(file.spec)?,
+        source: source.to_string(),
+    })
+}
+
+/// Load every `*.yaml` / `*.yml` bundle file directly inside `dir`, by file name.
+pub fn load_bundle_dir(dir: &Path) -> anyhow::Result<Vec<PolicyBundle>> {
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read bundle directory '{}'", dir.display()))?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && is_yaml(&path.to_string_lossy()))
+        .collect();
+    files.sort();
+
+    files
+        .iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read bundle file '{}'", path.display()))?;
+            parse_bundle(&content, &path.display().to_string())
+        })
+        .collect()
+}
+
+/// Whether a file name or ConfigMap key holds a bundle.
+pub fn is_yaml(name: &str) -> bool {
+    name.ends_with(".yaml") || name.ends_with(".yml")
+}
+
+/// The built-in bundles followed by `custom`. A name defined twice, including
+/// a custom bundle reusing a built-in name, is an error naming both sources.
+pub fn with_custom_bundles(custom: Vec<PolicyBundle>) -> anyhow::Result<Vec<PolicyBundle>> {
+    let mut bundles = all_bundles();
+    for bundle in custom {
+        if let Some(existing) = bundles.iter().find(|b| b.name == bundle.name) {
+            let existing = if existing.source == BUILT_IN {
+                "the built-in bundles"
+            } else {
+                &existing.source
+            };
+            anyhow::bail!(
+                "Bundle '{}' is defined by both {existing} and {}; rename one of them",
+                bundle.name,
+                bundle.source
+            );
+        }
+        bundles.push(bundle);
+    }
+    Ok(bundles)
+}
+
+/// Look up `name` (case-insensitive) among `bundles`, listing the available
+/// names when it is missing.
+pub fn find_bundle<'a>(
+    bundles: &'a [PolicyBundle],
+    name: &str,
+) -> anyhow::Result<&'a PolicyBundle> {
+    let lower = name.to_lowercase();
+    bundles.iter().find(|b| b.name == lower).ok_or_else(|| {
+        let available: Vec<&str> = bundles.iter().map(|b| b.name.as_str()).collect();
+        anyhow::anyhow!(
+            "Unknown bundle '{}'. Available bundles: {}",
+            name,
+            available.join(", ")
+        )
+    })
+}
+
+/* ============================= CLUSTER BASELINE ============================= */
+
+/// Lowest `maxRestartCount` a synthesized baseline uses, the built-in threshold.
+pub const BASELINE_MIN_RESTARTS: i32 = 3;
+
+/// Lowest `forbidPendingDuration` (seconds) a synthesized baseline uses.
+pub const BASELINE_MIN_PENDING_SECONDS: u64 = 300;
+
+/// How the observed pods fared on one check of a synthesized baseline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineCheck {
+    /// Spec field, e.g. `requireLivenessProbe`.
+    pub field: &'static str,
+    /// The chosen setting: `true`, `off`, or a threshold.
+    pub setting: String,
+    /// Pods that violate the strict setting (for toggles) or the chosen
+    /// threshold.
+    pub violating_pods: usize,
+}
+
+/// A policy synthesized from running pods, with the evidence behind it.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterBaseline {
+    pub total_pods: usize,
+    pub spec: DevOpsPolicySpec,
+    pub checks: Vec<BaselineCheck>,
+}
+
+/// Synthesize an audit-mode policy the given pods already meet.
+///
+/// Each toggle check is enabled only if no pod violates it. Thresholds use
+/// the 95th percentile of what is observed (per-container restart counts,
+/// age of Pending pods), never below [`BASELINE_MIN_RESTARTS`] and
+/// [`BASELINE_MIN_PENDING_SECONDS`], so the few outliers above it are the
+/// only violations.
+pub fn baseline_from_pods(pods: &[Pod], now: DateTime<Utc>) -> ClusterBaseline {
+    let mut spec = DevOpsPolicySpec {
+        enforcement_mode: Some(EnforcementMode::Audit),
+        ..Default::default()
+    };
+    let mut checks = Vec::new();
+    let violating = |policy: &DevOpsPolicySpec| {
+        pods.iter()
+            .filter(|pod| !governance::detect_violations_with_policy(pod, policy).is_empty())
+            .count()
+    };
+
+    type Toggle = fn(&mut DevOpsPolicySpec) -> &mut Option<bool>;
+    let toggles: [(&str, Toggle); 6] = [
+        ("forbidLatestTag", |s| &mut s.forbid_latest_tag),
+        ("requireLivenessProbe", |s| &mut s.require_liveness_probe),
+        ("requireReadinessProbe", |s| &mut s.require_readiness_probe),
+        ("requireResources", |s| &mut s.require_resources),
+        ("forbidCrashLoop", |s| &mut s.forbid_crash_loop),
+        ("forbidImagePullErrors", |s| &mut s.forbid_image_pull_errors),
+    ];
+    for (field, toggle) in toggles {
+        let mut strict = DevOpsPolicySpec::default();
+        *toggle(&mut strict) = Some(true);
+        let violating_pods = violating(&strict);
+        let enabled = violating_pods == 0;
+        if enabled {
+            *toggle(&mut spec) = Some(true);
+        }
+        checks.push(BaselineCheck {
+            field,
+            setting: if enabled { "true" } else { "off" }.to_string(),
+            violating_pods,
+        });
+    }
+
+    let restarts = pods
+        .iter()
+        .filter_map(|pod| pod.status.as_ref()?.container_statuses.as_ref())
+        .flatten()
+        .map(|cs| cs.restart_count.max(0) as u64);
+    let max_restarts = percentile_95(restarts)
+        .map_or(BASELINE_MIN_RESTARTS, |p| p.min(i32::MAX as u64) as i32)
+        .max(BASELINE_MIN_RESTARTS);
+    spec.max_restart_count = Some(max_restarts);
+    checks.push(BaselineCheck {
+        field: "maxRestartCount",
+        setting: max_restarts.to_string(),
+        violating_pods: violating(&DevOpsPolicySpec {
+            max_restart_count: Some(max_restarts),
+            ..Default::default()
+        }),
+    });
+
+    let pending_ages = pods
+        .iter()
+        .filter(|pod| pod.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Pending"))
+        .filter_map(governance::pending_since)
+        .map(|since| now.signed_duration_since(since).num_seconds().max(0) as u64);
+    let pending_limit = percentile_95(pending_ages)
+        .unwrap_or_default()
+        .max(BASELINE_MIN_PENDING_SECONDS);
+    spec.forbid_pending_duration = Some(pending_limit);
+    let pending_policy = DevOpsPolicySpec {
+        forbid_pending_duration: Some(pending_limit),
+        ..Default::default()
+    };
+    checks.push(BaselineCheck {
+        field: "forbidPendingDuration",
+        setting: pending_limit.to_string(),
+        violating_pods: pods
+            .iter()
+            .filter(|pod| governance::pending_violation(pod, &pending_policy, now).is_some())
+            .count(),
+    });
+
+    ClusterBaseline {
+        total_pods: pods.len(),
+        spec,
+        checks,
+    }
+}
+
+/// Nearest-rank 95th percentile, or `None` without values.
+fn percentile_95(values: impl Iterator<Item = u64>) -> Option<u64> {
+    let mut values: Vec<u64> = values.collect();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let rank = (values.len() * 95).div_ceil(100);
+    Some(values[rank.max(1) - 1])
 }
 
 /* ============================= TESTS ============================= */
@@ -180,4 +497,207 @@ mod tests {
         unique.dedup();
         assert_eq!(names.len(), unique.len(), "bundle names should be unique");
     }
+
+    // ── Custom bundles ──
+
+    const TEAM_BUNDLE: &str = "name: team-standard
+description: Payments defaults
+spec:
+  forbidLatestTag: true
+  maxRestartCount: 5
+";
+
+    #[test]
+    fn test_parse_bundle() {
+        let bundle = parse_bundle(TEAM_BUNDLE, "team.yaml").unwrap();
+        assert_eq!(bundle.name, "team-standard");
+        assert_eq!(bundle.description, "Payments defaults");
+        assert_eq!(bundle.source, "team.yaml");
+        assert_eq!(bundle.spec.forbid_latest_tag, Some(true));
+        assert_eq!(bundle.spec.max_restart_count, Some(5));
+    }
+
+    #[test]
+    fn test_parse_bundle_rejects_invalid_files() {
+        let err = |content: &str| parse_bundle(content, "b.yaml").unwrap_err().to_string();
+
+        assert!(err("name: Team_A\nspec: {}\n").contains("'Team_A' in b.yaml must be lowercase"));
+        assert!(err("name: a\nspecs: {}\n").contains("Invalid bundle file b.yaml"));
+
+        let invalid = err("name: a\nspec:\n  maxRestartCount: -1\n  requireLivenessProbes: true\n");
+        assert!(invalid.contains("Bundle 'a' in b.yaml has an invalid spec"));
+        assert!(invalid.contains("spec.maxRestartCount: -1 is below the minimum of 0"));
+        assert!(invalid.contains("did you mean 'requireLivenessProbe'"));
+
+        // Cross-field rules apply too.
+        let cel =
+            err("name: a\nspec:\n  enforcementMode: enforce\n  requireReadinessProbe: true\n");
+        assert!(cel.contains("defaultProbe is required"));
+    }
+
+    #[test]
+    fn test_load_bundle_dir() {
+        let dir = std::env::temp_dir().join(format!("kube-devops-bundles-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("team.yaml"), TEAM_BUNDLE).unwrap();
+        std::fs::write(dir.join("dev.yml"), "name: dev\nspec: {}\n").unwrap();
+        std::fs::write(dir.join("README.md"), "not a bundle").unwrap();
+        std::fs::write(dir.join("nested/ignored.yaml"), "name: x\nspec: {}\n").unwrap();
+
+        let loaded = load_bundle_dir(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        let names: Vec<String> = loaded.unwrap().into_iter().map(|b| b.name).collect();
+        assert_eq!(names, ["dev", "team-standard"]);
+
+        let missing = load_bundle_dir(&dir).unwrap_err().to_string();
+        assert!(missing.contains("Failed to read bundle directory"));
+    }
+
+    #[test]
+    fn test_with_custom_bundles_collisions() {
+        let team = parse_bundle(TEAM_BUNDLE, "team.yaml").unwrap();
+        let bundles = with_custom_bundles(vec![team.clone()]).unwrap();
+        assert_eq!(bundles.len(), 4);
+        assert_eq!(
+            find_bundle(&bundles, "Team-Standard").unwrap().source,
+            "team.yaml"
+        );
+
+        let err = with_custom_bundles(vec![
+            team.clone(),
+            PolicyBundle {
+                source: "configmap/ops/bundles:team.yaml".to_string(),
+                ..team
+            },
+        ])
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Bundle 'team-standard' is defined by both team.yaml and \
+             configmap/ops/bundles:team.yaml; rename one of them"
+        );
+
+        let shadow = parse_bundle("name: baseline\nspec: {}\n", "base.yaml").unwrap();
+        let err = with_custom_bundles(vec![shadow]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("both the built-in bundles and base.yaml")
+        );
+    }
+
+    #[test]
+    fn test_find_bundle_unknown() {
+        let err = find_bundle(&all_bundles(), "strict").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown bundle 'strict'. Available bundles: baseline, restricted, permissive"
+        );
+    }
+
+    // ── Cluster baseline ──
+
+    fn pod(image: &str, probes: bool, restarts: i32) -> Pod {
+        use k8s_openapi::api::core::v1::{Container, ContainerStatus, PodSpec, PodStatus, Probe};
+        Pod {
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    image: Some(image.to_string()),
+                    readiness_probe: probes.then(Probe::default),
+                    liveness_probe: probes.then(Probe::default),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some("Running".to_string()),
+                container_statuses: Some(vec![ContainerStatus {
+                    name: "app".to_string(),
+                    restart_count: restarts,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn setting(baseline: &ClusterBaseline, field: &str) -> (String, usize) {
+        let check = baseline.checks.iter().find(|c| c.field == field).unwrap();
+        (check.setting.clone(), check.violating_pods)
+    }
+
+    #[test]
+    fn test_baseline_enables_checks_the_cluster_meets() {
+        let pods = vec![
+            pod("nginx:1.25", true, 0),
+            pod("redis:7", true, 1),
+            pod("app:latest", false, 0),
+        ];
+        let baseline = baseline_from_pods(&pods, Utc::now());
+        assert_eq!(baseline.total_pods, 3);
+        assert_eq!(baseline.spec.enforcement_mode, Some(EnforcementMode::Audit));
+        assert_eq!(baseline.spec.forbid_latest_tag, None);
+        assert_eq!(baseline.spec.require_liveness_probe, None);
+        assert_eq!(baseline.spec.require_resources, None);
+        assert_eq!(baseline.spec.forbid_crash_loop, Some(true));
+        assert_eq!(baseline.spec.forbid_image_pull_errors, Some(true));
+        assert_eq!(
+            setting(&baseline, "forbidLatestTag"),
+            ("off".to_string(), 1)
+        );
+        assert_eq!(
+            setting(&baseline, "requireResources"),
+            ("off".to_string(), 3)
+        );
+        assert_eq!(
+            setting(&baseline, "forbidCrashLoop"),
+            ("true".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn test_baseline_thresholds_use_p95() {
+        // 19 quiet containers and one outlier: p95 is the 19th value.
+        let mut pods: Vec<Pod> = (0..19).map(|i| pod("app:1", true, 4 + i % 3)).collect();
+        pods.push(pod("app:1", true, 40));
+        let baseline = baseline_from_pods(&pods, Utc::now());
+        assert_eq!(baseline.spec.max_restart_count, Some(6));
+        assert_eq!(setting(&baseline, "maxRestartCount"), ("6".to_string(), 1));
+
+        // Quiet clusters keep the built-in floor.
+        let quiet = baseline_from_pods(&[pod("app:1", true, 0)], Utc::now());
+        assert_eq!(quiet.spec.max_restart_count, Some(BASELINE_MIN_RESTARTS));
+        assert_eq!(
+            quiet.spec.forbid_pending_duration,
+            Some(BASELINE_MIN_PENDING_SECONDS)
+        );
+    }
+
+    #[test]
+    fn test_baseline_pending_threshold() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+        let now = Utc::now();
+        let pending = |age_seconds: i64| {
+            let mut pod = pod("app:1", true, 0);
+            pod.metadata.creation_timestamp =
+                Some(Time(now - chrono::Duration::seconds(age_seconds)));
+            pod.status.as_mut().unwrap().phase = Some("Pending".to_string());
+            pod
+        };
+        let baseline = baseline_from_pods(&[pending(900), pending(60)], now);
+        assert_eq!(baseline.spec.forbid_pending_duration, Some(900));
+        assert_eq!(
+            setting(&baseline, "forbidPendingDuration"),
+            ("900".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn test_percentile_95() {
+        assert_eq!(percentile_95(std::iter::empty()), None);
+        assert_eq!(percentile_95([7].into_iter()), Some(7));
+        assert_eq!(percentile_95((1..=100).rev()), Some(95));
+        assert_eq!(percentile_95([1, 2, 3].into_iter()), Some(3));
+    }
 }
@@ -1,10 +1,74 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
 use clap::{Parser, Subcommand};
+use kube_devops::admission::{DEFAULT_TIMEOUT_SECONDS, FailureMode};
+use kube_devops::clock::Timezone;
+use kube_devops::crd::Severity;
+use kube_devops::output::OutputFormat;
+use tracing_subscriber::filter::Directive;
+
+use crate::logging::LogFormat;
 
 #[derive(Parser)]
 #[command(name = "kube-devops")]
 #[command(about = "Kubernetes DevOps Enhancement Tool")]
 #[command(version = env!("CARGO_PKG_VERSION"))]
 pub struct Cli {
+    /// Output format for analyze, check, and list (junit: analyze and lint only)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    /// Kubeconfig file to use instead of KUBECONFIG or ~/.kube/config
+    #[arg(long, global = true)]
+    pub kubeconfig: Option<PathBuf>,
+
+    /// Kubeconfig context to use instead of the current context
+    #[arg(long, global = true)]
+    pub context: Option<String>,
+
+    /// Namespace for namespaced commands (analyze, export, effective, audit, enforce, ...)
+    /// and the namespace watch is limited to
+    #[arg(long, short = 'n', global = true, env = "KUBE_DEVOPS_NAMESPACE")]
+    pub namespace: Option<String>,
+
+    /// Seconds to wait for each Kubernetes API response before retrying [default: 30]
+    #[arg(long, global = true, env = "KUBE_DEVOPS_REQUEST_TIMEOUT", value_parser = clap::value_parser!(u64).range(1..))]
+    pub request_timeout: Option<u64>,
+
+    /// Retries of API requests failing with 429, 5xx, or a timeout [default: 3]
+    #[arg(long, global = true, env = "KUBE_DEVOPS_MAX_RETRIES")]
+    pub max_retries: Option<u32>,
+
+    /// Never write to the cluster: reconcile skips status, audit, and
+    /// enforcement writes; the webhook allows every request with a warning
+    #[arg(long, global = true)]
+    pub read_only: bool,
+
+    /// Timezone for human-readable timestamps: utc, local, or an offset like +02:00
+    #[arg(long, global = true, default_value = "utc")]
+    pub timezone: Timezone,
+
+    /// YAML settings file for watch, reconcile, and webhook serve; flags take precedence
+    #[arg(long, global = true, env = "KUBE_DEVOPS_CONFIG")]
+    pub config: Option<PathBuf>,
+
+    /// Export trace spans to this OTLP/HTTP collector, e.g. http://otel-collector:4318
+    #[arg(long, global = true, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    pub otlp_endpoint: Option<String>,
+
+    /// Log line format: json for log collectors, pretty or compact for terminals [default: json]
+    #[arg(long, global = true, value_enum, env = "KUBE_DEVOPS_LOG_FORMAT")]
+    pub log_format: Option<LogFormat>,
+
+    /// Append logs to this file instead of writing them to stdout
+    #[arg(long, global = true, env = "KUBE_DEVOPS_LOG_FILE")]
+    pub log_file: Option<PathBuf>,
+
+    /// Per-target filter override such as kube=warn, applied on top of RUST_LOG; repeatable
+    #[arg(long, global = true, value_parser = parse_log_filter)]
+    pub log_filter: Vec<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -12,22 +76,116 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Display application version
-    Version,
+    Version {
+        /// Query the release channel for newer versions
+        #[arg(long)]
+        check_update: bool,
+        /// Release endpoint (GitHub releases API format)
+        #[arg(long, default_value = crate::commands::version::DEFAULT_RELEASE_URL)]
+        release_url: String,
+        /// Do not contact the network (air-gapped clusters)
+        #[arg(long)]
+        offline: bool,
+        /// Read the release list from a saved JSON file instead of the endpoint
+        #[arg(long)]
+        release_file: Option<String>,
+    },
 
     /// Check cluster connectivity and permissions
-    Check,
+    Check {
+        /// Also verify CRDs, RBAC, webhook configuration, leader lease, and
+        /// metrics endpoints; exits non-zero when any check fails
+        #[arg(long, short = 'v')]
+        verbose: bool,
+        /// Namespace the operator is deployed to
+        #[arg(long, default_value = crate::commands::deploy::DEFAULT_NAMESPACE)]
+        operator_namespace: String,
+    },
 
-    /// List Kubernetes resources (e.g. pods)
+    /// Inspect the deployed operator and suggest fixes for what is wrong
+    Doctor {
+        /// Namespace the operator is deployed to
+        #[arg(long, default_value = crate::commands::deploy::DEFAULT_NAMESPACE)]
+        operator_namespace: String,
+    },
+
+    /// List Kubernetes resources across all namespaces (or --namespace)
     List {
-        /// Resource type to list (pods)
-        resource: String,
+        /// Resource kind to list
+        #[arg(value_enum)]
+        resource: crate::commands::list::ResourceKind,
+        /// Label selector, e.g. app=web,tier!=cache
+        #[arg(long, short = 'l')]
+        selector: Option<String>,
+        /// Field selector, e.g. status.phase=Running
+        #[arg(long)]
+        field_selector: Option<String>,
+        /// Show additional columns in the table
+        #[arg(long)]
+        wide: bool,
     },
 
     /// Run governance analysis on cluster workloads
-    Analyze,
+    Analyze {
+        /// Exit non-zero if any violation is at or above this severity (critical, high, medium, low)
+        #[arg(long)]
+        fail_on: Option<Severity>,
+        /// Exit non-zero if the health score is below this value
+        #[arg(long)]
+        min_score: Option<u32>,
+        /// Show each policy's score history from stored PolicyAuditResults instead
+        #[arg(long, conflicts_with_all = ["fail_on", "min_score"])]
+        trend: bool,
+        /// Only list and count violations at or above this severity
+        #[arg(long)]
+        severity: Option<Severity>,
+        /// Comma-separated violation types to list and count, e.g. latest_tag,crash_loop
+        #[arg(long, value_delimiter = ',', value_parser = clap::builder::PossibleValuesParser::new(kube_devops::governance::VIOLATION_TYPES))]
+        violation_type: Vec<String>,
+        /// Only show the first N namespaces in sort order
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+        top: Option<u32>,
+        /// Namespace order
+        #[arg(long, value_enum, default_value_t)]
+        sort: crate::commands::analyze::SortKey,
+        /// Evaluate against the DevOpsPolicy in this file, as the operator would
+        #[arg(long, conflicts_with_all = ["bundle", "trend"])]
+        policy_file: Option<String>,
+        /// Evaluate against a built-in bundle (baseline, restricted, permissive)
+        #[arg(long, conflicts_with = "trend")]
+        bundle: Option<String>,
+        /// Also write a self-contained HTML report to this file
+        #[arg(long, conflicts_with = "trend")]
+        report: Option<PathBuf>,
+    },
+
+    /// Lint local manifests against a DevOpsPolicy without a cluster
+    Lint {
+        /// Manifest file or directory (searched recursively for .yaml/.yml)
+        path: String,
+        /// DevOpsPolicy YAML file (defaults to the baseline bundle)
+        #[arg(long)]
+        policy: Option<String>,
+    },
 
     /// Start real-time governance watch controller
-    Watch,
+    Watch {
+        /// Watch every namespace, overriding watch.namespace in the config file
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+        /// Comma-separated namespaces to score; replaces watch.namespaces.include
+        #[arg(long, value_delimiter = ',')]
+        include_namespaces: Vec<String>,
+        /// Comma-separated namespaces to skip; replaces watch.namespaces.exclude
+        #[arg(long, value_delimiter = ',')]
+        exclude_namespaces: Vec<String>,
+        /// Pods whose violation details are kept for the JSON API [default: 10000]
+        #[arg(long, env = "KUBE_DEVOPS_POD_DETAIL_CAPACITY", value_parser = clap::value_parser!(u32).range(1..))]
+        pod_detail_capacity: Option<u32>,
+        /// Seconds between full re-lists of the pod watch, jittered by ±10% [default: 600]
+        #[arg(long, env = "KUBE_DEVOPS_WATCH_RESYNC_INTERVAL", value_parser = clap::value_parser!(u64).range(1..))]
+        resync_interval: Option<u64>,
+    },
 
     /// Manage the DevOpsPolicy CRD
     Crd {
@@ -36,7 +194,26 @@ pub enum Commands {
     },
 
     /// Start the DevOpsPolicy operator reconcile loop
-    Reconcile,
+    Reconcile {
+        /// Seconds between re-evaluations of each policy [default: 30]
+        #[arg(long, env = "KUBE_DEVOPS_REQUEUE_INTERVAL", value_parser = clap::value_parser!(u64).range(1..))]
+        requeue_interval: Option<u64>,
+        /// Listen address for /metrics, /healthz, and /readyz [default: 0.0.0.0:9090]
+        #[arg(long, env = "KUBE_DEVOPS_METRICS_ADDR")]
+        metrics_addr: Option<SocketAddr>,
+        /// PolicyAuditResults kept per policy unless it sets auditRetention.maxCount [default: 10]
+        #[arg(long, env = "KUBE_DEVOPS_AUDIT_RETENTION", value_parser = clap::value_parser!(u32).range(1..))]
+        audit_retention: Option<u32>,
+        /// Seconds between passes that delete audit results past retention [default: 300]
+        #[arg(long, env = "KUBE_DEVOPS_AUDIT_GC_INTERVAL", value_parser = clap::value_parser!(u64).range(1..))]
+        audit_gc_interval: Option<u64>,
+        /// Objects per page when listing pods, Jobs, and CronJobs [default: 500]
+        #[arg(long, env = "KUBE_DEVOPS_LIST_PAGE_SIZE", value_parser = clap::value_parser!(u32).range(1..))]
+        list_page_size: Option<u32>,
+        /// Seconds to wait for pod events to settle before re-evaluating a policy; 0 disables [default: 5]
+        #[arg(long, env = "KUBE_DEVOPS_POD_EVENT_DEBOUNCE")]
+        pod_event_debounce: Option<u64>,
+    },
 
     /// Manage the admission webhook
     Webhook {
@@ -51,9 +228,29 @@ pub enum Commands {
     },
 
     /// Generate Kubernetes deployment manifests (RBAC, Deployments, PDBs)
+    ///
+    /// The manifests target `--namespace` (default: kube-devops).
     Deploy {
         #[command(subcommand)]
         action: DeployAction,
+        /// Container image of the three Deployments
+        #[arg(long, global = true, default_value = crate::commands::deploy::DEFAULT_IMAGE)]
+        image: String,
+        /// Replicas of each Deployment; one replica gets a PDB that allows eviction
+        #[arg(long, global = true, default_value_t = 2, value_parser = clap::value_parser!(u32).range(1..))]
+        replicas: u32,
+        /// CPU request of each container
+        #[arg(long, global = true, default_value = "100m", value_parser = crate::commands::deploy::parse_quantity)]
+        cpu_request: String,
+        /// Memory request of each container
+        #[arg(long, global = true, default_value = "64Mi", value_parser = crate::commands::deploy::parse_quantity)]
+        memory_request: String,
+        /// CPU limit of each container
+        #[arg(long, global = true, default_value = "250m", value_parser = crate::commands::deploy::parse_quantity)]
+        cpu_limit: String,
+        /// Memory limit of each container
+        #[arg(long, global = true, default_value = "128Mi", value_parser = crate::commands::deploy::parse_quantity)]
+        memory_limit: String,
     },
 
     /// Manage policies and policy bundles
@@ -67,49 +264,133 @@ pub enum Commands {
         #[command(subcommand)]
         action: MultiClusterAction,
     },
+
+    /// Score namespaces against their policies, or run scoring tools
+    #[command(args_conflicts_with_subcommands = true)]
+    Score {
+        #[command(subcommand)]
+        action: Option<ScoreAction>,
+        /// Score every non-system namespace, worst first
+        #[arg(long, short = 'A', conflicts_with = "namespace")]
+        all_namespaces: bool,
+        /// Only list namespaces scoring below this threshold
+        #[arg(long)]
+        below: Option<u32>,
+    },
+
+    /// Manage operator remediations on workloads
+    Enforce {
+        #[command(subcommand)]
+        action: EnforceAction,
+    },
+
+    /// Developer tooling for benchmarking against a test cluster
+    Devtools {
+        #[command(subcommand)]
+        action: DevtoolsAction,
+    },
+
+    /// Query stored PolicyAuditResults
+    Audit {
+        #[command(subcommand)]
+        action: AuditAction,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum WebhookAction {
     /// Start the admission webhook HTTPS server
     Serve {
-        #[arg(long, default_value = "0.0.0.0:8443")]
-        addr: String,
-        #[arg(long, default_value = "tls.crt")]
-        tls_cert: String,
-        #[arg(long, default_value = "tls.key")]
-        tls_key: String,
+        /// Listen address [default: 0.0.0.0:8443]
+        #[arg(long)]
+        addr: Option<SocketAddr>,
+        /// [default: tls.crt]
+        #[arg(long)]
+        tls_cert: Option<String>,
+        /// [default: tls.key]
+        #[arg(long)]
+        tls_key: Option<String>,
+        /// Allow (open) or deny (closed) requests the webhook cannot evaluate [default: open]
+        #[arg(long, value_enum)]
+        failure_mode: Option<FailureMode>,
+        /// timeoutSeconds of the webhook registration; evaluation stops at 80% of it [default: 10]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=30))]
+        timeout_seconds: Option<u32>,
+        /// Deny only violations at or above this severity (critical, high, medium, low);
+        /// lower ones are admitted with a warning. A policy's minDenySeverity takes precedence
+        #[arg(long)]
+        min_deny_severity: Option<Severity>,
     },
     /// Generate self-signed TLS certificates for development
     CertGenerate {
         #[arg(long, default_value = "kube-devops-webhook")]
         service_name: String,
-        #[arg(long, default_value = "default")]
-        namespace: String,
         #[arg(long, default_value = ".")]
         output_dir: String,
         /// Additional IP SANs (e.g. --ip-san 192.168.1.26)
         #[arg(long = "ip-san")]
         ip_sans: Vec<String>,
     },
-    /// Print the ValidatingWebhookConfiguration YAML
+    /// Print the ValidatingWebhookConfiguration YAML, or apply it with --apply
     InstallConfig {
         #[arg(long, default_value = "kube-devops-webhook")]
         service_name: String,
-        #[arg(long, default_value = "default")]
-        namespace: String,
         #[arg(long)]
         ca_bundle_path: String,
+        /// Sets failurePolicy: open → Ignore, closed → Fail
+        #[arg(long, value_enum, default_value = "open")]
+        failure_mode: FailureMode,
+        /// API server timeout for each admission call (1-30)
+        #[arg(long, default_value_t = DEFAULT_TIMEOUT_SECONDS, value_parser = clap::value_parser!(u32).range(1..=30))]
+        timeout_seconds: u32,
+        /// Create or update the configuration in the cluster instead of printing it
+        #[arg(long)]
+        apply: bool,
+        /// With --apply, validate the write server-side without persisting it
+        #[arg(long, requires = "apply")]
+        dry_run: bool,
     },
 }
 
 #[derive(Subcommand)]
 pub enum CrdAction {
     /// Print the CRD YAML to stdout
-    Generate,
+    Generate {
+        /// Also serve DevOpsPolicy v1alpha1, converted by the webhook verified with this CA
+        #[arg(long)]
+        conversion_ca_bundle_path: Option<PathBuf>,
+        /// Service of the conversion webhook, in --namespace
+        #[arg(
+            long,
+            default_value = "kube-devops-webhook",
+            requires = "conversion_ca_bundle_path"
+        )]
+        conversion_service_name: String,
+    },
+
+    /// Install or upgrade the CRDs in the connected cluster
+    Install {
+        /// Also serve DevOpsPolicy v1alpha1, converted by the webhook verified with this CA
+        #[arg(long)]
+        conversion_ca_bundle_path: Option<PathBuf>,
+        /// Service of the conversion webhook, in --namespace
+        #[arg(
+            long,
+            default_value = "kube-devops-webhook",
+            requires = "conversion_ca_bundle_path"
+        )]
+        conversion_service_name: String,
+    },
+
+    /// Rewrite stored objects at the storage version and trim storedVersions
+    MigrateStorage,
 
-    /// Install the CRD into the connected cluster
-    Install,
+    /// Delete the CRDs and every object they define
+    Uninstall {
+        /// First remove the cleanup finalizer from policies and delete all audit results
+        #[arg(long)]
+        purge: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -122,7 +403,27 @@ pub enum ObservabilityAction {
     GenerateServiceMonitors,
 
     /// Print only the Grafana dashboard ConfigMap
-    GenerateDashboard,
+    GenerateDashboard {
+        /// Lowest Healthy score, for health panel colors [default: 80]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=100))]
+        healthy_threshold: Option<u32>,
+        /// Lowest Stable score, for health panel colors [default: 60]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=100))]
+        stable_threshold: Option<u32>,
+        /// Lowest Degraded score, for health panel colors [default: 40]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=100))]
+        degraded_threshold: Option<u32>,
+    },
+
+    /// Print a PrometheusRule with alerts on the operator's metrics
+    GenerateAlerts {
+        /// Health score below which policies and the cluster alert [default: 80]
+        #[arg(long, value_parser = clap::value_parser!(u32).range(1..=100))]
+        score_threshold: Option<u32>,
+        /// Webhook denials per second that count as a spike [default: 0.5]
+        #[arg(long)]
+        denial_rate: Option<f64>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -136,38 +437,77 @@ pub enum DeployAction {
 
     /// Print Deployment manifests only (watch + reconcile + webhook)
     GenerateDeployments,
+
+    /// Compare generated manifests against what is deployed in the cluster
+    Diff,
+
+    /// Server-side apply the generated manifests to the current cluster
+    Apply {
+        /// Operator component to install; the Namespace and RBAC are always included
+        #[arg(long, value_enum, default_value = "all")]
+        component: crate::commands::deploy::Component,
+        /// Delete resources previously applied by this command that are no longer generated
+        #[arg(long)]
+        prune: bool,
+    },
+
+    /// Write a kustomize base plus dev/staging/prod overlays
+    GenerateKustomize {
+        /// Directory to write base/ and overlays/ into
+        #[arg(long, default_value = "kustomize")]
+        output_dir: PathBuf,
+    },
 }
 
 #[derive(Subcommand)]
 pub enum PolicyAction {
     /// List available policy bundles
-    BundleList,
+    BundleList {
+        /// Also load bundles from the *.yaml files in this directory
+        #[arg(long)]
+        bundle_dir: Option<PathBuf>,
+        /// Also load bundles from this ConfigMap (`name` in --namespace, or `namespace/name`)
+        #[arg(long)]
+        bundle_configmap: Option<String>,
+    },
 
     /// Show details of a policy bundle
     BundleShow {
-        /// Bundle name (baseline, restricted, permissive)
+        /// Bundle name (baseline, restricted, permissive, or a custom bundle)
         name: String,
+        /// Also load bundles from the *.yaml files in this directory
+        #[arg(long)]
+        bundle_dir: Option<PathBuf>,
+        /// Also load bundles from this ConfigMap (`name` in --namespace, or `namespace/name`)
+        #[arg(long)]
+        bundle_configmap: Option<String>,
     },
 
     /// Generate a DevOpsPolicy YAML from a bundle template
     BundleApply {
         /// Bundle name
         name: String,
-        /// Target namespace
-        #[arg(long, default_value = "default")]
-        namespace: String,
         /// Policy resource name
         #[arg(long, default_value = "devops-policy")]
         policy_name: String,
+        /// Also load bundles from the *.yaml files in this directory
+        #[arg(long)]
+        bundle_dir: Option<PathBuf>,
+        /// Also load bundles from this ConfigMap (`name` in --namespace, or `namespace/name`)
+        #[arg(long)]
+        bundle_configmap: Option<String>,
     },
 
-    /// Export DevOpsPolicies from a namespace as YAML
-    Export {
-        /// Namespace to export from
-        #[arg(long, default_value = "default")]
-        namespace: String,
+    /// Generate a DevOpsPolicy YAML whose checks and thresholds the pods in --namespace already meet
+    BundleCreate {
+        /// Policy resource name
+        #[arg(long, default_value = "cluster-baseline")]
+        policy_name: String,
     },
 
+    /// Export DevOpsPolicies from a namespace (`--namespace all` for every namespace) as YAML
+    Export,
+
     /// Import DevOpsPolicies from a YAML file
     Import {
         /// Path to YAML file
@@ -175,13 +515,55 @@ pub enum PolicyAction {
         /// Preview changes without applying
         #[arg(long)]
         dry_run: bool,
+        /// Delete DevOpsPolicies in the file's namespaces that the file does not list
+        #[arg(long)]
+        prune: bool,
     },
 
-    /// Diff local YAML policies against cluster state
+    /// Show what importing a YAML file changes, field by field
     Diff {
         /// Path to YAML file
         file: String,
     },
+
+    /// Show the merged policy in effect for a namespace
+    Effective,
+
+    /// Check policy manifests against the CRD schema and cross-field rules without a cluster
+    Validate {
+        /// YAML file with DevOpsPolicies or ClusterDevOpsPolicies
+        #[arg(short = 'f', long)]
+        file: String,
+    },
+
+    /// Run a new policy in shadow next to the current one and compare the results
+    Canary {
+        /// YAML file with the candidate DevOpsPolicy
+        #[arg(short = 'f', long)]
+        file: String,
+        /// How long to sample, e.g. 10m
+        #[arg(long, default_value = "10m")]
+        duration: String,
+        /// Time between samples, e.g. 30s
+        #[arg(long, default_value = "30s")]
+        interval: String,
+    },
+
+    /// Keep comparing live DevOpsPolicies with a Git checkout and report drift
+    DriftWatch {
+        /// YAML file or directory of YAML files (the Git checkout)
+        #[arg(short = 'f', long)]
+        file: PathBuf,
+        /// Time between checks, e.g. 5m
+        #[arg(long, default_value = "5m")]
+        interval: String,
+        /// Listen address for /metrics and /healthz
+        #[arg(long, default_value = "0.0.0.0:9091")]
+        addr: SocketAddr,
+        /// Re-apply drifted policies from the files (force-applies over other managers)
+        #[arg(long)]
+        revert: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -200,5 +582,179 @@ pub enum MultiClusterAction {
         /// Show per-cluster breakdown
         #[arg(long)]
         per_cluster: bool,
+        /// One line per cluster (`<context> <score> <classification>`)
+        #[arg(long)]
+        compact: bool,
+        /// Exit non-zero when the aggregate score is below this value
+        #[arg(long)]
+        fail_below_score: Option<u32>,
+        /// Exit non-zero when any cluster scores below this value
+        #[arg(long)]
+        fail_below_cluster_score: Option<u32>,
+        /// Compare clusters side by side and flag regressions since the last stored run
+        #[arg(long, conflicts_with_all = ["per_cluster", "compact"])]
+        compare: bool,
+        /// Where --compare reads the previous run and stores this one
+        #[arg(
+            long,
+            default_value = "kube-devops-last-run.json",
+            requires = "compare"
+        )]
+        last_run_file: PathBuf,
+    },
+
+    /// Apply the DevOpsPolicies in a file to several clusters
+    Apply {
+        /// Path to a YAML file of DevOpsPolicies
+        #[arg(long, short = 'f')]
+        file: String,
+        /// Comma-separated list of contexts (default: all)
+        #[arg(long, value_delimiter = ',')]
+        contexts: Option<Vec<String>>,
+        /// Validate with server-side dry runs without changing any cluster
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Watch several clusters in one process and serve their scores together
+    Watch {
+        /// Comma-separated list of contexts (default: all)
+        #[arg(long, value_delimiter = ',')]
+        contexts: Option<Vec<String>>,
+        /// Listen address for /metrics and the JSON API [default: watch.addr, 0.0.0.0:8080]
+        #[arg(long)]
+        addr: Option<SocketAddr>,
     },
 }
+
+#[derive(Subcommand)]
+pub enum ScoreAction {
+    /// Recompute audit results under proposed weights / severities
+    Simulate {
+        /// Proposed weights, e.g. latest_tag=3,missing_liveness=2
+        #[arg(long)]
+        weights: Option<String>,
+        /// Proposed severities (applies severity multipliers), e.g. latest_tag=critical
+        #[arg(long)]
+        severity: Option<String>,
+        /// Maximum points one check may deduct, e.g. missing_readiness=20
+        #[arg(long)]
+        caps: Option<String>,
+        /// PolicyAuditResult name or local YAML file (default: all results in namespace)
+        #[arg(long)]
+        from_audit: Option<String>,
+        /// Only simulate results produced by this policy
+        #[arg(long)]
+        policy: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EnforceAction {
+    /// Revert probes/resources injected by enforce mode on a workload
+    Rollback {
+        /// Workload as <kind>/<name> (deployment, statefulset, daemonset); a bare name is a Deployment
+        workload: String,
+        /// Show what would be restored without patching
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Scale a quarantined workload back to its recorded replicas
+    Restore {
+        /// Workload as <kind>/<name> (deployment, statefulset); a bare name is a Deployment
+        workload: String,
+        /// Show the quarantine record without restoring
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AuditAction {
+    /// Search violations in stored audit results
+    Search {
+        /// Violation type, e.g. latest_tag
+        #[arg(long = "type")]
+        violation_type: Option<String>,
+        /// Minimum severity (critical, high, medium, low)
+        #[arg(long)]
+        severity: Option<Severity>,
+        /// Only violations on pods whose name starts with this prefix
+        #[arg(long)]
+        pod_prefix: Option<String>,
+        /// Only results produced by this policy
+        #[arg(long)]
+        policy: Option<String>,
+        /// Only the most recent result of each policy
+        #[arg(long)]
+        latest: bool,
+    },
+    /// List stored audit results with their scores and violation counts
+    List {
+        /// Only results produced by this policy
+        #[arg(long)]
+        policy: Option<String>,
+        /// Only results with a violation at or above this severity
+        #[arg(long)]
+        severity: Option<Severity>,
+        /// Only results since this RFC 3339 time or duration ago (e.g. 24h)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only results until this RFC 3339 time or duration ago
+        #[arg(long)]
+        until: Option<String>,
+    },
+    /// Show one audit result with its violations
+    Show {
+        /// PolicyAuditResult name
+        name: String,
+        /// Only violations at or above this severity
+        #[arg(long)]
+        severity: Option<Severity>,
+    },
+    /// Show violations added, resolved, or re-graded between two audit results
+    Diff {
+        /// Older PolicyAuditResult name
+        from: String,
+        /// Newer PolicyAuditResult name
+        to: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum DevtoolsAction {
+    /// Create synthetic pods with a controlled violation mix
+    GenerateLoad {
+        /// Number of pods to create
+        #[arg(long, default_value_t = 100)]
+        pods: u32,
+        /// Fraction of pods carrying a violation (0.0–1.0)
+        #[arg(long, default_value_t = 0.3)]
+        violation_rate: f64,
+        /// Relative violation weights, e.g. latest_tag=2,missing_liveness=1
+        #[arg(long)]
+        mix: Option<String>,
+        /// Schedule onto kwok fake nodes instead of running pause containers
+        #[arg(long)]
+        kwok: bool,
+        /// Maximum concurrent create requests
+        #[arg(long, default_value_t = 20)]
+        concurrency: usize,
+        /// Seed for violation placement
+        #[arg(long, default_value_t = 42)]
+        seed: u64,
+        /// Print the pod manifests instead of creating them
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Delete pods created by generate-load
+    CleanLoad,
+}
+
+/// Reject an invalid `--log-filter` directive when arguments are parsed.
+fn parse_log_filter(value: &str) -> Result<String, String> {
+    value
+        .parse::<Directive>()
+        .map(|_| value.to_string())
+        .map_err(|e| e.to_string())
+}
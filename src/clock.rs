@@ -0,0 +1,336 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, FixedOffset, Local, Utc};
+
+/* ============================= CLOCK ============================= */
+
+/// Source of the current time.
+///
+/// Injected into the reconciler so timestamps in status and audit results
+/// can be pinned in tests instead of depending on `Utc::now()`.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall-clock time (production default).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// A clock that always returns the same instant until explicitly advanced.
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Move the clock forward by `delta`.
+    pub fn advance(&self, delta: chrono::Duration) {
+        let mut now = self.now.lock().expect("clock mutex poisoned");
+        *now += delta;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().expect("clock mutex poisoned")
+    }
+}
+
+/* ============================= TIMEZONES ============================= */
+
+/// Timezone for human-readable timestamps (console output, reports).
+///
+/// Stored timestamps (status, audit results) stay RFC 3339 in UTC so they
+/// sort lexicographically; this only affects rendering. IANA zone names are
+/// not supported: use `local` (the host's zone) or a fixed offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timezone {
+    #[default]
+    Utc,
+    /// The host's local timezone (`TZ` / `/etc/localtime`).
+    Local,
+    /// A fixed UTC offset such as `+02:00`.
+    Fixed(FixedOffset),
+}
+
+impl Timezone {
+    /// Render `time` in this timezone with a chrono `strftime` format.
+    pub fn format(&self, time: &DateTime<Utc>, fmt: &str) -> String {
+        match self {
+            Timezone::Utc => time.format(fmt).to_string(),
+            Timezone::Local => time.with_timezone(&Local).format(fmt).to_string(),
+            Timezone::Fixed(offset) => time.with_timezone(offset).format(fmt).to_string(),
+        }
+    }
+
+    /// Render an RFC 3339 timestamp in this timezone; unparseable input is
+    /// returned unchanged.
+    pub fn format_rfc3339(&self, timestamp: &str, fmt: &str) -> String {
+        match DateTime::parse_from_rfc3339(timestamp) {
+            Ok(time) => self.format(&time.with_timezone(&Utc), fmt),
+            Err(_) => timestamp.to_string(),
+        }
+    }
+}
+
+impl std::str::FromStr for Timezone {
+    type Err = String;
+
+    /// Parse `utc`, `local`, or an offset (`+02:00`, `-0530`, `+5`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid =
+            || format!("unknown timezone '{s}' (expected utc, local, or an offset like +02:00)");
+        match s.trim().to_ascii_lowercase().as_str() {
+            "utc" | "z" | "" => return Ok(Timezone::Utc),
+            "local" => return Ok(Timezone::Local),
+            _ => {}
+        }
+        let s = s.trim();
+        let (sign, rest) = match s.chars().next() {
+            Some('+') => (1, &s[1..]),
+            Some('-') => (-1, &s[1..]),
+            _ => return Err(invalid()),
+        };
+        let (hours, minutes) = match rest.split_once(':') {
+            Some((h, m)) => (h, m),
+            None if rest.len() == 4 => rest.split_at(2),
+            None => (rest, "0"),
+        };
+        let hours: i32 = hours.parse().map_err(|_| invalid())?;
+        let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+        if hours > 14 || minutes > 59 {
+            return Err(invalid());
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Timezone::Fixed)
+            .ok_or_else(invalid)
+    }
+}
+
+impl std::fmt::Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Timezone::Utc => write!(f, "UTC"),
+            Timezone::Local => write!(f, "local"),
+            Timezone::Fixed(offset) => write!(f, "{offset}"),
+        }
+    }
+}
+
+/* ============================= ID GENERATION ============================= */
+
+/// Source of unique suffixes for generated object names.
+pub trait IdGenerator: Send + Sync {
+    /// Return a short, DNS-safe (lowercase hex) suffix.
+    fn next_id(&self) -> String;
+}
+
+/// Hash-based generator that is unique per process and per call.
+///
+/// Mixes an instance identity (e.g. the pod hostname), a monotonically
+/// increasing counter, and the current time so two replicas writing in the
+/// same millisecond never produce the same name.
+#[derive(Debug)]
+pub struct HashIdGenerator {
+    instance: String,
+    counter: AtomicU64,
+}
+
+impl HashIdGenerator {
+    pub fn new(instance: impl Into<String>) -> Self {
+        Self {
+            instance: instance.into(),
+            counter: AtomicU64::new(0),
+        }
+    }
+
+    /// Build a generator keyed on this process's identity.
+    pub fn from_env() -> Self {
+        Self::new(instance_identity())
+    }
+}
+
+impl IdGenerator for HashIdGenerator {
+    fn next_id(&self) -> String {
+        let count = self.counter.fetch_add(1, Ordering::Relaxed);
+        let nanos = Utc::now().timestamp_nanos_opt().unwrap_or_default();
+        short_hash(&(self.instance.as_str(), count, nanos, std::process::id()))
+    }
+}
+
+/// Deterministic generator for tests: yields `prefix` followed by 0, 1, 2, ...
+#[derive(Debug, Default)]
+pub struct SequentialIdGenerator {
+    prefix: String,
+    counter: AtomicU64,
+}
+
+impl SequentialIdGenerator {
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            counter: AtomicU64::new(0),
+        }
+    }
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn next_id(&self) -> String {
+        let count = self.counter.fetch_add(1, Ordering::Relaxed);
+        format!("{}{}", self.prefix, count)
+    }
+}
+
+/* ============================= HELPERS ============================= */
+
+/// Identity of this process: `$HOSTNAME` (the pod name in-cluster) or `"local"`.
+pub fn instance_identity() -> String {
+    std::env::var("HOSTNAME")
+        .ok()
+        .filter(|h| !h.is_empty())
+        .unwrap_or_else(|| "local".to_string())
+}
+
+/// Hash any value into a 10-character lowercase hex string.
+pub fn short_hash<T: Hash + ?Sized>(value: &T) -> String {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())[..10].to_string()
+}
+
+/// Maximum length of a Kubernetes object name (DNS subdomain).
+const MAX_NAME_LEN: usize = 253;
+
+/// Build a collision-safe object name: `<base>-<id>`.
+///
+/// The base is truncated so the result never exceeds the Kubernetes
+/// name length limit, and trailing `-`/`.` are trimmed from the base.
+pub fn unique_name(base: &str, id: &str) -> String {
+    let budget = MAX_NAME_LEN.saturating_sub(id.len() + 1);
+    let truncated: String = base.chars().take(budget).collect();
+    let trimmed = truncated.trim_end_matches(['-', '.']);
+    format!("{trimmed}-{id}")
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock_returns_same_instant() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let clock = FixedClock::new(t);
+        assert_eq!(clock.now(), t);
+        assert_eq!(clock.now(), t);
+    }
+
+    #[test]
+    fn test_fixed_clock_advance() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap();
+        let clock = FixedClock::new(t);
+        clock.advance(chrono::Duration::seconds(30));
+        assert_eq!(clock.now(), t + chrono::Duration::seconds(30));
+    }
+
+    #[test]
+    fn test_timezone_parse() {
+        assert_eq!("UTC".parse::<Timezone>(), Ok(Timezone::Utc));
+        assert_eq!("local".parse::<Timezone>(), Ok(Timezone::Local));
+        let plus_two = Timezone::Fixed(FixedOffset::east_opt(2 * 3600).unwrap());
+        assert_eq!("+02:00".parse::<Timezone>(), Ok(plus_two));
+        assert_eq!("+0200".parse::<Timezone>(), Ok(plus_two));
+        assert_eq!("+2".parse::<Timezone>(), Ok(plus_two));
+        assert_eq!(
+            "-05:30".parse::<Timezone>(),
+            Ok(Timezone::Fixed(
+                FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()
+            ))
+        );
+        assert!("Europe/Berlin".parse::<Timezone>().is_err());
+        assert!("+15:00".parse::<Timezone>().is_err());
+        assert!("+02:75".parse::<Timezone>().is_err());
+    }
+
+    #[test]
+    fn test_timezone_format() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 23, 30, 0).unwrap();
+        assert_eq!(Timezone::Utc.format(&t, "%H:%M"), "23:30");
+        let tz: Timezone = "+02:00".parse().unwrap();
+        assert_eq!(
+            tz.format(&t, "%Y-%m-%d %H:%M %:z"),
+            "2026-03-02 01:30 +02:00"
+        );
+        assert_eq!(tz.to_string(), "+02:00");
+        assert_eq!(tz.format_rfc3339("2026-03-01T23:30:00Z", "%H:%M"), "01:30");
+        assert_eq!(tz.format_rfc3339("not a time", "%H:%M"), "not a time");
+    }
+
+    #[test]
+    fn test_sequential_ids() {
+        let ids = SequentialIdGenerator::new("id");
+        assert_eq!(ids.next_id(), "id0");
+        assert_eq!(ids.next_id(), "id1");
+    }
+
+    #[test]
+    fn test_hash_ids_unique_and_dns_safe() {
+        let ids = HashIdGenerator::new("pod-a");
+        let a = ids.next_id();
+        let b = ids.next_id();
+        assert_ne!(a, b);
+        assert_eq!(a.len(), 10);
+        assert!(
+            a.chars()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_uppercase())
+        );
+    }
+
+    #[test]
+    fn test_hash_ids_differ_between_instances() {
+        let a = HashIdGenerator::new("pod-a").next_id();
+        let b = HashIdGenerator::new("pod-b").next_id();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_short_hash_is_stable() {
+        assert_eq!(short_hash("web"), short_hash("web"));
+        assert_ne!(short_hash("web"), short_hash("api"));
+    }
+
+    #[test]
+    fn test_unique_name_format() {
+        assert_eq!(unique_name("my-policy", "abc123"), "my-policy-abc123");
+    }
+
+    #[test]
+    fn test_unique_name_truncates_long_base() {
+        let base = "a".repeat(300);
+        let name = unique_name(&base, "0123456789");
+        assert_eq!(name.len(), MAX_NAME_LEN);
+        assert!(name.ends_with("-0123456789"));
+    }
+
+    #[test]
+    fn test_unique_name_trims_trailing_separators() {
+        assert_eq!(unique_name("policy-", "x1"), "policy-x1");
+    }
+}
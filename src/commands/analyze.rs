@@ -1,57 +1,970 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
 use anyhow::Context;
+use chrono::Utc;
 use k8s_openapi::api::core::v1::Pod;
 use kube::api::ListParams;
-use kube::{Api, Client};
+use kube::{Api, ResourceExt};
+use serde::Serialize;
 
+use kube_devops::admission::severity_rank;
+use kube_devops::crd::{DevOpsPolicySpec, PolicyAuditResult, ScoreTrend, Severity};
 use kube_devops::governance::{
-    self, PodMetrics, add_metrics, calculate_health_score, classify_health,
+    self, PodMetrics, ScoringWeights, ViolationDetail, add_metrics, calculate_health_score,
+    calculate_health_score_with_weights, classify_health,
 };
+use kube_devops::junit::{self, TestSuite};
+use kube_devops::output::{self, OutputFormat};
+use kube_devops::{bundles, lint};
+
+use crate::commands::{client, html_report};
+
+/* ============================= REPORT ============================= */
+
+/// Structured result of a cluster analysis (used for `--output json|yaml`).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisReport {
+    /// Policy the pods were evaluated against; unset for the built-in checks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policy: Option<String>,
+    pub health_score: u32,
+    pub classification: String,
+    pub metrics: PodMetrics,
+    pub violations: Vec<ViolationDetail>,
+    /// Per-namespace breakdown, in `--sort` order.
+    pub namespaces: Vec<NamespaceSummary>,
+}
+
+/// Score and counts of one namespace's workload pods.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceSummary {
+    pub namespace: String,
+    pub health_score: u32,
+    pub classification: String,
+    pub pods: u32,
+    /// Violations matching `--severity` and `--violation-type`.
+    pub violations: usize,
+}
+
+/// Policy equivalent to the built-in checks used by `evaluate_pod`.
+pub(crate) fn analysis_policy() -> DevOpsPolicySpec {
+    DevOpsPolicySpec {
+        forbid_latest_tag: Some(true),
+        require_liveness_probe: Some(true),
+        require_readiness_probe: Some(true),
+        max_restart_count: Some(3),
+        forbid_pending_duration: Some(0),
+        ..Default::default()
+    }
+}
+
+/// Evaluate workload pods (system namespaces excluded) into a report.
+pub fn build_report(pods: &[Pod]) -> AnalysisReport {
+    let policy = analysis_policy();
+    let mut metrics = PodMetrics::default();
+    let mut by_namespace: BTreeMap<&str, PodMetrics> = BTreeMap::new();
+    let mut violations = Vec::new();
 
-pub async fn run() -> anyhow::Result<()> {
-    println!("Running DevOps analysis...\n");
+    for pod in pods {
+        let ns = pod.metadata.namespace.as_deref().unwrap_or("");
+
+        if governance::is_system_namespace(ns) {
+            continue;
+        }
+
+        let contribution = governance::evaluate_pod(pod);
+        add_metrics(&mut metrics, &contribution);
+        add_metrics(by_namespace.entry(ns).or_default(), &contribution);
+        violations.extend(governance::detect_violations_detailed(pod, &policy));
+    }
+
+    let namespaces = by_namespace
+        .into_iter()
+        .map(|(namespace, metrics)| {
+            let health_score = calculate_health_score(&metrics);
+            NamespaceSummary {
+                namespace: namespace.to_string(),
+                health_score,
+                classification: classify_health(health_score).to_string(),
+                pods: metrics.total_pods,
+                violations: violations
+                    .iter()
+                    .filter(|v| v.namespace == namespace)
+                    .count(),
+            }
+        })
+        .collect();
+
+    let health_score = calculate_health_score(&metrics);
+    AnalysisReport {
+        policy: None,
+        health_score,
+        classification: classify_health(health_score).to_string(),
+        metrics,
+        violations,
+        namespaces,
+    }
+}
 
-    let client = Client::try_default()
+/// A policy to analyze against instead of the built-in checks.
+#[derive(Debug, Clone)]
+pub struct AnalysisPolicy {
+    /// Where the spec came from, e.g. `bundle 'baseline'`.
+    pub source: String,
+    pub spec: DevOpsPolicySpec,
+}
+
+impl AnalysisPolicy {
+    /// Load `--policy-file` or `--bundle`; `None` when neither is given.
+    pub fn load(policy_file: Option<&str>, bundle: Option<&str>) -> anyhow::Result<Option<Self>> {
+        if let Some(file) = policy_file {
+            let content = std::fs::read_to_string(file)
+                .with_context(|| format!("Failed to read policy file '{file}'"))?;
+            let spec = lint::parse_policy(&content)
+                .with_context(|| format!("Invalid policy file '{file}'"))?;
+            return Ok(Some(AnalysisPolicy {
+                source: format!("file '{file}'"),
+                spec,
+            }));
+        }
+        let Some(name) = bundle else {
+            return Ok(None);
+        };
+        let bundle = bundles::find_bundle(&bundles::all_bundles(), name)?.clone();
+        Ok(Some(AnalysisPolicy {
+            source: format!("bundle '{}'", bundle.name),
+            spec: bundle.spec,
+        }))
+    }
+}
+
+/// Evaluate workload pods against a policy the way the operator does:
+/// replicas of a workload are evaluated together, policy checks and
+/// thresholds apply, scores use the policy's `scoringWeights`, and
+/// classifications its `healthThresholds`.
+///
+/// `metrics.totalPods` counts workloads, as in the operator's score.
+pub fn build_policy_report(pods: &[Pod], policy: &AnalysisPolicy) -> AnalysisReport {
+    let weights = ScoringWeights::for_policy(&policy.spec);
+    let thresholds = governance::ClassificationThresholds::for_policy(&policy.spec);
+    let mut metrics = PodMetrics::default();
+    let mut by_namespace: BTreeMap<String, (PodMetrics, u32)> = BTreeMap::new();
+    let mut violations = Vec::new();
+
+    for workload in governance::evaluate_workloads(pods, &policy.spec) {
+        add_metrics(&mut metrics, &workload.metrics);
+        let (ns_metrics, ns_pods) = by_namespace.entry(workload.namespace).or_default();
+        add_metrics(ns_metrics, &workload.metrics);
+        *ns_pods += workload.replicas;
+        violations.extend(workload.violations);
+    }
+
+    let namespaces = by_namespace
+        .into_iter()
+        .map(|(namespace, (metrics, pods))| {
+            let health_score = calculate_health_score_with_weights(&metrics, &weights);
+            NamespaceSummary {
+                health_score,
+                classification: thresholds.classify(health_score).to_string(),
+                pods,
+                violations: violations
+                    .iter()
+                    .filter(|v| v.namespace == namespace)
+                    .count(),
+                namespace,
+            }
+        })
+        .collect();
+
+    let health_score = calculate_health_score_with_weights(&metrics, &weights);
+    AnalysisReport {
+        policy: Some(policy.source.clone()),
+        health_score,
+        classification: thresholds.classify(health_score).to_string(),
+        metrics,
+        violations,
+        namespaces,
+    }
+}
+
+/* ============================= FILTERS ============================= */
+
+/// Namespace order for `analyze --sort`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum SortKey {
+    /// Lowest health score first.
+    #[default]
+    Score,
+    /// Most violations first.
+    Violations,
+    /// Most pods first.
+    Pods,
+}
+
+/// Narrows an analysis to what the user asked to see. Scores are not
+/// affected by the violation filters; they only choose which violations are
+/// listed and counted.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzeFilter {
+    /// Only analyze pods in this namespace.
+    pub namespace: Option<String>,
+    /// Only violations at or above this severity.
+    pub severity: Option<Severity>,
+    /// Only these violation types (empty matches all).
+    pub violation_types: Vec<String>,
+    /// Only the first N namespaces in sort order, and their violations.
+    pub top: Option<usize>,
+    pub sort: SortKey,
+}
+
+impl AnalyzeFilter {
+    /// Whether the filter selects violations (so the table lists them).
+    fn selects_violations(&self) -> bool {
+        self.severity.is_some() || !self.violation_types.is_empty()
+    }
+
+    fn matches(&self, violation: &ViolationDetail) -> bool {
+        self.severity
+            .as_ref()
+            .is_none_or(|min| severity_rank(&violation.severity) >= severity_rank(min))
+            && (self.violation_types.is_empty()
+                || self.violation_types.contains(&violation.violation_type))
+    }
+}
+
+/// Apply the violation filters, sort the namespaces, and keep the top N
+/// along with their violations.
+pub fn focus(report: &mut AnalysisReport, filter: &AnalyzeFilter) {
+    report.violations.retain(|v| filter.matches(v));
+    for ns in &mut report.namespaces {
+        ns.violations = report
+            .violations
+            .iter()
+            .filter(|v| v.namespace == ns.namespace)
+            .count();
+    }
+
+    // Stable sorts keep namespaces with equal keys in name order.
+    match filter.sort {
+        SortKey::Score => report.namespaces.sort_by_key(|ns| ns.health_score),
+        SortKey::Violations => report
+            .namespaces
+            .sort_by_key(|ns| std::cmp::Reverse(ns.violations)),
+        SortKey::Pods => report
+            .namespaces
+            .sort_by_key(|ns| std::cmp::Reverse(ns.pods)),
+    }
+
+    if let Some(top) = filter.top {
+        report.namespaces.truncate(top);
+        report.violations.retain(|v| {
+            report
+                .namespaces
+                .iter()
+                .any(|ns| ns.namespace == v.namespace)
+        });
+    }
+}
+
+/* ============================= TREND ============================= */
+
+/// Score history of one policy, from its stored PolicyAuditResults.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyTrend {
+    pub namespace: String,
+    pub policy_name: String,
+    /// Health scores, oldest first.
+    pub scores: Vec<u32>,
+    pub latest_score: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_score: Option<u32>,
+    /// Latest score against the average of the ones before it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trend: Option<ScoreTrend>,
+}
+
+/// One trend per policy and namespace, ordered by namespace and policy.
+pub fn build_trends(results: &[PolicyAuditResult]) -> Vec<PolicyTrend> {
+    let mut by_policy: BTreeMap<(String, &str), Vec<&PolicyAuditResult>> = BTreeMap::new();
+    for r in results {
+        by_policy
+            .entry((
+                r.namespace().unwrap_or_default(),
+                r.spec.policy_name.as_str(),
+            ))
+            .or_default()
+            .push(r);
+    }
+
+    by_policy
+        .into_iter()
+        .filter_map(|((namespace, policy), mut results)| {
+            // Timestamps are RFC 3339 in UTC, so they order lexicographically
+            results.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+            let scores: Vec<u32> = results.iter().map(|r| r.spec.health_score).collect();
+            let (&latest_score, history) = scores.split_last()?;
+            Some(PolicyTrend {
+                namespace,
+                policy_name: policy.to_string(),
+                latest_score,
+                previous_score: history.last().copied(),
+                trend: governance::score_trend(history, latest_score),
+                scores,
+            })
+        })
+        .collect()
+}
+
+/* ============================= CI GATE ============================= */
+
+/// Thresholds that make `analyze` exit non-zero (for CI / GitOps promotion).
+#[derive(Debug, Default, Clone)]
+pub struct Gate {
+    /// Fail when any violation is at or above this severity.
+    pub fail_on: Option<Severity>,
+    /// Fail when the health score is below this value.
+    pub min_score: Option<u32>,
+}
+
+/// Return a description of every gate condition the report breaches.
+pub fn evaluate_gate(report: &AnalysisReport, gate: &Gate) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    if let Some(threshold) = &gate.fail_on {
+        let rank = severity_rank(threshold);
+        let count = report
+            .violations
+            .iter()
+            .filter(|v| severity_rank(&v.severity) >= rank)
+            .count();
+        if count > 0 {
+            failures.push(format!(
+                "{count} violation(s) at or above {} severity",
+                format!("{threshold:?}").to_lowercase()
+            ));
+        }
+    }
+
+    if let Some(min_score) = gate.min_score
+        && report.health_score < min_score
+    {
+        failures.push(format!(
+            "health score {} is below minimum {min_score}",
+            report.health_score
+        ));
+    }
+
+    failures
+}
+
+/* ============================= COMMAND ============================= */
+
+pub async fn run(
+    format: OutputFormat,
+    gate: &Gate,
+    filter: &AnalyzeFilter,
+    policy: Option<&AnalysisPolicy>,
+    html_report: Option<&Path>,
+) -> anyhow::Result<()> {
+    if !format.is_structured() {
+        println!("Running DevOps analysis...\n");
+    }
+
+    let client = client::connect()
         .await
         .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
 
-    let pods: Api<Pod> = Api::all(client);
+    let pods: Api<Pod> = match &filter.namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    };
 
     let pod_list = pods
         .list(&ListParams::default())
         .await
         .context("Failed to list pods. Check RBAC permissions.")?;
 
-    let mut report = PodMetrics::default();
+    let mut report = match policy {
+        Some(policy) => build_policy_report(&pod_list.items, policy),
+        None => build_report(&pod_list.items),
+    };
+    focus(&mut report, filter);
 
-    for pod in pod_list {
-        let ns = pod.metadata.namespace.as_deref().unwrap_or("");
+    if let Some(path) = html_report {
+        std::fs::write(path, html_report::render_html(&report, Utc::now()))
+            .with_context(|| format!("Failed to write report '{}'", path.display()))?;
+        // Keep stdout a single document for structured output
+        eprintln!("Report written to {}", path.display());
+    }
 
-        if governance::is_system_namespace(ns) {
-            continue;
+    if format == OutputFormat::Junit {
+        let checks = governance::enabled_checks(policy.map_or(&analysis_policy(), |p| &p.spec));
+        print!(
+            "{}",
+            junit::render("kube-devops analyze", &junit_suites(&report, &checks))
+        );
+    } else if format.is_structured() {
+        output::print_structured(&report, format)?;
+    } else {
+        print_summary(&report);
+        print!("{}", format_namespaces(&report.namespaces));
+        if filter.selects_violations() {
+            print!("{}", format_violations(&report.violations));
         }
+    }
 
-        let contribution = governance::evaluate_pod(&pod);
-        add_metrics(&mut report, &contribution);
+    let failures = evaluate_gate(&report, gate);
+    if !failures.is_empty() {
+        anyhow::bail!("Gate failed: {}", failures.join("; "));
     }
 
-    print_summary(&report);
+    Ok(())
+}
+
+/// Print each policy's score history from stored PolicyAuditResults.
+pub async fn run_trend(format: OutputFormat) -> anyhow::Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    let api: Api<PolicyAuditResult> = Api::all(client);
+    let results = api
+        .list(&ListParams::default())
+        .await
+        .context("Failed to list PolicyAuditResults")?
+        .items;
 
+    let trends = build_trends(&results);
+    if format.is_structured() {
+        return output::print_structured(&trends, format);
+    }
+    print!("{}", format_trends(&trends, results.len()));
     Ok(())
 }
 
-fn print_summary(report: &PodMetrics) {
-    let score = calculate_health_score(report);
-    let status = classify_health(score);
+fn print_summary(analysis: &AnalysisReport) {
+    let report = &analysis.metrics;
 
     println!("===== DevOps Governance Summary =====");
-    println!("Workload Pods Analyzed     : {}", report.total_pods);
+    match &analysis.policy {
+        Some(policy) => {
+            println!("Policy                     : {policy}");
+            println!("Workloads Analyzed         : {}", report.total_pods);
+        }
+        None => println!("Workload Pods Analyzed     : {}", report.total_pods),
+    }
     println!("Images using :latest       : {}", report.latest_tag);
+    println!("Images without a digest    : {}", report.unpinned_image);
+    println!("Disallowed pull policies   : {}", report.pull_policy);
     println!("Missing liveness probes    : {}", report.missing_liveness);
     println!("Missing readiness probes   : {}", report.missing_readiness);
     println!("Restart severity score     : {}", report.high_restarts);
     println!("Pending pods               : {}", report.pending);
+    println!("Crash-looping containers   : {}", report.crash_loop);
+    println!("Image pull failures        : {}", report.image_pull_error);
+    println!("Missing required metadata  : {}", report.missing_metadata);
     println!("--------------------------------------");
-    println!("Cluster Health Score       : {}/100", score);
-    println!("Cluster Status             : {}", status);
+    println!("Cluster Health Score       : {}/100", analysis.health_score);
+    println!("Cluster Status             : {}", analysis.classification);
     println!("======================================\n");
 }
+
+/// One JUnit suite per namespace, with a test case per check.
+fn junit_suites(report: &AnalysisReport, checks: &[&str]) -> Vec<TestSuite> {
+    let checks = junit::checks_with_found(checks, &report.violations);
+    report
+        .namespaces
+        .iter()
+        .map(|ns| {
+            let violations = report
+                .violations
+                .iter()
+                .filter(|v| v.namespace == ns.namespace);
+            TestSuite {
+                name: ns.namespace.clone(),
+                cases: checks
+                    .iter()
+                    .map(|check| junit::check_case(&ns.namespace, check, violations.clone()))
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+/// Render the per-namespace breakdown as a table.
+fn format_namespaces(namespaces: &[NamespaceSummary]) -> String {
+    if namespaces.is_empty() {
+        return String::new();
+    }
+    let mut out = format!(
+        "{:<24} {:>6} {:<10} {:>6} {:>10}\n",
+        "NAMESPACE", "SCORE", "STATUS", "PODS", "VIOLATIONS"
+    );
+    out.push_str(&format!("{}\n", "-".repeat(60)));
+    for ns in namespaces {
+        out.push_str(&format!(
+            "{:<24} {:>6} {:<10} {:>6} {:>10}\n",
+            ns.namespace, ns.health_score, ns.classification, ns.pods, ns.violations
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+/// Render the matching violations, one per line.
+fn format_violations(violations: &[ViolationDetail]) -> String {
+    if violations.is_empty() {
+        return "No matching violations\n".to_string();
+    }
+    let mut out = format!(
+        "{:<24} {:<32} {:<20} {:<9} MESSAGE\n",
+        "NAMESPACE", "POD", "TYPE", "SEVERITY"
+    );
+    out.push_str(&format!("{}\n", "-".repeat(100)));
+    for v in violations {
+        out.push_str(&format!(
+            "{:<24} {:<32} {:<20} {:<9} {}\n",
+            v.namespace,
+            v.pod_name,
+            v.violation_type,
+            format!("{:?}", v.severity).to_lowercase(),
+            v.message
+        ));
+    }
+    out
+}
+
+/// Render trends as a table; `HISTORY` shows up to the last 10 scores.
+fn format_trends(trends: &[PolicyTrend], results: usize) -> String {
+    if trends.is_empty() {
+        return format!("No score history in {results} audit result(s)\n");
+    }
+    let mut out = format!(
+        "{:<16} {:<20} {:>6} {:>8} {:>6} {:<10} HISTORY\n",
+        "NAMESPACE", "POLICY", "SCORE", "PREVIOUS", "DELTA", "TREND"
+    );
+    out.push_str(&format!("{}\n", "-".repeat(100)));
+    for t in trends {
+        let previous = t.previous_score.map_or("-".to_string(), |p| p.to_string());
+        let delta = t.previous_score.map_or("-".to_string(), |p| {
+            format!("{:+}", t.latest_score as i64 - p as i64)
+        });
+        let trend = t.trend.map_or("-", |trend| trend.as_str());
+        let history: Vec<String> = t.scores[t.scores.len().saturating_sub(10)..]
+            .iter()
+            .map(u32::to_string)
+            .collect();
+        out.push_str(&format!(
+            "{:<16} {:<20} {:>6} {:>8} {:>6} {:<10} {}\n",
+            t.namespace,
+            t.policy_name,
+            t.latest_score,
+            previous,
+            delta,
+            trend,
+            history.join(" → ")
+        ));
+    }
+    out
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{Container, PodSpec, PodStatus, Probe};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn make_pod(name: &str, namespace: &str, image: &str, probes: bool) -> Pod {
+        let probe = || if probes { Some(Probe::default()) } else { None };
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "main".to_string(),
+                    image: Some(image.to_string()),
+                    liveness_probe: probe(),
+                    readiness_probe: probe(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some("Running".to_string()),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[test]
+    fn test_build_report_clean_pods() {
+        let pods = vec![make_pod("web", "prod", "nginx:1.25", true)];
+        let report = build_report(&pods);
+        assert_eq!(report.health_score, 100);
+        assert_eq!(report.classification, "Healthy");
+        assert_eq!(report.metrics.total_pods, 1);
+        assert!(report.violations.is_empty());
+    }
+
+    #[test]
+    fn test_build_report_collects_violations() {
+        let pods = vec![make_pod("bad", "prod", "nginx:latest", false)];
+        let report = build_report(&pods);
+        let types: Vec<&str> = report
+            .violations
+            .iter()
+            .map(|v| v.violation_type.as_str())
+            .collect();
+        assert_eq!(
+            types,
+            vec!["latest_tag", "missing_liveness", "missing_readiness"]
+        );
+        assert!(report.health_score < 100);
+    }
+
+    #[test]
+    fn test_build_report_skips_system_namespaces() {
+        let pods = vec![make_pod("dns", "kube-system", "coredns:latest", false)];
+        let report = build_report(&pods);
+        assert_eq!(report.metrics.total_pods, 0);
+        assert!(report.violations.is_empty());
+    }
+
+    // ── CI gate ──
+
+    #[test]
+    fn test_gate_passes_without_thresholds() {
+        let pods = vec![make_pod("bad", "prod", "nginx:latest", false)];
+        let report = build_report(&pods);
+        assert!(evaluate_gate(&report, &Gate::default()).is_empty());
+    }
+
+    #[test]
+    fn test_gate_fail_on_severity() {
+        // latest_tag is High, missing probes are Medium/Low
+        let pods = vec![make_pod("bad", "prod", "nginx:latest", false)];
+        let report = build_report(&pods);
+
+        let high = Gate {
+            fail_on: Some(Severity::High),
+            ..Default::default()
+        };
+        let failures = evaluate_gate(&report, &high);
+        assert_eq!(failures, vec!["1 violation(s) at or above high severity"]);
+
+        let critical = Gate {
+            fail_on: Some(Severity::Critical),
+            ..Default::default()
+        };
+        assert!(evaluate_gate(&report, &critical).is_empty());
+
+        let low = Gate {
+            fail_on: Some(Severity::Low),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_gate(&report, &low),
+            vec!["3 violation(s) at or above low severity"]
+        );
+    }
+
+    #[test]
+    fn test_gate_min_score() {
+        let pods = vec![make_pod("bad", "prod", "nginx:latest", false)];
+        let report = build_report(&pods);
+        let gate = Gate {
+            min_score: Some(report.health_score + 1),
+            ..Default::default()
+        };
+        assert_eq!(evaluate_gate(&report, &gate).len(), 1);
+
+        let gate = Gate {
+            min_score: Some(report.health_score),
+            ..Default::default()
+        };
+        assert!(evaluate_gate(&report, &gate).is_empty());
+    }
+
+    #[test]
+    fn test_report_json_shape() {
+        let pods = vec![make_pod("bad", "prod", "nginx:latest", true)];
+        let report = build_report(&pods);
+        let json = output::render(&report, OutputFormat::Json).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["healthScore"], report.health_score);
+        assert_eq!(v["metrics"]["totalPods"], 1);
+        assert_eq!(v["violations"][0]["violationType"], "latest_tag");
+        assert_eq!(v["violations"][0]["severity"], "high");
+    }
+
+    // ── filters ──
+
+    fn fleet() -> AnalysisReport {
+        build_report(&[
+            make_pod("web-1", "prod", "nginx:1.25", true),
+            make_pod("web-2", "prod", "nginx:1.25", true),
+            make_pod("web-3", "prod", "nginx:1.25", true),
+            make_pod("api", "staging", "api:latest", false),
+            make_pod("job", "batch", "job:1.0", false),
+        ])
+    }
+
+    fn namespace_order(report: &AnalysisReport) -> Vec<&str> {
+        report
+            .namespaces
+            .iter()
+            .map(|ns| ns.namespace.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_build_report_per_namespace() {
+        let report = fleet();
+        assert_eq!(namespace_order(&report), ["batch", "prod", "staging"]);
+        let prod = &report.namespaces[1];
+        assert_eq!((prod.pods, prod.health_score, prod.violations), (3, 100, 0));
+        assert_eq!(report.namespaces[2].violations, 3);
+    }
+
+    #[test]
+    fn test_focus_sorts_namespaces() {
+        let mut report = fleet();
+        focus(&mut report, &AnalyzeFilter::default());
+        assert_eq!(namespace_order(&report), ["staging", "batch", "prod"]);
+
+        let filter = AnalyzeFilter {
+            sort: SortKey::Pods,
+            ..Default::default()
+        };
+        focus(&mut report, &filter);
+        assert_eq!(namespace_order(&report), ["prod", "staging", "batch"]);
+
+        let filter = AnalyzeFilter {
+            sort: SortKey::Violations,
+            ..Default::default()
+        };
+        focus(&mut report, &filter);
+        assert_eq!(namespace_order(&report), ["staging", "batch", "prod"]);
+    }
+
+    #[test]
+    fn test_focus_filters_violations() {
+        let mut report = fleet();
+        let score = report.health_score;
+        let filter = AnalyzeFilter {
+            severity: Some(Severity::Medium),
+            ..Default::default()
+        };
+        focus(&mut report, &filter);
+        // latest_tag (high) and missing_liveness (medium) in staging, liveness in batch
+        assert_eq!(report.violations.len(), 3);
+        assert_eq!(report.health_score, score);
+
+        let filter = AnalyzeFilter {
+            violation_types: vec!["latest_tag".to_string()],
+            sort: SortKey::Violations,
+            ..Default::default()
+        };
+        focus(&mut report, &filter);
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.violations[0].namespace, "staging");
+        assert_eq!(report.namespaces[0].violations, 1);
+        assert_eq!(report.namespaces[1].violations, 0);
+    }
+
+    #[test]
+    fn test_focus_top() {
+        let mut report = fleet();
+        let filter = AnalyzeFilter {
+            top: Some(1),
+            sort: SortKey::Pods,
+            ..Default::default()
+        };
+        focus(&mut report, &filter);
+        assert_eq!(namespace_order(&report), ["prod"]);
+        assert!(report.violations.is_empty());
+
+        let table = format_namespaces(&report.namespaces);
+        assert!(table.starts_with("NAMESPACE"));
+        assert!(table.contains("prod"));
+        assert!(!table.contains("staging"));
+        assert_eq!(format_violations(&[]), "No matching violations\n");
+    }
+
+    // ── junit ──
+
+    #[test]
+    fn test_junit_suites() {
+        let report = fleet();
+        let checks = governance::enabled_checks(&analysis_policy());
+        let suites = junit_suites(&report, &checks);
+        let names: Vec<&str> = suites.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["batch", "prod", "staging"]);
+        assert!(suites.iter().all(|s| s.cases.len() == checks.len()));
+        assert_eq!(suites[1].failures(), 0);
+        // staging: latest_tag, missing_liveness, missing_readiness
+        assert_eq!(suites[2].failures(), 3);
+        let xml = junit::render("kube-devops analyze", &suites);
+        assert!(xml.contains("<testcase classname=\"staging\" name=\"latest_tag\">"));
+    }
+
+    // ── policy ──
+
+    fn bundle(name: &str) -> AnalysisPolicy {
+        AnalysisPolicy::load(None, Some(name)).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_load_policy() {
+        assert!(AnalysisPolicy::load(None, None).unwrap().is_none());
+        assert_eq!(bundle("baseline").source, "bundle 'baseline'");
+        let err = AnalysisPolicy::load(None, Some("nope")).unwrap_err();
+        assert!(err.to_string().contains("Unknown bundle 'nope'"));
+
+        let path =
+            std::env::temp_dir().join(format!("kube-devops-analyze-{}.yaml", std::process::id()));
+        std::fs::write(
+            &path,
+            "apiVersion: devops.stochastic.io/v1\nkind: DevOpsPolicy\nmetadata:\n  name: p\nspec:\n  forbidLatestTag: true\n",
+        )
+        .unwrap();
+        let policy = AnalysisPolicy::load(path.to_str(), Some("baseline"))
+            .unwrap()
+            .unwrap();
+        assert!(policy.source.starts_with("file '"));
+        assert_eq!(policy.spec.forbid_latest_tag, Some(true));
+        assert_eq!(policy.spec.require_liveness_probe, None);
+        std::fs::remove_file(&path).unwrap();
+        assert!(AnalysisPolicy::load(path.to_str(), None).is_err());
+    }
+
+    #[test]
+    fn test_policy_report_uses_policy_checks() {
+        let pods = vec![make_pod("bad", "prod", "nginx:latest", false)];
+        let latest_only = AnalysisPolicy {
+            source: "test".to_string(),
+            spec: DevOpsPolicySpec {
+                forbid_latest_tag: Some(true),
+                ..Default::default()
+            },
+        };
+        let report = build_policy_report(&pods, &latest_only);
+        assert_eq!(report.policy.as_deref(), Some("test"));
+        let types: Vec<&str> = report
+            .violations
+            .iter()
+            .map(|v| v.violation_type.as_str())
+            .collect();
+        assert_eq!(types, ["latest_tag"]);
+        assert_eq!(report.namespaces[0].violations, 1);
+
+        let json = output::render(&report, OutputFormat::Json).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["policy"], "test");
+        let builtin = output::render(&build_report(&pods), OutputFormat::Json).unwrap();
+        assert!(!builtin.contains("\"policy\""));
+    }
+
+    #[test]
+    fn test_policy_report_groups_replicas_and_weights() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+        let replica = |name: &str| {
+            let mut pod = make_pod(name, "prod", "nginx:latest", true);
+            pod.metadata.owner_references = Some(vec![OwnerReference {
+                kind: "ReplicaSet".to_string(),
+                name: "web-7d9f8".to_string(),
+                controller: Some(true),
+                ..Default::default()
+            }]);
+            pod
+        };
+        let pods = vec![replica("web-7d9f8-a"), replica("web-7d9f8-b")];
+        let mut policy = bundle("baseline");
+        let report = build_policy_report(&pods, &policy);
+        // One workload, one latest_tag violation, two pods in the namespace
+        assert_eq!(report.metrics.total_pods, 1);
+        assert_eq!(report.violations.len(), 1);
+        assert_eq!(report.namespaces[0].pods, 2);
+
+        policy.spec.scoring_weights = Some(kube_devops::crd::ScoringWeightOverrides {
+            latest_tag: Some(0),
+            ..Default::default()
+        });
+        assert_eq!(build_policy_report(&pods, &policy).health_score, 100);
+        assert!(report.health_score < 100);
+    }
+
+    // ── trend ──
+
+    fn audit(namespace: &str, policy: &str, timestamp: &str, score: u32) -> PolicyAuditResult {
+        let mut result = PolicyAuditResult::new(
+            &format!("{policy}-{score}"),
+            kube_devops::crd::PolicyAuditResultSpec {
+                policy_name: policy.to_string(),
+                cluster_name: None,
+                timestamp: timestamp.to_string(),
+                health_score: score,
+                total_violations: 0,
+                total_pods: 1,
+                total_workloads: None,
+                classification: classify_health(score).to_string(),
+                violations: Vec::new(),
+                planned_remediations: None,
+            },
+        );
+        result.metadata.namespace = Some(namespace.to_string());
+        result
+    }
+
+    #[test]
+    fn test_build_trends_per_policy() {
+        let results = vec![
+            audit("prod", "web", "2026-03-01T12:00:00Z", 70),
+            audit("prod", "web", "2026-03-01T10:00:00Z", 90),
+            audit("prod", "web", "2026-03-01T11:00:00Z", 88),
+            audit("dev", "web", "2026-03-01T10:00:00Z", 60),
+        ];
+        let trends = build_trends(&results);
+        assert_eq!(trends.len(), 2);
+
+        assert_eq!(trends[0].namespace, "dev");
+        assert_eq!(trends[0].previous_score, None);
+        assert_eq!(trends[0].trend, None);
+
+        let prod = &trends[1];
+        assert_eq!(prod.scores, vec![90, 88, 70]);
+        assert_eq!(prod.latest_score, 70);
+        assert_eq!(prod.previous_score, Some(88));
+        assert_eq!(prod.trend, Some(ScoreTrend::Degrading));
+    }
+
+    #[test]
+    fn test_format_trends() {
+        let results = vec![
+            audit("prod", "web", "2026-03-01T10:00:00Z", 70),
+            audit("prod", "web", "2026-03-01T11:00:00Z", 85),
+        ];
+        let table = format_trends(&build_trends(&results), results.len());
+        assert!(table.starts_with("NAMESPACE"));
+        assert!(table.contains("+15"));
+        assert!(table.contains("improving"));
+        assert!(table.contains("70 → 85"));
+        assert_eq!(
+            format_trends(&[], 0),
+            "No score history in 0 audit result(s)\n"
+        );
+
+        let json = output::render(&build_trends(&results), OutputFormat::Json).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v[0]["trend"], "improving");
+        assert_eq!(v[0]["previousScore"], 70);
+    }
+}
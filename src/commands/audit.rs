@@ -0,0 +1,893 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use kube::{Api, ResourceExt};
+use serde::Serialize;
+
+use kube_devops::admission::severity_rank;
+use kube_devops::clock::Timezone;
+use kube_devops::crd::{AuditViolation, PolicyAuditResult, Severity};
+use kube_devops::output::{self, OutputFormat};
+
+use crate::commands::canary::parse_duration;
+use crate::commands::client;
+
+/* ============================= TYPES ============================= */
+
+/// Filters for `audit search`; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct AuditFilter {
+    pub violation_type: Option<String>,
+    /// Match violations at or above this severity.
+    pub severity: Option<Severity>,
+    pub pod_prefix: Option<String>,
+    pub policy: Option<String>,
+    /// Only search the most recent result of each policy.
+    pub latest: bool,
+}
+
+/// One stored violation matching the filters.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditMatch {
+    pub namespace: String,
+    pub audit_result: String,
+    pub policy_name: String,
+    pub timestamp: String,
+    pub pod_name: String,
+    pub container_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload: Option<String>,
+    pub violation_type: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// Filters for `audit list`; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct ListFilter {
+    pub policy: Option<String>,
+    /// Only results with a violation at or above this severity.
+    pub severity: Option<Severity>,
+    /// Only results stored at or after this time.
+    pub since: Option<DateTime<Utc>>,
+    /// Only results stored at or before this time.
+    pub until: Option<DateTime<Utc>>,
+}
+
+/// One stored audit result, without its violations.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditSummary {
+    pub namespace: String,
+    pub name: String,
+    pub policy_name: String,
+    pub timestamp: String,
+    pub health_score: u32,
+    pub classification: String,
+    pub total_pods: u32,
+    pub total_violations: u32,
+    pub by_severity: SeverityCounts,
+}
+
+#[derive(Debug, Default, PartialEq, Serialize)]
+pub struct SeverityCounts {
+    pub critical: u32,
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+}
+
+/// What changed between two audit results.
+///
+/// Violations are matched by workload (the pod for results without one),
+/// container, and type, so pods replaced by a rollout do not show up as
+/// resolved and re-added.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditDiff {
+    pub from: String,
+    pub to: String,
+    pub from_timestamp: String,
+    pub to_timestamp: String,
+    pub score_before: u32,
+    pub score_after: u32,
+    pub classification_before: String,
+    pub classification_after: String,
+    /// Violations only in `to`.
+    pub added: Vec<AuditViolation>,
+    /// Violations only in `from`.
+    pub resolved: Vec<AuditViolation>,
+    pub severity_changed: Vec<SeverityChange>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeverityChange {
+    pub subject: String,
+    pub container_name: String,
+    pub violation_type: String,
+    pub before: Severity,
+    pub after: Severity,
+}
+
+/* ============================= SEARCH ============================= */
+
+/// Violations in `results` that match `filter`, oldest result first.
+pub fn search(results: &[PolicyAuditResult], filter: &AuditFilter) -> Vec<AuditMatch> {
+    let mut selected: Vec<&PolicyAuditResult> = results
+        .iter()
+        .filter(|r| {
+            filter
+                .policy
+                .as_deref()
+                .is_none_or(|p| r.spec.policy_name == p)
+        })
+        .collect();
+    if filter.latest {
+        // Timestamps are RFC 3339 in UTC, so they order lexicographically
+        let mut latest: BTreeMap<(Option<String>, &str), &str> = BTreeMap::new();
+        for r in &selected {
+            let newest = latest
+                .entry((r.namespace(), r.spec.policy_name.as_str()))
+                .or_default();
+            if r.spec.timestamp.as_str() > *newest {
+                *newest = &r.spec.timestamp;
+            }
+        }
+        selected.retain(|r| {
+            latest.get(&(r.namespace(), r.spec.policy_name.as_str()))
+                == Some(&r.spec.timestamp.as_str())
+        });
+    }
+    selected.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+
+    let min_rank = filter.severity.as_ref().map(severity_rank);
+    selected
+        .into_iter()
+        .flat_map(|r| r.spec.violations.iter().map(move |v| (r, v)))
+        .filter(|(_, v)| {
+            filter
+                .violation_type
+                .as_deref()
+                .is_none_or(|t| v.violation_type == t)
+                && min_rank.is_none_or(|rank| severity_rank(&v.severity) >= rank)
+                && filter
+                    .pod_prefix
+                    .as_deref()
+                    .is_none_or(|prefix| v.pod_name.starts_with(prefix))
+        })
+        .map(|(r, v)| AuditMatch {
+            namespace: r.namespace().unwrap_or_default(),
+            audit_result: r.name_any(),
+            policy_name: r.spec.policy_name.clone(),
+            timestamp: r.spec.timestamp.clone(),
+            pod_name: v.pod_name.clone(),
+            container_name: v.container_name.clone(),
+            workload: v.workload.clone(),
+            violation_type: v.violation_type.clone(),
+            severity: v.severity.clone(),
+            message: v.message.clone(),
+        })
+        .collect()
+}
+
+/* ============================= LIST ============================= */
+
+/// Parse a time bound: an RFC 3339 timestamp, or a duration (`90m`, `24h`)
+/// counted back from `now`.
+pub fn parse_time_bound(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(time.with_timezone(&Utc));
+    }
+    let ago = parse_duration(value)
+        .with_context(|| format!("Invalid time '{value}' (expected RFC 3339 or e.g. 24h)"))?;
+    Ok(now - chrono::Duration::from_std(ago)?)
+}
+
+fn count_severities(violations: &[AuditViolation]) -> SeverityCounts {
+    let mut counts = SeverityCounts::default();
+    for v in violations {
+        match v.severity {
+            Severity::Critical => counts.critical += 1,
+            Severity::High => counts.high += 1,
+            Severity::Medium => counts.medium += 1,
+            Severity::Low => counts.low += 1,
+        }
+    }
+    counts
+}
+
+/// Results in `results` that match `filter`, oldest first.
+///
+/// Results with an unparseable timestamp never match a time bound.
+pub fn list(results: &[PolicyAuditResult], filter: &ListFilter) -> Vec<AuditSummary> {
+    let min_rank = filter.severity.as_ref().map(severity_rank);
+    let mut selected: Vec<&PolicyAuditResult> = results
+        .iter()
+        .filter(|r| {
+            filter
+                .policy
+                .as_deref()
+                .is_none_or(|p| r.spec.policy_name == p)
+        })
+        .filter(|r| {
+            min_rank.is_none_or(|rank| {
+                r.spec
+                    .violations
+                    .iter()
+                    .any(|v| severity_rank(&v.severity) >= rank)
+            })
+        })
+        .filter(|r| {
+            if filter.since.is_none() && filter.until.is_none() {
+                return true;
+            }
+            let Ok(time) = DateTime::parse_from_rfc3339(&r.spec.timestamp) else {
+                return false;
+            };
+            filter.since.is_none_or(|since| time >= since)
+                && filter.until.is_none_or(|until| time <= until)
+        })
+        .collect();
+    selected.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+
+    selected
+        .into_iter()
+        .map(|r| AuditSummary {
+            namespace: r.namespace().unwrap_or_default(),
+            name: r.name_any(),
+            policy_name: r.spec.policy_name.clone(),
+            timestamp: r.spec.timestamp.clone(),
+            health_score: r.spec.health_score,
+            classification: r.spec.classification.clone(),
+            total_pods: r.spec.total_pods,
+            total_violations: r.spec.total_violations,
+            by_severity: count_severities(&r.spec.violations),
+        })
+        .collect()
+}
+
+/* ============================= DIFF ============================= */
+
+/// Workload (or pod), container, and type identifying a violation across results.
+fn violation_key(v: &AuditViolation) -> (String, String, String) {
+    (
+        v.workload.clone().unwrap_or_else(|| v.pod_name.clone()),
+        v.container_name.clone(),
+        v.violation_type.clone(),
+    )
+}
+
+/// Compare two audit results, `from` being the older one.
+pub fn diff(from: &PolicyAuditResult, to: &PolicyAuditResult) -> AuditDiff {
+    let before: BTreeMap<_, &AuditViolation> = from
+        .spec
+        .violations
+        .iter()
+        .map(|v| (violation_key(v), v))
+        .collect();
+    let after: BTreeMap<_, &AuditViolation> = to
+        .spec
+        .violations
+        .iter()
+        .map(|v| (violation_key(v), v))
+        .collect();
+
+    let added = after
+        .iter()
+        .filter(|(key, _)| !before.contains_key(*key))
+        .map(|(_, v)| (*v).clone())
+        .collect();
+    let resolved = before
+        .iter()
+        .filter(|(key, _)| !after.contains_key(*key))
+        .map(|(_, v)| (*v).clone())
+        .collect();
+    let severity_changed = after
+        .iter()
+        .filter_map(|(key, new)| {
+            let old = before.get(key)?;
+            (old.severity != new.severity).then(|| SeverityChange {
+                subject: key.0.clone(),
+                container_name: key.1.clone(),
+                violation_type: key.2.clone(),
+                before: old.severity.clone(),
+                after: new.severity.clone(),
+            })
+        })
+        .collect();
+
+    AuditDiff {
+        from: from.name_any(),
+        to: to.name_any(),
+        from_timestamp: from.spec.timestamp.clone(),
+        to_timestamp: to.spec.timestamp.clone(),
+        score_before: from.spec.health_score,
+        score_after: to.spec.health_score,
+        classification_before: from.spec.classification.clone(),
+        classification_after: to.spec.classification.clone(),
+        added,
+        resolved,
+        severity_changed,
+    }
+}
+
+/* ============================= COMMAND ============================= */
+
+async fn audit_api(namespace: Option<&str>) -> Result<Api<PolicyAuditResult>> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    Ok(match namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    })
+}
+
+async fn get_result(api: &Api<PolicyAuditResult>, name: &str) -> Result<PolicyAuditResult> {
+    api.get(name)
+        .await
+        .with_context(|| format!("Failed to fetch audit result '{name}'"))
+}
+
+/// List stored PolicyAuditResults in one namespace (or all).
+pub async fn list_command(
+    namespace: Option<&str>,
+    filter: &ListFilter,
+    timezone: Timezone,
+    format: OutputFormat,
+) -> Result<()> {
+    let results = audit_api(namespace)
+        .await?
+        .list(&Default::default())
+        .await
+        .context("Failed to list PolicyAuditResults")?
+        .items;
+
+    let summaries = list(&results, filter);
+    if format.is_structured() {
+        return output::print_structured(&summaries, format);
+    }
+    print!("{}", format_list(&summaries, results.len(), timezone));
+    Ok(())
+}
+
+/// Show one audit result with its violations at or above `severity`.
+pub async fn show_command(
+    namespace: &str,
+    name: &str,
+    severity: Option<&Severity>,
+    timezone: Timezone,
+    format: OutputFormat,
+) -> Result<()> {
+    let mut result = get_result(&audit_api(Some(namespace)).await?, name).await?;
+    if let Some(min) = severity {
+        let rank = severity_rank(min);
+        result
+            .spec
+            .violations
+            .retain(|v| severity_rank(&v.severity) >= rank);
+    }
+    if format.is_structured() {
+        return output::print_structured(&result, format);
+    }
+    print!("{}", format_show(&result, timezone));
+    Ok(())
+}
+
+/// Compare two audit results in `namespace`.
+pub async fn diff_command(
+    namespace: &str,
+    from: &str,
+    to: &str,
+    timezone: Timezone,
+    format: OutputFormat,
+) -> Result<()> {
+    let api = audit_api(Some(namespace)).await?;
+    let from = get_result(&api, from).await?;
+    let to = get_result(&api, to).await?;
+    if from.spec.policy_name != to.spec.policy_name && !format.is_structured() {
+        println!(
+            "Note: comparing results of different policies ({} and {})\n",
+            from.spec.policy_name, to.spec.policy_name
+        );
+    }
+
+    let diff = diff(&from, &to);
+    if format.is_structured() {
+        return output::print_structured(&diff, format);
+    }
+    print!("{}", format_diff(&diff, timezone));
+    Ok(())
+}
+
+/// Search stored PolicyAuditResults in one namespace (or all) for violations.
+pub async fn search_command(
+    namespace: Option<&str>,
+    filter: &AuditFilter,
+    timezone: Timezone,
+    format: OutputFormat,
+) -> Result<()> {
+    let results = audit_api(namespace)
+        .await?
+        .list(&Default::default())
+        .await
+        .context("Failed to list PolicyAuditResults")?
+        .items;
+
+    let matches = search(&results, filter);
+    if format.is_structured() {
+        return output::print_structured(&matches, format);
+    }
+    print!("{}", format_matches(&matches, results.len(), timezone));
+    Ok(())
+}
+
+/* ============================= DISPLAY ============================= */
+
+/// Render matches as a table, with timestamps shown in `timezone`.
+fn format_matches(matches: &[AuditMatch], searched: usize, timezone: Timezone) -> String {
+    if matches.is_empty() {
+        return format!("No matching violations in {searched} audit result(s)\n");
+    }
+    let mut out = format!(
+        "{:<16} {:<20} {:<21} {:<30} {:<18} {:<9} MESSAGE\n",
+        "NAMESPACE", "POLICY", "TIMESTAMP", "POD", "TYPE", "SEVERITY"
+    );
+    out.push_str(&format!("{}\n", "-".repeat(130)));
+    for m in matches {
+        out.push_str(&format!(
+            "{:<16} {:<20} {:<21} {:<30} {:<18} {:<9} {}\n",
+            m.namespace,
+            m.policy_name,
+            timezone.format_rfc3339(&m.timestamp, "%Y-%m-%d %H:%M:%S"),
+            m.pod_name,
+            m.violation_type,
+            format!("{:?}", m.severity).to_lowercase(),
+            m.message
+        ));
+    }
+    out.push_str(&format!(
+        "\n{} violation(s) in {searched} audit result(s)\n",
+        matches.len()
+    ));
+    out
+}
+
+fn severity_label(severity: &Severity) -> String {
+    format!("{severity:?}").to_lowercase()
+}
+
+/// Render summaries as a table, with timestamps shown in `timezone`.
+fn format_list(summaries: &[AuditSummary], searched: usize, timezone: Timezone) -> String {
+    if summaries.is_empty() {
+        return format!("No matching audit results ({searched} stored)\n");
+    }
+    let mut out = format!(
+        "{:<16} {:<36} {:<20} {:<21} {:>5} {:<10} {:>4} {:>5} {:>4} {:>4} {:>4}\n",
+        "NAMESPACE",
+        "NAME",
+        "POLICY",
+        "TIMESTAMP",
+        "SCORE",
+        "CLASS",
+        "PODS",
+        "CRIT",
+        "HIGH",
+        "MED",
+        "LOW"
+    );
+    out.push_str(&format!("{}\n", "-".repeat(140)));
+    for s in summaries {
+        out.push_str(&format!(
+            "{:<16} {:<36} {:<20} {:<21} {:>5} {:<10} {:>4} {:>5} {:>4} {:>4} {:>4}\n",
+            s.namespace,
+            s.name,
+            s.policy_name,
+            timezone.format_rfc3339(&s.timestamp, "%Y-%m-%d %H:%M:%S"),
+            s.health_score,
+            s.classification,
+            s.total_pods,
+            s.by_severity.critical,
+            s.by_severity.high,
+            s.by_severity.medium,
+            s.by_severity.low
+        ));
+    }
+    out.push_str(&format!(
+        "\n{} of {searched} audit result(s)\n",
+        summaries.len()
+    ));
+    out
+}
+
+/// Render one result and its violations, most severe first.
+fn format_show(result: &PolicyAuditResult, timezone: Timezone) -> String {
+    let spec = &result.spec;
+    let mut out = format!("Name:        {}\n", result.name_any());
+    out.push_str(&format!(
+        "Namespace:   {}\n",
+        result.namespace().unwrap_or_default()
+    ));
+    out.push_str(&format!("Policy:      {}\n", spec.policy_name));
+    if let Some(cluster) = &spec.cluster_name {
+        out.push_str(&format!("Cluster:     {cluster}\n"));
+    }
+    out.push_str(&format!(
+        "Timestamp:   {}\n",
+        timezone.format_rfc3339(&spec.timestamp, "%Y-%m-%d %H:%M:%S")
+    ));
+    out.push_str(&format!(
+        "Score:       {}/100 ({})\n",
+        spec.health_score, spec.classification
+    ));
+    out.push_str(&format!(
+        "Pods:        {}\nViolations:  {}\n",
+        spec.total_pods, spec.total_violations
+    ));
+
+    let mut violations: Vec<&AuditViolation> = spec.violations.iter().collect();
+    violations.sort_by(|a, b| {
+        severity_rank(&b.severity)
+            .cmp(&severity_rank(&a.severity))
+            .then_with(|| a.pod_name.cmp(&b.pod_name))
+    });
+    if violations.is_empty() {
+        out.push_str("\nNo violations\n");
+    } else {
+        out.push_str(&format!(
+            "\n{:<9} {:<18} {:<30} {:<16} {:<28} MESSAGE\n",
+            "SEVERITY", "TYPE", "POD", "CONTAINER", "WORKLOAD"
+        ));
+        for v in violations {
+            out.push_str(&format!(
+                "{:<9} {:<18} {:<30} {:<16} {:<28} {}\n",
+                severity_label(&v.severity),
+                v.violation_type,
+                v.pod_name,
+                v.container_name,
+                v.workload.as_deref().unwrap_or("-"),
+                v.message
+            ));
+        }
+    }
+
+    if let Some(planned) = spec.planned_remediations.as_ref().filter(|p| !p.is_empty()) {
+        out.push_str("\nPlanned remediations:\n");
+        for p in planned {
+            let actions: Vec<String> = p
+                .actions
+                .iter()
+                .map(|a| format!("{} ({})", a.action, a.container))
+                .collect();
+            out.push_str(&format!("  {:<28} {}\n", p.workload, actions.join(", ")));
+        }
+    }
+    out
+}
+
+/// Render a diff, `+` for new violations and `-` for resolved ones.
+fn format_diff(diff: &AuditDiff, timezone: Timezone) -> String {
+    let time = |t: &str| timezone.format_rfc3339(t, "%Y-%m-%d %H:%M:%S");
+    let mut out = format!(
+        "{} ({}) → {} ({})\n",
+        diff.from,
+        time(&diff.from_timestamp),
+        diff.to,
+        time(&diff.to_timestamp)
+    );
+    out.push_str(&format!(
+        "Score: {} → {} ({:+}), {} → {}\n",
+        diff.score_before,
+        diff.score_after,
+        diff.score_after as i64 - diff.score_before as i64,
+        diff.classification_before,
+        diff.classification_after
+    ));
+
+    if diff.added.is_empty() && diff.resolved.is_empty() && diff.severity_changed.is_empty() {
+        out.push_str("\nNo violation changes\n");
+        return out;
+    }
+    out.push('\n');
+    let subject = |v: &AuditViolation| v.workload.clone().unwrap_or_else(|| v.pod_name.clone());
+    for (sign, violations) in [("+", &diff.added), ("-", &diff.resolved)] {
+        for v in violations {
+            out.push_str(&format!(
+                "{sign} {:<9} {:<18} {:<30} {:<16} {}\n",
+                severity_label(&v.severity),
+                v.violation_type,
+                subject(v),
+                v.container_name,
+                v.message
+            ));
+        }
+    }
+    for c in &diff.severity_changed {
+        out.push_str(&format!(
+            "~ {:<9} {:<18} {:<30} {:<16} severity {} → {}\n",
+            severity_label(&c.after),
+            c.violation_type,
+            c.subject,
+            c.container_name,
+            severity_label(&c.before),
+            severity_label(&c.after)
+        ));
+    }
+    out.push_str(&format!(
+        "\n{} added, {} resolved, {} severity change(s)\n",
+        diff.added.len(),
+        diff.resolved.len(),
+        diff.severity_changed.len()
+    ));
+    out
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube_devops::crd::{AuditViolation, PolicyAuditResultSpec};
+
+    fn violation(pod: &str, violation_type: &str, severity: Severity) -> AuditViolation {
+        AuditViolation {
+            pod_name: pod.to_string(),
+            container_name: "app".to_string(),
+            violation_type: violation_type.to_string(),
+            severity,
+            message: format!("{violation_type} on {pod}"),
+            workload: None,
+        }
+    }
+
+    fn result(
+        namespace: &str,
+        name: &str,
+        policy: &str,
+        timestamp: &str,
+        violations: Vec<AuditViolation>,
+    ) -> PolicyAuditResult {
+        let mut result = PolicyAuditResult::new(
+            name,
+            PolicyAuditResultSpec {
+                policy_name: policy.to_string(),
+                cluster_name: None,
+                timestamp: timestamp.to_string(),
+                health_score: 50,
+                total_violations: violations.len() as u32,
+                total_pods: 3,
+                total_workloads: None,
+                classification: "Degraded".to_string(),
+                violations,
+                planned_remediations: None,
+            },
+        );
+        result.metadata.namespace = Some(namespace.to_string());
+        result
+    }
+
+    fn results() -> Vec<PolicyAuditResult> {
+        vec![
+            result(
+                "prod",
+                "p-2",
+                "policy",
+                "2026-03-01T11:00:00Z",
+                vec![
+                    violation("web-1", "latest_tag", Severity::Critical),
+                    violation("api-1", "missing_liveness", Severity::Medium),
+                ],
+            ),
+            result(
+                "prod",
+                "p-1",
+                "policy",
+                "2026-03-01T10:00:00Z",
+                vec![violation("web-1", "latest_tag", Severity::High)],
+            ),
+            result(
+                "dev",
+                "d-1",
+                "policy",
+                "2026-03-01T09:00:00Z",
+                vec![violation("web-9", "missing_readiness", Severity::Low)],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_search_without_filters_returns_all_oldest_first() {
+        let matches = search(&results(), &AuditFilter::default());
+        assert_eq!(matches.len(), 4);
+        assert_eq!(matches[0].namespace, "dev");
+        assert_eq!(matches[3].audit_result, "p-2");
+    }
+
+    #[test]
+    fn test_search_filters_combine() {
+        let filter = AuditFilter {
+            violation_type: Some("latest_tag".to_string()),
+            severity: Some(Severity::Critical),
+            pod_prefix: Some("web-".to_string()),
+            ..Default::default()
+        };
+        let matches = search(&results(), &filter);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].audit_result, "p-2");
+        assert_eq!(matches[0].severity, Severity::Critical);
+    }
+
+    #[test]
+    fn test_search_severity_is_a_minimum() {
+        let filter = AuditFilter {
+            severity: Some(Severity::Medium),
+            ..Default::default()
+        };
+        let types: Vec<String> = search(&results(), &filter)
+            .into_iter()
+            .map(|m| m.violation_type)
+            .collect();
+        assert_eq!(types, vec!["latest_tag", "latest_tag", "missing_liveness"]);
+    }
+
+    #[test]
+    fn test_search_latest_per_policy_and_namespace() {
+        let filter = AuditFilter {
+            latest: true,
+            ..Default::default()
+        };
+        let names: Vec<String> = search(&results(), &filter)
+            .into_iter()
+            .map(|m| m.audit_result)
+            .collect();
+        assert_eq!(names, vec!["d-1", "p-2", "p-2"]);
+    }
+
+    #[test]
+    fn test_format_matches() {
+        let matches = search(&results(), &AuditFilter::default());
+        let table = format_matches(&matches, 3, Timezone::Utc);
+        assert!(table.starts_with("NAMESPACE"));
+        assert!(table.contains("2026-03-01 09:00:00"));
+        assert!(table.contains("4 violation(s) in 3 audit result(s)"));
+        let shifted = format_matches(&matches, 3, "+02:00".parse().unwrap());
+        assert!(shifted.contains("2026-03-01 11:00:00"));
+        assert_eq!(
+            format_matches(&[], 3, Timezone::Utc),
+            "No matching violations in 3 audit result(s)\n"
+        );
+    }
+
+    // ── list ──
+
+    #[test]
+    fn test_parse_time_bound() {
+        let now = "2026-03-01T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            parse_time_bound("2h", now).unwrap(),
+            "2026-03-01T10:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert_eq!(
+            parse_time_bound("2026-03-01T09:30:00+01:00", now).unwrap(),
+            "2026-03-01T08:30:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert!(parse_time_bound("yesterday", now).is_err());
+    }
+
+    #[test]
+    fn test_list_filters_by_policy_severity_and_time() {
+        let all = list(&results(), &ListFilter::default());
+        let names: Vec<&str> = all.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["d-1", "p-1", "p-2"]);
+        assert_eq!(
+            all[2].by_severity,
+            SeverityCounts {
+                critical: 1,
+                medium: 1,
+                ..Default::default()
+            }
+        );
+
+        let filter = ListFilter {
+            severity: Some(Severity::High),
+            since: Some("2026-03-01T10:30:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let names: Vec<String> = list(&results(), &filter)
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(names, vec!["p-2"]);
+
+        let filter = ListFilter {
+            policy: Some("other".to_string()),
+            ..Default::default()
+        };
+        assert!(list(&results(), &filter).is_empty());
+
+        let filter = ListFilter {
+            until: Some("2026-03-01T10:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(list(&results(), &filter).len(), 2);
+    }
+
+    #[test]
+    fn test_format_list() {
+        let table = format_list(&list(&results(), &ListFilter::default()), 3, Timezone::Utc);
+        assert!(table.starts_with("NAMESPACE"));
+        assert!(table.contains("3 of 3 audit result(s)"));
+        assert_eq!(
+            format_list(&[], 3, Timezone::Utc),
+            "No matching audit results (3 stored)\n"
+        );
+    }
+
+    // ── show ──
+
+    #[test]
+    fn test_format_show_orders_by_severity() {
+        let results = results();
+        let out = format_show(&results[0], Timezone::Utc);
+        assert!(out.contains("Policy:      policy"));
+        assert!(out.contains("Score:       50/100 (Degraded)"));
+        let critical = out.find("critical").unwrap();
+        let medium = out.find("medium").unwrap();
+        assert!(critical < medium);
+
+        let empty = result("prod", "e", "policy", "2026-03-01T11:00:00Z", vec![]);
+        assert!(format_show(&empty, Timezone::Utc).contains("No violations"));
+    }
+
+    // ── diff ──
+
+    #[test]
+    fn test_diff_matches_violations_across_rollouts() {
+        let with_workload = |pod: &str, violation_type: &str, severity| AuditViolation {
+            workload: Some("Deployment/web".to_string()),
+            ..violation(pod, violation_type, severity)
+        };
+        let from = result(
+            "prod",
+            "a",
+            "policy",
+            "2026-03-01T10:00:00Z",
+            vec![
+                with_workload("web-abc", "latest_tag", Severity::Medium),
+                with_workload("web-abc", "missing_liveness", Severity::Medium),
+            ],
+        );
+        let to = result(
+            "prod",
+            "b",
+            "policy",
+            "2026-03-01T11:00:00Z",
+            vec![
+                // Same workload, new pod after a rollout
+                with_workload("web-xyz", "latest_tag", Severity::Critical),
+                violation("api-1", "missing_readiness", Severity::Low),
+            ],
+        );
+
+        let d = diff(&from, &to);
+        assert_eq!(d.added.len(), 1);
+        assert_eq!(d.added[0].pod_name, "api-1");
+        assert_eq!(d.resolved.len(), 1);
+        assert_eq!(d.resolved[0].violation_type, "missing_liveness");
+        assert_eq!(d.severity_changed.len(), 1);
+        assert_eq!(d.severity_changed[0].subject, "Deployment/web");
+        assert_eq!(d.severity_changed[0].after, Severity::Critical);
+
+        let out = format_diff(&d, Timezone::Utc);
+        assert!(out.contains("+ low"));
+        assert!(out.contains("- medium"));
+        assert!(out.contains("severity medium → critical"));
+        assert!(out.contains("1 added, 1 resolved, 1 severity change(s)"));
+
+        let same = diff(&from, &from);
+        assert!(format_diff(&same, Timezone::Utc).contains("No violation changes"));
+    }
+}
@@ -0,0 +1,467 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use k8s_openapi::api::core::v1::{Namespace, Pod};
+use kube::{Api, ResourceExt};
+use serde::Serialize;
+
+use kube_devops::admission;
+use kube_devops::crd::{ClusterDevOpsPolicy, DevOpsPolicy, DevOpsPolicySpec};
+use kube_devops::governance::{self, PodMetrics};
+use kube_devops::output::{self, OutputFormat};
+use kube_devops::policy_resolution;
+
+use crate::commands::{client, policy};
+
+/* ============================= TYPES ============================= */
+
+/// Shadow counters for one policy (current or candidate) across all samples.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShadowStats {
+    /// Pod evaluations across all samples.
+    pub pod_evaluations: u64,
+    pub violations: u64,
+    /// Pod evaluations the webhook would have denied.
+    pub would_deny: u64,
+    /// Health score of the most recent sample.
+    pub health_score: u32,
+    pub violations_by_type: BTreeMap<String, u64>,
+}
+
+impl ShadowStats {
+    /// Share of pod evaluations the webhook would have denied (0.0–1.0).
+    pub fn would_deny_rate(&self) -> f64 {
+        if self.pod_evaluations == 0 {
+            0.0
+        } else {
+            self.would_deny as f64 / self.pod_evaluations as f64
+        }
+    }
+}
+
+/// Side-by-side result of running a candidate policy in shadow.
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CanaryReport {
+    pub namespace: String,
+    pub candidate: String,
+    pub samples: u32,
+    pub current_stats: ShadowStats,
+    pub candidate_stats: ShadowStats,
+    /// Pods the candidate would deny that the current policy admits.
+    pub newly_denied: BTreeSet<String>,
+    /// Pods the current policy denies that the candidate would admit.
+    pub newly_admitted: BTreeSet<String>,
+}
+
+/* ============================= PARSING ============================= */
+
+/// Parse `90s`, `10m`, or `1h` (a bare number is seconds).
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let (number, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => value.split_at(i),
+        None => (value, "s"),
+    };
+    let number: u64 = number
+        .parse()
+        .with_context(|| format!("Invalid duration '{value}' (expected e.g. 30s, 10m, 1h)"))?;
+    let seconds = match unit {
+        "s" => number,
+        "m" => number * 60,
+        "h" => number * 3600,
+        other => anyhow::bail!("Unknown duration unit '{other}' in '{value}' (use s, m, or h)"),
+    };
+    if seconds == 0 {
+        anyhow::bail!("Duration '{value}' must be greater than zero");
+    }
+    Ok(Duration::from_secs(seconds))
+}
+
+/// First DevOpsPolicy in a (possibly multi-document) YAML file.
+pub fn load_candidate(content: &str) -> Result<DevOpsPolicy> {
+    policy::parse_policies(content)?
+        .into_iter()
+        .next()
+        .context("No DevOpsPolicy found in file")
+}
+
+/* ============================= SHADOW EVALUATION ============================= */
+
+/// Whether the webhook would deny `pod` under `policy`, honouring `minDenySeverity`.
+fn would_deny(pod: &Pod, policy: &DevOpsPolicySpec) -> bool {
+    let verdict = match &policy.min_deny_severity {
+        Some(threshold) => admission::validate_pod_admission_with_severity(pod, policy, threshold),
+        None => admission::validate_pod_admission(pod, policy),
+    };
+    !verdict.allowed
+}
+
+/// Evaluate one sample of pods under both policies and fold it into the
+/// report. Returns the sample's would-deny counts (current, candidate).
+pub fn record_sample(
+    report: &mut CanaryReport,
+    pods: &[Pod],
+    current: Option<&DevOpsPolicySpec>,
+    candidate: &DevOpsPolicySpec,
+) -> (usize, usize) {
+    report.samples += 1;
+    let mut current_denied = BTreeSet::new();
+    if let Some(current) = current {
+        current_denied = record_policy(&mut report.current_stats, pods, current);
+    }
+    let candidate_denied = record_policy(&mut report.candidate_stats, pods, candidate);
+
+    report
+        .newly_denied
+        .extend(candidate_denied.difference(&current_denied).cloned());
+    report
+        .newly_admitted
+        .extend(current_denied.difference(&candidate_denied).cloned());
+    (current_denied.len(), candidate_denied.len())
+}
+
+/// Add one sample to `stats`; returns the names of pods that would be denied.
+fn record_policy(
+    stats: &mut ShadowStats,
+    pods: &[Pod],
+    policy: &DevOpsPolicySpec,
+) -> BTreeSet<String> {
+    let mut aggregate = PodMetrics::default();
+    let mut denied = BTreeSet::new();
+    for pod in pods {
+        governance::add_metrics(
+            &mut aggregate,
+            &governance::evaluate_pod_with_policy(pod, policy),
+        );
+        for violation in governance::detect_violations_with_policy(pod, policy) {
+            stats.violations += 1;
+            *stats
+                .violations_by_type
+                .entry(violation.to_string())
+                .or_default() += 1;
+        }
+        if would_deny(pod, policy) {
+            denied.insert(pod.name_any());
+        }
+    }
+    stats.pod_evaluations += pods.len() as u64;
+    stats.would_deny += denied.len() as u64;
+    stats.health_score = governance::calculate_health_score(&aggregate);
+    denied
+}
+
+/* ============================= COMMAND ============================= */
+
+/// Run a candidate DevOpsPolicy in shadow next to the namespace's current
+/// policy for `duration`, sampling pods every `interval`, then compare
+/// violations and would-deny rates. Nothing is written to the cluster.
+///
+/// The candidate replaces the namespace's DevOpsPolicy and is layered over
+/// ClusterDevOpsPolicies and profiles exactly as the operator would.
+pub async fn canary(
+    file: &str,
+    namespace: Option<&str>,
+    duration: &str,
+    interval: &str,
+    format: OutputFormat,
+) -> Result<()> {
+    let duration = parse_duration(duration)?;
+    let interval = parse_duration(interval)?;
+    let content =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read '{file}'"))?;
+    let candidate = load_candidate(&content)?;
+    let namespace = namespace
+        .map(str::to_string)
+        .or_else(|| candidate.namespace())
+        .unwrap_or_else(|| "default".to_string());
+
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    let cluster_api: Api<ClusterDevOpsPolicy> = Api::all(client.clone());
+    let policy_api: Api<DevOpsPolicy> = Api::namespaced(client.clone(), &namespace);
+    let pod_api: Api<Pod> = Api::namespaced(client.clone(), &namespace);
+    let namespace_api: Api<Namespace> = Api::all(client);
+
+    let mut report = CanaryReport {
+        namespace: namespace.clone(),
+        candidate: candidate.name_any(),
+        ..Default::default()
+    };
+    let total_samples = duration.as_secs() / interval.as_secs() + 1;
+    if !format.is_structured() {
+        println!(
+            "Running '{}' in shadow in namespace '{namespace}' for {}s ({total_samples} samples)...\n",
+            report.candidate,
+            duration.as_secs()
+        );
+    }
+
+    let started = tokio::time::Instant::now();
+    loop {
+        let cluster_policies = match cluster_api.list(&Default::default()).await {
+            Ok(list) => list.items,
+            // ClusterDevOpsPolicy CRD not installed
+            Err(kube::Error::Api(err)) if err.code == 404 => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let current_policy = policy_api
+            .list(&Default::default())
+            .await?
+            .items
+            .into_iter()
+            .min_by_key(|p| p.name_any());
+        let pods = pod_api.list(&Default::default()).await?.items;
+        let labels = namespace_api
+            .get_opt(&namespace)
+            .await?
+            .and_then(|ns| ns.metadata.labels);
+
+        let resolve = |spec: Option<&DevOpsPolicySpec>| {
+            policy_resolution::resolve_effective_policy(&namespace, &cluster_policies, spec)
+                .map(|spec| policy_resolution::apply_profile(&spec, labels.as_ref()))
+        };
+        let current = resolve(current_policy.as_ref().map(|p| &p.spec));
+        let candidate_spec = resolve(Some(&candidate.spec)).unwrap_or_default();
+        let (current_denied, candidate_denied) =
+            record_sample(&mut report, &pods, current.as_ref(), &candidate_spec);
+
+        if !format.is_structured() {
+            println!(
+                "  [{}/{total_samples}] pods={} would-deny current={current_denied} candidate={candidate_denied}",
+                report.samples,
+                pods.len(),
+            );
+        }
+
+        if started.elapsed() + interval > duration {
+            break;
+        }
+        tokio::time::sleep(interval).await;
+    }
+
+    if format.is_structured() {
+        return output::print_structured(&report, format);
+    }
+    print!("\n{}", format_report(&report));
+    Ok(())
+}
+
+/* ============================= DISPLAY ============================= */
+
+fn format_report(report: &CanaryReport) -> String {
+    let current = &report.current_stats;
+    let candidate = &report.candidate_stats;
+    let mut out = format!(
+        "Canary: {} in namespace '{}' ({} samples)\n\n",
+        report.candidate, report.namespace, report.samples
+    );
+    if current.pod_evaluations == 0 && report.candidate_stats.pod_evaluations > 0 {
+        out.push_str("No current policy in the namespace; CURRENT is empty.\n\n");
+    }
+    out.push_str(&format!(
+        "{:<24} {:>10} {:>10} {:>8}\n",
+        "", "CURRENT", "CANDIDATE", "DELTA"
+    ));
+    out.push_str(&format!("{}\n", "-".repeat(55)));
+    let rows = [
+        ("Violations", current.violations, candidate.violations),
+        (
+            "Health score (last)",
+            current.health_score as u64,
+            candidate.health_score as u64,
+        ),
+    ];
+    for (label, a, b) in rows {
+        out.push_str(&format!(
+            "{label:<24} {a:>10} {b:>10} {:>+8}\n",
+            b as i64 - a as i64
+        ));
+    }
+    out.push_str(&format!(
+        "{:<24} {:>9.1}% {:>9.1}% {:>+7.1}%\n",
+        "Would-deny rate",
+        current.would_deny_rate() * 100.0,
+        candidate.would_deny_rate() * 100.0,
+        (candidate.would_deny_rate() - current.would_deny_rate()) * 100.0
+    ));
+
+    let types: BTreeSet<&String> = current
+        .violations_by_type
+        .keys()
+        .chain(candidate.violations_by_type.keys())
+        .collect();
+    for violation in types {
+        let a = current
+            .violations_by_type
+            .get(violation)
+            .copied()
+            .unwrap_or(0);
+        let b = candidate
+            .violations_by_type
+            .get(violation)
+            .copied()
+            .unwrap_or(0);
+        out.push_str(&format!(
+            "  {violation:<22} {a:>10} {b:>10} {:>+8}\n",
+            b as i64 - a as i64
+        ));
+    }
+
+    if !report.newly_denied.is_empty() {
+        out.push_str(&format!(
+            "\nWould newly deny ({}): {}\n",
+            report.newly_denied.len(),
+            report
+                .newly_denied
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if !report.newly_admitted.is_empty() {
+        out.push_str(&format!(
+            "\nWould newly admit ({}): {}\n",
+            report.newly_admitted.len(),
+            report
+                .newly_admitted
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    out
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{Container, PodSpec};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn pod(name: &str, image: &str) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("prod".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    image: Some(image.to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("90").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_duration("30s").unwrap(), Duration::from_secs(30));
+        assert_eq!(parse_duration("10m").unwrap(), Duration::from_secs(600));
+        assert_eq!(parse_duration("1h").unwrap(), Duration::from_secs(3600));
+        assert!(parse_duration("0m").is_err());
+        assert!(parse_duration("5d").is_err());
+        assert!(parse_duration("m").is_err());
+    }
+
+    #[test]
+    fn test_load_candidate_skips_other_kinds() {
+        let content = r#"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: other
+---
+apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: strict
+  namespace: prod
+spec:
+  forbidLatestTag: true
+"#;
+        let policy = load_candidate(content).unwrap();
+        assert_eq!(policy.name_any(), "strict");
+        assert_eq!(policy.spec.forbid_latest_tag, Some(true));
+        assert!(load_candidate("kind: ConfigMap").is_err());
+
+        // A `---` inside a value is not a document marker
+        let content =
+            "kind: DevOpsPolicy\nmetadata:\n  name: a---b\nspec:\n  requiredTagPattern: \"---\"\n";
+        let policy = load_candidate(content).unwrap();
+        assert_eq!(policy.name_any(), "a---b");
+        assert_eq!(policy.spec.required_tag_pattern.as_deref(), Some("---"));
+    }
+
+    #[test]
+    fn test_record_sample_compares_policies() {
+        let pods = vec![pod("web", "nginx:latest"), pod("api", "api:1.0")];
+        let current = DevOpsPolicySpec::default();
+        let candidate = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            ..Default::default()
+        };
+        let mut report = CanaryReport::default();
+        record_sample(&mut report, &pods, Some(&current), &candidate);
+        record_sample(&mut report, &pods, Some(&current), &candidate);
+
+        assert_eq!(report.samples, 2);
+        assert_eq!(report.current_stats.pod_evaluations, 4);
+        assert_eq!(report.current_stats.would_deny, 0);
+        assert_eq!(report.candidate_stats.would_deny, 2);
+        assert_eq!(report.candidate_stats.would_deny_rate(), 0.5);
+        assert_eq!(report.candidate_stats.violations_by_type["latest_tag"], 2);
+        assert!(report.candidate_stats.health_score < report.current_stats.health_score);
+        assert_eq!(report.newly_denied.iter().collect::<Vec<_>>(), vec!["web"]);
+        assert!(report.newly_admitted.is_empty());
+
+        let table = format_report(&report);
+        assert!(table.contains("Would-deny rate"));
+        assert!(table.contains("Would newly deny (1): web"));
+    }
+
+    #[test]
+    fn test_record_sample_honours_min_deny_severity() {
+        let pods = vec![pod("web", "nginx:latest")];
+        let current = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            ..Default::default()
+        };
+        // latest_tag is High, so a Critical threshold only warns
+        let candidate = DevOpsPolicySpec {
+            min_deny_severity: Some(kube_devops::crd::Severity::Critical),
+            ..current.clone()
+        };
+        let mut report = CanaryReport::default();
+        record_sample(&mut report, &pods, Some(&current), &candidate);
+        assert_eq!(report.candidate_stats.violations, 1);
+        assert_eq!(report.candidate_stats.would_deny, 0);
+        assert!(report.newly_admitted.contains("web"));
+    }
+
+    #[test]
+    fn test_record_sample_without_current_policy() {
+        let pods = vec![pod("web", "nginx:latest")];
+        let candidate = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            ..Default::default()
+        };
+        let mut report = CanaryReport::default();
+        record_sample(&mut report, &pods, None, &candidate);
+        assert_eq!(report.current_stats.pod_evaluations, 0);
+        assert_eq!(report.current_stats.would_deny_rate(), 0.0);
+        assert!(report.newly_denied.contains("web"));
+    }
+}
@@ -1,62 +1,691 @@
-use k8s_openapi::api::core::v1::{Node, Pod};
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::admissionregistration::v1::ValidatingWebhookConfiguration;
+use k8s_openapi::api::coordination::v1::{Lease, LeaseSpec};
+use k8s_openapi::api::core::v1::{Endpoints, Node, Pod, Service};
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
 use kube::api::ListParams;
 use kube::{Api, Client};
+use serde::Serialize;
 
-pub async fn run() -> anyhow::Result<()> {
-    println!("Running cluster connectivity checks...\n");
+use kube_devops::output::{self, OutputFormat};
+use kube_devops::rbac::{self, RbacReport};
+
+use crate::commands::watch::LeaseSettings;
+use crate::commands::{client, crd};
+
+/* ============================= REPORT ============================= */
+
+/// Outcome of a single connectivity / permission check.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResult {
+    pub name: String,
+    pub ok: bool,
+    /// Not applicable to this cluster (e.g. the webhook is not installed);
+    /// counts as passed.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub skipped: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Structured result of `check` (used for `--output json|yaml`).
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckReport {
+    pub checks: Vec<CheckResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kubernetes_version: Option<String>,
+}
+
+impl CheckReport {
+    fn record(&mut self, name: &str, ok: bool, detail: Option<String>) {
+        self.checks.push(CheckResult {
+            name: name.to_string(),
+            ok,
+            skipped: false,
+            detail,
+        });
+    }
+
+    /// Record a diagnostic and print its table line.
+    fn diagnose(&mut self, format: OutputFormat, label: &str, name: &str, outcome: Outcome) {
+        begin(format, &dotted(label));
+        let (ok, skipped, status, detail) = match outcome {
+            Outcome::Pass(detail) => (true, false, "OK", detail),
+            Outcome::Skip(detail) => (true, true, "SKIP", detail),
+            Outcome::Fail(detail) => (false, false, "FAIL", detail),
+        };
+        finish(format, &format!("{status} ({detail})"));
+        self.checks.push(CheckResult {
+            name: name.to_string(),
+            ok,
+            skipped,
+            detail: Some(detail),
+        });
+    }
+
+    pub fn failed(&self) -> usize {
+        self.checks.iter().filter(|c| !c.ok).count()
+    }
+}
+
+/// Result of one deep diagnostic, with a short explanation.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Outcome {
+    Pass(String),
+    Skip(String),
+    Fail(String),
+}
+
+/// Pad a label with dots to line up with the other checks.
+fn dotted(label: &str) -> String {
+    format!(
+        "{label} {:.<width$}",
+        "",
+        width = 28usize.saturating_sub(label.len())
+    )
+}
+
+/// Print the `  Label ........ ` prefix in table mode.
+fn begin(format: OutputFormat, label: &str) {
+    if !format.is_structured() {
+        print!("  {label} ");
+    }
+}
+
+/// Print the result suffix in table mode.
+fn finish(format: OutputFormat, text: &str) {
+    if !format.is_structured() {
+        println!("{text}");
+    }
+}
+
+/* ============================= DIAGNOSTICS ============================= */
+
+/// Name of the ValidatingWebhookConfiguration `webhook install-config` writes.
+const WEBHOOK_CONFIG_NAME: &str = "kube-devops-webhook";
+
+/// Operator components serving `/metrics` over HTTP, with their port.
+const METRICS_COMPONENTS: [(&str, u16); 2] = [("watch", 8080), ("reconcile", 9090)];
+
+/// Whether a CRD is installed, established, and serves the schema this
+/// binary expects.
+fn crd_outcome(
+    existing: Option<&CustomResourceDefinition>,
+    desired: &CustomResourceDefinition,
+) -> Outcome {
+    let Some(existing) = existing else {
+        return Outcome::Fail("not installed; run `kube-devops crd install`".to_string());
+    };
+    let established = existing
+        .status
+        .as_ref()
+        .and_then(|s| s.conditions.as_ref())
+        .is_some_and(|conditions| {
+            conditions
+                .iter()
+                .any(|c| c.type_ == "Established" && c.status == "True")
+        });
+    if !established {
+        return Outcome::Fail("installed but not Established".to_string());
+    }
+    // A conversion webhook serves extra versions; only missing or outdated
+    // versions matter here.
+    let changes: Vec<String> = crd::schema_changes(existing, desired)
+        .into_iter()
+        .filter(|c| !c.ends_with("version no longer served"))
+        .collect();
+    if !changes.is_empty() {
+        return Outcome::Fail(format!(
+            "{} schema change(s) pending; run `kube-devops crd install`",
+            changes.len()
+        ));
+    }
+    Outcome::Pass("established".to_string())
+}
+
+/// Required permissions fail the check; missing optional ones only note
+/// the features that would be disabled.
+fn rbac_outcome(report: &RbacReport) -> Outcome {
+    if !report.missing_required.is_empty() {
+        return Outcome::Fail(format!("missing {}", report.missing_required.join(", ")));
+    }
+    if report.degraded_features.is_empty() {
+        return Outcome::Pass("all permissions granted".to_string());
+    }
+    let features: Vec<&str> = report.degraded_features.iter().copied().collect();
+    Outcome::Pass(format!("degraded: {} disabled", features.join(", ")))
+}
+
+/// Leader lease held by an instance that renewed it within its duration.
+pub(crate) fn lease_outcome(spec: Option<&LeaseSpec>, now: DateTime<Utc>) -> Outcome {
+    let holder = spec.and_then(|s| s.holder_identity.as_deref());
+    let Some(holder) = holder.filter(|h| !h.is_empty()) else {
+        return Outcome::Fail("no holder; no watch instance is leading".to_string());
+    };
+    let Some(renewed) = spec.and_then(|s| s.renew_time.as_ref()) else {
+        return Outcome::Fail(format!("held by {holder} but never renewed"));
+    };
+    let age = (now - renewed.0).num_seconds().max(0);
+    let duration = spec
+        .and_then(|s| s.lease_duration_seconds)
+        .unwrap_or(LeaseSettings::default().duration_seconds);
+    if age > i64::from(duration) {
+        return Outcome::Fail(format!(
+            "held by {holder} but not renewed for {age}s (duration {duration}s)"
+        ));
+    }
+    Outcome::Pass(format!("held by {holder}, renewed {age}s ago"))
+}
+
+/// Problems with the configuration itself, before looking at its service.
+fn webhook_config_problems(config: &ValidatingWebhookConfiguration) -> Vec<String> {
+    let webhooks = config.webhooks.as_deref().unwrap_or_default();
+    if webhooks.is_empty() {
+        return vec!["no webhooks configured".to_string()];
+    }
+    let mut problems = Vec::new();
+    for webhook in webhooks {
+        if webhook
+            .client_config
+            .ca_bundle
+            .as_ref()
+            .is_none_or(|ca| ca.0.is_empty())
+        {
+            problems.push(format!("{}: empty caBundle", webhook.name));
+        }
+        if webhook.client_config.service.is_none() && webhook.client_config.url.is_none() {
+            problems.push(format!("{}: no service or url", webhook.name));
+        }
+    }
+    problems
+}
+
+/// Number of metric families in a Prometheus text exposition.
+fn metric_families(body: &str) -> usize {
+    body.lines().filter(|l| l.starts_with("# TYPE ")).count()
+}
+
+async fn check_webhook(client: &Client) -> Outcome {
+    let configs: Api<ValidatingWebhookConfiguration> = Api::all(client.clone());
+    let config = match configs.get_opt(WEBHOOK_CONFIG_NAME).await {
+        Ok(Some(config)) => config,
+        Ok(None) => return Outcome::Skip("not installed".to_string()),
+        Err(e) => return Outcome::Fail(e.to_string()),
+    };
+    let problems = webhook_config_problems(&config);
+    if !problems.is_empty() {
+        return Outcome::Fail(problems.join("; "));
+    }
+
+    let webhooks = config.webhooks.unwrap_or_default();
+    let mut ready = 0;
+    for service in webhooks
+        .iter()
+        .filter_map(|w| w.client_config.service.as_ref())
+    {
+        let services: Api<Service> = Api::namespaced(client.clone(), &service.namespace);
+        match services.get_opt(&service.name).await {
+            Ok(Some(_)) => {}
+            Ok(None) => {
+                return Outcome::Fail(format!(
+                    "service {}/{} not found",
+                    service.namespace, service.name
+                ));
+            }
+            Err(e) => return Outcome::Fail(e.to_string()),
+        }
+        let endpoints: Api<Endpoints> = Api::namespaced(client.clone(), &service.namespace);
+        let addresses = match endpoints.get_opt(&service.name).await {
+            Ok(endpoints) => endpoints
+                .and_then(|e| e.subsets)
+                .unwrap_or_default()
+                .iter()
+                .map(|s| s.addresses.as_ref().map_or(0, Vec::len))
+                .sum::<usize>(),
+            Err(e) => return Outcome::Fail(e.to_string()),
+        };
+        if addresses == 0 {
+            return Outcome::Fail(format!(
+                "service {}/{} has no ready endpoints",
+                service.namespace, service.name
+            ));
+        }
+        ready += addresses;
+    }
+    let failure_policy = webhooks
+        .first()
+        .and_then(|w| w.failure_policy.as_deref())
+        .unwrap_or("Fail");
+    Outcome::Pass(format!(
+        "failurePolicy {failure_policy}, {ready} ready endpoint(s)"
+    ))
+}
+
+async fn check_lease(client: &Client, namespace: &str) -> Outcome {
+    let name = LeaseSettings::default().name;
+    let leases: Api<Lease> = Api::namespaced(client.clone(), namespace);
+    match leases.get_opt(&name).await {
+        Ok(Some(lease)) => lease_outcome(lease.spec.as_ref(), Utc::now()),
+        Ok(None) => Outcome::Skip(format!(
+            "{namespace}/{name} not found; watch not running or read-only"
+        )),
+        Err(e) => Outcome::Fail(e.to_string()),
+    }
+}
+
+/// Scrape `/metrics` of a running pod of `component` through the API
+/// server's pod proxy.
+async fn check_metrics(client: &Client, namespace: &str, component: &str, port: u16) -> Outcome {
+    let pods: Api<Pod> = Api::namespaced(client.clone(), namespace);
+    let selector =
+        format!("app.kubernetes.io/name=kube-devops,app.kubernetes.io/component={component}");
+    let list = match pods.list(&ListParams::default().labels(&selector)).await {
+        Ok(list) => list,
+        Err(e) => return Outcome::Fail(e.to_string()),
+    };
+    let running = list
+        .items
+        .iter()
+        .find(|p| p.status.as_ref().and_then(|s| s.phase.as_deref()) == Some("Running"));
+    let Some(pod_name) = running.and_then(|p| p.metadata.name.as_deref()) else {
+        return Outcome::Skip(format!("no running {component} pod in {namespace}"));
+    };
+
+    let path = format!("/api/v1/namespaces/{namespace}/pods/{pod_name}:{port}/proxy/metrics");
+    let request = match hyper::http::Request::get(&path).body(Vec::new()) {
+        Ok(request) => request,
+        Err(e) => return Outcome::Fail(e.to_string()),
+    };
+    match client.request_text(request).await {
+        Ok(body) if metric_families(&body) > 0 => Outcome::Pass(format!(
+            "{pod_name}: {} metric families",
+            metric_families(&body)
+        )),
+        Ok(_) => Outcome::Fail(format!("{pod_name}: /metrics returned no metrics")),
+        Err(e) => Outcome::Fail(format!("{pod_name}: {e}")),
+    }
+}
+
+/// Deep checks for `check --verbose`: CRDs, RBAC for every component,
+/// webhook configuration, leader lease, and metrics endpoints.
+async fn diagnose(
+    client: &Client,
+    operator_namespace: &str,
+    format: OutputFormat,
+    report: &mut CheckReport,
+) {
+    // CRDs
+    let crds: Api<CustomResourceDefinition> = Api::all(client.clone());
+    for desired in crd::all_crds(None) {
+        let name = desired.metadata.name.clone().unwrap_or_default();
+        let kind = desired.spec.names.kind.clone();
+        let outcome = match crds.get_opt(&name).await {
+            Ok(existing) => crd_outcome(existing.as_ref(), &desired),
+            Err(e) => Outcome::Fail(e.to_string()),
+        };
+        report.diagnose(
+            format,
+            &format!("CRD {kind}"),
+            &format!("crd:{kind}"),
+            outcome,
+        );
+    }
+
+    // RBAC of the current identity, per component
+    for (component, permissions) in [
+        ("watch", rbac::watch_permissions(false, None)),
+        ("reconcile", rbac::reconcile_permissions(false)),
+        ("webhook", rbac::webhook_permissions()),
+    ] {
+        let outcome = match rbac::verify(client, &permissions).await {
+            Ok(rbac_report) => rbac_outcome(&rbac_report),
+            Err(e) => Outcome::Fail(format!("{e:#}")),
+        };
+        report.diagnose(
+            format,
+            &format!("RBAC ({component})"),
+            &format!("rbac:{component}"),
+            outcome,
+        );
+    }
+
+    let outcome = check_webhook(client).await;
+    report.diagnose(
+        format,
+        "Webhook configuration",
+        "webhookConfiguration",
+        outcome,
+    );
+
+    let outcome = check_lease(client, operator_namespace).await;
+    report.diagnose(format, "Leader lease", "leaderLease", outcome);
+
+    for (component, port) in METRICS_COMPONENTS {
+        let outcome = check_metrics(client, operator_namespace, component, port).await;
+        report.diagnose(
+            format,
+            &format!("Metrics ({component})"),
+            &format!("metrics:{component}"),
+            outcome,
+        );
+    }
+}
+
+/* ============================= COMMAND ============================= */
+
+/// Options for `check`.
+#[derive(Debug, Clone)]
+pub struct CheckOptions {
+    /// Run the deep diagnostics and exit non-zero when any check fails.
+    pub verbose: bool,
+    /// Namespace the operator is deployed to (lease, metrics pods).
+    pub operator_namespace: String,
+}
+
+pub async fn run(format: OutputFormat, options: &CheckOptions) -> anyhow::Result<()> {
+    let table = !format.is_structured();
+    let mut report = CheckReport::default();
+
+    if table {
+        println!("Running cluster connectivity checks...\n");
+    }
 
     // 1. Build Kubernetes client from kubeconfig
-    print!("  Kubeconfig .................. ");
-    let client = match Client::try_default().await {
+    begin(format, "Kubeconfig ..................");
+    let client = match client::connect().await {
         Ok(c) => {
-            println!("OK");
+            finish(format, "OK");
+            report.record("kubeconfig", true, None);
             c
         }
         Err(e) => {
-            println!("FAIL");
-            anyhow::bail!("Cannot load kubeconfig: {}", e);
+            finish(format, "FAIL");
+            report.record("kubeconfig", false, Some(format!("{e:#}")));
+            if !table {
+                output::print_structured(&report, format)?;
+            }
+            anyhow::bail!("Cannot load kubeconfig: {e:#}");
         }
     };
 
     // 2. Verify actual cluster connectivity by fetching server version
-    print!("  Cluster connection .......... ");
+    begin(format, "Cluster connection ..........");
     let version = match client.apiserver_version().await {
         Ok(v) => {
-            println!("OK (v{}.{})", v.major, v.minor);
+            finish(format, &format!("OK (v{}.{})", v.major, v.minor));
+            report.record("clusterConnection", true, None);
             Some(v)
         }
         Err(e) => {
-            println!("FAIL");
-            println!("\n  Error: {}", e);
-            println!("  Hint:  Is the cluster running? Check with: kubectl cluster-info\n");
+            finish(format, "FAIL");
+            report.record("clusterConnection", false, Some(e.to_string()));
+            if table {
+                println!("\n  Error: {}", e);
+                println!("  Hint:  Is the cluster running? Check with: kubectl cluster-info\n");
+            } else {
+                output::print_structured(&report, format)?;
+            }
+            if options.verbose {
+                anyhow::bail!("Cannot reach the cluster: {e}");
+            }
             return Ok(());
         }
     };
 
     // 3. List pods permission
-    print!("  List pods permission ........ ");
+    begin(format, "List pods permission ........");
     let pods: Api<Pod> = Api::all(client.clone());
     match pods.list(&ListParams::default().limit(1)).await {
-        Ok(_) => println!("OK"),
-        Err(e) => println!("FAIL ({})", e),
+        Ok(_) => {
+            finish(format, "OK");
+            report.record("listPods", true, None);
+        }
+        Err(e) => {
+            finish(format, &format!("FAIL ({})", e));
+            report.record("listPods", false, Some(e.to_string()));
+        }
     }
 
     // 4. List nodes permission
-    print!("  List nodes permission ....... ");
+    begin(format, "List nodes permission .......");
     let nodes: Api<Node> = Api::all(client.clone());
     match nodes.list(&ListParams::default()).await {
         Ok(node_list) => {
             let count = node_list.items.len();
-            println!("OK ({} nodes)", count);
+            finish(format, &format!("OK ({} nodes)", count));
+            report.record("listNodes", true, Some(format!("{count} nodes")));
+        }
+        Err(e) => {
+            finish(format, &format!("FAIL ({})", e));
+            report.record("listNodes", false, Some(e.to_string()));
         }
-        Err(e) => println!("FAIL ({})", e),
     }
 
     // 5. Kubernetes version (already fetched above)
     if let Some(v) = version {
-        println!("\n  Kubernetes version: {}.{}", v.major, v.minor);
+        report.kubernetes_version = Some(format!("{}.{}", v.major, v.minor));
+        if table {
+            println!("\n  Kubernetes version: {}.{}", v.major, v.minor);
+        }
+    }
+
+    if options.verbose {
+        if table {
+            println!("\nRunning diagnostics...\n");
+        }
+        diagnose(&client, &options.operator_namespace, format, &mut report).await;
     }
 
-    println!("\nAll checks completed.");
+    if !table {
+        output::print_structured(&report, format)?;
+    } else if options.verbose {
+        println!(
+            "\n{}/{} checks passed.",
+            report.checks.len() - report.failed(),
+            report.checks.len()
+        );
+    } else {
+        println!("\nAll checks completed.");
+    }
+    if options.verbose && report.failed() > 0 {
+        anyhow::bail!("{} check(s) failed", report.failed());
+    }
     Ok(())
 }
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_report_serializes() {
+        let mut report = CheckReport::default();
+        report.record("kubeconfig", true, None);
+        report.record("listPods", false, Some("forbidden".to_string()));
+        report.kubernetes_version = Some("1.29".to_string());
+
+        let json = output::render(&report, OutputFormat::Json).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(v["checks"][0]["name"], "kubeconfig");
+        assert_eq!(v["checks"][0]["ok"], true);
+        assert!(v["checks"][0].get("detail").is_none());
+        assert_eq!(v["checks"][1]["detail"], "forbidden");
+        assert_eq!(v["kubernetesVersion"], "1.29");
+    }
+
+    #[test]
+    fn test_skipped_and_failed() {
+        let mut report = CheckReport::default();
+        report.diagnose(
+            OutputFormat::Json,
+            "Leader lease",
+            "leaderLease",
+            Outcome::Skip("not found".to_string()),
+        );
+        report.diagnose(
+            OutputFormat::Json,
+            "Metrics (watch)",
+            "metrics:watch",
+            Outcome::Fail("refused".to_string()),
+        );
+        assert_eq!(report.failed(), 1);
+        let v: serde_json::Value =
+            serde_json::from_str(&output::render(&report, OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(v["checks"][0]["skipped"], true);
+        assert_eq!(v["checks"][0]["ok"], true);
+        assert!(v["checks"][1].get("skipped").is_none());
+    }
+
+    #[test]
+    fn test_dotted_aligns_with_basic_checks() {
+        assert_eq!(dotted("Kubeconfig"), "Kubeconfig ..................");
+        assert_eq!(dotted("CRD ClusterDevOpsPolicy").len(), 29);
+    }
+
+    // ── CRDs ──
+
+    fn established(mut crd: CustomResourceDefinition, status: &str) -> CustomResourceDefinition {
+        use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+            CustomResourceDefinitionCondition, CustomResourceDefinitionStatus,
+        };
+        crd.status = Some(CustomResourceDefinitionStatus {
+            conditions: Some(vec![CustomResourceDefinitionCondition {
+                type_: "Established".to_string(),
+                status: status.to_string(),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        });
+        crd
+    }
+
+    #[test]
+    fn test_crd_outcome() {
+        let [desired, ..] = crd::all_crds(None);
+        assert!(
+            matches!(crd_outcome(None, &desired), Outcome::Fail(d) if d.contains("not installed"))
+        );
+        let pending = established(desired.clone(), "False");
+        assert!(
+            matches!(crd_outcome(Some(&pending), &desired), Outcome::Fail(d) if d.contains("not Established"))
+        );
+        let installed = established(desired.clone(), "True");
+        assert_eq!(
+            crd_outcome(Some(&installed), &desired),
+            Outcome::Pass("established".to_string())
+        );
+
+        let mut outdated = installed.clone();
+        let schema = outdated.spec.versions[0].schema.as_mut().unwrap();
+        let spec = schema
+            .open_api_v3_schema
+            .as_mut()
+            .unwrap()
+            .properties
+            .as_mut()
+            .unwrap();
+        spec.get_mut("spec")
+            .unwrap()
+            .properties
+            .as_mut()
+            .unwrap()
+            .remove("forbidLatestTag");
+        assert!(
+            matches!(crd_outcome(Some(&outdated), &desired), Outcome::Fail(d) if d.contains("1 schema change(s)"))
+        );
+    }
+
+    // ── RBAC ──
+
+    #[test]
+    fn test_rbac_outcome() {
+        let mut report = RbacReport::default();
+        assert_eq!(
+            rbac_outcome(&report),
+            Outcome::Pass("all permissions granted".to_string())
+        );
+        report.degraded_features.insert(rbac::FEATURE_EVENTS);
+        assert_eq!(
+            rbac_outcome(&report),
+            Outcome::Pass("degraded: events disabled".to_string())
+        );
+        report.missing_required.push("list pods".to_string());
+        assert_eq!(
+            rbac_outcome(&report),
+            Outcome::Fail("missing list pods".to_string())
+        );
+    }
+
+    // ── Lease ──
+
+    #[test]
+    fn test_lease_outcome() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::MicroTime;
+        let now: DateTime<Utc> = "2026-03-01T12:00:00Z".parse().unwrap();
+        let lease = |holder: &str, age: i64| LeaseSpec {
+            holder_identity: Some(holder.to_string()),
+            lease_duration_seconds: Some(15),
+            renew_time: Some(MicroTime(now - chrono::Duration::seconds(age))),
+            ..Default::default()
+        };
+        assert_eq!(
+            lease_outcome(Some(&lease("watch-abc", 4)), now),
+            Outcome::Pass("held by watch-abc, renewed 4s ago".to_string())
+        );
+        assert_eq!(
+            lease_outcome(Some(&lease("watch-abc", 60)), now),
+            Outcome::Fail("held by watch-abc but not renewed for 60s (duration 15s)".to_string())
+        );
+        assert!(
+            matches!(lease_outcome(Some(&lease("", 1)), now), Outcome::Fail(d) if d.starts_with("no holder"))
+        );
+        assert!(matches!(lease_outcome(None, now), Outcome::Fail(_)));
+    }
+
+    // ── Webhook and metrics ──
+
+    #[test]
+    fn test_webhook_config_problems() {
+        use k8s_openapi::ByteString;
+        use k8s_openapi::api::admissionregistration::v1::{
+            ServiceReference, ValidatingWebhook, WebhookClientConfig,
+        };
+        let webhook = |ca: &[u8]| ValidatingWebhook {
+            name: "validate.devops.stochastic.io".to_string(),
+            client_config: WebhookClientConfig {
+                ca_bundle: Some(ByteString(ca.to_vec())),
+                service: Some(ServiceReference {
+                    name: "kube-devops-webhook".to_string(),
+                    namespace: "kube-devops".to_string(),
+                    ..Default::default()
+                }),
+                url: None,
+            },
+            ..Default::default()
+        };
+        let config = |webhooks| ValidatingWebhookConfiguration {
+            webhooks: Some(webhooks),
+            ..Default::default()
+        };
+        assert!(webhook_config_problems(&config(vec![webhook(b"ca")])).is_empty());
+        assert_eq!(
+            webhook_config_problems(&config(vec![webhook(b"")])),
+            ["validate.devops.stochastic.io: empty caBundle"]
+        );
+        assert_eq!(
+            webhook_config_problems(&config(Vec::new())),
+            ["no webhooks configured"]
+        );
+    }
+
+    #[test]
+    fn test_metric_families() {
+        let body = "# HELP a A\n# TYPE a gauge\na 1\n# HELP b B\n# TYPE b counter\nb 2\n";
+        assert_eq!(metric_families(body), 2);
+        assert_eq!(metric_families(""), 0);
+    }
+}
@@ -0,0 +1,640 @@
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context as TaskContext, Poll};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use hyper::http::{Method, Request, Response, StatusCode, header};
+use hyper::{Body, body::Bytes};
+use kube::client::ClientBuilder;
+use kube::config::KubeConfigOptions;
+use kube::{Client, Config};
+use kube_devops::multi_cluster::read_kubeconfig;
+use tower_layer::Layer;
+use tower_service::Service;
+use tracing::warn;
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/* ============================= SETTINGS ============================= */
+
+/// First retry delay; doubled per attempt.
+const RETRY_INITIAL: Duration = Duration::from_millis(200);
+/// Cap on the retry delay, also applied to `Retry-After`.
+const RETRY_MAX: Duration = Duration::from_secs(10);
+
+/// Timeouts, retries, and circuit breaker of every API client, see
+/// `Settings::client`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ClientSettings {
+    pub(crate) connect_timeout: Duration,
+    /// Time allowed until response headers arrive. Watch streams are not cut
+    /// off once they have started.
+    pub(crate) request_timeout: Duration,
+    /// Retries of a request answered with 429 or 5xx, or failing without a
+    /// response.
+    pub(crate) max_retries: u32,
+    /// Consecutive failed attempts that open the circuit breaker.
+    pub(crate) breaker_threshold: u32,
+    /// How long an open breaker fails requests before letting one through.
+    pub(crate) breaker_cooldown: Duration,
+}
+
+impl Default for ClientSettings {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Duration::from_secs(10),
+            request_timeout: Duration::from_secs(30),
+            max_retries: 3,
+            breaker_threshold: 5,
+            breaker_cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+/* ============================= CONNECTION ============================= */
+
+/// Cluster selection from the global `--kubeconfig`, `--context`, and
+/// `--namespace` flags.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct Connection {
+    /// Kubeconfig file to read instead of `KUBECONFIG` / `~/.kube/config`.
+    pub(crate) kubeconfig: Option<PathBuf>,
+    /// Kubeconfig context to use instead of the current one.
+    pub(crate) context: Option<String>,
+    /// Default namespace of the client; commands take their namespace from
+    /// the same flag.
+    pub(crate) namespace: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct Options {
+    connection: Connection,
+    settings: ClientSettings,
+}
+
+static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+fn options() -> &'static Options {
+    OPTIONS.get_or_init(Options::default)
+}
+
+/// Record the connection flags and client settings for every later
+/// `connect`. Called once from `main` before a command runs.
+pub(crate) fn configure(connection: Connection, settings: ClientSettings) {
+    let _ = OPTIONS.set(Options {
+        connection,
+        settings,
+    });
+}
+
+/// Connect to the cluster selected by the global flags. Without any of them
+/// this is `Client::try_default()` (in-cluster config, or the current
+/// kubeconfig context) with the configured timeouts, retries, and breaker.
+pub(crate) async fn connect() -> Result<Client> {
+    let options = options();
+    build(options.connection.config().await?, &options.settings)
+}
+
+/// Connect to `context` of the `--kubeconfig` file, for multi-cluster
+/// commands that pick their own contexts.
+pub(crate) async fn connect_context(context: &str) -> Result<Client> {
+    let options = options();
+    let connection = Connection {
+        kubeconfig: options.connection.kubeconfig.clone(),
+        context: Some(context.to_string()),
+        namespace: None,
+    };
+    build(connection.config().await?, &options.settings)
+}
+
+/// The `--kubeconfig` path, for listing the contexts multi-cluster commands
+/// choose from.
+pub(crate) fn kubeconfig_path() -> Option<&'static Path> {
+    options().connection.kubeconfig.as_deref()
+}
+
+fn build(mut config: Config, settings: &ClientSettings) -> Result<Client> {
+    config.connect_timeout = Some(settings.connect_timeout);
+    Ok(ClientBuilder::try_from(config)?
+        .with_layer(&ResilienceLayer::new(settings.clone()))
+        .build())
+}
+
+impl Connection {
+    async fn config(&self) -> Result<Config> {
+        let mut config = match (&self.kubeconfig, &self.context) {
+            (None, None) => Config::infer().await?,
+            (path, context) => {
+                let options = KubeConfigOptions {
+                    context: context.clone(),
+                    ..Default::default()
+                };
+                let kubeconfig = read_kubeconfig(path.as_deref())?;
+                Config::from_custom_kubeconfig(kubeconfig, &options)
+                    .await
+                    .with_context(|| match context {
+                        Some(context) => format!("Invalid kubeconfig context '{context}'"),
+                        None => "Invalid kubeconfig".to_string(),
+                    })?
+            }
+        };
+        if let Some(namespace) = &self.namespace {
+            config.default_namespace = namespace.clone();
+        }
+        Ok(config)
+    }
+}
+
+/* ============================= CIRCUIT BREAKER ============================= */
+
+/// Opens after `breaker_threshold` consecutive failed attempts (transport
+/// errors, timeouts, 5xx) and fails requests without sending them until the
+/// cooldown has passed. The next attempt then decides: a success closes the
+/// breaker, a failure opens it for another cooldown.
+#[derive(Debug, Default)]
+struct Breaker {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+impl Breaker {
+    /// `Err` with the remaining cooldown while the breaker is open.
+    fn check(&self, now: Instant) -> Result<(), Duration> {
+        match self.open_until {
+            Some(until) if until > now => Err(until - now),
+            _ => Ok(()),
+        }
+    }
+
+    /// Record one attempt; returns `true` when it opened the breaker.
+    fn record(&mut self, ok: bool, now: Instant, settings: &ClientSettings) -> bool {
+        if ok {
+            self.failures = 0;
+            self.open_until = None;
+            return false;
+        }
+        self.failures = self.failures.saturating_add(1);
+        if self.failures >= settings.breaker_threshold {
+            self.open_until = Some(now + settings.breaker_cooldown);
+            return true;
+        }
+        false
+    }
+}
+
+/// Returned while the circuit breaker is open.
+#[derive(Debug)]
+struct CircuitOpen(Duration);
+
+impl std::fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Kubernetes API unavailable, circuit breaker open for another {}s",
+            self.0.as_secs().max(1)
+        )
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
+
+/// Returned when no response headers arrived within `request_timeout`.
+#[derive(Debug)]
+struct RequestTimeout(Duration);
+
+impl std::fmt::Display for RequestTimeout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Kubernetes API request timed out after {}s",
+            self.0.as_secs()
+        )
+    }
+}
+
+impl std::error::Error for RequestTimeout {}
+
+/* ============================= RETRY LAYER ============================= */
+
+/// Whether an attempt that ended with `status` (`None`: no response) may be
+/// sent again. 429 and 503 mean the server did not process the request;
+/// other 5xx and transport errors are only retried when repeating the
+/// request is harmless, i.e. not for POST.
+fn retryable(method: &Method, status: Option<StatusCode>) -> bool {
+    match status {
+        Some(StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE) => true,
+        Some(status) => status.is_server_error() && method != Method::POST,
+        None => method != Method::POST,
+    }
+}
+
+/// Exponential backoff before retry number `attempt + 1`.
+fn retry_delay(attempt: u32) -> Duration {
+    let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+    RETRY_INITIAL.saturating_mul(factor).min(RETRY_MAX)
+}
+
+/// The `Retry-After` seconds of a throttled response, capped at `RETRY_MAX`.
+fn retry_after<B>(response: &Response<B>) -> Option<Duration> {
+    let seconds: u64 = response
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds).min(RETRY_MAX))
+}
+
+/// Wraps the kube client's service stack with the request timeout, retries,
+/// and circuit breaker of `ClientSettings`.
+struct ResilienceLayer {
+    settings: ClientSettings,
+    breaker: Arc<Mutex<Breaker>>,
+}
+
+impl ResilienceLayer {
+    fn new(settings: ClientSettings) -> Self {
+        Self {
+            settings,
+            breaker: Default::default(),
+        }
+    }
+}
+
+impl<S> Layer<S> for ResilienceLayer {
+    type Service = Resilient<S>;
+
+    fn layer(&self, inner: S) -> Resilient<S> {
+        Resilient {
+            inner: Arc::new(tokio::sync::Mutex::new(inner)),
+            settings: self.settings.clone(),
+            breaker: self.breaker.clone(),
+        }
+    }
+}
+
+struct Resilient<S> {
+    /// Shared so the retry loop can call the service again after `call`
+    /// returned.
+    inner: Arc<tokio::sync::Mutex<S>>,
+    settings: ClientSettings,
+    breaker: Arc<Mutex<Breaker>>,
+}
+
+impl<S, B> Service<Request<Body>> for Resilient<S>
+where
+    S: Service<Request<Body>, Response = Response<B>> + Send + 'static,
+    S::Future: Send + 'static,
+    S::Error: Into<BoxError>,
+    B: Send + 'static,
+{
+    type Response = Response<B>;
+    type Error = BoxError;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<B>, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut TaskContext<'_>) -> Poll<Result<(), BoxError>> {
+        // The inner service is polled before each attempt
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        let inner = self.inner.clone();
+        let settings = self.settings.clone();
+        let breaker = self.breaker.clone();
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+            // Buffered so the request can be sent more than once
+            let body = hyper::body::to_bytes(body).await?;
+            let mut attempt = 0;
+            loop {
+                let now = Instant::now();
+                if let Err(remaining) = lock(&breaker).check(now) {
+                    return Err(CircuitOpen(remaining).into());
+                }
+                let request = rebuild(&parts, body.clone());
+                let result =
+                    match tokio::time::timeout(settings.request_timeout, send(&inner, request))
+                        .await
+                    {
+                        Ok(result) => result,
+                        Err(_) => Err(RequestTimeout(settings.request_timeout).into()),
+                    };
+                let status = result.as_ref().ok().map(|response| response.status());
+                let failed = status.is_none_or(|status| status.is_server_error());
+                if lock(&breaker).record(!failed, Instant::now(), &settings) {
+                    warn!(
+                        method = %parts.method,
+                        path = parts.uri.path(),
+                        cooldown_secs = settings.breaker_cooldown.as_secs(),
+                        "api_circuit_breaker_opened"
+                    );
+                }
+                if attempt >= settings.max_retries || !retryable(&parts.method, status) {
+                    return result;
+                }
+                let delay = match &result {
+                    Ok(response) => retry_after(response).unwrap_or(retry_delay(attempt)),
+                    Err(_) => retry_delay(attempt),
+                };
+                warn!(
+                    method = %parts.method,
+                    path = parts.uri.path(),
+                    status = status.map(|s| s.as_u16()),
+                    error = result.as_ref().err().map(|e| e.to_string()),
+                    attempt = attempt + 1,
+                    delay_ms = delay.as_millis() as u64,
+                    "api_request_retry"
+                );
+                drop(result);
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        })
+    }
+}
+
+fn lock(breaker: &Mutex<Breaker>) -> std::sync::MutexGuard<'_, Breaker> {
+    breaker.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A copy of the original request for one attempt.
+fn rebuild(parts: &hyper::http::request::Parts, body: Bytes) -> Request<Body> {
+    let mut request = Request::new(Body::from(body));
+    *request.method_mut() = parts.method.clone();
+    *request.uri_mut() = parts.uri.clone();
+    *request.version_mut() = parts.version;
+    *request.headers_mut() = parts.headers.clone();
+    request
+}
+
+async fn send<S, B>(
+    inner: &tokio::sync::Mutex<S>,
+    request: Request<Body>,
+) -> Result<Response<B>, BoxError>
+where
+    S: Service<Request<Body>, Response = Response<B>>,
+    S::Error: Into<BoxError>,
+{
+    let response = {
+        let mut service = inner.lock().await;
+        futures::future::poll_fn(|cx| service.poll_ready(cx))
+            .await
+            .map_err(Into::into)?;
+        service.call(request)
+    };
+    response.await.map_err(Into::into)
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KUBECONFIG: &str = r#"
+apiVersion: v1
+kind: Config
+current-context: dev
+clusters:
+  - name: dev
+    cluster:
+      server: https://dev.example.com:6443
+  - name: prod
+    cluster:
+      server: https://prod.example.com:6443
+contexts:
+  - name: dev
+    context:
+      cluster: dev
+      user: admin
+  - name: prod
+    context:
+      cluster: prod
+      user: admin
+      namespace: payments
+users:
+  - name: admin
+    user:
+      token: secret
+"#;
+
+    fn kubeconfig_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "kube-devops-kubeconfig-{name}-{}.yaml",
+            std::process::id()
+        ));
+        std::fs::write(&path, KUBECONFIG).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_kubeconfig_uses_current_context() {
+        let path = kubeconfig_file("current");
+        let connection = Connection {
+            kubeconfig: Some(path.clone()),
+            ..Default::default()
+        };
+        let config = connection.config().await;
+        std::fs::remove_file(&path).unwrap();
+        let config = config.unwrap();
+        assert_eq!(config.cluster_url.host(), Some("dev.example.com"));
+        assert_eq!(config.default_namespace, "default");
+    }
+
+    #[tokio::test]
+    async fn test_context_and_namespace_override() {
+        let path = kubeconfig_file("override");
+        let prod = Connection {
+            kubeconfig: Some(path.clone()),
+            context: Some("prod".to_string()),
+            namespace: None,
+        };
+        let config = prod.config().await.unwrap();
+        assert_eq!(config.cluster_url.host(), Some("prod.example.com"));
+        assert_eq!(config.default_namespace, "payments");
+
+        let scoped = Connection {
+            namespace: Some("web".to_string()),
+            ..prod.clone()
+        };
+        assert_eq!(scoped.config().await.unwrap().default_namespace, "web");
+
+        let missing = Connection {
+            context: Some("staging".to_string()),
+            ..prod
+        };
+        let err = missing.config().await.unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.to_string().contains("'staging'"), "{err:#}");
+    }
+
+    // ── Retries ──
+
+    #[test]
+    fn test_retryable_statuses() {
+        let get = Method::GET;
+        let post = Method::POST;
+        assert!(retryable(&get, Some(StatusCode::TOO_MANY_REQUESTS)));
+        assert!(retryable(&post, Some(StatusCode::TOO_MANY_REQUESTS)));
+        assert!(retryable(&post, Some(StatusCode::SERVICE_UNAVAILABLE)));
+        assert!(retryable(&get, Some(StatusCode::BAD_GATEWAY)));
+        assert!(!retryable(&post, Some(StatusCode::INTERNAL_SERVER_ERROR)));
+        assert!(!retryable(&get, Some(StatusCode::NOT_FOUND)));
+        assert!(!retryable(&get, Some(StatusCode::OK)));
+        assert!(retryable(&Method::PATCH, None));
+        assert!(!retryable(&post, None));
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_max() {
+        assert_eq!(retry_delay(0), Duration::from_millis(200));
+        assert_eq!(retry_delay(1), Duration::from_millis(400));
+        assert_eq!(retry_delay(3), Duration::from_millis(1600));
+        assert_eq!(retry_delay(10), RETRY_MAX);
+        assert_eq!(retry_delay(u32::MAX), RETRY_MAX);
+    }
+
+    #[test]
+    fn test_retry_after_header() {
+        let throttled = |value: &str| {
+            Response::builder()
+                .status(StatusCode::TOO_MANY_REQUESTS)
+                .header(header::RETRY_AFTER, value)
+                .body(())
+                .unwrap()
+        };
+        assert_eq!(retry_after(&throttled("2")), Some(Duration::from_secs(2)));
+        assert_eq!(retry_after(&throttled("3600")), Some(RETRY_MAX));
+        assert_eq!(
+            retry_after(&throttled("Wed, 21 Oct 2015 07:28:00 GMT")),
+            None
+        );
+        assert_eq!(retry_after(&Response::new(())), None);
+    }
+
+    // ── Circuit breaker ──
+
+    #[test]
+    fn test_breaker_opens_and_recovers() {
+        let settings = ClientSettings {
+            breaker_threshold: 2,
+            ..Default::default()
+        };
+        let now = Instant::now();
+        let mut breaker = Breaker::default();
+        assert!(!breaker.record(false, now, &settings));
+        assert!(breaker.check(now).is_ok());
+        assert!(breaker.record(false, now, &settings));
+        assert_eq!(breaker.check(now), Err(settings.breaker_cooldown));
+
+        // After the cooldown one failure re-opens it, one success closes it
+        let later = now + settings.breaker_cooldown;
+        assert!(breaker.check(later).is_ok());
+        assert!(breaker.record(false, later, &settings));
+        assert!(breaker.check(later).is_err());
+        breaker.record(true, later + settings.breaker_cooldown, &settings);
+        assert_eq!(breaker.failures, 0);
+        assert!(breaker.check(later).is_ok());
+    }
+
+    /// Answers each request with the next scripted status.
+    struct Scripted {
+        statuses: Arc<Mutex<Vec<StatusCode>>>,
+        bodies: Arc<Mutex<Vec<Bytes>>>,
+    }
+
+    impl Service<Request<Body>> for Scripted {
+        type Response = Response<Body>;
+        type Error = BoxError;
+        type Future = Pin<Box<dyn Future<Output = Result<Response<Body>, BoxError>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut TaskContext<'_>) -> Poll<Result<(), BoxError>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<Body>) -> Self::Future {
+            let status = self.statuses.lock().unwrap().remove(0);
+            let bodies = self.bodies.clone();
+            Box::pin(async move {
+                let body = hyper::body::to_bytes(request.into_body()).await?;
+                bodies.lock().unwrap().push(body);
+                Ok(Response::builder().status(status).body(Body::empty())?)
+            })
+        }
+    }
+
+    fn scripted(
+        statuses: &[u16],
+        settings: ClientSettings,
+    ) -> (Resilient<Scripted>, Arc<Mutex<Vec<Bytes>>>) {
+        let bodies: Arc<Mutex<Vec<Bytes>>> = Default::default();
+        let inner = Scripted {
+            statuses: Arc::new(Mutex::new(
+                statuses
+                    .iter()
+                    .map(|s| StatusCode::from_u16(*s).unwrap())
+                    .collect(),
+            )),
+            bodies: bodies.clone(),
+        };
+        (ResilienceLayer::new(settings).layer(inner), bodies)
+    }
+
+    fn request(method: Method, body: &'static str) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri("/api/v1/namespaces/default/pods")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_layer_retries_until_success() {
+        let (mut service, bodies) = scripted(&[503, 500, 200], ClientSettings::default());
+        let response = service.call(request(Method::PATCH, "{}")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bodies = bodies.lock().unwrap();
+        assert_eq!(bodies.len(), 3);
+        assert!(bodies.iter().all(|b| b.as_ref() == b"{}"));
+    }
+
+    #[tokio::test]
+    async fn test_layer_does_not_repeat_failed_posts() {
+        let (mut service, bodies) = scripted(&[500, 200], ClientSettings::default());
+        let response = service.call(request(Method::POST, "{}")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(bodies.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_layer_fails_fast_while_breaker_open() {
+        let settings = ClientSettings {
+            max_retries: 0,
+            breaker_threshold: 2,
+            ..Default::default()
+        };
+        let (mut service, bodies) = scripted(&[500, 502, 200], settings);
+        for _ in 0..2 {
+            let response = service.call(request(Method::GET, "")).await.unwrap();
+            assert!(response.status().is_server_error());
+        }
+        let err = service.call(request(Method::GET, "")).await.unwrap_err();
+        assert!(err.to_string().contains("circuit breaker open"), "{err}");
+        assert_eq!(bodies.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_missing_kubeconfig_names_path() {
+        let connection = Connection {
+            kubeconfig: Some(PathBuf::from("/nonexistent/kubeconfig")),
+            ..Default::default()
+        };
+        let err = connection.config().await.unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/kubeconfig"));
+    }
+}
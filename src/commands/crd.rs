@@ -1,39 +1,459 @@
-use anyhow::Result;
-use kube::CustomResourceExt;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
+use std::path::Path;
+use std::time::Duration;
 
-use kube_devops::crd::{DevOpsPolicy, PolicyAuditResult};
+use anyhow::{Context, Result};
+use k8s_openapi::NamespaceResourceScope;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::api::{
+    Api, ApiResource, DeleteParams, DynamicObject, GroupVersionKind, ListParams, Patch,
+    PatchParams, PostParams,
+};
+use kube::{Client, CustomResourceExt, Resource, ResourceExt};
+use kube_runtime::wait::{await_condition, conditions};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
 
-/// Print both CRD YAMLs to stdout for `kubectl apply -f`.
-pub fn generate() -> Result<()> {
-    let policy_crd = DevOpsPolicy::crd();
-    let audit_crd = PolicyAuditResult::crd();
+use kube_devops::api::FINALIZER;
+use kube_devops::conversion;
+use kube_devops::crd::{
+    ClusterDevOpsPolicy, DevOpsPolicy, PolicyAuditResult, QuarantineRecord, with_spec_validations,
+};
 
-    let policy_yaml = serde_yaml::to_string(&policy_crd)?;
-    let audit_yaml = serde_yaml::to_string(&audit_crd)?;
+use crate::commands::client;
+use crate::commands::deploy::ApplyOutcome;
 
-    println!("{policy_yaml}---\n{audit_yaml}");
+/// Webhook that converts DevOpsPolicy objects between v1alpha1 and v1.
+pub struct ConversionWebhook {
+    pub service_name: String,
+    pub namespace: String,
+    pub ca_bundle: Vec<u8>,
+}
+
+impl ConversionWebhook {
+    /// Read the CA bundle the API server uses to verify the webhook.
+    pub fn load(service_name: &str, namespace: &str, ca_bundle_path: &Path) -> Result<Self> {
+        let ca_bundle = std::fs::read(ca_bundle_path).with_context(|| {
+            format!("Failed to read CA bundle file {}", ca_bundle_path.display())
+        })?;
+        Ok(Self {
+            service_name: service_name.to_string(),
+            namespace: namespace.to_string(),
+            ca_bundle,
+        })
+    }
+}
+
+/// Every CRD this binary serves, as generated from the Rust types, with the
+/// CEL rules on policy specs. With a conversion webhook, DevOpsPolicy also
+/// serves v1alpha1.
+pub(crate) fn all_crds(conversion: Option<&ConversionWebhook>) -> [CustomResourceDefinition; 4] {
+    let policy_crd = match conversion {
+        Some(webhook) => conversion::versioned_policy_crd(
+            &webhook.service_name,
+            &webhook.namespace,
+            &webhook.ca_bundle,
+        ),
+        None => with_spec_validations(DevOpsPolicy::crd()),
+    };
+    [
+        policy_crd,
+        with_spec_validations(ClusterDevOpsPolicy::crd()),
+        PolicyAuditResult::crd(),
+        QuarantineRecord::crd(),
+    ]
+}
+
+/// Print all CRD YAMLs to stdout for `kubectl apply -f`.
+pub fn generate(conversion: Option<&ConversionWebhook>) -> Result<()> {
+    let yaml = all_crds(conversion)
+        .iter()
+        .map(serde_yaml::to_string)
+        .collect::<Result<Vec<_>, _>>()?;
+    println!("{}", yaml.join("---\n"));
     Ok(())
 }
 
-/// Apply both CRDs directly to the connected cluster.
-pub async fn install() -> Result<()> {
-    use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
-    use kube::{Api, Client};
+/* ============================= INSTALL ============================= */
+
+/// Field manager that owns the CRDs written by `crd install`.
+const FIELD_MANAGER: &str = "kube-devops-cli";
 
-    let client = Client::try_default().await?;
+/// How long `crd install` waits for each CRD to report `Established`.
+const ESTABLISHED_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Field-level schema changes between the versions `existing` serves and
+/// the ones `desired` would serve, one line per change:
+/// `v1: + .spec.newField`, `v1: - .status.oldField`, `v1: ~ .spec.mode`.
+///
+/// Only structural keys are compared; description edits are not reported.
+pub(crate) fn schema_changes(
+    existing: &CustomResourceDefinition,
+    desired: &CustomResourceDefinition,
+) -> Vec<String> {
+    let served = |crd: &CustomResourceDefinition| -> BTreeMap<String, BTreeMap<String, Value>> {
+        crd.spec
+            .versions
+            .iter()
+            .filter(|v| v.served)
+            .map(|v| {
+                let schema = v
+                    .schema
+                    .as_ref()
+                    .and_then(|s| serde_json::to_value(&s.open_api_v3_schema).ok())
+                    .unwrap_or(Value::Null);
+                let mut fields = BTreeMap::new();
+                flatten_schema("", &schema, &mut fields);
+                (v.name.clone(), fields)
+            })
+            .collect()
+    };
+    let (old, new) = (served(existing), served(desired));
+
+    let mut changes = Vec::new();
+    for name in old.keys().chain(new.keys()).collect::<BTreeSet<_>>() {
+        let (old_fields, new_fields) = match (old.get(name), new.get(name)) {
+            (Some(o), Some(n)) => (o, n),
+            (Some(_), None) => {
+                changes.push(format!("{name}: - version no longer served"));
+                continue;
+            }
+            (None, _) => {
+                changes.push(format!("{name}: + version added"));
+                continue;
+            }
+        };
+        for path in old_fields
+            .keys()
+            .chain(new_fields.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            match (old_fields.get(path), new_fields.get(path)) {
+                (Some(o), Some(n)) if o != n => changes.push(format!("{name}: ~ {path}")),
+                (Some(_), None) => changes.push(format!("{name}: - {path}")),
+                (None, Some(_)) => changes.push(format!("{name}: + {path}")),
+                _ => {}
+            }
+        }
+    }
+    changes
+}
+
+/// Record every property under `path` with its structural keys (type,
+/// format, enum, required, ...), recursing into `properties`, array `items`,
+/// and map `additionalProperties`.
+fn flatten_schema(path: &str, node: &Value, out: &mut BTreeMap<String, Value>) {
+    let Some(obj) = node.as_object() else {
+        return;
+    };
+    let shape: serde_json::Map<String, Value> = obj
+        .iter()
+        .filter(|(k, _)| {
+            !matches!(
+                k.as_str(),
+                "properties" | "items" | "additionalProperties" | "description"
+            )
+        })
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    if !path.is_empty() {
+        out.insert(path.to_string(), Value::Object(shape));
+    }
+    if let Some(props) = obj.get("properties").and_then(Value::as_object) {
+        for (key, child) in props {
+            flatten_schema(&format!("{path}.{key}"), child, out);
+        }
+    }
+    if let Some(items) = obj.get("items") {
+        flatten_schema(&format!("{path}[]"), items, out);
+    }
+    if let Some(values) = obj.get("additionalProperties") {
+        flatten_schema(&format!("{path}.*"), values, out);
+    }
+}
+
+/// Install or upgrade all CRDs in the connected cluster.
+///
+/// Each CRD is server-side applied with field manager `kube-devops-cli`.
+/// For a CRD that already exists, the schema changes of its served versions
+/// are printed first. Returns once every CRD reports `Established`.
+pub async fn install(conversion: Option<&ConversionWebhook>) -> Result<()> {
+    let client = client::connect().await?;
     let crds: Api<CustomResourceDefinition> = Api::all(client);
+    let params = PatchParams::apply(FIELD_MANAGER).force();
 
-    for crd in [DevOpsPolicy::crd(), PolicyAuditResult::crd()] {
+    for crd in all_crds(conversion) {
         let name = crd.metadata.name.clone().unwrap_or_default();
 
-        match crds.create(&Default::default(), &crd).await {
-            Ok(_) => {
-                println!("CRD '{name}' installed successfully");
+        let existing = crds
+            .get_opt(&name)
+            .await
+            .with_context(|| format!("Failed to get CRD '{name}'"))?;
+        if let Some(existing) = &existing {
+            let changes = schema_changes(existing, &crd);
+            if !changes.is_empty() {
+                println!("CRD '{name}' schema changes:");
+                for change in &changes {
+                    println!("  {change}");
+                }
+            }
+        }
+
+        let applied = crds
+            .patch(&name, &params, &Patch::Apply(&crd))
+            .await
+            .with_context(|| format!("Failed to apply CRD '{name}'"))?;
+
+        let outcome = ApplyOutcome::classify(
+            existing
+                .as_ref()
+                .and_then(|o| o.metadata.resource_version.as_deref()),
+            applied.metadata.resource_version.as_deref(),
+        );
+        match outcome {
+            ApplyOutcome::Created => println!("CRD '{name}' installed"),
+            ApplyOutcome::Updated => println!("CRD '{name}' upgraded"),
+            ApplyOutcome::Unchanged => println!("CRD '{name}' unchanged"),
+        }
+
+        tokio::time::timeout(
+            ESTABLISHED_TIMEOUT,
+            await_condition(crds.clone(), &name, conditions::is_crd_established()),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "CRD '{name}' was not established within {}s",
+                ESTABLISHED_TIMEOUT.as_secs()
+            )
+        })?
+        .with_context(|| format!("Failed to watch CRD '{name}'"))?;
+        println!("CRD '{name}' established");
+    }
+
+    Ok(())
+}
+
+/* ============================= STORAGE MIGRATION ============================= */
+
+/// Storage version of `crd` and the versions `status.storedVersions` still
+/// lists besides it. Objects persisted at those versions must be rewritten
+/// before the versions can be dropped from the CRD.
+pub(crate) fn stale_stored_versions(crd: &CustomResourceDefinition) -> (String, Vec<String>) {
+    let storage = crd
+        .spec
+        .versions
+        .iter()
+        .find(|v| v.storage)
+        .map(|v| v.name.clone())
+        .unwrap_or_default();
+    let stale = crd
+        .status
+        .as_ref()
+        .and_then(|s| s.stored_versions.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|v| *v != storage)
+        .collect();
+    (storage, stale)
+}
+
+/// Rewrite every object of each installed CRD whose `storedVersions` lists
+/// versions other than the storage version, then trim `storedVersions` to
+/// the storage version.
+///
+/// An unchanged update makes the API server re-encode the object at the
+/// storage version. Afterwards an old version can be removed from the CRD
+/// without stranding objects stored at it.
+pub async fn migrate_storage() -> Result<()> {
+    let client = client::connect().await?;
+    let crds: Api<CustomResourceDefinition> = Api::all(client.clone());
+
+    for desired in all_crds(None) {
+        let name = desired.metadata.name.unwrap_or_default();
+        let Some(crd) = crds
+            .get_opt(&name)
+            .await
+            .with_context(|| format!("Failed to get CRD '{name}'"))?
+        else {
+            println!("CRD '{name}' not installed — skipping");
+            continue;
+        };
+        let (storage, stale) = stale_stored_versions(&crd);
+        if stale.is_empty() {
+            println!("CRD '{name}' already stored at {storage}");
+            continue;
+        }
+
+        let gvk = GroupVersionKind::gvk(&crd.spec.group, &storage, &crd.spec.names.kind);
+        let resource = ApiResource::from_gvk_with_plural(&gvk, &crd.spec.names.plural);
+        let objects = Api::<DynamicObject>::all_with(client.clone(), &resource)
+            .list(&ListParams::default())
+            .await
+            .with_context(|| format!("Failed to list {}", crd.spec.names.kind))?;
+
+        let mut rewritten = 0;
+        for object in objects {
+            let api = match object.namespace() {
+                Some(namespace) => Api::namespaced_with(client.clone(), &namespace, &resource),
+                None => Api::all_with(client.clone(), &resource),
+            };
+            let object_name = object.name_any();
+            match api
+                .replace(&object_name, &PostParams::default(), &object)
+                .await
+            {
+                Ok(_) => rewritten += 1,
+                // Deleted, or written by someone else — either way it is
+                // no longer stored at the old version.
+                Err(kube::Error::Api(err)) if err.code == 404 || err.code == 409 => {}
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to rewrite {} '{object_name}'", crd.spec.names.kind)
+                    });
+                }
+            }
+        }
+
+        let patch = serde_json::json!({ "status": { "storedVersions": [storage] } });
+        crds.patch_status(&name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .with_context(|| format!("Failed to update storedVersions of CRD '{name}'"))?;
+        println!(
+            "CRD '{name}': rewrote {rewritten} object(s) at {storage}; dropped stored version(s) {}",
+            stale.join(", ")
+        );
+    }
+
+    Ok(())
+}
+
+/* ============================= UNINSTALL ============================= */
+
+/// `finalizers` without the operator's cleanup finalizer, or `None` when it
+/// is not in the list.
+pub(crate) fn without_cleanup_finalizer(finalizers: &[String]) -> Option<Vec<String>> {
+    finalizers.iter().any(|f| f == FINALIZER).then(|| {
+        finalizers
+            .iter()
+            .filter(|f| *f != FINALIZER)
+            .cloned()
+            .collect()
+    })
+}
+
+/// List every object of `K`, treating a missing CRD as no objects.
+async fn list_all<K>(client: &Client) -> Result<Vec<K>>
+where
+    K: Resource<DynamicType = ()> + Clone + DeserializeOwned + Debug,
+{
+    match Api::<K>::all(client.clone())
+        .list(&ListParams::default())
+        .await
+    {
+        Ok(list) => Ok(list.items),
+        Err(kube::Error::Api(err)) if err.code == 404 => Ok(Vec::new()),
+        Err(e) => Err(e).with_context(|| format!("Failed to list {}", K::kind(&()))),
+    }
+}
+
+/// Handle for `obj` in its own namespace.
+fn api_for<K>(client: &Client, obj: &K) -> Api<K>
+where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope>,
+{
+    Api::namespaced(client.clone(), &obj.namespace().unwrap_or_default())
+}
+
+/// Remove the cleanup finalizer from every `K`, leaving other finalizers in
+/// place. Returns the number of objects patched.
+async fn strip_finalizers<K>(client: &Client) -> Result<usize>
+where
+    K: Resource<DynamicType = (), Scope = NamespaceResourceScope>
+        + Clone
+        + DeserializeOwned
+        + Serialize
+        + Debug,
+{
+    let mut stripped = 0;
+    for obj in list_all::<K>(client).await? {
+        let finalizers = obj.meta().finalizers.clone().unwrap_or_default();
+        let Some(remaining) = without_cleanup_finalizer(&finalizers) else {
+            continue;
+        };
+        let name = obj.name_any();
+        let patch = serde_json::json!({ "metadata": { "finalizers": remaining } });
+        api_for(client, &obj)
+            .patch(&name, &PatchParams::default(), &Patch::Merge(&patch))
+            .await
+            .with_context(|| {
+                format!("Failed to remove finalizer from {} '{name}'", K::kind(&()))
+            })?;
+        println!("Removed finalizer from {} '{name}'", K::kind(&()));
+        stripped += 1;
+    }
+    Ok(stripped)
+}
+
+/// Delete the CRDs and, with them, every DevOpsPolicy, PolicyAuditResult,
+/// and QuarantineRecord in the cluster.
+///
+/// Policies still carrying the cleanup finalizer keep their CRD terminating
+/// until the operator processes them. `purge` removes that finalizer and
+/// deletes all audit results first, for clusters where the operator is no
+/// longer running.
+pub async fn uninstall(purge: bool) -> Result<()> {
+    let client = client::connect().await?;
+
+    if purge {
+        let stripped = strip_finalizers::<DevOpsPolicy>(&client).await?;
+        let results = list_all::<PolicyAuditResult>(&client).await?;
+        for result in &results {
+            let name = result.name_any();
+            match api_for(&client, result)
+                .delete(&name, &DeleteParams::default())
+                .await
+            {
+                Ok(_) => {}
+                Err(kube::Error::Api(err)) if err.code == 404 => {}
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to delete PolicyAuditResult '{name}'"));
+                }
             }
-            Err(kube::Error::Api(err)) if err.code == 409 => {
-                println!("CRD '{name}' already exists — skipping");
+        }
+        println!(
+            "Purged {stripped} finalizer(s) and {} audit result(s)",
+            results.len()
+        );
+    } else {
+        let pending = list_all::<DevOpsPolicy>(&client)
+            .await?
+            .iter()
+            .filter(|p| {
+                without_cleanup_finalizer(p.finalizers()).is_some()
+                    && p.metadata.deletion_timestamp.is_none()
+            })
+            .count();
+        if pending > 0 {
+            println!(
+                "{pending} DevOpsPolicy object(s) carry the {FINALIZER} finalizer; their CRD stays \
+                 terminating until the operator removes it (use --purge if it is not running)"
+            );
+        }
+    }
+
+    let crds: Api<CustomResourceDefinition> = Api::all(client);
+    for crd in all_crds(None) {
+        let name = crd.metadata.name.unwrap_or_default();
+        match crds.delete(&name, &DeleteParams::default()).await {
+            Ok(_) => println!("CRD '{name}' deleted"),
+            Err(kube::Error::Api(err)) if err.code == 404 => {
+                println!("CRD '{name}' not installed — skipping");
             }
-            Err(e) => return Err(e.into()),
+            Err(e) => return Err(e).with_context(|| format!("Failed to delete CRD '{name}'")),
         }
     }
 
@@ -79,5 +499,250 @@ mod tests {
         let policy_crd = DevOpsPolicy::crd();
         let audit_crd = PolicyAuditResult::crd();
         assert_eq!(policy_crd.spec.group, audit_crd.spec.group);
+        assert_eq!(policy_crd.spec.group, ClusterDevOpsPolicy::crd().spec.group);
+    }
+
+    // ── printer columns ──
+
+    fn column_names(crd: &CustomResourceDefinition) -> Vec<String> {
+        crd.spec.versions[0]
+            .additional_printer_columns
+            .as_ref()
+            .expect("printer columns should be defined")
+            .iter()
+            .map(|c| c.name.clone())
+            .collect()
+    }
+
+    /// Resolve a printer column JSON path (e.g. `.status.healthScore`)
+    /// against the version's OpenAPI schema.
+    fn schema_has_path(crd: &CustomResourceDefinition, json_path: &str) -> bool {
+        let schema = serde_json::to_value(&crd.spec.versions[0].schema).unwrap();
+        let mut node = &schema["openAPIV3Schema"];
+        for segment in json_path.trim_start_matches('.').split('.') {
+            node = &node["properties"][segment];
+            if node.is_null() {
+                return false;
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn test_devops_policy_printer_columns() {
+        assert_eq!(
+            column_names(&DevOpsPolicy::crd()),
+            vec![
+                "Score",
+                "Violations",
+                "Classification",
+                "Trend",
+                "Mode",
+                "Age"
+            ]
+        );
+        assert_eq!(
+            column_names(&ClusterDevOpsPolicy::crd()),
+            column_names(&DevOpsPolicy::crd())
+        );
+    }
+
+    #[test]
+    fn test_audit_result_printer_columns() {
+        assert_eq!(
+            column_names(&PolicyAuditResult::crd()),
+            vec!["Policy", "Score", "Violations", "Classification", "Age"]
+        );
+    }
+
+    #[test]
+    fn test_printer_column_paths_exist_in_schema() {
+        for crd in [
+            DevOpsPolicy::crd(),
+            ClusterDevOpsPolicy::crd(),
+            PolicyAuditResult::crd(),
+        ] {
+            let columns = crd.spec.versions[0]
+                .additional_printer_columns
+                .clone()
+                .unwrap_or_default();
+            for column in columns {
+                if column.json_path.starts_with(".metadata") {
+                    continue;
+                }
+                assert!(
+                    schema_has_path(&crd, &column.json_path),
+                    "{} column {} points at missing field {}",
+                    crd.spec.names.kind,
+                    column.name,
+                    column.json_path
+                );
+            }
+        }
+    }
+
+    // ── schema migration ──
+
+    #[test]
+    fn test_schema_migration_keeps_v1_status_fields() {
+        // Objects written by v0.2.0 must still validate against the
+        // regenerated schema: every field stays, nothing new is required.
+        let crd = DevOpsPolicy::crd();
+        let version = &crd.spec.versions[0];
+        assert_eq!(version.name, "v1");
+        assert!(version.served && version.storage);
+
+        for field in [
+            "observedGeneration",
+            "healthy",
+            "healthScore",
+            "violations",
+            "lastEvaluated",
+            "message",
+            "remediationsApplied",
+            "remediationsFailed",
+            "remediatedWorkloads",
+        ] {
+            assert!(
+                schema_has_path(&crd, &format!(".status.{field}")),
+                "status.{field} removed from schema"
+            );
+        }
+
+        let schema = serde_json::to_value(&version.schema).unwrap();
+        let status = &schema["openAPIV3Schema"]["properties"]["status"];
+        assert!(
+            status["required"].is_null(),
+            "status must not gain required fields"
+        );
+    }
+
+    #[test]
+    fn test_schema_migration_reads_v1_status_without_classification() {
+        let old = serde_json::json!({
+            "observedGeneration": 4,
+            "healthy": true,
+            "healthScore": 91,
+            "violations": 2,
+            "lastEvaluated": "2026-02-22T10:00:00Z",
+            "message": "2 violations across 10 pods — Healthy (91)"
+        });
+        let status: kube_devops::crd::DevOpsPolicyStatus = serde_json::from_value(old).unwrap();
+        assert_eq!(status.health_score, Some(91));
+        assert_eq!(status.classification, None);
+    }
+
+    // ── upgrade schema diff ──
+
+    /// `DevOpsPolicy` CRD with `edit` applied to the v1 OpenAPI schema.
+    fn edited_crd(edit: impl FnOnce(&mut Value)) -> CustomResourceDefinition {
+        let mut crd = DevOpsPolicy::crd();
+        let schema = crd.spec.versions[0].schema.as_mut().unwrap();
+        let mut json = serde_json::to_value(&schema.open_api_v3_schema).unwrap();
+        edit(&mut json);
+        schema.open_api_v3_schema = Some(serde_json::from_value(json).unwrap());
+        crd
+    }
+
+    #[test]
+    fn test_schema_changes_identical() {
+        assert!(schema_changes(&DevOpsPolicy::crd(), &DevOpsPolicy::crd()).is_empty());
+    }
+
+    #[test]
+    fn test_schema_changes_reports_fields() {
+        let existing = edited_crd(|schema| {
+            let status = &mut schema["properties"]["status"]["properties"];
+            status.as_object_mut().unwrap().remove("classification");
+            status["legacyScore"] = serde_json::json!({"type": "integer"});
+            status["healthScore"]["type"] = Value::from("string");
+            status["message"]["description"] = Value::from("edited");
+        });
+        assert_eq!(
+            schema_changes(&existing, &DevOpsPolicy::crd()),
+            [
+                "v1: + .status.classification",
+                "v1: ~ .status.healthScore",
+                "v1: - .status.legacyScore",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_schema_changes_reports_versions() {
+        let mut existing = DevOpsPolicy::crd();
+        let mut beta = existing.spec.versions[0].clone();
+        beta.name = "v1beta1".to_string();
+        beta.storage = false;
+        existing.spec.versions.push(beta.clone());
+        assert_eq!(
+            schema_changes(&existing, &DevOpsPolicy::crd()),
+            ["v1beta1: - version no longer served"]
+        );
+
+        beta.served = false;
+        existing.spec.versions[1] = beta;
+        assert!(schema_changes(&existing, &DevOpsPolicy::crd()).is_empty());
+    }
+
+    // ── uninstall ──
+
+    #[test]
+    fn test_without_cleanup_finalizer() {
+        let list = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(without_cleanup_finalizer(&[]), None);
+        assert_eq!(
+            without_cleanup_finalizer(&list(&["example.com/other"])),
+            None
+        );
+        assert_eq!(
+            without_cleanup_finalizer(&list(&[FINALIZER])),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            without_cleanup_finalizer(&list(&["example.com/other", FINALIZER])),
+            Some(list(&["example.com/other"]))
+        );
+    }
+
+    // ── versions ──
+
+    #[test]
+    fn test_all_crds_with_conversion_serves_v1alpha1() {
+        let versions = |crd: &CustomResourceDefinition| -> Vec<String> {
+            crd.spec.versions.iter().map(|v| v.name.clone()).collect()
+        };
+        assert_eq!(versions(&all_crds(None)[0]), ["v1"]);
+
+        let webhook = ConversionWebhook {
+            service_name: "kube-devops-webhook".to_string(),
+            namespace: "kube-devops".to_string(),
+            ca_bundle: b"CA".to_vec(),
+        };
+        let crds = all_crds(Some(&webhook));
+        assert_eq!(versions(&crds[0]), ["v1", "v1alpha1"]);
+        assert!(crds[0].spec.conversion.is_some());
+        assert_eq!(versions(&crds[1]), ["v1"]);
+        assert_eq!(
+            schema_changes(&all_crds(None)[0], &crds[0]),
+            ["v1alpha1: + version added"]
+        );
+    }
+
+    #[test]
+    fn test_stale_stored_versions() {
+        use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinitionStatus;
+
+        let mut crd = DevOpsPolicy::crd();
+        assert_eq!(stale_stored_versions(&crd), ("v1".to_string(), Vec::new()));
+
+        crd.status = Some(CustomResourceDefinitionStatus {
+            stored_versions: Some(vec!["v1alpha1".to_string(), "v1".to_string()]),
+            ..Default::default()
+        });
+        assert_eq!(
+            stale_stored_versions(&crd),
+            ("v1".to_string(), vec!["v1alpha1".to_string()])
+        );
     }
 }
@@ -1,17 +1,90 @@
-/* ============================= CONSTANTS ============================= */
+use std::path::Path;
+
+use anyhow::Context;
+use kube::api::{
+    Api, DeleteParams, DynamicObject, GroupVersionKind, ListParams, Patch, PatchParams, TypeMeta,
+};
+use kube::discovery::{self, Scope};
+use kube::{Client, ResourceExt};
+use kube_devops::output;
+
+use crate::commands::client;
+
+/* ============================= OPTIONS ============================= */
 
-const NAMESPACE: &str = "kube-devops";
 const APP_NAME: &str = "kube-devops";
-const IMAGE: &str = "192.168.1.68:5000/kube-devops:v0.1.2";
+/// Namespace the manifests target without `--namespace`.
+pub const DEFAULT_NAMESPACE: &str = "kube-devops";
+pub const DEFAULT_IMAGE: &str = "192.168.1.68:5000/kube-devops:v0.1.2";
+
+/// Container resource requests and limits, as Kubernetes quantities.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resources {
+    pub cpu_request: String,
+    pub memory_request: String,
+    pub cpu_limit: String,
+    pub memory_limit: String,
+}
+
+impl Default for Resources {
+    fn default() -> Self {
+        Self {
+            cpu_request: "100m".to_string(),
+            memory_request: "64Mi".to_string(),
+            cpu_limit: "250m".to_string(),
+            memory_limit: "128Mi".to_string(),
+        }
+    }
+}
+
+/// Values the generated manifests are rendered with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeployOptions {
+    pub namespace: String,
+    pub image: String,
+    /// Replicas of each of the three Deployments.
+    pub replicas: u32,
+    pub resources: Resources,
+}
+
+impl Default for DeployOptions {
+    fn default() -> Self {
+        Self {
+            namespace: DEFAULT_NAMESPACE.to_string(),
+            image: DEFAULT_IMAGE.to_string(),
+            replicas: 2,
+            resources: Resources::default(),
+        }
+    }
+}
+
+/// Accept a Kubernetes resource quantity such as `250m`, `0.5`, or `128Mi`.
+pub fn parse_quantity(value: &str) -> Result<String, String> {
+    const SUFFIXES: &[&str] = &[
+        "", "m", "k", "M", "G", "T", "P", "E", "Ki", "Mi", "Gi", "Ti", "Pi", "Ei",
+    ];
+    let digits = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (number, suffix) = value.split_at(digits);
+    if number.parse::<f64>().is_ok_and(|n| n > 0.0) && SUFFIXES.contains(&suffix) {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "'{value}' is not a resource quantity like 250m or 128Mi"
+        ))
+    }
+}
 
 /* ============================= NAMESPACE ============================= */
 
-pub fn generate_namespace() -> String {
+pub fn generate_namespace(opts: &DeployOptions) -> String {
+    let namespace = &opts.namespace;
     format!(
         r#"apiVersion: v1
 kind: Namespace
 metadata:
-  name: {NAMESPACE}
+  name: {namespace}
   labels:
     app.kubernetes.io/name: {APP_NAME}
 "#
@@ -20,13 +93,14 @@ metadata:
 
 /* ============================= RBAC ============================= */
 
-pub fn generate_service_account() -> String {
+pub fn generate_service_account(opts: &DeployOptions) -> String {
+    let namespace = &opts.namespace;
     format!(
         r#"apiVersion: v1
 kind: ServiceAccount
 metadata:
   name: {APP_NAME}
-  namespace: {NAMESPACE}
+  namespace: {namespace}
   labels:
     app.kubernetes.io/name: {APP_NAME}
 "#
@@ -43,20 +117,35 @@ metadata:
     app.kubernetes.io/name: {APP_NAME}
 rules:
   - apiGroups: ["devops.stochastic.io"]
-    resources: ["devopspolicies"]
-    verbs: ["get", "list", "watch"]
+    resources: ["devopspolicies", "clusterdevopspolicies"]
+    verbs: ["get", "list", "watch", "patch"]
   - apiGroups: ["devops.stochastic.io"]
-    resources: ["devopspolicies/status"]
+    resources: ["devopspolicies/status", "clusterdevopspolicies/status"]
     verbs: ["patch"]
   - apiGroups: ["devops.stochastic.io"]
-    resources: ["policyauditresults"]
+    resources: ["policyauditresults", "quarantinerecords"]
     verbs: ["get", "list", "create", "delete"]
   - apiGroups: [""]
     resources: ["pods"]
     verbs: ["get", "list", "watch"]
+  - apiGroups: [""]
+    resources: ["namespaces"]
+    verbs: ["get", "list"]
+  - apiGroups: [""]
+    resources: ["pods/eviction"]
+    verbs: ["create"]
+  - apiGroups: ["events.k8s.io"]
+    resources: ["events"]
+    verbs: ["create"]
   - apiGroups: ["apps"]
     resources: ["deployments", "statefulsets", "daemonsets"]
     verbs: ["get", "list", "patch"]
+  - apiGroups: ["batch"]
+    resources: ["jobs", "cronjobs"]
+    verbs: ["get", "list"]
+  - apiGroups: ["policy"]
+    resources: ["poddisruptionbudgets"]
+    verbs: ["list"]
   - apiGroups: ["coordination.k8s.io"]
     resources: ["leases"]
     verbs: ["get", "create", "update", "patch"]
@@ -67,7 +156,8 @@ rules:
     )
 }
 
-pub fn generate_cluster_role_binding() -> String {
+pub fn generate_cluster_role_binding(opts: &DeployOptions) -> String {
+    let namespace = &opts.namespace;
     format!(
         r#"apiVersion: rbac.authorization.k8s.io/v1
 kind: ClusterRoleBinding
@@ -82,7 +172,7 @@ roleRef:
 subjects:
   - kind: ServiceAccount
     name: {APP_NAME}
-    namespace: {NAMESPACE}
+    namespace: {namespace}
 "#
     )
 }
@@ -90,6 +180,7 @@ subjects:
 /* ============================= DEPLOYMENT HELPER ============================= */
 
 pub fn generate_deployment(
+    opts: &DeployOptions,
     component: &str,
     port: u16,
     args: &[&str],
@@ -102,6 +193,12 @@ pub fn generate_deployment(
         .map(|a| format!("            - \"{a}\"\n"))
         .collect();
 
+    let DeployOptions {
+        namespace,
+        image,
+        replicas,
+        resources,
+    } = opts;
     let probe_path = "/healthz";
     let readiness_path = "/readyz";
 
@@ -122,12 +219,12 @@ pub fn generate_deployment(
 kind: Deployment
 metadata:
   name: {APP_NAME}-{component}
-  namespace: {NAMESPACE}
+  namespace: {namespace}
   labels:
     app.kubernetes.io/name: {APP_NAME}
     app.kubernetes.io/component: {component}
 spec:
-  replicas: 2
+  replicas: {replicas}
   selector:
     matchLabels:
       app.kubernetes.io/name: {APP_NAME}
@@ -141,7 +238,7 @@ spec:
       serviceAccountName: {APP_NAME}
       containers:
         - name: {APP_NAME}
-          image: {IMAGE}
+          image: {image}
           imagePullPolicy: IfNotPresent
           args:
 {args_yaml}          ports:
@@ -163,32 +260,37 @@ spec:
             periodSeconds: 5
           resources:
             requests:
-              memory: "64Mi"
-              cpu: "100m"
+              memory: "{memory_request}"
+              cpu: "{cpu_request}"
             limits:
-              memory: "128Mi"
-              cpu: "250m"
+              memory: "{memory_limit}"
+              cpu: "{cpu_limit}"
           securityContext:
             runAsNonRoot: true
             readOnlyRootFilesystem: true
-{volume_mounts_section}{volumes_section}"#
+{volume_mounts_section}{volumes_section}"#,
+        memory_request = resources.memory_request,
+        cpu_request = resources.cpu_request,
+        memory_limit = resources.memory_limit,
+        cpu_limit = resources.cpu_limit,
     )
 }
 
 /* ============================= DEPLOYMENTS ============================= */
 
-pub fn generate_deployment_watch() -> String {
-    generate_deployment("watch", 8080, &["watch"], "", "", "HTTP")
+pub fn generate_deployment_watch(opts: &DeployOptions) -> String {
+    generate_deployment(opts, "watch", 8080, &["watch"], "", "", "HTTP")
 }
 
-pub fn generate_deployment_reconcile() -> String {
-    generate_deployment("reconcile", 9090, &["reconcile"], "", "", "HTTP")
+pub fn generate_deployment_reconcile(opts: &DeployOptions) -> String {
+    generate_deployment(opts, "reconcile", 9090, &["reconcile"], "", "", "HTTP")
 }
 
-pub fn generate_deployment_webhook() -> String {
+pub fn generate_deployment_webhook(opts: &DeployOptions) -> String {
     let volume_mounts = "            - name: tls-certs\n              mountPath: /tls\n              readOnly: true\n";
     let volumes = "        - name: tls-certs\n          secret:\n            secretName: kube-devops-webhook-tls\n";
     generate_deployment(
+        opts,
         "webhook",
         8443,
         &[
@@ -207,18 +309,25 @@ pub fn generate_deployment_webhook() -> String {
 
 /* ============================= PDB HELPER ============================= */
 
-pub fn generate_pdb(component: &str) -> String {
+/// A single replica gets `maxUnavailable: 1` so node drains are not blocked.
+pub fn generate_pdb(opts: &DeployOptions, component: &str) -> String {
+    let namespace = &opts.namespace;
+    let budget = if opts.replicas > 1 {
+        "minAvailable: 1"
+    } else {
+        "maxUnavailable: 1"
+    };
     format!(
         r#"apiVersion: policy/v1
 kind: PodDisruptionBudget
 metadata:
   name: {APP_NAME}-{component}
-  namespace: {NAMESPACE}
+  namespace: {namespace}
   labels:
     app.kubernetes.io/name: {APP_NAME}
     app.kubernetes.io/component: {component}
 spec:
-  minAvailable: 1
+  {budget}
   selector:
     matchLabels:
       app.kubernetes.io/name: {APP_NAME}
@@ -229,65 +338,825 @@ spec:
 
 /* ============================= PDBs ============================= */
 
-pub fn generate_pdb_watch() -> String {
-    generate_pdb("watch")
+pub fn generate_pdb_watch(opts: &DeployOptions) -> String {
+    generate_pdb(opts, "watch")
 }
 
-pub fn generate_pdb_reconcile() -> String {
-    generate_pdb("reconcile")
+pub fn generate_pdb_reconcile(opts: &DeployOptions) -> String {
+    generate_pdb(opts, "reconcile")
 }
 
-pub fn generate_pdb_webhook() -> String {
-    generate_pdb("webhook")
+pub fn generate_pdb_webhook(opts: &DeployOptions) -> String {
+    generate_pdb(opts, "webhook")
 }
 
 /* ============================= AGGREGATORS ============================= */
 
-pub fn generate_all() -> String {
+pub fn generate_all(opts: &DeployOptions) -> String {
     let parts = [
-        generate_namespace(),
-        generate_service_account(),
+        generate_namespace(opts),
+        generate_service_account(opts),
         generate_cluster_role(),
-        generate_cluster_role_binding(),
-        generate_deployment_watch(),
-        generate_deployment_reconcile(),
-        generate_deployment_webhook(),
-        generate_pdb_watch(),
-        generate_pdb_reconcile(),
-        generate_pdb_webhook(),
+        generate_cluster_role_binding(opts),
+        generate_deployment_watch(opts),
+        generate_deployment_reconcile(opts),
+        generate_deployment_webhook(opts),
+        generate_pdb_watch(opts),
+        generate_pdb_reconcile(opts),
+        generate_pdb_webhook(opts),
     ];
     parts.join("---\n")
 }
 
-pub fn generate_rbac() -> String {
+pub fn generate_rbac(opts: &DeployOptions) -> String {
     let parts = [
-        generate_service_account(),
+        generate_service_account(opts),
         generate_cluster_role(),
-        generate_cluster_role_binding(),
+        generate_cluster_role_binding(opts),
     ];
     parts.join("---\n")
 }
 
-pub fn generate_deployments() -> String {
+pub fn generate_deployments(opts: &DeployOptions) -> String {
     let parts = [
-        generate_deployment_watch(),
-        generate_deployment_reconcile(),
-        generate_deployment_webhook(),
+        generate_deployment_watch(opts),
+        generate_deployment_reconcile(opts),
+        generate_deployment_webhook(opts),
     ];
     parts.join("---\n")
 }
 
+/* ============================= KUSTOMIZE ============================= */
+
+/// Per-environment values of a kustomize overlay.
+struct Overlay {
+    name: &'static str,
+    replicas: u32,
+    resources: [&'static str; 4],
+    /// `RUST_LOG` of every container.
+    log_level: &'static str,
+}
+
+/// dev, staging, and prod overlays. Resources are cpu/memory requests, then
+/// cpu/memory limits.
+const OVERLAYS: [Overlay; 3] = [
+    Overlay {
+        name: "dev",
+        replicas: 1,
+        resources: ["50m", "32Mi", "100m", "64Mi"],
+        log_level: "debug",
+    },
+    Overlay {
+        name: "staging",
+        replicas: 2,
+        resources: ["100m", "64Mi", "250m", "128Mi"],
+        log_level: "info",
+    },
+    Overlay {
+        name: "prod",
+        replicas: 3,
+        resources: ["200m", "128Mi", "500m", "256Mi"],
+        log_level: "info,kube=warn",
+    },
+];
+
+/// Base manifests, one file per group, as `(file name, contents)`.
+fn kustomize_base(opts: &DeployOptions) -> Vec<(&'static str, String)> {
+    vec![
+        ("namespace.yaml", generate_namespace(opts)),
+        ("rbac.yaml", generate_rbac(opts)),
+        ("deployments.yaml", generate_deployments(opts)),
+        (
+            "pdbs.yaml",
+            [
+                generate_pdb_watch(opts),
+                generate_pdb_reconcile(opts),
+                generate_pdb_webhook(opts),
+            ]
+            .join("---\n"),
+        ),
+    ]
+}
+
+fn overlay_kustomization(overlay: &Overlay) -> String {
+    let Overlay {
+        replicas,
+        resources: [cpu_request, memory_request, cpu_limit, memory_limit],
+        log_level,
+        ..
+    } = overlay;
+    // One replica cannot keep minAvailable: 1 through a node drain
+    let pdb_patch = if *replicas > 1 {
+        String::new()
+    } else {
+        r#"  - target:
+      kind: PodDisruptionBudget
+      labelSelector: app.kubernetes.io/name=kube-devops
+    patch: |-
+      - op: remove
+        path: /spec/minAvailable
+      - op: add
+        path: /spec/maxUnavailable
+        value: 1
+"#
+        .to_string()
+    };
+    format!(
+        r#"apiVersion: kustomize.config.k8s.io/v1beta1
+kind: Kustomization
+resources:
+  - ../../base
+patches:
+  - target:
+      kind: Deployment
+      labelSelector: app.kubernetes.io/name=kube-devops
+    patch: |-
+      - op: replace
+        path: /spec/replicas
+        value: {replicas}
+      - op: replace
+        path: /spec/template/spec/containers/0/resources
+        value:
+          requests:
+            cpu: "{cpu_request}"
+            memory: "{memory_request}"
+          limits:
+            cpu: "{cpu_limit}"
+            memory: "{memory_limit}"
+      - op: add
+        path: /spec/template/spec/containers/0/env
+        value:
+          - name: RUST_LOG
+            value: "{log_level}"
+{pdb_patch}"#
+    )
+}
+
+/// Every file of the kustomize layout, as `(path, contents)`: a base with the
+/// manifests for `opts` and dev/staging/prod overlays on top of it.
+pub fn kustomize_files(opts: &DeployOptions) -> Vec<(String, String)> {
+    let base = kustomize_base(opts);
+    let resources: String = base
+        .iter()
+        .map(|(file, _)| format!("  - {file}\n"))
+        .collect();
+    let mut files = vec![(
+        "base/kustomization.yaml".to_string(),
+        format!(
+            "apiVersion: kustomize.config.k8s.io/v1beta1\nkind: Kustomization\nresources:\n{resources}"
+        ),
+    )];
+    files.extend(
+        base.into_iter()
+            .map(|(file, contents)| (format!("base/{file}"), contents)),
+    );
+    files.extend(OVERLAYS.iter().map(|overlay| {
+        (
+            format!("overlays/{}/kustomization.yaml", overlay.name),
+            overlay_kustomization(overlay),
+        )
+    }));
+    files
+}
+
+/// Write the kustomize layout under `dir`, replacing files from an earlier
+/// run.
+pub fn generate_kustomize(opts: &DeployOptions, dir: &Path) -> anyhow::Result<()> {
+    for (path, contents) in kustomize_files(opts) {
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        std::fs::write(&path, contents)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        println!("{}", path.display());
+    }
+    Ok(())
+}
+
+/* ============================= DRIFT DETECTION ============================= */
+
+/// Server-managed fields that always differ between a rendered manifest and
+/// the live object; stripped before comparison.
+const VOLATILE_METADATA: &[&str] = &[
+    "managedFields",
+    "resourceVersion",
+    "generation",
+    "creationTimestamp",
+    "uid",
+    "selfLink",
+];
+
+/// Split a multi-document YAML string into individual documents.
+pub fn split_manifests(yaml: &str) -> anyhow::Result<Vec<DynamicObject>> {
+    output::yaml_documents(yaml)?
+        .into_iter()
+        .map(|doc| Ok(serde_yaml::from_value(doc)?))
+        .collect()
+}
+
+/// Strip server-managed fields so only user-controlled content is compared.
+pub fn normalize_for_diff(value: &mut serde_json::Value) {
+    if let Some(obj) = value.as_object_mut() {
+        obj.remove("status");
+        if let Some(meta) = obj.get_mut("metadata").and_then(|m| m.as_object_mut()) {
+            for field in VOLATILE_METADATA {
+                meta.remove(*field);
+            }
+            if let Some(annotations) = meta.get_mut("annotations").and_then(|a| a.as_object_mut()) {
+                annotations.remove("deployment.kubernetes.io/revision");
+                annotations.remove("kubectl.kubernetes.io/last-applied-configuration");
+                if annotations.is_empty() {
+                    meta.remove("annotations");
+                }
+            }
+        }
+    }
+}
+
+/// Build a dynamic API handle for a rendered manifest.
+async fn api_for(client: &Client, obj: &DynamicObject) -> anyhow::Result<Api<DynamicObject>> {
+    let types = obj
+        .types
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("manifest is missing apiVersion/kind"))?;
+    let namespace = obj
+        .metadata
+        .namespace
+        .as_deref()
+        .unwrap_or(DEFAULT_NAMESPACE);
+    api_for_type(client, &types.api_version, &types.kind, namespace).await
+}
+
+/// Build a dynamic API handle for `kind`, scoped to `namespace` when the kind
+/// is namespaced.
+async fn api_for_type(
+    client: &Client,
+    api_version: &str,
+    kind: &str,
+    namespace: &str,
+) -> anyhow::Result<Api<DynamicObject>> {
+    let (group, version) = match api_version.split_once('/') {
+        Some((g, v)) => (g, v),
+        None => ("", api_version),
+    };
+    let gvk = GroupVersionKind::gvk(group, version, kind);
+    let (resource, caps) = discovery::pinned_kind(client, &gvk)
+        .await
+        .with_context(|| format!("Failed to discover {kind}"))?;
+
+    Ok(match caps.scope {
+        Scope::Namespaced => Api::namespaced_with(client.clone(), namespace, &resource),
+        Scope::Cluster => Api::all_with(client.clone(), &resource),
+    })
+}
+
+/// Compare `generate-all` output for `opts` with what is deployed in the
+/// cluster.
+///
+/// Each manifest is server-side applied in dry-run mode, and the result is
+/// compared against the live object so defaulted fields don't show as drift.
+pub async fn diff(opts: &DeployOptions) -> anyhow::Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+
+    let manifests = split_manifests(&generate_all(opts))?;
+    let mut drifted = 0;
+    let mut missing = 0;
+
+    for manifest in &manifests {
+        let kind = manifest
+            .types
+            .as_ref()
+            .map(|t| t.kind.clone())
+            .unwrap_or_default();
+        let name = manifest.name_any();
+        let label = format!("{kind}/{name}");
+        let api = api_for(&client, manifest).await?;
+
+        let live = match api.get(&name).await {
+            Ok(live) => live,
+            Err(kube::Error::Api(err)) if err.code == 404 => {
+                println!("[+] {label}: missing from cluster");
+                missing += 1;
+                continue;
+            }
+            Err(e) => {
+                println!("[!] {label}: error fetching from cluster: {e}");
+                continue;
+            }
+        };
+
+        let desired = match api
+            .patch(
+                &name,
+                &PatchParams::apply("kube-devops-cli").dry_run().force(),
+                &Patch::Apply(manifest),
+            )
+            .await
+        {
+            Ok(obj) => obj,
+            Err(e) => {
+                println!("[!] {label}: server-side dry-run failed: {e}");
+                continue;
+            }
+        };
+
+        let mut live_json = serde_json::to_value(&live)?;
+        let mut desired_json = serde_json::to_value(&desired)?;
+        normalize_for_diff(&mut live_json);
+        normalize_for_diff(&mut desired_json);
+
+        if live_json == desired_json {
+            println!("[=] {label}: in sync");
+        } else {
+            println!("[~] {label}: drifted");
+            super::policy::diff_json(&kind, &live_json, &desired_json, "  ");
+            drifted += 1;
+        }
+    }
+
+    println!(
+        "\n{} resource(s) checked: {drifted} drifted, {missing} missing",
+        manifests.len()
+    );
+
+    Ok(())
+}
+
+/* ============================= APPLY ============================= */
+
+/// Field manager that owns everything `deploy apply` writes.
+const FIELD_MANAGER: &str = "kube-devops-cli";
+
+/// Which operator component `deploy apply` installs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Component {
+    Watch,
+    Reconcile,
+    Webhook,
+    All,
+}
+
+/// Manifests for `component`: the shared Namespace and RBAC plus the
+/// component's Deployment and PodDisruptionBudget.
+pub fn component_manifests(opts: &DeployOptions, component: Component) -> String {
+    let (deployment, pdb) = match component {
+        Component::All => return generate_all(opts),
+        Component::Watch => (generate_deployment_watch(opts), generate_pdb_watch(opts)),
+        Component::Reconcile => (
+            generate_deployment_reconcile(opts),
+            generate_pdb_reconcile(opts),
+        ),
+        Component::Webhook => (
+            generate_deployment_webhook(opts),
+            generate_pdb_webhook(opts),
+        ),
+    };
+    let parts = [
+        generate_namespace(opts),
+        generate_service_account(opts),
+        generate_cluster_role(),
+        generate_cluster_role_binding(opts),
+        deployment,
+        pdb,
+    ];
+    parts.join("---\n")
+}
+
+/// Result of server-side applying one manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApplyOutcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+impl ApplyOutcome {
+    /// Classify an apply by the object's resourceVersion before and after;
+    /// the API server only bumps it when the apply changed something.
+    pub fn classify(before: Option<&str>, after: Option<&str>) -> Self {
+        match before {
+            None => Self::Created,
+            Some(before) if Some(before) == after => Self::Unchanged,
+            Some(_) => Self::Updated,
+        }
+    }
+}
+
+/// Whether `obj` was last written by `deploy apply`. Only such objects are
+/// candidates for `--prune`, so resources created by hand or by other tools
+/// are never deleted.
+pub fn applied_by_cli(obj: &DynamicObject) -> bool {
+    obj.metadata.managed_fields.iter().flatten().any(|entry| {
+        entry.manager.as_deref() == Some(FIELD_MANAGER)
+            && entry.operation.as_deref() == Some("Apply")
+    })
+}
+
+/// `Kind/name` label used in apply and diff output.
+fn label_of(obj: &DynamicObject) -> String {
+    let kind = obj
+        .types
+        .as_ref()
+        .map(|t| t.kind.as_str())
+        .unwrap_or_default();
+    format!("{kind}/{}", obj.name_any())
+}
+
+/// Server-side apply the manifests for `component` with field manager
+/// `kube-devops-cli` and print what changed.
+///
+/// With `prune`, objects labelled `app.kubernetes.io/name=kube-devops` that
+/// were applied by this command but are no longer part of the rendered set
+/// are deleted. Namespaces are never pruned, since deleting one takes every
+/// object in it along.
+pub async fn apply(opts: &DeployOptions, component: Component, prune: bool) -> anyhow::Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+
+    let manifests = split_manifests(&component_manifests(opts, component))?;
+    let params = PatchParams::apply(FIELD_MANAGER).force();
+    let (mut created, mut updated, mut unchanged) = (0, 0, 0);
+
+    for manifest in &manifests {
+        let name = manifest.name_any();
+        let label = label_of(manifest);
+        let api = api_for(&client, manifest).await?;
+
+        let before = api
+            .get_opt(&name)
+            .await
+            .with_context(|| format!("Failed to get {label}"))?;
+        let after = api
+            .patch(&name, &params, &Patch::Apply(manifest))
+            .await
+            .with_context(|| format!("Failed to apply {label}"))?;
+
+        match ApplyOutcome::classify(
+            before
+                .as_ref()
+                .and_then(|o| o.metadata.resource_version.as_deref()),
+            after.metadata.resource_version.as_deref(),
+        ) {
+            ApplyOutcome::Created => {
+                println!("[+] {label}: created");
+                created += 1;
+            }
+            ApplyOutcome::Updated => {
+                println!("[~] {label}: updated");
+                updated += 1;
+            }
+            ApplyOutcome::Unchanged => {
+                println!("[=] {label}: unchanged");
+                unchanged += 1;
+            }
+        }
+    }
+
+    let mut summary = format!(
+        "\n{} resource(s) applied: {created} created, {updated} updated, {unchanged} unchanged",
+        manifests.len()
+    );
+    if prune {
+        let pruned = prune_stale(&client, opts, &manifests).await?;
+        summary.push_str(&format!(", {pruned} pruned"));
+    }
+    println!("{summary}");
+
+    Ok(())
+}
+
+/// Delete objects of the generated kinds that `deploy apply` owns but that
+/// are not in `applied`. Returns the number of deleted objects.
+async fn prune_stale(
+    client: &Client,
+    opts: &DeployOptions,
+    applied: &[DynamicObject],
+) -> anyhow::Result<usize> {
+    let keep: std::collections::HashSet<String> = applied.iter().map(label_of).collect();
+    let mut kinds: Vec<(String, String)> = split_manifests(&generate_all(opts))?
+        .into_iter()
+        .filter_map(|obj| obj.types.map(|t| (t.api_version, t.kind)))
+        .filter(|(_, kind)| kind != "Namespace")
+        .collect();
+    kinds.sort();
+    kinds.dedup();
+
+    let selector = ListParams::default().labels(&format!("app.kubernetes.io/name={APP_NAME}"));
+    let mut pruned = 0;
+    for (api_version, kind) in &kinds {
+        let api = api_for_type(client, api_version, kind, &opts.namespace).await?;
+        let existing = api
+            .list(&selector)
+            .await
+            .with_context(|| format!("Failed to list {kind}"))?;
+        for mut obj in existing {
+            obj.types = Some(TypeMeta {
+                api_version: api_version.clone(),
+                kind: kind.clone(),
+            });
+            let label = label_of(&obj);
+            if keep.contains(&label) || !applied_by_cli(&obj) {
+                continue;
+            }
+            api.delete(&obj.name_any(), &DeleteParams::default())
+                .await
+                .with_context(|| format!("Failed to prune {label}"))?;
+            println!("[-] {label}: pruned");
+            pruned += 1;
+        }
+    }
+    Ok(pruned)
+}
+
 /* ============================= TESTS ============================= */
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // ── Drift detection ──
+
+    #[test]
+    fn test_split_manifests_generate_all() {
+        let docs = split_manifests(&generate_all(&DeployOptions::default())).unwrap();
+        assert_eq!(docs.len(), 10);
+        assert_eq!(docs[0].types.as_ref().unwrap().kind, "Namespace");
+        assert_eq!(docs[0].name_any(), "kube-devops");
+    }
+
+    #[test]
+    fn test_split_manifests_only_splits_on_document_markers() {
+        let yaml = "apiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: a\ndata:\n  banner: \"---\"\n\
+                    ---\n---\napiVersion: v1\nkind: ConfigMap\nmetadata:\n  name: b---c\n";
+        let docs = split_manifests(yaml).unwrap();
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0].data["data"]["banner"], "---");
+        assert_eq!(docs[1].name_any(), "b---c");
+    }
+
+    #[test]
+    fn test_normalize_strips_server_fields() {
+        let mut v = serde_json::json!({
+            "metadata": {
+                "name": "x",
+                "uid": "abc",
+                "resourceVersion": "12",
+                "managedFields": [],
+                "annotations": {"deployment.kubernetes.io/revision": "3"}
+            },
+            "spec": {"replicas": 2},
+            "status": {"readyReplicas": 2}
+        });
+        normalize_for_diff(&mut v);
+        assert_eq!(
+            v,
+            serde_json::json!({"metadata": {"name": "x"}, "spec": {"replicas": 2}})
+        );
+    }
+
+    #[test]
+    fn test_normalize_keeps_user_annotations() {
+        let mut v = serde_json::json!({
+            "metadata": {"annotations": {"team": "platform"}}
+        });
+        normalize_for_diff(&mut v);
+        assert_eq!(v["metadata"]["annotations"]["team"], "platform");
+    }
+
+    // ── Options ──
+
+    fn custom() -> DeployOptions {
+        DeployOptions {
+            namespace: "platform".to_string(),
+            image: "ghcr.io/acme/kube-devops:v0.3.0".to_string(),
+            replicas: 3,
+            resources: Resources {
+                cpu_request: "200m".to_string(),
+                memory_request: "128Mi".to_string(),
+                cpu_limit: "1".to_string(),
+                memory_limit: "512Mi".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_options_reach_every_manifest() {
+        let docs = split_manifests(&generate_all(&custom())).unwrap();
+        assert_eq!(docs.len(), 10);
+        assert_eq!(docs[0].name_any(), "platform");
+        for doc in &docs[1..] {
+            let kind = &doc.types.as_ref().unwrap().kind;
+            if kind != "ClusterRole" && kind != "ClusterRoleBinding" {
+                assert_eq!(
+                    doc.metadata.namespace.as_deref(),
+                    Some("platform"),
+                    "{kind}"
+                );
+            }
+        }
+        let binding: serde_yaml::Value =
+            serde_yaml::from_str(&generate_cluster_role_binding(&custom())).unwrap();
+        assert_eq!(binding["subjects"][0]["namespace"], "platform");
+    }
+
+    #[test]
+    fn test_deployment_uses_image_replicas_and_resources() {
+        let yaml = generate_deployment_webhook(&custom());
+        let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
+        assert_eq!(doc["spec"]["replicas"], 3);
+        let container = &doc["spec"]["template"]["spec"]["containers"][0];
+        assert_eq!(container["image"], "ghcr.io/acme/kube-devops:v0.3.0");
+        assert_eq!(container["resources"]["requests"]["cpu"], "200m");
+        assert_eq!(container["resources"]["requests"]["memory"], "128Mi");
+        assert_eq!(container["resources"]["limits"]["cpu"], "1");
+        assert_eq!(container["resources"]["limits"]["memory"], "512Mi");
+    }
+
+    #[test]
+    fn test_single_replica_pdb_allows_eviction() {
+        let single = DeployOptions {
+            replicas: 1,
+            ..Default::default()
+        };
+        let doc: serde_yaml::Value = serde_yaml::from_str(&generate_pdb_watch(&single)).unwrap();
+        assert_eq!(doc["spec"]["maxUnavailable"], 1);
+        assert!(doc["spec"]["minAvailable"].is_null());
+    }
+
+    #[test]
+    fn test_parse_quantity() {
+        for valid in ["100m", "0.5", "2", "64Mi", "1Gi", "1.5G"] {
+            assert_eq!(parse_quantity(valid).as_deref(), Ok(valid));
+        }
+        for invalid in ["", "m", "0", "64MB", "-1", "1 Gi", "abc"] {
+            assert!(
+                parse_quantity(invalid).is_err(),
+                "{invalid} should be rejected"
+            );
+        }
+    }
+
+    // ── Kustomize ──
+
+    fn kustomize_file(path: &str) -> serde_yaml::Value {
+        let files = kustomize_files(&DeployOptions::default());
+        let (_, contents) = files.iter().find(|(p, _)| p == path).expect(path);
+        serde_yaml::from_str(contents).expect("valid YAML")
+    }
+
+    #[test]
+    fn test_kustomize_layout() {
+        let files = kustomize_files(&DeployOptions::default());
+        let paths: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "base/kustomization.yaml",
+                "base/namespace.yaml",
+                "base/rbac.yaml",
+                "base/deployments.yaml",
+                "base/pdbs.yaml",
+                "overlays/dev/kustomization.yaml",
+                "overlays/staging/kustomization.yaml",
+                "overlays/prod/kustomization.yaml",
+            ]
+        );
+        let base = kustomize_file("base/kustomization.yaml");
+        assert_eq!(base["resources"].as_sequence().unwrap().len(), 4);
+
+        // The base files hold the same ten manifests as generate-all
+        let manifests: usize = files[1..5]
+            .iter()
+            .map(|(_, contents)| split_manifests(contents).unwrap().len())
+            .sum();
+        assert_eq!(manifests, 10);
+    }
+
+    #[test]
+    fn test_kustomize_overlays_differ() {
+        let patch = |overlay: &str| -> serde_yaml::Value {
+            let kustomization = kustomize_file(&format!("overlays/{overlay}/kustomization.yaml"));
+            assert_eq!(kustomization["resources"][0], "../../base");
+            serde_yaml::from_str(kustomization["patches"][0]["patch"].as_str().unwrap()).unwrap()
+        };
+        let dev = patch("dev");
+        let prod = patch("prod");
+        assert_eq!(dev[0]["value"], 1);
+        assert_eq!(prod[0]["value"], 3);
+        assert_eq!(dev[1]["value"]["limits"]["memory"], "64Mi");
+        assert_eq!(prod[1]["value"]["limits"]["memory"], "256Mi");
+        assert_eq!(dev[2]["value"][0]["value"], "debug");
+        assert_eq!(prod[2]["value"][0]["value"], "info,kube=warn");
+
+        // Only the single-replica overlay relaxes the PDBs
+        let dev = kustomize_file("overlays/dev/kustomization.yaml");
+        assert_eq!(dev["patches"][1]["target"]["kind"], "PodDisruptionBudget");
+        let prod = kustomize_file("overlays/prod/kustomization.yaml");
+        assert_eq!(prod["patches"].as_sequence().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_generate_kustomize_writes_files() {
+        let dir =
+            std::env::temp_dir().join(format!("kube-devops-kustomize-{}", std::process::id()));
+        let opts = DeployOptions {
+            namespace: "platform".to_string(),
+            ..Default::default()
+        };
+        generate_kustomize(&opts, &dir).unwrap();
+        let namespace = std::fs::read_to_string(dir.join("base/namespace.yaml")).unwrap();
+        let prod = dir.join("overlays/prod/kustomization.yaml").exists();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(namespace.contains("name: platform"));
+        assert!(prod);
+    }
+
+    // ── Apply ──
+
+    fn labels(yaml: &str) -> Vec<String> {
+        split_manifests(yaml)
+            .unwrap()
+            .iter()
+            .map(label_of)
+            .collect()
+    }
+
+    #[test]
+    fn test_component_manifests() {
+        let opts = DeployOptions::default();
+        assert_eq!(
+            component_manifests(&opts, Component::All),
+            generate_all(&opts)
+        );
+        assert_eq!(
+            labels(&component_manifests(&opts, Component::Webhook)),
+            [
+                "Namespace/kube-devops",
+                "ServiceAccount/kube-devops",
+                "ClusterRole/kube-devops",
+                "ClusterRoleBinding/kube-devops",
+                "Deployment/kube-devops-webhook",
+                "PodDisruptionBudget/kube-devops-webhook",
+            ]
+        );
+        let watch = labels(&component_manifests(&opts, Component::Watch));
+        assert!(watch.contains(&"Deployment/kube-devops-watch".to_string()));
+        assert!(!watch.iter().any(|l| l.ends_with("-reconcile")));
+    }
+
+    #[test]
+    fn test_apply_outcome_classify() {
+        assert_eq!(
+            ApplyOutcome::classify(None, Some("7")),
+            ApplyOutcome::Created
+        );
+        assert_eq!(
+            ApplyOutcome::classify(Some("7"), Some("7")),
+            ApplyOutcome::Unchanged
+        );
+        assert_eq!(
+            ApplyOutcome::classify(Some("7"), Some("9")),
+            ApplyOutcome::Updated
+        );
+    }
+
+    #[test]
+    fn test_applied_by_cli() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry;
+
+        let entry = |manager: &str, operation: &str| ManagedFieldsEntry {
+            manager: Some(manager.to_string()),
+            operation: Some(operation.to_string()),
+            ..Default::default()
+        };
+        let mut obj = split_manifests(&generate_pdb_watch(&DeployOptions::default()))
+            .unwrap()
+            .remove(0);
+        assert!(!applied_by_cli(&obj));
+
+        obj.metadata.managed_fields = Some(vec![entry("kubectl-client-side-apply", "Update")]);
+        assert!(!applied_by_cli(&obj));
+
+        obj.metadata.managed_fields = Some(vec![entry("kube-devops-cli", "Update")]);
+        assert!(!applied_by_cli(&obj));
+
+        obj.metadata.managed_fields = Some(vec![
+            entry("kube-controller-manager", "Update"),
+            entry("kube-devops-cli", "Apply"),
+        ]);
+        assert!(applied_by_cli(&obj));
+    }
+
     // ── RBAC tests ──
 
     #[test]
     fn test_service_account_fields() {
-        let yaml = generate_service_account();
+        let yaml = generate_service_account(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "ServiceAccount");
@@ -308,12 +1177,53 @@ mod tests {
         let rules = doc["rules"]
             .as_sequence()
             .expect("rules should be a sequence");
-        assert_eq!(rules.len(), 7, "ClusterRole should have 7 rules");
+        assert_eq!(rules.len(), 12, "ClusterRole should have 12 rules");
+    }
+
+    #[test]
+    fn test_cluster_role_can_list_batch_workloads() {
+        let yaml = generate_cluster_role();
+        let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
+        let batch = doc["rules"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .find(|r| r["apiGroups"][0] == "batch")
+            .expect("batch rule present");
+        assert_eq!(batch["resources"][0], "jobs");
+        assert_eq!(batch["resources"][1], "cronjobs");
+    }
+
+    #[test]
+    fn test_cluster_role_can_list_disruption_budgets() {
+        let yaml = generate_cluster_role();
+        let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
+        let policy = doc["rules"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .find(|r| r["apiGroups"][0] == "policy")
+            .expect("policy rule present");
+        assert_eq!(policy["resources"][0], "poddisruptionbudgets");
+        assert_eq!(policy["verbs"][0], "list");
+    }
+
+    #[test]
+    fn test_cluster_role_can_evict_pods() {
+        let yaml = generate_cluster_role();
+        let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
+        let eviction = doc["rules"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .find(|r| r["resources"][0] == "pods/eviction")
+            .expect("eviction rule present");
+        assert_eq!(eviction["verbs"][0], "create");
     }
 
     #[test]
     fn test_cluster_role_binding_references() {
-        let yaml = generate_cluster_role_binding();
+        let yaml = generate_cluster_role_binding(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "ClusterRoleBinding");
@@ -328,14 +1238,14 @@ mod tests {
 
     #[test]
     fn test_deployment_watch_fields() {
-        let yaml = generate_deployment_watch();
+        let yaml = generate_deployment_watch(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "Deployment");
         assert_eq!(doc["metadata"]["name"], "kube-devops-watch");
         assert_eq!(doc["spec"]["replicas"], 2);
         let container = &doc["spec"]["template"]["spec"]["containers"][0];
-        assert_eq!(container["image"], IMAGE);
+        assert_eq!(container["image"], DEFAULT_IMAGE);
         assert_eq!(container["ports"][0]["containerPort"], 8080);
         assert_eq!(container["livenessProbe"]["httpGet"]["path"], "/healthz");
         assert_eq!(container["readinessProbe"]["httpGet"]["path"], "/readyz");
@@ -343,7 +1253,7 @@ mod tests {
 
     #[test]
     fn test_deployment_reconcile_fields() {
-        let yaml = generate_deployment_reconcile();
+        let yaml = generate_deployment_reconcile(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "Deployment");
@@ -356,7 +1266,7 @@ mod tests {
 
     #[test]
     fn test_deployment_webhook_fields() {
-        let yaml = generate_deployment_webhook();
+        let yaml = generate_deployment_webhook(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "Deployment");
@@ -374,7 +1284,7 @@ mod tests {
 
     #[test]
     fn test_pdb_watch_fields() {
-        let yaml = generate_pdb_watch();
+        let yaml = generate_pdb_watch(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "PodDisruptionBudget");
@@ -388,7 +1298,7 @@ mod tests {
 
     #[test]
     fn test_pdb_reconcile_fields() {
-        let yaml = generate_pdb_reconcile();
+        let yaml = generate_pdb_reconcile(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "PodDisruptionBudget");
@@ -402,7 +1312,7 @@ mod tests {
 
     #[test]
     fn test_pdb_webhook_fields() {
-        let yaml = generate_pdb_webhook();
+        let yaml = generate_pdb_webhook(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "PodDisruptionBudget");
@@ -418,7 +1328,7 @@ mod tests {
 
     #[test]
     fn test_namespace_fields() {
-        let yaml = generate_namespace();
+        let yaml = generate_namespace(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "Namespace");
@@ -434,9 +1344,9 @@ mod tests {
     #[test]
     fn test_all_deployments_parseable_yaml() {
         for yaml in [
-            generate_deployment_watch(),
-            generate_deployment_reconcile(),
-            generate_deployment_webhook(),
+            generate_deployment_watch(&DeployOptions::default()),
+            generate_deployment_reconcile(&DeployOptions::default()),
+            generate_deployment_webhook(&DeployOptions::default()),
         ] {
             let _: serde_yaml::Value =
                 serde_yaml::from_str(&yaml).expect("deployment YAML should be parseable");
@@ -446,9 +1356,9 @@ mod tests {
     #[test]
     fn test_all_pdbs_parseable_yaml() {
         for yaml in [
-            generate_pdb_watch(),
-            generate_pdb_reconcile(),
-            generate_pdb_webhook(),
+            generate_pdb_watch(&DeployOptions::default()),
+            generate_pdb_reconcile(&DeployOptions::default()),
+            generate_pdb_webhook(&DeployOptions::default()),
         ] {
             let _: serde_yaml::Value =
                 serde_yaml::from_str(&yaml).expect("PDB YAML should be parseable");
@@ -458,9 +1368,9 @@ mod tests {
     #[test]
     fn test_all_rbac_parseable_yaml() {
         for yaml in [
-            generate_service_account(),
+            generate_service_account(&DeployOptions::default()),
             generate_cluster_role(),
-            generate_cluster_role_binding(),
+            generate_cluster_role_binding(&DeployOptions::default()),
         ] {
             let _: serde_yaml::Value =
                 serde_yaml::from_str(&yaml).expect("RBAC YAML should be parseable");
@@ -472,9 +1382,9 @@ mod tests {
     #[test]
     fn test_deployment_security_context_run_as_non_root() {
         for yaml in [
-            generate_deployment_watch(),
-            generate_deployment_reconcile(),
-            generate_deployment_webhook(),
+            generate_deployment_watch(&DeployOptions::default()),
+            generate_deployment_reconcile(&DeployOptions::default()),
+            generate_deployment_webhook(&DeployOptions::default()),
         ] {
             let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
             let sec = &doc["spec"]["template"]["spec"]["containers"][0]["securityContext"];
@@ -489,9 +1399,9 @@ mod tests {
     #[test]
     fn test_deployment_resource_limits_present() {
         for yaml in [
-            generate_deployment_watch(),
-            generate_deployment_reconcile(),
-            generate_deployment_webhook(),
+            generate_deployment_watch(&DeployOptions::default()),
+            generate_deployment_reconcile(&DeployOptions::default()),
+            generate_deployment_webhook(&DeployOptions::default()),
         ] {
             let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
             let resources = &doc["spec"]["template"]["spec"]["containers"][0]["resources"];
@@ -518,7 +1428,7 @@ mod tests {
 
     #[test]
     fn test_generate_all_contains_all_kinds() {
-        let output = generate_all();
+        let output = generate_all(&DeployOptions::default());
         for kind in [
             "kind: Namespace",
             "kind: ServiceAccount",
@@ -533,14 +1443,14 @@ mod tests {
 
     #[test]
     fn test_generate_rbac_has_three_docs() {
-        let output = generate_rbac();
+        let output = generate_rbac(&DeployOptions::default());
         let docs: Vec<&str> = output.split("---\n").collect();
         assert_eq!(docs.len(), 3, "generate_rbac should produce 3 documents");
     }
 
     #[test]
     fn test_generate_deployments_has_three_docs() {
-        let output = generate_deployments();
+        let output = generate_deployments(&DeployOptions::default());
         let docs: Vec<&str> = output.split("---\n").collect();
         assert_eq!(
             docs.len(),
@@ -553,7 +1463,7 @@ mod tests {
 
     #[test]
     fn test_label_consistency_namespace() {
-        let yaml = generate_namespace();
+        let yaml = generate_namespace(&DeployOptions::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
         assert_eq!(
             doc["metadata"]["labels"]["app.kubernetes.io/name"],
@@ -564,9 +1474,9 @@ mod tests {
     #[test]
     fn test_label_consistency_deployments() {
         for yaml in [
-            generate_deployment_watch(),
-            generate_deployment_reconcile(),
-            generate_deployment_webhook(),
+            generate_deployment_watch(&DeployOptions::default()),
+            generate_deployment_reconcile(&DeployOptions::default()),
+            generate_deployment_webhook(&DeployOptions::default()),
         ] {
             let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
             assert_eq!(
@@ -583,9 +1493,9 @@ mod tests {
     #[test]
     fn test_label_consistency_rbac() {
         for yaml in [
-            generate_service_account(),
+            generate_service_account(&DeployOptions::default()),
             generate_cluster_role(),
-            generate_cluster_role_binding(),
+            generate_cluster_role_binding(&DeployOptions::default()),
         ] {
             let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
             assert_eq!(
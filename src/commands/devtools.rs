@@ -0,0 +1,539 @@
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use futures::{StreamExt, stream};
+use k8s_openapi::api::core::v1::{
+    Container, ExecAction, Namespace, Pod, PodSpec, Probe, ResourceRequirements, Toleration,
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use kube::api::{Api, DeleteParams, ListParams, PostParams};
+
+use kube_devops::crd::DevOpsPolicySpec;
+use kube_devops::governance;
+
+use crate::commands::client;
+
+/* ============================= CONFIG ============================= */
+
+/// Label set on every generated pod; `clean-load` deletes by it.
+pub const LOAD_LABEL: &str = "devops.stochastic.io/load-test";
+/// Namespace generate-load and clean-load use without `--namespace`.
+pub const LOAD_NAMESPACE: &str = "kube-devops-load";
+
+const COMPLIANT_IMAGE: &str = "registry.k8s.io/pause:3.9";
+const LATEST_IMAGE: &str = "registry.k8s.io/pause:latest";
+
+/// Generated probes are never meant to run: the pause container serves
+/// nothing, so a live probe would restart it and skew restart metrics.
+const PROBE_DELAY_SECONDS: i32 = 86_400;
+
+const KWOK_NODE_SELECTOR: (&str, &str) = ("type", "kwok");
+const KWOK_TAINT_KEY: &str = "kwok.x-k8s.io/node";
+
+/* ============================= TYPES ============================= */
+
+/// A violation a generated pod can carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LoadViolation {
+    LatestTag,
+    MissingLiveness,
+    MissingReadiness,
+    MissingResources,
+}
+
+impl LoadViolation {
+    const ALL: [LoadViolation; 4] = [
+        LoadViolation::LatestTag,
+        LoadViolation::MissingLiveness,
+        LoadViolation::MissingReadiness,
+        LoadViolation::MissingResources,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            LoadViolation::LatestTag => "latest_tag",
+            LoadViolation::MissingLiveness => "missing_liveness",
+            LoadViolation::MissingReadiness => "missing_readiness",
+            LoadViolation::MissingResources => "missing_resources",
+        }
+    }
+}
+
+/// Settings for one `generate-load` run.
+#[derive(Debug, Clone)]
+pub struct LoadOptions {
+    pub pods: u32,
+    /// Fraction of pods (0.0–1.0) that carry exactly one violation.
+    pub violation_rate: f64,
+    /// Relative weight of each violation among violating pods.
+    pub mix: Vec<(LoadViolation, u32)>,
+    pub namespace: String,
+    /// Target kwok fake nodes instead of running pause containers.
+    pub kwok: bool,
+    /// Maximum in-flight create requests.
+    pub concurrency: usize,
+    /// Seed for violation placement, so runs are reproducible.
+    pub seed: u64,
+}
+
+/* ============================= PARSING ============================= */
+
+/// Parse `latest_tag=2,missing_liveness=1` into violation weights.
+///
+/// Without a mix every violation type is equally likely.
+pub fn parse_mix(spec: Option<&str>) -> Result<Vec<(LoadViolation, u32)>> {
+    let Some(spec) = spec else {
+        return Ok(LoadViolation::ALL.iter().map(|v| (*v, 1)).collect());
+    };
+
+    let mut mix = Vec::new();
+    for (key, value) in super::score::parse_pairs(spec)? {
+        let violation = LoadViolation::ALL
+            .into_iter()
+            .find(|v| v.as_str() == key)
+            .ok_or_else(|| anyhow::anyhow!("Unknown violation '{key}'"))?;
+        let weight: u32 = value
+            .parse()
+            .with_context(|| format!("Invalid weight '{value}' for '{key}'"))?;
+        if weight > 0 {
+            mix.push((violation, weight));
+        }
+    }
+    if mix.is_empty() {
+        anyhow::bail!("--mix needs at least one violation with a positive weight");
+    }
+    Ok(mix)
+}
+
+/* ============================= PLANNING ============================= */
+
+/// SplitMix64: tiny, seedable, and good enough for spreading test pods.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+}
+
+/// Decide which pods violate and how.
+///
+/// Exactly `round(pods * violation_rate)` pods get one violation each;
+/// placement and type are drawn from the seeded generator.
+pub fn plan_load(opts: &LoadOptions) -> Vec<Option<LoadViolation>> {
+    let total = opts.pods as usize;
+    let violating = ((total as f64) * opts.violation_rate.clamp(0.0, 1.0)).round() as usize;
+    let mut rng = SplitMix64(opts.seed);
+
+    // Fisher–Yates shuffle picks which indices violate
+    let mut indices: Vec<usize> = (0..total).collect();
+    for i in (1..total).rev() {
+        let j = rng.below(i as u64 + 1) as usize;
+        indices.swap(i, j);
+    }
+
+    let weight_total: u64 = opts.mix.iter().map(|(_, w)| *w as u64).sum();
+    let mut plan = vec![None; total];
+    for &index in indices.iter().take(violating) {
+        let mut roll = rng.below(weight_total.max(1));
+        let violation = opts
+            .mix
+            .iter()
+            .find(|(_, w)| {
+                if roll < *w as u64 {
+                    true
+                } else {
+                    roll -= *w as u64;
+                    false
+                }
+            })
+            .map(|(v, _)| *v)
+            .unwrap_or(LoadViolation::LatestTag);
+        plan[index] = Some(violation);
+    }
+    plan
+}
+
+/// Count planned violations by type.
+pub fn summarize_plan(plan: &[Option<LoadViolation>]) -> BTreeMap<&'static str, u32> {
+    let mut counts = BTreeMap::new();
+    for violation in plan.iter().flatten() {
+        *counts.entry(violation.as_str()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Health score the cluster should converge to under a policy that enables
+/// every generated check.
+pub fn expected_health_score(pods: &[Pod]) -> u32 {
+    let policy = DevOpsPolicySpec {
+        forbid_latest_tag: Some(true),
+        require_liveness_probe: Some(true),
+        require_readiness_probe: Some(true),
+        require_resources: Some(true),
+        ..Default::default()
+    };
+    let mut aggregate = governance::PodMetrics::default();
+    for pod in pods {
+        governance::add_metrics(
+            &mut aggregate,
+            &governance::evaluate_pod_with_policy(pod, &policy),
+        );
+    }
+    governance::calculate_health_score(&aggregate)
+}
+
+/* ============================= POD TEMPLATE ============================= */
+
+fn idle_probe() -> Probe {
+    Probe {
+        exec: Some(ExecAction {
+            command: Some(vec!["/pause".to_string()]),
+        }),
+        initial_delay_seconds: Some(PROBE_DELAY_SECONDS),
+        ..Default::default()
+    }
+}
+
+/// Build one synthetic pod carrying at most one violation.
+pub fn build_load_pod(index: usize, violation: Option<LoadViolation>, opts: &LoadOptions) -> Pod {
+    let image = if violation == Some(LoadViolation::LatestTag) {
+        LATEST_IMAGE
+    } else {
+        COMPLIANT_IMAGE
+    };
+
+    let resources = (violation != Some(LoadViolation::MissingResources)).then(|| {
+        let requests = BTreeMap::from([
+            ("cpu".to_string(), Quantity("1m".to_string())),
+            ("memory".to_string(), Quantity("4Mi".to_string())),
+        ]);
+        ResourceRequirements {
+            requests: Some(requests.clone()),
+            limits: Some(requests),
+            ..Default::default()
+        }
+    });
+
+    let labels = BTreeMap::from([
+        (LOAD_LABEL.to_string(), "true".to_string()),
+        (
+            "app.kubernetes.io/managed-by".to_string(),
+            "kube-devops".to_string(),
+        ),
+        (
+            "devops.stochastic.io/load-violation".to_string(),
+            violation.map_or("none", LoadViolation::as_str).to_string(),
+        ),
+    ]);
+
+    let (node_selector, tolerations) = if opts.kwok {
+        (
+            Some(BTreeMap::from([(
+                KWOK_NODE_SELECTOR.0.to_string(),
+                KWOK_NODE_SELECTOR.1.to_string(),
+            )])),
+            Some(vec![Toleration {
+                key: Some(KWOK_TAINT_KEY.to_string()),
+                operator: Some("Exists".to_string()),
+                effect: Some("NoSchedule".to_string()),
+                ..Default::default()
+            }]),
+        )
+    } else {
+        (None, None)
+    };
+
+    Pod {
+        metadata: ObjectMeta {
+            name: Some(format!("load-{index:05}")),
+            namespace: Some(opts.namespace.clone()),
+            labels: Some(labels),
+            ..Default::default()
+        },
+        spec: Some(PodSpec {
+            containers: vec![Container {
+                name: "pause".to_string(),
+                image: Some(image.to_string()),
+                liveness_probe: (violation != Some(LoadViolation::MissingLiveness))
+                    .then(idle_probe),
+                readiness_probe: (violation != Some(LoadViolation::MissingReadiness))
+                    .then(idle_probe),
+                resources,
+                ..Default::default()
+            }],
+            node_selector,
+            tolerations,
+            termination_grace_period_seconds: Some(0),
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+/* ============================= COMMANDS ============================= */
+
+/// Create synthetic pods (or print them with `dry_run`).
+pub async fn generate_load(opts: &LoadOptions, dry_run: bool) -> Result<()> {
+    if !(0.0..=1.0).contains(&opts.violation_rate) {
+        anyhow::bail!("--violation-rate must be between 0.0 and 1.0");
+    }
+    if opts.concurrency == 0 {
+        anyhow::bail!("--concurrency must be at least 1");
+    }
+
+    let plan = plan_load(opts);
+    let pods: Vec<Pod> = plan
+        .iter()
+        .enumerate()
+        .map(|(i, v)| build_load_pod(i, *v, opts))
+        .collect();
+
+    if dry_run {
+        let docs: Vec<String> = pods
+            .iter()
+            .map(serde_yaml::to_string)
+            .collect::<std::result::Result<_, _>>()?;
+        println!("{}", docs.join("---\n"));
+        return Ok(());
+    }
+
+    println!("Generating synthetic load...\n");
+    println!("  Namespace ................... {}", opts.namespace);
+    println!("  Pods ........................ {}", opts.pods);
+    println!(
+        "  Violation rate .............. {:.2} (seed {})",
+        opts.violation_rate, opts.seed
+    );
+    println!(
+        "  Backend ..................... {}",
+        if opts.kwok {
+            "kwok fake nodes"
+        } else {
+            "pause containers"
+        }
+    );
+
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster")?;
+
+    let namespaces: Api<Namespace> = Api::all(client.clone());
+    let namespace = Namespace {
+        metadata: ObjectMeta {
+            name: Some(opts.namespace.clone()),
+            labels: Some(BTreeMap::from([(
+                LOAD_LABEL.to_string(),
+                "true".to_string(),
+            )])),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    match namespaces.create(&PostParams::default(), &namespace).await {
+        Ok(_) | Err(kube::Error::Api(kube::error::ErrorResponse { code: 409, .. })) => {}
+        Err(e) => return Err(e).context("Failed to create load namespace"),
+    }
+
+    let api: Api<Pod> = Api::namespaced(client, &opts.namespace);
+    let started = Instant::now();
+    let expected_score = expected_health_score(&pods);
+
+    let results: Vec<std::result::Result<(), kube::Error>> = stream::iter(pods)
+        .map(|pod| {
+            let api = api.clone();
+            async move { api.create(&PostParams::default(), &pod).await.map(|_| ()) }
+        })
+        .buffer_unordered(opts.concurrency)
+        .collect()
+        .await;
+
+    let mut created = 0u32;
+    let mut existing = 0u32;
+    let mut failed = 0u32;
+    for result in &results {
+        match result {
+            Ok(()) => created += 1,
+            Err(kube::Error::Api(err)) if err.code == 409 => existing += 1,
+            Err(e) => {
+                failed += 1;
+                if failed == 1 {
+                    eprintln!("  First failure: {e}");
+                }
+            }
+        }
+    }
+
+    let elapsed = started.elapsed().as_secs_f64();
+    println!();
+    println!(
+        "  Created ..................... {created} ({existing} already existed, {failed} failed)"
+    );
+    println!(
+        "  Elapsed ..................... {elapsed:.1}s ({:.0} pods/s)",
+        created as f64 / elapsed.max(0.001)
+    );
+    for (violation, count) in summarize_plan(&plan) {
+        println!("  {violation:<28} {count}");
+    }
+    println!("  Expected health score ....... {expected_score}");
+    println!(
+        "\nClean up with: kube-devops devtools clean-load --namespace {}",
+        opts.namespace
+    );
+
+    if failed > 0 {
+        anyhow::bail!("{failed} pod(s) could not be created");
+    }
+    Ok(())
+}
+
+/// Delete every pod created by `generate-load` in `namespace`.
+pub async fn clean_load(namespace: &str) -> Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster")?;
+    let api: Api<Pod> = Api::namespaced(client, namespace);
+
+    let selector = format!("{LOAD_LABEL}=true");
+    let params = DeleteParams {
+        grace_period_seconds: Some(0),
+        ..Default::default()
+    };
+    let deleted = api
+        .delete_collection(&params, &ListParams::default().labels(&selector))
+        .await?;
+
+    match deleted.left() {
+        Some(list) => println!("Deleted {} load pod(s) from {namespace}", list.items.len()),
+        None => println!("Deletion of load pods in {namespace} started"),
+    }
+    Ok(())
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn opts(pods: u32, rate: f64) -> LoadOptions {
+        LoadOptions {
+            pods,
+            violation_rate: rate,
+            mix: parse_mix(None).unwrap(),
+            namespace: "load".to_string(),
+            kwok: false,
+            concurrency: 4,
+            seed: 7,
+        }
+    }
+
+    #[test]
+    fn test_plan_load_exact_violation_count() {
+        let plan = plan_load(&opts(1000, 0.3));
+        assert_eq!(plan.len(), 1000);
+        assert_eq!(plan.iter().flatten().count(), 300);
+        assert!(plan_load(&opts(50, 0.0)).iter().all(Option::is_none));
+        assert!(plan_load(&opts(50, 1.0)).iter().all(Option::is_some));
+    }
+
+    #[test]
+    fn test_plan_load_is_reproducible() {
+        assert_eq!(plan_load(&opts(200, 0.5)), plan_load(&opts(200, 0.5)));
+        let mut other = opts(200, 0.5);
+        other.seed = 8;
+        assert_ne!(plan_load(&opts(200, 0.5)), plan_load(&other));
+    }
+
+    #[test]
+    fn test_plan_load_respects_mix() {
+        let mut o = opts(100, 1.0);
+        o.mix = parse_mix(Some("latest_tag=1")).unwrap();
+        let counts = summarize_plan(&plan_load(&o));
+        assert_eq!(counts.get("latest_tag"), Some(&100));
+        assert_eq!(counts.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_mix_rejects_unknown() {
+        assert!(parse_mix(Some("pending=1")).is_err());
+        assert!(parse_mix(Some("latest_tag=0")).is_err());
+        assert_eq!(
+            parse_mix(Some("missing_liveness=2, latest_tag=1")).unwrap(),
+            vec![
+                (LoadViolation::MissingLiveness, 2),
+                (LoadViolation::LatestTag, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_load_pod_violations_detected() {
+        let o = opts(1, 0.0);
+        let policy = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            require_liveness_probe: Some(true),
+            require_readiness_probe: Some(true),
+            require_resources: Some(true),
+            ..Default::default()
+        };
+
+        let clean = build_load_pod(0, None, &o);
+        assert!(governance::detect_violations_with_policy(&clean, &policy).is_empty());
+
+        for violation in LoadViolation::ALL {
+            let pod = build_load_pod(1, Some(violation), &o);
+            assert_eq!(
+                governance::detect_violations_with_policy(&pod, &policy),
+                vec![violation.as_str()]
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_load_pod_kwok_scheduling() {
+        let mut o = opts(1, 0.0);
+        o.kwok = true;
+        let pod = build_load_pod(3, None, &o);
+        let spec = pod.spec.unwrap();
+        assert_eq!(pod.metadata.name.as_deref(), Some("load-00003"));
+        assert_eq!(
+            spec.node_selector.unwrap().get("type").map(String::as_str),
+            Some("kwok")
+        );
+        assert_eq!(
+            spec.tolerations.unwrap()[0].key.as_deref(),
+            Some(KWOK_TAINT_KEY)
+        );
+        assert_eq!(
+            pod.metadata
+                .labels
+                .unwrap()
+                .get(LOAD_LABEL)
+                .map(String::as_str),
+            Some("true")
+        );
+    }
+
+    #[test]
+    fn test_expected_health_score() {
+        let o = opts(2, 0.0);
+        let clean: Vec<Pod> = (0..2).map(|i| build_load_pod(i, None, &o)).collect();
+        assert_eq!(expected_health_score(&clean), 100);
+
+        let mut mixed = clean;
+        mixed[0] = build_load_pod(0, Some(LoadViolation::LatestTag), &o);
+        assert!(expected_health_score(&mixed) < 100);
+    }
+}
@@ -0,0 +1,799 @@
+use std::collections::BTreeSet;
+
+use anyhow::Context;
+use chrono::{DateTime, Duration, Utc};
+use k8s_openapi::api::apps::v1::Deployment;
+use k8s_openapi::api::coordination::v1::Lease;
+use k8s_openapi::api::core::v1::{Event, Pod};
+use kube::api::ListParams;
+use kube::{Api, Client};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use kube_devops::api::FINALIZER;
+use kube_devops::crd::{DevOpsPolicy, PolicyAuditResult};
+use kube_devops::output::{self, Color, OutputFormat};
+
+use crate::commands::check::{self, Outcome};
+use crate::commands::client;
+use crate::commands::watch::LeaseSettings;
+
+/// Label selector matching everything `deploy` creates.
+const OPERATOR_SELECTOR: &str = "app.kubernetes.io/name=kube-devops";
+
+/// Warning events older than this are not reported.
+const EVENT_WINDOW_MINUTES: i64 = 60;
+
+/// Most recent warning events reported.
+const MAX_EVENTS: usize = 10;
+
+/// A policy still terminating this long after deletion is stuck.
+const STUCK_FINALIZER_MINUTES: i64 = 5;
+
+/* ============================= FINDINGS ============================= */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warning,
+}
+
+/// One problem found, with the command or change that usually fixes it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Finding {
+    pub level: Level,
+    /// `kind/name` or `kind/namespace/name` of the object at fault.
+    pub subject: String,
+    pub problem: String,
+    pub fix: String,
+}
+
+fn finding(level: Level, subject: String, problem: String, fix: String) -> Finding {
+    Finding {
+        level,
+        subject,
+        problem,
+        fix,
+    }
+}
+
+/// Everything `doctor` looks at, read once from the cluster.
+#[derive(Debug, Default)]
+pub(crate) struct Inventory {
+    /// Namespace the operator is deployed to.
+    pub namespace: String,
+    pub deployments: Vec<Deployment>,
+    pub pods: Vec<Pod>,
+    pub events: Vec<Event>,
+    pub lease: Option<Lease>,
+    pub policies: Vec<DevOpsPolicy>,
+    pub audit_results: Vec<PolicyAuditResult>,
+    /// Lists that failed, as `(what, error)`; their checks are skipped.
+    pub unreadable: Vec<(String, String)>,
+}
+
+/// Every problem in `inventory`, errors first.
+pub(crate) fn diagnose(inventory: &Inventory, now: DateTime<Utc>) -> Vec<Finding> {
+    let mut findings: Vec<Finding> = inventory
+        .unreadable
+        .iter()
+        .map(|(what, error)| {
+            finding(
+                Level::Warning,
+                what.clone(),
+                format!("could not be read: {error}"),
+                "grant the missing RBAC permission, or run `kube-devops check --verbose`"
+                    .to_string(),
+            )
+        })
+        .collect();
+    findings.extend(deployment_findings(inventory));
+    findings.extend(pod_findings(inventory));
+    findings.extend(event_findings(inventory, now));
+    findings.extend(lease_findings(inventory, now));
+    findings.extend(finalizer_findings(&inventory.policies, now));
+    findings.extend(orphaned_audit_results(inventory));
+    findings.sort_by_key(|f| f.level);
+    findings
+}
+
+/* ============================= CHECKS ============================= */
+
+fn deployment_findings(inventory: &Inventory) -> Vec<Finding> {
+    let ns = &inventory.namespace;
+    if inventory.deployments.is_empty() {
+        return vec![finding(
+            Level::Error,
+            format!("namespace/{ns}"),
+            "no operator Deployments found".to_string(),
+            format!("kube-devops deploy apply --namespace {ns}"),
+        )];
+    }
+    inventory
+        .deployments
+        .iter()
+        .filter_map(|d| {
+            let name = d.metadata.name.as_deref().unwrap_or_default();
+            let desired = d.spec.as_ref().and_then(|s| s.replicas).unwrap_or(1);
+            let ready = d
+                .status
+                .as_ref()
+                .and_then(|s| s.ready_replicas)
+                .unwrap_or(0);
+            (ready < desired).then(|| {
+                finding(
+                    if ready == 0 {
+                        Level::Error
+                    } else {
+                        Level::Warning
+                    },
+                    format!("deployment/{ns}/{name}"),
+                    format!("{ready}/{desired} replicas ready"),
+                    format!("kubectl -n {ns} describe deployment {name}"),
+                )
+            })
+        })
+        .collect()
+}
+
+fn pod_findings(inventory: &Inventory) -> Vec<Finding> {
+    let ns = &inventory.namespace;
+    let mut findings = Vec::new();
+    for pod in &inventory.pods {
+        let name = pod.metadata.name.as_deref().unwrap_or_default();
+        let statuses = pod
+            .status
+            .as_ref()
+            .and_then(|s| s.container_statuses.as_deref())
+            .unwrap_or_default();
+        for status in statuses.iter().filter(|s| s.restart_count > 0) {
+            let crash_looping = status
+                .state
+                .as_ref()
+                .and_then(|s| s.waiting.as_ref())
+                .and_then(|w| w.reason.as_deref())
+                == Some("CrashLoopBackOff");
+            let last_reason = status
+                .last_state
+                .as_ref()
+                .and_then(|s| s.terminated.as_ref())
+                .and_then(|t| t.reason.as_deref());
+            let fix = match last_reason {
+                Some("OOMKilled") => format!(
+                    "raise the memory limit: kube-devops deploy apply --namespace {ns} --memory-limit 256Mi"
+                ),
+                _ => format!("kubectl -n {ns} logs {name} -c {} --previous", status.name),
+            };
+            findings.push(finding(
+                if crash_looping {
+                    Level::Error
+                } else {
+                    Level::Warning
+                },
+                format!("pod/{ns}/{name}"),
+                format!(
+                    "container '{}' restarted {} time(s){}{}",
+                    status.name,
+                    status.restart_count,
+                    last_reason.map_or(String::new(), |r| format!(", last exit {r}")),
+                    if crash_looping {
+                        ", now in CrashLoopBackOff"
+                    } else {
+                        ""
+                    }
+                ),
+                fix,
+            ));
+        }
+    }
+    findings
+}
+
+/// When an event last occurred.
+fn event_time(event: &Event) -> Option<DateTime<Utc>> {
+    event
+        .last_timestamp
+        .as_ref()
+        .map(|t| t.0)
+        .or_else(|| event.event_time.as_ref().map(|t| t.0))
+        .or_else(|| event.metadata.creation_timestamp.as_ref().map(|t| t.0))
+}
+
+fn event_findings(inventory: &Inventory, now: DateTime<Utc>) -> Vec<Finding> {
+    let ns = &inventory.namespace;
+    let since = now - Duration::minutes(EVENT_WINDOW_MINUTES);
+    let mut recent: Vec<(&Event, DateTime<Utc>)> = inventory
+        .events
+        .iter()
+        .filter(|e| e.type_.as_deref() == Some("Warning"))
+        .filter_map(|e| event_time(e).filter(|t| *t >= since).map(|t| (e, t)))
+        .collect();
+    recent.sort_by_key(|(_, t)| std::cmp::Reverse(*t));
+    recent.truncate(MAX_EVENTS);
+
+    recent
+        .into_iter()
+        .map(|(event, _)| {
+            let object = &event.involved_object;
+            let kind = object.kind.as_deref().unwrap_or("object").to_lowercase();
+            let name = object.name.as_deref().unwrap_or_default();
+            let reason = event.reason.as_deref().unwrap_or("Warning");
+            let fix = match reason {
+                "FailedMount" => format!(
+                    "check the webhook TLS secret: kube-devops webhook cert-generate, then kubectl -n {ns} create secret tls kube-devops-webhook-tls ..."
+                ),
+                "FailedScheduling" => {
+                    "free node capacity or lower the requests: kube-devops deploy apply --cpu-request/--memory-request"
+                        .to_string()
+                }
+                _ => format!("kubectl -n {ns} describe {kind} {name}"),
+            };
+            finding(
+                Level::Warning,
+                format!("{kind}/{ns}/{name}"),
+                format!(
+                    "{reason} (x{}): {}",
+                    event.count.unwrap_or(1),
+                    event.message.as_deref().unwrap_or_default().trim()
+                ),
+                fix,
+            )
+        })
+        .collect()
+}
+
+fn lease_findings(inventory: &Inventory, now: DateTime<Utc>) -> Vec<Finding> {
+    let Some(lease) = &inventory.lease else {
+        return Vec::new();
+    };
+    let ns = &inventory.namespace;
+    let name = lease.metadata.name.as_deref().unwrap_or_default();
+    let Outcome::Fail(problem) = check::lease_outcome(lease.spec.as_ref(), now) else {
+        return Vec::new();
+    };
+    let holder = lease
+        .spec
+        .as_ref()
+        .and_then(|s| s.holder_identity.as_deref())
+        .unwrap_or_default();
+    let holder_running = inventory.pods.iter().any(|p| {
+        p.metadata
+            .name
+            .as_deref()
+            .is_some_and(|pod| holder.starts_with(pod))
+    });
+    let fix = if holder_running {
+        format!("kubectl -n {ns} logs {}", holder_pod(holder))
+    } else {
+        format!(
+            "the holder is gone; if no instance takes over, kubectl -n {ns} delete lease {name}"
+        )
+    };
+    vec![finding(
+        Level::Error,
+        format!("lease/{ns}/{name}"),
+        problem,
+        fix,
+    )]
+}
+
+/// Pod name of a lease holder identity (`<pod>-<suffix>`).
+fn holder_pod(identity: &str) -> &str {
+    identity.rsplit_once('-').map_or(identity, |(pod, _)| pod)
+}
+
+fn finalizer_findings(policies: &[DevOpsPolicy], now: DateTime<Utc>) -> Vec<Finding> {
+    let cutoff = now - Duration::minutes(STUCK_FINALIZER_MINUTES);
+    policies
+        .iter()
+        .filter(|p| {
+            p.metadata
+                .finalizers
+                .as_ref()
+                .is_some_and(|f| f.iter().any(|s| s == FINALIZER))
+        })
+        .filter_map(|p| {
+            let deleted = p.metadata.deletion_timestamp.as_ref()?.0;
+            (deleted <= cutoff).then(|| {
+                let ns = p.metadata.namespace.as_deref().unwrap_or("default");
+                let name = p.metadata.name.as_deref().unwrap_or_default();
+                finding(
+                    Level::Error,
+                    format!("devopspolicy/{ns}/{name}"),
+                    format!(
+                        "deleted {} minute(s) ago but still held by the {FINALIZER} finalizer",
+                        (now - deleted).num_minutes()
+                    ),
+                    format!(
+                        "start the reconcile operator, or kubectl -n {ns} patch devopspolicy {name} --type=merge -p '{{\"metadata\":{{\"finalizers\":null}}}}'"
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Audit results whose policy no longer exists, one finding per namespace.
+fn orphaned_audit_results(inventory: &Inventory) -> Vec<Finding> {
+    let policies: BTreeSet<(&str, &str)> = inventory
+        .policies
+        .iter()
+        .filter_map(|p| {
+            Some((
+                p.metadata.namespace.as_deref()?,
+                p.metadata.name.as_deref()?,
+            ))
+        })
+        .collect();
+    let mut orphans: Vec<(&str, &str, &str)> = inventory
+        .audit_results
+        .iter()
+        .filter_map(|r| {
+            let ns = r.metadata.namespace.as_deref()?;
+            let policy = r.spec.policy_name.as_str();
+            (!policies.contains(&(ns, policy))).then_some((ns, policy, r.metadata.name.as_deref()?))
+        })
+        .collect();
+    orphans.sort();
+
+    let mut findings = Vec::new();
+    for chunk in orphans.chunk_by(|a, b| a.0 == b.0) {
+        let ns = chunk[0].0;
+        let policies: BTreeSet<&str> = chunk.iter().map(|(_, p, _)| *p).collect();
+        let names: Vec<&str> = chunk.iter().map(|(_, _, n)| *n).collect();
+        findings.push(finding(
+            Level::Warning,
+            format!("namespace/{ns}"),
+            format!(
+                "{} PolicyAuditResult(s) of deleted policies: {}",
+                chunk.len(),
+                policies.into_iter().collect::<Vec<_>>().join(", ")
+            ),
+            format!(
+                "kubectl -n {ns} delete policyauditresults {}",
+                names.join(" ")
+            ),
+        ));
+    }
+    findings
+}
+
+/* ============================= COLLECTION ============================= */
+
+/// List `api`, recording the failure in `unreadable` instead of aborting.
+async fn list_or_record<K>(
+    api: Api<K>,
+    params: &ListParams,
+    what: &str,
+    unreadable: &mut Vec<(String, String)>,
+) -> Vec<K>
+where
+    K: Clone + DeserializeOwned + std::fmt::Debug,
+{
+    match api.list(params).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            unreadable.push((what.to_string(), e.to_string()));
+            Vec::new()
+        }
+    }
+}
+
+async fn collect(client: &Client, namespace: &str) -> Inventory {
+    let mut unreadable = Vec::new();
+    let operator = ListParams::default().labels(OPERATOR_SELECTOR);
+
+    let deployments = list_or_record(
+        Api::<Deployment>::namespaced(client.clone(), namespace),
+        &operator,
+        "deployments",
+        &mut unreadable,
+    )
+    .await;
+    let pods = list_or_record(
+        Api::<Pod>::namespaced(client.clone(), namespace),
+        &operator,
+        "pods",
+        &mut unreadable,
+    )
+    .await;
+    let events = list_or_record(
+        Api::<Event>::namespaced(client.clone(), namespace),
+        &ListParams::default().fields("type=Warning"),
+        "events",
+        &mut unreadable,
+    )
+    .await;
+    let policies = list_or_record(
+        Api::<DevOpsPolicy>::all(client.clone()),
+        &ListParams::default(),
+        "devopspolicies",
+        &mut unreadable,
+    )
+    .await;
+    let audit_results = list_or_record(
+        Api::<PolicyAuditResult>::all(client.clone()),
+        &ListParams::default(),
+        "policyauditresults",
+        &mut unreadable,
+    )
+    .await;
+
+    let leases: Api<Lease> = Api::namespaced(client.clone(), namespace);
+    let lease = match leases.get_opt(&LeaseSettings::default().name).await {
+        Ok(lease) => lease,
+        Err(e) => {
+            unreadable.push(("leases".to_string(), e.to_string()));
+            None
+        }
+    };
+
+    Inventory {
+        namespace: namespace.to_string(),
+        deployments,
+        pods,
+        events,
+        lease,
+        policies,
+        audit_results,
+        unreadable,
+    }
+}
+
+/* ============================= COMMAND ============================= */
+
+/// Structured result of `doctor` (used for `--output json|yaml`).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorReport {
+    pub namespace: String,
+    pub findings: Vec<Finding>,
+}
+
+fn format_findings(findings: &[Finding], color: bool) -> String {
+    if findings.is_empty() {
+        return "No problems found.\n".to_string();
+    }
+    let mut out = String::new();
+    for f in findings {
+        let marker = match f.level {
+            Level::Error => output::paint("[ERROR]", Color::Red, color),
+            Level::Warning => output::paint("[WARN] ", Color::Yellow, color),
+        };
+        out.push_str(&format!("  {marker} {}: {}\n", f.subject, f.problem));
+        out.push_str(&format!("          fix: {}\n", f.fix));
+    }
+    let errors = findings.iter().filter(|f| f.level == Level::Error).count();
+    out.push_str(&format!(
+        "\n{} problem(s) found: {errors} error(s), {} warning(s)\n",
+        findings.len(),
+        findings.len() - errors
+    ));
+    out
+}
+
+/// Inspect the operator deployed in `namespace` and the objects it manages,
+/// printing each problem with a suggested fix. Fails when any error is found.
+pub async fn run(namespace: &str, format: OutputFormat) -> anyhow::Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    let inventory = collect(&client, namespace).await;
+    let findings = diagnose(&inventory, Utc::now());
+    let errors = findings.iter().filter(|f| f.level == Level::Error).count();
+
+    if format.is_structured() {
+        output::print_structured(
+            &DoctorReport {
+                namespace: namespace.to_string(),
+                findings,
+            },
+            format,
+        )?;
+    } else {
+        println!(
+            "Operator in '{namespace}': {} deployment(s), {} pod(s)\n",
+            inventory.deployments.len(),
+            inventory.pods.len()
+        );
+        print!("{}", format_findings(&findings, output::use_color()));
+    }
+
+    if errors > 0 {
+        anyhow::bail!("{errors} error(s) found");
+    }
+    Ok(())
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DeploymentSpec, DeploymentStatus};
+    use k8s_openapi::api::coordination::v1::LeaseSpec;
+    use k8s_openapi::api::core::v1::{
+        ContainerState, ContainerStateTerminated, ContainerStateWaiting, ContainerStatus,
+        ObjectReference, PodStatus,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{MicroTime, ObjectMeta, Time};
+    use kube_devops::crd::{DevOpsPolicySpec, PolicyAuditResultSpec};
+
+    const NS: &str = "kube-devops";
+
+    fn now() -> DateTime<Utc> {
+        "2026-03-01T12:00:00Z".parse().unwrap()
+    }
+
+    fn meta(namespace: &str, name: &str) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        }
+    }
+
+    fn deployment(name: &str, desired: i32, ready: i32) -> Deployment {
+        Deployment {
+            metadata: meta(NS, name),
+            spec: Some(DeploymentSpec {
+                replicas: Some(desired),
+                ..Default::default()
+            }),
+            status: Some(DeploymentStatus {
+                ready_replicas: Some(ready),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn healthy() -> Inventory {
+        Inventory {
+            namespace: NS.to_string(),
+            deployments: vec![deployment("kube-devops-watch", 2, 2)],
+            ..Default::default()
+        }
+    }
+
+    fn policy(namespace: &str, name: &str) -> DevOpsPolicy {
+        let mut policy = DevOpsPolicy::new(name, DevOpsPolicySpec::default());
+        policy.metadata.namespace = Some(namespace.to_string());
+        policy
+    }
+
+    fn audit_result(namespace: &str, name: &str, policy_name: &str) -> PolicyAuditResult {
+        let mut result = PolicyAuditResult::new(
+            name,
+            PolicyAuditResultSpec {
+                policy_name: policy_name.to_string(),
+                cluster_name: None,
+                timestamp: "2026-03-01T11:00:00Z".to_string(),
+                health_score: 90,
+                total_violations: 0,
+                total_pods: 1,
+                total_workloads: None,
+                classification: "Healthy".to_string(),
+                violations: Vec::new(),
+                planned_remediations: None,
+            },
+        );
+        result.metadata.namespace = Some(namespace.to_string());
+        result
+    }
+
+    #[test]
+    fn test_healthy_operator_has_no_findings() {
+        assert!(diagnose(&healthy(), now()).is_empty());
+        assert_eq!(format_findings(&[], false), "No problems found.\n");
+    }
+
+    // ── Deployments and pods ──
+
+    #[test]
+    fn test_deployment_findings() {
+        let mut inventory = healthy();
+        inventory.deployments = vec![
+            deployment("kube-devops-watch", 2, 1),
+            deployment("kube-devops-reconcile", 2, 0),
+        ];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings.len(), 2);
+        // Errors sort first
+        assert_eq!(findings[0].level, Level::Error);
+        assert_eq!(
+            findings[0].subject,
+            "deployment/kube-devops/kube-devops-reconcile"
+        );
+        assert_eq!(findings[1].problem, "1/2 replicas ready");
+
+        inventory.deployments.clear();
+        let findings = diagnose(&inventory, now());
+        assert_eq!(
+            findings[0].fix,
+            "kube-devops deploy apply --namespace kube-devops"
+        );
+    }
+
+    #[test]
+    fn test_pod_restart_findings() {
+        let status = |reason: &str, waiting: Option<&str>| ContainerStatus {
+            name: "kube-devops".to_string(),
+            restart_count: 4,
+            last_state: Some(ContainerState {
+                terminated: Some(ContainerStateTerminated {
+                    reason: Some(reason.to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            state: waiting.map(|reason| ContainerState {
+                waiting: Some(ContainerStateWaiting {
+                    reason: Some(reason.to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let pod = |name: &str, status: ContainerStatus| Pod {
+            metadata: meta(NS, name),
+            status: Some(PodStatus {
+                container_statuses: Some(vec![status]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut inventory = healthy();
+        inventory.pods = vec![
+            pod("watch-1", status("OOMKilled", None)),
+            pod("reconcile-1", status("Error", Some("CrashLoopBackOff"))),
+        ];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].level, Level::Error);
+        assert_eq!(
+            findings[0].problem,
+            "container 'kube-devops' restarted 4 time(s), last exit Error, now in CrashLoopBackOff"
+        );
+        assert_eq!(
+            findings[0].fix,
+            "kubectl -n kube-devops logs reconcile-1 -c kube-devops --previous"
+        );
+        assert!(findings[1].fix.contains("--memory-limit"));
+    }
+
+    // ── Events ──
+
+    #[test]
+    fn test_recent_warning_events_only() {
+        let event = |reason: &str, minutes_ago: i64, type_: &str| Event {
+            metadata: meta(NS, reason),
+            involved_object: ObjectReference {
+                kind: Some("Pod".to_string()),
+                name: Some("webhook-1".to_string()),
+                ..Default::default()
+            },
+            reason: Some(reason.to_string()),
+            message: Some("something failed".to_string()),
+            type_: Some(type_.to_string()),
+            count: Some(3),
+            last_timestamp: Some(Time(now() - Duration::minutes(minutes_ago))),
+            ..Default::default()
+        };
+        let mut inventory = healthy();
+        inventory.events = vec![
+            event("FailedMount", 5, "Warning"),
+            event("Unhealthy", 120, "Warning"),
+            event("Pulled", 1, "Normal"),
+        ];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].subject, "pod/kube-devops/webhook-1");
+        assert_eq!(findings[0].problem, "FailedMount (x3): something failed");
+        assert!(findings[0].fix.contains("webhook cert-generate"));
+
+        inventory.events = (0..15).map(|i| event("BackOff", i, "Warning")).collect();
+        assert_eq!(diagnose(&inventory, now()).len(), MAX_EVENTS);
+    }
+
+    // ── Leases ──
+
+    #[test]
+    fn test_stale_lease() {
+        let mut inventory = healthy();
+        inventory.lease = Some(Lease {
+            metadata: meta(NS, "kube-devops-leader"),
+            spec: Some(LeaseSpec {
+                holder_identity: Some("kube-devops-watch-abc12-x7k2".to_string()),
+                lease_duration_seconds: Some(15),
+                renew_time: Some(MicroTime(now() - Duration::minutes(10))),
+                ..Default::default()
+            }),
+        });
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].subject, "lease/kube-devops/kube-devops-leader");
+        assert!(findings[0].fix.contains("delete lease kube-devops-leader"));
+
+        inventory.pods = vec![Pod {
+            metadata: meta(NS, "kube-devops-watch-abc12"),
+            ..Default::default()
+        }];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(
+            findings[0].fix,
+            "kubectl -n kube-devops logs kube-devops-watch-abc12"
+        );
+    }
+
+    // ── Policies and audit results ──
+
+    #[test]
+    fn test_stuck_finalizer() {
+        let terminating = |name: &str, minutes_ago: i64| {
+            let mut p = policy("prod", name);
+            p.metadata.finalizers = Some(vec![FINALIZER.to_string()]);
+            p.metadata.deletion_timestamp = Some(Time(now() - Duration::minutes(minutes_ago)));
+            p
+        };
+        let mut inventory = healthy();
+        inventory.policies = vec![terminating("stuck", 30), terminating("deleting", 1)];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].subject, "devopspolicy/prod/stuck");
+        assert!(findings[0].problem.starts_with("deleted 30 minute(s) ago"));
+        assert!(findings[0].fix.ends_with(
+            "patch devopspolicy stuck --type=merge -p '{\"metadata\":{\"finalizers\":null}}'"
+        ));
+    }
+
+    #[test]
+    fn test_orphaned_audit_results() {
+        let mut inventory = healthy();
+        inventory.policies = vec![policy("prod", "web")];
+        inventory.audit_results = vec![
+            audit_result("prod", "web-1", "web"),
+            audit_result("prod", "old-2", "old"),
+            audit_result("prod", "old-1", "old"),
+            audit_result("staging", "web-1", "web"),
+        ];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings.len(), 2);
+        assert_eq!(
+            findings[0].problem,
+            "2 PolicyAuditResult(s) of deleted policies: old"
+        );
+        assert_eq!(
+            findings[0].fix,
+            "kubectl -n prod delete policyauditresults old-1 old-2"
+        );
+        assert_eq!(findings[1].subject, "namespace/staging");
+    }
+
+    #[test]
+    fn test_unreadable_lists_are_reported() {
+        let mut inventory = healthy();
+        inventory.unreadable = vec![("events".to_string(), "forbidden".to_string())];
+        let findings = diagnose(&inventory, now());
+        assert_eq!(findings[0].subject, "events");
+        assert_eq!(findings[0].problem, "could not be read: forbidden");
+    }
+
+    #[test]
+    fn test_format_findings() {
+        let findings = [finding(
+            Level::Error,
+            "deployment/kube-devops/kube-devops-watch".to_string(),
+            "0/1 replicas ready".to_string(),
+            "kubectl -n kube-devops describe deployment kube-devops-watch".to_string(),
+        )];
+        assert_eq!(
+            format_findings(&findings, false),
+            "  [ERROR] deployment/kube-devops/kube-devops-watch: 0/1 replicas ready\n\
+             \x20         fix: kubectl -n kube-devops describe deployment kube-devops-watch\n\
+             \n1 problem(s) found: 1 error(s), 0 warning(s)\n"
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use axum::Router;
+use axum::http::StatusCode;
+use axum::routing::get;
+use chrono::Utc;
+use kube::api::{Patch, PatchParams};
+use kube::{Api, Client};
+use prometheus::{IntCounter, IntGaugeVec, Registry};
+use tracing::{info, warn};
+
+use kube_devops::bundles;
+use kube_devops::crd::DevOpsPolicy;
+use kube_devops::output;
+use kube_devops::policy_diff::{self, DiffStatus, PolicyDiff};
+
+use crate::commands::{canary, client, policy, watch};
+
+/* ============================= METRICS ============================= */
+
+struct DriftMetrics {
+    detected: IntGaugeVec,
+    reverts: IntCounter,
+    errors: IntCounter,
+}
+
+impl DriftMetrics {
+    fn register(registry: &Registry) -> Result<Self> {
+        let metrics = DriftMetrics {
+            detected: IntGaugeVec::new(
+                prometheus::Opts::new(
+                    "policy_drift_detected",
+                    "1 when the live DevOpsPolicy differs from the Git copy, 0 when in sync",
+                ),
+                &["namespace", "policy"],
+            )?,
+            reverts: IntCounter::new(
+                "policy_drift_reverts_total",
+                "DevOpsPolicies re-applied from the Git copy to revert drift",
+            )?,
+            errors: IntCounter::new(
+                "policy_drift_check_errors_total",
+                "Drift checks that could not read the policy directory or a live policy",
+            )?,
+        };
+        registry.register(Box::new(metrics.detected.clone()))?;
+        registry.register(Box::new(metrics.reverts.clone()))?;
+        registry.register(Box::new(metrics.errors.clone()))?;
+        Ok(metrics)
+    }
+}
+
+/* ============================= LOADING ============================= */
+
+/// DevOpsPolicies in a YAML file, or in every `.yaml`/`.yml` file of a
+/// directory (not recursive), in file-name order.
+pub(crate) fn read_policies(path: &Path) -> Result<Vec<DevOpsPolicy>> {
+    let files = if path.is_dir() {
+        let entries = std::fs::read_dir(path)
+            .with_context(|| format!("Failed to read policy directory '{}'", path.display()))?;
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|p| p.is_file() && bundles::is_yaml(&p.to_string_lossy()))
+            .collect();
+        files.sort();
+        files
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut policies = Vec::new();
+    for file in files {
+        let content = std::fs::read_to_string(&file)
+            .with_context(|| format!("Failed to read policy file '{}'", file.display()))?;
+        let parsed = policy::parse_policies(&content)
+            .with_context(|| format!("Failed to parse policy file '{}'", file.display()))?;
+        policies.extend(parsed);
+    }
+    Ok(policies)
+}
+
+/// Whether the live policy no longer matches the Git copy: it was deleted,
+/// or importing the copy would change a field.
+fn drifted(diff: &PolicyDiff) -> bool {
+    matches!(diff.status, DiffStatus::New | DiffStatus::Changed)
+}
+
+/* ============================= DRIFT WATCH ============================= */
+
+/// Compare the DevOpsPolicies under `path` with the cluster every
+/// `interval` until Ctrl+C, exposing `policy_drift_detected` on `addr`.
+///
+/// The files are re-read on every check, so a `git pull` in the directory
+/// takes effect on the next cycle. With `revert`, drifted policies are
+/// force-applied from the files, taking back fields other managers changed.
+pub async fn drift_watch(
+    path: &Path,
+    interval: &str,
+    addr: SocketAddr,
+    revert: bool,
+) -> Result<()> {
+    let interval = canary::parse_duration(interval)?;
+    let initial = read_policies(path)?;
+    if initial.is_empty() {
+        anyhow::bail!("No DevOpsPolicy found in '{}'", path.display());
+    }
+    let client = client::connect().await?;
+
+    let registry = Arc::new(Registry::new());
+    let metrics = DriftMetrics::register(&registry)?;
+    let app = Router::new()
+        .route("/healthz", get(|| async { (StatusCode::OK, "OK") }))
+        .route(
+            "/metrics",
+            get(move || async move { watch::encode_metrics(&registry) }),
+        );
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind HTTP server on {addr}"))?;
+    let http_handle = tokio::spawn(async move { axum::serve(listener, app).await });
+
+    println!(
+        "Watching {} DevOpsPolicy(ies) from '{}' for drift every {}s{}",
+        initial.len(),
+        path.display(),
+        interval.as_secs(),
+        if revert { " (auto-revert on)" } else { "" }
+    );
+    println!("  GET http://{addr}/metrics ... policy_drift_detected per policy");
+    println!("Press Ctrl+C to stop.\n");
+    info!(path = %path.display(), revert, "drift_watch_starting");
+
+    loop {
+        check(&client, path, revert, &metrics).await;
+        tokio::select! {
+            _ = tokio::time::sleep(interval) => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    http_handle.abort();
+    info!("drift_watch_stopped");
+    println!("\nDrift watch stopped.");
+    Ok(())
+}
+
+/// One drift check: diff every policy, update the metrics, print what
+/// drifted, and re-apply it when `revert` is set.
+async fn check(client: &Client, path: &Path, revert: bool, metrics: &DriftMetrics) {
+    let now = Utc::now().format("%Y-%m-%dT%H:%M:%SZ");
+    let policies = match read_policies(path) {
+        Ok(policies) => policies,
+        Err(e) => {
+            metrics.errors.inc();
+            warn!(error = %format!("{e:#}"), "drift_policies_unreadable");
+            eprintln!("[{now}] [ERROR] {e:#}");
+            return;
+        }
+    };
+
+    metrics.detected.reset();
+    let mut drifted_count = 0;
+    for policy in &policies {
+        let diff = match policy::diff_policy(client, policy).await {
+            Ok(diff) => diff,
+            Err(e) => PolicyDiff::failed(
+                policy.metadata.namespace.as_deref().unwrap_or("default"),
+                policy.metadata.name.as_deref().unwrap_or("unnamed"),
+                e.to_string(),
+            ),
+        };
+        if diff.status == DiffStatus::Error {
+            metrics.errors.inc();
+            print!("[{now}] {}", policy::format_diff(&[diff], false));
+            continue;
+        }
+
+        let is_drifted = drifted(&diff);
+        metrics
+            .detected
+            .with_label_values(&[&diff.namespace, &diff.name])
+            .set(i64::from(is_drifted));
+        if !is_drifted {
+            continue;
+        }
+        drifted_count += 1;
+        warn!(
+            namespace = %diff.namespace,
+            policy = %diff.name,
+            changes = diff.changes.len(),
+            "policy_drift_detected"
+        );
+        print!(
+            "[{now}] {}",
+            policy::format_diff(std::slice::from_ref(&diff), output::use_color())
+        );
+
+        if revert {
+            match reapply(client, policy).await {
+                Ok(()) => {
+                    metrics.reverts.inc();
+                    metrics
+                        .detected
+                        .with_label_values(&[&diff.namespace, &diff.name])
+                        .set(0);
+                    println!(
+                        "  Reverted {}/{} to the Git copy",
+                        diff.namespace, diff.name
+                    );
+                }
+                Err(e) => {
+                    metrics.errors.inc();
+                    eprintln!("  Failed to revert {}/{}: {e}", diff.namespace, diff.name);
+                }
+            }
+        }
+    }
+    println!(
+        "[{now}] {} policy(ies) checked, {drifted_count} drifted",
+        policies.len()
+    );
+}
+
+/// Force-apply the Git copy of a policy as the CLI's field manager.
+async fn reapply(client: &Client, policy: &DevOpsPolicy) -> Result<()> {
+    let name = policy.metadata.name.as_deref().unwrap_or("unnamed");
+    let ns = policy.metadata.namespace.as_deref().unwrap_or("default");
+    let api: Api<DevOpsPolicy> = Api::namespaced(client.clone(), ns);
+    api.patch(
+        name,
+        &PatchParams::apply(policy_diff::CLI_FIELD_MANAGER).force(),
+        &Patch::Apply(policy),
+    )
+    .await?;
+    Ok(())
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEB: &str = "apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: web
+  namespace: prod
+spec:
+  forbidLatestTag: true
+";
+
+    const API: &str = "apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: api
+  namespace: prod
+spec:
+  maxRestartCount: 3
+";
+
+    #[test]
+    fn test_read_policies_from_directory() {
+        let dir = std::env::temp_dir().join(format!("kube-devops-drift-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("nested")).unwrap();
+        std::fs::write(dir.join("b-web.yaml"), WEB).unwrap();
+        std::fs::write(dir.join("a-api.yml"), API).unwrap();
+        std::fs::write(dir.join("README.md"), "not a policy").unwrap();
+        std::fs::write(dir.join("nested/skip.yaml"), WEB).unwrap();
+
+        let names: Vec<_> = read_policies(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.metadata.name.unwrap())
+            .collect();
+        assert_eq!(names, ["api", "web"]);
+        let single = read_policies(&dir.join("b-web.yaml")).unwrap();
+        assert_eq!(single.len(), 1);
+
+        std::fs::write(dir.join("c-bad.yaml"), "kind: [").unwrap();
+        let err = read_policies(&dir).unwrap_err();
+        assert!(format!("{err:#}").contains("c-bad.yaml"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_drifted() {
+        let spec = serde_json::json!({"maxRestartCount": 3});
+        let edited = serde_json::json!({"maxRestartCount": 10});
+        let in_sync = PolicyDiff::compare("prod", "api", &spec, Some((&spec, &[])));
+        let deleted = PolicyDiff::compare("prod", "api", &spec, None);
+        let changed = PolicyDiff::compare("prod", "api", &spec, Some((&edited, &[])));
+        let failed = PolicyDiff::failed("prod", "api", "forbidden".into());
+        assert!(!drifted(&in_sync));
+        assert!(drifted(&deleted));
+        assert!(drifted(&changed));
+        assert!(!drifted(&failed));
+    }
+
+    #[test]
+    fn test_metrics_register() {
+        let registry = Registry::new();
+        let metrics = DriftMetrics::register(&registry).unwrap();
+        metrics.detected.with_label_values(&["prod", "web"]).set(1);
+        let (_, body) = watch::encode_metrics(&registry);
+        assert!(body.contains("policy_drift_detected{namespace=\"prod\",policy=\"web\"} 1"));
+        assert!(body.contains("policy_drift_reverts_total 0"));
+    }
+}
@@ -0,0 +1,219 @@
+use anyhow::{Context, Result};
+
+use kube_devops::crd::QuarantineRecord;
+use kube_devops::enforcement::{self, RemediationBackup, WorkloadRef};
+use kube_devops::quarantine;
+
+use crate::commands::client;
+
+/* ============================= PARSING ============================= */
+
+/// Parse `<kind>/<name>` (or a bare name, meaning a Deployment) into a
+/// workload reference. Kinds accept kubectl-style short names.
+pub fn parse_workload(spec: &str, namespace: &str) -> Result<WorkloadRef> {
+    let (kind, name) = match spec.split_once('/') {
+        Some((kind, name)) => (kind, name),
+        None => ("deployment", spec),
+    };
+    let kind = match kind.to_lowercase().as_str() {
+        "deployment" | "deployments" | "deploy" => "Deployment",
+        "statefulset" | "statefulsets" | "sts" => "StatefulSet",
+        "daemonset" | "daemonsets" | "ds" => "DaemonSet",
+        other => anyhow::bail!(
+            "Unsupported workload kind '{other}' (expected deployment, statefulset, or daemonset)"
+        ),
+    };
+    if name.is_empty() {
+        anyhow::bail!("Workload name is empty in '{spec}'");
+    }
+    Ok(WorkloadRef {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+    })
+}
+
+/// Parse a workload for `enforce restore`; only scalable kinds can be
+/// quarantined.
+pub fn parse_scalable_workload(spec: &str, namespace: &str) -> Result<WorkloadRef> {
+    let workload = parse_workload(spec, namespace)?;
+    if workload.kind == "DaemonSet" {
+        anyhow::bail!("DaemonSets cannot be quarantined (expected deployment or statefulset)");
+    }
+    Ok(workload)
+}
+
+/* ============================= FORMATTING ============================= */
+
+/// One line per restored field: container, field, and the value it returns to.
+pub fn format_backup(backup: &RemediationBackup) -> String {
+    let mut out = String::new();
+    for (container, fields) in &backup.0 {
+        for (field, value) in fields {
+            let restore = if value.is_null() {
+                "(remove)".to_string()
+            } else {
+                value.to_string()
+            };
+            out.push_str(&format!("  {container:<20} {field:<16} {restore}\n"));
+        }
+    }
+    out
+}
+
+/// Summary of a quarantine record: policy, timestamp, replicas, violations.
+pub fn format_record(record: &QuarantineRecord) -> String {
+    let spec = &record.spec;
+    let mut out = format!(
+        "  Policy:      {}\n  Quarantined: {}\n  Replicas:    {}\n",
+        spec.policy_name, spec.quarantined_at, spec.original_replicas
+    );
+    for violation in &spec.violations {
+        out.push_str(&format!("  - {violation}\n"));
+    }
+    out
+}
+
+/* ============================= ROLLBACK ============================= */
+
+/// Revert operator-injected probes/resources on a workload.
+pub async fn rollback(workload: &str, namespace: &str, dry_run: bool) -> Result<()> {
+    let workload = parse_workload(workload, namespace)?;
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster")?;
+
+    let backup = if dry_run {
+        enforcement::read_remediation_backup(&workload, &client).await
+    } else {
+        enforcement::rollback_remediation(&workload, &client).await
+    }
+    .with_context(|| format!("Failed to roll back {}", workload.key()))?;
+
+    let Some(backup) = backup else {
+        println!(
+            "{}/{} in {} has no recorded remediations; nothing to roll back.",
+            workload.kind, workload.name, workload.namespace
+        );
+        return Ok(());
+    };
+
+    let verb = if dry_run { "Would restore" } else { "Restored" };
+    println!(
+        "{verb} {}/{} in {}:",
+        workload.kind, workload.name, workload.namespace
+    );
+    print!("{}", format_backup(&backup));
+    Ok(())
+}
+
+/* ============================= RESTORE ============================= */
+
+/// Scale a quarantined workload back up and approve it.
+pub async fn restore(workload: &str, namespace: &str, dry_run: bool) -> Result<()> {
+    let workload = parse_scalable_workload(workload, namespace)?;
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster")?;
+
+    let record = if dry_run {
+        quarantine::get_record(&workload, &client).await
+    } else {
+        quarantine::restore_workload(&workload, &client, &chrono::Utc::now().to_rfc3339()).await
+    }
+    .with_context(|| format!("Failed to restore {}", workload.key()))?;
+
+    let Some(record) = record else {
+        println!(
+            "{}/{} in {} is not quarantined; nothing to restore.",
+            workload.kind, workload.name, workload.namespace
+        );
+        return Ok(());
+    };
+
+    let verb = if dry_run { "Would restore" } else { "Restored" };
+    println!(
+        "{verb} {}/{} in {} to {} replicas:",
+        workload.kind, workload.name, workload.namespace, record.spec.original_replicas
+    );
+    print!("{}", format_record(&record));
+    Ok(())
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_parse_workload_kinds() {
+        let w = parse_workload("deployment/web", "prod").unwrap();
+        assert_eq!((w.kind.as_str(), w.name.as_str()), ("Deployment", "web"));
+        assert_eq!(w.namespace, "prod");
+
+        assert_eq!(
+            parse_workload("sts/db", "prod").unwrap().kind,
+            "StatefulSet"
+        );
+        assert_eq!(
+            parse_workload("DaemonSet/agent", "prod").unwrap().kind,
+            "DaemonSet"
+        );
+        assert_eq!(parse_workload("web", "prod").unwrap().kind, "Deployment");
+    }
+
+    #[test]
+    fn test_parse_workload_rejects_invalid() {
+        assert!(parse_workload("job/batch", "prod").is_err());
+        assert!(parse_workload("deployment/", "prod").is_err());
+    }
+
+    #[test]
+    fn test_parse_scalable_workload_rejects_daemonset() {
+        assert!(parse_scalable_workload("ds/agent", "prod").is_err());
+        assert_eq!(
+            parse_scalable_workload("sts/db", "prod").unwrap().kind,
+            "StatefulSet"
+        );
+    }
+
+    #[test]
+    fn test_format_record() {
+        let record = QuarantineRecord::new(
+            "deployment-web",
+            kube_devops::crd::QuarantineRecordSpec {
+                workload_kind: "Deployment".to_string(),
+                workload_name: "web".to_string(),
+                policy_name: "strict".to_string(),
+                original_replicas: 3,
+                violations: vec!["privileged_container (app)".to_string()],
+                quarantined_at: "2026-03-01T10:00:00Z".to_string(),
+            },
+        );
+        let out = format_record(&record);
+        assert!(out.contains("strict"));
+        assert!(out.contains("Replicas:    3"));
+        assert!(out.contains("- privileged_container (app)"));
+    }
+
+    #[test]
+    fn test_format_backup() {
+        let backup = RemediationBackup(BTreeMap::from([(
+            "main".to_string(),
+            BTreeMap::from([
+                ("livenessProbe".to_string(), serde_json::Value::Null),
+                (
+                    "resources".to_string(),
+                    serde_json::json!({"limits": {"cpu": "1"}}),
+                ),
+            ]),
+        )]));
+        let out = format_backup(&backup);
+        assert!(out.contains("livenessProbe"));
+        assert!(out.contains("(remove)"));
+        assert!(out.contains(r#"{"limits":{"cpu":"1"}}"#));
+        assert_eq!(out.lines().count(), 2);
+    }
+}
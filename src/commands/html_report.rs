@@ -0,0 +1,344 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use kube_devops::crd::Severity;
+use kube_devops::governance::{self, ViolationDetail};
+
+use crate::commands::analyze::AnalysisReport;
+
+/// Pods listed under "Worst offenders".
+const WORST_OFFENDERS: usize = 10;
+
+const SEVERITIES: [Severity; 4] = [
+    Severity::Critical,
+    Severity::High,
+    Severity::Medium,
+    Severity::Low,
+];
+
+const STYLE: &str = "body{font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;\
+margin:2em auto;max-width:1100px;color:#1f2328;padding:0 1em}\
+h1{margin-bottom:0}.meta{color:#59636e;margin-top:.3em}\
+table{border-collapse:collapse;width:100%;margin:.5em 0 2em}\
+th,td{border:1px solid #d1d9e0;padding:.35em .6em;text-align:left;font-size:.9em}\
+th{background:#f6f8fa}td.num{text-align:right}\
+.score{font-size:2.5em;font-weight:600}\
+.healthy{color:#1a7f37}.stable{color:#9a6700}.degraded{color:#bc4c00}.critical{color:#cf222e}\
+.sev-critical{background:#ffebe9}.sev-high{background:#fff1e5}";
+
+/* ============================= SECTIONS ============================= */
+
+/// Violations of one pod, by severity.
+#[derive(Debug, Default)]
+struct Offender<'a> {
+    namespace: &'a str,
+    pod: &'a str,
+    counts: [usize; 4],
+    types: Vec<&'a str>,
+}
+
+impl Offender<'_> {
+    /// Severity-weighted violation count, as in scoring.
+    fn weight(&self) -> u32 {
+        SEVERITIES
+            .iter()
+            .zip(self.counts)
+            .map(|(severity, count)| governance::severity_multiplier(severity) * count as u32)
+            .sum()
+    }
+}
+
+fn severity_index(severity: &Severity) -> usize {
+    SEVERITIES.iter().position(|s| s == severity).unwrap_or(2)
+}
+
+fn severity_counts<'a>(violations: impl Iterator<Item = &'a ViolationDetail>) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for v in violations {
+        counts[severity_index(&v.severity)] += 1;
+    }
+    counts
+}
+
+/// Pods with the most severe violations first, at most `WORST_OFFENDERS`.
+fn worst_offenders(violations: &[ViolationDetail]) -> Vec<Offender<'_>> {
+    let mut by_pod: BTreeMap<(&str, &str), Offender> = BTreeMap::new();
+    for v in violations {
+        let offender = by_pod
+            .entry((&v.namespace, &v.pod_name))
+            .or_insert_with(|| Offender {
+                namespace: &v.namespace,
+                pod: &v.pod_name,
+                ..Default::default()
+            });
+        offender.counts[severity_index(&v.severity)] += 1;
+        if !offender.types.contains(&v.violation_type.as_str()) {
+            offender.types.push(&v.violation_type);
+        }
+    }
+    let mut offenders: Vec<Offender> = by_pod.into_values().collect();
+    offenders.sort_by_key(|o| std::cmp::Reverse(o.weight()));
+    offenders.truncate(WORST_OFFENDERS);
+    offenders
+}
+
+/* ============================= RENDERING ============================= */
+
+/// Render an analysis as a self-contained HTML page: no scripts, no
+/// external stylesheets or images, so it survives being attached to a
+/// ticket or sent by email.
+pub fn render_html(report: &AnalysisReport, generated_at: DateTime<Utc>) -> String {
+    let mut out =
+        String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>kube-devops governance report</title>\n");
+    out.push_str(&format!("<style>{STYLE}</style>\n</head>\n<body>\n"));
+    out.push_str("<h1>Governance report</h1>\n");
+    out.push_str(&format!(
+        "<p class=\"meta\">Generated {} by kube-devops {} &middot; evaluated against {}</p>\n",
+        generated_at.format("%Y-%m-%d %H:%M:%S UTC"),
+        env!("CARGO_PKG_VERSION"),
+        escape(report.policy.as_deref().unwrap_or("the built-in checks"))
+    ));
+
+    // ── Score ──
+    out.push_str(&format!(
+        "<p><span class=\"score {}\">{}/100</span> {}</p>\n",
+        report.classification.to_lowercase(),
+        report.health_score,
+        escape(&report.classification)
+    ));
+    let counts = severity_counts(report.violations.iter());
+    out.push_str(&format!(
+        "<p>{} pod(s) analyzed, {} violation(s): {} critical, {} high, {} medium, {} low.</p>\n",
+        report.metrics.total_pods,
+        report.violations.len(),
+        counts[0],
+        counts[1],
+        counts[2],
+        counts[3]
+    ));
+
+    // ── Namespaces ──
+    out.push_str("<h2>Namespaces</h2>\n");
+    if report.namespaces.is_empty() {
+        out.push_str("<p>No workload pods found.</p>\n");
+    } else {
+        out.push_str(
+            "<table>\n<tr><th>Namespace</th><th>Score</th><th>Status</th><th>Pods</th>\
+             <th>Critical</th><th>High</th><th>Medium</th><th>Low</th></tr>\n",
+        );
+        for ns in &report.namespaces {
+            let counts = severity_counts(
+                report
+                    .violations
+                    .iter()
+                    .filter(|v| v.namespace == ns.namespace),
+            );
+            out.push_str(&format!(
+                "<tr><td>{}</td><td class=\"num {}\">{}</td><td>{}</td><td class=\"num\">{}</td>{}</tr>\n",
+                escape(&ns.namespace),
+                ns.classification.to_lowercase(),
+                ns.health_score,
+                escape(&ns.classification),
+                ns.pods,
+                count_cells(counts)
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    // ── Worst offenders ──
+    out.push_str("<h2>Worst offenders</h2>\n");
+    let offenders = worst_offenders(&report.violations);
+    if offenders.is_empty() {
+        out.push_str("<p>No violations.</p>\n");
+    } else {
+        out.push_str(
+            "<table>\n<tr><th>Namespace</th><th>Pod</th><th>Critical</th><th>High</th>\
+             <th>Medium</th><th>Low</th><th>Violations</th></tr>\n",
+        );
+        for o in &offenders {
+            let class = match o.counts {
+                [c, ..] if c > 0 => " class=\"sev-critical\"",
+                [_, h, ..] if h > 0 => " class=\"sev-high\"",
+                _ => "",
+            };
+            out.push_str(&format!(
+                "<tr{class}><td>{}</td><td>{}</td>{}<td>{}</td></tr>\n",
+                escape(o.namespace),
+                escape(o.pod),
+                count_cells(o.counts),
+                escape(&o.types.join(", "))
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    // ── Remediation ──
+    let mut by_type: BTreeMap<&str, (usize, &Severity)> = BTreeMap::new();
+    for v in &report.violations {
+        by_type
+            .entry(&v.violation_type)
+            .or_insert((0, &v.severity))
+            .0 += 1;
+    }
+    if !by_type.is_empty() {
+        out.push_str("<h2>Remediation</h2>\n");
+        out.push_str(
+            "<table>\n<tr><th>Violation</th><th>Severity</th><th>Count</th><th>Suggested fix</th></tr>\n",
+        );
+        let mut types: Vec<_> = by_type.into_iter().collect();
+        types.sort_by_key(|(_, (count, severity))| {
+            (severity_index(severity), std::cmp::Reverse(*count))
+        });
+        for (violation_type, (count, severity)) in types {
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td class=\"num\">{count}</td><td>{}</td></tr>\n",
+                escape(violation_type),
+                format!("{severity:?}").to_lowercase(),
+                escape(governance::remediation_hint(violation_type))
+            ));
+        }
+        out.push_str("</table>\n");
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn count_cells(counts: [usize; 4]) -> String {
+    counts
+        .iter()
+        .map(|c| format!("<td class=\"num\">{c}</td>"))
+        .collect()
+}
+
+/// Escape text for HTML element content and attribute values.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::analyze::NamespaceSummary;
+    use kube_devops::governance::PodMetrics;
+
+    fn violation(namespace: &str, pod: &str, violation_type: &str) -> ViolationDetail {
+        ViolationDetail {
+            violation_type: violation_type.to_string(),
+            severity: governance::default_severity(violation_type),
+            pod_name: pod.to_string(),
+            namespace: namespace.to_string(),
+            container_name: "main".to_string(),
+            message: String::new(),
+        }
+    }
+
+    fn report(violations: Vec<ViolationDetail>) -> AnalysisReport {
+        AnalysisReport {
+            policy: Some("bundle 'baseline'".to_string()),
+            health_score: 62,
+            classification: "Degraded".to_string(),
+            metrics: PodMetrics {
+                total_pods: 3,
+                ..Default::default()
+            },
+            violations,
+            namespaces: vec![NamespaceSummary {
+                namespace: "prod".to_string(),
+                health_score: 62,
+                classification: "Degraded".to_string(),
+                pods: 3,
+                violations: 4,
+            }],
+        }
+    }
+
+    fn generated_at() -> DateTime<Utc> {
+        "2026-03-01T12:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape("<a href=\"x\">'&'</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn test_worst_offenders_order() {
+        let violations = vec![
+            violation("prod", "web", "missing_readiness"),
+            violation("prod", "web", "missing_liveness"),
+            violation("prod", "api", "crash_loop"),
+            violation("prod", "db", "latest_tag"),
+        ];
+        let offenders = worst_offenders(&violations);
+        let pods: Vec<&str> = offenders.iter().map(|o| o.pod).collect();
+        // crash_loop (3) > latest_tag (2) = liveness + readiness (1 + 1)
+        assert_eq!(pods, ["api", "db", "web"]);
+        assert_eq!(offenders[2].counts, [0, 0, 1, 1]);
+        assert_eq!(
+            offenders[2].types,
+            ["missing_readiness", "missing_liveness"]
+        );
+
+        let many: Vec<_> = (0..15)
+            .map(|i| violation("prod", &format!("pod-{i}"), "pending"))
+            .collect();
+        assert_eq!(worst_offenders(&many).len(), WORST_OFFENDERS);
+    }
+
+    #[test]
+    fn test_render_html() {
+        let html = render_html(
+            &report(vec![
+                violation("prod", "web", "latest_tag"),
+                violation("prod", "web", "missing_liveness"),
+                violation("prod", "api<script>", "crash_loop"),
+                violation("prod", "api<script>", "missing_liveness"),
+            ]),
+            generated_at(),
+        );
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.ends_with("</html>\n"));
+        assert!(html.contains("Generated 2026-03-01 12:00:00 UTC"));
+        assert!(html.contains("bundle &#39;baseline&#39;"));
+        assert!(html.contains("62/100"));
+        assert!(html.contains("4 violation(s): 1 critical, 1 high, 2 medium, 0 low"));
+        assert!(html.contains("api&lt;script&gt;"));
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains("http://") && !html.contains("https://"));
+        // Remediation rows: critical first, then by count
+        let crash = html.find("Inspect the exit code").unwrap();
+        let latest = html.find("Pin the image").unwrap();
+        let liveness = html.find("Add a livenessProbe").unwrap();
+        assert!(crash < latest && latest < liveness);
+    }
+
+    #[test]
+    fn test_render_html_without_violations() {
+        let mut clean = report(Vec::new());
+        clean.namespaces.clear();
+        let html = render_html(&clean, generated_at());
+        assert!(html.contains("No workload pods found."));
+        assert!(html.contains("No violations."));
+        assert!(!html.contains("<h2>Remediation</h2>"));
+    }
+}
@@ -0,0 +1,199 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use kube_devops::junit::{self, TestSuite};
+use kube_devops::lint::{self, LintFinding};
+use kube_devops::output::{self, OutputFormat};
+use kube_devops::{admission, bundles, governance};
+
+/* ============================= COMMAND ============================= */
+
+/// Lint local manifests against a policy file (or the baseline bundle).
+///
+/// Exits with an error when any violation is found so CI jobs fail.
+pub fn run(path: &str, policy_file: Option<&str>, format: OutputFormat) -> Result<()> {
+    let policy = match policy_file {
+        Some(file) => {
+            let content = std::fs::read_to_string(file)
+                .with_context(|| format!("Failed to read policy file '{file}'"))?;
+            lint::parse_policy(&content).with_context(|| format!("Invalid policy file '{file}'"))?
+        }
+        None => {
+            bundles::get_bundle("baseline")
+                .expect("baseline bundle exists")
+                .spec
+        }
+    };
+
+    let files = collect_manifest_files(Path::new(path))?;
+    let mut findings: Vec<LintFinding> = Vec::new();
+
+    for file in &files {
+        let display = file.display().to_string();
+        let content = std::fs::read_to_string(file)
+            .with_context(|| format!("Failed to read manifest '{display}'"))?;
+        let workloads = lint::parse_workloads(&content)
+            .with_context(|| format!("Failed to parse manifest '{display}'"))?;
+        findings.extend(lint::lint_workloads(&display, &workloads, &policy));
+    }
+
+    let total: usize = findings.iter().map(|f| f.violations.len()).sum();
+
+    if format == OutputFormat::Junit {
+        let checks =
+            governance::enabled_checks(&admission::build_admission_policy_for_validation(&policy));
+        print!(
+            "{}",
+            junit::render("kube-devops lint", &junit_suites(&findings, &checks))
+        );
+    } else if format.is_structured() {
+        output::print_structured(&findings, format)?;
+    } else {
+        print_findings(&findings, files.len(), total);
+    }
+
+    if total > 0 {
+        anyhow::bail!("{total} violation(s) found");
+    }
+    Ok(())
+}
+
+/// One JUnit suite per manifest file, with a test case per workload and check.
+fn junit_suites(findings: &[LintFinding], checks: &[&str]) -> Vec<TestSuite> {
+    let mut suites: Vec<TestSuite> = Vec::new();
+    for finding in findings {
+        if suites.last().is_none_or(|s| s.name != finding.file) {
+            suites.push(TestSuite {
+                name: finding.file.clone(),
+                cases: Vec::new(),
+            });
+        }
+        let classname = format!("{}/{}/{}", finding.kind, finding.namespace, finding.name);
+        let suite = suites.last_mut().expect("suite pushed above");
+        for check in junit::checks_with_found(checks, &finding.violations) {
+            suite
+                .cases
+                .push(junit::check_case(&classname, check, &finding.violations));
+        }
+    }
+    suites
+}
+
+fn print_findings(findings: &[LintFinding], file_count: usize, total: usize) {
+    for finding in findings {
+        let status = if finding.violations.is_empty() {
+            "OK"
+        } else {
+            "FAIL"
+        };
+        println!(
+            "[{status}] {}: {}/{} ({})",
+            finding.file, finding.kind, finding.name, finding.namespace
+        );
+        for v in &finding.violations {
+            println!("    {:<9} {}", format!("{:?}", v.severity), v.message);
+        }
+    }
+    println!(
+        "\n{} workload(s) in {file_count} file(s): {total} violation(s)",
+        findings.len()
+    );
+}
+
+/// Resolve a path to the list of YAML files it contains (recursively).
+fn collect_manifest_files(path: &Path) -> Result<Vec<PathBuf>> {
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    if !path.is_dir() {
+        anyhow::bail!("Path '{}' does not exist", path.display());
+    }
+
+    let mut files = Vec::new();
+    let mut stack = vec![path.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        for entry in std::fs::read_dir(&dir)
+            .with_context(|| format!("Failed to read directory '{}'", dir.display()))?
+        {
+            let entry_path = entry?.path();
+            if entry_path.is_dir() {
+                stack.push(entry_path);
+            } else if is_yaml(&entry_path) {
+                files.push(entry_path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn is_yaml(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("yaml") | Some("yml")
+    )
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_yaml() {
+        assert!(is_yaml(Path::new("deploy.yaml")));
+        assert!(is_yaml(Path::new("a/b/pod.yml")));
+        assert!(!is_yaml(Path::new("README.md")));
+        assert!(!is_yaml(Path::new("Makefile")));
+    }
+
+    #[test]
+    fn test_collect_manifest_files_recurses() {
+        let dir = std::env::temp_dir().join(format!("kube-devops-lint-{}", std::process::id()));
+        let nested = dir.join("nested");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(dir.join("a.yaml"), "").unwrap();
+        std::fs::write(nested.join("b.yml"), "").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+
+        let files = collect_manifest_files(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().all(|f| is_yaml(f)));
+    }
+
+    #[test]
+    fn test_junit_suites() {
+        let policy = bundles::get_bundle("baseline").unwrap().spec;
+        let workloads = lint::parse_workloads(
+            "apiVersion: v1\nkind: Pod\nmetadata: {name: web, namespace: prod}\n\
+             spec: {containers: [{name: main, image: 'nginx:latest'}]}\n",
+        )
+        .unwrap();
+        let mut findings = lint::lint_workloads("a.yaml", &workloads, &policy);
+        findings.extend(lint::lint_workloads("a.yaml", &workloads, &policy));
+        findings.extend(lint::lint_workloads("b.yaml", &workloads, &policy));
+
+        let checks =
+            governance::enabled_checks(&admission::build_admission_policy_for_validation(&policy));
+        let suites = junit_suites(&findings, &checks);
+        assert_eq!(suites.len(), 2);
+        assert_eq!(suites[0].cases.len(), 2 * checks.len());
+        assert_eq!(suites[0].cases[0].classname, "Pod/prod/web");
+        let failed: Vec<&str> = suites[1]
+            .cases
+            .iter()
+            .filter(|c| c.failure.is_some())
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(failed.len(), findings[2].violations.len());
+        assert!(failed.contains(&"latest_tag"));
+    }
+
+    #[test]
+    fn test_collect_manifest_files_missing_path() {
+        assert!(collect_manifest_files(Path::new("/nonexistent/kube-devops")).is_err());
+    }
+}
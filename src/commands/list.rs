@@ -1,58 +1,706 @@
+use std::fmt::Debug;
+
 use anyhow::Context;
-use k8s_openapi::api::core::v1::Pod;
+use k8s_openapi::NamespaceResourceScope;
+use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
+use k8s_openapi::api::core::v1::{Pod, PodTemplateSpec, Service};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
 use kube::api::ListParams;
-use kube::{Api, Client};
+use kube::{Api, Client, Resource};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value, json};
+
+use kube_devops::crd::{DevOpsPolicy, EnforcementMode, PolicyAuditResult};
+use kube_devops::output::{self, OutputFormat};
+
+use crate::commands::client;
+
+/* ============================= RESOURCES ============================= */
+
+/// Resource kinds `list` can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ResourceKind {
+    #[value(alias = "pod", alias = "po")]
+    Pods,
+    #[value(alias = "deployment", alias = "deploy")]
+    Deployments,
+    #[value(alias = "statefulset", alias = "sts")]
+    Statefulsets,
+    #[value(alias = "daemonset", alias = "ds")]
+    Daemonsets,
+    #[value(alias = "service", alias = "svc")]
+    Services,
+    /// DevOpsPolicies
+    #[value(alias = "policy", alias = "devopspolicies")]
+    Policies,
+    /// PolicyAuditResults
+    #[value(alias = "audit-result", alias = "policyauditresults")]
+    AuditResults,
+}
 
-pub async fn run(resource: String) -> anyhow::Result<()> {
-    if resource != "pods" {
-        anyhow::bail!("Unsupported resource '{}'. Supported: pods", resource);
+impl ResourceKind {
+    fn plural(self) -> &'static str {
+        match self {
+            ResourceKind::Pods => "pods",
+            ResourceKind::Deployments => "deployments",
+            ResourceKind::Statefulsets => "statefulsets",
+            ResourceKind::Daemonsets => "daemonsets",
+            ResourceKind::Services => "services",
+            ResourceKind::Policies => "DevOpsPolicies",
+            ResourceKind::AuditResults => "PolicyAuditResults",
+        }
     }
 
-    let client = Client::try_default()
-        .await
-        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    /// Columns after NAMESPACE and NAME.
+    fn columns(self) -> &'static [Column] {
+        match self {
+            ResourceKind::Pods => POD_COLUMNS,
+            ResourceKind::Deployments => DEPLOYMENT_COLUMNS,
+            ResourceKind::Statefulsets => STATEFULSET_COLUMNS,
+            ResourceKind::Daemonsets => DAEMONSET_COLUMNS,
+            ResourceKind::Services => SERVICE_COLUMNS,
+            ResourceKind::Policies => POLICY_COLUMNS,
+            ResourceKind::AuditResults => AUDIT_RESULT_COLUMNS,
+        }
+    }
+}
 
-    let pods: Api<Pod> = Api::all(client);
+/// A table column; `key` names the field in structured output.
+#[derive(Debug)]
+struct Column {
+    header: &'static str,
+    key: &'static str,
+    /// Only shown in the table with `--wide`.
+    wide: bool,
+}
 
-    let pod_list = pods
-        .list(&ListParams::default())
-        .await
-        .context("Failed to list pods. Check RBAC permissions.")?;
+const fn col(header: &'static str, key: &'static str) -> Column {
+    Column {
+        header,
+        key,
+        wide: false,
+    }
+}
+
+const fn wide(header: &'static str, key: &'static str) -> Column {
+    Column {
+        header,
+        key,
+        wide: true,
+    }
+}
+
+const POD_COLUMNS: &[Column] = &[
+    col("STATUS", "status"),
+    col("NODE", "node"),
+    wide("READY", "ready"),
+    wide("RESTARTS", "restarts"),
+    wide("IP", "ip"),
+];
+const DEPLOYMENT_COLUMNS: &[Column] = &[
+    col("READY", "ready"),
+    col("UP-TO-DATE", "upToDate"),
+    col("AVAILABLE", "available"),
+    wide("IMAGES", "images"),
+    wide("SELECTOR", "selector"),
+];
+const STATEFULSET_COLUMNS: &[Column] = &[col("READY", "ready"), wide("IMAGES", "images")];
+const DAEMONSET_COLUMNS: &[Column] = &[
+    col("DESIRED", "desired"),
+    col("READY", "ready"),
+    col("AVAILABLE", "available"),
+    wide("IMAGES", "images"),
+    wide("SELECTOR", "selector"),
+];
+const SERVICE_COLUMNS: &[Column] = &[
+    col("TYPE", "type"),
+    col("CLUSTER-IP", "clusterIp"),
+    col("PORTS", "ports"),
+    wide("SELECTOR", "selector"),
+];
+const POLICY_COLUMNS: &[Column] = &[
+    col("MODE", "enforcementMode"),
+    col("SCORE", "healthScore"),
+    col("VIOLATIONS", "violations"),
+    wide("CLASSIFICATION", "classification"),
+    wide("LAST EVALUATED", "lastEvaluated"),
+];
+const AUDIT_RESULT_COLUMNS: &[Column] = &[
+    col("POLICY", "policy"),
+    col("SCORE", "healthScore"),
+    col("VIOLATIONS", "violations"),
+    col("TIMESTAMP", "timestamp"),
+    wide("CLASSIFICATION", "classification"),
+    wide("PODS", "totalPods"),
+];
+
+/// One listed object: namespace, name, and one value per column.
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub namespace: String,
+    pub name: String,
+    pub values: Vec<Value>,
+}
+
+fn row<K: Resource>(obj: &K, values: Vec<Value>) -> Row {
+    let meta = obj.meta();
+    Row {
+        namespace: meta.namespace.clone().unwrap_or_default(),
+        name: meta.name.clone().unwrap_or_default(),
+        values,
+    }
+}
+
+/* ============================= ROWS ============================= */
+
+fn pod_row(p: &Pod) -> Row {
+    let status = p.status.as_ref();
+    let statuses = status
+        .and_then(|s| s.container_statuses.as_deref())
+        .unwrap_or_default();
+    let containers = p.spec.as_ref().map_or(0, |s| s.containers.len());
+    row(
+        p,
+        vec![
+            json!(status.and_then(|s| s.phase.as_deref()).unwrap_or("Unknown")),
+            json!(
+                p.spec
+                    .as_ref()
+                    .and_then(|s| s.node_name.as_deref())
+                    .unwrap_or("Not Scheduled")
+            ),
+            json!(format!(
+                "{}/{containers}",
+                statuses.iter().filter(|s| s.ready).count()
+            )),
+            json!(statuses.iter().map(|s| s.restart_count).sum::<i32>()),
+            json!(status.and_then(|s| s.pod_ip.as_deref())),
+        ],
+    )
+}
+
+fn deployment_row(d: &Deployment) -> Row {
+    let status = d.status.as_ref();
+    let spec = d.spec.as_ref();
+    let replicas = spec.and_then(|s| s.replicas).unwrap_or(1);
+    row(
+        d,
+        vec![
+            json!(format!(
+                "{}/{replicas}",
+                status.and_then(|s| s.ready_replicas).unwrap_or(0)
+            )),
+            json!(status.and_then(|s| s.updated_replicas).unwrap_or(0)),
+            json!(status.and_then(|s| s.available_replicas).unwrap_or(0)),
+            json!(images(spec.map(|s| &s.template))),
+            json!(label_selector(spec.map(|s| &s.selector))),
+        ],
+    )
+}
+
+fn statefulset_row(s: &StatefulSet) -> Row {
+    let spec = s.spec.as_ref();
+    let replicas = spec.and_then(|s| s.replicas).unwrap_or(1);
+    let ready = s
+        .status
+        .as_ref()
+        .and_then(|s| s.ready_replicas)
+        .unwrap_or(0);
+    row(
+        s,
+        vec![
+            json!(format!("{ready}/{replicas}")),
+            json!(images(spec.map(|s| &s.template))),
+        ],
+    )
+}
+
+fn daemonset_row(d: &DaemonSet) -> Row {
+    let status = d.status.as_ref();
+    let spec = d.spec.as_ref();
+    row(
+        d,
+        vec![
+            json!(status.map_or(0, |s| s.desired_number_scheduled)),
+            json!(status.map_or(0, |s| s.number_ready)),
+            json!(status.and_then(|s| s.number_available).unwrap_or(0)),
+            json!(images(spec.map(|s| &s.template))),
+            json!(label_selector(spec.map(|s| &s.selector))),
+        ],
+    )
+}
 
-    let mut rows: Vec<(String, String, String, String)> = pod_list
+fn service_row(s: &Service) -> Row {
+    let spec = s.spec.as_ref();
+    let ports: Vec<String> = spec
+        .and_then(|s| s.ports.as_ref())
         .into_iter()
-        .map(|p| {
-            let namespace = p.metadata.namespace.unwrap_or_default();
-            let name = p.metadata.name.unwrap_or_default();
-            let phase = p
-                .status
-                .as_ref()
-                .and_then(|s| s.phase.as_deref())
-                .unwrap_or("Unknown")
-                .to_string();
-            let node = p
-                .spec
-                .as_ref()
-                .and_then(|s| s.node_name.as_deref())
-                .unwrap_or("Not Scheduled")
-                .to_string();
-            (namespace, name, phase, node)
+        .flatten()
+        .map(|p| format!("{}/{}", p.port, p.protocol.as_deref().unwrap_or("TCP")))
+        .collect();
+    let selector = spec.and_then(|s| s.selector.as_ref()).map(|labels| {
+        labels
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    row(
+        s,
+        vec![
+            json!(spec.and_then(|s| s.type_.as_deref()).unwrap_or("ClusterIP")),
+            json!(spec.and_then(|s| s.cluster_ip.as_deref())),
+            json!(ports.join(",")),
+            json!(selector),
+        ],
+    )
+}
+
+fn policy_row(p: &DevOpsPolicy) -> Row {
+    let status = p.status.as_ref();
+    row(
+        p,
+        vec![
+            json!(
+                p.spec
+                    .enforcement_mode
+                    .as_ref()
+                    .map_or("audit", EnforcementMode::as_str)
+            ),
+            json!(status.and_then(|s| s.health_score)),
+            json!(status.and_then(|s| s.violations)),
+            json!(status.and_then(|s| s.classification.as_deref())),
+            json!(status.and_then(|s| s.last_evaluated.as_deref())),
+        ],
+    )
+}
+
+fn audit_result_row(r: &PolicyAuditResult) -> Row {
+    row(
+        r,
+        vec![
+            json!(r.spec.policy_name),
+            json!(r.spec.health_score),
+            json!(r.spec.total_violations),
+            json!(r.spec.timestamp),
+            json!(r.spec.classification),
+            json!(r.spec.total_pods),
+        ],
+    )
+}
+
+/// Comma-separated container images of a pod template.
+fn images(template: Option<&PodTemplateSpec>) -> String {
+    template
+        .and_then(|t| t.spec.as_ref())
+        .map(|spec| {
+            spec.containers
+                .iter()
+                .filter_map(|c| c.image.as_deref())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default()
+}
+
+/// A label selector's `matchLabels` as `k=v,...`.
+fn label_selector(selector: Option<&LabelSelector>) -> Option<String> {
+    let labels = selector?.match_labels.as_ref()?;
+    Some(
+        labels
+            .iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join(","),
+    )
+}
+
+/* ============================= RENDERING ============================= */
+
+/// Rows as objects keyed by column, for `--output json|yaml`. Wide columns
+/// are always included.
+fn structured_rows(kind: ResourceKind, rows: &[Row]) -> Vec<Map<String, Value>> {
+    rows.iter()
+        .map(|r| {
+            let mut obj = Map::new();
+            obj.insert("namespace".to_string(), json!(r.namespace));
+            obj.insert("name".to_string(), json!(r.name));
+            for (column, value) in kind.columns().iter().zip(&r.values) {
+                obj.insert(column.key.to_string(), value.clone());
+            }
+            obj
         })
+        .collect()
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "<none>".to_string(),
+        Value::String(s) if s.is_empty() => "<none>".to_string(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Render rows as a table sized to its contents.
+fn format_table(kind: ResourceKind, rows: &[Row], show_wide: bool) -> String {
+    let shown: Vec<usize> = kind
+        .columns()
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| show_wide || !c.wide)
+        .map(|(i, _)| i)
         .collect();
+    let mut lines: Vec<Vec<String>> = vec![
+        ["NAMESPACE", "NAME"]
+            .into_iter()
+            .chain(shown.iter().map(|&i| kind.columns()[i].header))
+            .map(str::to_string)
+            .collect(),
+    ];
+    for r in rows {
+        let mut line = vec![r.namespace.clone(), r.name.clone()];
+        line.extend(shown.iter().map(|&i| cell(&r.values[i])));
+        lines.push(line);
+    }
 
-    rows.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.cmp(&b.1)));
+    let widths: Vec<usize> = (0..lines[0].len())
+        .map(|i| lines.iter().map(|l| l[i].len()).max().unwrap_or(0))
+        .collect();
+    let mut out = String::new();
+    for line in &lines {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{c:<w$}"))
+            .collect();
+        out.push_str(cells.join("   ").trim_end());
+        out.push('\n');
+    }
+    out.push_str(&format!("\nTotal: {} {}\n", rows.len(), kind.plural()));
+    out
+}
+
+/* ============================= COMMAND ============================= */
 
-    println!(
-        "{:<20} {:<60} {:<12} {:<15}",
-        "NAMESPACE", "NAME", "STATUS", "NODE"
-    );
-    println!("{}", "-".repeat(107));
+/// Label and field selectors and table width for `list`.
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    pub selector: Option<String>,
+    pub field_selector: Option<String>,
+    pub wide: bool,
+}
 
-    for (namespace, name, phase, node) in &rows {
-        println!("{:<20} {:<60} {:<12} {:<15}", namespace, name, phase, node);
+impl ListOptions {
+    fn params(&self) -> ListParams {
+        let mut params = ListParams::default();
+        if let Some(selector) = &self.selector {
+            params = params.labels(selector);
+        }
+        if let Some(selector) = &self.field_selector {
+            params = params.fields(selector);
+        }
+        params
     }
+}
+
+async fn fetch<K>(
+    client: Client,
+    namespace: Option<&str>,
+    params: &ListParams,
+) -> anyhow::Result<Vec<K>>
+where
+    K: Resource<Scope = NamespaceResourceScope> + Clone + DeserializeOwned + Debug,
+    K::DynamicType: Default,
+{
+    let api: Api<K> = match namespace {
+        Some(ns) => Api::namespaced(client, ns),
+        None => Api::all(client),
+    };
+    let plural = K::plural(&K::DynamicType::default()).into_owned();
+    Ok(api
+        .list(params)
+        .await
+        .with_context(|| format!("Failed to list {plural}. Check RBAC permissions and selectors."))?
+        .items)
+}
+
+/// List resources of one kind across all namespaces, or in `namespace`.
+pub async fn run(
+    kind: ResourceKind,
+    namespace: Option<&str>,
+    options: &ListOptions,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    let params = options.params();
 
-    println!("\nTotal: {} pods", rows.len());
+    let mut rows: Vec<Row> = match kind {
+        ResourceKind::Pods => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(pod_row)
+            .collect(),
+        ResourceKind::Deployments => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(deployment_row)
+            .collect(),
+        ResourceKind::Statefulsets => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(statefulset_row)
+            .collect(),
+        ResourceKind::Daemonsets => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(daemonset_row)
+            .collect(),
+        ResourceKind::Services => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(service_row)
+            .collect(),
+        ResourceKind::Policies => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(policy_row)
+            .collect(),
+        ResourceKind::AuditResults => fetch(client, namespace, &params)
+            .await?
+            .iter()
+            .map(audit_result_row)
+            .collect(),
+    };
 
+    rows.sort_by(|a, b| a.namespace.cmp(&b.namespace).then(a.name.cmp(&b.name)));
+
+    if format.is_structured() {
+        return output::print_structured(&structured_rows(kind, &rows), format);
+    }
+    print!("{}", format_table(kind, &rows, options.wide));
     Ok(())
 }
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::apps::v1::{DeploymentSpec, DeploymentStatus};
+    use k8s_openapi::api::core::v1::{
+        Container, ContainerStatus, PodSpec, PodStatus, ServicePort, ServiceSpec,
+    };
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+    use kube_devops::crd::PolicyAuditResultSpec;
+
+    fn meta(name: &str) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some("prod".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_every_kind_has_matching_columns() {
+        use clap::ValueEnum;
+        let rows = [
+            (ResourceKind::Pods, pod_row(&Pod::default())),
+            (
+                ResourceKind::Deployments,
+                deployment_row(&Deployment::default()),
+            ),
+            (
+                ResourceKind::Statefulsets,
+                statefulset_row(&StatefulSet::default()),
+            ),
+            (
+                ResourceKind::Daemonsets,
+                daemonset_row(&DaemonSet::default()),
+            ),
+            (ResourceKind::Services, service_row(&Service::default())),
+            (
+                ResourceKind::Policies,
+                policy_row(&DevOpsPolicy::new("p", Default::default())),
+            ),
+            (
+                ResourceKind::AuditResults,
+                audit_result_row(&PolicyAuditResult::new(
+                    "r",
+                    PolicyAuditResultSpec {
+                        policy_name: "web".to_string(),
+                        cluster_name: None,
+                        timestamp: "2026-03-01T12:00:00Z".to_string(),
+                        health_score: 90,
+                        total_violations: 1,
+                        total_pods: 4,
+                        total_workloads: None,
+                        classification: "Healthy".to_string(),
+                        violations: Vec::new(),
+                        planned_remediations: None,
+                    },
+                )),
+            ),
+        ];
+        assert_eq!(rows.len(), ResourceKind::value_variants().len());
+        for (kind, row) in rows {
+            assert_eq!(row.values.len(), kind.columns().len(), "{kind:?}");
+        }
+    }
+
+    #[test]
+    fn test_kind_aliases() {
+        use clap::ValueEnum;
+        for (alias, kind) in [
+            ("po", ResourceKind::Pods),
+            ("deploy", ResourceKind::Deployments),
+            ("sts", ResourceKind::Statefulsets),
+            ("ds", ResourceKind::Daemonsets),
+            ("svc", ResourceKind::Services),
+            ("devopspolicies", ResourceKind::Policies),
+            ("audit-results", ResourceKind::AuditResults),
+        ] {
+            assert_eq!(ResourceKind::from_str(alias, false).unwrap(), kind);
+        }
+    }
+
+    #[test]
+    fn test_pod_row_defaults_for_unscheduled_pod() {
+        let pod = Pod {
+            metadata: meta("web"),
+            ..Default::default()
+        };
+        let row = pod_row(&pod);
+        assert_eq!(row.values[0], "Unknown");
+        assert_eq!(row.values[1], "Not Scheduled");
+        assert_eq!(row.values[4], Value::Null);
+    }
+
+    #[test]
+    fn test_pod_row_ready_and_restarts() {
+        let status = |ready, restart_count| ContainerStatus {
+            ready,
+            restart_count,
+            ..Default::default()
+        };
+        let pod = Pod {
+            metadata: meta("web"),
+            spec: Some(PodSpec {
+                containers: vec![Container::default(), Container::default()],
+                node_name: Some("node-1".to_string()),
+                ..Default::default()
+            }),
+            status: Some(PodStatus {
+                phase: Some("Running".to_string()),
+                container_statuses: Some(vec![status(true, 2), status(false, 5)]),
+                pod_ip: Some("10.0.0.7".to_string()),
+                ..Default::default()
+            }),
+        };
+        let row = pod_row(&pod);
+        assert_eq!(
+            row.values,
+            [
+                json!("Running"),
+                json!("node-1"),
+                json!("1/2"),
+                json!(7),
+                json!("10.0.0.7")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deployment_row() {
+        let deployment = Deployment {
+            metadata: meta("web"),
+            spec: Some(DeploymentSpec {
+                replicas: Some(3),
+                selector: LabelSelector {
+                    match_labels: Some([("app".to_string(), "web".to_string())].into()),
+                    ..Default::default()
+                },
+                template: PodTemplateSpec {
+                    spec: Some(PodSpec {
+                        containers: vec![
+                            Container {
+                                image: Some("nginx:1.25".to_string()),
+                                ..Default::default()
+                            },
+                            Container {
+                                image: Some("envoy:1.29".to_string()),
+                                ..Default::default()
+                            },
+                        ],
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            status: Some(DeploymentStatus {
+                ready_replicas: Some(2),
+                updated_replicas: Some(3),
+                available_replicas: Some(2),
+                ..Default::default()
+            }),
+        };
+        let row = deployment_row(&deployment);
+        assert_eq!(
+            row.values,
+            [
+                json!("2/3"),
+                json!(3),
+                json!(2),
+                json!("nginx:1.25,envoy:1.29"),
+                json!("app=web")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_table_and_wide() {
+        let service = Service {
+            metadata: meta("web"),
+            spec: Some(ServiceSpec {
+                cluster_ip: Some("10.96.0.10".to_string()),
+                ports: Some(vec![ServicePort {
+                    port: 80,
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let rows = [service_row(&service)];
+        assert_eq!(
+            format_table(ResourceKind::Services, &rows, false),
+            "NAMESPACE   NAME   TYPE        CLUSTER-IP   PORTS\n\
+             prod        web    ClusterIP   10.96.0.10   80/TCP\n\
+             \nTotal: 1 services\n"
+        );
+        let wide = format_table(ResourceKind::Services, &rows, true);
+        assert!(
+            wide.starts_with("NAMESPACE   NAME   TYPE        CLUSTER-IP   PORTS    SELECTOR\n")
+        );
+        assert!(wide.contains("80/TCP   <none>\n"));
+    }
+
+    #[test]
+    fn test_structured_rows_include_wide_columns() {
+        let pod = Pod {
+            metadata: meta("web"),
+            ..Default::default()
+        };
+        let rows = structured_rows(ResourceKind::Pods, &[pod_row(&pod)]);
+        let yaml = output::render(&rows, OutputFormat::Yaml).unwrap();
+        let v: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(v[0]["name"], "web");
+        assert_eq!(v[0]["node"], "Not Scheduled");
+        assert_eq!(v[0]["restarts"], 0);
+        assert!(v[0]["ip"].is_null());
+    }
+}
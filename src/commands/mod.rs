@@ -1,12 +1,26 @@
 pub mod analyze;
+pub mod audit;
+pub mod audit_sink;
+pub mod canary;
 pub mod check;
+pub(crate) mod client;
 pub mod crd;
 pub mod deploy;
+pub mod devtools;
+pub mod doctor;
+pub mod drift;
+pub mod enforce;
+pub mod html_report;
+pub mod lint;
 pub mod list;
 pub mod multi_cluster;
+pub mod notifier;
 pub mod observability;
+pub(crate) mod pod_cache;
 pub mod policy;
+pub(crate) mod policy_cache;
 pub mod reconcile;
+pub mod score;
 pub mod version;
 pub mod watch;
 pub mod webhook;
@@ -1,12 +1,69 @@
-use anyhow::Result;
-use kube_devops::bundles;
-use kube_devops::multi_cluster;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use axum::response::IntoResponse;
+use axum::routing::get;
+use axum::{Json, Router, http::StatusCode};
+use kube::api::{Patch, PatchParams};
+use kube::{Api, Client, ResourceExt};
+use prometheus::{IntGauge, Registry};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, broadcast};
+use tracing::{Instrument, info, info_span};
+
+use kube_devops::crd::DevOpsPolicy;
+use kube_devops::multi_cluster::{self, ClusterEvaluation, MultiClusterReport};
+use kube_devops::output::{self, OutputFormat};
+use kube_devops::{bundles, governance, rbac};
+
+use crate::commands::client;
+use crate::commands::policy;
+use crate::commands::policy_cache::PolicyCache;
+use crate::commands::watch::{self, ClusterState, PodTracker, WatchMetrics, WatchSettings};
+
+/* ============================= THRESHOLDS ============================= */
+
+/// Exit-code thresholds for fleet analysis.
+#[derive(Debug, Clone, Default)]
+pub struct Thresholds {
+    /// Fail when the aggregate score is below this value.
+    pub fail_below_score: Option<u32>,
+    /// Fail when any single cluster scores below this value.
+    pub fail_below_cluster_score: Option<u32>,
+}
+
+/// Return a description of every threshold the report breaches.
+pub fn evaluate_thresholds(report: &MultiClusterReport, thresholds: &Thresholds) -> Vec<String> {
+    let mut failures = Vec::new();
+
+    if let Some(min) = thresholds.fail_below_score
+        && report.aggregate_score < min
+    {
+        failures.push(format!(
+            "aggregate score {} is below minimum {min}",
+            report.aggregate_score
+        ));
+    }
+
+    if let Some(min) = thresholds.fail_below_cluster_score {
+        for cluster in report.clusters.iter().filter(|c| c.health_score < min) {
+            failures.push(format!(
+                "cluster '{}' score {} is below minimum {min}",
+                cluster.context_name, cluster.health_score
+            ));
+        }
+    }
+
+    failures
+}
 
 /* ============================= COMMANDS ============================= */
 
 /// List available kubeconfig contexts.
 pub fn list_contexts() -> Result<()> {
-    let contexts = multi_cluster::list_contexts()?;
+    let contexts = multi_cluster::list_contexts(client::kubeconfig_path())?;
 
     if contexts.is_empty() {
         println!("No kubeconfig contexts found.");
@@ -23,15 +80,27 @@ pub fn list_contexts() -> Result<()> {
 }
 
 /// Analyze one or more clusters against a policy or bundle.
+///
+/// With `compare`, prints the clusters side by side instead, flags the ones
+/// that regressed since the run stored in that file, and stores this run.
+///
+/// Returns an error when any threshold is breached so pipelines can gate on
+/// fleet compliance.
 pub async fn analyze(
     contexts: Option<Vec<String>>,
     bundle_name: Option<String>,
     per_cluster: bool,
+    compact: bool,
+    thresholds: &Thresholds,
+    compare: Option<&Path>,
+    format: OutputFormat,
 ) -> Result<()> {
+    let quiet = compact || format.is_structured();
+
     // Resolve which contexts to analyze
     let target_contexts = match contexts {
         Some(c) if !c.is_empty() => c,
-        _ => multi_cluster::list_contexts()?,
+        _ => multi_cluster::list_contexts(client::kubeconfig_path())?,
     };
 
     if target_contexts.is_empty() {
@@ -48,11 +117,13 @@ pub async fn analyze(
         )
     })?;
 
-    println!(
-        "Analyzing {} cluster(s) with '{}' bundle...\n",
-        target_contexts.len(),
-        bundle.name
-    );
+    if !quiet {
+        println!(
+            "Analyzing {} cluster(s) with '{}' bundle...\n",
+            target_contexts.len(),
+            bundle.name
+        );
+    }
 
     // Evaluate all clusters in parallel
     let handles: Vec<_> = target_contexts
@@ -60,7 +131,7 @@ pub async fn analyze(
         .map(|ctx| {
             let spec = bundle.spec.clone();
             tokio::spawn(async move {
-                match multi_cluster::client_for_context(&ctx).await {
+                match client::connect_context(&ctx).await {
                     Ok(client) => multi_cluster::evaluate_cluster(&client, &ctx, &spec).await,
                     Err(e) => Err(e),
                 }
@@ -81,14 +152,44 @@ pub async fn analyze(
         return Ok(());
     }
 
-    // Print per-cluster results
+    let report = multi_cluster::aggregate_report(evaluations);
+
+    if let Some(path) = compare {
+        let previous = load_last_run(path)?;
+        let comparison = compare_clusters(&report, previous.as_ref());
+        if format.is_structured() {
+            output::print_structured(&comparison, format)?;
+        } else {
+            print!("{}", format_comparison(&comparison));
+        }
+        save_last_run(
+            path,
+            &StoredRun::from_report(&report, chrono::Utc::now().to_rfc3339()),
+        )?;
+    } else if format.is_structured() {
+        output::print_structured(&report, format)?;
+    } else if compact {
+        print_compact(&report);
+    } else {
+        print_report(&report, per_cluster);
+    }
+
+    let failures = evaluate_thresholds(&report, thresholds);
+    if !failures.is_empty() {
+        anyhow::bail!("Threshold failed: {}", failures.join("; "));
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &MultiClusterReport, per_cluster: bool) {
     if per_cluster {
         println!(
             "{:<30} {:>6} {:>6} {:>12} STATUS",
             "CLUSTER", "SCORE", "PODS", "VIOLATIONS"
         );
         println!("{}", "-".repeat(75));
-        for eval in &evaluations {
+        for eval in &report.clusters {
             println!(
                 "{:<30} {:>6} {:>6} {:>12} {}",
                 eval.context_name,
@@ -101,22 +202,718 @@ pub async fn analyze(
         println!();
     }
 
-    // Print aggregate report
-    let report = multi_cluster::aggregate_report(evaluations);
     println!(
         "Aggregate: {} — score {}/100 across {} cluster(s)",
         report.aggregate_classification,
         report.aggregate_score,
         report.clusters.len()
     );
+}
+
+/// One line per cluster plus the aggregate, for CI logs.
+fn print_compact(report: &MultiClusterReport) {
+    for line in compact_lines(report) {
+        println!("{line}");
+    }
+}
+
+fn compact_lines(report: &MultiClusterReport) -> Vec<String> {
+    let mut lines: Vec<String> = report
+        .clusters
+        .iter()
+        .map(|c| format!("{} {} {}", c.context_name, c.health_score, c.classification))
+        .collect();
+    lines.push(format!(
+        "aggregate {} {}",
+        report.aggregate_score, report.aggregate_classification
+    ));
+    lines
+}
+
+/* ============================= COMPARISON ============================= */
+
+/// Offending namespaces listed per cluster.
+const TOP_NAMESPACES: usize = 3;
+
+/// Scores per context from an earlier `analyze --compare`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredRun {
+    pub timestamp: String,
+    pub scores: BTreeMap<String, u32>,
+}
+
+impl StoredRun {
+    fn from_report(report: &MultiClusterReport, timestamp: String) -> Self {
+        Self {
+            timestamp,
+            scores: report
+                .clusters
+                .iter()
+                .map(|c| (c.context_name.clone(), c.health_score))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceOffender {
+    pub namespace: String,
+    pub violations: u32,
+}
+
+/// One cluster's column of the comparison.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterColumn {
+    pub context: String,
+    pub score: u32,
+    /// Score in the previous run, if the cluster was part of it.
+    pub previous_score: Option<u32>,
+    /// Score dropped by more than `governance::TREND_TOLERANCE` points.
+    pub regressed: bool,
+    /// Violations per type, every type listed.
+    pub violations: BTreeMap<&'static str, u32>,
+    pub top_namespaces: Vec<NamespaceOffender>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonReport {
+    /// When the previous run was stored; `None` on the first run.
+    pub previous_run: Option<String>,
+    pub clusters: Vec<ClusterColumn>,
+    pub aggregate_score: u32,
+}
+
+impl ComparisonReport {
+    fn regressed(&self) -> impl Iterator<Item = &ClusterColumn> {
+        self.clusters.iter().filter(|c| c.regressed)
+    }
+}
+
+/// Compare the clusters of `report` with each other and with `previous`.
+pub fn compare_clusters(
+    report: &MultiClusterReport,
+    previous: Option<&StoredRun>,
+) -> ComparisonReport {
+    let clusters = report
+        .clusters
+        .iter()
+        .map(|cluster| {
+            let previous_score =
+                previous.and_then(|p| p.scores.get(&cluster.context_name).copied());
+            let mut violations: BTreeMap<&'static str, u32> = governance::VIOLATION_TYPES
+                .iter()
+                .map(|&violation_type| (violation_type, 0))
+                .collect();
+            let mut namespaces: BTreeMap<&str, u32> = BTreeMap::new();
+            for v in &cluster.violations {
+                if let Some(count) = violations.get_mut(v.violation_type.as_str()) {
+                    *count += 1;
+                }
+                *namespaces.entry(v.namespace.as_str()).or_default() += 1;
+            }
+            let mut top_namespaces: Vec<NamespaceOffender> = namespaces
+                .into_iter()
+                .map(|(namespace, violations)| NamespaceOffender {
+                    namespace: namespace.to_string(),
+                    violations,
+                })
+                .collect();
+            // Stable sort keeps equal counts in namespace order
+            top_namespaces.sort_by_key(|n| std::cmp::Reverse(n.violations));
+            top_namespaces.truncate(TOP_NAMESPACES);
+
+            ClusterColumn {
+                context: cluster.context_name.clone(),
+                score: cluster.health_score,
+                previous_score,
+                regressed: previous_score
+                    .is_some_and(|prev| prev > cluster.health_score + governance::TREND_TOLERANCE),
+                violations,
+                top_namespaces,
+            }
+        })
+        .collect();
+
+    ComparisonReport {
+        previous_run: previous.map(|p| p.timestamp.clone()),
+        clusters,
+        aggregate_score: report.aggregate_score,
+    }
+}
+
+/// Read the previous run; `None` when the file does not exist yet.
+fn load_last_run(path: &Path) -> Result<Option<StoredRun>> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .map(Some)
+            .with_context(|| format!("Invalid last-run file {}", path.display())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => {
+            Err(e).with_context(|| format!("Failed to read last-run file {}", path.display()))
+        }
+    }
+}
+
+fn save_last_run(path: &Path, run: &StoredRun) -> Result<()> {
+    std::fs::write(path, serde_json::to_string_pretty(run)?)
+        .with_context(|| format!("Failed to write last-run file {}", path.display()))
+}
 
+fn format_comparison(report: &ComparisonReport) -> String {
+    let width = report
+        .clusters
+        .iter()
+        .map(|c| c.context.len())
+        .max()
+        .unwrap_or(0)
+        .max(12)
+        + 2;
+    let row = |label: &str, cells: Vec<String>| {
+        let cells: String = cells.iter().map(|c| format!("{c:<width$}")).collect();
+        format!("{label:<20} {}\n", cells.trim_end())
+    };
+
+    let mut out = match &report.previous_run {
+        Some(timestamp) => format!("Cluster comparison (previous run {timestamp}):\n\n"),
+        None => "Cluster comparison (no previous run stored):\n\n".to_string(),
+    };
+    out.push_str(&row(
+        "",
+        report.clusters.iter().map(|c| c.context.clone()).collect(),
+    ));
+    out.push_str(&row(
+        "score",
+        report
+            .clusters
+            .iter()
+            .map(|c| match c.previous_score {
+                Some(prev) if prev != c.score => {
+                    format!("{} ({:+})", c.score, c.score as i64 - prev as i64)
+                }
+                _ => c.score.to_string(),
+            })
+            .collect(),
+    ));
+    for violation_type in governance::VIOLATION_TYPES {
+        out.push_str(&row(
+            violation_type,
+            report
+                .clusters
+                .iter()
+                .map(|c| c.violations[violation_type].to_string())
+                .collect(),
+        ));
+    }
+
+    out.push_str("\nTop offending namespaces:\n");
+    for c in &report.clusters {
+        let namespaces = if c.top_namespaces.is_empty() {
+            "none".to_string()
+        } else {
+            c.top_namespaces
+                .iter()
+                .map(|n| format!("{} ({})", n.namespace, n.violations))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        out.push_str(&format!("  {:<width$}{namespaces}\n", c.context));
+    }
+
+    let regressed: Vec<String> = report
+        .regressed()
+        .map(|c| {
+            format!(
+                "{} ({} -> {})",
+                c.context,
+                c.previous_score.unwrap_or_default(),
+                c.score
+            )
+        })
+        .collect();
+    if !regressed.is_empty() {
+        out.push_str(&format!(
+            "\nREGRESSED since last run: {}\n",
+            regressed.join(", ")
+        ));
+    }
+    out.push_str(&format!(
+        "\nAggregate score: {}/100\n",
+        report.aggregate_score
+    ));
+    out
+}
+
+/* ============================= APPLY ============================= */
+
+/// Outcome of applying one policy to one cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ApplyResult {
+    Created,
+    Configured,
+    Unchanged,
+    Failed,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyApply {
+    pub namespace: String,
+    pub name: String,
+    pub result: ApplyResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterApply {
+    pub context: String,
+    /// Why the cluster could not be reached; no policies were applied.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub policies: Vec<PolicyApply>,
+}
+
+impl ClusterApply {
+    fn failed(&self) -> bool {
+        self.error.is_some()
+            || self
+                .policies
+                .iter()
+                .any(|p| p.result == ApplyResult::Failed)
+    }
+}
+
+/// Fleet rollout of a policy file, one entry per context.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyReport {
+    pub dry_run: bool,
+    pub clusters: Vec<ClusterApply>,
+}
+
+/// Server-side apply the DevOpsPolicies in `file` to every context in
+/// parallel. `dry_run` sends the same requests as server-side dry runs, so
+/// schema and admission errors still surface. Fails when any cluster or
+/// policy failed, after reporting all of them.
+pub async fn apply(
+    file: &str,
+    contexts: Option<Vec<String>>,
+    dry_run: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let content =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read {file}"))?;
+    let policies =
+        policy::parse_policies(&content).with_context(|| format!("Invalid policy file {file}"))?;
+    if policies.is_empty() {
+        anyhow::bail!("{file} contains no DevOpsPolicy");
+    }
+
+    let target_contexts = match contexts {
+        Some(c) if !c.is_empty() => c,
+        _ => multi_cluster::list_contexts(client::kubeconfig_path())?,
+    };
+    if target_contexts.is_empty() {
+        println!("No kubeconfig contexts to apply to.");
+        return Ok(());
+    }
+
+    if !format.is_structured() {
+        let mode = if dry_run { " (dry run)" } else { "" };
+        println!(
+            "Applying {} policy(ies) to {} cluster(s){mode}...\n",
+            policies.len(),
+            target_contexts.len()
+        );
+    }
+
+    let policies = Arc::new(policies);
+    let handles: Vec<_> = target_contexts
+        .into_iter()
+        .map(|context| {
+            let policies = policies.clone();
+            tokio::spawn(async move {
+                match client::connect_context(&context).await {
+                    Ok(client) => ClusterApply {
+                        policies: apply_to_cluster(&client, &policies, dry_run).await,
+                        context,
+                        error: None,
+                    },
+                    Err(e) => ClusterApply {
+                        context,
+                        error: Some(format!("{e:#}")),
+                        policies: vec![],
+                    },
+                }
+            })
+        })
+        .collect();
+
+    let mut clusters = Vec::new();
+    for handle in handles {
+        clusters.push(handle.await?);
+    }
+    let report = ApplyReport { dry_run, clusters };
+
+    if format.is_structured() {
+        output::print_structured(&report, format)?;
+    } else {
+        print!("{}", format_apply_report(&report));
+    }
+
+    let failed: Vec<&str> = report
+        .clusters
+        .iter()
+        .filter(|c| c.failed())
+        .map(|c| c.context.as_str())
+        .collect();
+    if !failed.is_empty() {
+        anyhow::bail!(
+            "Apply failed in {} cluster(s): {}",
+            failed.len(),
+            failed.join(", ")
+        );
+    }
     Ok(())
 }
 
+async fn apply_to_cluster(
+    client: &Client,
+    policies: &[DevOpsPolicy],
+    dry_run: bool,
+) -> Vec<PolicyApply> {
+    let mut params = PatchParams::apply("kube-devops-cli");
+    if dry_run {
+        params = params.dry_run();
+    }
+
+    let mut results = Vec::new();
+    for policy in policies {
+        let name = policy.name_any();
+        let namespace = policy.namespace().unwrap_or_else(|| "default".to_string());
+        let api: Api<DevOpsPolicy> = Api::namespaced(client.clone(), &namespace);
+
+        let outcome = async {
+            let existing = api.get_opt(&name).await?;
+            api.patch(&name, &params, &Patch::Apply(policy)).await?;
+            anyhow::Ok(apply_result(existing.as_ref(), policy))
+        }
+        .await;
+        let (result, error) = match outcome {
+            Ok(result) => (result, None),
+            Err(e) => (ApplyResult::Failed, Some(format!("{e:#}"))),
+        };
+        results.push(PolicyApply {
+            namespace,
+            name,
+            result,
+            error,
+        });
+    }
+    results
+}
+
+/// How applying `local` changes the cluster, given the policy it replaces.
+fn apply_result(existing: Option<&DevOpsPolicy>, local: &DevOpsPolicy) -> ApplyResult {
+    let spec = |p: &DevOpsPolicy| serde_json::to_value(&p.spec).ok();
+    match existing {
+        None => ApplyResult::Created,
+        Some(existing) if spec(existing) == spec(local) => ApplyResult::Unchanged,
+        Some(_) => ApplyResult::Configured,
+    }
+}
+
+fn format_apply_report(report: &ApplyReport) -> String {
+    let verb = |result: ApplyResult| match (result, report.dry_run) {
+        (ApplyResult::Created, false) => "created",
+        (ApplyResult::Created, true) => "would create",
+        (ApplyResult::Configured, false) => "configured",
+        (ApplyResult::Configured, true) => "would configure",
+        (ApplyResult::Unchanged, _) => "unchanged",
+        (ApplyResult::Failed, _) => "FAILED",
+    };
+
+    let mut out = format!("{:<30} {:<40} RESULT\n", "CLUSTER", "POLICY");
+    out.push_str(&format!("{}\n", "-".repeat(85)));
+    for cluster in &report.clusters {
+        if let Some(error) = &cluster.error {
+            out.push_str(&format!(
+                "{:<30} {:<40} UNREACHABLE: {error}\n",
+                cluster.context, "-"
+            ));
+        }
+        for p in &cluster.policies {
+            let policy = format!("{}/{}", p.namespace, p.name);
+            out.push_str(&format!(
+                "{:<30} {policy:<40} {}",
+                cluster.context,
+                verb(p.result)
+            ));
+            if let Some(error) = &p.error {
+                out.push_str(&format!(": {error}"));
+            }
+            out.push('\n');
+        }
+    }
+
+    let failed = report.clusters.iter().filter(|c| c.failed()).count();
+    out.push_str(&format!(
+        "\n{} cluster(s) succeeded, {failed} failed.\n",
+        report.clusters.len() - failed
+    ));
+    out
+}
+
+/* ============================= WATCH ============================= */
+
+/// One context of `multi-cluster watch`.
+struct WatchedCluster {
+    context: String,
+    state: Arc<Mutex<ClusterState>>,
+}
+
+/// Run a watch loop per context in one process and serve their state
+/// together: metrics carry a `cluster` label, `/api/v1/clusters` reports the
+/// pod-weighted aggregate, and `/clusters/<context>/api/v1/...` serves each
+/// cluster's JSON API. Contexts that cannot be reached are skipped.
+///
+/// There is no leader election and nothing is written to the clusters, so
+/// only the read permissions of `watch` are needed.
+pub async fn watch(contexts: Option<Vec<String>>, settings: WatchSettings) -> Result<()> {
+    let mut target_contexts = match contexts {
+        Some(c) if !c.is_empty() => c,
+        _ => multi_cluster::list_contexts(client::kubeconfig_path())?,
+    };
+    let mut seen = HashSet::new();
+    target_contexts.retain(|c| seen.insert(c.clone()));
+
+    if target_contexts.is_empty() {
+        println!("No kubeconfig contexts to watch.");
+        return Ok(());
+    }
+
+    println!(
+        "Starting multi-cluster watch for {} context(s)...\n",
+        target_contexts.len()
+    );
+    info!(
+        contexts = target_contexts.len(),
+        "multi_cluster_watch_starting"
+    );
+
+    let scope = settings.namespace;
+    let registry = Registry::new();
+    let (shutdown_tx, _) = broadcast::channel::<()>(1);
+    let mut clusters = Vec::new();
+    let mut handles = Vec::new();
+
+    for context in target_contexts {
+        println!("  [{context}]");
+        let (client, policies) = match connect(&context, scope.as_deref()).await {
+            Ok(connected) => connected,
+            Err(e) => {
+                eprintln!("  [ERROR] {context}: {e:#}");
+                continue;
+            }
+        };
+        let metrics = WatchMetrics::register(&registry, Some(&context))?;
+        let tracker = PodTracker::new(
+            Arc::new(metrics),
+            policies,
+            scope.is_some(),
+            settings.namespaces.clone(),
+        );
+        let state = Arc::new(Mutex::new(ClusterState::new(settings.pod_detail_capacity)));
+        let span = info_span!("cluster", cluster = %context);
+        handles.push(tokio::spawn(
+            watch::watch_loop(
+                client,
+                state.clone(),
+                tracker,
+                scope.clone(),
+                settings.resync_interval,
+                shutdown_tx.subscribe(),
+            )
+            .instrument(span),
+        ));
+        clusters.push(WatchedCluster { context, state });
+    }
+
+    if clusters.is_empty() {
+        anyhow::bail!("No clusters could be reached.");
+    }
+
+    let aggregate = IntGauge::new(
+        "aggregate_health_score",
+        "Pod-weighted mean of the watched clusters' health scores (0-100)",
+    )?;
+    registry.register(Box::new(aggregate.clone()))?;
+
+    let addr = settings.addr;
+    let app = fleet_router(Arc::new(clusters), Arc::new(registry), aggregate);
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind HTTP server on {addr}"))?;
+
+    println!();
+    println!("  HTTP server ................. http://{addr}");
+    println!("    GET /metrics .............. Metrics of every cluster, labelled by `cluster`");
+    println!("    GET /api/v1/clusters ...... Per-cluster and aggregate scores (JSON)");
+    println!("    GET /clusters/<context>/api/v1/... The watch JSON API of one cluster");
+    println!();
+    println!("Multi-cluster watch running. Press Ctrl+C to stop.\n");
+
+    let mut http_shutdown = shutdown_tx.subscribe();
+    let http_handle = tokio::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async move {
+                let _ = http_shutdown.recv().await;
+            })
+            .await
+    });
+
+    tokio::signal::ctrl_c().await?;
+    info!("shutdown_signal_received");
+    println!("\nShutdown signal received. Stopping multi-cluster watch...");
+    let _ = shutdown_tx.send(());
+    for handle in handles {
+        let _ = handle.await?;
+    }
+    http_handle.await??;
+
+    info!("multi_cluster_watch_stopped");
+    println!("Multi-cluster watch stopped.");
+    Ok(())
+}
+
+/// Connect to `context`, check the watch's read permissions, and start the
+/// policy caches it may read.
+async fn connect(context: &str, scope: Option<&str>) -> Result<(Client, Option<PolicyCache>)> {
+    let client = client::connect_context(context).await?;
+
+    print!("  Cluster connection .......... ");
+    match client.apiserver_version().await {
+        Ok(v) => println!("OK (v{}.{})", v.major, v.minor),
+        Err(e) => {
+            println!("FAIL");
+            return Err(e).context("Cannot reach cluster");
+        }
+    }
+
+    let report = rbac::startup_check(&client, &rbac::watch_permissions(true, scope)).await?;
+    let policies = report.feature_enabled(rbac::FEATURE_POLICIES).then(|| {
+        PolicyCache::spawn(
+            &client,
+            scope,
+            report.feature_enabled(rbac::FEATURE_CLUSTER_POLICIES),
+        )
+    });
+    Ok((client, policies))
+}
+
+/// The watch's view of one cluster as a `ClusterEvaluation`. Violation
+/// details stay in the cluster's own `/pods` endpoint.
+fn cluster_evaluation(context: &str, state: &ClusterState) -> ClusterEvaluation {
+    let health_score = watch::cluster_score(state).unwrap_or(100);
+    let namespaces = state.namespaces.values();
+    ClusterEvaluation {
+        context_name: context.to_string(),
+        health_score,
+        classification: governance::classify_health(health_score).to_string(),
+        total_pods: namespaces.clone().map(|ns| ns.metrics.total_pods).sum(),
+        total_violations: namespaces
+            .flat_map(|ns| {
+                governance::VIOLATION_TYPES
+                    .iter()
+                    .map(|violation_type| ns.metrics.count(violation_type))
+            })
+            .sum(),
+        violations: vec![],
+    }
+}
+
+/// Aggregate of the clusters whose initial sync has completed, or `None`
+/// while none has.
+async fn fleet_report(clusters: &[WatchedCluster]) -> Option<MultiClusterReport> {
+    let mut evaluations = Vec::new();
+    for cluster in clusters {
+        let state = cluster.state.lock().await;
+        if state.ready {
+            evaluations.push(cluster_evaluation(&cluster.context, &state));
+        }
+    }
+    (!evaluations.is_empty()).then(|| multi_cluster::aggregate_report(evaluations))
+}
+
+fn fleet_router(
+    clusters: Arc<Vec<WatchedCluster>>,
+    registry: Arc<Registry>,
+    aggregate: IntGauge,
+) -> Router {
+    let mut router = Router::new()
+        .route("/healthz", get(|| async { (StatusCode::OK, "OK") }))
+        .route(
+            "/readyz",
+            get({
+                let clusters = clusters.clone();
+                move || async move {
+                    for cluster in clusters.iter() {
+                        if !cluster.state.lock().await.ready {
+                            return (StatusCode::SERVICE_UNAVAILABLE, "NOT READY");
+                        }
+                    }
+                    (StatusCode::OK, "READY")
+                }
+            }),
+        )
+        .route(
+            "/metrics",
+            get({
+                let clusters = clusters.clone();
+                move || async move {
+                    if let Some(report) = fleet_report(&clusters).await {
+                        aggregate.set(report.aggregate_score as i64);
+                    }
+                    watch::encode_metrics(&registry)
+                }
+            }),
+        )
+        .route(
+            "/api/v1/clusters",
+            get({
+                let clusters = clusters.clone();
+                move || async move {
+                    match fleet_report(&clusters).await {
+                        Some(report) => Json(serde_json::json!(report)).into_response(),
+                        None => (
+                            StatusCode::SERVICE_UNAVAILABLE,
+                            Json(serde_json::json!({ "error": "initial sync in progress" })),
+                        )
+                            .into_response(),
+                    }
+                }
+            }),
+        );
+    for cluster in clusters.iter() {
+        router = router.nest(
+            &format!("/clusters/{}", cluster.context),
+            watch::api_routes(cluster.state.clone()),
+        );
+    }
+    router
+}
+
 /* ============================= TESTS ============================= */
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use kube_devops::governance;
     use kube_devops::multi_cluster::{ClusterEvaluation, aggregate_report};
 
@@ -162,4 +959,296 @@ mod tests {
         assert!(names.contains(&"cluster-a"));
         assert!(names.contains(&"cluster-b"));
     }
+
+    #[test]
+    fn test_thresholds_aggregate() {
+        let report = aggregate_report(vec![make_eval("a", 90, 10), make_eval("b", 40, 10)]);
+        let thresholds = Thresholds {
+            fail_below_score: Some(70),
+            fail_below_cluster_score: None,
+        };
+        let failures = evaluate_thresholds(&report, &thresholds);
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].starts_with("aggregate score 65"));
+    }
+
+    #[test]
+    fn test_thresholds_per_cluster() {
+        let report = aggregate_report(vec![make_eval("a", 90, 10), make_eval("b", 40, 10)]);
+        let thresholds = Thresholds {
+            fail_below_score: None,
+            fail_below_cluster_score: Some(50),
+        };
+        let failures = evaluate_thresholds(&report, &thresholds);
+        assert_eq!(failures, vec!["cluster 'b' score 40 is below minimum 50"]);
+        assert!(evaluate_thresholds(&report, &Thresholds::default()).is_empty());
+    }
+
+    #[test]
+    fn test_compact_lines() {
+        let report = aggregate_report(vec![make_eval("prod", 95, 10)]);
+        assert_eq!(
+            compact_lines(&report),
+            vec!["prod 95 Healthy", "aggregate 95 Healthy"]
+        );
+    }
+
+    // ── Compare ──
+
+    fn violation(namespace: &str, violation_type: &str) -> governance::ViolationDetail {
+        governance::ViolationDetail {
+            violation_type: violation_type.to_string(),
+            severity: governance::default_severity(violation_type),
+            pod_name: "web-1".to_string(),
+            namespace: namespace.to_string(),
+            container_name: "app".to_string(),
+            message: String::new(),
+        }
+    }
+
+    fn compared_report() -> MultiClusterReport {
+        let mut prod = make_eval("prod", 80, 10);
+        prod.violations = vec![
+            violation("payments", "latest_tag"),
+            violation("payments", "missing_liveness"),
+            violation("web", "latest_tag"),
+            violation("api", "crash_loop"),
+            violation("batch", "pending"),
+        ];
+        aggregate_report(vec![prod, make_eval("staging", 95, 10)])
+    }
+
+    #[test]
+    fn test_compare_counts_and_top_namespaces() {
+        let comparison = compare_clusters(&compared_report(), None);
+        let prod = &comparison.clusters[0];
+        assert_eq!(prod.violations["latest_tag"], 2);
+        assert_eq!(prod.violations["crash_loop"], 1);
+        assert_eq!(prod.violations["image_pull_error"], 0);
+        let top: Vec<(&str, u32)> = prod
+            .top_namespaces
+            .iter()
+            .map(|n| (n.namespace.as_str(), n.violations))
+            .collect();
+        assert_eq!(top, [("payments", 2), ("api", 1), ("batch", 1)]);
+        assert!(comparison.clusters[1].top_namespaces.is_empty());
+        assert!(comparison.regressed().next().is_none());
+    }
+
+    #[test]
+    fn test_compare_flags_regressions_beyond_tolerance() {
+        let previous = StoredRun {
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+            scores: BTreeMap::from([("prod".to_string(), 90), ("staging".to_string(), 96)]),
+        };
+        let comparison = compare_clusters(&compared_report(), Some(&previous));
+        let regressed: Vec<&str> = comparison.regressed().map(|c| c.context.as_str()).collect();
+        assert_eq!(regressed, ["prod"]);
+        assert_eq!(comparison.clusters[1].previous_score, Some(96));
+
+        let table = format_comparison(&comparison);
+        assert!(table.contains("previous run 2026-01-01T00:00:00+00:00"));
+        assert!(table.contains("80 (-10)"));
+        assert!(table.contains("payments (2), api (1), batch (1)"));
+        assert!(table.contains("REGRESSED since last run: prod (90 -> 80)"));
+    }
+
+    #[test]
+    fn test_last_run_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("kube-devops-last-run-{}.json", std::process::id()));
+        assert!(load_last_run(&path).unwrap().is_none());
+
+        let run = StoredRun::from_report(&compared_report(), "2026-01-01T00:00:00Z".to_string());
+        save_last_run(&path, &run).unwrap();
+        let loaded = load_last_run(&path).unwrap().unwrap();
+        assert_eq!(loaded.scores["prod"], 80);
+        assert_eq!(loaded.scores["staging"], 95);
+
+        std::fs::write(&path, "not json").unwrap();
+        let err = load_last_run(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert!(err.to_string().contains("Invalid last-run file"));
+    }
+
+    // ── Apply ──
+
+    fn policy(forbid_latest_tag: bool) -> DevOpsPolicy {
+        DevOpsPolicy::new(
+            "web",
+            kube_devops::crd::DevOpsPolicySpec {
+                forbid_latest_tag: Some(forbid_latest_tag),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn applied(result: ApplyResult, error: Option<&str>) -> PolicyApply {
+        PolicyApply {
+            namespace: "prod".to_string(),
+            name: "web".to_string(),
+            result,
+            error: error.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_apply_result_compares_spec() {
+        let local = policy(true);
+        assert_eq!(apply_result(None, &local), ApplyResult::Created);
+        assert_eq!(
+            apply_result(Some(&policy(true)), &local),
+            ApplyResult::Unchanged
+        );
+        assert_eq!(
+            apply_result(Some(&policy(false)), &local),
+            ApplyResult::Configured
+        );
+    }
+
+    #[test]
+    fn test_apply_report_lists_failures_per_cluster() {
+        let report = ApplyReport {
+            dry_run: true,
+            clusters: vec![
+                ClusterApply {
+                    context: "prod-eu".to_string(),
+                    error: None,
+                    policies: vec![applied(ApplyResult::Created, None)],
+                },
+                ClusterApply {
+                    context: "prod-us".to_string(),
+                    error: None,
+                    policies: vec![applied(ApplyResult::Failed, Some("forbidden"))],
+                },
+                ClusterApply {
+                    context: "staging".to_string(),
+                    error: Some("context not found".to_string()),
+                    policies: vec![],
+                },
+            ],
+        };
+        assert!(!report.clusters[0].failed());
+        assert!(report.clusters[1].failed());
+        assert!(report.clusters[2].failed());
+
+        let table = format_apply_report(&report);
+        assert!(table.contains("prod/web"));
+        assert!(table.contains("would create"));
+        assert!(table.contains("FAILED: forbidden"));
+        assert!(table.contains("UNREACHABLE: context not found"));
+        assert!(table.ends_with("1 cluster(s) succeeded, 2 failed.\n"));
+
+        let json = output::render(&report, OutputFormat::Json).unwrap();
+        assert!(json.contains("\"dryRun\": true"));
+        assert!(json.contains("\"result\": \"failed\""));
+    }
+
+    // ── Watch ──
+
+    fn watched(context: &str, ready: bool, pods: u32, latest_tag: u32) -> WatchedCluster {
+        let mut state = ClusterState::new(10);
+        state.ready = ready;
+        state.namespaces.insert(
+            "prod".to_string(),
+            watch::NamespaceState {
+                metrics: governance::PodMetrics {
+                    total_pods: pods,
+                    latest_tag,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        WatchedCluster {
+            context: context.to_string(),
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    async fn get(router: Router, uri: &str) -> (StatusCode, String) {
+        use axum::body::Body;
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        let req = axum::http::Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap();
+        let resp = router.oneshot(req).await.unwrap();
+        let status = resp.status();
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn fleet(clusters: Vec<WatchedCluster>) -> (Router, IntGauge) {
+        let registry = Registry::new();
+        let aggregate = IntGauge::new("aggregate_health_score", "test").unwrap();
+        registry.register(Box::new(aggregate.clone())).unwrap();
+        let router = fleet_router(Arc::new(clusters), Arc::new(registry), aggregate.clone());
+        (router, aggregate)
+    }
+
+    #[tokio::test]
+    async fn test_cluster_evaluation_from_watch_state() {
+        let cluster = watched("prod-eu", true, 4, 2);
+        let state = cluster.state.lock().await;
+        let eval = cluster_evaluation("prod-eu", &state);
+        assert_eq!(eval.context_name, "prod-eu");
+        assert_eq!(eval.total_pods, 4);
+        assert_eq!(eval.total_violations, 2);
+        assert_eq!(eval.health_score, watch::cluster_score(&state).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_fleet_report_skips_unsynced_clusters() {
+        let (router, aggregate) = fleet(vec![
+            watched("prod-eu", true, 10, 0),
+            watched("prod-us", false, 10, 10),
+        ]);
+        let (status, body) = get(router.clone(), "/api/v1/clusters").await;
+        assert_eq!(status, StatusCode::OK);
+        let report: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(report["clusters"].as_array().unwrap().len(), 1);
+        assert_eq!(report["aggregateScore"], 100);
+
+        let (status, _) = get(router.clone(), "/readyz").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+
+        let (status, body) = get(router, "/metrics").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("aggregate_health_score 100"), "{body}");
+        assert_eq!(aggregate.get(), 100);
+    }
+
+    #[tokio::test]
+    async fn test_fleet_unavailable_before_any_sync() {
+        let (router, _) = fleet(vec![watched("prod-eu", false, 1, 0)]);
+        let (status, body) = get(router, "/api/v1/clusters").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(body.contains("initial sync in progress"));
+    }
+
+    #[tokio::test]
+    async fn test_cluster_api_is_nested_per_context() {
+        let (router, _) = fleet(vec![
+            watched("prod-eu", true, 2, 0),
+            watched("prod-us", true, 3, 0),
+        ]);
+        let (status, body) = get(router.clone(), "/clusters/prod-us/api/v1/score").await;
+        assert_eq!(status, StatusCode::OK);
+        let score: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(score["pods"], 3);
+
+        let (status, _) = get(router, "/clusters/staging/api/v1/score").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn test_report_serializes_camel_case() {
+        let report = aggregate_report(vec![make_eval("prod", 95, 10)]);
+        let json = output::render(&report, OutputFormat::Json).unwrap();
+        assert!(json.contains("\"aggregateScore\": 95"));
+        assert!(json.contains("\"contextName\": \"prod\""));
+    }
 }
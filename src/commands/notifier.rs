@@ -0,0 +1,220 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+
+use kube_devops::crd::{NotificationChannel, NotificationChannelType};
+use kube_devops::notifications::Notification;
+
+/* ============================= CONFIG ============================= */
+
+/// Timeout for one delivery to one channel.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(15);
+
+/* ============================= NOTIFIER ============================= */
+
+/// Delivers notifications to Slack, HTTP webhooks, and SMTP relays.
+pub struct Notifier {
+    client: reqwest::Client,
+}
+
+impl Notifier {
+    pub fn new() -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .user_agent(concat!("kube-devops/", env!("CARGO_PKG_VERSION")))
+            .timeout(DELIVERY_TIMEOUT)
+            .build()?;
+        Ok(Self { client })
+    }
+
+    /// Send `notification` to one channel. Channels missing required fields
+    /// are rejected by `notifications::deliverable_channels` beforehand.
+    pub async fn send(
+        &self,
+        channel: &NotificationChannel,
+        notification: &Notification,
+    ) -> Result<()> {
+        match channel.channel_type {
+            NotificationChannelType::Slack => {
+                self.post(channel, &notification.slack_payload()).await
+            }
+            NotificationChannelType::Webhook => {
+                self.post(channel, &serde_json::to_value(notification)?)
+                    .await
+            }
+            NotificationChannelType::Smtp => {
+                tokio::time::timeout(DELIVERY_TIMEOUT, send_mail(channel, notification))
+                    .await
+                    .context("SMTP delivery timed out")?
+            }
+        }
+    }
+
+    async fn post(&self, channel: &NotificationChannel, body: &serde_json::Value) -> Result<()> {
+        let url = channel.url.as_deref().unwrap_or_default();
+        self.client
+            .post(url)
+            .json(body)
+            .send()
+            .await
+            .map_err(reqwest::Error::without_url)
+            .with_context(|| format!("Failed to reach {}", redact_url(url)))?
+            .error_for_status()
+            .map_err(reqwest::Error::without_url)
+            .with_context(|| format!("{} rejected the notification", redact_url(url)))?;
+        Ok(())
+    }
+}
+
+/// Scheme and host of `url` only; Slack webhook paths are credentials.
+fn redact_url(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(parsed) => format!(
+            "{}://{}",
+            parsed.scheme(),
+            parsed.host_str().unwrap_or_default()
+        ),
+        Err(_) => "<invalid url>".to_string(),
+    }
+}
+
+/* ============================= SMTP ============================= */
+
+/// Send `notification` as a plain-text email over unauthenticated SMTP.
+async fn send_mail(channel: &NotificationChannel, notification: &Notification) -> Result<()> {
+    let server = channel.smtp_server.as_deref().unwrap_or_default();
+    let from = channel.from.as_deref().unwrap_or_default();
+    let to = channel.to.as_deref().unwrap_or_default();
+
+    let stream = TcpStream::connect(server)
+        .await
+        .with_context(|| format!("Failed to connect to SMTP server {server}"))?;
+    let (read, mut write) = stream.into_split();
+    let mut read = BufReader::new(read);
+
+    expect_reply(&mut read, 220).await?;
+    command(&mut write, &mut read, "EHLO kube-devops", 250).await?;
+    command(&mut write, &mut read, &format!("MAIL FROM:<{from}>"), 250).await?;
+    for recipient in to {
+        command(
+            &mut write,
+            &mut read,
+            &format!("RCPT TO:<{recipient}>"),
+            250,
+        )
+        .await?;
+    }
+    command(&mut write, &mut read, "DATA", 354).await?;
+    let message = mail_message(from, to, notification);
+    write.write_all(message.as_bytes()).await?;
+    command(&mut write, &mut read, ".", 250).await?;
+    command(&mut write, &mut read, "QUIT", 221).await?;
+    Ok(())
+}
+
+/// RFC 5322 message with dot-stuffed body, without the terminating `.`.
+fn mail_message(from: &str, to: &[String], notification: &Notification) -> String {
+    let body: String = notification
+        .text()
+        .lines()
+        .map(|line| {
+            let line = if line.starts_with('.') {
+                format!(".{line}")
+            } else {
+                line.to_string()
+            };
+            format!("{line}\r\n")
+        })
+        .collect();
+    format!(
+        "From: {from}\r\nTo: {}\r\nSubject: {}\r\nDate: {}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{body}",
+        to.join(", "),
+        notification.subject(),
+        Utc::now().to_rfc2822()
+    )
+}
+
+async fn command<W, R>(write: &mut W, read: &mut R, line: &str, expected: u16) -> Result<()>
+where
+    W: AsyncWriteExt + Unpin,
+    R: AsyncBufReadExt + Unpin,
+{
+    write.write_all(format!("{line}\r\n").as_bytes()).await?;
+    expect_reply(read, expected).await
+}
+
+/// Read a (possibly multi-line) SMTP reply and check its code. `251` is
+/// accepted wherever `250` is expected.
+async fn expect_reply<R: AsyncBufReadExt + Unpin>(read: &mut R, expected: u16) -> Result<()> {
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if read.read_line(&mut line).await? == 0 {
+            anyhow::bail!("SMTP server closed the connection");
+        }
+        // "250-..." continues a multi-line reply; "250 ..." ends it
+        if line.as_bytes().get(3) != Some(&b'-') {
+            break;
+        }
+    }
+    let code: u16 = line
+        .get(..3)
+        .and_then(|c| c.parse().ok())
+        .with_context(|| format!("Invalid SMTP reply: {}", line.trim()))?;
+    if code == expected || (expected == 250 && code == 251) {
+        Ok(())
+    } else {
+        anyhow::bail!("SMTP server replied {}", line.trim())
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification() -> Notification {
+        Notification {
+            policy: "web-policy".to_string(),
+            namespace: "prod".to_string(),
+            health_score: 40,
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            events: vec![],
+        }
+    }
+
+    #[test]
+    fn test_redact_url_drops_path() {
+        assert_eq!(
+            redact_url("https://hooks.slack.com/services/T000/B000/secret"),
+            "https://hooks.slack.com"
+        );
+        assert_eq!(redact_url("not a url"), "<invalid url>");
+    }
+
+    #[test]
+    fn test_mail_message_headers() {
+        let message = mail_message(
+            "kube-devops@example.com",
+            &["ops@example.com".to_string(), "sre@example.com".to_string()],
+            &notification(),
+        );
+        assert!(message.starts_with("From: kube-devops@example.com\r\n"));
+        assert!(message.contains("To: ops@example.com, sre@example.com\r\n"));
+        assert!(message.contains("Subject: kube-devops: prod/web-policy (score 40, 0 events)\r\n"));
+        assert!(message.ends_with("\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_expect_reply_multiline() {
+        let mut reply = "250-mail.example.com\r\n250-SIZE 1000\r\n250 OK\r\n".as_bytes();
+        expect_reply(&mut reply, 250).await.unwrap();
+
+        let mut rejected = "550 no such user\r\n".as_bytes();
+        let err = expect_reply(&mut rejected, 250).await.unwrap_err();
+        assert!(err.to_string().contains("550"));
+    }
+}
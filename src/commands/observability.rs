@@ -1,3 +1,5 @@
+use kube_devops::governance::ClassificationThresholds;
+
 /* ============================= SERVICE GENERATORS ============================= */
 
 const NAMESPACE: &str = "kube-devops";
@@ -87,8 +89,9 @@ pub fn generate_service_monitor_webhook() -> String {
 
 /* ============================= GRAFANA DASHBOARD ============================= */
 
-pub fn generate_grafana_dashboard_configmap() -> String {
-    let dashboard = build_dashboard_json();
+/// Dashboard ConfigMap; health score panels are colored by `thresholds`.
+pub fn generate_grafana_dashboard_configmap(thresholds: ClassificationThresholds) -> String {
+    let dashboard = build_dashboard_json(thresholds);
     let dashboard_str = serde_json::to_string_pretty(&dashboard).expect("dashboard JSON is valid");
 
     // Escape for YAML embedding (indent every line by 4 spaces)
@@ -114,7 +117,7 @@ data:
     )
 }
 
-fn build_dashboard_json() -> serde_json::Value {
+fn build_dashboard_json(thresholds: ClassificationThresholds) -> serde_json::Value {
     serde_json::json!({
         "annotations": { "list": [] },
         "editable": true,
@@ -125,20 +128,21 @@ fn build_dashboard_json() -> serde_json::Value {
         "panels": [
             // ── Row 1: Overview ──
             row_panel(0, "Overview"),
-            stat_panel(1, "Cluster Health Score", "cluster_health_score", 0),
+            health_panel(stat_panel(1, "Cluster Health Score", "cluster_health_score", 0), thresholds),
             graph_panel(2, "Reconcile Cycles", "rate(devopspolicy_reconcile_total[5m])", 0),
             graph_panel(3, "Webhook Requests", "rate(webhook_requests_total[5m])", 0),
 
             // ── Row 2: Watch ──
             row_panel(4, "Watch"),
-            graph_panel(5, "Namespace Health Scores", "namespace_health_score", 1),
+            health_panel(graph_panel(5, "Namespace Health Scores", "namespace_health_score", 1), thresholds),
             graph_panel(6, "Pod Events Rate", "rate(pod_events_total[5m])", 1),
             stat_panel(7, "Pods Tracked", "pods_tracked_total", 1),
+            graph_panel(26, "Violations by Type", "sum by (namespace, type) (namespace_violation_count)", 1),
 
             // ── Row 3: Reconcile ──
             row_panel(8, "Reconcile"),
             graph_panel(9, "Violations by Namespace", "devopspolicy_violations_total", 2),
-            graph_panel(10, "Health Scores", "devopspolicy_health_score", 2),
+            policy_health_panel(10, thresholds),
             graph_panel(11, "Reconcile Rate", "rate(devopspolicy_reconcile_total[5m])", 2),
             graph_panel(12, "Reconcile Errors", "rate(devopspolicy_reconcile_errors_total[5m])", 2),
             graph_panel(13, "Reconcile Duration", "histogram_quantile(0.99, rate(devopspolicy_reconcile_duration_seconds_bucket[5m]))", 2),
@@ -201,6 +205,46 @@ fn stat_panel(id: u32, title: &str, expr: &str, _row: u32) -> serde_json::Value
     })
 }
 
+/// Color a health score panel red, orange, yellow, and green from the
+/// Critical up to the Healthy cut-off.
+fn health_panel(
+    mut panel: serde_json::Value,
+    thresholds: ClassificationThresholds,
+) -> serde_json::Value {
+    panel["fieldConfig"]["defaults"] = serde_json::json!({
+        "min": 0,
+        "max": 100,
+        "thresholds": {
+            "mode": "absolute",
+            "steps": [
+                { "color": "red", "value": null },
+                { "color": "orange", "value": thresholds.degraded },
+                { "color": "yellow", "value": thresholds.stable },
+                { "color": "green", "value": thresholds.healthy }
+            ]
+        }
+    });
+    panel
+}
+
+/// Policy health scores next to each policy's own `Healthy` cut-off, which
+/// `healthThresholds` may set above the dashboard-wide one.
+fn policy_health_panel(id: u32, thresholds: ClassificationThresholds) -> serde_json::Value {
+    let mut panel = health_panel(
+        graph_panel(id, "Health Scores", "devopspolicy_health_score", 2),
+        thresholds,
+    );
+    panel["targets"]
+        .as_array_mut()
+        .expect("graph panel has targets")
+        .push(serde_json::json!({
+            "expr": "devopspolicy_health_threshold{classification=\"healthy\"}",
+            "legendFormat": "{{namespace}}/{{policy}} healthy cut-off",
+            "refId": "B"
+        }));
+    panel
+}
+
 fn graph_panel(id: u32, title: &str, expr: &str, _row: u32) -> serde_json::Value {
     serde_json::json!({
         "id": id,
@@ -216,6 +260,171 @@ fn graph_panel(id: u32, title: &str, expr: &str, _row: u32) -> serde_json::Value
     })
 }
 
+/* ============================= ALERT RULES ============================= */
+
+/// Thresholds for `generate-alerts`.
+#[derive(Debug, Clone, Copy)]
+pub struct AlertThresholds {
+    /// Health score below which a policy or the cluster alerts.
+    pub score: u32,
+    /// Webhook denials per second that count as a spike (and at least
+    /// twice the hourly rate).
+    pub denial_rate: f64,
+}
+
+impl Default for AlertThresholds {
+    fn default() -> Self {
+        Self {
+            score: kube_devops::api::HEALTHY_SCORE_THRESHOLD,
+            denial_rate: 0.5,
+        }
+    }
+}
+
+/// PrometheusRule with alerts on the metrics exposed by watch, reconcile,
+/// and the webhook.
+pub fn generate_alert_rules(thresholds: AlertThresholds) -> String {
+    let rule = serde_json::json!({
+        "apiVersion": "monitoring.coreos.com/v1",
+        "kind": "PrometheusRule",
+        "metadata": {
+            "name": format!("{APP_NAME}-alerts"),
+            "namespace": NAMESPACE,
+            "labels": {
+                "app.kubernetes.io/name": APP_NAME,
+                "release": "stable"
+            }
+        },
+        "spec": { "groups": build_alert_groups(thresholds) }
+    });
+    serde_yaml::to_string(&rule).expect("alert rules serialize to YAML")
+}
+
+fn build_alert_groups(thresholds: AlertThresholds) -> serde_json::Value {
+    let score = thresholds.score;
+    let denial_rate = thresholds.denial_rate;
+    serde_json::json!([
+        {
+            "name": "kube-devops.governance",
+            "rules": [
+                alert_rule(
+                    "KubeDevopsPolicyScoreLow",
+                    &format!("devopspolicy_health_score < {score}"),
+                    "15m",
+                    "warning",
+                    &format!("Policy health score below {score}"),
+                    &format!("{{{{ $labels.namespace }}}}/{{{{ $labels.policy }}}} has scored {{{{ $value }}}} (below {score}) for 15 minutes."),
+                ),
+                alert_rule(
+                    "KubeDevopsClusterScoreLow",
+                    &format!("cluster_health_score < {score}"),
+                    "15m",
+                    "warning",
+                    &format!("Cluster health score below {score}"),
+                    &format!("The watch controller reports a cluster health score of {{{{ $value }}}} (below {score})."),
+                ),
+                alert_rule(
+                    "KubeDevopsCriticalViolations",
+                    "sum by (namespace, policy) (devopspolicy_violations_by_severity{severity=\"critical\"}) > 0",
+                    "15m",
+                    "warning",
+                    "Critical policy violations",
+                    "{{ $labels.namespace }}/{{ $labels.policy }} has had {{ $value }} critical violations for 15 minutes.",
+                ),
+            ]
+        },
+        {
+            "name": "kube-devops.operator",
+            "rules": [
+                alert_rule(
+                    "KubeDevopsOperatorNotReady",
+                    "time() - devopspolicy_last_successful_reconcile_timestamp_seconds > 300",
+                    "5m",
+                    "critical",
+                    "kube-devops operator is not reconciling",
+                    "No DevOpsPolicy status patch has succeeded for {{ $value | humanizeDuration }}.",
+                ),
+                alert_rule(
+                    "KubeDevopsReconcileErrors",
+                    "rate(devopspolicy_reconcile_errors_total[5m]) > 0",
+                    "10m",
+                    "warning",
+                    "kube-devops reconcile errors",
+                    "Reconciles are failing at {{ $value | humanize }}/s.",
+                ),
+                alert_rule(
+                    "KubeDevopsRemediationFailures",
+                    "increase(devopspolicy_remediations_failed_total[15m]) > 0",
+                    "0m",
+                    "warning",
+                    "Enforce-mode remediations failing",
+                    "{{ $value | humanize }} workload patches failed in the last 15 minutes.",
+                ),
+                alert_rule(
+                    "KubeDevopsCrdMissing",
+                    "devopspolicy_crd_available == 0",
+                    "5m",
+                    "critical",
+                    "kube-devops CRD not installed",
+                    "{{ $labels.crd }} is not served; run `kube-devops crd install`.",
+                ),
+                alert_rule(
+                    "KubeDevopsAuditExportFailures",
+                    "increase(devopspolicy_audit_export_failures_total[1h]) > 0",
+                    "0m",
+                    "warning",
+                    "Audit results not exported",
+                    "{{ $value | humanize }} audit results failed to reach the audit sink in the last hour.",
+                ),
+            ]
+        },
+        {
+            "name": "kube-devops.webhook",
+            "rules": [
+                alert_rule(
+                    "KubeDevopsWebhookDenialSpike",
+                    &format!(
+                        "sum(rate(webhook_denials_total[5m])) > {denial_rate} \
+                         and sum(rate(webhook_denials_total[5m])) > 2 * sum(rate(webhook_denials_total[1h]))"
+                    ),
+                    "10m",
+                    "warning",
+                    "Admission denial spike",
+                    "The webhook is denying {{ $value | humanize }} requests/s, more than twice the hourly rate.",
+                ),
+                alert_rule(
+                    "KubeDevopsWebhookFailures",
+                    "sum(rate(webhook_failures_total[5m])) > 0",
+                    "10m",
+                    "warning",
+                    "Admission webhook cannot evaluate requests",
+                    "{{ $value | humanize }} admission requests/s fall back to the failure mode.",
+                ),
+            ]
+        }
+    ])
+}
+
+fn alert_rule(
+    name: &str,
+    expr: &str,
+    for_duration: &str,
+    severity: &str,
+    summary: &str,
+    description: &str,
+) -> serde_json::Value {
+    serde_json::json!({
+        "alert": name,
+        "expr": expr,
+        "for": for_duration,
+        "labels": { "severity": severity },
+        "annotations": {
+            "summary": summary,
+            "description": description
+        }
+    })
+}
+
 /* ============================= GENERATE ALL ============================= */
 
 pub fn generate_all() -> String {
@@ -233,7 +442,11 @@ pub fn generate_all() -> String {
     output.push_str("---\n");
     output.push_str(&generate_service_monitor_webhook());
     output.push_str("---\n");
-    output.push_str(&generate_grafana_dashboard_configmap());
+    output.push_str(&generate_grafana_dashboard_configmap(
+        ClassificationThresholds::default(),
+    ));
+    output.push_str("---\n");
+    output.push_str(&generate_alert_rules(AlertThresholds::default()));
 
     output
 }
@@ -375,7 +588,7 @@ mod tests {
 
     #[test]
     fn test_dashboard_configmap_valid_json() {
-        let yaml = generate_grafana_dashboard_configmap();
+        let yaml = generate_grafana_dashboard_configmap(Default::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         let dashboard_json_str = doc["data"]["kube-devops.json"]
@@ -388,7 +601,7 @@ mod tests {
 
     #[test]
     fn test_dashboard_has_panels() {
-        let dashboard = build_dashboard_json();
+        let dashboard = build_dashboard_json(Default::default());
         let panels = dashboard["panels"]
             .as_array()
             .expect("panels should be an array");
@@ -400,16 +613,105 @@ mod tests {
 
     #[test]
     fn test_dashboard_configmap_has_grafana_label() {
-        let yaml = generate_grafana_dashboard_configmap();
+        let yaml = generate_grafana_dashboard_configmap(Default::default());
         let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
 
         assert_eq!(doc["kind"], "ConfigMap");
         assert_eq!(doc["metadata"]["labels"]["grafana_dashboard"], "1");
     }
 
+    #[test]
+    fn test_dashboard_health_thresholds() {
+        let dashboard = build_dashboard_json(ClassificationThresholds {
+            healthy: 90,
+            stable: 75,
+            degraded: 50,
+        });
+        let panels = dashboard["panels"].as_array().unwrap();
+        for title in [
+            "Cluster Health Score",
+            "Namespace Health Scores",
+            "Health Scores",
+        ] {
+            let panel = panels.iter().find(|p| p["title"] == title).unwrap();
+            let steps: Vec<_> = panel["fieldConfig"]["defaults"]["thresholds"]["steps"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|s| (s["color"].as_str().unwrap(), s["value"].as_u64()))
+                .collect();
+            assert_eq!(
+                steps,
+                [
+                    ("red", None),
+                    ("orange", Some(50)),
+                    ("yellow", Some(75)),
+                    ("green", Some(90))
+                ],
+                "{title}"
+            );
+        }
+    }
+
+    // ── Alert rule tests ──
+
+    #[test]
+    fn test_alert_rules_parseable_prometheus_rule() {
+        let yaml = generate_alert_rules(AlertThresholds::default());
+        let doc: serde_yaml::Value = serde_yaml::from_str(&yaml).expect("valid YAML");
+        assert_eq!(doc["kind"], "PrometheusRule");
+        assert_eq!(doc["metadata"]["name"], "kube-devops-alerts");
+
+        let alerts: Vec<&str> = doc["spec"]["groups"]
+            .as_sequence()
+            .unwrap()
+            .iter()
+            .flat_map(|g| g["rules"].as_sequence().unwrap())
+            .map(|r| r["alert"].as_str().unwrap())
+            .collect();
+        for expected in [
+            "KubeDevopsPolicyScoreLow",
+            "KubeDevopsReconcileErrors",
+            "KubeDevopsWebhookDenialSpike",
+            "KubeDevopsRemediationFailures",
+            "KubeDevopsOperatorNotReady",
+        ] {
+            assert!(alerts.contains(&expected), "missing alert {expected}");
+        }
+    }
+
+    #[test]
+    fn test_alert_rules_use_thresholds() {
+        let yaml = generate_alert_rules(AlertThresholds {
+            score: 65,
+            denial_rate: 2.0,
+        });
+        assert!(yaml.contains("devopspolicy_health_score < 65"));
+        assert!(yaml.contains("cluster_health_score < 65"));
+        assert!(yaml.contains("sum(rate(webhook_denials_total[5m])) > 2 and"));
+    }
+
+    #[test]
+    fn test_alert_rules_reference_exposed_metrics() {
+        let yaml = generate_alert_rules(AlertThresholds::default());
+        for metric in [
+            "devopspolicy_health_score",
+            "cluster_health_score",
+            "devopspolicy_violations_by_severity",
+            "devopspolicy_last_successful_reconcile_timestamp_seconds",
+            "devopspolicy_reconcile_errors_total",
+            "devopspolicy_remediations_failed_total",
+            "devopspolicy_crd_available",
+            "webhook_denials_total",
+            "webhook_failures_total",
+        ] {
+            assert!(yaml.contains(metric), "alerts should use {metric}");
+        }
+    }
+
     #[test]
     fn test_dashboard_references_all_metrics() {
-        let dashboard = build_dashboard_json();
+        let dashboard = build_dashboard_json(Default::default());
         let dashboard_str = serde_json::to_string(&dashboard).expect("valid JSON");
 
         let expected_metrics = [
@@ -419,8 +721,10 @@ mod tests {
             "namespace_health_score",
             "pod_events_total",
             "pods_tracked_total",
+            "namespace_violation_count",
             "devopspolicy_violations_total",
             "devopspolicy_health_score",
+            "devopspolicy_health_threshold",
             "devopspolicy_reconcile_errors_total",
             "devopspolicy_reconcile_duration_seconds",
             "devopspolicy_pods_scanned_total",
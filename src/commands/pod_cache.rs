@@ -0,0 +1,271 @@
+use std::collections::{BTreeSet, HashMap};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+use futures::{Stream, StreamExt};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{Api, Client, ResourceExt};
+use kube_runtime::WatchStreamExt;
+use kube_runtime::reflector::{self, ObjectRef, Store};
+use kube_runtime::watcher::{Config, Event, watcher};
+use tokio::sync::broadcast;
+use tracing::info;
+
+/* ============================= POD CACHE ============================= */
+
+/// Reflector cache of every pod in the cluster, indexed by namespace, so
+/// policies sharing a namespace and periodic requeues evaluate from memory
+/// instead of listing pods on every reconcile.
+///
+/// `managedFields` are dropped before objects enter the store. The watcher
+/// retries with backoff, so a briefly unavailable API server leaves the last
+/// known pods in place. The same watch feeds [`PodCache::changed_namespaces`],
+/// so pod-triggered reconciles need no second pod watch.
+#[derive(Clone)]
+pub(crate) struct PodCache {
+    store: Store<Pod>,
+    index: Arc<RwLock<NamespaceIndex>>,
+    synced: Arc<AtomicBool>,
+    changes: broadcast::Sender<String>,
+}
+
+/// Pending namespace notifications per subscriber before the oldest are
+/// dropped; a lagging subscriber still catches up on its periodic requeue.
+const CHANGE_BUFFER: usize = 1024;
+
+impl PodCache {
+    /// Start the pod reflector.
+    pub(crate) fn spawn(client: &Client) -> Self {
+        let (store, writer) = reflector::store();
+        let index: Arc<RwLock<NamespaceIndex>> = Default::default();
+        let (changes, _) = broadcast::channel(CHANGE_BUFFER);
+        let stream = reflector::reflector(
+            writer,
+            watcher(Api::<Pod>::all(client.clone()), Config::default())
+                .default_backoff()
+                .modify(|pod| pod.managed_fields_mut().clear()),
+        );
+        let events = index.clone();
+        let notify = changes.clone();
+        tokio::spawn(stream.for_each(move |event| {
+            if let Ok(event) = event {
+                events
+                    .write()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .apply(&event);
+                for namespace in event_namespaces(&event) {
+                    // No subscribers yet is not an error
+                    let _ = notify.send(namespace);
+                }
+            }
+            futures::future::ready(())
+        }));
+
+        let synced = Arc::new(AtomicBool::new(false));
+        let cache = Self {
+            store,
+            index,
+            synced: synced.clone(),
+            changes,
+        };
+        let waiting = cache.clone();
+        tokio::spawn(async move {
+            if waiting.store.wait_until_ready().await.is_ok() {
+                synced.store(true, Ordering::Relaxed);
+                info!(pods = waiting.store.state().len(), "pod_cache_synced");
+            }
+        });
+        cache
+    }
+
+    /// Whether the initial list has completed. Until then callers list pods
+    /// from the API server.
+    pub(crate) fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Relaxed)
+    }
+
+    /// Namespaces of pods the watch sees change, from subscription on. A
+    /// resync reports every namespace with pods.
+    pub(crate) fn changed_namespaces(&self) -> impl Stream<Item = String> + Send + 'static {
+        futures::stream::unfold(self.changes.subscribe(), |mut changes| async move {
+            loop {
+                match changes.recv().await {
+                    Ok(namespace) => return Some((namespace, changes)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    /// Cached pods in `namespace`, ordered by name.
+    pub(crate) fn pods_in(&self, namespace: &str) -> Vec<Pod> {
+        self.pods(|ns| ns == namespace)
+    }
+
+    /// Cached pods in every namespace `covers` accepts.
+    pub(crate) fn pods(&self, covers: impl Fn(&str) -> bool) -> Vec<Pod> {
+        let index = self.index.read().unwrap_or_else(|e| e.into_inner());
+        index
+            .namespaces
+            .iter()
+            .filter(|(namespace, _)| covers(namespace))
+            .flat_map(|(namespace, names)| {
+                names.iter().filter_map(|name| {
+                    self.store
+                        .get(&ObjectRef::new(name).within(namespace))
+                        .map(|pod| pod.as_ref().clone())
+                })
+            })
+            .collect()
+    }
+}
+
+/// Namespaces a watch event touches, each once.
+fn event_namespaces(event: &Event<Pod>) -> BTreeSet<String> {
+    match event {
+        Event::Applied(pod) | Event::Deleted(pod) => {
+            BTreeSet::from([pod.namespace().unwrap_or_default()])
+        }
+        Event::Restarted(pods) => pods
+            .iter()
+            .map(|pod| pod.namespace().unwrap_or_default())
+            .collect(),
+    }
+}
+
+/// Pod names per namespace, maintained from the same watch events as the store.
+#[derive(Debug, Default)]
+struct NamespaceIndex {
+    namespaces: HashMap<String, BTreeSet<String>>,
+}
+
+impl NamespaceIndex {
+    fn apply(&mut self, event: &Event<Pod>) {
+        match event {
+            Event::Applied(pod) => {
+                self.namespaces
+                    .entry(pod.namespace().unwrap_or_default())
+                    .or_default()
+                    .insert(pod.name_any());
+            }
+            Event::Deleted(pod) => {
+                let namespace = pod.namespace().unwrap_or_default();
+                if let Some(names) = self.namespaces.get_mut(&namespace) {
+                    names.remove(&pod.name_any());
+                    if names.is_empty() {
+                        self.namespaces.remove(&namespace);
+                    }
+                }
+            }
+            Event::Restarted(pods) => {
+                self.namespaces.clear();
+                for pod in pods {
+                    self.apply(&Event::Applied(pod.clone()));
+                }
+            }
+        }
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn pod(namespace: &str, name: &str) -> Pod {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn cache(events: &[Event<Pod>]) -> PodCache {
+        let (store, mut writer) = reflector::store();
+        let mut index = NamespaceIndex::default();
+        for event in events {
+            writer.apply_watcher_event(event);
+            index.apply(event);
+        }
+        PodCache {
+            store,
+            index: Arc::new(RwLock::new(index)),
+            synced: Arc::new(AtomicBool::new(true)),
+            changes: broadcast::channel(CHANGE_BUFFER).0,
+        }
+    }
+
+    fn names(pods: &[Pod]) -> Vec<String> {
+        pods.iter().map(|p| p.name_any()).collect()
+    }
+
+    #[test]
+    fn test_pods_in_namespace() {
+        let cache = cache(&[Event::Restarted(vec![
+            pod("prod", "web-2"),
+            pod("prod", "web-1"),
+            pod("dev", "web-1"),
+        ])]);
+        assert_eq!(names(&cache.pods_in("prod")), ["web-1", "web-2"]);
+        assert_eq!(names(&cache.pods_in("dev")), ["web-1"]);
+        assert!(cache.pods_in("staging").is_empty());
+
+        let mut covered = names(&cache.pods(|ns| ns != "staging"));
+        covered.sort();
+        assert_eq!(covered, ["web-1", "web-1", "web-2"]);
+    }
+
+    #[test]
+    fn test_index_follows_watch_events() {
+        let cache = cache(&[
+            Event::Restarted(vec![pod("prod", "web-1"), pod("dev", "api-1")]),
+            Event::Applied(pod("prod", "web-2")),
+            Event::Deleted(pod("prod", "web-1")),
+            Event::Deleted(pod("dev", "api-1")),
+        ]);
+        assert_eq!(names(&cache.pods_in("prod")), ["web-2"]);
+        assert!(cache.pods_in("dev").is_empty());
+        let index = cache.index.read().unwrap();
+        assert!(!index.namespaces.contains_key("dev"));
+    }
+
+    #[test]
+    fn test_event_namespaces() {
+        let names = |event| event_namespaces(&event).into_iter().collect::<Vec<_>>();
+        assert_eq!(names(Event::Applied(pod("prod", "web-1"))), ["prod"]);
+        assert_eq!(names(Event::Deleted(pod("dev", "web-1"))), ["dev"]);
+        assert_eq!(
+            names(Event::Restarted(vec![
+                pod("prod", "web-1"),
+                pod("dev", "api-1"),
+                pod("prod", "web-2"),
+            ])),
+            ["dev", "prod"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_changed_namespaces_follow_notifications() {
+        let cache = cache(&[]);
+        let mut changes = Box::pin(cache.changed_namespaces());
+        cache.changes.send("prod".to_string()).unwrap();
+        cache.changes.send("dev".to_string()).unwrap();
+        assert_eq!(changes.next().await.as_deref(), Some("prod"));
+        assert_eq!(changes.next().await.as_deref(), Some("dev"));
+    }
+
+    #[test]
+    fn test_restart_replaces_index() {
+        let cache = cache(&[
+            Event::Applied(pod("prod", "web-1")),
+            Event::Restarted(vec![pod("prod", "web-2")]),
+        ]);
+        assert_eq!(names(&cache.pods_in("prod")), ["web-2"]);
+    }
+}
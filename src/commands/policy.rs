@@ -1,63 +1,154 @@
-use anyhow::Result;
-use kube_devops::bundles;
-use kube_devops::crd::DevOpsPolicy;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use chrono::Utc;
+use k8s_openapi::api::core::v1::{ConfigMap, Namespace, Pod};
+use kube::ResourceExt;
+use kube_devops::bundles::{self, PolicyBundle};
+use kube_devops::crd::{ClusterDevOpsPolicy, DevOpsPolicy, DevOpsPolicySpec};
+use kube_devops::governance;
+use kube_devops::output::{self, Color, OutputFormat};
+use kube_devops::policy_diff::{self, ChangeKind, DiffStatus, FieldChange, PolicyDiff};
+use kube_devops::policy_resolution::{self, ResolvedPolicy, WorkloadOverrides};
+use kube_devops::policy_validation::{self, IssueLevel, PolicyValidation};
+use serde::Serialize;
+
+use crate::commands::client;
 
 /* ============================= BUNDLE COMMANDS ============================= */
 
+/// The built-in bundles plus those in `bundle_dir` and the ConfigMap
+/// `bundle_configmap` (`name` in `namespace`, or `namespace/name`), where
+/// every `*.yaml` / `*.yml` key holds one bundle.
+pub async fn load_bundles(
+    bundle_dir: Option<&Path>,
+    bundle_configmap: Option<&str>,
+    namespace: &str,
+) -> Result<Vec<PolicyBundle>> {
+    let mut custom = match bundle_dir {
+        Some(dir) => bundles::load_bundle_dir(dir)?,
+        None => Vec::new(),
+    };
+    if let Some(configmap) = bundle_configmap {
+        let (namespace, name) = configmap.split_once('/').unwrap_or((namespace, configmap));
+        let client = client::connect().await?;
+        let api: kube::Api<ConfigMap> = kube::Api::namespaced(client, namespace);
+        let data = api
+            .get(name)
+            .await
+            .with_context(|| format!("Failed to read bundle ConfigMap {namespace}/{name}"))?
+            .data
+            .unwrap_or_default();
+        for (key, content) in data.iter().filter(|(key, _)| bundles::is_yaml(key)) {
+            let source = format!("configmap/{namespace}/{name}:{key}");
+            custom.push(bundles::parse_bundle(content, &source)?);
+        }
+    }
+    bundles::with_custom_bundles(custom)
+}
+
 /// List all available policy bundles.
-pub fn bundle_list() -> Result<()> {
-    let bundles = bundles::all_bundles();
-    println!("{:<15} DESCRIPTION", "NAME");
+pub fn bundle_list(bundles: &[PolicyBundle]) -> Result<()> {
+    println!("{:<15} {:<12} DESCRIPTION", "NAME", "SOURCE");
     println!("{}", "-".repeat(70));
-    for bundle in &bundles {
-        println!("{:<15} {}", bundle.name, bundle.description);
+    for bundle in bundles {
+        println!(
+            "{:<15} {:<12} {}",
+            bundle.name,
+            source_label(bundle),
+            bundle.description
+        );
     }
     Ok(())
 }
 
+/// `built-in` or `custom`; `bundle-show` prints the full source.
+fn source_label(bundle: &PolicyBundle) -> &'static str {
+    if bundle.source == bundles::BUILT_IN {
+        bundles::BUILT_IN
+    } else {
+        "custom"
+    }
+}
+
 /// Show details of a specific bundle.
-pub fn bundle_show(name: &str) -> Result<()> {
-    match bundles::get_bundle(name) {
-        Some(bundle) => {
-            println!("Bundle: {}", bundle.name);
-            println!("Description: {}", bundle.description);
-            println!();
-            let yaml = serde_yaml::to_string(&bundle.spec)?;
-            println!("Spec:");
-            for line in yaml.lines() {
-                println!("  {line}");
-            }
-            Ok(())
-        }
-        None => {
-            let available: Vec<String> = bundles::all_bundles()
-                .iter()
-                .map(|b| b.name.clone())
-                .collect();
-            anyhow::bail!(
-                "Unknown bundle '{}'. Available bundles: {}",
-                name,
-                available.join(", ")
-            )
-        }
+pub fn bundle_show(bundles: &[PolicyBundle], name: &str) -> Result<()> {
+    let bundle = bundles::find_bundle(bundles, name)?;
+    println!("Bundle: {}", bundle.name);
+    println!("Description: {}", bundle.description);
+    println!("Source: {}", bundle.source);
+    println!();
+    let yaml = serde_yaml::to_string(&bundle.spec)?;
+    println!("Spec:");
+    for line in yaml.lines() {
+        println!("  {line}");
     }
+    Ok(())
 }
 
 /// Generate a DevOpsPolicy YAML from a bundle template.
-pub fn bundle_apply(name: &str, namespace: &str, policy_name: &str) -> Result<()> {
-    let bundle = bundles::get_bundle(name).ok_or_else(|| {
-        let available: Vec<String> = bundles::all_bundles()
-            .iter()
-            .map(|b| b.name.clone())
-            .collect();
-        anyhow::anyhow!(
-            "Unknown bundle '{}'. Available bundles: {}",
-            name,
-            available.join(", ")
-        )
-    })?;
+pub fn bundle_apply(
+    bundles: &[PolicyBundle],
+    name: &str,
+    namespace: &str,
+    policy_name: &str,
+) -> Result<()> {
+    let bundle = bundles::find_bundle(bundles, name)?;
+    print!(
+        "{}",
+        policy_yaml(&bundle.name, namespace, policy_name, &bundle.spec)?
+    );
+    Ok(())
+}
 
-    let spec_yaml = serde_yaml::to_string(&bundle.spec)?;
+/// Generate a DevOpsPolicy YAML from the pods running in `namespace`, with a
+/// comment header recording how the pods fared on each check.
+pub async fn bundle_create(namespace: &str, policy_name: &str) -> Result<()> {
+    let client = client::connect().await?;
+    let pod_api: kube::Api<Pod> = kube::Api::namespaced(client, namespace);
+    let pods = pod_api.list(&Default::default()).await?.items;
+    if pods.is_empty() {
+        anyhow::bail!("No pods in namespace '{namespace}' to derive a policy from");
+    }
+
+    let baseline = bundles::baseline_from_pods(&pods, Utc::now());
+    print!("{}", baseline_header(namespace, &baseline));
+    print!(
+        "{}",
+        policy_yaml("from-cluster", namespace, policy_name, &baseline.spec)?
+    );
+    Ok(())
+}
+
+/// YAML comments summarising a synthesized baseline, one line per check.
+fn baseline_header(namespace: &str, baseline: &bundles::ClusterBaseline) -> String {
+    let mut out = format!(
+        "# Synthesized from {} pod(s) in namespace '{namespace}'.\n",
+        baseline.total_pods
+    );
+    for check in &baseline.checks {
+        let note = match (check.setting.as_str(), check.violating_pods) {
+            ("off", n) => format!("{n} pod(s) would violate it"),
+            (_, 0) => "met by every pod".to_string(),
+            (_, n) => format!("{n} pod(s) above the 95th percentile"),
+        };
+        out.push_str(&format!(
+            "#   {:<22} {:<6} {note}\n",
+            check.field, check.setting
+        ));
+    }
+    out
+}
+
+/// A DevOpsPolicy manifest labelled with the bundle it came from.
+fn policy_yaml(
+    bundle_name: &str,
+    namespace: &str,
+    policy_name: &str,
+    spec: &DevOpsPolicySpec,
+) -> Result<String> {
+    let spec_yaml = serde_yaml::to_string(spec)?;
 
     // Indent the spec YAML for embedding
     let indented_spec: String = spec_yaml
@@ -67,7 +158,7 @@ pub fn bundle_apply(name: &str, namespace: &str, policy_name: &str) -> Result<()
         .collect::<Vec<_>>()
         .join("\n");
 
-    let output = format!(
+    Ok(format!(
         r#"apiVersion: devops.stochastic.io/v1
 kind: DevOpsPolicy
 metadata:
@@ -78,81 +169,117 @@ metadata:
     app.kubernetes.io/managed-by: kube-devops
 spec:
 {indented_spec}
-"#,
-        bundle_name = bundle.name,
-    );
-
-    print!("{output}");
-    Ok(())
+"#
+    ))
 }
 
 /* ============================= GITOPS COMMANDS ============================= */
 
-/// Export DevOpsPolicies from a namespace as YAML.
-pub async fn export(namespace: &str) -> Result<()> {
-    let client = kube::Client::try_default().await?;
-    let api: kube::Api<DevOpsPolicy> = kube::Api::namespaced(client, namespace);
-    let policies = api.list(&Default::default()).await?;
-
-    if policies.items.is_empty() {
-        println!("No DevOpsPolicies found in namespace '{namespace}'");
-        return Ok(());
-    }
+/// `--namespace` value that exports policies from every namespace.
+pub(crate) const ALL_NAMESPACES: &str = "all";
 
-    let now = chrono::Utc::now().to_rfc3339();
-    let mut first = true;
-    for policy in &policies.items {
-        if !first {
-            println!("---");
-        }
-        first = false;
+/// Annotation kubectl uses for client-side apply; meaningless in another cluster.
+const LAST_APPLIED_ANNOTATION: &str = "kubectl.kubernetes.io/last-applied-configuration";
 
-        let spec_yaml = serde_yaml::to_string(&policy.spec)?;
-        let indented_spec: String = spec_yaml
-            .lines()
-            .filter(|l| !l.is_empty())
-            .map(|line| format!("  {line}"))
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        let name = policy.metadata.name.as_deref().unwrap_or("unnamed");
-        let ns = policy.metadata.namespace.as_deref().unwrap_or(namespace);
+/// Export DevOpsPolicies from a namespace (or every namespace, for
+/// [`ALL_NAMESPACES`]) as a multi-document YAML file.
+pub async fn export(namespace: &str) -> Result<()> {
+    let client = client::connect().await?;
+    let api: kube::Api<DevOpsPolicy> = if namespace == ALL_NAMESPACES {
+        kube::Api::all(client)
+    } else {
+        kube::Api::namespaced(client, namespace)
+    };
+    let mut policies = api.list(&Default::default()).await?.items;
 
-        println!(
-            r#"apiVersion: devops.stochastic.io/v1
-kind: DevOpsPolicy
-metadata:
-  name: {name}
-  namespace: {ns}
-  annotations:
-    devops.stochastic.io/exported-at: "{now}"
-    devops.stochastic.io/exported-from: "{ns}"
-spec:
-{indented_spec}"#
-        );
+    if policies.is_empty() {
+        eprintln!("No DevOpsPolicies found in namespace '{namespace}'");
+        return Ok(());
     }
 
+    policies.sort_by_key(|p| (p.metadata.namespace.clone(), p.metadata.name.clone()));
+    let now = Utc::now().to_rfc3339();
+    print!("{}", export_yaml(policies, &now)?);
     Ok(())
 }
 
-/// Import DevOpsPolicies from a YAML file.
-pub async fn import(file: &str, dry_run: bool) -> Result<()> {
-    let content = std::fs::read_to_string(file)?;
-    let client = kube::Client::try_default().await?;
+/// Policies as YAML documents separated by `---`, stripped of server-managed
+/// fields and annotated with when and where they were exported from.
+fn export_yaml(policies: Vec<DevOpsPolicy>, exported_at: &str) -> Result<String> {
+    let docs = policies
+        .into_iter()
+        .map(|mut policy| {
+            strip_server_fields(&mut policy);
+            let ns = policy.metadata.namespace.clone().unwrap_or_default();
+            let annotations = policy
+                .metadata
+                .annotations
+                .get_or_insert_with(Default::default);
+            annotations.insert(
+                "devops.stochastic.io/exported-at".to_string(),
+                exported_at.to_string(),
+            );
+            annotations.insert("devops.stochastic.io/exported-from".to_string(), ns);
+            serde_yaml::to_string(&policy)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(docs.join("---\n"))
+}
 
-    for doc in content.split("---") {
-        let trimmed = doc.trim();
-        if trimmed.is_empty() {
-            continue;
+/// Drop status and the metadata the API server manages (uid,
+/// resourceVersion, generation, timestamps, managedFields, owner references,
+/// finalizers), so a policy can be applied to any cluster.
+pub(crate) fn strip_server_fields(policy: &mut DevOpsPolicy) {
+    let metadata = &mut policy.metadata;
+    metadata.uid = None;
+    metadata.resource_version = None;
+    metadata.generation = None;
+    metadata.creation_timestamp = None;
+    metadata.deletion_timestamp = None;
+    metadata.deletion_grace_period_seconds = None;
+    metadata.managed_fields = None;
+    metadata.owner_references = None;
+    metadata.finalizers = None;
+    metadata.self_link = None;
+    if let Some(annotations) = &mut metadata.annotations {
+        annotations.remove(LAST_APPLIED_ANNOTATION);
+        if annotations.is_empty() {
+            metadata.annotations = None;
         }
+    }
+    policy.status = None;
+}
 
-        let value: serde_yaml::Value = serde_yaml::from_str(trimmed)?;
-        let kind = value["kind"].as_str().unwrap_or("");
-        if kind != "DevOpsPolicy" {
-            continue;
+/// The DevOpsPolicies of a multi-document YAML file, including the items of
+/// `kind: List` documents (as written by `kubectl get -o yaml`); other kinds
+/// are skipped. Server-managed fields are stripped.
+pub(crate) fn parse_policies(content: &str) -> Result<Vec<DevOpsPolicy>> {
+    let mut policies = Vec::new();
+    for value in output::yaml_documents(content)? {
+        let items = match value["kind"].as_str() {
+            Some("List") => value["items"].as_sequence().cloned().unwrap_or_default(),
+            _ => vec![value],
+        };
+        for item in items {
+            if item["kind"].as_str() == Some("DevOpsPolicy") {
+                let mut policy: DevOpsPolicy = serde_yaml::from_value(item)?;
+                strip_server_fields(&mut policy);
+                policies.push(policy);
+            }
         }
+    }
+    Ok(policies)
+}
+
+/// Import DevOpsPolicies from a YAML file. With `prune`, DevOpsPolicies in
+/// the namespaces the file covers that the file does not list are deleted.
+pub async fn import(file: &str, dry_run: bool, prune: bool) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read policy file '{file}'"))?;
+    let policies = parse_policies(&content)?;
+    let client = client::connect().await?;
 
-        let policy: DevOpsPolicy = serde_yaml::from_str(trimmed)?;
+    for policy in &policies {
         let name = policy.metadata.name.as_deref().unwrap_or("unnamed");
         let ns = policy.metadata.namespace.as_deref().unwrap_or("default");
 
@@ -163,8 +290,8 @@ pub async fn import(file: &str, dry_run: bool) -> Result<()> {
             match api
                 .patch(
                     name,
-                    &kube::api::PatchParams::apply("kube-devops-cli"),
-                    &kube::api::Patch::Apply(&policy),
+                    &kube::api::PatchParams::apply(policy_diff::CLI_FIELD_MANAGER),
+                    &kube::api::Patch::Apply(policy),
                 )
                 .await
             {
@@ -174,60 +301,383 @@ pub async fn import(file: &str, dry_run: bool) -> Result<()> {
         }
     }
 
+    if !prune {
+        return Ok(());
+    }
+    let kept = policies_by_namespace(&policies);
+    for ns in kept.keys() {
+        let api: kube::Api<DevOpsPolicy> = kube::Api::namespaced(client.clone(), ns);
+        let existing = api.list(&Default::default()).await?.items;
+        for name in prune_candidates(&existing, &kept) {
+            if dry_run {
+                println!("[DRY-RUN] Would delete DevOpsPolicy '{name}' in namespace '{ns}'");
+                continue;
+            }
+            match api.delete(&name, &Default::default()).await {
+                Ok(_) => {
+                    println!("Deleted DevOpsPolicy '{name}' in namespace '{ns}' (not in file)")
+                }
+                Err(kube::Error::Api(err)) if err.code == 404 => {}
+                Err(e) => eprintln!("Failed to delete '{name}': {e}"),
+            }
+        }
+    }
+
     Ok(())
 }
 
-/// Diff local YAML policies against cluster state.
-pub async fn diff(file: &str) -> Result<()> {
-    let content = std::fs::read_to_string(file)?;
-    let client = kube::Client::try_default().await?;
+/// Policy names per namespace, as listed in an import file.
+fn policies_by_namespace(policies: &[DevOpsPolicy]) -> BTreeMap<String, BTreeSet<String>> {
+    let mut kept: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for policy in policies {
+        kept.entry(
+            policy
+                .metadata
+                .namespace
+                .clone()
+                .unwrap_or_else(|| "default".to_string()),
+        )
+        .or_default()
+        .insert(policy.name_any());
+    }
+    kept
+}
 
-    for doc in content.split("---") {
-        let trimmed = doc.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
+/// Names of `existing` policies (all in one namespace) missing from `kept`.
+fn prune_candidates(
+    existing: &[DevOpsPolicy],
+    kept: &BTreeMap<String, BTreeSet<String>>,
+) -> Vec<String> {
+    existing
+        .iter()
+        .filter(|policy| {
+            let ns = policy.metadata.namespace.as_deref().unwrap_or("default");
+            !kept
+                .get(ns)
+                .is_some_and(|names| names.contains(&policy.name_any()))
+        })
+        .map(|policy| policy.name_any())
+        .collect()
+}
 
-        let value: serde_yaml::Value = serde_yaml::from_str(trimmed)?;
-        let kind = value["kind"].as_str().unwrap_or("");
-        if kind != "DevOpsPolicy" {
-            continue;
-        }
+/// Diff local YAML policies against cluster state, field by field.
+///
+/// Each field is classified the way `policy import` will treat it: added,
+/// changed, removed (applied by the CLI before and dropped from the file),
+/// or in conflict with another field manager. Fields only other managers
+/// own are left out, since import does not touch them.
+pub async fn diff(file: &str, format: OutputFormat) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read policy file '{file}'"))?;
+    let client = client::connect().await?;
 
-        let local_policy: DevOpsPolicy = serde_yaml::from_str(trimmed)?;
-        let name = local_policy.metadata.name.as_deref().unwrap_or("unnamed");
-        let ns = local_policy
-            .metadata
-            .namespace
-            .as_deref()
-            .unwrap_or("default");
+    let mut diffs = Vec::new();
+    for local_policy in parse_policies(&content)? {
+        diffs.push(diff_policy(&client, &local_policy).await?);
+    }
 
-        let api: kube::Api<DevOpsPolicy> = kube::Api::namespaced(client.clone(), ns);
-        match api.get(name).await {
-            Ok(remote_policy) => {
-                let local_json = serde_json::to_value(&local_policy.spec)?;
-                let remote_json = serde_json::to_value(&remote_policy.spec)?;
-
-                if local_json == remote_json {
-                    println!("[=] {ns}/{name}: no changes");
-                } else {
-                    println!("[~] {ns}/{name}: spec differs");
-                    diff_json("spec", &remote_json, &local_json, "  ");
+    if format.is_structured() {
+        return output::print_structured(&diffs, format);
+    }
+    print!("{}", format_diff(&diffs, output::use_color()));
+    Ok(())
+}
+
+/// Three-way diff of one policy against its live counterpart. Failing to
+/// read the live policy is reported in the diff, not as an error.
+pub(crate) async fn diff_policy(
+    client: &kube::Client,
+    policy: &DevOpsPolicy,
+) -> Result<PolicyDiff> {
+    let name = policy.metadata.name.as_deref().unwrap_or("unnamed");
+    let ns = policy.metadata.namespace.as_deref().unwrap_or("default");
+    let local_json = serde_json::to_value(&policy.spec)?;
+
+    let api: kube::Api<DevOpsPolicy> = kube::Api::namespaced(client.clone(), ns);
+    Ok(match api.get_opt(name).await {
+        Ok(Some(live_policy)) => {
+            let live_json = serde_json::to_value(&live_policy.spec)?;
+            PolicyDiff::compare(
+                ns,
+                name,
+                &local_json,
+                Some((&live_json, live_policy.managed_fields())),
+            )
+        }
+        Ok(None) => PolicyDiff::compare(ns, name, &local_json, None),
+        Err(e) => PolicyDiff::failed(ns, name, format!("error fetching from cluster: {e}")),
+    })
+}
+
+/// Render policy diffs as text, one `[marker] ns/name` line per policy
+/// followed by its field changes.
+pub(crate) fn format_diff(diffs: &[PolicyDiff], color: bool) -> String {
+    let mut out = String::new();
+    for diff in diffs {
+        let id = format!("{}/{}", diff.namespace, diff.name);
+        match diff.status {
+            DiffStatus::New => out.push_str(&format!("[+] {id}: new (not in cluster)\n")),
+            DiffStatus::Unchanged => out.push_str(&format!("[=] {id}: no changes\n")),
+            DiffStatus::Error => out.push_str(&format!(
+                "[!] {id}: {}\n",
+                diff.error.as_deref().unwrap_or("error")
+            )),
+            DiffStatus::Changed => {
+                out.push_str(&format!("[~] {id}: {} change(s)", diff.changes.len()));
+                match diff.conflicts() {
+                    0 => out.push('\n'),
+                    n => out.push_str(&format!(", {n} conflict(s)\n")),
+                }
+                for change in &diff.changes {
+                    out.push_str(&format!("  {}\n", format_change(change, color)));
                 }
             }
-            Err(kube::Error::Api(err)) if err.code == 404 => {
-                println!("[+] {ns}/{name}: new (not in cluster)");
-            }
-            Err(e) => {
-                println!("[!] {ns}/{name}: error fetching from cluster: {e}");
+        }
+    }
+    out
+}
+
+fn format_change(change: &FieldChange, color: bool) -> String {
+    let value =
+        |v: &Option<serde_json::Value>| v.as_ref().map(|v| v.to_string()).unwrap_or_default();
+    let (line, paint) = match change.kind {
+        ChangeKind::Add => (
+            format!("+ {}: {}", change.path, value(&change.file)),
+            Color::Green,
+        ),
+        ChangeKind::Change => (
+            format!(
+                "~ {}: {} → {}",
+                change.path,
+                value(&change.live),
+                value(&change.file)
+            ),
+            Color::Yellow,
+        ),
+        ChangeKind::Remove => (
+            format!("- {}: {}", change.path, value(&change.live)),
+            Color::Red,
+        ),
+        ChangeKind::Conflict => (
+            format!(
+                "! {}: {} → {} (conflict with {})",
+                change.path,
+                value(&change.live),
+                value(&change.file),
+                change.owners.join(", ")
+            ),
+            Color::Red,
+        ),
+    };
+    output::paint(&line, paint, color)
+}
+
+/* ============================= VALIDATION ============================= */
+
+/// Validate the policies in a local file against the CRD schema and
+/// cross-field rules, without a cluster.
+///
+/// Exits with an error when any policy has an error; warnings alone pass.
+pub fn validate(file: &str, format: OutputFormat) -> Result<()> {
+    let content = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read policy file '{file}'"))?;
+    let results = policy_validation::validate_manifests(&content)
+        .with_context(|| format!("Failed to parse policy file '{file}'"))?;
+    if results.is_empty() {
+        anyhow::bail!("No DevOpsPolicy or ClusterDevOpsPolicy found in '{file}'");
+    }
+
+    if format.is_structured() {
+        output::print_structured(&results, format)?;
+    } else {
+        print!("{}", format_validation(file, &results));
+    }
+
+    let errors: usize = results.iter().map(PolicyValidation::errors).sum();
+    if errors > 0 {
+        anyhow::bail!("{errors} error(s) found");
+    }
+    Ok(())
+}
+
+fn format_validation(file: &str, results: &[PolicyValidation]) -> String {
+    let mut out = String::new();
+    for result in results {
+        let status = if result.errors() > 0 { "FAIL" } else { "OK" };
+        let name = match &result.namespace {
+            Some(ns) => format!("{ns}/{}", result.name),
+            None => result.name.clone(),
+        };
+        out.push_str(&format!("[{status}] {file}: {} {name}\n", result.kind));
+        for issue in &result.issues {
+            let level = match issue.level {
+                IssueLevel::Error => "error",
+                IssueLevel::Warning => "warning",
+            };
+            out.push_str(&format!(
+                "    {level:<8} {}: {}\n",
+                issue.field, issue.message
+            ));
+        }
+    }
+    let errors: usize = results.iter().map(PolicyValidation::errors).sum();
+    let warnings: usize = results.iter().map(PolicyValidation::warnings).sum();
+    out.push_str(&format!(
+        "\n{} policy manifest(s): {errors} error(s), {warnings} warning(s)\n",
+        results.len()
+    ));
+    out
+}
+
+/* ============================= EFFECTIVE POLICY ============================= */
+
+/// Effective policy for a namespace, plus workloads that override it.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EffectivePolicyReport {
+    namespace: String,
+    /// `None` when no DevOpsPolicy or ClusterDevOpsPolicy applies.
+    policy: Option<ResolvedPolicy>,
+    /// Profile selected by the namespace's labels, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    profile: Option<String>,
+    /// Workload label → overrides parsed from its pod annotations.
+    workload_overrides: BTreeMap<String, WorkloadOverrides>,
+}
+
+fn build_effective_report(
+    namespace: &str,
+    cluster_policies: &[ClusterDevOpsPolicy],
+    policies: &[DevOpsPolicy],
+    pods: &[Pod],
+    namespace_labels: Option<&BTreeMap<String, String>>,
+) -> EffectivePolicyReport {
+    // Same selection as the watch controller: first live policy by name
+    let namespaced = policies
+        .iter()
+        .filter(|p| p.metadata.deletion_timestamp.is_none())
+        .min_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+
+    let mut workload_overrides = BTreeMap::new();
+    for pod in pods {
+        let overrides = policy_resolution::workload_overrides(pod.metadata.annotations.as_ref());
+        if !overrides.is_empty() || !overrides.invalid.is_empty() {
+            workload_overrides
+                .entry(governance::workload_label(pod))
+                .or_insert(overrides);
+        }
+    }
+
+    let policy =
+        policy_resolution::explain_effective_policy(namespace, cluster_policies, namespaced);
+    let profile = policy.as_ref().and_then(|p| {
+        policy_resolution::selected_profile(&p.spec, namespace_labels).map(str::to_string)
+    });
+
+    EffectivePolicyReport {
+        namespace: namespace.to_string(),
+        policy: policy.map(|p| policy_resolution::explain_profile(p, namespace_labels)),
+        profile,
+        workload_overrides,
+    }
+}
+
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn format_effective_table(report: &EffectivePolicyReport) -> String {
+    let mut out = String::new();
+    let Some(policy) = &report.policy else {
+        out.push_str(&format!(
+            "No DevOpsPolicy or ClusterDevOpsPolicy applies to namespace '{}'\n",
+            report.namespace
+        ));
+        return out;
+    };
+
+    out.push_str(&format!(
+        "Effective policy for namespace '{}'\n",
+        report.namespace
+    ));
+    if let Some(profile) = &report.profile {
+        out.push_str(&format!("Profile: {profile}\n"));
+    }
+    out.push('\n');
+    out.push_str(&format!("{:<24} {:<20} SOURCE\n", "FIELD", "VALUE"));
+    out.push_str(&format!("{}\n", "-".repeat(70)));
+    if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(&policy.spec) {
+        for (field, value) in &fields {
+            let source = policy.sources.get(field).map_or("-", String::as_str);
+            out.push_str(&format!(
+                "{field:<24} {:<20} {source}\n",
+                display_value(value)
+            ));
+        }
+    }
+
+    if !report.workload_overrides.is_empty() {
+        out.push_str("\nWorkload overrides:\n");
+        for (workload, overrides) in &report.workload_overrides {
+            let spec = serde_json::to_value(&overrides.spec).unwrap_or_default();
+            let set: Vec<String> = overrides
+                .fields
+                .iter()
+                .map(|f| format!("{f}={}", display_value(&spec[f.as_str()])))
+                .collect();
+            out.push_str(&format!("  {workload:<30} {}\n", set.join(", ")));
+            for invalid in &overrides.invalid {
+                out.push_str(&format!("  {:<30} ignored: {invalid}\n", ""));
             }
         }
     }
+    out
+}
 
+/// Show the merged policy in effect for a namespace and where each field comes from.
+pub async fn effective(namespace: &str, format: OutputFormat) -> Result<()> {
+    let client = client::connect().await?;
+
+    let cluster_api: kube::Api<ClusterDevOpsPolicy> = kube::Api::all(client.clone());
+    let cluster_policies = match cluster_api.list(&Default::default()).await {
+        Ok(list) => list.items,
+        // ClusterDevOpsPolicy CRD not installed
+        Err(kube::Error::Api(err)) if err.code == 404 => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let policy_api: kube::Api<DevOpsPolicy> = kube::Api::namespaced(client.clone(), namespace);
+    let policies = policy_api.list(&Default::default()).await?;
+    let pod_api: kube::Api<Pod> = kube::Api::namespaced(client.clone(), namespace);
+    let pods = pod_api.list(&Default::default()).await?;
+    let namespace_api: kube::Api<Namespace> = kube::Api::all(client);
+    let labels = namespace_api
+        .get_opt(namespace)
+        .await?
+        .and_then(|ns| ns.metadata.labels);
+
+    let report = build_effective_report(
+        namespace,
+        &cluster_policies,
+        &policies.items,
+        &pods.items,
+        labels.as_ref(),
+    );
+    if format.is_structured() {
+        return output::print_structured(&report, format);
+    }
+    print!("{}", format_effective_table(&report));
     Ok(())
 }
 
-fn diff_json(prefix: &str, remote: &serde_json::Value, local: &serde_json::Value, indent: &str) {
+pub(crate) fn diff_json(
+    prefix: &str,
+    remote: &serde_json::Value,
+    local: &serde_json::Value,
+    indent: &str,
+) {
     match (remote, local) {
         (serde_json::Value::Object(r), serde_json::Value::Object(l)) => {
             for key in r
@@ -264,6 +714,7 @@ fn diff_json(prefix: &str, remote: &serde_json::Value, local: &serde_json::Value
 #[cfg(test)]
 mod tests {
     use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::{FieldsV1, ManagedFieldsEntry};
 
     #[test]
     fn test_bundle_apply_output_valid_yaml() {
@@ -350,6 +801,168 @@ spec:
         }
     }
 
+    #[test]
+    fn test_parse_policies_skips_other_kinds() {
+        let content = r#"
+apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: web
+  namespace: prod
+spec:
+  forbidLatestTag: true
+---
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: other
+---
+"#;
+        let policies = parse_policies(content).unwrap();
+        assert_eq!(policies.len(), 1);
+        assert_eq!(policies[0].metadata.name.as_deref(), Some("web"));
+        assert_eq!(policies[0].spec.forbid_latest_tag, Some(true));
+        assert!(parse_policies("kind: DevOpsPolicy\nspec: [").is_err());
+    }
+
+    fn live_policy(ns: &str, name: &str) -> DevOpsPolicy {
+        let mut policy = DevOpsPolicy::new(name, kube_devops::crd::DevOpsPolicySpec::default());
+        let metadata = &mut policy.metadata;
+        metadata.namespace = Some(ns.to_string());
+        metadata.uid = Some("0b1c".to_string());
+        metadata.resource_version = Some("4711".to_string());
+        metadata.generation = Some(3);
+        metadata.finalizers = Some(vec!["devops.stochastic.io/cleanup".to_string()]);
+        metadata.managed_fields = Some(vec![Default::default()]);
+        metadata.labels = Some([("team".to_string(), "web".to_string())].into());
+        metadata.annotations =
+            Some([(LAST_APPLIED_ANNOTATION.to_string(), "{}".to_string())].into());
+        policy.status = Some(Default::default());
+        policy
+    }
+
+    #[test]
+    fn test_export_strips_server_fields() {
+        let yaml = export_yaml(
+            vec![live_policy("prod", "web"), live_policy("dev", "api")],
+            "2026-01-01T00:00:00+00:00",
+        )
+        .unwrap();
+        for field in [
+            "uid",
+            "resourceVersion",
+            "generation",
+            "managedFields",
+            "finalizers",
+            "status",
+            LAST_APPLIED_ANNOTATION,
+        ] {
+            assert!(!yaml.contains(field), "{field} in\n{yaml}");
+        }
+        assert!(yaml.contains("team: web"));
+        assert!(yaml.contains("devops.stochastic.io/exported-from: prod"));
+
+        // Round trip: the export parses back into the same policies.
+        let policies = parse_policies(&yaml).unwrap();
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[1].metadata.namespace.as_deref(), Some("dev"));
+        assert_eq!(policies[1].name_any(), "api");
+    }
+
+    #[test]
+    fn test_parse_policies_lists_and_strips() {
+        let content = r#"
+apiVersion: v1
+kind: List
+items:
+- apiVersion: devops.stochastic.io/v1
+  kind: DevOpsPolicy
+  metadata:
+    name: web
+    namespace: prod
+    resourceVersion: "12"
+    uid: abc
+  spec:
+    forbidLatestTag: true
+  status:
+    healthScore: 90
+- apiVersion: v1
+  kind: ConfigMap
+  metadata:
+    name: other
+---
+# a comment containing --- does not split documents
+apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: api
+spec:
+  requiredTagPattern: "^v---"
+"#;
+        let policies = parse_policies(content).unwrap();
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[0].name_any(), "web");
+        assert_eq!(policies[0].metadata.resource_version, None);
+        assert_eq!(policies[0].metadata.uid, None);
+        assert!(policies[0].status.is_none());
+        assert_eq!(
+            policies[1].spec.required_tag_pattern.as_deref(),
+            Some("^v---")
+        );
+    }
+
+    #[test]
+    fn test_prune_candidates() {
+        let file = [live_policy("prod", "web"), live_policy("dev", "api")];
+        let kept = policies_by_namespace(&file);
+        assert_eq!(kept.len(), 2);
+
+        let existing = [
+            live_policy("prod", "web"),
+            live_policy("prod", "old"),
+            live_policy("prod", "legacy"),
+        ];
+        assert_eq!(prune_candidates(&existing, &kept), ["old", "legacy"]);
+        assert!(prune_candidates(&file[1..], &kept).is_empty());
+    }
+
+    #[test]
+    fn test_format_diff() {
+        let file = serde_json::json!({"maxRestartCount": 5, "forbidLatestTag": true});
+        let live = serde_json::json!({"maxRestartCount": 3, "requireResources": true});
+        let managed = [ManagedFieldsEntry {
+            manager: Some(policy_diff::CLI_FIELD_MANAGER.to_string()),
+            operation: Some("Apply".to_string()),
+            fields_v1: Some(FieldsV1(
+                serde_json::json!({"f:spec": {"f:requireResources": {}}}),
+            )),
+            ..Default::default()
+        }];
+        let diffs = [
+            PolicyDiff::compare("prod", "web", &file, Some((&live, &managed))),
+            PolicyDiff::compare("prod", "api", &file, Some((&file, &[]))),
+            PolicyDiff::compare("dev", "web", &file, None),
+            PolicyDiff::failed(
+                "dev",
+                "api",
+                "error fetching from cluster: forbidden".into(),
+            ),
+        ];
+        assert_eq!(
+            format_diff(&diffs, false),
+            "[~] prod/web: 3 change(s)\n\
+             \x20 + spec.forbidLatestTag: true\n\
+             \x20 ~ spec.maxRestartCount: 3 → 5\n\
+             \x20 - spec.requireResources: true\n\
+             [=] prod/api: no changes\n\
+             [+] dev/web: new (not in cluster)\n\
+             [!] dev/api: error fetching from cluster: forbidden\n"
+        );
+        assert!(
+            format_diff(&diffs[..1], true).contains("\x1b[32m+ spec.forbidLatestTag: true\x1b[0m")
+        );
+    }
+
     #[test]
     fn test_diff_json_detects_changed_field() {
         let remote = serde_json::json!({"forbidLatestTag": true, "maxRestartCount": 3});
@@ -378,4 +991,175 @@ spec:
         let local = serde_json::json!({"forbidLatestTag": true});
         diff_json("spec", &remote, &local, "  ");
     }
+
+    #[test]
+    fn test_bundle_create_output() {
+        use k8s_openapi::api::core::v1::{Container, PodSpec};
+        let pods = vec![Pod {
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    image: Some("app:latest".to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let baseline = bundles::baseline_from_pods(&pods, chrono::Utc::now());
+        let header = baseline_header("prod", &baseline);
+        assert!(header.starts_with("# Synthesized from 1 pod(s) in namespace 'prod'.\n"));
+        assert!(header.contains("#   forbidLatestTag        off    1 pod(s) would violate it\n"));
+        assert!(header.contains("#   forbidCrashLoop        true   met by every pod\n"));
+
+        // The generated policy passes `policy validate`.
+        let yaml =
+            header + &policy_yaml("from-cluster", "prod", "baseline", &baseline.spec).unwrap();
+        let results = policy_validation::validate_manifests(&yaml).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].issues.is_empty(), "{:?}", results[0].issues);
+    }
+
+    // ── policy validate ──
+
+    #[test]
+    fn test_format_validation() {
+        let content = r#"
+apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: web
+  namespace: prod
+spec:
+  enforcementMode: enforce
+  requireLivenessProbe: true
+---
+apiVersion: devops.stochastic.io/v1
+kind: ClusterDevOpsPolicy
+metadata:
+  name: all
+spec:
+  quarantine:
+    namespaces: [prod]
+"#;
+        let results = policy_validation::validate_manifests(content).unwrap();
+        let out = format_validation("policy.yaml", &results);
+        assert!(
+            out.contains("[FAIL] policy.yaml: DevOpsPolicy prod/web\n"),
+            "{out}"
+        );
+        assert!(out.contains("    error    spec.defaultProbe: defaultProbe is required"));
+        assert!(out.contains("[OK] policy.yaml: ClusterDevOpsPolicy all\n"));
+        assert!(out.contains("    warning  spec.quarantine: has no effect in audit mode"));
+        assert!(out.ends_with("2 policy manifest(s): 1 error(s), 1 warning(s)\n"));
+    }
+
+    // ── policy effective ──
+
+    fn cluster(name: &str, spec: kube_devops::crd::DevOpsPolicySpec) -> ClusterDevOpsPolicy {
+        ClusterDevOpsPolicy::new(
+            name,
+            kube_devops::crd::ClusterDevOpsPolicySpec {
+                excluded_namespaces: None,
+                policy: spec,
+            },
+        )
+    }
+
+    fn pod_with_annotations(name: &str, annotations: &[(&str, &str)]) -> Pod {
+        let mut pod = Pod::default();
+        pod.metadata.name = Some(name.to_string());
+        pod.metadata.namespace = Some("web".to_string());
+        pod.metadata.annotations = Some(
+            annotations
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        );
+        pod
+    }
+
+    #[test]
+    fn test_effective_report_layers_and_overrides() {
+        use kube_devops::crd::DevOpsPolicySpec;
+
+        let clusters = vec![cluster(
+            "baseline",
+            DevOpsPolicySpec {
+                forbid_latest_tag: Some(true),
+                max_restart_count: Some(3),
+                ..Default::default()
+            },
+        )];
+        let policies = vec![kube_devops::api::new_policy(
+            "web",
+            "team",
+            DevOpsPolicySpec {
+                max_restart_count: Some(5),
+                ..Default::default()
+            },
+        )];
+        let pods = vec![
+            pod_with_annotations(
+                "batch-0",
+                &[
+                    ("devops.stochastic.io/override.maxRestartCount", "20"),
+                    ("devops.stochastic.io/override.enforcementMode", "audit"),
+                ],
+            ),
+            pod_with_annotations("plain", &[]),
+        ];
+
+        let report = build_effective_report("web", &clusters, &policies, &pods, None);
+        let policy = report.policy.as_ref().unwrap();
+        assert_eq!(policy.spec.forbid_latest_tag, Some(true));
+        assert_eq!(policy.spec.max_restart_count, Some(5));
+        assert_eq!(report.workload_overrides.len(), 1);
+
+        let table = format_effective_table(&report);
+        assert!(table.contains("ClusterDevOpsPolicy/baseline"));
+        assert!(table.contains("DevOpsPolicy/team"));
+        assert!(table.contains("Pod/batch-0"));
+        assert!(table.contains("maxRestartCount=20"));
+        assert!(table.contains("ignored: enforcementMode"));
+    }
+
+    #[test]
+    fn test_effective_report_shows_profile() {
+        use kube_devops::crd::{DevOpsPolicySpec, PolicyProfile};
+
+        let policies = vec![kube_devops::api::new_policy(
+            "web",
+            "team",
+            DevOpsPolicySpec {
+                profiles: Some(BTreeMap::from([(
+                    "prod".to_string(),
+                    PolicyProfile {
+                        forbid_latest_tag: Some(true),
+                        ..Default::default()
+                    },
+                )])),
+                ..Default::default()
+            },
+        )];
+        let labels = BTreeMap::from([(
+            kube_devops::api::DEFAULT_PROFILE_LABEL.to_string(),
+            "prod".to_string(),
+        )]);
+
+        let report = build_effective_report("web", &[], &policies, &[], Some(&labels));
+        assert_eq!(report.profile.as_deref(), Some("prod"));
+        let table = format_effective_table(&report);
+        assert!(table.contains("Profile: prod"));
+        assert!(table.contains("Profile/prod"));
+    }
+
+    #[test]
+    fn test_effective_report_no_policy() {
+        let report = build_effective_report("web", &[], &[], &[], None);
+        assert!(report.policy.is_none());
+        assert!(format_effective_table(&report).starts_with("No DevOpsPolicy"));
+        let json = output::render(&report, OutputFormat::Json).unwrap();
+        assert!(json.contains("\"policy\": null"));
+    }
 }
@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Namespace;
+use kube::core::PartialObjectMeta;
+use kube::{Api, Client, ResourceExt};
+use kube_runtime::WatchStreamExt;
+use kube_runtime::reflector::{self, ObjectRef, Store};
+use kube_runtime::watcher::{Config, metadata_watcher, watcher};
+use tracing::info;
+
+use kube_devops::crd::{ClusterDevOpsPolicy, DevOpsPolicy, DevOpsPolicySpec};
+use kube_devops::policy_resolution;
+
+/* ============================= POLICY CACHE ============================= */
+
+/// Reflector caches of DevOpsPolicies (all namespaces, or one) and, optionally,
+/// ClusterDevOpsPolicies, used to resolve the effective policy for a
+/// namespace without an API call.
+///
+/// The watchers retry with backoff, so a throttled or briefly unavailable
+/// API server leaves the last known policies in place.
+#[derive(Clone)]
+pub(crate) struct PolicyCache {
+    policies: Store<DevOpsPolicy>,
+    cluster_policies: Option<Store<ClusterDevOpsPolicy>>,
+    synced: Arc<AtomicBool>,
+}
+
+impl PolicyCache {
+    /// Start the reflectors, watching DevOpsPolicies in `namespace` only when
+    /// given. Without `cluster_policies`, ClusterDevOpsPolicies are neither
+    /// watched nor merged.
+    pub(crate) fn spawn(client: &Client, namespace: Option<&str>, cluster_policies: bool) -> Self {
+        let (policies, policy_writer) = reflector::store();
+        let api: Api<DevOpsPolicy> = match namespace {
+            Some(namespace) => Api::namespaced(client.clone(), namespace),
+            None => Api::all(client.clone()),
+        };
+        let policy_stream = reflector::reflector(
+            policy_writer,
+            watcher(api, Config::default()).default_backoff(),
+        );
+        tokio::spawn(policy_stream.for_each(|_| futures::future::ready(())));
+
+        let cluster_policies = cluster_policies.then(|| {
+            let (store, writer) = reflector::store();
+            let stream = reflector::reflector(
+                writer,
+                watcher(
+                    Api::<ClusterDevOpsPolicy>::all(client.clone()),
+                    Config::default(),
+                )
+                .default_backoff(),
+            );
+            tokio::spawn(stream.for_each(|_| futures::future::ready(())));
+            store
+        });
+
+        let synced = Arc::new(AtomicBool::new(false));
+        let cache = Self {
+            policies,
+            cluster_policies,
+            synced: synced.clone(),
+        };
+        let waiting = cache.clone();
+        tokio::spawn(async move {
+            if waiting.wait_until_synced().await {
+                synced.store(true, Ordering::Relaxed);
+                info!(
+                    policies = waiting.policies.state().len(),
+                    "policy_cache_synced"
+                );
+            }
+        });
+        cache
+    }
+
+    /// Wait for the initial list of every watched kind. Returns `false` if
+    /// a reflector stopped before syncing.
+    pub(crate) async fn wait_until_synced(&self) -> bool {
+        if self.policies.wait_until_ready().await.is_err() {
+            return false;
+        }
+        match &self.cluster_policies {
+            Some(store) => store.wait_until_ready().await.is_ok(),
+            None => true,
+        }
+    }
+
+    /// Whether the initial list has completed.
+    pub(crate) fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Relaxed)
+    }
+
+    /// Number of cached DevOpsPolicies.
+    pub(crate) fn policy_count(&self) -> usize {
+        self.policies.state().len()
+    }
+
+    /// Cluster policies merged with the namespace's first DevOpsPolicy.
+    pub(crate) fn effective_policy(&self, namespace: &str) -> Option<DevOpsPolicySpec> {
+        let namespaced = self
+            .policies
+            .state()
+            .into_iter()
+            .filter(|p| p.namespace().as_deref() == Some(namespace))
+            .min_by_key(|p| p.name_any());
+        let cluster: Vec<ClusterDevOpsPolicy> = self
+            .cluster_policies
+            .iter()
+            .flat_map(|store| store.state())
+            .map(|p| p.as_ref().clone())
+            .collect();
+        policy_resolution::resolve_effective_policy(
+            namespace,
+            &cluster,
+            namespaced.as_ref().map(|p| &p.spec),
+        )
+    }
+}
+
+/* ============================= NAMESPACE CACHE ============================= */
+
+/// Reflector cache of namespace metadata, used to select policy profiles by
+/// namespace label without an API call per admission request.
+#[derive(Clone)]
+pub(crate) struct NamespaceCache {
+    namespaces: Store<PartialObjectMeta<Namespace>>,
+    synced: Arc<AtomicBool>,
+}
+
+impl NamespaceCache {
+    /// Start a metadata-only watch of all namespaces.
+    pub(crate) fn spawn(client: &Client) -> Self {
+        let (namespaces, writer) = reflector::store();
+        let stream = reflector::reflector(
+            writer,
+            metadata_watcher(Api::<Namespace>::all(client.clone()), Config::default())
+                .default_backoff()
+                .modify(|ns| {
+                    ns.managed_fields_mut().clear();
+                }),
+        );
+        tokio::spawn(stream.for_each(|_| futures::future::ready(())));
+
+        let synced = Arc::new(AtomicBool::new(false));
+        let waiting = namespaces.clone();
+        let flag = synced.clone();
+        tokio::spawn(async move {
+            if waiting.wait_until_ready().await.is_ok() {
+                flag.store(true, Ordering::Relaxed);
+                info!(namespaces = waiting.state().len(), "namespace_cache_synced");
+            }
+        });
+        Self { namespaces, synced }
+    }
+
+    /// Whether the initial list has completed.
+    pub(crate) fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::Relaxed)
+    }
+
+    /// Labels of `namespace`, or `None` when it is unknown or unlabeled.
+    pub(crate) fn labels(&self, namespace: &str) -> Option<BTreeMap<String, String>> {
+        self.namespaces
+            .get(&ObjectRef::new(namespace))
+            .and_then(|ns| ns.metadata.labels.clone())
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube_devops::crd::ClusterDevOpsPolicySpec;
+    use kube_runtime::watcher::Event;
+
+    fn policy(namespace: &str, name: &str, max_restarts: i32) -> DevOpsPolicy {
+        kube_devops::api::new_policy(
+            namespace,
+            name,
+            DevOpsPolicySpec {
+                max_restart_count: Some(max_restarts),
+                ..Default::default()
+            },
+        )
+    }
+
+    fn cache(
+        policies: Vec<DevOpsPolicy>,
+        cluster: Option<Vec<ClusterDevOpsPolicy>>,
+    ) -> PolicyCache {
+        let (store, mut writer) = reflector::store();
+        writer.apply_watcher_event(&Event::Restarted(policies));
+        let cluster_policies = cluster.map(|items| {
+            let (store, mut writer) = reflector::store();
+            writer.apply_watcher_event(&Event::Restarted(items));
+            store
+        });
+        PolicyCache {
+            policies: store,
+            cluster_policies,
+            synced: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    fn namespace(name: &str, labels: &[(&str, &str)]) -> PartialObjectMeta<Namespace> {
+        let mut ns = PartialObjectMeta::<Namespace>::default();
+        ns.metadata.name = Some(name.to_string());
+        if !labels.is_empty() {
+            ns.metadata.labels = Some(
+                labels
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            );
+        }
+        ns
+    }
+
+    #[test]
+    fn test_namespace_cache_labels() {
+        let (namespaces, mut writer) = reflector::store();
+        writer.apply_watcher_event(&Event::Restarted(vec![
+            namespace("payments", &[("devops.stochastic.io/profile", "prod")]),
+            namespace("scratch", &[]),
+        ]));
+        let cache = NamespaceCache {
+            namespaces,
+            synced: Arc::new(AtomicBool::new(true)),
+        };
+        assert_eq!(
+            cache.labels("payments").unwrap()["devops.stochastic.io/profile"],
+            "prod"
+        );
+        assert!(cache.labels("scratch").is_none());
+        assert!(cache.labels("missing").is_none());
+    }
+
+    #[test]
+    fn test_effective_policy_picks_first_by_name() {
+        let cache = cache(
+            vec![
+                policy("prod", "z-relaxed", 10),
+                policy("prod", "a-strict", 1),
+                policy("dev", "dev", 50),
+            ],
+            None,
+        );
+        assert_eq!(cache.policy_count(), 3);
+        let spec = cache.effective_policy("prod").unwrap();
+        assert_eq!(spec.max_restart_count, Some(1));
+        assert!(cache.effective_policy("staging").is_none());
+    }
+
+    #[test]
+    fn test_effective_policy_merges_cluster_policies() {
+        let cluster = kube_devops::api::new_cluster_policy(
+            "org",
+            ClusterDevOpsPolicySpec {
+                policy: DevOpsPolicySpec {
+                    forbid_latest_tag: Some(true),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        let cache = cache(vec![policy("prod", "p", 5)], Some(vec![cluster]));
+        let spec = cache.effective_policy("prod").unwrap();
+        assert_eq!(spec.forbid_latest_tag, Some(true));
+        assert_eq!(spec.max_restart_count, Some(5));
+        assert_eq!(
+            cache.effective_policy("staging").unwrap().forbid_latest_tag,
+            Some(true)
+        );
+    }
+}
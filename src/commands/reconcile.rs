@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
 use std::net::SocketAddr;
 use std::sync::{Arc, LazyLock};
 use std::time::Duration;
@@ -7,26 +9,100 @@ use axum::Router;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::get;
+use chrono::{DateTime, Utc};
 use futures::StreamExt;
-use k8s_openapi::api::core::v1::Pod;
-use kube::api::{Api, Patch, PatchParams};
-use kube::runtime::controller::{Action, Controller};
-use kube::{Client, ResourceExt};
-use prometheus::{Encoder, Histogram, IntCounter, IntGaugeVec, Registry, TextEncoder};
+use k8s_openapi::NamespaceResourceScope;
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Namespace, ObjectReference, Pod};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+use kube::api::{Api, ListParams, ObjectList, Patch, PatchParams};
+use kube::runtime::controller::{self, Action, Controller};
+use kube::runtime::events::{Event, EventType, Recorder, Reporter};
+use kube::runtime::reflector::{ObjectRef, Store};
+use kube::runtime::watcher;
+use kube::{Client, CustomResourceExt, Resource, ResourceExt};
+use prometheus::{
+    Encoder, Histogram, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry, TextEncoder,
+};
+use serde::de::DeserializeOwned;
 use tokio::signal;
 use tokio::sync::{Mutex, broadcast};
-use tracing::{info, warn};
+use tracing::{Instrument, field, info, info_span, warn};
 
+use kube_devops::api::FINALIZER;
+use kube_devops::clock::{self, Clock, HashIdGenerator, IdGenerator, SystemClock, Timezone};
 use kube_devops::crd::{
-    AuditViolation, DevOpsPolicy, DevOpsPolicyStatus, PolicyAuditResult, PolicyAuditResultSpec,
+    AuditViolation, ClusterDevOpsPolicy, DevOpsPolicy, DevOpsPolicySpec, DevOpsPolicyStatus,
+    EnforcementMode, NotificationConfig, PlannedRemediation, PolicyAuditResult,
+    PolicyAuditResultSpec, ScoreTrend,
 };
-use kube_devops::enforcement;
-use kube_devops::governance;
+use kube_devops::enforcement::{self, EvictionOutcome};
+use kube_devops::governance::{self, WorkloadEvaluation};
+use kube_devops::notifications::{self, Notification, NotificationCooldown};
+use kube_devops::policy_resolution;
+use kube_devops::quarantine::{self, QuarantineOutcome};
+use kube_devops::rbac;
+
+use crate::commands::audit_sink::{AuditSink, AuditSinkConfig};
+use crate::commands::client;
+use crate::commands::notifier::Notifier;
+use crate::commands::pod_cache::PodCache;
+use crate::otlp;
 
 /* ============================= CONFIG ============================= */
 
-const FINALIZER: &str = "devops.stochastic.io/cleanup";
-const REQUEUE_INTERVAL: Duration = Duration::from_secs(30);
+/// Runtime settings for `kube-devops reconcile`, set by flags or environment.
+#[derive(Debug, Clone)]
+pub struct ReconcileSettings {
+    /// Delay before each policy is re-evaluated.
+    pub requeue_interval: Duration,
+    /// Listen address of the metrics and health server.
+    pub metrics_addr: SocketAddr,
+    /// PolicyAuditResults kept per policy unless it sets `auditRetention.maxCount`.
+    pub audit_retention: usize,
+    /// Delay between audit result garbage collection passes.
+    pub audit_gc_interval: Duration,
+    /// Objects per page when listing pods, Jobs, and CronJobs.
+    pub list_page_size: u32,
+    /// Quiet period after a pod or policy event before the policy is
+    /// reconciled, so a rollout triggers one evaluation rather than dozens.
+    pub pod_event_debounce: Duration,
+    /// External storage every audit result is also exported to.
+    pub audit_sink: Option<AuditSinkConfig>,
+}
+
+impl Default for ReconcileSettings {
+    fn default() -> Self {
+        Self {
+            requeue_interval: Duration::from_secs(30),
+            metrics_addr: SocketAddr::from(([0, 0, 0, 0], 9090)),
+            audit_retention: 10,
+            audit_gc_interval: Duration::from_secs(300),
+            list_page_size: 500,
+            pod_event_debounce: Duration::from_secs(5),
+            audit_sink: None,
+        }
+    }
+}
+
+/// First delay before re-checking a CRD that is not installed.
+const CRD_RETRY_INITIAL: Duration = Duration::from_secs(5);
+
+/// Upper bound for the CRD re-check backoff.
+const CRD_RETRY_MAX: Duration = Duration::from_secs(300);
+
+/// How long a policy reconcile waits for the ClusterDevOpsPolicy store's
+/// initial list before evaluating without cluster policies.
+const CLUSTER_POLICY_SYNC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// `/readyz` fails once no reconcile has succeeded for this many requeue intervals.
+const READY_STALE_INTERVALS: u32 = 3;
+
+/// Seconds between evaluations of an unedited policy without
+/// `reevaluationInterval`.
+const DEFAULT_REEVALUATION_INTERVAL: u64 = 300;
 
 /* ============================= PROMETHEUS ============================= */
 
@@ -86,6 +162,36 @@ static POLICY_HEALTH: LazyLock<IntGaugeVec> = LazyLock::new(|| {
     g
 });
 
+static HEALTH_THRESHOLD: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    let g = IntGaugeVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_health_threshold",
+            "Lowest health score of each classification per namespace and policy",
+        ),
+        &["namespace", "policy", "classification"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(g.clone()))
+        .expect("metric not yet registered");
+    g
+});
+
+static HEALTH_SCORE_DELTA: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    let g = IntGaugeVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_health_score_delta",
+            "Change in health score since the previous evaluation",
+        ),
+        &["namespace", "policy"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(g.clone()))
+        .expect("metric not yet registered");
+    g
+});
+
 static REMEDIATIONS_APPLIED: LazyLock<IntCounter> = LazyLock::new(|| {
     let c = IntCounter::new(
         "devopspolicy_remediations_applied_total",
@@ -114,7 +220,7 @@ static ENFORCEMENT_MODE: LazyLock<IntGaugeVec> = LazyLock::new(|| {
     let g = IntGaugeVec::new(
         prometheus::Opts::new(
             "devopspolicy_enforcement_mode",
-            "Enforcement mode per policy (0=audit, 1=enforce)",
+            "Enforcement mode per policy (0=audit, 1=enforce, 2=dryRun)",
         ),
         &["namespace", "policy"],
     )
@@ -137,6 +243,18 @@ static PODS_SCANNED: LazyLock<IntCounter> = LazyLock::new(|| {
     c
 });
 
+static LAST_SUCCESSFUL_RECONCILE: LazyLock<IntGauge> = LazyLock::new(|| {
+    let g = IntGauge::new(
+        "devopspolicy_last_successful_reconcile_timestamp_seconds",
+        "Unix time of the last reconcile that updated (or confirmed) a policy status",
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(g.clone()))
+        .expect("metric not yet registered");
+    g
+});
+
 static RECONCILE_DURATION: LazyLock<Histogram> = LazyLock::new(|| {
     let h = Histogram::with_opts(prometheus::HistogramOpts::new(
         "devopspolicy_reconcile_duration_seconds",
@@ -149,6 +267,21 @@ static RECONCILE_DURATION: LazyLock<Histogram> = LazyLock::new(|| {
     h
 });
 
+static VIOLATIONS_BY_TYPE: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    let g = IntGaugeVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_violations_by_type",
+            "Policy violations grouped by violation type",
+        ),
+        &["violation_type", "namespace", "policy"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(g.clone()))
+        .expect("metric not yet registered");
+    g
+});
+
 static VIOLATIONS_BY_SEVERITY: LazyLock<IntGaugeVec> = LazyLock::new(|| {
     let g = IntGaugeVec::new(
         prometheus::Opts::new(
@@ -176,24 +309,313 @@ static AUDIT_RESULTS_TOTAL: LazyLock<IntCounter> = LazyLock::new(|| {
     c
 });
 
+static AUDIT_RESULTS_PRUNED: LazyLock<IntCounter> = LazyLock::new(|| {
+    let c = IntCounter::new(
+        "devopspolicy_audit_results_pruned_total",
+        "Total audit results deleted by retention garbage collection",
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static STATUS_PATCHES_SKIPPED: LazyLock<IntCounter> = LazyLock::new(|| {
+    let c = IntCounter::new(
+        "devopspolicy_status_patches_skipped_total",
+        "Status patches skipped because the evaluation did not change the status",
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static AUDIT_EXPORT_FAILURES: LazyLock<IntCounter> = LazyLock::new(|| {
+    let c = IntCounter::new(
+        "devopspolicy_audit_export_failures_total",
+        "Audit results that could not be exported to the audit sink after retries",
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static REMEDIATIONS_DEFERRED: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    let g = IntGaugeVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_remediations_deferred",
+            "Remediations deferred by maxRemediationsPerCycle or remediationCooldownSeconds in the last cycle",
+        ),
+        &["namespace", "policy"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(g.clone()))
+        .expect("metric not yet registered");
+    g
+});
+
+static QUARANTINES_TOTAL: LazyLock<IntCounter> = LazyLock::new(|| {
+    let c = IntCounter::new(
+        "devopspolicy_quarantines_total",
+        "Workloads scaled to zero by quarantine",
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static POD_EVICTIONS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let c = IntCounterVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_pod_evictions_total",
+            "Crash-looping pod evictions by result",
+        ),
+        &["result"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static POD_LISTINGS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let c = IntCounterVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_pod_listings_total",
+            "Pod sets read for reconciles, by source (cache or api)",
+        ),
+        &["source"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static NOTIFICATIONS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let c = IntCounterVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_notifications_total",
+            "Policy notifications by channel type and result",
+        ),
+        &["channel", "result"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static CRD_AVAILABLE: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    let g = IntGaugeVec::new(
+        prometheus::Opts::new(
+            "devopspolicy_crd_available",
+            "Whether a watched CRD is installed (1) or missing (0)",
+        ),
+        &["crd"],
+    )
+    .expect("metric definition is valid");
+    REGISTRY
+        .register(Box::new(g.clone()))
+        .expect("metric not yet registered");
+    g
+});
+
 /* ============================= STATE ============================= */
 
 pub(crate) struct ReconcileState {
-    pub(crate) ready: bool,
+    /// Last successful status patch (or confirmation that status is current).
+    pub(crate) last_success: Option<DateTime<Utc>>,
+    /// Requeue interval the readiness window is measured in.
+    pub(crate) requeue_interval: Duration,
+}
+
+impl ReconcileState {
+    /// Ready while a reconcile has succeeded within the last
+    /// `READY_STALE_INTERVALS` requeue intervals.
+    pub(crate) fn is_ready(&self, now: DateTime<Utc>) -> bool {
+        let window = self.requeue_interval * READY_STALE_INTERVALS;
+        self.last_success.is_some_and(|at| {
+            now.signed_duration_since(at)
+                .to_std()
+                .map_or(true, |age| age <= window)
+        })
+    }
 }
 
 /* ============================= CONTEXT ============================= */
 
 struct ReconcileContext {
     client: Client,
+    /// Time source for status and audit timestamps.
+    clock: Arc<dyn Clock>,
+    /// Timezone for console timestamps unless a policy sets `timezone`.
+    timezone: Timezone,
+    /// Suffix generator for audit result names.
+    ids: Arc<dyn IdGenerator>,
+    /// Evaluate and report only; never write to the cluster.
+    read_only: bool,
+    /// Requeue interval, metrics address, and audit retention.
+    settings: ReconcileSettings,
+    /// Long-term storage for audit results, when configured.
+    audit_sink: Option<Arc<AuditSink>>,
+    /// Delivers policy notifications.
+    notifier: Notifier,
+    /// Notification events sent recently, per policy.
+    notification_cooldown: std::sync::Mutex<NotificationCooldown>,
+    /// Shared pod reflector; reconciles list from the API until it syncs.
+    pods: PodCache,
+    /// Store of the ClusterDevOpsPolicy controller, set once that controller
+    /// starts (after its CRD is served).
+    cluster_policies: std::sync::OnceLock<Store<ClusterDevOpsPolicy>>,
+    /// Optional features granted by RBAC at startup.
+    features: Features,
+    /// Last eviction per workload key, for the eviction cooldown.
+    evictions: std::sync::Mutex<HashMap<String, DateTime<Utc>>>,
+    /// Last cycle that patched workloads, per policy, for the remediation cooldown.
+    remediation_rounds: std::sync::Mutex<HashMap<String, DateTime<Utc>>>,
+    /// Last evaluation per policy, for `reevaluationInterval`. Empty after a
+    /// restart, so every policy is evaluated once on startup.
+    evaluations: std::sync::Mutex<HashMap<String, DateTime<Utc>>>,
+    /// Policies whose namespace had pod events since their last evaluation.
+    pod_changes: std::sync::Mutex<HashSet<String>>,
+    /// Readiness state shared with the `/readyz` handler.
+    state: Arc<Mutex<ReconcileState>>,
+}
+
+impl ReconcileContext {
+    /// Whether the policy under `policy_key` is due for another evaluation.
+    fn reevaluation_due(&self, policy_key: &str, spec: &DevOpsPolicySpec) -> bool {
+        let last = self
+            .evaluations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(policy_key)
+            .copied();
+        reevaluation_due(spec, last, self.clock.now())
+    }
+
+    /// Flag the policies a pod event in `namespace` affects and return them
+    /// for the controller to reconcile.
+    fn pod_changed(
+        &self,
+        namespace: &str,
+        policies: &[Arc<DevOpsPolicy>],
+    ) -> Vec<ObjectRef<DevOpsPolicy>> {
+        let affected = policies_in_namespace(namespace, policies);
+        let mut changes = self.pod_changes.lock().unwrap_or_else(|e| e.into_inner());
+        for policy in &affected {
+            changes.insert(format!("{namespace}/{}", policy.name));
+        }
+        affected
+    }
+
+    /// Whether pods changed since the policy under `policy_key` was last
+    /// evaluated, clearing the flag.
+    fn take_pod_change(&self, policy_key: &str) -> bool {
+        self.pod_changes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(policy_key)
+    }
+
+    fn record_evaluation(&self, policy_key: &str) {
+        self.evaluations
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(policy_key.to_string(), self.clock.now());
+    }
+}
+
+/// The DevOpsPolicies that evaluate pods in `namespace`.
+fn policies_in_namespace(
+    namespace: &str,
+    policies: &[Arc<DevOpsPolicy>],
+) -> Vec<ObjectRef<DevOpsPolicy>> {
+    policies
+        .iter()
+        .filter(|p| p.metadata.namespace.as_deref() == Some(namespace))
+        .map(|p| ObjectRef::from_obj(p.as_ref()))
+        .collect()
+}
+
+/// Whether `reevaluationInterval` has passed since the `last` evaluation;
+/// always true when this process has not evaluated the policy yet.
+fn reevaluation_due(
+    spec: &DevOpsPolicySpec,
+    last: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    let Some(last) = last else {
+        return true;
+    };
+    let interval = spec
+        .reevaluation_interval
+        .unwrap_or(DEFAULT_REEVALUATION_INTERVAL);
+    // Intervals beyond what chrono can represent never come due
+    let interval = i64::try_from(interval)
+        .ok()
+        .and_then(chrono::Duration::try_seconds)
+        .unwrap_or(chrono::Duration::MAX);
+    now.signed_duration_since(last) >= interval
+}
+
+/// Record a successful reconcile for readiness and
+/// `devopspolicy_last_successful_reconcile_timestamp_seconds`.
+async fn mark_reconciled(ctx: &ReconcileContext) {
+    let now = ctx.clock.now();
+    LAST_SUCCESSFUL_RECONCILE.set(now.timestamp());
+    ctx.state.lock().await.last_success = Some(now);
+}
+
+/// Optional operator features; each is disabled when its RBAC permissions
+/// are missing at startup instead of failing every reconcile.
+#[derive(Debug, Clone, Copy)]
+struct Features {
+    enforcement: bool,
+    audit_results: bool,
+    batch_workloads: bool,
+    replicated_workloads: bool,
+    cluster_policies: bool,
+    eviction: bool,
+    quarantine: bool,
+    profiles: bool,
+    events: bool,
+}
+
+impl Features {
+    fn from_report(report: &rbac::RbacReport) -> Self {
+        Self {
+            enforcement: report.feature_enabled(rbac::FEATURE_ENFORCEMENT),
+            audit_results: report.feature_enabled(rbac::FEATURE_AUDIT_RESULTS),
+            batch_workloads: report.feature_enabled(rbac::FEATURE_BATCH_WORKLOADS),
+            replicated_workloads: report.feature_enabled(rbac::FEATURE_REPLICATED_WORKLOADS),
+            cluster_policies: report.feature_enabled(rbac::FEATURE_CLUSTER_POLICIES),
+            eviction: report.feature_enabled(rbac::FEATURE_EVICTION),
+            quarantine: report.feature_enabled(rbac::FEATURE_QUARANTINE),
+            profiles: report.feature_enabled(rbac::FEATURE_PROFILES),
+            events: report.feature_enabled(rbac::FEATURE_EVENTS),
+        }
+    }
 }
 
 /* ============================= ENTRY ============================= */
 
-pub async fn run() -> Result<()> {
+pub async fn run(read_only: bool, timezone: Timezone, settings: ReconcileSettings) -> Result<()> {
     println!("Starting DevOpsPolicy operator...\n");
 
-    let client = Client::try_default()
+    let client = client::connect()
         .await
         .context("Failed to load kubeconfig")?;
 
@@ -207,11 +629,41 @@ pub async fn run() -> Result<()> {
         }
     }
 
+    let report = rbac::startup_check(&client, &rbac::reconcile_permissions(read_only)).await?;
+
     let policies: Api<DevOpsPolicy> = Api::all(client.clone());
-    let pods: Api<Pod> = Api::all(client.clone());
+
+    let audit_sink = settings
+        .audit_sink
+        .clone()
+        .map(AuditSink::new)
+        .transpose()
+        .context("Invalid audit sink")?
+        .map(Arc::new);
+
+    let reconcile_state = Arc::new(Mutex::new(ReconcileState {
+        last_success: None,
+        requeue_interval: settings.requeue_interval,
+    }));
 
     let ctx = Arc::new(ReconcileContext {
         client: client.clone(),
+        clock: Arc::new(SystemClock),
+        timezone,
+        ids: Arc::new(HashIdGenerator::from_env()),
+        read_only,
+        settings: settings.clone(),
+        audit_sink,
+        notifier: Notifier::new()?,
+        notification_cooldown: Default::default(),
+        pods: PodCache::spawn(&client),
+        cluster_policies: Default::default(),
+        features: Features::from_report(&report),
+        evictions: Default::default(),
+        remediation_rounds: Default::default(),
+        evaluations: Default::default(),
+        pod_changes: Default::default(),
+        state: reconcile_state.clone(),
     });
 
     // Force-init Prometheus metrics so they appear on /metrics
@@ -225,21 +677,56 @@ pub async fn run() -> Result<()> {
     LazyLock::force(&PODS_SCANNED);
     LazyLock::force(&RECONCILE_DURATION);
     LazyLock::force(&VIOLATIONS_BY_SEVERITY);
+    LazyLock::force(&VIOLATIONS_BY_TYPE);
+    LazyLock::force(&HEALTH_THRESHOLD);
+    LazyLock::force(&HEALTH_SCORE_DELTA);
     LazyLock::force(&AUDIT_RESULTS_TOTAL);
-
-    let addr = SocketAddr::from(([0, 0, 0, 0], 9090));
+    LazyLock::force(&AUDIT_RESULTS_PRUNED);
+    LazyLock::force(&STATUS_PATCHES_SKIPPED);
+    LazyLock::force(&AUDIT_EXPORT_FAILURES);
+    LazyLock::force(&CRD_AVAILABLE);
+    LazyLock::force(&POD_EVICTIONS);
+    LazyLock::force(&NOTIFICATIONS);
+    LazyLock::force(&POD_LISTINGS);
+    LazyLock::force(&QUARANTINES_TOTAL);
+    LazyLock::force(&REMEDIATIONS_DEFERRED);
+    LazyLock::force(&LAST_SUCCESSFUL_RECONCILE);
+
+    let addr = settings.metrics_addr;
 
     println!("  CRD watch ................... DevOpsPolicy.devops.stochastic.io/v1");
+    if ctx.features.cluster_policies {
+        println!("  CRD watch ................... ClusterDevOpsPolicy.devops.stochastic.io/v1");
+    }
     println!(
         "  Requeue interval ............ {}s",
-        REQUEUE_INTERVAL.as_secs()
+        settings.requeue_interval.as_secs()
+    );
+    println!(
+        "  Pod event debounce .......... {}s",
+        settings.pod_event_debounce.as_secs()
     );
+    println!(
+        "  Audit retention ............. {} per policy, pruned every {}s",
+        settings.audit_retention,
+        settings.audit_gc_interval.as_secs()
+    );
+    if let Some(sink) = &ctx.audit_sink {
+        println!("  Audit sink .................. {}", sink.describe());
+    }
     println!("  Metrics server .............. http://{addr}");
+    if read_only {
+        println!("  Mode ........................ read-only (no cluster writes)");
+    }
+    if timezone != Timezone::Utc {
+        println!("  Timezone .................... {timezone}");
+    }
     println!();
     println!("  Available endpoints:");
     println!("    GET /healthz .............. Liveness probe (always 200 OK)");
     println!(
-        "    GET /readyz ............... Readiness probe (503 until first reconcile, then 200)"
+        "    GET /readyz ............... Readiness probe (200 while a reconcile succeeded in the last {}s)",
+        (settings.requeue_interval * READY_STALE_INTERVALS).as_secs()
     );
     println!("    GET /metrics .............. Prometheus metrics scrape endpoint");
     println!();
@@ -248,8 +735,6 @@ pub async fn run() -> Result<()> {
 
     info!("operator_controller_started");
 
-    let reconcile_state = Arc::new(Mutex::new(ReconcileState { ready: false }));
-
     let (shutdown_tx, _) = broadcast::channel::<()>(1);
 
     let http_state = reconcile_state.clone();
@@ -258,35 +743,79 @@ pub async fn run() -> Result<()> {
     let http_handle =
         tokio::spawn(async move { start_metrics_server(http_state, http_shutdown, addr).await });
 
-    let controller_state = reconcile_state.clone();
-    let controller = Controller::new(policies, Default::default())
-        .owns(pods, Default::default())
-        .run(reconcile, error_policy, ctx)
-        .for_each(move |result| {
-            let state = controller_state.clone();
-            async move {
-                // Mark ready after first successful reconcile dispatch
-                {
-                    let mut s = state.lock().await;
-                    if !s.ready {
-                        s.ready = true;
+    // ── Audit result garbage collection (replaces per-creation pruning) ──
+    let gc_handle = (!read_only && ctx.features.audit_results).then(|| {
+        let gc_ctx = ctx.clone();
+        let gc_shutdown = shutdown_tx.subscribe();
+        tokio::spawn(async move { run_audit_gc(gc_ctx, gc_shutdown).await })
+    });
+
+    // ── ClusterDevOpsPolicy controller (when RBAC allows) ──
+    let cluster_ctx = ctx.clone();
+    let cluster_controller = async move {
+        if !cluster_ctx.features.cluster_policies {
+            return;
+        }
+        wait_for_crd::<ClusterDevOpsPolicy>(&cluster_ctx.client).await;
+        let cluster_policies: Api<ClusterDevOpsPolicy> = Api::all(cluster_ctx.client.clone());
+        let controller = Controller::new(cluster_policies, Default::default());
+        // DevOpsPolicy reconciles layer cluster policies from this store
+        let _ = cluster_ctx.cluster_policies.set(controller.store());
+        controller
+            .run(reconcile_cluster, error_policy, cluster_ctx)
+            .for_each(|result| async move {
+                match result {
+                    Ok(_) => set_crd_available(ClusterDevOpsPolicy::crd_name(), true),
+                    Err(e) if is_crd_missing(&e) => {
+                        report_crd_missing(ClusterDevOpsPolicy::crd_name());
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "cluster_reconcile_dispatch_error");
+                        eprintln!("[ERROR] Cluster reconcile dispatch: {e}");
                     }
                 }
+            })
+            .await;
+    };
+
+    // ── DevOpsPolicy controller (starts once the CRD is served) ──
+    // Readiness follows successful status patches (see `mark_reconciled`),
+    // not dispatches, so a controller whose reconciles all fail stays unready.
+    // Pods carry no reference to the policies evaluating them, so pod events
+    // are mapped to the policies of the pod's namespace. They come from the
+    // pod cache's watch rather than a second cluster-wide pod watch.
+    let controller = async move {
+        wait_for_crd::<DevOpsPolicy>(&ctx.client).await;
+        let config = controller::Config::default().debounce(ctx.settings.pod_event_debounce);
+        let controller = Controller::new(policies, Default::default()).with_config(config);
+        let store = controller.store();
+        let mapper_ctx = ctx.clone();
+        let pod_triggers = ctx.pods.changed_namespaces().flat_map(move |namespace| {
+            futures::stream::iter(mapper_ctx.pod_changed(&namespace, &store.state()))
+        });
+        controller
+            .reconcile_on(pod_triggers)
+            .run(reconcile, error_policy, ctx)
+            .for_each(|result| async move {
                 match result {
-                    Ok((_obj, _action)) => {}
+                    Ok((_obj, _action)) => set_crd_available(DevOpsPolicy::crd_name(), true),
+                    Err(e) if is_crd_missing(&e) => {
+                        report_crd_missing(DevOpsPolicy::crd_name());
+                    }
                     Err(e) => {
                         warn!(error = %e, "reconcile_dispatch_error");
                         eprintln!("[ERROR] Reconcile dispatch: {e}");
                     }
                 }
-            }
-        });
+            })
+            .await;
+    };
 
     // Use select! so Ctrl+C drops (cancels) the controller stream.
     // The kube Controller has no built-in shutdown hook, so dropping
     // the future is the only way to stop it cleanly.
     tokio::select! {
-        _ = controller => {
+        _ = futures::future::join(controller, cluster_controller) => {
             info!("operator_controller_stream_ended");
             println!("\nController stream ended unexpectedly.");
         }
@@ -301,6 +830,9 @@ pub async fn run() -> Result<()> {
     // Signal the HTTP server to shut down
     let _ = shutdown_tx.send(());
     let _ = http_handle.await?;
+    if let Some(handle) = gc_handle {
+        handle.await?;
+    }
 
     info!("operator_stopped");
     println!("Operator stopped.");
@@ -313,14 +845,41 @@ pub async fn run() -> Result<()> {
 async fn reconcile(
     policy: Arc<DevOpsPolicy>,
     ctx: Arc<ReconcileContext>,
+) -> std::result::Result<Action, kube::Error> {
+    let span = otlp::traced(info_span!(
+        "reconcile",
+        policy = %policy.name_any(),
+        namespace = %policy.namespace().unwrap_or_default(),
+        trace_id = field::Empty,
+    ));
+    reconcile_policy(policy, ctx).instrument(span).await
+}
+
+async fn reconcile_policy(
+    policy: Arc<DevOpsPolicy>,
+    ctx: Arc<ReconcileContext>,
 ) -> std::result::Result<Action, kube::Error> {
     let name = policy.name_any();
     let namespace = policy.namespace().unwrap_or_default();
     let generation = policy.metadata.generation;
 
+    // ── Resolve cluster policies layered under this one ──
+    let cluster_policies = list_cluster_policies(&ctx).await;
+    let cluster_covered = cluster_policies
+        .iter()
+        .any(|p| policy_resolution::cluster_policy_applies(&p.spec, &namespace));
+
     // ── Skip if already reconciled this generation ──
-    let already_reconciled =
-        policy.status.as_ref().and_then(|s| s.observed_generation) == generation;
+    // A cluster policy can change without bumping this policy's generation,
+    // so covered namespaces are always re-evaluated. Pods change without
+    // touching the policy at all, so it is also re-evaluated after pod
+    // events and once `reevaluationInterval` has passed.
+    let policy_key = format!("{namespace}/{name}");
+    let pods_changed = ctx.take_pod_change(&policy_key);
+    let already_reconciled = !cluster_covered
+        && !pods_changed
+        && policy.status.as_ref().and_then(|s| s.observed_generation) == generation
+        && !ctx.reevaluation_due(&policy_key, &policy.spec);
 
     if already_reconciled {
         info!(
@@ -331,11 +890,12 @@ async fn reconcile(
         );
         println!(
             "[{}] {namespace}/{name}: unchanged (generation {:?}), requeue in {}s",
-            chrono::Utc::now().format("%H:%M:%S"),
+            policy_timezone(&policy.spec, ctx.timezone).format(&ctx.clock.now(), "%H:%M:%S"),
             generation,
-            REQUEUE_INTERVAL.as_secs()
+            ctx.settings.requeue_interval.as_secs()
         );
-        return Ok(Action::requeue(REQUEUE_INTERVAL));
+        mark_reconciled(&ctx).await;
+        return Ok(Action::requeue(ctx.settings.requeue_interval));
     }
 
     RECONCILE_TOTAL.inc();
@@ -349,219 +909,191 @@ async fn reconcile(
 
     // ── Handle deletion with finalizer ──
     if policy.metadata.deletion_timestamp.is_some() {
-        return handle_deletion(&policy, &ctx.client).await;
+        return handle_deletion(&policy, &ctx.client, ctx.read_only, ctx.features).await;
     }
 
     // ── Ensure finalizer is present ──
-    if !has_finalizer(&policy) {
+    if !ctx.read_only && !has_finalizer(&policy) {
         add_finalizer(&policy, &ctx.client).await?;
     }
 
-    // ── List pods in the policy's namespace ──
-    let pods_api: Api<Pod> = Api::namespaced(ctx.client.clone(), &namespace);
-    let pod_list = pods_api.list(&Default::default()).await?;
+    let spec = policy_resolution::resolve_effective_policy(
+        &namespace,
+        &cluster_policies,
+        Some(&policy.spec),
+    )
+    .unwrap_or_else(|| policy.spec.clone());
+    let spec = if policy_resolution::has_profiles(&spec) {
+        let labels = namespace_labels(&ctx, &namespace).await;
+        policy_resolution::apply_profile(&spec, labels.as_ref())
+    } else {
+        spec
+    };
 
-    PODS_SCANNED.inc_by(pod_list.items.len() as u64);
+    // ── Pods in the policy's namespace, from the shared cache once synced ──
+    let pods_api: Api<Pod> = Api::namespaced(ctx.client.clone(), &namespace);
+    let pods = if ctx.pods.is_synced() {
+        POD_LISTINGS.with_label_values(&["cache"]).inc();
+        ctx.pods.pods_in(&namespace)
+    } else {
+        POD_LISTINGS.with_label_values(&["api"]).inc();
+        list_paged(&pods_api, ctx.settings.list_page_size, |_| true)
+            .instrument(info_span!("list_pods", namespace = %namespace))
+            .await?
+    };
 
-    // ── Evaluate pods against the policy spec ──
-    let mut aggregate = governance::PodMetrics::default();
-    let mut total_violations: u32 = 0;
+    PODS_SCANNED.inc_by(pods.len() as u64);
 
-    for pod in &pod_list.items {
-        let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
-        if governance::is_system_namespace(ns) {
-            continue;
-        }
+    // ── Jobs and CronJobs are evaluated from their templates ──
+    let (jobs, cron_jobs) = if ctx.features.batch_workloads {
+        let jobs_api: Api<Job> = Api::namespaced(ctx.client.clone(), &namespace);
+        let cron_jobs_api: Api<CronJob> = Api::namespaced(ctx.client.clone(), &namespace);
+        let page_size = ctx.settings.list_page_size;
+        (
+            list_paged(&jobs_api, page_size, |_| true).await?,
+            list_paged(&cron_jobs_api, page_size, |_| true).await?,
+        )
+    } else {
+        Default::default()
+    };
 
-        let contribution = governance::evaluate_pod_with_policy(pod, &policy.spec);
-        governance::add_metrics(&mut aggregate, &contribution);
+    // ── Evaluate workloads against the policy spec ──
+    // Replicas of the same Deployment/StatefulSet/DaemonSet are evaluated
+    // together so a template problem counts once, not once per replica, and
+    // every run of a CronJob counts as the CronJob.
+    let mut workloads =
+        governance::evaluate_workloads_with_jobs(&pods, &jobs, &spec, ctx.clock.now());
+    governance::merge_template_evaluations(
+        &mut workloads,
+        governance::evaluate_batch_workloads(&jobs, &cron_jobs, &spec),
+    );
 
-        let violations = governance::detect_violations_with_policy(pod, &policy.spec);
-        total_violations += violations.len() as u32;
+    // ── Multi-replica workloads need a PodDisruptionBudget and spreading ──
+    if ReplicatedWorkloads::checked_by(&spec) {
+        let replicated = ReplicatedWorkloads::list(&ctx, Some(&namespace), |_| true).await?;
+        governance::merge_workload_violations(&mut workloads, replicated.evaluate(&spec));
     }
 
-    let health_score = governance::calculate_health_score(&aggregate);
-    let classification = governance::classify_health(health_score);
-    let healthy = health_score >= 80;
+    ctx.record_evaluation(&policy_key);
 
-    let message = format!(
-        "{} violations across {} pods — {} ({})",
-        total_violations, aggregate.total_pods, classification, health_score
-    );
+    let history = score_history(&ctx, Some(&namespace), &name, policy.status.as_ref()).await;
+    let summary = EvaluationSummary::from_workloads(&workloads, &spec).with_history(&history);
+    let health_score = summary.health_score;
+    let total_violations = summary.total_violations;
 
     // ── Print human-readable summary ──
-    let now = chrono::Utc::now();
-    let timestamp = now.format("%H:%M:%S");
-
-    let enforce_mode = enforcement::is_enforcement_enabled(&policy.spec);
-    let mode_label = if enforce_mode { "enforce" } else { "audit" };
-
-    println!(
-        "[{timestamp}] {namespace}/{name}: {classification} — score {health_score}/100, \
-         {total_violations} violations, {pods} pods (mode: {mode_label})",
-        pods = aggregate.total_pods
+    let now = ctx.clock.now();
+    let mode = enforcement::effective_mode(&spec);
+    let enforce_mode = mode == EnforcementMode::Enforce;
+    report_evaluation(
+        &format!("{namespace}/{name}"),
+        &summary,
+        &mode,
+        &policy_timezone(&spec, ctx.timezone).format(&now, "%H:%M:%S"),
     );
-
     info!(
         policy = %name,
         namespace = %namespace,
         health_score,
         violations = total_violations,
-        pods = aggregate.total_pods,
-        classification,
-        mode = mode_label,
+        workloads = summary.workloads,
+        pods = summary.total_pods,
+        classification = %summary.classification,
         "reconcile_evaluated"
     );
 
     // ── Update Prometheus metrics ──
-    POLICY_VIOLATIONS
-        .with_label_values(&[&namespace, &name])
-        .set(total_violations as i64);
-    POLICY_HEALTH
-        .with_label_values(&[&namespace, &name])
-        .set(health_score as i64);
-    ENFORCEMENT_MODE
-        .with_label_values(&[&namespace, &name])
-        .set(if enforce_mode { 1 } else { 0 });
-
-    // ── Violations by severity ──
-    {
-        let mut severity_counts = std::collections::HashMap::new();
-        for pod in &pod_list.items {
-            let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
-            if governance::is_system_namespace(ns) {
-                continue;
-            }
-            let details = governance::detect_violations_detailed(pod, &policy.spec);
-            for d in &details {
-                let sev = format!("{:?}", d.severity).to_lowercase();
-                *severity_counts.entry(sev).or_insert(0i64) += 1;
-            }
-        }
-        for sev in &["critical", "high", "medium", "low"] {
-            VIOLATIONS_BY_SEVERITY
-                .with_label_values(&[sev, &namespace, &name])
-                .set(*severity_counts.get(*sev).unwrap_or(&0));
-        }
-    }
+    record_policy_metrics(&namespace, &name, &workloads, &summary, &mode);
 
     // ── Enforcement phase ──
-    let mut remediations_applied: u32 = 0;
-    let mut remediations_failed: u32 = 0;
-    let mut remediated_workloads: Vec<String> = Vec::new();
-    let mut seen_workloads = std::collections::HashSet::new();
-
-    if enforce_mode {
-        for pod in &pod_list.items {
-            let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
-            if governance::is_system_namespace(ns) || enforcement::is_protected_namespace(ns) {
-                continue;
-            }
+    let mut outcome = match mode {
+        EnforcementMode::Enforce => {
+            enforce(&ctx, &pods, &workloads, &spec, &namespace, &name).await
+        }
+        EnforcementMode::DryRun => plan_only(&pods, &spec, &name, ctx.clock.now()),
+        EnforcementMode::Audit => EnforcementOutcome::default(),
+    };
+    let planned = outcome.planned.clone();
 
-            if let Some(plan) = enforcement::plan_remediation(pod, &policy.spec) {
-                let key = plan.workload.key();
+    if ctx.read_only {
+        // Nothing to patch; a completed evaluation is the read-only success
+        mark_reconciled(&ctx).await;
+        return Ok(Action::requeue(ctx.settings.requeue_interval));
+    }
 
-                // Deduplicate: skip if we already patched this workload in this cycle
-                if !seen_workloads.insert(key.clone()) {
-                    continue;
-                }
+    // ── Notifications (delivered in the background) ──
+    if let Some(config) = &spec.notifications {
+        let previous_score = policy.status.as_ref().and_then(|s| s.health_score);
+        let failures = std::mem::take(&mut outcome.failures);
+        notify(
+            &ctx,
+            config,
+            &namespace,
+            &name,
+            &workloads,
+            previous_score,
+            health_score,
+            &failures,
+        );
+    }
 
-                let result = enforcement::apply_remediation(&plan, &ctx.client, &policy.spec).await;
+    // ── Update status sub-resource ──
+    let status = build_status(generation, &summary, &now, enforce_mode, outcome);
+    if status_changed(policy.status.as_ref(), &status) {
+        let policies_api: Api<DevOpsPolicy> = Api::namespaced(ctx.client.clone(), &namespace);
+        policies_api
+            .patch_status(
+                &name,
+                &PatchParams::apply("kube-devops-operator"),
+                &Patch::Merge(&status_patch(&status)),
+            )
+            .await?;
 
-                if result.success {
-                    remediations_applied += 1;
-                    REMEDIATIONS_APPLIED.inc();
-                    remediated_workloads.push(key.clone());
-                    info!(
-                        workload = %key,
-                        policy = %name,
-                        "enforcement_remediation_applied"
-                    );
-                    println!(
-                        "  [ENFORCE] Patched {key} ({} action(s))",
-                        plan.actions.len()
-                    );
-                } else {
-                    remediations_failed += 1;
-                    REMEDIATIONS_FAILED.inc();
-                    warn!(
-                        workload = %key,
-                        error = %result.message,
-                        policy = %name,
-                        "enforcement_remediation_failed"
-                    );
-                    println!("  [ENFORCE] FAILED {key}: {}", result.message);
-                }
-            }
-        }
-
-        if remediations_applied > 0 || remediations_failed > 0 {
-            println!(
-                "  [ENFORCE] Summary: {remediations_applied} applied, {remediations_failed} failed"
-            );
-        }
+        info!(
+            policy = %name,
+            namespace = %namespace,
+            "status_updated"
+        );
+    } else {
+        STATUS_PATCHES_SKIPPED.inc();
+        info!(
+            policy = %name,
+            namespace = %namespace,
+            "status_unchanged"
+        );
     }
+    mark_reconciled(&ctx).await;
 
-    // ── Update status sub-resource ──
-    let status = DevOpsPolicyStatus {
-        observed_generation: generation,
-        healthy: Some(healthy),
-        health_score: Some(health_score),
-        violations: Some(total_violations),
-        last_evaluated: Some(now.to_rfc3339()),
-        message: Some(message),
-        remediations_applied: if enforce_mode {
-            Some(remediations_applied)
-        } else {
-            None
-        },
-        remediations_failed: if enforce_mode {
-            Some(remediations_failed)
-        } else {
-            None
-        },
-        remediated_workloads: if remediated_workloads.is_empty() {
-            None
-        } else {
-            Some(remediated_workloads)
-        },
-    };
-
-    let status_patch = serde_json::json!({ "status": status });
-    let policies_api: Api<DevOpsPolicy> = Api::namespaced(ctx.client.clone(), &namespace);
-
-    policies_api
-        .patch_status(
-            &name,
-            &PatchParams::apply("kube-devops-operator"),
-            &Patch::Merge(&status_patch),
-        )
-        .await?;
-
-    info!(
-        policy = %name,
-        namespace = %namespace,
-        "status_updated"
-    );
+    if !ctx.features.audit_results {
+        return Ok(Action::requeue(ctx.settings.requeue_interval));
+    }
 
     // ── Create audit result (async, non-blocking) ──
     let audit_client = ctx.client.clone();
     let audit_name = name.clone();
+    let audit_result_name = audit_result_name(&name, ctx.ids.as_ref());
     let audit_ns = namespace.clone();
-    let audit_policy_spec = policy.spec.clone();
+    // Owned by the policy so Kubernetes garbage-collects results the
+    // finalizer cleanup misses
+    let audit_owner = policy.controller_owner_ref(&());
     let audit_timestamp = now.to_rfc3339();
-    let audit_pods: Vec<_> = pod_list.items.clone();
+    let classification = summary.classification.clone();
+    let audit_sink = ctx.audit_sink.clone();
 
     tokio::spawn(async move {
         if let Err(e) = create_audit_result(
             &audit_client,
+            &audit_result_name,
             &audit_name,
             &audit_ns,
-            &audit_policy_spec,
+            audit_owner,
             &audit_timestamp,
             health_score,
+            classification,
             total_violations,
-            &audit_pods,
+            &workloads,
+            planned,
+            audit_sink.as_deref(),
         )
         .await
         {
@@ -569,54 +1101,1175 @@ async fn reconcile(
         }
     });
 
-    Ok(Action::requeue(REQUEUE_INTERVAL))
+    Ok(Action::requeue(ctx.settings.requeue_interval))
+}
+
+/* ============================= CLUSTER POLICY RECONCILE ============================= */
+
+/// ClusterDevOpsPolicies from the cluster controller's store, or none when
+/// the feature is unavailable or the CRD is not served yet.
+async fn list_cluster_policies(ctx: &ReconcileContext) -> Vec<ClusterDevOpsPolicy> {
+    let Some(store) = ctx.cluster_policies.get() else {
+        return Vec::new();
+    };
+    if tokio::time::timeout(CLUSTER_POLICY_SYNC_TIMEOUT, store.wait_until_ready())
+        .await
+        .is_err()
+    {
+        warn!("cluster_policy_store_not_synced");
+    }
+    store.state().iter().map(|p| p.as_ref().clone()).collect()
+}
+
+/// Labels of `namespace`, for profile selection. `None` when they cannot
+/// be read, in which case the policy applies without a profile.
+async fn namespace_labels(
+    ctx: &ReconcileContext,
+    namespace: &str,
+) -> Option<std::collections::BTreeMap<String, String>> {
+    if !ctx.features.profiles {
+        warn!(namespace = %namespace, "profiles_skipped_missing_rbac");
+        return None;
+    }
+    let api: Api<Namespace> = Api::all(ctx.client.clone());
+    match api.get_opt(namespace).await {
+        Ok(ns) => ns.and_then(|ns| ns.metadata.labels),
+        Err(e) => {
+            warn!(namespace = %namespace, error = %e, "namespace_labels_failed");
+            None
+        }
+    }
+}
+
+/// Effective spec for every namespace whose labels select one of the
+/// policy's profiles. Namespaces without a profile are absent.
+async fn profiled_namespace_specs(
+    ctx: &ReconcileContext,
+    spec: &DevOpsPolicySpec,
+) -> HashMap<String, DevOpsPolicySpec> {
+    if !ctx.features.profiles {
+        warn!("profiles_skipped_missing_rbac");
+        return HashMap::new();
+    }
+    let api: Api<Namespace> = Api::all(ctx.client.clone());
+    let namespaces = match api.list(&Default::default()).await {
+        Ok(list) => list.items,
+        Err(e) => {
+            warn!(error = %e, "namespace_labels_failed");
+            return HashMap::new();
+        }
+    };
+    namespaces
+        .iter()
+        .filter_map(|ns| {
+            let labels = ns.metadata.labels.as_ref();
+            policy_resolution::selected_profile(spec, labels)?;
+            Some((
+                ns.name_any(),
+                policy_resolution::apply_profile(spec, labels),
+            ))
+        })
+        .collect()
+}
+
+/// Evaluate cluster-policy workloads, using the profile spec for each
+/// namespace in `profile_specs` and the policy spec everywhere else.
+fn evaluate_cluster_workloads(
+    pods: &[Pod],
+    jobs: &[Job],
+    cron_jobs: &[CronJob],
+    replicated: &ReplicatedWorkloads,
+    spec: &DevOpsPolicySpec,
+    profile_specs: &HashMap<String, DevOpsPolicySpec>,
+    now: DateTime<Utc>,
+) -> Vec<WorkloadEvaluation> {
+    if profile_specs.is_empty() {
+        let mut workloads = governance::evaluate_workloads_with_jobs(pods, jobs, spec, now);
+        governance::merge_template_evaluations(
+            &mut workloads,
+            governance::evaluate_batch_workloads(jobs, cron_jobs, spec),
+        );
+        governance::merge_workload_violations(&mut workloads, replicated.evaluate(spec));
+        return workloads;
+    }
+
+    let in_namespace = |ns: Option<String>, target: Option<&str>| match target {
+        Some(target) => ns.as_deref() == Some(target),
+        None => !profile_specs.contains_key(ns.as_deref().unwrap_or_default()),
+    };
+
+    let mut workloads = Vec::new();
+    let layers = std::iter::once((None, spec))
+        .chain(profile_specs.iter().map(|(ns, s)| (Some(ns.as_str()), s)));
+    for (target, layer_spec) in layers {
+        let pods: Vec<Pod> = pods
+            .iter()
+            .filter(|p| in_namespace(p.namespace(), target))
+            .cloned()
+            .collect();
+        let jobs: Vec<Job> = jobs
+            .iter()
+            .filter(|j| in_namespace(j.namespace(), target))
+            .cloned()
+            .collect();
+        let cron_jobs: Vec<CronJob> = cron_jobs
+            .iter()
+            .filter(|c| in_namespace(c.namespace(), target))
+            .cloned()
+            .collect();
+        let mut layer = governance::evaluate_workloads_with_jobs(&pods, &jobs, layer_spec, now);
+        governance::merge_template_evaluations(
+            &mut layer,
+            governance::evaluate_batch_workloads(&jobs, &cron_jobs, layer_spec),
+        );
+        governance::merge_workload_violations(
+            &mut layer,
+            replicated
+                .filter(|ns| in_namespace(ns, target))
+                .evaluate(layer_spec),
+        );
+        workloads.extend(layer);
+    }
+    workloads.sort_by(|a, b| (&a.namespace, &a.workload).cmp(&(&b.namespace, &b.workload)));
+    workloads
+}
+
+/// Deployments, StatefulSets, and PodDisruptionBudgets for the
+/// `missing_pdb` and `no_spread` checks.
+#[derive(Debug, Default)]
+struct ReplicatedWorkloads {
+    deployments: Vec<Deployment>,
+    stateful_sets: Vec<StatefulSet>,
+    pdbs: Vec<PodDisruptionBudget>,
+}
+
+impl ReplicatedWorkloads {
+    /// Whether `spec` enables a check that needs the listing.
+    fn checked_by(spec: &DevOpsPolicySpec) -> bool {
+        spec.require_pod_disruption_budget.unwrap_or(false)
+            || spec.require_spread_constraints.unwrap_or(false)
+    }
+
+    /// List the three kinds in `namespace` (all namespaces with `None`),
+    /// keeping objects whose namespace passes `keep`. Empty when RBAC
+    /// does not allow listing them.
+    async fn list(
+        ctx: &ReconcileContext,
+        namespace: Option<&str>,
+        keep: impl Fn(Option<String>) -> bool,
+    ) -> std::result::Result<Self, kube::Error> {
+        if !ctx.features.replicated_workloads {
+            warn!(namespace = ?namespace, "replicated_workloads_skipped_missing_rbac");
+            return Ok(Self::default());
+        }
+        let page_size = ctx.settings.list_page_size;
+        Ok(Self {
+            deployments: list_paged(&scoped_api(ctx, namespace), page_size, |d: &Deployment| {
+                keep(d.namespace())
+            })
+            .await?,
+            stateful_sets: list_paged(&scoped_api(ctx, namespace), page_size, |s: &StatefulSet| {
+                keep(s.namespace())
+            })
+            .await?,
+            pdbs: list_paged(
+                &scoped_api(ctx, namespace),
+                page_size,
+                |p: &PodDisruptionBudget| keep(p.namespace()),
+            )
+            .await?,
+        })
+    }
+
+    /// The subset in namespaces passing `keep`.
+    fn filter(&self, keep: impl Fn(Option<String>) -> bool) -> Self {
+        fn select<K: Resource + Clone>(
+            items: &[K],
+            keep: impl Fn(Option<String>) -> bool,
+        ) -> Vec<K> {
+            items
+                .iter()
+                .filter(|i| keep(i.namespace()))
+                .cloned()
+                .collect()
+        }
+        Self {
+            deployments: select(&self.deployments, &keep),
+            stateful_sets: select(&self.stateful_sets, &keep),
+            pdbs: select(&self.pdbs, &keep),
+        }
+    }
+
+    fn evaluate(&self, spec: &DevOpsPolicySpec) -> Vec<WorkloadEvaluation> {
+        governance::evaluate_replicated_workloads(
+            &self.deployments,
+            &self.stateful_sets,
+            &self.pdbs,
+            spec,
+        )
+    }
+}
+
+/// `Api` for a namespaced kind in `namespace`, or across all namespaces.
+fn scoped_api<K>(ctx: &ReconcileContext, namespace: Option<&str>) -> Api<K>
+where
+    K: Resource<Scope = NamespaceResourceScope>,
+    <K as Resource>::DynamicType: Default,
+{
+    match namespace {
+        Some(ns) => Api::namespaced(ctx.client.clone(), ns),
+        None => Api::all(ctx.client.clone()),
+    }
+}
+
+async fn reconcile_cluster(
+    policy: Arc<ClusterDevOpsPolicy>,
+    ctx: Arc<ReconcileContext>,
+) -> std::result::Result<Action, kube::Error> {
+    let span = otlp::traced(info_span!(
+        "reconcile_cluster",
+        policy = %policy.name_any(),
+        trace_id = field::Empty,
+    ));
+    reconcile_cluster_policy(policy, ctx).instrument(span).await
+}
+
+/// Evaluate a cluster policy over the namespaces it covers that have no
+/// namespaced DevOpsPolicy; those namespaces report through their own policy.
+async fn reconcile_cluster_policy(
+    policy: Arc<ClusterDevOpsPolicy>,
+    ctx: Arc<ReconcileContext>,
+) -> std::result::Result<Action, kube::Error> {
+    let name = policy.name_any();
+    let generation = policy.metadata.generation;
+
+    if policy.metadata.deletion_timestamp.is_some() {
+        let _ = POLICY_VIOLATIONS.remove_label_values(&["", &name]);
+        let _ = POLICY_HEALTH.remove_label_values(&["", &name]);
+        for (classification, _) in threshold_levels(&Default::default()) {
+            let _ = HEALTH_THRESHOLD.remove_label_values(&["", &name, classification]);
+        }
+        let _ = ENFORCEMENT_MODE.remove_label_values(&["", &name]);
+        let _ = REMEDIATIONS_DEFERRED.remove_label_values(&["", &name]);
+        return Ok(Action::await_change());
+    }
+
+    let policy_key = format!("cluster/{name}");
+    if policy.status.as_ref().and_then(|s| s.observed_generation) == generation
+        && !ctx.reevaluation_due(&policy_key, &policy.spec.policy)
+    {
+        info!(policy = %name, generation = ?generation, "reconcile_skip_unchanged");
+        return Ok(Action::requeue(ctx.settings.requeue_interval));
+    }
+
+    RECONCILE_TOTAL.inc();
+    let _timer = RECONCILE_DURATION.start_timer();
+    info!(policy = %name, "cluster_reconcile_start");
+
+    let namespaced: Api<DevOpsPolicy> = Api::all(ctx.client.clone());
+    let overridden: std::collections::HashSet<String> = namespaced
+        .list(&Default::default())
+        .await?
+        .items
+        .iter()
+        .filter_map(|p| p.namespace())
+        .collect();
+    let covers = |ns: Option<String>| {
+        let ns = ns.unwrap_or_default();
+        policy_resolution::cluster_policy_applies(&policy.spec, &ns) && !overridden.contains(&ns)
+    };
+
+    let spec = &policy.spec.policy;
+    let pods_api: Api<Pod> = Api::all(ctx.client.clone());
+    let page_size = ctx.settings.list_page_size;
+    let pods = if ctx.pods.is_synced() {
+        POD_LISTINGS.with_label_values(&["cache"]).inc();
+        ctx.pods.pods(|ns| covers(Some(ns.to_string())))
+    } else {
+        POD_LISTINGS.with_label_values(&["api"]).inc();
+        list_paged(&pods_api, page_size, |p| covers(p.namespace()))
+            .instrument(info_span!("list_pods", namespace = "*"))
+            .await?
+    };
+
+    PODS_SCANNED.inc_by(pods.len() as u64);
+
+    let (jobs, cron_jobs) = if ctx.features.batch_workloads {
+        let jobs_api: Api<Job> = Api::all(ctx.client.clone());
+        let cron_jobs_api: Api<CronJob> = Api::all(ctx.client.clone());
+        let jobs = list_paged(&jobs_api, page_size, |j| covers(j.namespace())).await?;
+        let cron_jobs = list_paged(&cron_jobs_api, page_size, |c| covers(c.namespace())).await?;
+        (jobs, cron_jobs)
+    } else {
+        (Vec::new(), Vec::new())
+    };
+    let profile_specs = if policy_resolution::has_profiles(spec) {
+        profiled_namespace_specs(&ctx, spec).await
+    } else {
+        HashMap::new()
+    };
+    let replicated = if std::iter::once(spec)
+        .chain(profile_specs.values())
+        .any(ReplicatedWorkloads::checked_by)
+    {
+        ReplicatedWorkloads::list(&ctx, None, covers).await?
+    } else {
+        ReplicatedWorkloads::default()
+    };
+    let workloads = evaluate_cluster_workloads(
+        &pods,
+        &jobs,
+        &cron_jobs,
+        &replicated,
+        spec,
+        &profile_specs,
+        ctx.clock.now(),
+    );
+    ctx.record_evaluation(&policy_key);
+
+    let history = score_history(&ctx, None, &name, policy.status.as_ref()).await;
+    let summary = EvaluationSummary::from_workloads(&workloads, spec).with_history(&history);
+    let now = ctx.clock.now();
+    let mode = enforcement::effective_mode(spec);
+    let enforce_mode = mode == EnforcementMode::Enforce;
+    report_evaluation(
+        &policy_key,
+        &summary,
+        &mode,
+        &policy_timezone(spec, ctx.timezone).format(&now, "%H:%M:%S"),
+    );
+    info!(
+        policy = %name,
+        health_score = summary.health_score,
+        violations = summary.total_violations,
+        workloads = summary.workloads,
+        pods = summary.total_pods,
+        "cluster_reconcile_evaluated"
+    );
+
+    record_policy_metrics("", &name, &workloads, &summary, &mode);
+
+    let outcome = match mode {
+        EnforcementMode::Enforce => enforce(&ctx, &pods, &workloads, spec, "", &name).await,
+        EnforcementMode::DryRun => plan_only(&pods, spec, &name, ctx.clock.now()),
+        EnforcementMode::Audit => EnforcementOutcome::default(),
+    };
+
+    if ctx.read_only {
+        mark_reconciled(&ctx).await;
+        return Ok(Action::requeue(ctx.settings.requeue_interval));
+    }
+
+    let status = build_status(generation, &summary, &now, enforce_mode, outcome);
+    if status_changed(policy.status.as_ref(), &status) {
+        let api: Api<ClusterDevOpsPolicy> = Api::all(ctx.client.clone());
+        api.patch_status(
+            &name,
+            &PatchParams::apply("kube-devops-operator"),
+            &Patch::Merge(&status_patch(&status)),
+        )
+        .await?;
+        info!(policy = %name, "status_updated");
+    } else {
+        STATUS_PATCHES_SKIPPED.inc();
+        info!(policy = %name, "status_unchanged");
+    }
+    mark_reconciled(&ctx).await;
+    Ok(Action::requeue(ctx.settings.requeue_interval))
+}
+
+/* ============================= PAGED LISTING ============================= */
+
+/// Times a listing may restart after its continue token expired.
+const LIST_RESTARTS: u32 = 3;
+
+/// List every object in `api`, `page_size` at a time, keeping those `keep`
+/// accepts. Filtering per page and dropping `managedFields` keeps a
+/// cluster-wide listing from holding every full object in memory at once.
+async fn list_paged<K>(
+    api: &Api<K>,
+    page_size: u32,
+    keep: impl Fn(&K) -> bool,
+) -> std::result::Result<Vec<K>, kube::Error>
+where
+    K: kube::Resource + Clone + DeserializeOwned + Debug,
+{
+    collect_pages(
+        page_size,
+        |params| async move { api.list(&params).await },
+        keep,
+    )
+    .await
+}
+
+/// Pagination loop behind `list_paged`, with the request abstracted out.
+async fn collect_pages<K, F, Fut>(
+    page_size: u32,
+    mut fetch: F,
+    keep: impl Fn(&K) -> bool,
+) -> std::result::Result<Vec<K>, kube::Error>
+where
+    K: kube::Resource + Clone,
+    F: FnMut(ListParams) -> Fut,
+    Fut: std::future::Future<Output = std::result::Result<ObjectList<K>, kube::Error>>,
+{
+    let first_page = ListParams::default().limit(page_size);
+    let mut params = first_page.clone();
+    let mut items = Vec::new();
+    let mut restarts = 0;
+
+    loop {
+        let page = match fetch(params.clone()).await {
+            Ok(page) => page,
+            // 410 Gone: the snapshot behind the continue token was compacted
+            Err(kube::Error::Api(e))
+                if e.code == 410 && params.continue_token.is_some() && restarts < LIST_RESTARTS =>
+            {
+                restarts += 1;
+                items.clear();
+                params = first_page.clone();
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+
+        items.extend(
+            page.items
+                .into_iter()
+                .filter(|item| keep(item))
+                .map(|mut item| {
+                    item.meta_mut().managed_fields = None;
+                    item
+                }),
+        );
+        match page.metadata.continue_.filter(|token| !token.is_empty()) {
+            Some(token) => params = first_page.clone().continue_token(&token),
+            None => return Ok(items),
+        }
+    }
+}
+
+/* ============================= EVALUATION SUMMARY ============================= */
+
+/// Aggregate score and counts for one reconcile cycle.
+#[derive(Clone)]
+struct EvaluationSummary {
+    health_score: u32,
+    classification: String,
+    thresholds: governance::ClassificationThresholds,
+    total_violations: u32,
+    total_pods: u32,
+    workloads: usize,
+    previous_score: Option<u32>,
+    score_trend: Option<ScoreTrend>,
+}
+
+impl EvaluationSummary {
+    /// Aggregate `workloads`, scored with the policy's `scoringWeights` and
+    /// classified with its `healthThresholds`.
+    fn from_workloads(workloads: &[WorkloadEvaluation], spec: &DevOpsPolicySpec) -> Self {
+        let mut aggregate = governance::PodMetrics::default();
+        let mut total_violations: u32 = 0;
+        let mut total_pods: u32 = 0;
+
+        for w in workloads {
+            governance::add_metrics(&mut aggregate, &w.metrics);
+            total_violations += w.violations.len() as u32;
+            total_pods += w.replicas;
+        }
+
+        let health_score = governance::calculate_health_score_with_weights(
+            &aggregate,
+            &governance::ScoringWeights::for_policy(spec),
+        );
+        let thresholds = governance::ClassificationThresholds::for_policy(spec);
+        Self {
+            health_score,
+            classification: thresholds.classify(health_score).to_string(),
+            thresholds,
+            total_violations,
+            total_pods,
+            workloads: workloads.len(),
+            previous_score: None,
+            score_trend: None,
+        }
+    }
+
+    /// Compare the score with the policy's stored scores (oldest first).
+    fn with_history(mut self, history: &[u32]) -> Self {
+        self.previous_score = history.last().copied();
+        self.score_trend = governance::score_trend(history, self.health_score);
+        self
+    }
+
+    /// Score change since the previous evaluation (0 without one).
+    fn score_delta(&self) -> i64 {
+        self.previous_score
+            .map_or(0, |previous| self.health_score as i64 - previous as i64)
+    }
+
+    fn message(&self) -> String {
+        format!(
+            "{} violations across {} workloads ({} pods) — {} ({})",
+            self.total_violations,
+            self.workloads,
+            self.total_pods,
+            self.classification,
+            self.health_score
+        )
+    }
+}
+
+/// Stored scores of a policy, oldest first: its PolicyAuditResults in
+/// `namespace`, or the score in its current status when there are none
+/// (always the case for cluster policies).
+async fn score_history(
+    ctx: &ReconcileContext,
+    namespace: Option<&str>,
+    name: &str,
+    status: Option<&DevOpsPolicyStatus>,
+) -> Vec<u32> {
+    if let Some(namespace) = namespace
+        && ctx.features.audit_results
+    {
+        let api: Api<PolicyAuditResult> = Api::namespaced(ctx.client.clone(), namespace);
+        match api.list(&Default::default()).await {
+            Ok(list) => {
+                let history = governance::score_history(&list.items, name);
+                if !history.is_empty() {
+                    return history;
+                }
+            }
+            Err(e) => warn!(error = %e, policy = %name, "score_history_failed"),
+        }
+    }
+    status.and_then(|s| s.health_score).into_iter().collect()
+}
+
+/// The policy's `timezone`, or `default` when unset or invalid.
+fn policy_timezone(spec: &DevOpsPolicySpec, default: Timezone) -> Timezone {
+    match spec.timezone.as_deref().map(str::parse::<Timezone>) {
+        Some(Ok(timezone)) => timezone,
+        Some(Err(e)) => {
+            warn!(error = %e, "invalid_policy_timezone");
+            default
+        }
+        None => default,
+    }
+}
+
+fn report_evaluation(label: &str, summary: &EvaluationSummary, mode: &EnforcementMode, time: &str) {
+    let mode_label = mode.as_str();
+    println!(
+        "[{time}] {label}: {} — score {}/100, {} violations, {} workloads, {} pods (mode: {mode_label})",
+        summary.classification,
+        summary.health_score,
+        summary.total_violations,
+        summary.workloads,
+        summary.total_pods,
+    );
+}
+
+fn record_policy_metrics(
+    namespace: &str,
+    name: &str,
+    workloads: &[WorkloadEvaluation],
+    summary: &EvaluationSummary,
+    mode: &EnforcementMode,
+) {
+    POLICY_VIOLATIONS
+        .with_label_values(&[namespace, name])
+        .set(summary.total_violations as i64);
+    POLICY_HEALTH
+        .with_label_values(&[namespace, name])
+        .set(summary.health_score as i64);
+    HEALTH_SCORE_DELTA
+        .with_label_values(&[namespace, name])
+        .set(summary.score_delta());
+    for (classification, threshold) in threshold_levels(&summary.thresholds) {
+        HEALTH_THRESHOLD
+            .with_label_values(&[namespace, name, classification])
+            .set(i64::from(threshold));
+    }
+    ENFORCEMENT_MODE
+        .with_label_values(&[namespace, name])
+        .set(match mode {
+            EnforcementMode::Audit => 0,
+            EnforcementMode::Enforce => 1,
+            EnforcementMode::DryRun => 2,
+        });
+
+    // ── Violations by severity ──
+    let mut severity_counts = std::collections::HashMap::new();
+    for d in workloads.iter().flat_map(|w| &w.violations) {
+        let sev = format!("{:?}", d.severity).to_lowercase();
+        *severity_counts.entry(sev).or_insert(0i64) += 1;
+    }
+    for sev in &["critical", "high", "medium", "low"] {
+        VIOLATIONS_BY_SEVERITY
+            .with_label_values(&[sev, namespace, name])
+            .set(*severity_counts.get(*sev).unwrap_or(&0));
+    }
+
+    // ── Violations by type ──
+    let mut type_counts = std::collections::HashMap::new();
+    for d in workloads.iter().flat_map(|w| &w.violations) {
+        *type_counts.entry(d.violation_type.as_str()).or_insert(0i64) += 1;
+    }
+    for violation_type in governance::VIOLATION_TYPES {
+        VIOLATIONS_BY_TYPE
+            .with_label_values(&[violation_type, namespace, name])
+            .set(*type_counts.get(violation_type).unwrap_or(&0));
+    }
+}
+
+/// `devopspolicy_health_threshold` series of a policy, by classification label.
+fn threshold_levels(thresholds: &governance::ClassificationThresholds) -> [(&'static str, u32); 3] {
+    [
+        ("healthy", thresholds.healthy),
+        ("stable", thresholds.stable),
+        ("degraded", thresholds.degraded),
+    ]
+}
+
+fn build_status(
+    generation: Option<i64>,
+    summary: &EvaluationSummary,
+    now: &chrono::DateTime<chrono::Utc>,
+    enforce_mode: bool,
+    outcome: EnforcementOutcome,
+) -> DevOpsPolicyStatus {
+    DevOpsPolicyStatus {
+        observed_generation: generation,
+        healthy: Some(summary.health_score >= summary.thresholds.healthy),
+        health_score: Some(summary.health_score),
+        violations: Some(summary.total_violations),
+        classification: Some(summary.classification.clone()),
+        health_thresholds: Some(summary.thresholds.into()),
+        previous_score: summary.previous_score,
+        score_trend: summary.score_trend,
+        last_evaluated: Some(now.to_rfc3339()),
+        message: Some(summary.message()),
+        remediations_applied: enforce_mode.then_some(outcome.applied),
+        remediations_failed: enforce_mode.then_some(outcome.failed),
+        remediated_workloads: if outcome.remediated_workloads.is_empty() {
+            None
+        } else {
+            Some(outcome.remediated_workloads)
+        },
+        planned_remediations: outcome.planned,
+    }
+}
+
+/// Whether `status` differs from the stored status in anything but
+/// `lastEvaluated`, which changes on every cycle. Unchanged statuses are not
+/// patched, sparing the API server a write per policy per requeue.
+fn status_changed(current: Option<&DevOpsPolicyStatus>, status: &DevOpsPolicyStatus) -> bool {
+    let Some(current) = current else {
+        return true;
+    };
+    let comparable = DevOpsPolicyStatus {
+        last_evaluated: current.last_evaluated.clone(),
+        ..status.clone()
+    };
+    *current != comparable
+}
+
+/// Merge patch for a policy's status sub-resource.
+///
+/// `plannedRemediations` is nulled explicitly so it clears when a policy
+/// leaves dry-run mode; a merge patch otherwise keeps the stale list.
+fn status_patch(status: &DevOpsPolicyStatus) -> serde_json::Value {
+    let mut patch = serde_json::json!({ "status": status });
+    if status.planned_remediations.is_none() {
+        patch["status"]["plannedRemediations"] = serde_json::Value::Null;
+    }
+    patch
+}
+
+/* ============================= ENFORCEMENT ============================= */
+
+/// Result of one enforcement pass.
+#[derive(Default)]
+struct EnforcementOutcome {
+    applied: u32,
+    failed: u32,
+    remediated_workloads: Vec<String>,
+    /// Failed patches as `(workload, error)`.
+    failures: Vec<(String, String)>,
+    /// Set in dry-run mode only.
+    planned: Option<Vec<PlannedRemediation>>,
+}
+
+/// Dry-run enforcement: plan remediations and print them without patching.
+fn plan_only(
+    pods: &[Pod],
+    spec: &DevOpsPolicySpec,
+    name: &str,
+    now: DateTime<Utc>,
+) -> EnforcementOutcome {
+    let mut planned = enforcement::plan_remediations(pods, spec);
+    for plan in &planned {
+        let actions: Vec<String> = plan
+            .actions
+            .iter()
+            .map(|a| format!("{} ({})", a.action, a.container))
+            .collect();
+        println!(
+            "  [DRY-RUN] Would patch {}: {}",
+            plan.workload,
+            actions.join(", ")
+        );
+    }
+    // Dry runs keep no eviction history, so the cooldown never applies
+    for candidate in enforcement::plan_evictions(pods, spec, &HashMap::new(), now) {
+        println!(
+            "  [DRY-RUN] Would evict {}/{} of {} ({} restarts on {})",
+            candidate.workload.namespace,
+            candidate.pod_name,
+            candidate.workload.key(),
+            candidate.restarts,
+            candidate.container
+        );
+        planned.push(enforcement::describe_eviction(&candidate));
+    }
+    info!(policy = %name, planned = planned.len(), "enforcement_dry_run_planned");
+    EnforcementOutcome {
+        planned: Some(planned),
+        ..Default::default()
+    }
+}
+
+/// Patch the owners of non-compliant pods, throttled by the policy's
+/// per-cycle limit and cooldown; in read-only mode only print what would be
+/// patched. `namespace` is empty for cluster policies.
+async fn enforce(
+    ctx: &ReconcileContext,
+    pods: &[Pod],
+    workloads: &[WorkloadEvaluation],
+    spec: &DevOpsPolicySpec,
+    namespace: &str,
+    name: &str,
+) -> EnforcementOutcome {
+    let mut outcome = EnforcementOutcome::default();
+    let plans = enforcement::plan_workload_remediations(pods, spec);
+
+    evict_crash_looping(ctx, pods, spec, name).await;
+    quarantine_critical(ctx, workloads, spec, name).await;
+
+    if ctx.read_only {
+        for plan in &plans {
+            println!("  [READ-ONLY] Would patch {}", plan.workload.key());
+        }
+        info!(policy = %name, planned = plans.len(), "enforcement_skipped_read_only");
+        return outcome;
+    }
+
+    if !ctx.features.enforcement {
+        warn!(policy = %name, "enforcement_skipped_missing_rbac");
+        return outcome;
+    }
+
+    // ── Throttle ──
+    let policy_key = if namespace.is_empty() {
+        format!("cluster/{name}")
+    } else {
+        format!("{namespace}/{name}")
+    };
+    let now = ctx.clock.now();
+    let last_round = ctx
+        .remediation_rounds
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&policy_key)
+        .copied();
+    let throttled = enforcement::throttle_plans(plans, spec, last_round, now);
+    REMEDIATIONS_DEFERRED
+        .with_label_values(&[namespace, name])
+        .set(throttled.deferred.len() as i64);
+    if !throttled.deferred.is_empty() {
+        info!(
+            policy = %name,
+            deferred = throttled.deferred.len(),
+            cooldown = enforcement::in_remediation_cooldown(spec, last_round, now),
+            "enforcement_remediations_deferred"
+        );
+        println!(
+            "  [ENFORCE] Deferred {} remediation(s) (throttled)",
+            throttled.deferred.len()
+        );
+    }
+
+    for plan in &throttled.apply {
+        let key = plan.workload.key();
+        let result = enforcement::apply_remediation(plan, &ctx.client, spec, name)
+            .instrument(info_span!("apply_remediation", workload = %key))
+            .await;
+
+        if result.success {
+            outcome.applied += 1;
+            REMEDIATIONS_APPLIED.inc();
+            outcome.remediated_workloads.push(key.clone());
+            info!(
+                workload = %key,
+                policy = %name,
+                "enforcement_remediation_applied"
+            );
+            println!(
+                "  [ENFORCE] Patched {key} ({} action(s))",
+                plan.actions.len()
+            );
+            for change in &result.changes {
+                println!("    {}", change.describe());
+            }
+            if ctx.features.events {
+                publish_remediation_event(&ctx.client, &result).await;
+            }
+        } else {
+            outcome.failed += 1;
+            REMEDIATIONS_FAILED.inc();
+            warn!(
+                workload = %key,
+                error = %result.message,
+                policy = %name,
+                "enforcement_remediation_failed"
+            );
+            println!("  [ENFORCE] FAILED {key}: {}", result.message);
+            outcome.failures.push((key.clone(), result.message.clone()));
+        }
+    }
+
+    if outcome.applied > 0 {
+        ctx.remediation_rounds
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(policy_key, now);
+    }
+
+    if outcome.applied > 0 || outcome.failed > 0 {
+        println!(
+            "  [ENFORCE] Summary: {} applied, {} failed",
+            outcome.applied, outcome.failed
+        );
+    }
+    outcome
+}
+
+/// Event notes are limited to 1 KiB by the API server.
+const EVENT_NOTE_LIMIT: usize = 1024;
+
+/// Event note listing a remediation's field changes, one per line,
+/// truncated to `EVENT_NOTE_LIMIT`.
+fn remediation_event_note(result: &enforcement::RemediationResult) -> String {
+    let mut note = result.message.clone();
+    for change in &result.changes {
+        let line = format!("\n{}", change.describe());
+        if note.len() + line.len() > EVENT_NOTE_LIMIT {
+            let more = "\n...";
+            if note.len() + more.len() <= EVENT_NOTE_LIMIT {
+                note.push_str(more);
+            }
+            break;
+        }
+        note.push_str(&line);
+    }
+    note
+}
+
+/// Record a `Remediated` Event on the patched workload. Failures are only logged.
+async fn publish_remediation_event(client: &Client, result: &enforcement::RemediationResult) {
+    let reference = ObjectReference {
+        api_version: Some("apps/v1".to_string()),
+        kind: Some(result.workload.kind.clone()),
+        name: Some(result.workload.name.clone()),
+        namespace: Some(result.workload.namespace.clone()),
+        ..Default::default()
+    };
+    let reporter = Reporter {
+        controller: "kube-devops-operator".to_string(),
+        instance: Some(clock::instance_identity()),
+    };
+    let event = Event {
+        type_: EventType::Normal,
+        reason: "Remediated".to_string(),
+        note: Some(remediation_event_note(result)),
+        action: "PatchPodTemplate".to_string(),
+        secondary: None,
+    };
+    if let Err(e) = Recorder::new(client.clone(), reporter, reference)
+        .publish(event)
+        .await
+    {
+        warn!(
+            workload = %result.workload.key(),
+            error = %e,
+            "remediation_event_failed"
+        );
+    }
+}
+
+/// Evict crash-looping pods when the policy opts in; in read-only mode
+/// only print what would be evicted.
+async fn evict_crash_looping(
+    ctx: &ReconcileContext,
+    pods: &[Pod],
+    spec: &DevOpsPolicySpec,
+    name: &str,
+) {
+    if !enforcement::is_eviction_enabled(spec) {
+        return;
+    }
+    let now = ctx.clock.now();
+    let candidates = {
+        let last_evicted = ctx.evictions.lock().unwrap_or_else(|e| e.into_inner());
+        enforcement::plan_evictions(pods, spec, &last_evicted, now)
+    };
+
+    if ctx.read_only {
+        for c in &candidates {
+            println!(
+                "  [READ-ONLY] Would evict {}/{} ({} restarts)",
+                c.workload.namespace, c.pod_name, c.restarts
+            );
+        }
+        return;
+    }
+    if !ctx.features.eviction {
+        if !candidates.is_empty() {
+            warn!(policy = %name, "eviction_skipped_missing_rbac");
+        }
+        return;
+    }
+
+    for c in &candidates {
+        let pod = format!("{}/{}", c.workload.namespace, c.pod_name);
+        let result = enforcement::evict_pod(c, &ctx.client).await;
+        POD_EVICTIONS
+            .with_label_values(&[eviction_result_label(&result)])
+            .inc();
+        match result {
+            EvictionOutcome::Evicted => {
+                ctx.evictions
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .insert(c.workload.key(), now);
+                info!(pod = %pod, policy = %name, "enforcement_pod_evicted");
+                println!(
+                    "  [ENFORCE] Evicted {pod} of {} ({} restarts on {})",
+                    c.workload.key(),
+                    c.restarts,
+                    c.container
+                );
+            }
+            EvictionOutcome::BlockedByDisruptionBudget => {
+                println!("  [ENFORCE] Eviction of {pod} blocked by PodDisruptionBudget");
+            }
+            EvictionOutcome::NotFound => {}
+            EvictionOutcome::Failed(message) => {
+                println!("  [ENFORCE] FAILED to evict {pod}: {message}");
+            }
+        }
+    }
+}
+
+/// Scale workloads with critical violations to zero in the policy's
+/// quarantine namespaces; in read-only mode only print what would be
+/// quarantined.
+async fn quarantine_critical(
+    ctx: &ReconcileContext,
+    workloads: &[WorkloadEvaluation],
+    spec: &DevOpsPolicySpec,
+    name: &str,
+) {
+    let candidates = quarantine::plan_quarantines(workloads, spec);
+    if candidates.is_empty() {
+        return;
+    }
+    if ctx.read_only {
+        for c in &candidates {
+            println!("  [READ-ONLY] Would quarantine {}", c.workload.key());
+        }
+        return;
+    }
+    if !ctx.features.enforcement || !ctx.features.quarantine {
+        warn!(policy = %name, "quarantine_skipped_missing_rbac");
+        return;
+    }
+
+    let now = ctx.clock.now().to_rfc3339();
+    for c in &candidates {
+        let key = c.workload.key();
+        match quarantine::quarantine_workload(c, &ctx.client, name, &now).await {
+            QuarantineOutcome::Quarantined { original_replicas } => {
+                QUARANTINES_TOTAL.inc();
+                println!(
+                    "  [ENFORCE] Quarantined {key} ({original_replicas} -> 0 replicas): {}",
+                    c.violations.join(", ")
+                );
+            }
+            QuarantineOutcome::AlreadyQuarantined
+            | QuarantineOutcome::Approved
+            | QuarantineOutcome::AlreadyScaledDown => {}
+            QuarantineOutcome::Failed(message) => {
+                warn!(workload = %key, error = %message, policy = %name, "enforcement_quarantine_failed");
+                println!("  [ENFORCE] FAILED to quarantine {key}: {message}");
+            }
+        }
+    }
+}
+
+/// `result` label for `devopspolicy_pod_evictions_total`.
+fn eviction_result_label(outcome: &EvictionOutcome) -> &'static str {
+    match outcome {
+        EvictionOutcome::Evicted => "evicted",
+        EvictionOutcome::BlockedByDisruptionBudget => "blocked_by_pdb",
+        EvictionOutcome::NotFound => "not_found",
+        EvictionOutcome::Failed(_) => "failed",
+    }
+}
+
+/* ============================= NOTIFICATIONS ============================= */
+
+/// Send the policy's notification events that are not cooling down to its
+/// channels. Delivery runs in the background so a slow endpoint does not
+/// hold up the reconcile.
+#[allow(clippy::too_many_arguments)]
+fn notify(
+    ctx: &Arc<ReconcileContext>,
+    config: &NotificationConfig,
+    namespace: &str,
+    name: &str,
+    workloads: &[WorkloadEvaluation],
+    previous_score: Option<u32>,
+    health_score: u32,
+    failures: &[(String, String)],
+) {
+    let mut events = notifications::violation_events(config, workloads);
+    events.extend(notifications::score_event(
+        config,
+        previous_score,
+        health_score,
+    ));
+    events.extend(notifications::remediation_failure_events(config, failures));
+
+    let now = ctx.clock.now();
+    let events = {
+        let mut cooldown = ctx
+            .notification_cooldown
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        cooldown.prune(now);
+        cooldown.admit(
+            &format!("{namespace}/{name}"),
+            events,
+            config
+                .cooldown_seconds
+                .unwrap_or(notifications::DEFAULT_COOLDOWN_SECONDS),
+            now,
+        )
+    };
+    if events.is_empty() {
+        return;
+    }
+    // Reported here, after the cooldown, so a misconfiguration is not logged every cycle
+    for error in notifications::channel_errors(config) {
+        warn!(policy = %name, namespace = %namespace, error = %error, "notification_channel_invalid");
+    }
+
+    let channels: Vec<_> = notifications::deliverable_channels(config)
+        .into_iter()
+        .cloned()
+        .collect();
+    let notification = Notification {
+        policy: name.to_string(),
+        namespace: namespace.to_string(),
+        health_score,
+        timestamp: now.to_rfc3339(),
+        events,
+    };
+    let ctx = ctx.clone();
+    tokio::spawn(async move {
+        for channel in &channels {
+            let channel_type = channel.channel_type.as_str();
+            match ctx.notifier.send(channel, &notification).await {
+                Ok(()) => {
+                    NOTIFICATIONS
+                        .with_label_values(&[channel_type, "sent"])
+                        .inc();
+                    info!(
+                        policy = %notification.policy,
+                        namespace = %notification.namespace,
+                        channel = channel_type,
+                        events = notification.events.len(),
+                        "notification_sent"
+                    );
+                }
+                Err(e) => {
+                    NOTIFICATIONS
+                        .with_label_values(&[channel_type, "failed"])
+                        .inc();
+                    warn!(
+                        error = %e,
+                        policy = %notification.policy,
+                        namespace = %notification.namespace,
+                        channel = channel_type,
+                        "notification_failed"
+                    );
+                }
+            }
+        }
+    });
 }
 
 /* ============================= AUDIT RESULTS ============================= */
 
-const AUDIT_RETENTION: usize = 10;
+/// Name for a new audit result: policy name plus a unique hash suffix.
+///
+/// A millisecond timestamp alone collides when two replicas write at the
+/// same instant; the injected generator mixes in the instance identity.
+fn audit_result_name(policy_name: &str, ids: &dyn IdGenerator) -> String {
+    clock::unique_name(policy_name, &ids.next_id())
+}
+
+/// Flatten workload evaluations into audit violations tagged with their workload.
+fn audit_violations(workloads: &[WorkloadEvaluation]) -> Vec<AuditViolation> {
+    workloads
+        .iter()
+        .flat_map(|w| {
+            w.violations.iter().map(|d| AuditViolation {
+                pod_name: d.pod_name.clone(),
+                container_name: d.container_name.clone(),
+                violation_type: d.violation_type.clone(),
+                severity: d.severity.clone(),
+                message: d.message.clone(),
+                workload: Some(w.workload.clone()),
+            })
+        })
+        .collect()
+}
 
 #[allow(clippy::too_many_arguments)]
 async fn create_audit_result(
     client: &Client,
+    result_name: &str,
     policy_name: &str,
     namespace: &str,
-    policy_spec: &kube_devops::crd::DevOpsPolicySpec,
+    owner: Option<OwnerReference>,
     timestamp: &str,
     health_score: u32,
+    classification: String,
     total_violations: u32,
-    pods: &[Pod],
+    workloads: &[WorkloadEvaluation],
+    planned_remediations: Option<Vec<PlannedRemediation>>,
+    sink: Option<&AuditSink>,
 ) -> anyhow::Result<()> {
     let audit_api: Api<PolicyAuditResult> = Api::namespaced(client.clone(), namespace);
 
-    // Collect detailed violations
-    let mut violations = Vec::new();
-    let mut total_pods: u32 = 0;
-    for pod in pods {
-        let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
-        if governance::is_system_namespace(ns) {
-            continue;
-        }
-        total_pods += 1;
-        let details = governance::detect_violations_detailed(pod, policy_spec);
-        for d in details {
-            violations.push(AuditViolation {
-                pod_name: d.pod_name,
-                container_name: d.container_name,
-                violation_type: d.violation_type,
-                severity: d.severity,
-                message: d.message,
-            });
-        }
-    }
-
-    let classification = governance::classify_health(health_score).to_string();
+    let total_pods = workloads.iter().map(|w| w.replicas).sum();
+    let violations = audit_violations(workloads);
 
-    let ts_millis = chrono::Utc::now().timestamp_millis();
-    let result_name = format!("{policy_name}-{ts_millis}");
-
-    let audit_result = PolicyAuditResult::new(
-        &result_name,
+    let mut audit_result = PolicyAuditResult::new(
+        result_name,
         PolicyAuditResultSpec {
             policy_name: policy_name.to_string(),
             cluster_name: None,
@@ -624,12 +2277,15 @@ async fn create_audit_result(
             health_score,
             total_violations,
             total_pods,
+            total_workloads: Some(workloads.len() as u32),
             classification,
             violations,
+            planned_remediations,
         },
     );
+    audit_result.metadata.owner_references = owner.map(|o| vec![o]);
 
-    audit_api.create(&Default::default(), &audit_result).await?;
+    let created = audit_api.create(&Default::default(), &audit_result).await?;
 
     AUDIT_RESULTS_TOTAL.inc();
 
@@ -639,42 +2295,229 @@ async fn create_audit_result(
         "audit_result_created"
     );
 
-    // Retention: keep last N results per policy
-    let existing = audit_api.list(&Default::default()).await?;
+    if let Some(sink) = sink
+        && let Err(e) = sink.export(&created).await
+    {
+        AUDIT_EXPORT_FAILURES.inc();
+        warn!(error = %e, audit_result = %result_name, "audit_export_failed");
+    }
 
-    let mut policy_results: Vec<_> = existing
-        .items
-        .iter()
-        .filter(|r| r.spec.policy_name == policy_name)
-        .collect();
+    Ok(())
+}
+
+/* ============================= AUDIT RETENTION ============================= */
+
+/// Retention limits for one policy's audit results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RetentionLimits {
+    max_count: usize,
+    max_age: Option<chrono::Duration>,
+}
+
+impl RetentionLimits {
+    /// Limits from the policy's `auditRetention`, falling back to the
+    /// operator's `--audit-retention` count and no age limit. At least one
+    /// result is always kept.
+    fn for_policy(spec: Option<&DevOpsPolicySpec>, default_count: usize) -> Self {
+        let retention = spec.and_then(|s| s.audit_retention.as_ref());
+        Self {
+            max_count: retention
+                .and_then(|r| r.max_count)
+                .map_or(default_count, |n| n as usize)
+                .max(1),
+            max_age: retention
+                .and_then(|r| r.max_age_days)
+                .map(|days| chrono::Duration::days(i64::from(days))),
+        }
+    }
+}
 
-    policy_results.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+/// Audit results past their policy's retention limits: per namespace and
+/// policy, everything beyond `max_count` newest plus anything older than
+/// `max_age`. Results whose timestamp does not parse are only count-limited.
+fn expired_audit_results(
+    results: &[PolicyAuditResult],
+    limits: impl Fn(&str, &str) -> RetentionLimits,
+    now: DateTime<Utc>,
+) -> Vec<&PolicyAuditResult> {
+    let mut by_policy: HashMap<(String, &str), Vec<&PolicyAuditResult>> = HashMap::new();
+    for result in results {
+        by_policy
+            .entry((
+                result.namespace().unwrap_or_default(),
+                &result.spec.policy_name,
+            ))
+            .or_default()
+            .push(result);
+    }
 
-    if policy_results.len() > AUDIT_RETENTION {
-        let to_delete = policy_results.len() - AUDIT_RETENTION;
-        for result in policy_results.iter().take(to_delete) {
-            let name = result.metadata.name.as_deref().unwrap_or_default();
-            if let Err(e) = audit_api.delete(name, &Default::default()).await {
-                warn!(error = %e, name = %name, "audit_result_delete_failed");
+    let mut expired = Vec::new();
+    for ((namespace, policy_name), mut group) in by_policy {
+        let limits = limits(&namespace, policy_name);
+        // Newest first
+        group.sort_by(|a, b| b.spec.timestamp.cmp(&a.spec.timestamp));
+        for (index, result) in group.into_iter().enumerate() {
+            let too_old = limits.max_age.is_some_and(|max_age| {
+                DateTime::parse_from_rfc3339(&result.spec.timestamp)
+                    .is_ok_and(|at| now.signed_duration_since(at) > max_age)
+            });
+            if index >= limits.max_count || too_old {
+                expired.push(result);
             }
         }
     }
+    expired.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+    expired
+}
 
-    Ok(())
+/// Delete audit results past their policy's retention. Returns the number
+/// deleted; individual delete failures are logged and retried next pass.
+async fn collect_audit_garbage(ctx: &ReconcileContext) -> Result<usize> {
+    let results = Api::<PolicyAuditResult>::all(ctx.client.clone())
+        .list(&Default::default())
+        .await
+        .context("Failed to list PolicyAuditResults")?
+        .items;
+    if results.is_empty() {
+        return Ok(0);
+    }
+    let policies = Api::<DevOpsPolicy>::all(ctx.client.clone())
+        .list(&Default::default())
+        .await
+        .context("Failed to list DevOpsPolicies")?
+        .items;
+    let cluster_policies = list_cluster_policies(ctx).await;
+
+    let specs: HashMap<(String, String), DevOpsPolicySpec> = policies
+        .into_iter()
+        .map(|p| {
+            let namespace = p.namespace().unwrap_or_default();
+            let spec = policy_resolution::resolve_effective_policy(
+                &namespace,
+                &cluster_policies,
+                Some(&p.spec),
+            )
+            .unwrap_or_else(|| p.spec.clone());
+            ((namespace, p.name_any()), spec)
+        })
+        .collect();
+    let default_count = ctx.settings.audit_retention;
+    let expired = expired_audit_results(
+        &results,
+        |namespace, policy| {
+            RetentionLimits::for_policy(
+                specs.get(&(namespace.to_string(), policy.to_string())),
+                default_count,
+            )
+        },
+        ctx.clock.now(),
+    );
+
+    let mut deleted = 0;
+    for result in expired {
+        let name = result.name_any();
+        let namespace = result.namespace().unwrap_or_default();
+        let api: Api<PolicyAuditResult> = Api::namespaced(ctx.client.clone(), &namespace);
+        match api.delete(&name, &Default::default()).await {
+            Ok(_) => deleted += 1,
+            Err(kube::Error::Api(e)) if e.code == 404 => {}
+            Err(e) => {
+                warn!(error = %e, name = %name, namespace = %namespace, "audit_result_delete_failed")
+            }
+        }
+    }
+    AUDIT_RESULTS_PRUNED.inc_by(deleted as u64);
+    Ok(deleted)
+}
+
+/// Run `collect_audit_garbage` every `audit_gc_interval` until shutdown.
+async fn run_audit_gc(ctx: Arc<ReconcileContext>, mut shutdown: broadcast::Receiver<()>) {
+    let mut interval = tokio::time::interval(ctx.settings.audit_gc_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = shutdown.recv() => return,
+        }
+        match collect_audit_garbage(&ctx).await {
+            Ok(0) => {}
+            Ok(deleted) => info!(deleted, "audit_results_pruned"),
+            Err(e) => warn!(error = %e, "audit_gc_failed"),
+        }
+    }
 }
 
 /* ============================= ERROR POLICY ============================= */
 
-fn error_policy(
-    _policy: Arc<DevOpsPolicy>,
-    error: &kube::Error,
-    _ctx: Arc<ReconcileContext>,
-) -> Action {
+fn error_policy<K>(_policy: Arc<K>, error: &kube::Error, _ctx: Arc<ReconcileContext>) -> Action {
     RECONCILE_ERRORS.inc();
     warn!(error = %error, "reconcile_error");
     Action::requeue(Duration::from_secs(60))
 }
 
+/* ============================= CRD AVAILABILITY ============================= */
+
+/// Backoff before the `attempt`-th (zero-based) re-check of a missing CRD:
+/// doubles from `CRD_RETRY_INITIAL` and is capped at `CRD_RETRY_MAX`.
+fn crd_retry_delay(attempt: u32) -> Duration {
+    let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+    CRD_RETRY_INITIAL.saturating_mul(factor).min(CRD_RETRY_MAX)
+}
+
+/// Whether a controller error means the watched type is not served by the
+/// API server, i.e. its CRD is not installed (or was removed).
+fn is_crd_missing<R>(error: &controller::Error<R, watcher::Error>) -> bool {
+    matches!(
+        error,
+        controller::Error::QueueError(
+            watcher::Error::InitialListFailed(kube::Error::Api(e))
+                | watcher::Error::WatchStartFailed(kube::Error::Api(e))
+        ) if e.code == 404
+    )
+}
+
+fn set_crd_available(crd: &str, available: bool) {
+    CRD_AVAILABLE
+        .with_label_values(&[crd])
+        .set(i64::from(available));
+}
+
+fn report_crd_missing(crd: &str) {
+    set_crd_available(crd, false);
+    warn!(crd = %crd, hint = "kube-devops crd install", "crd_not_installed");
+    eprintln!("[WARN] CRD {crd} is not installed. Run `kube-devops crd install` to install it.");
+}
+
+/// Wait until the CRD for `K` is served, re-checking with capped exponential
+/// backoff. Errors other than "not found" (RBAC, connectivity) are logged and
+/// retried the same way instead of ending the operator.
+async fn wait_for_crd<K>(client: &Client)
+where
+    K: kube::Resource<DynamicType = ()> + CustomResourceExt + Clone + DeserializeOwned + Debug,
+{
+    let crd = K::crd_name();
+    let api: Api<K> = Api::all(client.clone());
+    let mut attempt = 0;
+    loop {
+        match api.list(&ListParams::default().limit(1)).await {
+            Ok(_) => {
+                set_crd_available(crd, true);
+                if attempt > 0 {
+                    info!(crd = %crd, "crd_available");
+                    println!("[INFO] CRD {crd} is installed; starting controller.");
+                }
+                return;
+            }
+            Err(kube::Error::Api(e)) if e.code == 404 => report_crd_missing(crd),
+            Err(e) => warn!(crd = %crd, error = %e, "crd_check_failed"),
+        }
+        let delay = crd_retry_delay(attempt);
+        info!(crd = %crd, retry_in_secs = delay.as_secs(), "crd_check_retry");
+        tokio::time::sleep(delay).await;
+        attempt = attempt.saturating_add(1);
+    }
+}
+
 /* ============================= FINALIZER ============================= */
 
 fn has_finalizer(policy: &DevOpsPolicy) -> bool {
@@ -685,6 +2528,49 @@ fn has_finalizer(policy: &DevOpsPolicy) -> bool {
         .is_some_and(|f| f.iter().any(|s| s == FINALIZER))
 }
 
+/// JSON patch appending the cleanup finalizer. A merge patch would replace
+/// the whole list, so other controllers' finalizers are kept by appending,
+/// with a `test` on the list as read so a concurrent edit fails the patch.
+fn finalizer_add_patch(finalizers: Option<&[String]>) -> serde_json::Value {
+    match finalizers {
+        Some(current) if !current.is_empty() => serde_json::json!([
+            { "op": "test", "path": "/metadata/finalizers", "value": current },
+            { "op": "add", "path": "/metadata/finalizers/-", "value": FINALIZER },
+        ]),
+        _ => serde_json::json!([
+            { "op": "add", "path": "/metadata/finalizers", "value": [FINALIZER] },
+        ]),
+    }
+}
+
+/// JSON patch removing only the cleanup finalizer, or `None` when it is not
+/// in the list. The `test` makes the patch fail rather than remove another
+/// entry if the list changed since it was read.
+fn finalizer_removal_patch(finalizers: &[String]) -> Option<serde_json::Value> {
+    let index = finalizers.iter().position(|f| f == FINALIZER)?;
+    let path = format!("/metadata/finalizers/{index}");
+    Some(serde_json::json!([
+        { "op": "test", "path": path, "value": FINALIZER },
+        { "op": "remove", "path": path },
+    ]))
+}
+
+/// Send a JSON patch built by `finalizer_add_patch`/`finalizer_removal_patch`.
+async fn patch_finalizers(
+    api: &Api<DevOpsPolicy>,
+    name: &str,
+    patch: serde_json::Value,
+) -> std::result::Result<(), kube::Error> {
+    let patch = serde_json::from_value(patch).map_err(kube::Error::SerdeError)?;
+    api.patch(
+        name,
+        &PatchParams::apply("kube-devops-operator"),
+        &Patch::<()>::Json(patch),
+    )
+    .await?;
+    Ok(())
+}
+
 async fn add_finalizer(
     policy: &DevOpsPolicy,
     client: &Client,
@@ -693,18 +2579,8 @@ async fn add_finalizer(
     let namespace = policy.namespace().unwrap_or_default();
     let api: Api<DevOpsPolicy> = Api::namespaced(client.clone(), &namespace);
 
-    let patch = serde_json::json!({
-        "metadata": {
-            "finalizers": [FINALIZER]
-        }
-    });
-
-    api.patch(
-        &name,
-        &PatchParams::apply("kube-devops-operator"),
-        &Patch::Merge(&patch),
-    )
-    .await?;
+    let patch = finalizer_add_patch(policy.metadata.finalizers.as_deref());
+    patch_finalizers(&api, &name, patch).await?;
 
     info!(policy = %name, "finalizer_added");
     Ok(())
@@ -718,18 +2594,11 @@ async fn remove_finalizer(
     let namespace = policy.namespace().unwrap_or_default();
     let api: Api<DevOpsPolicy> = Api::namespaced(client.clone(), &namespace);
 
-    let patch = serde_json::json!({
-        "metadata": {
-            "finalizers": []
-        }
-    });
-
-    api.patch(
-        &name,
-        &PatchParams::apply("kube-devops-operator"),
-        &Patch::Merge(&patch),
-    )
-    .await?;
+    let finalizers = policy.metadata.finalizers.as_deref().unwrap_or_default();
+    let Some(patch) = finalizer_removal_patch(finalizers) else {
+        return Ok(());
+    };
+    patch_finalizers(&api, &name, patch).await?;
 
     info!(policy = %name, "finalizer_removed");
     Ok(())
@@ -738,16 +2607,45 @@ async fn remove_finalizer(
 async fn handle_deletion(
     policy: &DevOpsPolicy,
     client: &Client,
+    read_only: bool,
+    features: Features,
 ) -> std::result::Result<Action, kube::Error> {
     let name = policy.name_any();
     let namespace = policy.namespace().unwrap_or_default();
 
     info!(policy = %name, namespace = %namespace, "handling_deletion");
 
-    // Clear Prometheus metrics for this policy
-    let _ = POLICY_VIOLATIONS.remove_label_values(&[&namespace, &name]);
-    let _ = POLICY_HEALTH.remove_label_values(&[&namespace, &name]);
-    let _ = ENFORCEMENT_MODE.remove_label_values(&[&namespace, &name]);
+    // Clear Prometheus metrics for this policy
+    let _ = POLICY_VIOLATIONS.remove_label_values(&[&namespace, &name]);
+    let _ = POLICY_HEALTH.remove_label_values(&[&namespace, &name]);
+    for (classification, _) in threshold_levels(&Default::default()) {
+        let _ = HEALTH_THRESHOLD.remove_label_values(&[&namespace, &name, classification]);
+    }
+    let _ = ENFORCEMENT_MODE.remove_label_values(&[&namespace, &name]);
+    let _ = REMEDIATIONS_DEFERRED.remove_label_values(&[&namespace, &name]);
+
+    if read_only {
+        info!(policy = %name, "deletion_writes_skipped_read_only");
+        return Ok(Action::await_change());
+    }
+
+    // Optionally strip tracking annotations from workloads this policy patched.
+    // Failures are logged rather than blocking deletion on the finalizer.
+    if policy.spec.cleanup_on_delete.unwrap_or(false) && features.enforcement {
+        match enforcement::cleanup_patched_annotations(client, &namespace, &name).await {
+            Ok(cleaned) => info!(policy = %name, workloads = cleaned, "deletion_cleanup_complete"),
+            Err(e) => warn!(policy = %name, error = %e, "deletion_cleanup_failed"),
+        }
+    }
+
+    // Results created before they carried an owner reference are not
+    // garbage-collected, so delete them all while the finalizer holds.
+    if features.audit_results {
+        match delete_policy_audit_results(client, &namespace, &name).await {
+            Ok(deleted) => info!(policy = %name, deleted, "deletion_audit_results_deleted"),
+            Err(e) => warn!(policy = %name, error = %e, "deletion_audit_results_failed"),
+        }
+    }
 
     if has_finalizer(policy) {
         remove_finalizer(policy, client).await?;
@@ -756,6 +2654,36 @@ async fn handle_deletion(
     Ok(Action::await_change())
 }
 
+/// Audit results produced by the policy `policy_name`.
+fn results_of_policy<'a>(
+    results: &'a [PolicyAuditResult],
+    policy_name: &str,
+) -> Vec<&'a PolicyAuditResult> {
+    results
+        .iter()
+        .filter(|r| r.spec.policy_name == policy_name)
+        .collect()
+}
+
+/// Delete every audit result of a policy. Returns the number deleted.
+async fn delete_policy_audit_results(
+    client: &Client,
+    namespace: &str,
+    policy_name: &str,
+) -> std::result::Result<usize, kube::Error> {
+    let api: Api<PolicyAuditResult> = Api::namespaced(client.clone(), namespace);
+    let results = api.list(&Default::default()).await?.items;
+    let mut deleted = 0;
+    for result in results_of_policy(&results, policy_name) {
+        match api.delete(&result.name_any(), &Default::default()).await {
+            Ok(_) => deleted += 1,
+            Err(kube::Error::Api(e)) if e.code == 404 => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(deleted)
+}
+
 /* ============================= HTTP SERVER ============================= */
 
 pub(crate) fn build_reconcile_router(state: Arc<Mutex<ReconcileState>>) -> Router {
@@ -780,7 +2708,7 @@ async fn start_metrics_server(
 
     let listener = tokio::net::TcpListener::bind(addr)
         .await
-        .context("Failed to bind metrics server on :9090")?;
+        .with_context(|| format!("Failed to bind metrics server on {addr}"))?;
 
     info!(addr = %addr, "reconcile_metrics_server_started");
 
@@ -795,7 +2723,7 @@ async fn start_metrics_server(
 
 async fn reconcile_ready_handler(state: Arc<Mutex<ReconcileState>>) -> impl IntoResponse {
     let state = state.lock().await;
-    if state.ready {
+    if state.is_ready(Utc::now()) {
         (StatusCode::OK, "READY")
     } else {
         (StatusCode::SERVICE_UNAVAILABLE, "NOT READY")
@@ -835,8 +2763,13 @@ mod tests {
     use kube_devops::crd::DevOpsPolicySpec;
     use tower::ServiceExt;
 
+    use kube_devops::clock::SequentialIdGenerator;
+
     fn test_reconcile_state(ready: bool) -> Arc<Mutex<ReconcileState>> {
-        Arc::new(Mutex::new(ReconcileState { ready }))
+        Arc::new(Mutex::new(ReconcileState {
+            last_success: ready.then(Utc::now),
+            requeue_interval: ReconcileSettings::default().requeue_interval,
+        }))
     }
 
     fn make_test_pod(
@@ -999,17 +2932,213 @@ mod tests {
 
     #[test]
     fn test_status_message_format() {
-        let total_violations: u32 = 5;
-        let total_pods: u32 = 10;
-        let health_score: u32 = 72;
-        let classification = governance::classify_health(health_score);
-
-        let message = format!(
-            "{} violations across {} pods — {} ({})",
-            total_violations, total_pods, classification, health_score
+        let summary = EvaluationSummary {
+            health_score: 72,
+            classification: governance::classify_health(72).to_string(),
+            thresholds: Default::default(),
+            total_violations: 5,
+            total_pods: 10,
+            workloads: 4,
+            previous_score: None,
+            score_trend: None,
+        };
+
+        assert_eq!(
+            summary.message(),
+            "5 violations across 4 workloads (10 pods) — Stable (72)"
+        );
+    }
+
+    #[test]
+    fn test_summary_uses_policy_scoring_weights() {
+        let pods = vec![
+            make_test_pod("a", "prod", "nginx:latest", true, true, 0, "Running"),
+            make_test_pod("b", "prod", "nginx:1.25", true, true, 0, "Running"),
+        ];
+        let mut policy = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            ..Default::default()
+        };
+        let workloads = governance::evaluate_workloads(&pods, &policy);
+        // Default latest_tag weight 5 over 2 pods
+        assert_eq!(
+            EvaluationSummary::from_workloads(&workloads, &policy).health_score,
+            98
+        );
+
+        policy.scoring_weights = Some(kube_devops::crd::ScoringWeightOverrides {
+            latest_tag: Some(40),
+            ..Default::default()
+        });
+        let summary = EvaluationSummary::from_workloads(&workloads, &policy);
+        assert_eq!(summary.health_score, 80);
+        assert_eq!(summary.classification, "Healthy");
+    }
+
+    #[test]
+    fn test_summary_history_sets_trend_and_delta() {
+        let summary = EvaluationSummary::from_workloads(&[], &DevOpsPolicySpec::default());
+        assert_eq!(summary.health_score, 100);
+        assert_eq!(summary.score_delta(), 0);
+
+        let summary = summary.with_history(&[60, 75]);
+        assert_eq!(summary.previous_score, Some(75));
+        assert_eq!(summary.score_trend, Some(ScoreTrend::Improving));
+        assert_eq!(summary.score_delta(), 25);
+
+        let status = build_status(
+            None,
+            &summary,
+            &chrono::Utc::now(),
+            false,
+            Default::default(),
+        );
+        assert_eq!(status.previous_score, Some(75));
+        let patch = status_patch(&status);
+        assert_eq!(patch["status"]["scoreTrend"], "improving");
+        assert_eq!(patch["status"]["previousScore"], 75);
+    }
+
+    #[test]
+    fn test_build_status_enforcement_fields() {
+        let pods = vec![
+            make_test_pod("a", "prod", "nginx:latest", false, true, 0, "Running"),
+            make_test_pod("b", "prod", "nginx:1.25", true, true, 0, "Running"),
+        ];
+        let policy = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            require_liveness_probe: Some(true),
+            ..Default::default()
+        };
+        let workloads = governance::evaluate_workloads(&pods, &policy);
+        let summary = EvaluationSummary::from_workloads(&workloads, &policy);
+        assert_eq!(summary.total_pods, 2);
+        assert_eq!(summary.total_violations, 2);
+
+        let now = chrono::Utc::now();
+        let audit = build_status(Some(2), &summary, &now, false, Default::default());
+        assert_eq!(audit.observed_generation, Some(2));
+        assert_eq!(audit.remediations_applied, None);
+        assert_eq!(
+            audit.classification.as_deref(),
+            Some(summary.classification.as_str())
+        );
+
+        let outcome = EnforcementOutcome {
+            applied: 1,
+            failed: 0,
+            remediated_workloads: vec!["deployments/web".to_string()],
+            failures: vec![],
+            planned: None,
+        };
+        let enforced = build_status(Some(2), &summary, &now, true, outcome);
+        assert_eq!(enforced.remediations_applied, Some(1));
+        assert_eq!(enforced.remediations_failed, Some(0));
+        assert_eq!(
+            enforced.remediated_workloads,
+            Some(vec!["deployments/web".to_string()])
+        );
+        assert_eq!(
+            status_patch(&enforced)["status"]["plannedRemediations"],
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn test_build_status_dry_run_records_plan() {
+        let summary = EvaluationSummary::from_workloads(&[], &DevOpsPolicySpec::default());
+        let planned = vec![PlannedRemediation {
+            workload: "Deployment/web".to_string(),
+            actions: vec![kube_devops::crd::PlannedAction {
+                action: "injectLivenessProbe".to_string(),
+                container: "app".to_string(),
+            }],
+        }];
+        let outcome = EnforcementOutcome {
+            planned: Some(planned.clone()),
+            ..Default::default()
+        };
+        let status = build_status(Some(1), &summary, &chrono::Utc::now(), false, outcome);
+        assert_eq!(status.remediations_applied, None);
+        assert_eq!(status.planned_remediations, Some(planned));
+
+        let patch = status_patch(&status);
+        assert_eq!(
+            patch["status"]["plannedRemediations"][0]["workload"],
+            "Deployment/web"
+        );
+    }
+
+    #[test]
+    fn test_status_uses_health_thresholds() {
+        let mut spec = all_enabled_policy();
+        spec.health_thresholds = Some(kube_devops::crd::HealthThresholds {
+            healthy: Some(90),
+            stable: Some(70),
+            degraded: None,
+        });
+        let mut summary = EvaluationSummary::from_workloads(&[], &spec);
+        summary.health_score = 85;
+        summary.classification = summary.thresholds.classify(85).to_string();
+        let status = build_status(
+            Some(1),
+            &summary,
+            &chrono::Utc::now(),
+            false,
+            Default::default(),
         );
+        assert_eq!(status.healthy, Some(false));
+        assert_eq!(status.classification.as_deref(), Some("Stable"));
+        let thresholds = status.health_thresholds.unwrap();
+        assert_eq!(
+            (thresholds.healthy, thresholds.stable, thresholds.degraded),
+            (Some(90), Some(70), Some(40))
+        );
+    }
+
+    #[test]
+    fn test_status_changed_ignores_last_evaluated() {
+        let summary = EvaluationSummary {
+            health_score: 90,
+            classification: "Healthy".to_string(),
+            thresholds: Default::default(),
+            total_violations: 2,
+            total_pods: 10,
+            workloads: 4,
+            previous_score: Some(90),
+            score_trend: None,
+        };
+        let earlier: chrono::DateTime<chrono::Utc> = "2026-01-01T00:00:00Z".parse().unwrap();
+        let stored = build_status(Some(1), &summary, &earlier, false, Default::default());
+        let next = build_status(
+            Some(1),
+            &summary,
+            &chrono::Utc::now(),
+            false,
+            Default::default(),
+        );
+        assert!(!status_changed(Some(&stored), &next));
+        assert!(status_changed(None, &next));
 
-        assert_eq!(message, "5 violations across 10 pods — Stable (72)");
+        for changed in [
+            EvaluationSummary {
+                health_score: 85,
+                ..summary.clone()
+            },
+            EvaluationSummary {
+                total_violations: 3,
+                ..summary.clone()
+            },
+            EvaluationSummary {
+                total_pods: 11,
+                ..summary.clone()
+            },
+        ] {
+            let next = build_status(Some(1), &changed, &earlier, false, Default::default());
+            assert!(status_changed(Some(&stored), &next));
+        }
+        let next_generation = build_status(Some(2), &summary, &earlier, false, Default::default());
+        assert!(status_changed(Some(&stored), &next_generation));
     }
 
     #[test]
@@ -1019,11 +3148,16 @@ mod tests {
             healthy: Some(true),
             health_score: Some(95),
             violations: Some(2),
+            classification: Some("Healthy".to_string()),
+            health_thresholds: None,
+            previous_score: None,
+            score_trend: None,
             last_evaluated: Some("2026-01-01T00:00:00Z".to_string()),
             message: Some("2 violations across 20 pods — Healthy (95)".to_string()),
             remediations_applied: None,
             remediations_failed: None,
             remediated_workloads: None,
+            planned_remediations: None,
         };
 
         assert_eq!(status.observed_generation, Some(3));
@@ -1034,6 +3168,56 @@ mod tests {
         assert!(status.message.unwrap().contains("Healthy"));
     }
 
+    // ── Re-evaluation ──
+
+    fn policy_in(namespace: &str, name: &str) -> Arc<DevOpsPolicy> {
+        let mut policy = DevOpsPolicy::new(name, all_enabled_policy());
+        policy.metadata.namespace = Some(namespace.to_string());
+        Arc::new(policy)
+    }
+
+    #[test]
+    fn test_policies_in_namespace() {
+        let policies = [
+            policy_in("prod", "strict"),
+            policy_in("staging", "baseline"),
+            policy_in("prod", "extra"),
+        ];
+        let names: Vec<_> = policies_in_namespace("prod", &policies)
+            .into_iter()
+            .map(|r| (r.namespace.unwrap(), r.name))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("prod".to_string(), "strict".to_string()),
+                ("prod".to_string(), "extra".to_string())
+            ]
+        );
+        assert!(policies_in_namespace("dev", &policies).is_empty());
+    }
+
+    #[test]
+    fn test_reevaluation_due() {
+        let now: DateTime<Utc> = "2026-03-01T12:00:00Z".parse().unwrap();
+        let mut spec = all_enabled_policy();
+        assert!(reevaluation_due(&spec, None, now));
+        let recent = now - chrono::Duration::seconds(120);
+        assert!(!reevaluation_due(&spec, Some(recent), now));
+        assert!(reevaluation_due(
+            &spec,
+            Some(now - chrono::Duration::seconds(300)),
+            now
+        ));
+
+        spec.reevaluation_interval = Some(60);
+        assert!(reevaluation_due(&spec, Some(recent), now));
+        spec.reevaluation_interval = Some(u64::MAX);
+        assert!(!reevaluation_due(&spec, Some(recent), now));
+        spec.reevaluation_interval = Some(10_000_000_000_000_000);
+        assert!(!reevaluation_due(&spec, Some(recent), now));
+    }
+
     // ── Finalizer detection ──
 
     #[test]
@@ -1096,6 +3280,429 @@ mod tests {
         assert!(!has_finalizer(&policy));
     }
 
+    #[test]
+    fn test_finalizer_removal_patch_keeps_other_finalizers() {
+        let finalizers = vec![
+            "example.com/backup".to_string(),
+            FINALIZER.to_string(),
+            "example.com/audit".to_string(),
+        ];
+        let patch = finalizer_removal_patch(&finalizers).unwrap();
+        assert_eq!(
+            patch,
+            serde_json::json!([
+                { "op": "test", "path": "/metadata/finalizers/1", "value": FINALIZER },
+                { "op": "remove", "path": "/metadata/finalizers/1" },
+            ])
+        );
+
+        // Applying it leaves the third-party entries in order
+        let mut doc = serde_json::json!({ "metadata": { "finalizers": finalizers } });
+        let patch: json_patch::Patch = serde_json::from_value(patch).unwrap();
+        json_patch::patch(&mut doc, &patch).unwrap();
+        assert_eq!(
+            doc["metadata"]["finalizers"],
+            serde_json::json!(["example.com/backup", "example.com/audit"])
+        );
+
+        assert!(finalizer_removal_patch(&["example.com/backup".to_string()]).is_none());
+        assert!(finalizer_removal_patch(&[]).is_none());
+    }
+
+    #[test]
+    fn test_finalizer_removal_patch_fails_when_list_changed() {
+        let read = vec!["example.com/backup".to_string(), FINALIZER.to_string()];
+        let patch: json_patch::Patch =
+            serde_json::from_value(finalizer_removal_patch(&read).unwrap()).unwrap();
+        // Another controller removed its entry in the meantime
+        let mut doc = serde_json::json!({ "metadata": { "finalizers": [FINALIZER] } });
+        assert!(json_patch::patch(&mut doc, &patch).is_err());
+    }
+
+    #[test]
+    fn test_finalizer_add_patch_appends() {
+        assert_eq!(
+            finalizer_add_patch(None),
+            serde_json::json!([
+                { "op": "add", "path": "/metadata/finalizers", "value": [FINALIZER] },
+            ])
+        );
+        let current = vec!["example.com/backup".to_string()];
+        let patch: json_patch::Patch =
+            serde_json::from_value(finalizer_add_patch(Some(&current))).unwrap();
+        let mut doc = serde_json::json!({ "metadata": { "finalizers": current } });
+        json_patch::patch(&mut doc, &patch).unwrap();
+        assert_eq!(
+            doc["metadata"]["finalizers"],
+            serde_json::json!(["example.com/backup", FINALIZER])
+        );
+    }
+
+    // ── Audit result naming ──
+
+    #[test]
+    fn test_audit_result_name_uses_generator() {
+        let ids = SequentialIdGenerator::new("abc");
+        assert_eq!(audit_result_name("web-policy", &ids), "web-policy-abc0");
+        assert_eq!(audit_result_name("web-policy", &ids), "web-policy-abc1");
+    }
+
+    #[test]
+    fn test_audit_result_names_unique_across_replicas() {
+        let replica_a = HashIdGenerator::new("operator-0");
+        let replica_b = HashIdGenerator::new("operator-1");
+        let a = audit_result_name("web-policy", &replica_a);
+        let b = audit_result_name("web-policy", &replica_b);
+        assert_ne!(a, b);
+        assert!(a.starts_with("web-policy-"));
+    }
+
+    // ── Audit retention ──
+
+    fn stored_result(
+        namespace: &str,
+        name: &str,
+        policy: &str,
+        days_ago: i64,
+    ) -> PolicyAuditResult {
+        let at = Utc::now() - chrono::Duration::days(days_ago);
+        let mut result = PolicyAuditResult::new(
+            name,
+            PolicyAuditResultSpec {
+                policy_name: policy.to_string(),
+                cluster_name: None,
+                timestamp: at.to_rfc3339(),
+                health_score: 90,
+                total_violations: 0,
+                total_pods: 1,
+                total_workloads: None,
+                classification: "Healthy".to_string(),
+                violations: vec![],
+                planned_remediations: None,
+            },
+        );
+        result.metadata.namespace = Some(namespace.to_string());
+        result
+    }
+
+    fn expired_names(results: &[PolicyAuditResult], limits: RetentionLimits) -> Vec<String> {
+        expired_audit_results(results, |_, _| limits, Utc::now())
+            .into_iter()
+            .map(|r| r.name_any())
+            .collect()
+    }
+
+    #[test]
+    fn test_retention_limits_from_policy() {
+        let default = RetentionLimits::for_policy(None, 10);
+        assert_eq!(default.max_count, 10);
+        assert_eq!(default.max_age, None);
+
+        let spec = DevOpsPolicySpec {
+            audit_retention: Some(kube_devops::crd::AuditRetention {
+                max_count: Some(0),
+                max_age_days: Some(30),
+            }),
+            ..Default::default()
+        };
+        let limits = RetentionLimits::for_policy(Some(&spec), 10);
+        assert_eq!(limits.max_count, 1, "the newest result is always kept");
+        assert_eq!(limits.max_age, Some(chrono::Duration::days(30)));
+    }
+
+    #[test]
+    fn test_expired_audit_results_by_count_per_policy() {
+        let results = vec![
+            stored_result("prod", "web-3", "web", 1),
+            stored_result("prod", "web-1", "web", 3),
+            stored_result("prod", "web-2", "web", 2),
+            stored_result("prod", "api-1", "api", 5),
+            stored_result("dev", "web-dev", "web", 9),
+        ];
+        let limits = RetentionLimits {
+            max_count: 1,
+            max_age: None,
+        };
+        assert_eq!(expired_names(&results, limits), ["web-1", "web-2"]);
+    }
+
+    #[test]
+    fn test_expired_audit_results_by_age() {
+        let results = vec![
+            stored_result("prod", "new", "web", 1),
+            stored_result("prod", "old", "web", 40),
+            stored_result("prod", "older", "web", 60),
+        ];
+        let limits = RetentionLimits {
+            max_count: 10,
+            max_age: Some(chrono::Duration::days(30)),
+        };
+        assert_eq!(expired_names(&results, limits), ["older", "old"]);
+    }
+
+    #[test]
+    fn test_results_of_policy() {
+        let results = vec![
+            stored_result("prod", "web-1", "web", 2),
+            stored_result("prod", "api-1", "api", 1),
+            stored_result("prod", "web-2", "web", 1),
+        ];
+        let names: Vec<String> = results_of_policy(&results, "web")
+            .iter()
+            .map(|r| r.name_any())
+            .collect();
+        assert_eq!(names, ["web-1", "web-2"]);
+        assert!(results_of_policy(&results, "gone").is_empty());
+    }
+
+    #[test]
+    fn test_audit_owner_reference() {
+        let mut policy = DevOpsPolicy::new("web", DevOpsPolicySpec::default());
+        assert!(policy.controller_owner_ref(&()).is_none(), "needs a uid");
+        policy.metadata.uid = Some("0f1c".to_string());
+        let owner = policy.controller_owner_ref(&()).unwrap();
+        assert_eq!(owner.api_version, "devops.stochastic.io/v1");
+        assert_eq!(owner.kind, "DevOpsPolicy");
+        assert_eq!(owner.name, "web");
+        assert_eq!(owner.uid, "0f1c");
+        assert_eq!(owner.controller, Some(true));
+        // blockOwnerDeletion would need update on devopspolicies/finalizers
+        assert_eq!(owner.block_owner_deletion, None);
+    }
+
+    #[test]
+    fn test_expired_audit_results_uses_per_policy_limits() {
+        let results = vec![
+            stored_result("prod", "web-1", "web", 2),
+            stored_result("prod", "web-2", "web", 1),
+            stored_result("prod", "api-1", "api", 2),
+            stored_result("prod", "api-2", "api", 1),
+        ];
+        let expired = expired_audit_results(
+            &results,
+            |_, policy| RetentionLimits {
+                max_count: if policy == "web" { 1 } else { 5 },
+                max_age: None,
+            },
+            Utc::now(),
+        );
+        let names: Vec<String> = expired.iter().map(|r| r.name_any()).collect();
+        assert_eq!(names, ["web-1"]);
+    }
+
+    // ── Workload aggregation ──
+
+    #[test]
+    fn test_audit_violations_tagged_with_workload() {
+        let mut replica_a = make_test_pod(
+            "web-abc12-x1",
+            "prod",
+            "nginx:latest",
+            false,
+            true,
+            0,
+            "Running",
+        );
+        let mut replica_b = make_test_pod(
+            "web-abc12-x2",
+            "prod",
+            "nginx:latest",
+            false,
+            true,
+            0,
+            "Running",
+        );
+        for pod in [&mut replica_a, &mut replica_b] {
+            pod.metadata.owner_references = Some(vec![
+                k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference {
+                    kind: "ReplicaSet".to_string(),
+                    name: "web-abc12".to_string(),
+                    ..Default::default()
+                },
+            ]);
+        }
+
+        let workloads =
+            governance::evaluate_workloads(&[replica_a, replica_b], &all_enabled_policy());
+        let violations = audit_violations(&workloads);
+
+        // latest_tag + missing_liveness, once for the Deployment rather than per replica
+        assert_eq!(violations.len(), 2);
+        assert!(
+            violations
+                .iter()
+                .all(|v| v.workload.as_deref() == Some("Deployment/web"))
+        );
+    }
+
+    #[test]
+    fn test_evaluate_cluster_workloads_uses_namespace_profiles() {
+        let pods = vec![
+            make_test_pod("a", "dev", "app:latest", true, true, 0, "Running"),
+            make_test_pod("b", "prod", "app:latest", true, true, 0, "Running"),
+        ];
+        let base = DevOpsPolicySpec::default();
+        let strict = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            ..Default::default()
+        };
+
+        let plain = evaluate_cluster_workloads(
+            &pods,
+            &[],
+            &[],
+            &ReplicatedWorkloads::default(),
+            &base,
+            &HashMap::new(),
+            Utc::now(),
+        );
+        assert!(plain.iter().all(|w| w.violations.is_empty()));
+
+        let profiles = HashMap::from([("prod".to_string(), strict)]);
+        let workloads = evaluate_cluster_workloads(
+            &pods,
+            &[],
+            &[],
+            &ReplicatedWorkloads::default(),
+            &base,
+            &profiles,
+            Utc::now(),
+        );
+        assert_eq!(workloads.len(), 2);
+        assert_eq!(workloads[0].namespace, "dev");
+        assert!(workloads[0].violations.is_empty());
+        assert_eq!(workloads[1].namespace, "prod");
+        assert_eq!(workloads[1].violations.len(), 1);
+    }
+
+    #[test]
+    fn test_cluster_workloads_age_pending_pods_with_the_clock() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+        use kube_devops::clock::FixedClock;
+
+        let created = chrono::DateTime::parse_from_rfc3339("2026-03-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let mut pod = make_test_pod("a", "prod", "app:1.0", true, true, 0, "Pending");
+        pod.metadata.creation_timestamp = Some(Time(created));
+        let spec = DevOpsPolicySpec {
+            forbid_pending_duration: Some(300),
+            ..Default::default()
+        };
+        let clock = FixedClock::new(created + chrono::Duration::seconds(60));
+        let pending = |now| {
+            evaluate_cluster_workloads(
+                std::slice::from_ref(&pod),
+                &[],
+                &[],
+                &ReplicatedWorkloads::default(),
+                &spec,
+                &HashMap::new(),
+                now,
+            )[0]
+            .metrics
+            .pending
+        };
+
+        assert_eq!(pending(clock.now()), 0);
+        clock.advance(chrono::Duration::seconds(600));
+        assert_eq!(pending(clock.now()), 1);
+    }
+
+    // ── Paged listing ──
+
+    fn page(names: &[&str], token: Option<&str>) -> ObjectList<Pod> {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::{
+            ListMeta, ManagedFieldsEntry, ObjectMeta,
+        };
+        ObjectList {
+            types: Default::default(),
+            metadata: ListMeta {
+                continue_: token.map(str::to_string),
+                ..Default::default()
+            },
+            items: names
+                .iter()
+                .map(|name| Pod {
+                    metadata: ObjectMeta {
+                        name: Some(name.to_string()),
+                        managed_fields: Some(vec![ManagedFieldsEntry::default()]),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_follows_continue_tokens() {
+        let mut requests = Vec::new();
+        let pods = collect_pages(
+            2,
+            |params: ListParams| {
+                requests.push((params.limit, params.continue_token.clone()));
+                let page = match params.continue_token.as_deref() {
+                    None => page(&["a", "b"], Some("next")),
+                    Some(_) => page(&["c", "skip"], Some("")),
+                };
+                async move { Ok(page) }
+            },
+            |pod: &Pod| pod.name_any() != "skip",
+        )
+        .await
+        .unwrap();
+
+        let names: Vec<_> = pods.iter().map(|p| p.name_any()).collect();
+        assert_eq!(names, ["a", "b", "c"]);
+        assert!(pods.iter().all(|p| p.metadata.managed_fields.is_none()));
+        assert_eq!(
+            requests,
+            [(Some(2), None), (Some(2), Some("next".to_string()))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_restarts_on_expired_token() {
+        let mut calls = 0;
+        let pods = collect_pages(
+            1,
+            |params: ListParams| {
+                calls += 1;
+                let result = match (calls, params.continue_token.as_deref()) {
+                    (2, Some(_)) => Err(api_error(410)),
+                    (_, None) => Ok(page(&["a"], Some("next"))),
+                    (_, Some(_)) => Ok(page(&["b"], None)),
+                };
+                async move { result }
+            },
+            |_: &Pod| true,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(calls, 4);
+        let names: Vec<_> = pods.iter().map(|p| p.name_any()).collect();
+        assert_eq!(names, ["a", "b"]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_gives_up_after_restarts() {
+        let result = collect_pages(
+            1,
+            |params: ListParams| {
+                let result = match params.continue_token {
+                    None => Ok(page(&["a"], Some("next"))),
+                    Some(_) => Err(api_error(410)),
+                };
+                async move { result }
+            },
+            |_: &Pod| true,
+        )
+        .await;
+        assert!(matches!(result, Err(kube::Error::Api(e)) if e.code == 410));
+    }
+
     // ── Deletion detection ──
 
     #[test]
@@ -1222,4 +3829,159 @@ mod tests {
             "reconcile_duration_seconds should be registered"
         );
     }
+
+    #[test]
+    fn test_last_successful_reconcile_metric_registered() {
+        LazyLock::force(&LAST_SUCCESSFUL_RECONCILE);
+        let families = REGISTRY.gather();
+        assert!(
+            families
+                .iter()
+                .any(|f| f.get_name() == "devopspolicy_last_successful_reconcile_timestamp_seconds")
+        );
+    }
+
+    // ── Readiness ──
+
+    #[test]
+    fn test_ready_only_within_stale_window() {
+        let now = Utc::now();
+        let state = |ago: Option<i64>| ReconcileState {
+            last_success: ago.map(|secs| now - chrono::Duration::seconds(secs)),
+            requeue_interval: Duration::from_secs(20),
+        };
+        let window = 20 * READY_STALE_INTERVALS as i64;
+        assert!(!state(None).is_ready(now));
+        assert!(state(Some(0)).is_ready(now));
+        assert!(state(Some(window)).is_ready(now));
+        assert!(!state(Some(window + 1)).is_ready(now));
+        // A clock step backwards does not make the operator unready
+        assert!(state(Some(-5)).is_ready(now));
+    }
+
+    // ── CRD availability ──
+
+    fn api_error(code: u16) -> kube::Error {
+        kube::Error::Api(kube::error::ErrorResponse {
+            status: "Failure".to_string(),
+            message: "the server could not find the requested resource".to_string(),
+            reason: "NotFound".to_string(),
+            code,
+        })
+    }
+
+    #[test]
+    fn test_crd_retry_delay_doubles_and_caps() {
+        assert_eq!(crd_retry_delay(0), CRD_RETRY_INITIAL);
+        assert_eq!(crd_retry_delay(1), CRD_RETRY_INITIAL * 2);
+        assert_eq!(crd_retry_delay(3), CRD_RETRY_INITIAL * 8);
+        assert_eq!(crd_retry_delay(10), CRD_RETRY_MAX);
+        assert_eq!(crd_retry_delay(u32::MAX), CRD_RETRY_MAX);
+    }
+
+    #[test]
+    fn test_is_crd_missing_on_list_not_found() {
+        let err: controller::Error<kube::Error, watcher::Error> =
+            controller::Error::QueueError(watcher::Error::InitialListFailed(api_error(404)));
+        assert!(is_crd_missing(&err));
+
+        let err: controller::Error<kube::Error, watcher::Error> =
+            controller::Error::QueueError(watcher::Error::WatchStartFailed(api_error(404)));
+        assert!(is_crd_missing(&err));
+    }
+
+    #[test]
+    fn test_is_crd_missing_ignores_other_errors() {
+        let forbidden: controller::Error<kube::Error, watcher::Error> =
+            controller::Error::QueueError(watcher::Error::InitialListFailed(api_error(403)));
+        assert!(!is_crd_missing(&forbidden));
+
+        let reconcile_404: controller::Error<kube::Error, watcher::Error> =
+            controller::Error::ReconcilerFailed(
+                api_error(404),
+                kube::runtime::reflector::ObjectRef::<DevOpsPolicy>::new("p")
+                    .within("ns")
+                    .erase(),
+            );
+        assert!(!is_crd_missing(&reconcile_404));
+    }
+
+    #[test]
+    fn test_crd_available_gauge_tracks_state() {
+        let crd = "test.crd.devops.stochastic.io";
+        set_crd_available(crd, true);
+        assert_eq!(CRD_AVAILABLE.with_label_values(&[crd]).get(), 1);
+        report_crd_missing(crd);
+        assert_eq!(CRD_AVAILABLE.with_label_values(&[crd]).get(), 0);
+
+        let families = REGISTRY.gather();
+        assert!(
+            families
+                .iter()
+                .any(|f| f.get_name() == "devopspolicy_crd_available")
+        );
+    }
+
+    // ── Pod eviction ──
+
+    #[test]
+    fn test_eviction_result_labels() {
+        assert_eq!(eviction_result_label(&EvictionOutcome::Evicted), "evicted");
+        assert_eq!(
+            eviction_result_label(&EvictionOutcome::BlockedByDisruptionBudget),
+            "blocked_by_pdb"
+        );
+        assert_eq!(
+            eviction_result_label(&EvictionOutcome::NotFound),
+            "not_found"
+        );
+        assert_eq!(
+            eviction_result_label(&EvictionOutcome::Failed("boom".to_string())),
+            "failed"
+        );
+    }
+
+    // ── Timezone ──
+
+    #[test]
+    fn test_policy_timezone_falls_back_to_default() {
+        let local = Timezone::Local;
+        let mut spec = DevOpsPolicySpec::default();
+        assert_eq!(policy_timezone(&spec, local), Timezone::Local);
+        spec.timezone = Some("+05:30".to_string());
+        assert_eq!(policy_timezone(&spec, local).to_string(), "+05:30");
+        spec.timezone = Some("Europe/Berlin".to_string());
+        assert_eq!(policy_timezone(&spec, local), Timezone::Local);
+    }
+
+    // ── Remediation events ──
+
+    #[test]
+    fn test_remediation_event_note_lists_changes_within_limit() {
+        let change = |path: &str| enforcement::FieldChange {
+            container: "app".to_string(),
+            path: path.to_string(),
+            before: serde_json::Value::Null,
+            after: serde_json::json!("500m"),
+        };
+        let mut result = enforcement::RemediationResult {
+            workload: enforcement::WorkloadRef {
+                kind: "Deployment".to_string(),
+                name: "web".to_string(),
+                namespace: "prod".to_string(),
+            },
+            success: true,
+            message: "Applied 1 remediation(s) to deployment/prod/web".to_string(),
+            changes: vec![change("resources.limits.cpu")],
+        };
+        assert_eq!(
+            remediation_event_note(&result),
+            "Applied 1 remediation(s) to deployment/prod/web\napp.resources.limits.cpu: null -> \"500m\""
+        );
+
+        result.changes = (0..100).map(|i| change(&format!("field{i}"))).collect();
+        let note = remediation_event_note(&result);
+        assert!(note.len() <= EVENT_NOTE_LIMIT);
+        assert!(note.ends_with("\n..."));
+    }
 }
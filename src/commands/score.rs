@@ -0,0 +1,790 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+use k8s_openapi::api::core::v1::{Namespace, Pod};
+use kube::{Api, ResourceExt};
+use serde::Serialize;
+
+use kube_devops::crd::{
+    ClusterDevOpsPolicy, DevOpsPolicy, DevOpsPolicySpec, PolicyAuditResult, Severity,
+    SeverityOverrides,
+};
+use kube_devops::governance::{
+    self, ClassificationThresholds, PodMetrics, ScoringCaps, ScoringWeights,
+};
+use kube_devops::output::{self, OutputFormat};
+use kube_devops::policy_resolution;
+
+use crate::commands::analyze;
+use crate::commands::client;
+
+/* ============================= TYPES ============================= */
+
+/// Proposed scoring settings for a simulation run.
+#[derive(Debug, Clone, Default)]
+pub struct Proposal {
+    pub weights: ScoringWeights,
+    /// When set, severity multipliers are applied using these overrides.
+    pub severity: Option<SeverityOverrides>,
+}
+
+/// Outcome of re-scoring one audit result.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedResult {
+    pub name: String,
+    pub policy_name: String,
+    pub timestamp: String,
+    pub baseline_score: u32,
+    pub baseline_classification: String,
+    pub simulated_score: u32,
+    pub simulated_classification: String,
+    pub classification_changed: bool,
+}
+
+/// Health of one namespace under its effective policy.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NamespaceScore {
+    pub namespace: String,
+    /// DevOpsPolicy name, `cluster` (ClusterDevOpsPolicies only), or `defaults`.
+    pub policy: String,
+    pub health_score: u32,
+    pub classification: String,
+    pub workloads: usize,
+    pub pods: u32,
+    pub violations: u32,
+}
+
+/* ============================= PARSING ============================= */
+
+/// Parse `latest_tag=3,pending=2` into weights (unspecified checks keep defaults).
+pub fn parse_weights(spec: &str) -> Result<ScoringWeights> {
+    let mut weights = ScoringWeights::default();
+    for (key, value) in parse_pairs(spec)? {
+        let value: u32 = value
+            .parse()
+            .with_context(|| format!("Invalid weight '{value}' for '{key}'"))?;
+        match key {
+            "latest_tag" => weights.latest_tag = value,
+            "missing_liveness" => weights.missing_liveness = value,
+            "missing_readiness" => weights.missing_readiness = value,
+            "high_restarts" => weights.high_restarts = value,
+            "pending" => weights.pending = value,
+            "missing_resources" => weights.missing_resources = value,
+            "crash_loop" => weights.crash_loop = value,
+            "image_pull_error" => weights.image_pull_error = value,
+            "missing_metadata" => weights.missing_metadata = value,
+            "missing_pdb" => weights.missing_pdb = value,
+            "no_spread" => weights.no_spread = value,
+            "unpinned_image" => weights.unpinned_image = value,
+            "pull_policy" => weights.pull_policy = value,
+            other => anyhow::bail!("Unknown check '{other}'"),
+        }
+    }
+    Ok(weights)
+}
+
+/// Parse `latest_tag=critical,pending=low` into severity overrides.
+pub fn parse_severity(spec: &str) -> Result<SeverityOverrides> {
+    let mut overrides = SeverityOverrides::default();
+    for (key, value) in parse_pairs(spec)? {
+        let severity: Severity = value.parse().map_err(|e: String| anyhow::anyhow!(e))?;
+        match key {
+            "latest_tag" => overrides.latest_tag = Some(severity),
+            "missing_liveness" => overrides.missing_liveness = Some(severity),
+            "missing_readiness" => overrides.missing_readiness = Some(severity),
+            "high_restarts" => overrides.high_restarts = Some(severity),
+            "pending" => overrides.pending = Some(severity),
+            "missing_resources" => overrides.missing_resources = Some(severity),
+            "crash_loop" => overrides.crash_loop = Some(severity),
+            "image_pull_error" => overrides.image_pull_error = Some(severity),
+            "missing_metadata" => overrides.missing_metadata = Some(severity),
+            "missing_pdb" => overrides.missing_pdb = Some(severity),
+            "no_spread" => overrides.no_spread = Some(severity),
+            "unpinned_image" => overrides.unpinned_image = Some(severity),
+            "pull_policy" => overrides.pull_policy = Some(severity),
+            other => anyhow::bail!("Unknown check '{other}'"),
+        }
+    }
+    Ok(overrides)
+}
+
+/// Parse `missing_readiness=20,pending=10` into per-check point caps.
+pub fn parse_caps(spec: &str) -> Result<ScoringCaps> {
+    let mut caps = ScoringCaps::default();
+    for (key, value) in parse_pairs(spec)? {
+        let value: u32 = value
+            .parse()
+            .with_context(|| format!("Invalid cap '{value}' for '{key}'"))?;
+        match key {
+            "latest_tag" => caps.latest_tag = Some(value),
+            "missing_liveness" => caps.missing_liveness = Some(value),
+            "missing_readiness" => caps.missing_readiness = Some(value),
+            "high_restarts" => caps.high_restarts = Some(value),
+            "pending" => caps.pending = Some(value),
+            "missing_resources" => caps.missing_resources = Some(value),
+            "crash_loop" => caps.crash_loop = Some(value),
+            "image_pull_error" => caps.image_pull_error = Some(value),
+            "missing_metadata" => caps.missing_metadata = Some(value),
+            "missing_pdb" => caps.missing_pdb = Some(value),
+            "no_spread" => caps.no_spread = Some(value),
+            "unpinned_image" => caps.unpinned_image = Some(value),
+            "pull_policy" => caps.pull_policy = Some(value),
+            other => anyhow::bail!("Unknown check '{other}'"),
+        }
+    }
+    Ok(caps)
+}
+
+pub(crate) fn parse_pairs(spec: &str) -> Result<Vec<(&str, &str)>> {
+    spec.split(',')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(k, v)| (k.trim(), v.trim()))
+                .ok_or_else(|| anyhow::anyhow!("Expected key=value, got '{pair}'"))
+        })
+        .collect()
+}
+
+/* ============================= SIMULATION ============================= */
+
+/// Re-score an audit result under the proposal.
+///
+/// The baseline is the score and classification the operator recorded; the
+/// simulated score is recomputed from the recorded violations, averaged over
+/// workloads as the operator does (`totalPods` for results without
+/// `totalWorkloads`), and classified with the policy's `thresholds`.
+pub fn simulate(
+    result: &PolicyAuditResult,
+    proposal: &Proposal,
+    thresholds: &ClassificationThresholds,
+) -> SimulatedResult {
+    let workloads = result
+        .spec
+        .total_workloads
+        .unwrap_or(result.spec.total_pods);
+    let metrics = governance::metrics_from_violations(workloads, &result.spec.violations);
+
+    let baseline_score = result.spec.health_score;
+    let simulated_score = match &proposal.severity {
+        Some(overrides) => governance::calculate_health_score_with_weights_and_severity(
+            &metrics,
+            &proposal.weights,
+            Some(overrides),
+        ),
+        None => governance::calculate_health_score_with_weights(&metrics, &proposal.weights),
+    };
+
+    let baseline_classification = result.spec.classification.clone();
+    let simulated_classification = thresholds.classify(simulated_score).to_string();
+
+    SimulatedResult {
+        name: result.metadata.name.clone().unwrap_or_default(),
+        policy_name: result.spec.policy_name.clone(),
+        timestamp: result.spec.timestamp.clone(),
+        baseline_score,
+        classification_changed: baseline_classification != simulated_classification,
+        baseline_classification,
+        simulated_score,
+        simulated_classification,
+    }
+}
+
+/* ============================= NAMESPACE SCORING ============================= */
+
+/// Score a namespace's pods the way the operator does: per workload, under
+/// `policy` and its scoring weights, without severity multipliers.
+pub fn score_namespace(
+    namespace: &str,
+    pods: &[Pod],
+    policy: &DevOpsPolicySpec,
+    source: &str,
+) -> NamespaceScore {
+    let workloads = governance::evaluate_workloads(pods, policy);
+    let mut aggregate = PodMetrics::default();
+    let mut pod_count = 0;
+    let mut violations = 0;
+    for w in &workloads {
+        governance::add_metrics(&mut aggregate, &w.metrics);
+        pod_count += w.replicas;
+        violations += w.violations.len() as u32;
+    }
+    let health_score = governance::calculate_health_score_with_weights(
+        &aggregate,
+        &ScoringWeights::for_policy(policy),
+    );
+    NamespaceScore {
+        namespace: namespace.to_string(),
+        policy: source.to_string(),
+        health_score,
+        classification: governance::classify_health(health_score).to_string(),
+        workloads: workloads.len(),
+        pods: pod_count,
+        violations,
+    }
+}
+
+/// Worst score first (ties by namespace), keeping only scores below `below`.
+pub fn rank_scores(mut scores: Vec<NamespaceScore>, below: Option<u32>) -> Vec<NamespaceScore> {
+    scores.retain(|s| below.is_none_or(|threshold| s.health_score < threshold));
+    scores.sort_by(|a, b| {
+        a.health_score
+            .cmp(&b.health_score)
+            .then_with(|| a.namespace.cmp(&b.namespace))
+    });
+    scores
+}
+
+/* ============================= COMMAND ============================= */
+
+/// Score one namespace, or every non-system namespace when `namespace` is
+/// `None`, against its effective policy (or the built-in checks when no
+/// policy applies) and print a ranked table.
+pub async fn score_namespaces(
+    namespace: Option<&str>,
+    below: Option<u32>,
+    format: OutputFormat,
+) -> Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    let (pod_api, policy_api): (Api<Pod>, Api<DevOpsPolicy>) = match namespace {
+        Some(ns) => (
+            Api::namespaced(client.clone(), ns),
+            Api::namespaced(client.clone(), ns),
+        ),
+        None => (Api::all(client.clone()), Api::all(client.clone())),
+    };
+    let namespace_api: Api<Namespace> = Api::all(client.clone());
+    let cluster_api: Api<ClusterDevOpsPolicy> = Api::all(client);
+
+    let namespaces: Vec<Namespace> = match namespace {
+        Some(ns) => namespace_api.get_opt(ns).await?.into_iter().collect(),
+        None => namespace_api
+            .list(&Default::default())
+            .await
+            .context("Failed to list namespaces")?
+            .items
+            .into_iter()
+            .filter(|ns| !governance::is_system_namespace(&ns.name_any()))
+            .collect(),
+    };
+    let pods = pod_api
+        .list(&Default::default())
+        .await
+        .context("Failed to list pods. Check RBAC permissions.")?
+        .items;
+    let policies = match policy_api.list(&Default::default()).await {
+        Ok(list) => list.items,
+        // DevOpsPolicy CRD not installed: every namespace uses the defaults
+        Err(kube::Error::Api(err)) if err.code == 404 => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let cluster_policies = match cluster_api.list(&Default::default()).await {
+        Ok(list) => list.items,
+        Err(kube::Error::Api(err)) if err.code == 404 => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut pods_by_namespace: BTreeMap<String, Vec<Pod>> = BTreeMap::new();
+    for pod in pods {
+        pods_by_namespace
+            .entry(pod.namespace().unwrap_or_default())
+            .or_default()
+            .push(pod);
+    }
+
+    let defaults = analyze::analysis_policy();
+    let scores: Vec<NamespaceScore> = namespaces
+        .iter()
+        .map(|ns| {
+            let name = ns.name_any();
+            let namespaced = policies
+                .iter()
+                .filter(|p| p.namespace().as_deref() == Some(name.as_str()))
+                .min_by_key(|p| p.name_any());
+            let effective = policy_resolution::resolve_effective_policy(
+                &name,
+                &cluster_policies,
+                namespaced.map(|p| &p.spec),
+            )
+            .map(|spec| policy_resolution::apply_profile(&spec, ns.metadata.labels.as_ref()));
+            let (spec, source) = match (&effective, namespaced) {
+                (Some(spec), Some(policy)) => (spec, policy.name_any()),
+                (Some(spec), None) => (spec, "cluster".to_string()),
+                (None, _) => (&defaults, "defaults".to_string()),
+            };
+            let pods = pods_by_namespace.get(&name).map_or(&[][..], Vec::as_slice);
+            score_namespace(&name, pods, spec, &source)
+        })
+        .collect();
+    let scored = scores.len();
+    let ranked = rank_scores(scores, below);
+
+    if format.is_structured() {
+        return output::print_structured(&ranked, format);
+    }
+    print!("{}", format_scores(&ranked, scored, below));
+    Ok(())
+}
+
+/// Recompute historical audit results under proposed weights / severities.
+///
+/// `from_audit` is a PolicyAuditResult name or a local YAML file; when
+/// omitted, every result in the namespace (optionally for one policy) is used.
+/// Results from the cluster are classified with their policy's
+/// `healthThresholds`; results from a file with the default cut-offs.
+pub async fn simulate_command(
+    proposal: &Proposal,
+    from_audit: Option<&str>,
+    namespace: &str,
+    policy: Option<&str>,
+    format: OutputFormat,
+) -> Result<()> {
+    let (results, thresholds) = match from_audit.filter(|s| std::path::Path::new(s).is_file()) {
+        Some(path) => (load_results_file(path)?, BTreeMap::new()),
+        None => {
+            let client = client::connect()
+                .await
+                .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+            let results = load_results(&client, from_audit, namespace, policy).await?;
+            let thresholds = policy_thresholds(&client, namespace, &results).await?;
+            (results, thresholds)
+        }
+    };
+
+    if results.is_empty() {
+        println!("No audit results found in namespace '{namespace}'");
+        return Ok(());
+    }
+
+    let simulated: Vec<SimulatedResult> = results
+        .iter()
+        .map(|r| {
+            let thresholds = thresholds
+                .get(&r.spec.policy_name)
+                .copied()
+                .unwrap_or_default();
+            simulate(r, proposal, &thresholds)
+        })
+        .collect();
+
+    if format.is_structured() {
+        return output::print_structured(&simulated, format);
+    }
+
+    println!(
+        "{:<40} {:>8} {:<10} {:>8} {:<10}",
+        "AUDIT RESULT", "BASELINE", "", "SIMULATED", ""
+    );
+    println!("{}", "-".repeat(82));
+    for s in &simulated {
+        let marker = if s.classification_changed { " *" } else { "" };
+        println!(
+            "{:<40} {:>8} {:<10} {:>8} {:<10}{marker}",
+            s.name,
+            s.baseline_score,
+            s.baseline_classification,
+            s.simulated_score,
+            s.simulated_classification
+        );
+    }
+
+    let changed = simulated
+        .iter()
+        .filter(|s| s.classification_changed)
+        .count();
+    println!(
+        "\n{changed} of {} classification(s) would change (marked *)",
+        simulated.len()
+    );
+    Ok(())
+}
+
+fn load_results_file(source: &str) -> Result<Vec<PolicyAuditResult>> {
+    let content = std::fs::read_to_string(source)?;
+    parse_results(&content).with_context(|| format!("Invalid PolicyAuditResult in '{source}'"))
+}
+
+/// PolicyAuditResults in a multi-document YAML string; empty documents are skipped.
+pub fn parse_results(content: &str) -> Result<Vec<PolicyAuditResult>> {
+    output::yaml_documents(content)?
+        .into_iter()
+        .map(|doc| Ok(serde_yaml::from_value(doc)?))
+        .collect()
+}
+
+async fn load_results(
+    client: &kube::Client,
+    from_audit: Option<&str>,
+    namespace: &str,
+    policy: Option<&str>,
+) -> Result<Vec<PolicyAuditResult>> {
+    let api: Api<PolicyAuditResult> = Api::namespaced(client.clone(), namespace);
+
+    if let Some(name) = from_audit {
+        return Ok(vec![api.get(name).await.with_context(|| {
+            format!("Failed to fetch audit result '{name}'")
+        })?]);
+    }
+
+    let mut results: Vec<PolicyAuditResult> = api
+        .list(&Default::default())
+        .await?
+        .items
+        .into_iter()
+        .filter(|r| policy.is_none_or(|p| r.spec.policy_name == p))
+        .collect();
+    results.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+    Ok(results)
+}
+
+/// Health cut-offs of each DevOpsPolicy in `namespace` named by `results`.
+/// Policies that no longer exist are left out and fall back to the defaults.
+async fn policy_thresholds(
+    client: &kube::Client,
+    namespace: &str,
+    results: &[PolicyAuditResult],
+) -> Result<BTreeMap<String, ClassificationThresholds>> {
+    let api: Api<DevOpsPolicy> = Api::namespaced(client.clone(), namespace);
+    let mut thresholds = BTreeMap::new();
+    for result in results {
+        let name = &result.spec.policy_name;
+        if thresholds.contains_key(name) {
+            continue;
+        }
+        let policy = api
+            .get_opt(name)
+            .await
+            .with_context(|| format!("Failed to fetch DevOpsPolicy '{name}'"))?;
+        if let Some(policy) = policy {
+            thresholds.insert(
+                name.clone(),
+                ClassificationThresholds::for_policy(&policy.spec),
+            );
+        }
+    }
+    Ok(thresholds)
+}
+
+/* ============================= DISPLAY ============================= */
+
+fn format_scores(scores: &[NamespaceScore], scored: usize, below: Option<u32>) -> String {
+    let mut out = format!(
+        "{:>4}  {:<30} {:>5}  {:<10} {:>9} {:>5} {:>10}  POLICY\n",
+        "RANK", "NAMESPACE", "SCORE", "STATUS", "WORKLOADS", "PODS", "VIOLATIONS"
+    );
+    out.push_str(&format!("{}\n", "-".repeat(100)));
+    for (rank, s) in scores.iter().enumerate() {
+        out.push_str(&format!(
+            "{:>4}  {:<30} {:>5}  {:<10} {:>9} {:>5} {:>10}  {}\n",
+            rank + 1,
+            s.namespace,
+            s.health_score,
+            s.classification,
+            s.workloads,
+            s.pods,
+            s.violations,
+            s.policy
+        ));
+    }
+    match below {
+        Some(threshold) => out.push_str(&format!(
+            "\n{} of {scored} namespace(s) scored below {threshold}\n",
+            scores.len()
+        )),
+        None => out.push_str(&format!("\n{scored} namespace(s) scored\n")),
+    }
+    out
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kube_devops::crd::{AuditViolation, PolicyAuditResultSpec};
+
+    fn audit(violation_types: &[&str], total_pods: u32) -> PolicyAuditResult {
+        let violations: Vec<AuditViolation> = violation_types
+            .iter()
+            .map(|t| AuditViolation {
+                pod_name: "p".to_string(),
+                container_name: "c".to_string(),
+                violation_type: t.to_string(),
+                severity: governance::default_severity(t),
+                message: String::new(),
+                workload: None,
+            })
+            .collect();
+        // Recorded as the operator scores it with default settings
+        let health_score = governance::calculate_health_score(
+            &governance::metrics_from_violations(total_pods, &violations),
+        );
+        PolicyAuditResult::new(
+            "policy-abc",
+            PolicyAuditResultSpec {
+                policy_name: "policy".to_string(),
+                cluster_name: None,
+                timestamp: "2026-03-01T00:00:00Z".to_string(),
+                health_score,
+                total_violations: violations.len() as u32,
+                total_pods,
+                total_workloads: None,
+                classification: governance::classify_health(health_score).to_string(),
+                violations,
+                planned_remediations: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_parse_results_keeps_dashes_inside_documents() {
+        let mut result = audit(&["latest_tag"], 1);
+        result.spec.violations[0].message = "tag --- dev".to_string();
+        let yaml = format!(
+            "---\n{}---\n---\n{}",
+            serde_yaml::to_string(&result).unwrap(),
+            serde_yaml::to_string(&audit(&[], 1)).unwrap()
+        );
+        let results = parse_results(&yaml).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].spec.violations[0].message, "tag --- dev");
+        assert!(parse_results("kind: [").is_err());
+    }
+
+    #[test]
+    fn test_parse_weights() {
+        let w =
+            parse_weights("latest_tag=10, pending=0, missing_resources=4, crash_loop=8").unwrap();
+        assert_eq!(w.latest_tag, 10);
+        assert_eq!(w.pending, 0);
+        assert_eq!(w.missing_resources, 4);
+        assert_eq!(w.crash_loop, 8);
+        assert_eq!(
+            w.missing_liveness,
+            ScoringWeights::default().missing_liveness
+        );
+    }
+
+    #[test]
+    fn test_parse_weights_errors() {
+        assert!(parse_weights("latest_tag").is_err());
+        assert!(parse_weights("latest_tag=x").is_err());
+        assert!(parse_weights("unknown=1").is_err());
+    }
+
+    #[test]
+    fn test_parse_severity() {
+        let s = parse_severity("latest_tag=critical,pending=LOW,image_pull_error=medium").unwrap();
+        assert_eq!(s.latest_tag, Some(Severity::Critical));
+        assert_eq!(s.pending, Some(Severity::Low));
+        assert_eq!(s.image_pull_error, Some(Severity::Medium));
+        assert!(s.missing_liveness.is_none());
+        assert!(parse_severity("latest_tag=extreme").is_err());
+    }
+
+    #[test]
+    fn test_parse_caps() {
+        let c = parse_caps("missing_readiness=20, pending=5").unwrap();
+        assert_eq!(c.missing_readiness, Some(20));
+        assert_eq!(c.pending, Some(5));
+        assert!(c.latest_tag.is_none());
+        assert!(parse_caps("pending=-1").is_err());
+        assert!(parse_caps("unknown=1").is_err());
+    }
+
+    #[test]
+    fn test_simulate_caps_limit_dominant_check() {
+        let result = audit(&["high_restarts"; 20], 1);
+        let weights = ScoringWeights {
+            caps: parse_caps("high_restarts=30").unwrap(),
+            ..Default::default()
+        };
+        let sim = simulate(
+            &result,
+            &Proposal {
+                weights,
+                severity: None,
+            },
+            &ClassificationThresholds::default(),
+        );
+        assert_eq!(sim.baseline_score, 0);
+        assert_eq!(sim.simulated_score, 70);
+        assert!(sim.classification_changed);
+    }
+
+    #[test]
+    fn test_simulate_default_proposal_is_unchanged() {
+        let result = audit(&["latest_tag", "missing_liveness"], 1);
+        let sim = simulate(
+            &result,
+            &Proposal::default(),
+            &ClassificationThresholds::default(),
+        );
+        assert_eq!(sim.baseline_score, sim.simulated_score);
+        assert!(!sim.classification_changed);
+        assert_eq!(sim.name, "policy-abc");
+    }
+
+    #[test]
+    fn test_simulate_heavier_weights_change_classification() {
+        let result = audit(&["latest_tag"], 1);
+        let proposal = Proposal {
+            weights: parse_weights("latest_tag=45").unwrap(),
+            severity: None,
+        };
+        let sim = simulate(&result, &proposal, &ClassificationThresholds::default());
+        assert_eq!(sim.baseline_classification, "Healthy");
+        assert_eq!(sim.simulated_score, 55);
+        assert_eq!(sim.simulated_classification, "Degraded");
+        assert!(sim.classification_changed);
+    }
+
+    #[test]
+    fn test_simulate_with_severity_multipliers() {
+        let result = audit(&["latest_tag"], 1);
+        let proposal = Proposal {
+            weights: ScoringWeights::default(),
+            severity: Some(parse_severity("latest_tag=critical").unwrap()),
+        };
+        let sim = simulate(&result, &proposal, &ClassificationThresholds::default());
+        // weight 5 × critical multiplier 3
+        assert_eq!(sim.simulated_score, 85);
+    }
+
+    #[test]
+    fn test_simulate_baseline_is_the_recorded_result() {
+        let mut result = audit(&["latest_tag"], 1);
+        result.spec.health_score = 42;
+        result.spec.classification = "Degraded".to_string();
+        let sim = simulate(
+            &result,
+            &Proposal::default(),
+            &ClassificationThresholds::default(),
+        );
+        assert_eq!(sim.baseline_score, 42);
+        assert_eq!(sim.baseline_classification, "Degraded");
+        assert_eq!(sim.simulated_score, 95);
+        assert!(sim.classification_changed);
+    }
+
+    #[test]
+    fn test_simulate_uses_policy_thresholds() {
+        let result = audit(&["latest_tag"], 1);
+        let thresholds = ClassificationThresholds {
+            healthy: 96,
+            ..Default::default()
+        };
+        let sim = simulate(&result, &Proposal::default(), &thresholds);
+        assert_eq!(sim.simulated_score, 95);
+        assert_eq!(sim.simulated_classification, "Stable");
+    }
+
+    #[test]
+    fn test_simulate_averages_over_workloads() {
+        // Ten replicas of two workloads: the operator divides by two
+        let mut result = audit(&["latest_tag"; 4], 10);
+        let default = Proposal::default();
+        let thresholds = ClassificationThresholds::default();
+        assert_eq!(simulate(&result, &default, &thresholds).simulated_score, 98);
+
+        result.spec.total_workloads = Some(2);
+        assert_eq!(simulate(&result, &default, &thresholds).simulated_score, 90);
+    }
+
+    // ── Namespace scoring ──
+
+    fn pod(namespace: &str, name: &str, image: &str) -> Pod {
+        use k8s_openapi::api::core::v1::{Container, PodSpec};
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    image: Some(image.to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            status: None,
+        }
+    }
+
+    fn namespace_score(namespace: &str, health_score: u32) -> NamespaceScore {
+        NamespaceScore {
+            namespace: namespace.to_string(),
+            policy: "defaults".to_string(),
+            health_score,
+            classification: governance::classify_health(health_score).to_string(),
+            workloads: 1,
+            pods: 1,
+            violations: 0,
+        }
+    }
+
+    #[test]
+    fn test_score_namespace_uses_policy() {
+        let pods = vec![
+            pod("prod", "web", "nginx:latest"),
+            pod("prod", "api", "api:1.0"),
+        ];
+        let strict = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            ..Default::default()
+        };
+        let score = score_namespace("prod", &pods, &strict, "strict");
+        assert_eq!(score.workloads, 2);
+        assert_eq!(score.pods, 2);
+        assert_eq!(score.violations, 1);
+        assert!(score.health_score < 100);
+        assert_eq!(score.policy, "strict");
+
+        let lenient = score_namespace("prod", &pods, &DevOpsPolicySpec::default(), "cluster");
+        assert_eq!(lenient.health_score, 100);
+        assert_eq!(
+            score_namespace("empty", &[], &strict, "strict").health_score,
+            100
+        );
+    }
+
+    #[test]
+    fn test_rank_scores_worst_first_with_threshold() {
+        let scores = vec![
+            namespace_score("b", 70),
+            namespace_score("c", 95),
+            namespace_score("a", 70),
+            namespace_score("d", 40),
+        ];
+        let ranked: Vec<String> = rank_scores(scores, None)
+            .into_iter()
+            .map(|s| s.namespace)
+            .collect();
+        assert_eq!(ranked, vec!["d", "a", "b", "c"]);
+
+        let below = rank_scores(
+            vec![namespace_score("x", 80), namespace_score("y", 79)],
+            Some(80),
+        );
+        assert_eq!(below.len(), 1);
+        assert_eq!(below[0].namespace, "y");
+    }
+
+    #[test]
+    fn test_format_scores() {
+        let table = format_scores(&[namespace_score("prod", 45)], 3, Some(60));
+        assert!(table.starts_with("RANK"));
+        assert!(table.contains("   1  prod"));
+        assert!(table.ends_with("1 of 3 namespace(s) scored below 60\n"));
+        assert!(format_scores(&[], 2, None).ends_with("2 namespace(s) scored\n"));
+    }
+}
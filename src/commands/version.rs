@@ -1,4 +1,218 @@
+use anyhow::Context;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+
+use kube_devops::output::{self, OutputFormat};
+
+/* ============================= CONFIG ============================= */
+
+/// Default release endpoint (GitHub releases API).
+pub const DEFAULT_RELEASE_URL: &str = "https://api.github.com/repos/stochasticquant/kubernetes-cluster-observer-and-controller-using-Rust/releases";
+
+/* ============================= TYPES ============================= */
+
+/// A release entry as returned by the GitHub releases API.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    #[serde(default)]
+    pub html_url: String,
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
+    #[serde(default)]
+    pub prerelease: bool,
+}
+
+/// A newer release than the running binary.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AvailableRelease {
+    pub version: String,
+    pub url: String,
+    /// Release notes mention CRD changes — run `crd install` after upgrading.
+    pub crd_changes: bool,
+}
+
+/// Result of `version --check-update`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReport {
+    pub current: String,
+    pub update_available: bool,
+    pub releases: Vec<AvailableRelease>,
+}
+
+/* ============================= COMMANDS ============================= */
+
 pub fn run() -> anyhow::Result<()> {
     println!("kube-devops version {}", env!("CARGO_PKG_VERSION"));
     Ok(())
 }
+
+/// Check the release channel for newer versions.
+///
+/// With `offline`, no network request is made: releases are read from
+/// `release_file` (a saved copy of the releases JSON) if given, otherwise
+/// the check is skipped.
+pub async fn check_update(
+    release_url: &str,
+    offline: bool,
+    release_file: Option<&str>,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let body = match (offline, release_file) {
+        (_, Some(file)) => Some(
+            std::fs::read_to_string(file)
+                .with_context(|| format!("Failed to read release file '{file}'"))?,
+        ),
+        (true, None) => None,
+        (false, None) => Some(fetch_releases(release_url).await?),
+    };
+
+    let Some(body) = body else {
+        run()?;
+        println!("Update check skipped (--offline without --release-file).");
+        return Ok(());
+    };
+
+    let releases: Vec<Release> =
+        serde_json::from_str(&body).context("Failed to parse release list")?;
+    let report = build_update_report(env!("CARGO_PKG_VERSION"), &releases)?;
+
+    if format.is_structured() {
+        return output::print_structured(&report, format);
+    }
+
+    println!("kube-devops version {}", report.current);
+    if !report.update_available {
+        println!("You are running the latest release.");
+        return Ok(());
+    }
+
+    println!("\nNewer releases available:");
+    for r in &report.releases {
+        let crd_note = if r.crd_changes {
+            "  [CRD changes: run 'kube-devops crd install' after upgrading]"
+        } else {
+            ""
+        };
+        println!("  {:<10} {}{crd_note}", r.version, r.url);
+    }
+    Ok(())
+}
+
+async fn fetch_releases(url: &str) -> anyhow::Result<String> {
+    let client = reqwest::Client::builder()
+        .user_agent(concat!("kube-devops/", env!("CARGO_PKG_VERSION")))
+        .timeout(std::time::Duration::from_secs(10))
+        .build()?;
+
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to reach release endpoint {url}"))?
+        .error_for_status()
+        .context("Release endpoint returned an error")?;
+
+    Ok(response.text().await?)
+}
+
+/* ============================= HELPERS ============================= */
+
+/// Parse a release tag such as `v0.3.0` into a semantic version.
+fn parse_tag(tag: &str) -> Option<Version> {
+    Version::parse(tag.trim_start_matches('v')).ok()
+}
+
+/// Whether release notes call out CRD schema changes.
+fn mentions_crd_change(body: Option<&str>) -> bool {
+    body.is_some_and(|b| b.to_lowercase().contains("crd"))
+}
+
+/// Collect published, non-prerelease releases newer than `current`, newest first.
+pub fn build_update_report(current: &str, releases: &[Release]) -> anyhow::Result<UpdateReport> {
+    let current_version = Version::parse(current).context("Invalid current version")?;
+
+    let mut newer: Vec<(Version, &Release)> = releases
+        .iter()
+        .filter(|r| !r.draft && !r.prerelease)
+        .filter_map(|r| parse_tag(&r.tag_name).map(|v| (v, r)))
+        .filter(|(v, _)| *v > current_version)
+        .collect();
+    newer.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let releases: Vec<AvailableRelease> = newer
+        .into_iter()
+        .map(|(v, r)| AvailableRelease {
+            version: v.to_string(),
+            url: r.html_url.clone(),
+            crd_changes: mentions_crd_change(r.body.as_deref()),
+        })
+        .collect();
+
+    Ok(UpdateReport {
+        current: current.to_string(),
+        update_available: !releases.is_empty(),
+        releases,
+    })
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(tag: &str, body: &str, prerelease: bool) -> Release {
+        Release {
+            tag_name: tag.to_string(),
+            html_url: format!("https://example.com/{tag}"),
+            body: Some(body.to_string()),
+            draft: false,
+            prerelease,
+        }
+    }
+
+    #[test]
+    fn test_parse_tag() {
+        assert_eq!(parse_tag("v0.3.0"), Some(Version::new(0, 3, 0)));
+        assert_eq!(parse_tag("1.2.3"), Some(Version::new(1, 2, 3)));
+        assert_eq!(parse_tag("nightly"), None);
+    }
+
+    #[test]
+    fn test_report_lists_newer_releases_newest_first() {
+        let releases = vec![
+            release("v0.1.2", "", false),
+            release("v0.3.0", "CRD: added scoringWeights", false),
+            release("v0.2.1", "bug fixes", false),
+        ];
+        let report = build_update_report("0.2.0", &releases).unwrap();
+        assert!(report.update_available);
+        let versions: Vec<&str> = report.releases.iter().map(|r| r.version.as_str()).collect();
+        assert_eq!(versions, vec!["0.3.0", "0.2.1"]);
+        assert!(report.releases[0].crd_changes);
+        assert!(!report.releases[1].crd_changes);
+    }
+
+    #[test]
+    fn test_report_skips_prereleases_and_drafts() {
+        let mut draft = release("v0.5.0", "", false);
+        draft.draft = true;
+        let releases = vec![release("v0.4.0-rc.1", "", true), draft];
+        let report = build_update_report("0.2.0", &releases).unwrap();
+        assert!(!report.update_available);
+        assert!(report.releases.is_empty());
+    }
+
+    #[test]
+    fn test_release_json_parses() {
+        let json = r#"[{"tag_name":"v0.3.0","html_url":"u","body":null,"draft":false,"prerelease":false,"assets":[]}]"#;
+        let releases: Vec<Release> = serde_json::from_str(json).unwrap();
+        assert_eq!(releases[0].tag_name, "v0.3.0");
+        assert!(releases[0].body.is_none());
+    }
+}
@@ -1,4 +1,10 @@
-use std::{collections::HashMap, net::SocketAddr, sync::LazyLock, time::Duration};
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::{BuildHasher, Hasher, RandomState},
+    net::SocketAddr,
+    sync::LazyLock,
+    time::{Duration, SystemTime},
+};
 
 use anyhow::{Context, Result};
 use futures::StreamExt;
@@ -7,97 +13,352 @@ use k8s_openapi::api::{
     core::v1::Pod,
 };
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::{MicroTime, ObjectMeta};
-use k8s_openapi::chrono::{self, Utc};
+use k8s_openapi::chrono::{self, DateTime, Utc};
+use kube::api::PostParams;
 use kube::{Api, Client};
 use kube_runtime::watcher::{Config, Event, watcher};
-
-use axum::{Router, http::StatusCode, response::IntoResponse, routing::get};
-use prometheus::{Encoder, IntCounter, IntGauge, IntGaugeVec, Registry, TextEncoder};
+use serde::{Deserialize, Serialize};
+
+use axum::{
+    Json, Router,
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use prometheus::{
+    Encoder, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Registry, TextEncoder,
+};
 use tokio::sync::{Mutex, broadcast};
 use tokio::{signal, time::sleep};
-use tracing::info;
+use tracing::{info, warn};
 
+use kube_devops::clock;
+use kube_devops::crd::DevOpsPolicySpec;
 use kube_devops::governance::{
-    self, PodMetrics, add_metrics, calculate_health_score, subtract_metrics,
+    self, PodMetrics, ScoringWeights, ViolationDetail, add_metrics,
+    calculate_health_score_with_weights, subtract_metrics,
 };
+use kube_devops::rbac;
+
+use crate::commands::client;
+use crate::commands::policy_cache::PolicyCache;
 
 /* ============================= CONFIG ============================= */
 
-const LEASE_NAME: &str = "kube-devops-leader";
-const LEASE_NAMESPACE: &str = "kube-devops";
-const LEASE_DURATION_SECONDS: i32 = 15;
-const LEASE_RENEW_INTERVAL: Duration = Duration::from_secs(5);
+/// First delay before polling the pod watch again after an error; doubles
+/// per consecutive error.
+const WATCH_RETRY_INITIAL: Duration = Duration::from_secs(1);
+
+/// Upper bound for the pod watch retry backoff.
+const WATCH_RETRY_MAX: Duration = Duration::from_secs(60);
+
+/// Runtime settings for `kube-devops watch`.
+#[derive(Debug, Clone)]
+pub struct WatchSettings {
+    /// Listen address of the metrics and health server.
+    pub addr: SocketAddr,
+    /// Watch only this namespace, with namespaced API calls, so RBAC scoped
+    /// to one namespace is enough. `None` watches all namespaces.
+    pub namespace: Option<String>,
+    /// Namespaces whose pods are scored.
+    pub namespaces: NamespaceFilter,
+    /// Pods with violations whose details are kept for the JSON API; the
+    /// least recently updated are dropped beyond this.
+    pub pod_detail_capacity: usize,
+    /// Interval, jittered by ±10%, at which the pod watch is restarted with a
+    /// fresh list to correct any drift from missed events.
+    pub resync_interval: Duration,
+    /// Leader election lease.
+    pub lease: LeaseSettings,
+}
+
+impl Default for WatchSettings {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from(([0, 0, 0, 0], 8080)),
+            namespace: None,
+            namespaces: NamespaceFilter::default(),
+            pod_detail_capacity: 10_000,
+            resync_interval: Duration::from_secs(600),
+            lease: LeaseSettings::default(),
+        }
+    }
+}
+
+/// Leader election lease name and timing.
+#[derive(Debug, Clone)]
+pub struct LeaseSettings {
+    pub name: String,
+    pub namespace: String,
+    /// Seconds a lease stays valid without renewal.
+    pub duration_seconds: i32,
+    /// How often the leader renews the lease.
+    pub renew_interval: Duration,
+    /// How often a standby instance retries acquiring the lease.
+    pub retry_interval: Duration,
+}
+
+impl Default for LeaseSettings {
+    fn default() -> Self {
+        Self {
+            name: "kube-devops-leader".to_string(),
+            namespace: "kube-devops".to_string(),
+            duration_seconds: 15,
+            renew_interval: Duration::from_secs(5),
+            retry_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Namespaces the watch scores. System namespaces are skipped unless
+/// listed in `include`; `exclude` always wins.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NamespaceFilter {
+    /// Only these namespaces, when non-empty.
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl NamespaceFilter {
+    pub fn allows(&self, namespace: &str) -> bool {
+        if self.exclude.iter().any(|ns| ns == namespace) {
+            return false;
+        }
+        if self.include.is_empty() {
+            !governance::is_system_namespace(namespace)
+        } else {
+            self.include.iter().any(|ns| ns == namespace)
+        }
+    }
+}
 
 /* ============================= PROMETHEUS ============================= */
 
 static REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::new);
 
-static CLUSTER_SCORE: LazyLock<IntGauge> = LazyLock::new(|| {
-    let g = IntGauge::new(
-        "cluster_health_score",
-        "Cluster governance health score (0-100)",
-    )
-    .expect("metric definition is valid");
-    REGISTRY
-        .register(Box::new(g.clone()))
-        .expect("metric not yet registered");
-    g
-});
-
-static NAMESPACE_SCORE: LazyLock<IntGaugeVec> = LazyLock::new(|| {
-    let g = IntGaugeVec::new(
-        prometheus::Opts::new(
-            "namespace_health_score",
-            "Namespace governance health score (0-100)",
-        ),
-        &["namespace"],
-    )
-    .expect("metric definition is valid");
-    REGISTRY
-        .register(Box::new(g.clone()))
-        .expect("metric not yet registered");
-    g
+static METRICS: LazyLock<std::sync::Arc<WatchMetrics>> = LazyLock::new(|| {
+    std::sync::Arc::new(WatchMetrics::register(&REGISTRY, None).expect("metric not yet registered"))
 });
 
-static POD_EVENTS: LazyLock<IntCounter> = LazyLock::new(|| {
-    let c = IntCounter::new("pod_events_total", "Total pod events processed")
-        .expect("metric definition is valid");
-    REGISTRY
-        .register(Box::new(c.clone()))
-        .expect("metric not yet registered");
-    c
-});
+/// Metrics of one watched cluster. `watch` registers a single set;
+/// `multi-cluster watch` registers one per context, with a constant
+/// `cluster` label.
+pub(crate) struct WatchMetrics {
+    cluster_score: IntGauge,
+    namespace_score: IntGaugeVec,
+    namespace_violations: IntGaugeVec,
+    pod_events: IntCounter,
+    pods_tracked: IntGauge,
+    restarts: IntCounterVec,
+    pod_details_tracked: IntGauge,
+    pod_detail_evictions: IntCounter,
+}
 
-static PODS_TRACKED: LazyLock<IntGauge> = LazyLock::new(|| {
-    let g = IntGauge::new(
-        "pods_tracked_total",
-        "Total pods currently tracked by the watch controller",
-    )
-    .expect("metric definition is valid");
-    REGISTRY
-        .register(Box::new(g.clone()))
-        .expect("metric not yet registered");
-    g
-});
+impl WatchMetrics {
+    pub(crate) fn register(registry: &Registry, cluster: Option<&str>) -> Result<Self> {
+        let opts = |name: &str, help: &str| {
+            let opts = prometheus::Opts::new(name, help);
+            match cluster {
+                Some(cluster) => opts.const_label("cluster", cluster),
+                None => opts,
+            }
+        };
+        let metrics = Self {
+            cluster_score: IntGauge::with_opts(opts(
+                "cluster_health_score",
+                "Cluster governance health score (0-100)",
+            ))?,
+            namespace_score: IntGaugeVec::new(
+                opts(
+                    "namespace_health_score",
+                    "Namespace governance health score (0-100)",
+                ),
+                &["namespace"],
+            )?,
+            namespace_violations: IntGaugeVec::new(
+                opts(
+                    "namespace_violation_count",
+                    "Pods with each violation type in a namespace",
+                ),
+                &["namespace", "type"],
+            )?,
+            pod_events: IntCounter::with_opts(opts(
+                "pod_events_total",
+                "Total pod events processed",
+            ))?,
+            pods_tracked: IntGauge::with_opts(opts(
+                "pods_tracked_total",
+                "Total pods currently tracked by the watch controller",
+            ))?,
+            restarts: IntCounterVec::new(
+                opts(
+                    "watch_stream_restarts_total",
+                    "Pod watch restarts by reason (error, ended, resync)",
+                ),
+                &["reason"],
+            )?,
+            pod_details_tracked: IntGauge::with_opts(opts(
+                "pod_details_tracked",
+                "Pods whose violation details are held for the JSON API",
+            ))?,
+            pod_detail_evictions: IntCounter::with_opts(opts(
+                "pod_detail_evictions_total",
+                "Pod violation details dropped to stay within the detail capacity",
+            ))?,
+        };
+
+        let collectors: [Box<dyn prometheus::core::Collector>; 8] = [
+            Box::new(metrics.cluster_score.clone()),
+            Box::new(metrics.namespace_score.clone()),
+            Box::new(metrics.namespace_violations.clone()),
+            Box::new(metrics.pod_events.clone()),
+            Box::new(metrics.pods_tracked.clone()),
+            Box::new(metrics.restarts.clone()),
+            Box::new(metrics.pod_details_tracked.clone()),
+            Box::new(metrics.pod_detail_evictions.clone()),
+        ];
+        for collector in collectors {
+            registry.register(collector)?;
+        }
+        Ok(metrics)
+    }
+}
 
 /* ============================= STATE ============================= */
 
+#[derive(Default)]
 pub(crate) struct NamespaceState {
     pub(crate) metrics: PodMetrics,
+    /// Weights of the namespace's effective policy, as of its latest pod event.
+    pub(crate) weights: ScoringWeights,
+    /// Violation types per pod; pods without violations are not kept.
+    pub(crate) violations: BTreeMap<String, Vec<&'static str>>,
+}
+
+impl NamespaceState {
+    fn set_pod_violations(&mut self, pod: &str, violations: Vec<&'static str>) {
+        if violations.is_empty() {
+            self.violations.remove(pod);
+        } else {
+            self.violations.insert(pod.to_string(), violations);
+        }
+    }
+
+    fn score(&self) -> u32 {
+        calculate_health_score_with_weights(&self.metrics, &self.weights)
+    }
 }
 
 pub(crate) struct ClusterState {
     pub(crate) namespaces: HashMap<String, NamespaceState>,
+    pub(crate) pod_details: PodDetailStore,
     pub(crate) ready: bool,
 }
 
+impl ClusterState {
+    pub(crate) fn new(pod_detail_capacity: usize) -> Self {
+        Self {
+            namespaces: HashMap::new(),
+            pod_details: PodDetailStore::new(pod_detail_capacity),
+            ready: false,
+        }
+    }
+}
+
+/// Violations of one pod and the score it would have on its own.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct PodDetail {
+    pub(crate) pod: String,
+    /// Score of this pod alone under its namespace's weights.
+    pub(crate) score: u32,
+    pub(crate) violations: Vec<ViolationDetail>,
+}
+
+/// Violation details of pods with violations, bounded to `capacity` pods.
+/// Beyond that the least recently updated pod is evicted; namespace
+/// metrics are unaffected.
+pub(crate) struct PodDetailStore {
+    capacity: usize,
+    /// `namespace/pod` -> (update tick, namespace, detail)
+    entries: HashMap<String, (u64, String, PodDetail)>,
+    /// Keys by update tick, oldest first.
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl PodDetailStore {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            tick: 0,
+        }
+    }
+
+    /// Store `detail` as the most recent entry, or drop the pod when it has
+    /// no violations. Returns how many pods were evicted.
+    fn update(&mut self, namespace: &str, detail: PodDetail) -> usize {
+        self.remove(namespace, &detail.pod);
+        if detail.violations.is_empty() {
+            return 0;
+        }
+        let key = format!("{}/{}", namespace, detail.pod);
+        self.tick += 1;
+        self.order.insert(self.tick, key.clone());
+        self.entries
+            .insert(key, (self.tick, namespace.to_string(), detail));
+
+        let mut evicted = 0;
+        while self.entries.len() > self.capacity {
+            let Some((_, oldest)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&oldest);
+            evicted += 1;
+        }
+        evicted
+    }
+
+    fn remove(&mut self, namespace: &str, pod: &str) {
+        if let Some((tick, _, _)) = self.entries.remove(&format!("{namespace}/{pod}")) {
+            self.order.remove(&tick);
+        }
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Details of pods in `namespace`, lowest score first.
+    fn in_namespace(&self, namespace: &str) -> Vec<&PodDetail> {
+        let mut pods: Vec<&PodDetail> = self
+            .entries
+            .values()
+            .filter(|(_, ns, _)| ns == namespace)
+            .map(|(_, _, detail)| detail)
+            .collect();
+        pods.sort_by(|a, b| a.score.cmp(&b.score).then_with(|| a.pod.cmp(&b.pod)));
+        pods
+    }
+}
+
 /* ============================= ENTRY ============================= */
 
-pub async fn run() -> Result<()> {
+pub async fn run(read_only: bool, settings: WatchSettings) -> Result<()> {
     println!("Starting watch controller...\n");
     info!("controller_starting");
 
-    let client = Client::try_default()
+    let client = client::connect()
         .await
         .context("Failed to connect to Kubernetes cluster")?;
 
@@ -110,12 +371,31 @@ pub async fn run() -> Result<()> {
         }
     }
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], 8080));
+    let scope = settings.namespace;
+    println!(
+        "  Namespace scope ............. {}",
+        scope.as_deref().unwrap_or("all namespaces")
+    );
+    let report = rbac::startup_check(
+        &client,
+        &rbac::watch_permissions(read_only, scope.as_deref()),
+    )
+    .await?;
+
+    // Without policy read access the watch falls back to the built-in checks
+    let policy_stores = report.feature_enabled(rbac::FEATURE_POLICIES).then(|| {
+        PolicyCache::spawn(
+            &client,
+            scope.as_deref(),
+            report.feature_enabled(rbac::FEATURE_CLUSTER_POLICIES),
+        )
+    });
 
-    let cluster_state = std::sync::Arc::new(Mutex::new(ClusterState {
-        namespaces: HashMap::new(),
-        ready: false,
-    }));
+    let addr = settings.addr;
+    let lease = settings.lease;
+
+    let cluster_state =
+        std::sync::Arc::new(Mutex::new(ClusterState::new(settings.pod_detail_capacity)));
 
     let (shutdown_tx, _) = broadcast::channel::<()>(1);
 
@@ -128,51 +408,119 @@ pub async fn run() -> Result<()> {
     println!("  HTTP server ................. http://{addr}");
 
     print!("  Leader election ............. ");
-    if !acquire_leader(&client).await? {
+    let leases: Api<Lease> = Api::namespaced(client.clone(), &lease.namespace);
+    let identity = leader_identity();
+    if read_only {
+        // Leases are writes; a read-only instance observes without coordinating
+        println!("skipped (read-only)");
+        info!("leader_election_skipped_read_only");
+    } else if try_acquire_lease(&leases, &lease, &identity)
+        .await
+        .unwrap_or(false)
+    {
+        println!("acquired ({identity})");
+        info!(identity = %identity, "leader_acquired");
+    } else {
         println!("waiting (another instance holds the lease)");
-        info!("not_leader_waiting");
-        // Non-leader: keep running so HTTP health probes pass; retry periodically
-        loop {
-            sleep(Duration::from_secs(10)).await;
-            if acquire_leader(&client).await.unwrap_or(false) {
-                println!("  Leader election ............. acquired (promoted)");
-                info!("leader_promoted");
-                break;
+        info!(identity = %identity, "not_leader_waiting");
+        // Non-leader: keep serving health probes and retry until promoted
+        tokio::select! {
+            _ = wait_for_lease(&leases, &lease, &identity) => {}
+            res = signal::ctrl_c() => {
+                res?;
+                info!("shutdown_signal_received");
+                let _ = shutdown_tx.send(());
+                let _ = http_handle.await?;
+                println!("Watch controller stopped (never became leader).");
+                return Ok(());
             }
         }
+        println!("  Leader election ............. acquired (promoted)");
+        info!(identity = %identity, "leader_promoted");
     }
-    println!("acquired");
-    info!("leader_acquired");
 
     println!();
     println!("  Available endpoints:");
     println!("    GET /healthz .............. Liveness probe (always 200 OK)");
     println!("    GET /readyz ............... Readiness probe (503 until initial sync, then 200)");
     println!("    GET /metrics .............. Prometheus metrics scrape endpoint");
+    println!("    GET /api/v1/namespaces .... Namespace scores and violation totals (JSON)");
+    println!("    GET /api/v1/namespaces/{{ns}}/violations  Violations by type and pod (JSON)");
+    println!("    GET /api/v1/namespaces/{{ns}}/pods  Pod violation details, worst first (JSON)");
+    println!("    GET /api/v1/score ......... Cluster score (JSON)");
     println!();
     println!("Watch controller running. Press Ctrl+C to stop.\n");
     println!("{}", "=".repeat(70));
 
-    // Spawn lease renewal
-    let renewal_client = client.clone();
-    let renewal_shutdown = shutdown_tx.subscribe();
-    tokio::spawn(async move { lease_renewal_loop(renewal_client, renewal_shutdown).await });
+    // Renew the lease until shutdown (then release it) or until it is lost
+    let mut renewal = (!read_only).then(|| {
+        let renewal_shutdown = shutdown_tx.subscribe();
+        let identity = identity.clone();
+        tokio::spawn(
+            async move { lease_renewal_loop(leases, lease, identity, renewal_shutdown).await },
+        )
+    });
 
     let watch_state = cluster_state.clone();
     let watch_shutdown = shutdown_tx.subscribe();
 
-    let watch_handle = tokio::spawn(async move { watch_loop(watch_state, watch_shutdown).await });
+    let tracker = PodTracker::new(
+        METRICS.clone(),
+        policy_stores,
+        scope.is_some(),
+        settings.namespaces,
+    );
+    let resync_interval = settings.resync_interval;
+    let watch_handle = tokio::spawn(async move {
+        watch_loop(
+            client,
+            watch_state,
+            tracker,
+            scope,
+            resync_interval,
+            watch_shutdown,
+        )
+        .await
+    });
+
+    let lease_lost = tokio::select! {
+        res = signal::ctrl_c() => {
+            res?;
+            false
+        }
+        _ = async {
+            match renewal.as_mut() {
+                Some(handle) => {
+                    let _ = handle.await;
+                }
+                None => std::future::pending().await,
+            }
+        } => true,
+    };
 
-    signal::ctrl_c().await?;
-    info!("shutdown_signal_received");
     println!("\n{}", "=".repeat(70));
-    println!("Shutdown signal received. Stopping watch controller...");
+    if lease_lost {
+        warn!(identity = %identity, "leader_lease_lost");
+        println!("Leader lease lost. Stopping watch controller...");
+    } else {
+        info!("shutdown_signal_received");
+        println!("Shutdown signal received. Stopping watch controller...");
+    }
     println!("{}", "=".repeat(70));
 
     let _ = shutdown_tx.send(());
 
     let _ = watch_handle.await?;
     let _ = http_handle.await?;
+    if let Some(handle) = renewal.filter(|_| !lease_lost) {
+        // Wait for the lease release so a standby can take over immediately
+        let _ = handle.await;
+    }
+
+    if lease_lost {
+        // Exit non-zero so the pod restarts and rejoins the election
+        anyhow::bail!("lost the leader lease to another instance");
+    }
 
     info!("controller_stopped");
     println!("Watch controller stopped.");
@@ -181,107 +529,154 @@ pub async fn run() -> Result<()> {
 
 /* ============================= LEADER ELECTION ============================= */
 
-async fn acquire_leader(client: &Client) -> Result<bool> {
-    let leases: Api<Lease> = Api::namespaced(client.clone(), LEASE_NAMESPACE);
-
-    let now = MicroTime(Utc::now());
-
-    let lease = Lease {
-        metadata: ObjectMeta {
-            name: Some(LEASE_NAME.to_string()),
-            ..Default::default()
-        },
-        spec: Some(LeaseSpec {
-            holder_identity: Some("kube-devops-instance".to_string()),
-            lease_duration_seconds: Some(LEASE_DURATION_SECONDS),
-            acquire_time: Some(now.clone()),
-            renew_time: Some(now),
-            ..Default::default()
-        }),
-    };
+/// Lease holder identity: the pod name plus a random suffix, so restarted
+/// containers and local runs never share an identity.
+fn leader_identity() -> String {
+    let suffix = clock::short_hash(&(std::process::id(), SystemTime::now()));
+    format!("{}-{}", clock::instance_identity(), &suffix[..6])
+}
 
-    // Try to create a fresh lease
-    match leases.create(&Default::default(), &lease).await {
-        Ok(_) => return Ok(true),
-        Err(kube::Error::Api(err)) if err.code == 409 => {
-            // Lease already exists — check if we can take it over
-            info!("lease_exists_checking_expiry");
-        }
-        Err(_) => return Ok(false),
+/// The spec to write for `identity` to hold the lease at `now`, or `None`
+/// while another holder's lease is still valid. Holding renews the lease;
+/// an expired or released lease is taken over.
+fn next_lease_spec(
+    current: Option<&LeaseSpec>,
+    identity: &str,
+    duration_seconds: i32,
+    now: DateTime<Utc>,
+) -> Option<LeaseSpec> {
+    let current = current.cloned().unwrap_or_default();
+    let holder = current.holder_identity.as_deref().unwrap_or_default();
+    if holder == identity {
+        return Some(LeaseSpec {
+            lease_duration_seconds: Some(duration_seconds),
+            renew_time: Some(MicroTime(now)),
+            ..current
+        });
     }
 
-    // Lease exists — fetch it and check ownership / expiry
-    let existing = leases.get(LEASE_NAME).await?;
-
-    let can_take = match &existing.spec {
-        Some(spec) => {
-            let is_ours = spec.holder_identity.as_deref() == Some("kube-devops-instance");
-
-            let is_expired = spec.renew_time.as_ref().is_none_or(|t| {
-                let duration_secs = spec.lease_duration_seconds.unwrap_or(15) as i64;
-                Utc::now().signed_duration_since(t.0) > chrono::Duration::seconds(duration_secs)
-            });
+    let expired = current.renew_time.as_ref().is_none_or(|t| {
+        let duration = current.lease_duration_seconds.unwrap_or(duration_seconds);
+        now.signed_duration_since(t.0) > chrono::Duration::seconds(duration.into())
+    });
+    if !holder.is_empty() && !expired {
+        return None;
+    }
+    Some(LeaseSpec {
+        holder_identity: Some(identity.to_string()),
+        lease_duration_seconds: Some(duration_seconds),
+        acquire_time: Some(MicroTime(now)),
+        renew_time: Some(MicroTime(now)),
+        lease_transitions: Some(current.lease_transitions.unwrap_or(0) + 1),
+    })
+}
 
-            is_ours || is_expired
-        }
-        None => true,
+/// Create, renew, or take over the lease. Returns `false` when another
+/// instance holds it or won a concurrent write (the `resourceVersion`
+/// makes the update conditional).
+async fn try_acquire_lease(
+    leases: &Api<Lease>,
+    settings: &LeaseSettings,
+    identity: &str,
+) -> Result<bool> {
+    let now = Utc::now();
+    let duration = settings.duration_seconds;
+    let Some(mut lease) = leases.get_opt(&settings.name).await? else {
+        let lease = Lease {
+            metadata: ObjectMeta {
+                name: Some(settings.name.clone()),
+                ..Default::default()
+            },
+            spec: next_lease_spec(None, identity, duration, now),
+        };
+        return match leases.create(&PostParams::default(), &lease).await {
+            Ok(_) => Ok(true),
+            Err(kube::Error::Api(err)) if err.code == 409 => Ok(false),
+            Err(e) => Err(e.into()),
+        };
     };
 
-    if !can_take {
+    let Some(spec) = next_lease_spec(lease.spec.as_ref(), identity, duration, now) else {
         return Ok(false);
+    };
+    if spec.holder_identity != lease.spec.as_ref().and_then(|s| s.holder_identity.clone()) {
+        info!(identity = %identity, "lease_takeover");
     }
-
-    // Take over the expired / our lease
-    info!("lease_takeover");
-    let now = MicroTime(Utc::now());
-    let patch = serde_json::json!({
-        "spec": {
-            "holderIdentity": "kube-devops-instance",
-            "leaseDurationSeconds": LEASE_DURATION_SECONDS,
-            "acquireTime": now,
-            "renewTime": now
-        }
-    });
-
+    lease.spec = Some(spec);
     match leases
-        .patch(
-            LEASE_NAME,
-            &kube::api::PatchParams::default(),
-            &kube::api::Patch::Merge(&patch),
-        )
+        .replace(&settings.name, &PostParams::default(), &lease)
         .await
     {
         Ok(_) => Ok(true),
-        Err(_) => Ok(false),
+        Err(kube::Error::Api(err)) if err.code == 409 => Ok(false),
+        Err(e) => Err(e.into()),
     }
 }
 
-async fn lease_renewal_loop(client: Client, mut shutdown: broadcast::Receiver<()>) {
-    let leases: Api<Lease> = Api::namespaced(client, LEASE_NAMESPACE);
+/// Retry acquisition every `retry_interval` until the lease is ours.
+async fn wait_for_lease(leases: &Api<Lease>, settings: &LeaseSettings, identity: &str) {
+    loop {
+        sleep(settings.retry_interval).await;
+        match try_acquire_lease(leases, settings, identity).await {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => info!(error = %e, "lease_acquire_failed"),
+        }
+    }
+}
+
+/// Clear the holder so a standby can take over without waiting for expiry.
+async fn release_lease(
+    leases: &Api<Lease>,
+    settings: &LeaseSettings,
+    identity: &str,
+) -> Result<()> {
+    let Some(mut lease) = leases.get_opt(&settings.name).await? else {
+        return Ok(());
+    };
+    let Some(spec) = lease.spec.as_mut() else {
+        return Ok(());
+    };
+    if spec.holder_identity.as_deref() != Some(identity) {
+        return Ok(());
+    }
+    spec.holder_identity = None;
+    spec.renew_time = None;
+    leases
+        .replace(&settings.name, &PostParams::default(), &lease)
+        .await?;
+    Ok(())
+}
 
+/// Renew the lease until shutdown, then release it. Returns early when the
+/// lease is lost: another instance holds it, or renewals have failed for
+/// longer than the lease duration.
+async fn lease_renewal_loop(
+    leases: Api<Lease>,
+    settings: LeaseSettings,
+    identity: String,
+    mut shutdown: broadcast::Receiver<()>,
+) {
+    let mut last_renewed = Utc::now();
     loop {
         tokio::select! {
             _ = shutdown.recv() => {
-                info!("lease_renewal_stopped");
+                match release_lease(&leases, &settings, &identity).await {
+                    Ok(()) => info!(identity = %identity, "lease_released"),
+                    Err(e) => warn!(error = %e, "lease_release_failed"),
+                }
                 return;
             }
-            _ = sleep(LEASE_RENEW_INTERVAL) => {
-                let now = MicroTime(Utc::now());
-
-                let patch = serde_json::json!({
-                    "spec": {
-                        "renewTime": now
-                    }
-                });
-
-                match leases.patch(
-                    LEASE_NAME,
-                    &kube::api::PatchParams::default(),
-                    &kube::api::Patch::Merge(&patch),
-                ).await {
-                    Ok(_) => {}
+            _ = sleep(settings.renew_interval) => {
+                match try_acquire_lease(&leases, &settings, &identity).await {
+                    Ok(true) => last_renewed = Utc::now(),
+                    Ok(false) => return,
                     Err(e) => {
-                        info!(error=%e, "lease_renewal_failed");
+                        info!(error = %e, "lease_renewal_failed");
+                        let held_for = Utc::now().signed_duration_since(last_renewed);
+                        if held_for > chrono::Duration::seconds(settings.duration_seconds.into()) {
+                            return;
+                        }
                     }
                 }
             }
@@ -289,143 +684,468 @@ async fn lease_renewal_loop(client: Client, mut shutdown: broadcast::Receiver<()
     }
 }
 
+/// Evaluate a pod against the effective policy, or the built-in checks
+/// when no policy applies to its namespace.
+fn evaluate(pod: &Pod, policy: Option<&DevOpsPolicySpec>) -> (PodMetrics, Vec<&'static str>) {
+    match policy {
+        Some(policy) => (
+            governance::evaluate_pod_with_policy(pod, policy),
+            governance::detect_violations_with_policy(pod, policy),
+        ),
+        None => (
+            governance::evaluate_pod(pod),
+            governance::detect_violations(pod),
+        ),
+    }
+}
+
+/// Violation details of a pod whose own metrics are `contribution`.
+fn pod_detail(
+    pod: &Pod,
+    policy: Option<&DevOpsPolicySpec>,
+    contribution: &PodMetrics,
+    weights: &ScoringWeights,
+) -> PodDetail {
+    let violations = match policy {
+        Some(policy) => governance::detect_violations_detailed(pod, policy),
+        None => governance::detect_violations_detailed_builtin(pod),
+    };
+    PodDetail {
+        pod: pod.metadata.name.clone().unwrap_or_default(),
+        score: calculate_health_score_with_weights(contribution, weights),
+        violations,
+    }
+}
+
 /* ============================= WATCH LOOP ============================= */
 
-async fn watch_loop(
+/// Keep `cluster_state` in step with the pods of one cluster (or of
+/// `scope`) until shutdown.
+pub(crate) async fn watch_loop(
+    client: Client,
     cluster_state: std::sync::Arc<Mutex<ClusterState>>,
+    mut tracker: PodTracker,
+    scope: Option<String>,
+    resync_interval: Duration,
     mut shutdown: broadcast::Receiver<()>,
 ) -> Result<()> {
-    let client = Client::try_default()
-        .await
-        .context("Failed to connect to Kubernetes cluster for watcher")?;
-
-    let pods: Api<Pod> = Api::all(client);
-    let mut pod_store: HashMap<String, (String, PodMetrics)> = HashMap::new();
+    let pods: Api<Pod> = match &scope {
+        Some(namespace) => Api::namespaced(client, namespace),
+        None => Api::all(client),
+    };
+    let metrics = tracker.metrics.clone();
 
-    let config = Config::default();
-    let mut stream = watcher(pods, config).boxed();
+    let mut stream = watcher(pods.clone(), Config::default()).boxed();
+    let resync = sleep(jittered(resync_interval));
+    tokio::pin!(resync);
+    let mut failures = 0;
 
     loop {
-        tokio::select! {
+        let restart = tokio::select! {
             _ = shutdown.recv() => {
                 info!("watcher_shutdown");
                 return Ok(());
             }
 
-            event = stream.next() => {
-                if let Some(Ok(event)) = event {
-                    POD_EVENTS.inc();
+            _ = &mut resync => "resync",
+
+            event = stream.next() => match event {
+                Some(Ok(event)) => {
+                    failures = 0;
+                    metrics.pod_events.inc();
 
                     let mut state = cluster_state.lock().await;
+                    for update in pod_updates(event) {
+                        tracker.apply(&mut state, update);
+                    }
 
-                    match event {
-                        Event::Applied(pod) => {
-                            let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
-
-                            if governance::is_system_namespace(ns) {
-                                continue;
-                            }
-
-                            let name = pod.metadata.name.as_deref().unwrap_or_default();
-                            let key = format!("{}/{}", ns, name);
-
-                            // Remove old contribution if pod already tracked
-                            if let Some((old_ns, old_metrics)) = pod_store.remove(&key)
-                                && let Some(ns_state) = state.namespaces.get_mut(&old_ns)
-                            {
-                                subtract_metrics(&mut ns_state.metrics, &old_metrics);
-                            }
-
-                            let contribution = governance::evaluate_pod(&pod);
-
-                            let violations = governance::detect_violations(&pod);
-                            if !violations.is_empty() {
-                                info!(
-                                    event = "policy_violation",
-                                    namespace = %ns,
-                                    pod = %name,
-                                    violations = ?violations,
-                                    "policy_violation_detected"
-                                );
-                            }
-
-                            let ns_state = state.namespaces
-                                .entry(ns.to_string())
-                                .or_insert(NamespaceState {
-                                    metrics: PodMetrics::default(),
-                                });
-
-                            add_metrics(&mut ns_state.metrics, &contribution);
-                            pod_store.insert(key, (ns.to_string(), contribution));
-
-                            state.ready = true;
-                        }
+                    update_prometheus_metrics(&metrics, &state);
+                    metrics.pods_tracked.set(tracker.pods.len() as i64);
+                    metrics
+                        .pod_details_tracked
+                        .set(state.pod_details.len() as i64);
+                    continue;
+                }
+                Some(Err(e)) => {
+                    warn!(error = %e, failures, "watch_stream_error");
+                    "error"
+                }
+                None => "ended",
+            },
+        };
+        metrics.restarts.with_label_values(&[restart]).inc();
+
+        if restart != "resync" {
+            let delay = jittered(watch_retry_delay(failures));
+            failures += 1;
+            tokio::select! {
+                _ = shutdown.recv() => {
+                    info!("watcher_shutdown");
+                    return Ok(());
+                }
+                _ = sleep(delay) => {}
+            }
+        }
+        // After an error the watcher re-watches, or re-lists if its
+        // resourceVersion expired, on the next poll
+        if restart != "error" {
+            info!(reason = restart, "watch_relist");
+            stream = watcher(pods.clone(), Config::default()).boxed();
+            resync
+                .as_mut()
+                .reset(tokio::time::Instant::now() + jittered(resync_interval));
+        }
+    }
+}
 
-                        Event::Deleted(pod) => {
-                            let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
-                            let name = pod.metadata.name.as_deref().unwrap_or_default();
-                            let key = format!("{}/{}", ns, name);
+/// Delay before polling the watch again after the `failures`-th (zero-based)
+/// consecutive error: doubles from `WATCH_RETRY_INITIAL` and is capped at
+/// `WATCH_RETRY_MAX`.
+fn watch_retry_delay(failures: u32) -> Duration {
+    let factor = 1u32.checked_shl(failures).unwrap_or(u32::MAX);
+    WATCH_RETRY_INITIAL
+        .saturating_mul(factor)
+        .min(WATCH_RETRY_MAX)
+}
 
-                            if let Some((old_ns, old_metrics)) = pod_store.remove(&key)
-                                && let Some(ns_state) = state.namespaces.get_mut(&old_ns)
-                            {
-                                subtract_metrics(&mut ns_state.metrics, &old_metrics);
-                            }
-                        }
+/// `delay` scaled by a random factor in [0.9, 1.1], so replicas started
+/// together do not re-list in lockstep.
+fn jittered(delay: Duration) -> Duration {
+    let random = RandomState::new().build_hasher().finish();
+    delay.mul_f64(0.9 + 0.2 * (random as f64 / u64::MAX as f64))
+}
 
-                        Event::Restarted(pods) => {
-                            pod_store.clear();
-                            state.namespaces.clear();
+/// One step of the pod watch.
+#[derive(Debug)]
+enum PodUpdate {
+    /// An initial list (or re-list) starts; pods follow as `InitApply`.
+    Init,
+    InitApply(Pod),
+    /// The list is complete and replaces the tracked pods.
+    InitDone,
+    Applied(Pod),
+    Deleted(Pod),
+}
 
-                            for pod in pods {
-                                let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
+/// The steps of one watcher event. kube-runtime 0.88 delivers a whole
+/// (re-)list as `Restarted`; newer releases stream it as `Init`,
+/// `InitApply`, and `InitDone`, which map one-to-one onto `PodUpdate`.
+fn pod_updates(event: Event<Pod>) -> Vec<PodUpdate> {
+    match event {
+        Event::Applied(pod) => vec![PodUpdate::Applied(pod)],
+        Event::Deleted(pod) => vec![PodUpdate::Deleted(pod)],
+        Event::Restarted(pods) => std::iter::once(PodUpdate::Init)
+            .chain(pods.into_iter().map(PodUpdate::InitApply))
+            .chain(std::iter::once(PodUpdate::InitDone))
+            .collect(),
+    }
+}
 
-                                if governance::is_system_namespace(ns) {
-                                    continue;
-                                }
+/// Each tracked pod's contribution to its namespace's state.
+pub(crate) struct PodTracker {
+    metrics: std::sync::Arc<WatchMetrics>,
+    policies: Option<PolicyCache>,
+    /// An explicit scope is watched even if it is a system namespace.
+    scoped: bool,
+    namespaces: NamespaceFilter,
+    /// `namespace/pod` -> (namespace, metrics contribution)
+    pods: HashMap<String, (String, PodMetrics)>,
+    /// Pods of a list in progress. The previous state is served until
+    /// `InitDone` swaps them in.
+    relist: Option<Vec<Pod>>,
+}
 
-                                let name = pod.metadata.name.as_deref().unwrap_or_default();
-                                let key = format!("{}/{}", ns, name);
+impl PodTracker {
+    /// Without `policies` pods are scored with the built-in checks. With
+    /// `scoped`, every namespace delivered by the watch is scored.
+    pub(crate) fn new(
+        metrics: std::sync::Arc<WatchMetrics>,
+        policies: Option<PolicyCache>,
+        scoped: bool,
+        namespaces: NamespaceFilter,
+    ) -> Self {
+        Self {
+            metrics,
+            policies,
+            scoped,
+            namespaces,
+            pods: HashMap::new(),
+            relist: None,
+        }
+    }
 
-                                let contribution = governance::evaluate_pod(&pod);
+    fn allows(&self, namespace: &str) -> bool {
+        self.scoped || self.namespaces.allows(namespace)
+    }
 
-                                let ns_state = state.namespaces
-                                    .entry(ns.to_string())
-                                    .or_insert(NamespaceState {
-                                        metrics: PodMetrics::default(),
-                                    });
+    fn apply(&mut self, state: &mut ClusterState, update: PodUpdate) {
+        match update {
+            PodUpdate::Init => self.relist = Some(Vec::new()),
+            PodUpdate::InitApply(pod) => self.relist.get_or_insert_default().push(pod),
+            PodUpdate::InitDone => {
+                let pods = self.relist.take().unwrap_or_default();
+                self.pods.clear();
+                state.namespaces.clear();
+                state.pod_details.clear();
+                for pod in &pods {
+                    self.track(state, pod, false);
+                }
+                state.ready = true;
+            }
+            PodUpdate::Applied(pod) => {
+                if self.track(state, &pod, true) {
+                    state.ready = true;
+                }
+            }
+            PodUpdate::Deleted(pod) => {
+                let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
+                let name = pod.metadata.name.as_deref().unwrap_or_default();
+                self.untrack(state, ns, name);
+            }
+        }
+    }
 
-                                add_metrics(&mut ns_state.metrics, &contribution);
-                                pod_store.insert(key, (ns.to_string(), contribution));
-                            }
+    /// Replace the pod's contribution with a fresh evaluation. Returns
+    /// `false` for pods in namespaces the watch does not score.
+    fn track(&mut self, state: &mut ClusterState, pod: &Pod, log_violations: bool) -> bool {
+        let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
+        if !self.allows(ns) {
+            return false;
+        }
+        let name = pod.metadata.name.as_deref().unwrap_or_default();
+        self.untrack(state, ns, name);
+
+        let policy = self.policies.as_ref().and_then(|p| p.effective_policy(ns));
+        let (contribution, violations) = evaluate(pod, policy.as_ref());
+        if log_violations && !violations.is_empty() {
+            info!(
+                event = "policy_violation",
+                namespace = %ns,
+                pod = %name,
+                violations = ?violations,
+                "policy_violation_detected"
+            );
+        }
 
-                            state.ready = true;
-                        }
-                    }
+        let weights = scoring_weights(policy.as_ref());
+        let detail = pod_detail(pod, policy.as_ref(), &contribution, &weights);
+        let ns_state = state.namespaces.entry(ns.to_string()).or_default();
+        ns_state.weights = weights;
+
+        add_metrics(&mut ns_state.metrics, &contribution);
+        ns_state.set_pod_violations(name, violations);
+        self.metrics
+            .pod_detail_evictions
+            .inc_by(state.pod_details.update(ns, detail) as u64);
+        self.pods
+            .insert(format!("{ns}/{name}"), (ns.to_string(), contribution));
+        true
+    }
 
-                    update_prometheus_metrics(&state);
-                    PODS_TRACKED.set(pod_store.len() as i64);
-                }
-            }
+    /// Remove the pod's contribution, if it is tracked.
+    fn untrack(&mut self, state: &mut ClusterState, ns: &str, name: &str) {
+        if let Some((old_ns, old_metrics)) = self.pods.remove(&format!("{ns}/{name}"))
+            && let Some(ns_state) = state.namespaces.get_mut(&old_ns)
+        {
+            subtract_metrics(&mut ns_state.metrics, &old_metrics);
+            ns_state.violations.remove(name);
         }
+        state.pod_details.remove(ns, name);
     }
 }
 
-/* ============================= PROMETHEUS UPDATE ============================= */
+/// The policy's scoring weights, or the built-in ones without a policy.
+fn scoring_weights(policy: Option<&DevOpsPolicySpec>) -> ScoringWeights {
+    policy.map(ScoringWeights::for_policy).unwrap_or_default()
+}
 
-fn update_prometheus_metrics(state: &ClusterState) {
-    let mut total: i64 = 0;
-    let mut count: i64 = 0;
+/* ============================= PROMETHEUS UPDATE ============================= */
 
+fn update_prometheus_metrics(metrics: &WatchMetrics, state: &ClusterState) {
     for (ns_name, ns_state) in &state.namespaces {
-        let score = calculate_health_score(&ns_state.metrics) as i64;
-        NAMESPACE_SCORE.with_label_values(&[ns_name]).set(score);
-        total += score;
-        count += 1;
+        metrics
+            .namespace_score
+            .with_label_values(&[ns_name])
+            .set(ns_state.score() as i64);
+        for violation_type in governance::VIOLATION_TYPES {
+            metrics
+                .namespace_violations
+                .with_label_values(&[ns_name, violation_type])
+                .set(ns_state.metrics.count(violation_type) as i64);
+        }
+    }
+
+    if let Some(score) = cluster_score(state) {
+        metrics.cluster_score.set(score as i64);
+    }
+}
+
+/// Mean of the namespace scores; `None` before any namespace is tracked.
+pub(crate) fn cluster_score(state: &ClusterState) -> Option<u32> {
+    let count = state.namespaces.len() as u32;
+    let total: u32 = state.namespaces.values().map(NamespaceState::score).sum();
+    (count > 0).then(|| total / count)
+}
+
+/* ============================= JSON API ============================= */
+
+/// `GET /api/v1/namespaces` entry.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NamespaceSummary {
+    namespace: String,
+    score: u32,
+    pods: u32,
+    /// Violations across all types.
+    violations: u32,
+    pods_with_violations: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct NamespaceList {
+    namespaces: Vec<NamespaceSummary>,
+}
+
+/// `GET /api/v1/namespaces/{namespace}/violations`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NamespaceViolations {
+    namespace: String,
+    score: u32,
+    /// Violations per type, every type listed.
+    counts: BTreeMap<&'static str, u32>,
+    pods: Vec<PodViolations>,
+}
+
+#[derive(Debug, Serialize)]
+struct PodViolations {
+    pod: String,
+    violations: Vec<&'static str>,
+}
+
+/// `GET /api/v1/namespaces/{namespace}/pods`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NamespacePods<'a> {
+    namespace: String,
+    score: u32,
+    /// Pods with violations, lowest score first.
+    pods: Vec<&'a PodDetail>,
+    /// Pods with violations whose details were evicted.
+    pods_without_detail: usize,
+}
+
+/// `GET /api/v1/score`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScoreSummary {
+    /// Mean namespace score; 100 before any pod is tracked.
+    score: u32,
+    namespaces: usize,
+    pods: u32,
+}
+
+fn violation_counts(metrics: &PodMetrics) -> BTreeMap<&'static str, u32> {
+    governance::VIOLATION_TYPES
+        .iter()
+        .map(|&violation_type| (violation_type, metrics.count(violation_type)))
+        .collect()
+}
+
+fn namespace_list(state: &ClusterState) -> NamespaceList {
+    let mut namespaces: Vec<NamespaceSummary> = state
+        .namespaces
+        .iter()
+        .map(|(namespace, ns_state)| NamespaceSummary {
+            namespace: namespace.clone(),
+            score: ns_state.score(),
+            pods: ns_state.metrics.total_pods,
+            violations: violation_counts(&ns_state.metrics).values().sum(),
+            pods_with_violations: ns_state.violations.len(),
+        })
+        .collect();
+    namespaces.sort_by(|a, b| a.namespace.cmp(&b.namespace));
+    NamespaceList { namespaces }
+}
+
+fn tracked_namespace<'a>(
+    state: &'a ClusterState,
+    namespace: &str,
+) -> Result<&'a NamespaceState, (StatusCode, String)> {
+    state.namespaces.get(namespace).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            format!("namespace '{namespace}' is not tracked"),
+        )
+    })
+}
+
+fn namespace_violations(
+    state: &ClusterState,
+    namespace: &str,
+) -> Result<NamespaceViolations, (StatusCode, String)> {
+    let ns_state = tracked_namespace(state, namespace)?;
+    Ok(NamespaceViolations {
+        namespace: namespace.to_string(),
+        score: ns_state.score(),
+        counts: violation_counts(&ns_state.metrics),
+        pods: ns_state
+            .violations
+            .iter()
+            .map(|(pod, violations)| PodViolations {
+                pod: pod.clone(),
+                violations: violations.clone(),
+            })
+            .collect(),
+    })
+}
+
+fn namespace_pods<'a>(
+    state: &'a ClusterState,
+    namespace: &str,
+) -> Result<NamespacePods<'a>, (StatusCode, String)> {
+    let ns_state = tracked_namespace(state, namespace)?;
+    let pods = state.pod_details.in_namespace(namespace);
+    Ok(NamespacePods {
+        namespace: namespace.to_string(),
+        score: ns_state.score(),
+        pods_without_detail: ns_state.violations.len().saturating_sub(pods.len()),
+        pods,
+    })
+}
+
+fn score_summary(state: &ClusterState) -> ScoreSummary {
+    ScoreSummary {
+        score: cluster_score(state).unwrap_or(100),
+        namespaces: state.namespaces.len(),
+        pods: state
+            .namespaces
+            .values()
+            .map(|ns| ns.metrics.total_pods)
+            .sum(),
     }
+}
 
-    if count > 0 {
-        CLUSTER_SCORE.set(total / count);
+/// Answer from the live state, or 503 until the initial sync. Errors are
+/// returned as `{"error": "..."}`.
+async fn api_handler(
+    state: std::sync::Arc<Mutex<ClusterState>>,
+    respond: impl FnOnce(&ClusterState) -> Result<serde_json::Value, (StatusCode, String)>,
+) -> Response {
+    let state = state.lock().await;
+    let result = if state.ready {
+        respond(&state)
+    } else {
+        Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            "initial sync in progress".to_string(),
+        ))
+    };
+    match result {
+        Ok(body) => Json(body).into_response(),
+        Err((status, error)) => {
+            (status, Json(serde_json::json!({ "error": error }))).into_response()
+        }
     }
 }
 
@@ -433,7 +1153,7 @@ fn update_prometheus_metrics(state: &ClusterState) {
 
 pub(crate) fn build_router(state: std::sync::Arc<Mutex<ClusterState>>) -> Router {
     Router::new()
-        .route("/metrics", get(metrics_handler))
+        .route("/metrics", get(|| async { encode_metrics(&REGISTRY) }))
         .route("/healthz", get(|| async { (StatusCode::OK, "OK") }))
         .route(
             "/readyz",
@@ -442,6 +1162,45 @@ pub(crate) fn build_router(state: std::sync::Arc<Mutex<ClusterState>>) -> Router
                 move || ready_handler(state.clone())
             }),
         )
+        .merge(api_routes(state))
+}
+
+/// The `/api/v1` JSON endpoints over one cluster's state.
+pub(crate) fn api_routes(state: std::sync::Arc<Mutex<ClusterState>>) -> Router {
+    Router::new()
+        .route(
+            "/api/v1/namespaces",
+            get({
+                let state = state.clone();
+                move || api_handler(state.clone(), |s| Ok(serde_json::json!(namespace_list(s))))
+            }),
+        )
+        .route(
+            "/api/v1/namespaces/:namespace/violations",
+            get({
+                let state = state.clone();
+                move |Path(namespace): Path<String>| {
+                    api_handler(state.clone(), move |s| {
+                        namespace_violations(s, &namespace).map(|v| serde_json::json!(v))
+                    })
+                }
+            }),
+        )
+        .route(
+            "/api/v1/namespaces/:namespace/pods",
+            get({
+                let state = state.clone();
+                move |Path(namespace): Path<String>| {
+                    api_handler(state.clone(), move |s| {
+                        namespace_pods(s, &namespace).map(|p| serde_json::json!(p))
+                    })
+                }
+            }),
+        )
+        .route(
+            "/api/v1/score",
+            get(move || api_handler(state.clone(), |s| Ok(serde_json::json!(score_summary(s))))),
+        )
 }
 
 async fn start_http_server(
@@ -453,7 +1212,7 @@ async fn start_http_server(
 
     let listener = tokio::net::TcpListener::bind(addr)
         .await
-        .context("Failed to bind HTTP server on :8080")?;
+        .with_context(|| format!("Failed to bind HTTP server on {addr}"))?;
 
     info!(addr = %addr, "http_server_started");
 
@@ -475,9 +1234,10 @@ async fn ready_handler(state: std::sync::Arc<Mutex<ClusterState>>) -> impl IntoR
     }
 }
 
-async fn metrics_handler() -> impl IntoResponse {
+/// `registry` in the Prometheus text format.
+pub(crate) fn encode_metrics(registry: &Registry) -> (StatusCode, String) {
     let encoder = TextEncoder::new();
-    let metric_families = REGISTRY.gather();
+    let metric_families = registry.gather();
     let mut buffer = Vec::new();
 
     match encoder.encode(&metric_families, &mut buffer) {
@@ -505,8 +1265,8 @@ mod tests {
 
     fn test_state(ready: bool) -> std::sync::Arc<Mutex<ClusterState>> {
         std::sync::Arc::new(Mutex::new(ClusterState {
-            namespaces: HashMap::new(),
             ready,
+            ..ClusterState::new(100)
         }))
     }
 
@@ -581,7 +1341,7 @@ mod tests {
 
     #[test]
     fn test_pods_tracked_metric_registered() {
-        LazyLock::force(&PODS_TRACKED);
+        LazyLock::force(&METRICS);
         let families = REGISTRY.gather();
         let names: Vec<&str> = families.iter().map(|f| f.get_name()).collect();
         assert!(
@@ -589,4 +1349,436 @@ mod tests {
             "pods_tracked_total should be registered"
         );
     }
+
+    #[test]
+    fn test_namespace_violation_counts_exported() {
+        let mut state = ClusterState {
+            ready: true,
+            ..ClusterState::new(100)
+        };
+        state.namespaces.insert(
+            "violation-breakdown".to_string(),
+            NamespaceState {
+                metrics: PodMetrics {
+                    total_pods: 3,
+                    latest_tag: 2,
+                    missing_liveness: 1,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        );
+        update_prometheus_metrics(&METRICS, &state);
+
+        let count = |violation_type: &str| {
+            METRICS
+                .namespace_violations
+                .with_label_values(&["violation-breakdown", violation_type])
+                .get()
+        };
+        assert_eq!(count("latest_tag"), 2);
+        assert_eq!(count("missing_liveness"), 1);
+        assert_eq!(count("crash_loop"), 0);
+    }
+
+    #[test]
+    fn test_cluster_metrics_share_a_registry() {
+        let registry = Registry::new();
+        let a = WatchMetrics::register(&registry, Some("prod-eu")).unwrap();
+        let b = WatchMetrics::register(&registry, Some("prod-us")).unwrap();
+        a.cluster_score.set(90);
+        b.cluster_score.set(60);
+        assert!(WatchMetrics::register(&registry, Some("prod-eu")).is_err());
+
+        let (status, body) = encode_metrics(&registry);
+        assert_eq!(status, StatusCode::OK);
+        assert!(
+            body.contains("cluster_health_score{cluster=\"prod-eu\"} 90"),
+            "{body}"
+        );
+        assert!(
+            body.contains("cluster_health_score{cluster=\"prod-us\"} 60"),
+            "{body}"
+        );
+    }
+
+    // ── JSON API ──
+
+    fn populated_state() -> std::sync::Arc<Mutex<ClusterState>> {
+        let mut prod = NamespaceState {
+            metrics: PodMetrics {
+                total_pods: 4,
+                latest_tag: 2,
+                missing_liveness: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        prod.set_pod_violations("web-1", vec!["latest_tag", "missing_liveness"]);
+        prod.set_pod_violations("web-2", vec!["latest_tag"]);
+        prod.set_pod_violations("web-3", vec![]);
+        let dev = NamespaceState {
+            metrics: PodMetrics {
+                total_pods: 2,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut pod_details = PodDetailStore::new(100);
+        pod_details.update(
+            "prod",
+            detail("web-1", 70, &["latest_tag", "missing_liveness"]),
+        );
+        pod_details.update("prod", detail("web-2", 80, &["latest_tag"]));
+        std::sync::Arc::new(Mutex::new(ClusterState {
+            namespaces: HashMap::from([("prod".to_string(), prod), ("dev".to_string(), dev)]),
+            pod_details,
+            ready: true,
+        }))
+    }
+
+    fn detail(pod: &str, score: u32, types: &[&str]) -> PodDetail {
+        PodDetail {
+            pod: pod.to_string(),
+            score,
+            violations: types
+                .iter()
+                .map(|&violation_type| ViolationDetail {
+                    violation_type: violation_type.to_string(),
+                    severity: governance::default_severity(violation_type),
+                    pod_name: pod.to_string(),
+                    namespace: "prod".to_string(),
+                    container_name: "app".to_string(),
+                    message: format!("{violation_type} in app"),
+                })
+                .collect(),
+        }
+    }
+
+    async fn get_json(
+        state: std::sync::Arc<Mutex<ClusterState>>,
+        uri: &str,
+    ) -> (StatusCode, serde_json::Value) {
+        let req = Request::builder().uri(uri).body(Body::empty()).unwrap();
+        let resp = build_router(state).oneshot(req).await.unwrap();
+        let status = resp.status();
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_api_namespaces_sorted_with_totals() {
+        let (status, body) = get_json(populated_state(), "/api/v1/namespaces").await;
+        assert_eq!(status, StatusCode::OK);
+        let namespaces = body["namespaces"].as_array().unwrap();
+        assert_eq!(namespaces[0]["namespace"], "dev");
+        assert_eq!(namespaces[0]["score"], 100);
+        assert_eq!(namespaces[1]["namespace"], "prod");
+        assert_eq!(namespaces[1]["pods"], 4);
+        assert_eq!(namespaces[1]["violations"], 3);
+        assert_eq!(namespaces[1]["podsWithViolations"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_api_namespace_violations() {
+        let (status, body) =
+            get_json(populated_state(), "/api/v1/namespaces/prod/violations").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["counts"]["latest_tag"], 2);
+        assert_eq!(body["counts"]["crash_loop"], 0);
+        assert_eq!(body["pods"][0]["pod"], "web-1");
+        assert_eq!(
+            body["pods"][0]["violations"],
+            serde_json::json!(["latest_tag", "missing_liveness"])
+        );
+        assert_eq!(body["pods"].as_array().unwrap().len(), 2);
+
+        let (status, body) =
+            get_json(populated_state(), "/api/v1/namespaces/missing/violations").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["error"], "namespace 'missing' is not tracked");
+    }
+
+    #[tokio::test]
+    async fn test_api_namespace_pods_worst_first() {
+        let state = populated_state();
+        state
+            .lock()
+            .await
+            .pod_details
+            .update("prod", detail("web-2", 60, &["latest_tag", "crash_loop"]));
+        let (status, body) = get_json(state, "/api/v1/namespaces/prod/pods").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["pods"][0]["pod"], "web-2");
+        assert_eq!(body["pods"][0]["score"], 60);
+        assert_eq!(
+            body["pods"][0]["violations"][1]["violationType"],
+            "crash_loop"
+        );
+        assert_eq!(body["pods"][0]["violations"][1]["severity"], "critical");
+        assert_eq!(body["pods"][1]["pod"], "web-1");
+        assert_eq!(body["podsWithoutDetail"], 0);
+
+        let (status, body) = get_json(populated_state(), "/api/v1/namespaces/dev/pods").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["pods"].as_array().unwrap().is_empty());
+    }
+
+    // ── Pod detail store ──
+
+    #[test]
+    fn test_pod_detail_store_evicts_least_recently_updated() {
+        let mut store = PodDetailStore::new(2);
+        assert_eq!(
+            store.update("prod", detail("web-1", 70, &["latest_tag"])),
+            0
+        );
+        assert_eq!(
+            store.update("prod", detail("web-2", 70, &["latest_tag"])),
+            0
+        );
+        // Updating web-1 makes web-2 the oldest entry
+        assert_eq!(
+            store.update("prod", detail("web-1", 50, &["crash_loop"])),
+            0
+        );
+        assert_eq!(store.update("dev", detail("api-1", 80, &["pending"])), 1);
+
+        assert_eq!(store.len(), 2);
+        let prod: Vec<&str> = store
+            .in_namespace("prod")
+            .iter()
+            .map(|d| d.pod.as_str())
+            .collect();
+        assert_eq!(prod, ["web-1"]);
+        assert_eq!(store.in_namespace("dev")[0].pod, "api-1");
+    }
+
+    #[test]
+    fn test_pod_detail_store_drops_compliant_pods() {
+        let mut store = PodDetailStore::new(10);
+        store.update("prod", detail("web-1", 70, &["latest_tag"]));
+        store.update("prod", detail("web-1", 100, &[]));
+        assert_eq!(store.len(), 0);
+
+        store.update("prod", detail("web-2", 70, &["latest_tag"]));
+        store.remove("prod", "web-2");
+        assert!(store.in_namespace("prod").is_empty());
+        assert!(store.order.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_api_score() {
+        let state = populated_state();
+        let expected = cluster_score(&*state.lock().await).unwrap();
+        let (status, body) = get_json(state, "/api/v1/score").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["score"], expected);
+        assert_eq!(body["namespaces"], 2);
+        assert_eq!(body["pods"], 6);
+    }
+
+    #[tokio::test]
+    async fn test_api_unavailable_before_sync() {
+        let (status, body) = get_json(test_state(false), "/api/v1/score").await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["error"], "initial sync in progress");
+    }
+
+    // ── Watch events ──
+
+    fn pod(namespace: &str, name: &str, image: &str) -> Pod {
+        use k8s_openapi::api::core::v1::{Container, PodSpec};
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "app".to_string(),
+                    image: Some(image.to_string()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn tracker() -> PodTracker {
+        PodTracker::new(METRICS.clone(), None, false, NamespaceFilter::default())
+    }
+
+    #[test]
+    fn test_restarted_maps_to_init_steps() {
+        let updates = pod_updates(Event::Restarted(vec![
+            pod("prod", "web-1", "nginx:1.25"),
+            pod("prod", "web-2", "nginx:1.25"),
+        ]));
+        assert!(matches!(
+            updates.as_slice(),
+            [
+                PodUpdate::Init,
+                PodUpdate::InitApply(_),
+                PodUpdate::InitApply(_),
+                PodUpdate::InitDone
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_relist_replaces_state_at_init_done() {
+        let mut state = ClusterState::new(100);
+        let mut tracker = tracker();
+        tracker.apply(
+            &mut state,
+            PodUpdate::Applied(pod("prod", "web-1", "nginx:latest")),
+        );
+        assert!(state.ready);
+
+        tracker.apply(&mut state, PodUpdate::Init);
+        tracker.apply(
+            &mut state,
+            PodUpdate::InitApply(pod("dev", "api-1", "api:1.0")),
+        );
+        // The previous state is served until the list completes
+        assert_eq!(state.namespaces["prod"].metrics.latest_tag, 1);
+        assert!(!state.namespaces.contains_key("dev"));
+
+        tracker.apply(&mut state, PodUpdate::InitDone);
+        assert!(!state.namespaces.contains_key("prod"));
+        assert_eq!(state.namespaces["dev"].metrics.total_pods, 1);
+        assert_eq!(state.pod_details.in_namespace("dev")[0].pod, "api-1");
+        assert!(state.pod_details.in_namespace("prod").is_empty());
+        assert_eq!(tracker.pods.len(), 1);
+    }
+
+    #[test]
+    fn test_tracker_applies_and_deletes() {
+        let mut state = ClusterState::new(100);
+        let mut tracker = tracker();
+        tracker.apply(
+            &mut state,
+            PodUpdate::Applied(pod("prod", "web-1", "nginx:latest")),
+        );
+        tracker.apply(
+            &mut state,
+            PodUpdate::Applied(pod("prod", "web-1", "nginx:1.25")),
+        );
+        tracker.apply(
+            &mut state,
+            PodUpdate::Applied(pod("kube-system", "dns", "coredns:latest")),
+        );
+        let prod = &state.namespaces["prod"];
+        assert_eq!(prod.metrics.total_pods, 1);
+        assert_eq!(prod.metrics.latest_tag, 0);
+        assert!(!state.namespaces.contains_key("kube-system"));
+
+        tracker.apply(
+            &mut state,
+            PodUpdate::Deleted(pod("prod", "web-1", "nginx:1.25")),
+        );
+        assert_eq!(state.namespaces["prod"].metrics.total_pods, 0);
+        assert!(state.pod_details.in_namespace("prod").is_empty());
+        assert!(tracker.pods.is_empty());
+    }
+
+    #[test]
+    fn test_watch_retry_delay_doubles_to_cap() {
+        assert_eq!(watch_retry_delay(0), Duration::from_secs(1));
+        assert_eq!(watch_retry_delay(3), Duration::from_secs(8));
+        assert_eq!(watch_retry_delay(6), WATCH_RETRY_MAX);
+        assert_eq!(watch_retry_delay(40), WATCH_RETRY_MAX);
+    }
+
+    #[test]
+    fn test_jittered_stays_within_ten_percent() {
+        let base = Duration::from_secs(600);
+        for _ in 0..100 {
+            let delay = jittered(base);
+            assert!(delay >= Duration::from_secs(540), "{delay:?}");
+            assert!(delay <= Duration::from_secs(660), "{delay:?}");
+        }
+    }
+
+    // ── Namespace filter ──
+
+    #[test]
+    fn test_namespace_filter() {
+        let all = NamespaceFilter::default();
+        assert!(all.allows("production"));
+        assert!(!all.allows("kube-system"));
+
+        let scoped = NamespaceFilter {
+            include: vec!["production".to_string(), "monitoring".to_string()],
+            exclude: vec!["monitoring".to_string()],
+        };
+        assert!(scoped.allows("production"));
+        assert!(!scoped.allows("staging"));
+        assert!(!scoped.allows("monitoring"));
+    }
+
+    // ── Leader election ──
+
+    fn held_by(holder: &str, renewed: DateTime<Utc>) -> LeaseSpec {
+        LeaseSpec {
+            holder_identity: Some(holder.to_string()),
+            lease_duration_seconds: Some(15),
+            renew_time: Some(MicroTime(renewed)),
+            lease_transitions: Some(3),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_leader_identity_is_unique_per_process_start() {
+        let identity = leader_identity();
+        assert!(identity.starts_with(&clock::instance_identity()));
+        assert_eq!(identity.len(), clock::instance_identity().len() + 7);
+    }
+
+    #[test]
+    fn test_next_lease_spec_creates_fresh_lease() {
+        let now = Utc::now();
+        let spec = next_lease_spec(None, "pod-a-123456", 15, now).unwrap();
+        assert_eq!(spec.holder_identity.as_deref(), Some("pod-a-123456"));
+        assert_eq!(spec.lease_transitions, Some(1));
+        assert_eq!(spec.acquire_time, Some(MicroTime(now)));
+    }
+
+    #[test]
+    fn test_next_lease_spec_renews_own_lease() {
+        let now = Utc::now();
+        let current = held_by("pod-a", now - chrono::Duration::seconds(5));
+        let spec = next_lease_spec(Some(&current), "pod-a", 15, now).unwrap();
+        assert_eq!(spec.renew_time, Some(MicroTime(now)));
+        assert_eq!(spec.lease_transitions, Some(3));
+        assert!(spec.acquire_time.is_none());
+    }
+
+    #[test]
+    fn test_next_lease_spec_respects_other_holder() {
+        let now = Utc::now();
+        let current = held_by("pod-a", now - chrono::Duration::seconds(5));
+        assert!(next_lease_spec(Some(&current), "pod-b", 15, now).is_none());
+    }
+
+    #[test]
+    fn test_next_lease_spec_takes_over_expired_or_released_lease() {
+        let now = Utc::now();
+        let expired = held_by("pod-a", now - chrono::Duration::seconds(60));
+        let spec = next_lease_spec(Some(&expired), "pod-b", 15, now).unwrap();
+        assert_eq!(spec.holder_identity.as_deref(), Some("pod-b"));
+        assert_eq!(spec.lease_transitions, Some(4));
+
+        let released = LeaseSpec {
+            holder_identity: None,
+            renew_time: None,
+            ..held_by("pod-a", now)
+        };
+        let spec = next_lease_spec(Some(&released), "pod-b", 15, now).unwrap();
+        assert_eq!(spec.holder_identity.as_deref(), Some("pod-b"));
+    }
 }
@@ -1,5 +1,6 @@
 use std::net::SocketAddr;
 use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
 
 use anyhow::{Context, Result};
 use axum::Router;
@@ -7,16 +8,28 @@ use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::routing::{get, post};
-use kube::api::ListParams;
-use kube::{Api, Client};
-use prometheus::{Encoder, Histogram, IntCounterVec, Registry, TextEncoder};
+use axum_server::tls_rustls::RustlsConfig;
+use kube::Api;
+use kube::api::PostParams;
+use kube::core::conversion::ConversionReview;
+use prometheus::{Encoder, Histogram, IntCounter, IntCounterVec, Registry, TextEncoder};
 use tokio::sync::broadcast;
-use tracing::info;
+use tracing::{Instrument, Span, field, info, info_span, warn};
 
+use k8s_openapi::api::admissionregistration::v1::{
+    ValidatingWebhook, ValidatingWebhookConfiguration,
+};
 use k8s_openapi::api::core::v1::Pod;
-use kube_devops::admission::{self, AdmissionVerdict};
-use kube_devops::crd::DevOpsPolicy;
-use kube_devops::governance;
+use kube_devops::admission::{self, AdmissionVerdict, FailureMode};
+use kube_devops::conversion;
+use kube_devops::crd::Severity;
+use kube_devops::governance::{self, ViolationDetail};
+use kube_devops::policy_resolution;
+use kube_devops::rbac;
+
+use crate::commands::client;
+use crate::commands::policy_cache::{NamespaceCache, PolicyCache};
+use crate::otlp;
 
 /* ============================= PROMETHEUS ============================= */
 
@@ -49,6 +62,75 @@ static WEBHOOK_DENIALS: LazyLock<IntCounterVec> = LazyLock::new(|| {
     c
 });
 
+static WEBHOOK_WARNINGS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let c = IntCounterVec::new(
+        prometheus::Opts::new(
+            "webhook_warnings_total",
+            "Violations below minDenySeverity admitted with a warning, by namespace and violation",
+        ),
+        &["namespace", "violation"],
+    )
+    .expect("metric definition is valid");
+    WEBHOOK_REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static WEBHOOK_FAILURES: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let c = IntCounterVec::new(
+        prometheus::Opts::new(
+            "webhook_failures_total",
+            "Admission requests the webhook could not evaluate, by reason",
+        ),
+        &["reason"],
+    )
+    .expect("metric definition is valid");
+    WEBHOOK_REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static WEBHOOK_TIMEOUTS: LazyLock<IntCounter> = LazyLock::new(|| {
+    let c = IntCounter::new(
+        "webhook_timeouts_total",
+        "Admission requests whose evaluation exceeded the latency budget",
+    )
+    .expect("metric definition is valid");
+    WEBHOOK_REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static WEBHOOK_CERT_RELOADS: LazyLock<IntCounter> = LazyLock::new(|| {
+    let c = IntCounter::new(
+        "webhook_cert_reloads_total",
+        "TLS certificate reloads after the cert/key files changed",
+    )
+    .expect("metric definition is valid");
+    WEBHOOK_REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
+static WEBHOOK_CONVERSIONS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let c = IntCounterVec::new(
+        prometheus::Opts::new(
+            "webhook_conversions_total",
+            "DevOpsPolicy ConversionReviews answered, by desired version and result",
+        ),
+        &["desired_version", "result"],
+    )
+    .expect("metric definition is valid");
+    WEBHOOK_REGISTRY
+        .register(Box::new(c.clone()))
+        .expect("metric not yet registered");
+    c
+});
+
 static WEBHOOK_DURATION: LazyLock<Histogram> = LazyLock::new(|| {
     let h = Histogram::with_opts(prometheus::HistogramOpts::new(
         "webhook_request_duration_seconds",
@@ -63,19 +145,68 @@ static WEBHOOK_DURATION: LazyLock<Histogram> = LazyLock::new(|| {
 
 /* ============================= STATE ============================= */
 
+/// How long startup waits for the policy cache before serving anyway.
+const CACHE_SYNC_TIMEOUT: Duration = Duration::from_secs(30);
+
 #[derive(Clone)]
 pub(crate) struct WebhookState {
-    pub(crate) client: Client,
     pub(crate) ready: bool,
+    /// Allow every request, returning would-be denials as warnings.
+    pub(crate) read_only: bool,
+    /// Reflector cache of DevOpsPolicies and (RBAC permitting) ClusterDevOpsPolicies.
+    pub(crate) policies: PolicyCache,
+    /// Namespace label cache for selecting policy profiles; `None` when RBAC
+    /// does not allow watching namespaces, in which case profiles are skipped.
+    pub(crate) namespaces: Option<NamespaceCache>,
+    /// Allow or deny requests that cannot be evaluated.
+    pub(crate) failure_mode: FailureMode,
+    /// Evaluation deadline per request; see `admission::evaluation_budget`.
+    pub(crate) budget: Duration,
+    /// Deny threshold for namespaces whose policy sets no `minDenySeverity`.
+    pub(crate) min_deny_severity: Option<Severity>,
 }
 
 /* ============================= ENTRY: SERVE ============================= */
 
-pub async fn serve(addr_str: &str, tls_cert: &str, tls_key: &str) -> Result<()> {
+/// Runtime settings for `kube-devops webhook serve`.
+#[derive(Debug, Clone)]
+pub struct ServeSettings {
+    pub addr: SocketAddr,
+    pub tls_cert: String,
+    pub tls_key: String,
+    pub failure_mode: FailureMode,
+    /// `timeoutSeconds` of the webhook registration.
+    pub timeout_seconds: u32,
+    /// Deny threshold for namespaces whose policy sets no `minDenySeverity`.
+    pub min_deny_severity: Option<Severity>,
+}
+
+impl Default for ServeSettings {
+    fn default() -> Self {
+        Self {
+            addr: SocketAddr::from(([0, 0, 0, 0], 8443)),
+            tls_cert: "tls.crt".to_string(),
+            tls_key: "tls.key".to_string(),
+            failure_mode: FailureMode::Open,
+            timeout_seconds: admission::DEFAULT_TIMEOUT_SECONDS,
+            min_deny_severity: None,
+        }
+    }
+}
+
+pub async fn serve(settings: ServeSettings, read_only: bool) -> Result<()> {
+    let ServeSettings {
+        addr,
+        tls_cert,
+        tls_key,
+        failure_mode,
+        timeout_seconds,
+        min_deny_severity,
+    } = settings;
     println!("Starting admission webhook server...\n");
     info!("webhook_starting");
 
-    let client = Client::try_default()
+    let client = client::connect()
         .await
         .context("Failed to connect to Kubernetes cluster")?;
 
@@ -88,14 +219,35 @@ pub async fn serve(addr_str: &str, tls_cert: &str, tls_key: &str) -> Result<()>
         }
     }
 
+    let report = rbac::startup_check(&client, &rbac::webhook_permissions()).await?;
+
     // Validate TLS certificate and key files exist
     print!("  TLS ......................... ");
-    validate_tls_files(tls_cert, tls_key)?;
+    validate_tls_files(&tls_cert, &tls_key)?;
     println!("loaded ({}, {})", tls_cert, tls_key);
 
-    let addr: SocketAddr = addr_str.parse().context("Invalid address format")?;
-
     println!("  HTTPS server ................ https://{addr}");
+    if read_only {
+        println!("  Mode ........................ read-only (allow with warnings)");
+    }
+    println!(
+        "  Latency budget .............. {}ms (timeoutSeconds {timeout_seconds})",
+        admission::evaluation_budget(timeout_seconds).as_millis()
+    );
+    println!(
+        "  Failure mode ................ {}",
+        if failure_mode.allows() {
+            "open (allow on error)"
+        } else {
+            "closed (deny on error)"
+        }
+    );
+    if let Some(severity) = &min_deny_severity {
+        println!(
+            "  Deny threshold .............. {} (lower severities warn)",
+            format!("{severity:?}").to_lowercase()
+        );
+    }
     println!();
     println!("  Available endpoints:");
     println!("    POST /validate ............ Admission review handler");
@@ -106,14 +258,29 @@ pub async fn serve(addr_str: &str, tls_cert: &str, tls_key: &str) -> Result<()>
     println!("Admission webhook running. Press Ctrl+C to stop.\n");
     println!("{}", "=".repeat(70));
 
+    let policies = PolicyCache::spawn(
+        &client,
+        None,
+        report.feature_enabled(rbac::FEATURE_CLUSTER_POLICIES),
+    );
+    print!("  Policy cache ................ ");
+    match tokio::time::timeout(CACHE_SYNC_TIMEOUT, policies.wait_until_synced()).await {
+        Ok(true) => println!("synced ({} policies)", policies.policy_count()),
+        _ => println!("not synced (requests follow the failure mode until it is)"),
+    }
+
     let state = WebhookState {
-        client,
         ready: true,
+        read_only,
+        policies,
+        namespaces: report
+            .feature_enabled(rbac::FEATURE_PROFILES)
+            .then(|| NamespaceCache::spawn(&client)),
+        failure_mode,
+        budget: admission::evaluation_budget(timeout_seconds),
+        min_deny_severity,
     };
 
-    let tls_cert = tls_cert.to_string();
-    let tls_key = tls_key.to_string();
-
     let (shutdown_tx, _) = broadcast::channel::<()>(1);
     let http_shutdown = shutdown_tx.subscribe();
 
@@ -137,6 +304,13 @@ pub async fn serve(addr_str: &str, tls_cert: &str, tls_key: &str) -> Result<()>
 
 /* ============================= TLS ============================= */
 
+/// Select aws-lc-rs as the process-wide rustls provider. rustls cannot
+/// choose one itself when several are compiled in (reqwest brings ring).
+fn install_crypto_provider() {
+    // Err means a provider is already installed, which is fine
+    let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+}
+
 fn validate_tls_files(cert_path: &str, key_path: &str) -> Result<()> {
     if !std::path::Path::new(cert_path).exists() {
         anyhow::bail!("TLS certificate file not found: {}", cert_path);
@@ -147,11 +321,59 @@ fn validate_tls_files(cert_path: &str, key_path: &str) -> Result<()> {
     Ok(())
 }
 
+/// How often the cert/key files are checked for rotation.
+const CERT_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Modification times of the cert and key files, or `None` while either is
+/// missing (a secret volume mid-update).
+fn tls_files_version(cert_path: &str, key_path: &str) -> Option<(SystemTime, SystemTime)> {
+    let modified = |path: &str| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    Some((modified(cert_path)?, modified(key_path)?))
+}
+
+/// Reload `config` if the cert/key files changed since `loaded`. Returns
+/// whether a reload happened; on error `loaded` is kept so the next poll
+/// retries and the previous certificate stays in use.
+async fn reload_tls_if_changed(
+    config: &RustlsConfig,
+    cert_path: &str,
+    key_path: &str,
+    loaded: &mut Option<(SystemTime, SystemTime)>,
+) -> Result<bool> {
+    let current = tls_files_version(cert_path, key_path);
+    if current.is_none() || current == *loaded {
+        return Ok(false);
+    }
+    config
+        .reload_from_pem_file(cert_path, key_path)
+        .await
+        .context("Failed to reload TLS configuration")?;
+    *loaded = current;
+    WEBHOOK_CERT_RELOADS.inc();
+    info!(cert = %cert_path, key = %key_path, "tls_certificates_reloaded");
+    Ok(true)
+}
+
+/// Poll the cert/key files so a rotated secret (e.g. from cert-manager) is
+/// picked up without restarting the pod.
+async fn watch_tls_files(config: RustlsConfig, cert_path: String, key_path: String) {
+    let mut loaded = tls_files_version(&cert_path, &key_path);
+    let mut ticker = tokio::time::interval(CERT_POLL_INTERVAL);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        if let Err(e) = reload_tls_if_changed(&config, &cert_path, &key_path, &mut loaded).await {
+            warn!(error = %e, "tls_reload_failed");
+        }
+    }
+}
+
 /* ============================= HTTPS SERVER ============================= */
 
 pub(crate) fn build_webhook_router(state: WebhookState) -> Router {
     Router::new()
         .route("/validate", post(admission_handler))
+        .route(conversion::CONVERSION_PATH, post(conversion_handler))
         .route("/healthz", get(|| async { (StatusCode::OK, "OK") }))
         .route(
             "/readyz",
@@ -173,9 +395,15 @@ async fn start_https_server(
 ) -> Result<()> {
     let app = build_webhook_router(state);
 
-    let rustls_config = axum_server::tls_rustls::RustlsConfig::from_pem_file(tls_cert, tls_key)
+    install_crypto_provider();
+    let rustls_config = RustlsConfig::from_pem_file(tls_cert, tls_key)
         .await
         .context("Failed to load TLS configuration")?;
+    let tls_watcher = tokio::spawn(watch_tls_files(
+        rustls_config.clone(),
+        tls_cert.to_string(),
+        tls_key.to_string(),
+    ));
 
     info!(addr = %addr, "https_server_started");
 
@@ -187,16 +415,18 @@ async fn start_https_server(
         shutdown_handle.graceful_shutdown(Some(std::time::Duration::from_secs(5)));
     });
 
-    axum_server::bind_rustls(addr, rustls_config)
+    let served = axum_server::bind_rustls(addr, rustls_config)
         .handle(handle)
         .serve(app.into_make_service())
-        .await?;
+        .await;
+    tls_watcher.abort();
+    served?;
 
     Ok(())
 }
 
 async fn ready_handler(state: WebhookState) -> impl IntoResponse {
-    if state.ready {
+    if state.ready && state.policies.is_synced() {
         (StatusCode::OK, "READY")
     } else {
         (StatusCode::SERVICE_UNAVAILABLE, "NOT READY")
@@ -225,37 +455,63 @@ async fn webhook_metrics_handler() -> impl IntoResponse {
 
 /* ============================= ADMISSION HANDLER ============================= */
 
+/// Short violation type label for `webhook_denials_total`.
+fn denial_label(violation: &str) -> &'static str {
+    if violation.contains("required label") || violation.contains("required annotation") {
+        "missing_metadata"
+    } else if violation.contains("sha256 digest") {
+        "unpinned_image"
+    } else if violation.contains("imagePullPolicy") {
+        "pull_policy"
+    } else if violation.contains(":latest") || violation.contains("image tag") {
+        "latest_tag"
+    } else if violation.contains("liveness") {
+        "missing_liveness"
+    } else if violation.contains("readiness") {
+        "missing_readiness"
+    } else if violation.contains("resource requests/limits") {
+        "missing_resources"
+    } else {
+        "unknown"
+    }
+}
+
 async fn admission_handler(State(state): State<WebhookState>, body: String) -> impl IntoResponse {
+    let span = otlp::traced(info_span!(
+        "admission_review",
+        otel.kind = "server",
+        namespace = field::Empty,
+        operation = field::Empty,
+        trace_id = field::Empty,
+    ));
+    review(state, body).instrument(span).await
+}
+
+async fn review(state: WebhookState, body: String) -> impl IntoResponse {
     let _timer = WEBHOOK_DURATION.start_timer();
 
-    let review: serde_json::Value = match serde_json::from_str(&body) {
-        Ok(v) => v,
+    let request = match admission::parse_admission_review(&body) {
+        Ok(r) => r,
         Err(e) => {
             info!(error = %e, "invalid_admission_review");
-            return (
-                StatusCode::BAD_REQUEST,
-                serde_json::json!({
-                    "apiVersion": "admission.k8s.io/v1",
-                    "kind": "AdmissionReview",
-                    "response": {
-                        "uid": "",
-                        "allowed": true
-                    }
-                })
-                .to_string(),
+            let (_, response) = failure_response(
+                state.failure_mode,
+                state.read_only,
+                "",
+                "UNKNOWN",
+                "invalid_review",
+                &e,
             );
+            return (StatusCode::BAD_REQUEST, response);
         }
     };
-
-    let uid = review["request"]["uid"].as_str().unwrap_or("").to_string();
-    let operation = review["request"]["operation"]
-        .as_str()
-        .unwrap_or("UNKNOWN")
-        .to_string();
-    let namespace = review["request"]["namespace"]
-        .as_str()
-        .unwrap_or("default")
-        .to_string();
+    let workload = request.workload();
+    let uid = request.uid;
+    let operation = request.operation;
+    let namespace = request.namespace;
+    Span::current()
+        .record("namespace", namespace.as_str())
+        .record("operation", operation.as_str());
 
     // System namespace bypass
     if governance::is_system_namespace(&namespace) {
@@ -263,35 +519,75 @@ async fn admission_handler(State(state): State<WebhookState>, body: String) -> i
         WEBHOOK_REQUESTS
             .with_label_values(&[&operation, "true"])
             .inc();
-        return (StatusCode::OK, build_admission_response(&uid, true, None));
+        return (
+            StatusCode::OK,
+            admission::build_admission_response(&uid, true, None, &[]),
+        );
     }
 
-    // Extract pod from the admission request
-    let pod: Pod = match serde_json::from_value(review["request"]["object"].clone()) {
+    // Extract the pod (or workload pod template) from the admission request
+    let pod: Pod = match request.pod {
         Ok(p) => p,
         Err(e) => {
-            info!(error = %e, "failed_to_parse_pod");
-            // Fail-open: if we can't parse the pod, allow it
-            WEBHOOK_REQUESTS
-                .with_label_values(&[&operation, "true"])
-                .inc();
-            return (StatusCode::OK, build_admission_response(&uid, true, None));
+            info!(error = %e, kind = %request.kind, "failed_to_parse_pod");
+            return failure_response(
+                state.failure_mode,
+                state.read_only,
+                &uid,
+                &operation,
+                "decode_error",
+                &e,
+            );
         }
     };
 
     // Look up DevOpsPolicy for the namespace
-    let verdict = match lookup_policy_and_validate(&state.client, &namespace, &pod).await {
-        Ok(v) => v,
-        Err(e) => {
-            // Fail-open: if we can't look up the policy, allow the request
-            info!(error = %e, namespace = %namespace, "policy_lookup_failed_failopen");
-            WEBHOOK_REQUESTS
-                .with_label_values(&[&operation, "true"])
-                .inc();
-            return (StatusCode::OK, build_admission_response(&uid, true, None));
+    let evaluation = tokio::time::timeout(
+        state.budget,
+        lookup_policy_and_validate(&state, &namespace, &pod, request.old_pod.as_ref()),
+    );
+    let (verdict, warnings) = match evaluation.await {
+        Err(_) => return budget_exceeded_response(&state, &uid, &operation, &namespace),
+        Ok(Ok((v, warnings))) => match &workload {
+            Some(workload) => (v.for_workload(workload), warnings),
+            None => (v, warnings),
+        },
+        Ok(Err(e)) => {
+            info!(error = %e, namespace = %namespace, "policy_lookup_failed");
+            return failure_response(
+                state.failure_mode,
+                state.read_only,
+                &uid,
+                &operation,
+                "policy_lookup",
+                &e,
+            );
         }
     };
 
+    // Below-threshold violations never deny; count them and pass them back as warnings
+    let warnings: Vec<String> = warnings
+        .iter()
+        .map(|w| {
+            WEBHOOK_WARNINGS
+                .with_label_values(&[&namespace, &w.violation_type])
+                .inc();
+            let warning = admission::format_admission_warning(w);
+            match &workload {
+                Some(workload) => format!("{workload}: {warning}"),
+                None => warning,
+            }
+        })
+        .collect();
+    if !warnings.is_empty() {
+        info!(
+            namespace = %namespace,
+            workload = workload.as_deref().unwrap_or("-"),
+            warnings = ?warnings,
+            "admission_warnings"
+        );
+    }
+
     let allowed_str = if verdict.allowed { "true" } else { "false" };
     WEBHOOK_REQUESTS
         .with_label_values(&[&operation, allowed_str])
@@ -299,72 +595,216 @@ async fn admission_handler(State(state): State<WebhookState>, body: String) -> i
 
     if !verdict.allowed {
         for violation in &verdict.violations {
-            // Extract a short violation type label
-            let violation_type = if violation.contains(":latest") {
-                "latest_tag"
-            } else if violation.contains("liveness") {
-                "missing_liveness"
-            } else if violation.contains("readiness") {
-                "missing_readiness"
-            } else {
-                "unknown"
-            };
             WEBHOOK_DENIALS
-                .with_label_values(&[&namespace, violation_type])
+                .with_label_values(&[&namespace, denial_label(violation)])
                 .inc();
         }
         info!(
             namespace = %namespace,
+            workload = workload.as_deref().unwrap_or("-"),
             violations = ?verdict.violations,
+            read_only = state.read_only,
             "admission_denied"
         );
+
+        if state.read_only {
+            let would_deny = verdict
+                .violations
+                .iter()
+                .map(|v| admission::format_read_only_warning(v));
+            let warnings: Vec<String> = would_deny.chain(warnings).collect();
+            return (
+                StatusCode::OK,
+                admission::build_allow_with_warnings(&uid, &warnings),
+            );
+        }
     }
 
     (
         StatusCode::OK,
-        build_admission_response(&uid, verdict.allowed, verdict.message.as_deref()),
+        admission::build_admission_response(
+            &uid,
+            verdict.allowed,
+            verdict.message.as_deref(),
+            &warnings,
+        ),
     )
 }
 
+/// Answer a request whose evaluation ran past the latency budget: admit it
+/// with a warning so a slow policy lookup never blocks pod creation, unless
+/// the failure mode is closed.
+fn budget_exceeded_response(
+    state: &WebhookState,
+    uid: &str,
+    operation: &str,
+    namespace: &str,
+) -> (StatusCode, String) {
+    WEBHOOK_TIMEOUTS.inc();
+    let budget_ms = state.budget.as_millis();
+    info!(namespace = %namespace, budget_ms, "admission_budget_exceeded");
+    if !state.failure_mode.allows() {
+        return failure_response(
+            state.failure_mode,
+            state.read_only,
+            uid,
+            operation,
+            "timeout",
+            &format!("evaluation exceeded {budget_ms}ms budget"),
+        );
+    }
+    WEBHOOK_REQUESTS
+        .with_label_values(&[operation, "true"])
+        .inc();
+    (
+        StatusCode::OK,
+        admission::build_allow_with_warnings(
+            uid,
+            &[format!(
+                "kube-devops: policy evaluation exceeded its {budget_ms}ms budget; admitted without checks"
+            )],
+        ),
+    )
+}
+
+/// Answer a request that could not be evaluated according to the failure
+/// mode. Read-only mode still allows, surfacing the would-be denial.
+fn failure_response(
+    failure_mode: FailureMode,
+    read_only: bool,
+    uid: &str,
+    operation: &str,
+    reason: &str,
+    error: &dyn std::fmt::Display,
+) -> (StatusCode, String) {
+    WEBHOOK_FAILURES.with_label_values(&[reason]).inc();
+    if failure_mode.allows() {
+        WEBHOOK_REQUESTS
+            .with_label_values(&[operation, "true"])
+            .inc();
+        return (
+            StatusCode::OK,
+            admission::build_admission_response(uid, true, None, &[]),
+        );
+    }
+
+    let message = admission::format_failure_message(&format!("{reason}: {error}"));
+    if read_only {
+        WEBHOOK_REQUESTS
+            .with_label_values(&[operation, "true"])
+            .inc();
+        return (
+            StatusCode::OK,
+            admission::build_warning_response(uid, &[message]),
+        );
+    }
+    WEBHOOK_REQUESTS
+        .with_label_values(&[operation, "false"])
+        .inc();
+    (
+        StatusCode::OK,
+        admission::build_admission_response(uid, false, Some(&message), &[]),
+    )
+}
+
+/// Validate `pod` against the namespace's effective policy. For an UPDATE,
+/// violations `old_pod` already had are neither grounds for denial nor
+/// repeated as warnings.
+///
+/// With a deny threshold (the policy's `minDenySeverity`, else the
+/// `--min-deny-severity` flag) the violations below it are returned
+/// separately as warnings.
 async fn lookup_policy_and_validate(
-    client: &Client,
+    state: &WebhookState,
     namespace: &str,
     pod: &Pod,
-) -> Result<AdmissionVerdict> {
-    let policies: Api<DevOpsPolicy> = Api::namespaced(client.clone(), namespace);
-    let policy_list = policies.list(&ListParams::default()).await?;
+    old_pod: Option<&Pod>,
+) -> Result<(AdmissionVerdict, Vec<ViolationDetail>)> {
+    // Until the first list completes an empty cache would admit everything
+    if !state.policies.is_synced() {
+        anyhow::bail!("policy cache not synced yet");
+    }
 
-    if policy_list.items.is_empty() {
+    // The first policy in the namespace by name, layered over any cluster policies
+    let Some(policy) = state.policies.effective_policy(namespace) else {
         // No policy → allow (fail-open)
-        return Ok(AdmissionVerdict {
-            allowed: true,
-            message: None,
-            violations: Vec::new(),
-        });
-    }
+        return Ok((
+            AdmissionVerdict {
+                allowed: true,
+                message: None,
+                violations: Vec::new(),
+            },
+            Vec::new(),
+        ));
+    };
+    let policy = match &state.namespaces {
+        Some(namespaces) if policy_resolution::has_profiles(&policy) => {
+            // Unsynced, every namespace would look unlabeled and skip its profile
+            if !namespaces.is_synced() {
+                anyhow::bail!("namespace cache not synced yet");
+            }
+            policy_resolution::apply_profile(&policy, namespaces.labels(namespace).as_ref())
+        }
+        _ => policy,
+    };
 
-    // Use the first policy in the namespace
-    let policy = &policy_list.items[0];
-    Ok(admission::validate_pod_admission(pod, &policy.spec))
+    let validate = |pod: &Pod| match policy
+        .min_deny_severity
+        .as_ref()
+        .or(state.min_deny_severity.as_ref())
+    {
+        Some(threshold) => admission::validate_pod_admission_with_warnings(pod, &policy, threshold),
+        None => (admission::validate_pod_admission(pod, &policy), Vec::new()),
+    };
+    let (verdict, mut warnings) = validate(pod);
+    Ok(match old_pod {
+        Some(old) => {
+            let (old_verdict, old_warnings) = validate(old);
+            warnings.retain(|w| !old_warnings.iter().any(|o| o.message == w.message));
+            (verdict.without_existing(&old_verdict), warnings)
+        }
+        None => (verdict, warnings),
+    })
 }
 
-fn build_admission_response(uid: &str, allowed: bool, message: Option<&str>) -> String {
-    let mut response = serde_json::json!({
-        "apiVersion": "admission.k8s.io/v1",
-        "kind": "AdmissionReview",
-        "response": {
-            "uid": uid,
-            "allowed": allowed
-        }
-    });
+/* ============================= CONVERSION HANDLER ============================= */
 
-    if let Some(msg) = message {
-        response["response"]["status"] = serde_json::json!({
-            "message": msg
-        });
+/// Convert DevOpsPolicy objects between v1alpha1 and v1 for the API server.
+async fn conversion_handler(body: String) -> impl IntoResponse {
+    let review: ConversionReview = match serde_json::from_str(&body) {
+        Ok(review) => review,
+        Err(e) => {
+            info!(error = %e, "invalid_conversion_review");
+            return (StatusCode::BAD_REQUEST, e.to_string());
+        }
+    };
+    let desired = review
+        .request
+        .as_ref()
+        .map(|r| r.desired_api_version.clone())
+        .unwrap_or_default();
+
+    let response = conversion::review(review);
+    let failed = response
+        .response
+        .as_ref()
+        .is_none_or(|r| r.result.is_failure());
+    if failed {
+        let message = response
+            .response
+            .as_ref()
+            .map(|r| r.result.message.as_str())
+            .unwrap_or_default();
+        warn!(desired_version = %desired, error = %message, "conversion_failed");
     }
+    WEBHOOK_CONVERSIONS
+        .with_label_values(&[&desired, if failed { "failure" } else { "success" }])
+        .inc();
 
-    response.to_string()
+    match serde_json::to_string(&response) {
+        Ok(body) => (StatusCode::OK, body),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()),
+    }
 }
 
 /* ============================= CERT GENERATION ============================= */
@@ -476,13 +916,51 @@ pub fn generate_self_signed_certs(
 
 /* ============================= INSTALL CONFIG ============================= */
 
-pub fn install_config(service_name: &str, namespace: &str, ca_bundle_path: &str) -> Result<()> {
-    use base64::Engine;
+/// Field manager recorded on a ValidatingWebhookConfiguration written by `--apply`.
+const INSTALL_CONFIG_MANAGER: &str = "kube-devops-cli";
 
+/// Print the ValidatingWebhookConfiguration, or with `apply` create/update it
+/// in the cluster (`dry_run` sends the write as a server-side dry run).
+pub async fn install_config(
+    service_name: &str,
+    namespace: &str,
+    ca_bundle_path: &str,
+    failure_mode: FailureMode,
+    timeout_seconds: u32,
+    apply: bool,
+    dry_run: bool,
+) -> Result<()> {
     let ca_bytes = std::fs::read(ca_bundle_path).context("Failed to read CA bundle file")?;
-    let ca_b64 = base64::engine::general_purpose::STANDARD.encode(&ca_bytes);
+    let yaml = render_config(
+        service_name,
+        namespace,
+        &ca_bytes,
+        failure_mode,
+        timeout_seconds,
+    );
+    if !apply {
+        println!("{yaml}");
+        return Ok(());
+    }
+    let desired: ValidatingWebhookConfiguration =
+        serde_yaml::from_str(&yaml).context("Failed to parse rendered webhook configuration")?;
+    apply_config(desired, dry_run).await
+}
+
+/// Render the ValidatingWebhookConfiguration YAML with `ca_bytes` as the CA bundle.
+fn render_config(
+    service_name: &str,
+    namespace: &str,
+    ca_bytes: &[u8],
+    failure_mode: FailureMode,
+    timeout_seconds: u32,
+) -> String {
+    use base64::Engine;
+
+    let ca_b64 = base64::engine::general_purpose::STANDARD.encode(ca_bytes);
+    let failure_policy = failure_mode.failure_policy();
 
-    let yaml = format!(
+    format!(
         r#"apiVersion: admissionregistration.k8s.io/v1
 kind: ValidatingWebhookConfiguration
 metadata:
@@ -494,6 +972,12 @@ webhooks:
         resources: ["pods"]
         apiVersions: ["v1"]
         operations: ["CREATE", "UPDATE"]
+        scope: "*"
+      - apiGroups: ["apps"]
+        resources: ["deployments", "statefulsets", "daemonsets"]
+        apiVersions: ["v1"]
+        operations: ["CREATE", "UPDATE"]
+        scope: "*"
     clientConfig:
       service:
         name: {service_name}
@@ -501,7 +985,8 @@ webhooks:
         path: /validate
         port: 8443
       caBundle: {ca_b64}
-    failurePolicy: Ignore
+    failurePolicy: {failure_policy}
+    timeoutSeconds: {timeout_seconds}
     sideEffects: None
     admissionReviewVersions: ["v1"]
     namespaceSelector:
@@ -510,9 +995,95 @@ webhooks:
           operator: NotIn
           values: ["kube-system", "kube-public", "kube-node-lease"]
 "#
-    );
+    )
+}
 
-    println!("{yaml}");
+/// Write needed to bring the cluster's configuration in line with the rendered one.
+#[derive(Debug, PartialEq)]
+enum ConfigChange {
+    Create,
+    /// The existing object with the managed webhook fields replaced.
+    Update(Box<ValidatingWebhookConfiguration>),
+    Unchanged,
+}
+
+/// Compare `desired` with the object in the cluster.
+///
+/// Only the fields `render_config` sets are replaced, so fields the API server
+/// defaults (`matchPolicy`, `objectSelector`, ...) don't cause an update on
+/// every run. Webhooks missing from `desired` are dropped.
+fn plan_config_change(
+    existing: Option<&ValidatingWebhookConfiguration>,
+    desired: &ValidatingWebhookConfiguration,
+) -> ConfigChange {
+    let Some(existing) = existing else {
+        return ConfigChange::Create;
+    };
+    let current = existing.webhooks.as_deref().unwrap_or_default();
+    let webhooks: Vec<ValidatingWebhook> = desired
+        .webhooks
+        .iter()
+        .flatten()
+        .map(|want| match current.iter().find(|w| w.name == want.name) {
+            Some(have) => ValidatingWebhook {
+                admission_review_versions: want.admission_review_versions.clone(),
+                client_config: want.client_config.clone(),
+                failure_policy: want.failure_policy.clone(),
+                namespace_selector: want.namespace_selector.clone(),
+                rules: want.rules.clone(),
+                side_effects: want.side_effects.clone(),
+                timeout_seconds: want.timeout_seconds,
+                ..have.clone()
+            },
+            None => want.clone(),
+        })
+        .collect();
+    if existing.webhooks.as_ref() == Some(&webhooks) {
+        return ConfigChange::Unchanged;
+    }
+    let mut updated = existing.clone();
+    updated.webhooks = Some(webhooks);
+    ConfigChange::Update(Box::new(updated))
+}
+
+/// Create or update the ValidatingWebhookConfiguration, skipping the write
+/// when the cluster already matches.
+async fn apply_config(desired: ValidatingWebhookConfiguration, dry_run: bool) -> Result<()> {
+    let client = client::connect()
+        .await
+        .context("Failed to connect to Kubernetes cluster. Is your kubeconfig valid?")?;
+    let api: Api<ValidatingWebhookConfiguration> = Api::all(client);
+    let name = desired.metadata.name.clone().unwrap_or_default();
+    let existing = api.get_opt(&name).await.context(format!(
+        "Failed to get ValidatingWebhookConfiguration '{name}'"
+    ))?;
+
+    let params = PostParams {
+        dry_run,
+        field_manager: Some(INSTALL_CONFIG_MANAGER.to_string()),
+    };
+    let prefix = if dry_run { "[DRY-RUN] " } else { "" };
+    match plan_config_change(existing.as_ref(), &desired) {
+        ConfigChange::Create => {
+            api.create(&params, &desired).await.context(format!(
+                "Failed to create ValidatingWebhookConfiguration '{name}'"
+            ))?;
+            println!("{prefix}Created ValidatingWebhookConfiguration '{name}'");
+        }
+        ConfigChange::Update(updated) => {
+            // The existing resourceVersion makes a concurrent edit fail with a
+            // conflict instead of being overwritten
+            api.replace(&name, &params, &updated)
+                .await
+                .context(format!(
+                    "Failed to update ValidatingWebhookConfiguration '{name}'"
+                ))?;
+            println!("{prefix}Updated ValidatingWebhookConfiguration '{name}'");
+        }
+        ConfigChange::Unchanged => {
+            println!("ValidatingWebhookConfiguration '{name}' is up to date");
+        }
+    }
     Ok(())
 }
 
@@ -523,38 +1094,29 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_build_admission_response_allowed() {
-        let resp = build_admission_response("test-uid-123", true, None);
-        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
-        assert_eq!(v["response"]["uid"], "test-uid-123");
-        assert_eq!(v["response"]["allowed"], true);
-        assert!(v["response"]["status"].is_null());
-    }
-
-    #[test]
-    fn test_build_admission_response_denied() {
-        let resp = build_admission_response(
-            "test-uid-456",
-            false,
-            Some("container 'nginx' uses :latest tag"),
+    fn test_denial_label() {
+        assert_eq!(denial_label("container 'a' uses :latest tag"), "latest_tag");
+        assert_eq!(
+            denial_label("container 'a' image tag 'dev-1' matches forbidden pattern '^dev-'"),
+            "latest_tag"
         );
-        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
-        assert_eq!(v["response"]["uid"], "test-uid-456");
-        assert_eq!(v["response"]["allowed"], false);
         assert_eq!(
-            v["response"]["status"]["message"],
-            "container 'nginx' uses :latest tag"
+            denial_label("container 'a' missing readiness probe"),
+            "missing_readiness"
         );
-    }
-
-    #[test]
-    fn test_build_admission_response_preserves_uid() {
-        let uid = "550e8400-e29b-41d4-a716-446655440000";
-        let resp = build_admission_response(uid, true, None);
-        let v: serde_json::Value = serde_json::from_str(&resp).unwrap();
-        assert_eq!(v["response"]["uid"], uid);
-        assert_eq!(v["apiVersion"], "admission.k8s.io/v1");
-        assert_eq!(v["kind"], "AdmissionReview");
+        assert_eq!(
+            denial_label("pod missing required label 'team'"),
+            "missing_metadata"
+        );
+        assert_eq!(
+            denial_label("container 'a' image 'app:latest' is not pinned to a sha256 digest"),
+            "unpinned_image"
+        );
+        assert_eq!(
+            denial_label("container 'a' uses imagePullPolicy Always"),
+            "pull_policy"
+        );
+        assert_eq!(denial_label("something else"), "unknown");
     }
 
     #[test]
@@ -594,19 +1156,176 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    fn rendered(ca: &[u8], timeout_seconds: u32) -> ValidatingWebhookConfiguration {
+        let yaml = render_config(
+            "test-webhook",
+            "test-ns",
+            ca,
+            FailureMode::Closed,
+            timeout_seconds,
+        );
+        serde_yaml::from_str(&yaml).unwrap()
+    }
+
     #[test]
-    fn test_install_config_output() {
+    fn test_render_config() {
+        let config = rendered(b"FAKE-CA-CERT", 5);
+        assert_eq!(config.metadata.name.as_deref(), Some("test-webhook"));
+        let webhook = &config.webhooks.as_ref().unwrap()[0];
+        assert_eq!(
+            webhook.client_config.ca_bundle.as_ref().unwrap().0,
+            b"FAKE-CA-CERT"
+        );
+        assert_eq!(webhook.failure_policy.as_deref(), Some("Fail"));
+        assert_eq!(webhook.timeout_seconds, Some(5));
+        let service = webhook.client_config.service.as_ref().unwrap();
+        assert_eq!(service.namespace, "test-ns");
+        assert_eq!(webhook.rules.as_ref().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_install_config_prints_without_apply() {
         let temp_dir = std::env::temp_dir().join("kube-devops-test-webhook");
         let _ = std::fs::create_dir_all(&temp_dir);
         let ca_path = temp_dir.join("test-ca.crt");
         std::fs::write(&ca_path, "FAKE-CA-CERT").unwrap();
 
-        let result = install_config("test-webhook", "test-ns", ca_path.to_str().unwrap());
+        let result = install_config(
+            "test-webhook",
+            "test-ns",
+            ca_path.to_str().unwrap(),
+            FailureMode::Closed,
+            5,
+            false,
+            false,
+        )
+        .await;
         assert!(result.is_ok());
 
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_conversion_handler() {
+        let body = serde_json::json!({
+            "apiVersion": "apiextensions.k8s.io/v1",
+            "kind": "ConversionReview",
+            "request": {
+                "uid": "c1",
+                "desiredAPIVersion": "devops.stochastic.io/v1alpha1",
+                "objects": [{
+                    "apiVersion": "devops.stochastic.io/v1",
+                    "kind": "DevOpsPolicy",
+                    "metadata": {"name": "web", "namespace": "prod"},
+                    "spec": {"forbidLatestTag": true}
+                }]
+            }
+        });
+        let response = conversion_handler(body.to_string()).await.into_response();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let review: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(review["response"]["uid"], "c1");
+        assert_eq!(
+            review["response"]["convertedObjects"][0]["spec"]["forbidLatest"],
+            true
+        );
+
+        let response = conversion_handler("not json".to_string())
+            .await
+            .into_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn test_plan_config_change_creates_when_missing() {
+        assert_eq!(
+            plan_config_change(None, &rendered(b"CA", 10)),
+            ConfigChange::Create
+        );
+    }
+
+    #[test]
+    fn test_plan_config_change_ignores_server_defaults() {
+        let desired = rendered(b"CA", 10);
+        let mut existing = desired.clone();
+        existing.metadata.resource_version = Some("42".to_string());
+        let webhook = &mut existing.webhooks.as_mut().unwrap()[0];
+        webhook.match_policy = Some("Equivalent".to_string());
+        webhook.object_selector = Some(Default::default());
+        assert_eq!(
+            plan_config_change(Some(&existing), &desired),
+            ConfigChange::Unchanged
+        );
+    }
+
+    #[test]
+    fn test_plan_config_change_patches_ca_bundle_in_place() {
+        let mut existing = rendered(b"OLD-CA", 10);
+        existing.metadata.resource_version = Some("42".to_string());
+        existing.webhooks.as_mut().unwrap()[0].match_policy = Some("Exact".to_string());
+
+        let ConfigChange::Update(updated) =
+            plan_config_change(Some(&existing), &rendered(b"NEW-CA", 10))
+        else {
+            panic!("expected an update");
+        };
+        assert_eq!(updated.metadata.resource_version.as_deref(), Some("42"));
+        let webhook = &updated.webhooks.as_ref().unwrap()[0];
+        assert_eq!(
+            webhook.client_config.ca_bundle.as_ref().unwrap().0,
+            b"NEW-CA"
+        );
+        assert_eq!(webhook.match_policy.as_deref(), Some("Exact"));
+    }
+
+    #[test]
+    fn test_failure_response_modes() {
+        let error = "boom";
+        let (_, body) = failure_response(
+            FailureMode::Open,
+            false,
+            "u1",
+            "CREATE",
+            "policy_lookup",
+            &error,
+        );
+        let v: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(v["response"]["allowed"], true);
+
+        let (_, body) = failure_response(
+            FailureMode::Closed,
+            false,
+            "u2",
+            "CREATE",
+            "policy_lookup",
+            &error,
+        );
+        let v: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(v["response"]["uid"], "u2");
+        assert_eq!(v["response"]["allowed"], false);
+        assert!(
+            v["response"]["status"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("policy_lookup: boom")
+        );
+
+        let (_, body) = failure_response(
+            FailureMode::Closed,
+            true,
+            "u3",
+            "CREATE",
+            "decode_error",
+            &error,
+        );
+        let v: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(v["response"]["allowed"], true);
+        assert_eq!(v["response"]["warnings"].as_array().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_validate_tls_files_missing_cert() {
         let result = validate_tls_files("/nonexistent/cert.pem", "/nonexistent/key.pem");
@@ -638,6 +1357,69 @@ mod tests {
         let _ = std::fs::remove_dir_all(&temp_dir);
     }
 
+    #[tokio::test]
+    async fn test_reload_tls_if_changed() {
+        let temp_dir = std::env::temp_dir().join("kube-devops-test-tls-reload");
+        let _ = std::fs::create_dir_all(&temp_dir);
+        let cert_path = temp_dir.join("tls.crt");
+        let key_path = temp_dir.join("tls.key");
+        let (cert, key) = (cert_path.to_str().unwrap(), key_path.to_str().unwrap());
+        let write_pair = || {
+            let (_, cert_pem, key_pem) =
+                generate_self_signed_certs("svc", "ns", &[]).expect("cert generation");
+            std::fs::write(&cert_path, cert_pem).unwrap();
+            std::fs::write(&key_path, key_pem).unwrap();
+        };
+
+        write_pair();
+        install_crypto_provider();
+        let config = RustlsConfig::from_pem_file(cert, key).await.unwrap();
+        let mut loaded = tls_files_version(cert, key);
+        assert!(loaded.is_some());
+        assert!(
+            !reload_tls_if_changed(&config, cert, key, &mut loaded)
+                .await
+                .unwrap()
+        );
+
+        // Rotate, pushing the mtime forward in case the filesystem clock is coarse
+        write_pair();
+        let later = SystemTime::now() + Duration::from_secs(5);
+        for path in [&cert_path, &key_path] {
+            std::fs::File::options()
+                .write(true)
+                .open(path)
+                .unwrap()
+                .set_modified(later)
+                .unwrap();
+        }
+        let before = WEBHOOK_CERT_RELOADS.get();
+        assert!(
+            reload_tls_if_changed(&config, cert, key, &mut loaded)
+                .await
+                .unwrap()
+        );
+        assert!(WEBHOOK_CERT_RELOADS.get() > before);
+
+        // A half-written rotation keeps the old config and retries next poll
+        std::fs::write(&key_path, "not a key").unwrap();
+        std::fs::File::options()
+            .write(true)
+            .open(&key_path)
+            .unwrap()
+            .set_modified(later + Duration::from_secs(5))
+            .unwrap();
+        let previous = loaded;
+        assert!(
+            reload_tls_if_changed(&config, cert, key, &mut loaded)
+                .await
+                .is_err()
+        );
+        assert_eq!(loaded, previous);
+
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_webhook_duration_metric_registered() {
         LazyLock::force(&WEBHOOK_DURATION);
@@ -648,4 +1430,15 @@ mod tests {
             "webhook_request_duration_seconds should be registered"
         );
     }
+
+    #[test]
+    fn test_webhook_timeouts_metric_registered() {
+        WEBHOOK_TIMEOUTS.inc();
+        let families = WEBHOOK_REGISTRY.gather();
+        assert!(
+            families
+                .iter()
+                .any(|f| f.get_name() == "webhook_timeouts_total")
+        );
+    }
 }
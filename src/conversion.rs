@@ -0,0 +1,482 @@
+//! DevOpsPolicy conversion between `v1alpha1` and `v1`.
+//!
+//! The API server calls the webhook's `/convert` endpoint whenever a client
+//! reads or writes a version other than the stored one (v1). The mapping:
+//!
+//! | v1alpha1 | v1 |
+//! |---|---|
+//! | `forbidLatest` | `forbidLatestTag` |
+//! | `maxRestarts` | `maxRestartCount` |
+//! | `pendingTimeoutSeconds` | `forbidPendingDuration` |
+//! | `mode` | `enforcementMode` |
+//! | `severityOverrides: [{check, severity}]` | `severityOverrides: {<check>: severity}` |
+//!
+//! v1 fields without a v1alpha1 equivalent round-trip through the
+//! [`CONVERSION_DATA_ANNOTATION`] annotation, so a v1 object read and written
+//! back at v1alpha1 loses nothing.
+
+use k8s_openapi::ByteString;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceConversion, CustomResourceDefinition, ServiceReference, WebhookClientConfig,
+    WebhookConversion,
+};
+use kube::CustomResourceExt;
+use kube::core::Status;
+use kube::core::conversion::{ConversionRequest, ConversionResponse, ConversionReview};
+use serde_json::{Map, Value};
+
+use crate::api::{API_GROUP, API_VERSION};
+use crate::crd::{DevOpsPolicy, SeverityOverrides, v1alpha1, with_spec_validations};
+
+/* ============================= CONSTANTS ============================= */
+
+/// Webhook path the API server posts ConversionReviews to.
+pub const CONVERSION_PATH: &str = "/convert";
+
+/// Annotation holding, as a JSON object, the v1 spec fields a v1alpha1 object
+/// cannot represent.
+pub const CONVERSION_DATA_ANNOTATION: &str = "devops.stochastic.io/conversion-data";
+
+/// Spec fields renamed in v1, as `(v1alpha1, v1)`.
+const RENAMED_FIELDS: [(&str, &str); 4] = [
+    ("forbidLatest", "forbidLatestTag"),
+    ("maxRestarts", "maxRestartCount"),
+    ("pendingTimeoutSeconds", "forbidPendingDuration"),
+    ("mode", "enforcementMode"),
+];
+
+/// Spec fields with the same name and shape in both versions.
+const SHARED_FIELDS: [&str; 2] = ["requireLivenessProbe", "requireReadinessProbe"];
+
+/// Spec fields by their camelCase name.
+type Fields = Map<String, Value>;
+
+/* ============================= CRD ============================= */
+
+/// The DevOpsPolicy CRD serving v1alpha1 and v1, stored as v1, with
+/// conversions sent to the webhook service `service_name` in `namespace`.
+pub fn versioned_policy_crd(
+    service_name: &str,
+    namespace: &str,
+    ca_bundle: &[u8],
+) -> CustomResourceDefinition {
+    let mut crd = kube::core::crd::merge_crds(
+        vec![
+            with_spec_validations(DevOpsPolicy::crd()),
+            v1alpha1::DevOpsPolicy::crd(),
+        ],
+        API_VERSION,
+    )
+    .expect("v1 and v1alpha1 DevOpsPolicy share group, kind, and scope");
+    crd.spec.conversion = Some(CustomResourceConversion {
+        strategy: "Webhook".to_string(),
+        webhook: Some(WebhookConversion {
+            client_config: Some(WebhookClientConfig {
+                ca_bundle: Some(ByteString(ca_bundle.to_vec())),
+                service: Some(ServiceReference {
+                    name: service_name.to_string(),
+                    namespace: namespace.to_string(),
+                    path: Some(CONVERSION_PATH.to_string()),
+                    port: Some(8443),
+                }),
+                url: None,
+            }),
+            conversion_review_versions: vec!["v1".to_string()],
+        }),
+    });
+    crd
+}
+
+/* ============================= CONVERSION ============================= */
+
+/// Answer a ConversionReview, converting every object or failing the whole
+/// request with the first error.
+pub fn review(review: ConversionReview) -> ConversionReview {
+    let request = match ConversionRequest::from_review(review) {
+        Ok(request) => request,
+        Err(e) => {
+            return ConversionResponse::invalid(Status::failure(&e.to_string(), "InvalidRequest"))
+                .into_review();
+        }
+    };
+    let desired = request.desired_api_version.clone();
+    let objects = request.objects.clone();
+    let response = ConversionResponse::for_request(request);
+
+    let converted: Result<Vec<Value>, String> = objects
+        .into_iter()
+        .map(|object| convert_object(object, &desired))
+        .collect();
+    match converted {
+        Ok(objects) => response.success(objects),
+        Err(message) => response.failure(Status::failure(&message, "ConversionFailed")),
+    }
+    .into_review()
+}
+
+/// Convert one DevOpsPolicy object to `desired_api_version`
+/// (`devops.stochastic.io/v1` or `devops.stochastic.io/v1alpha1`).
+pub fn convert_object(mut object: Value, desired_api_version: &str) -> Result<Value, String> {
+    let current = object["apiVersion"]
+        .as_str()
+        .ok_or("object has no apiVersion")?
+        .to_string();
+    if current == desired_api_version {
+        return Ok(object);
+    }
+    let version = |api_version: &str| {
+        api_version
+            .strip_prefix(API_GROUP)
+            .and_then(|v| v.strip_prefix('/'))
+            .map(str::to_string)
+    };
+
+    let spec = match object.get_mut("spec").map(Value::take) {
+        Some(Value::Object(spec)) => spec,
+        Some(Value::Null) | None => Map::new(),
+        Some(_) => return Err("spec is not an object".to_string()),
+    };
+    let spec = match (
+        version(&current).as_deref(),
+        version(desired_api_version).as_deref(),
+    ) {
+        (Some(API_VERSION), Some("v1alpha1")) => {
+            let (spec, stash) = spec_to_v1alpha1(spec)?;
+            set_conversion_data(&mut object, stash)?;
+            spec
+        }
+        (Some("v1alpha1"), Some(API_VERSION)) => {
+            let stash = take_conversion_data(&mut object)?;
+            spec_to_v1(spec, stash)?
+        }
+        _ => {
+            return Err(format!(
+                "cannot convert DevOpsPolicy from {current} to {desired_api_version}"
+            ));
+        }
+    };
+
+    object["spec"] = Value::Object(spec);
+    object["apiVersion"] = Value::String(desired_api_version.to_string());
+    Ok(object)
+}
+
+/// Split a v1 spec into the v1alpha1 spec and the fields v1alpha1 lacks.
+fn spec_to_v1alpha1(mut spec: Fields) -> Result<(Fields, Fields), String> {
+    let mut alpha = Map::new();
+    for (old, new) in RENAMED_FIELDS {
+        if let Some(value) = spec.remove(new) {
+            alpha.insert(old.to_string(), value);
+        }
+    }
+    for field in SHARED_FIELDS {
+        if let Some(value) = spec.remove(field) {
+            alpha.insert(field.to_string(), value);
+        }
+    }
+    if let Some(overrides) = spec.remove("severityOverrides") {
+        let overrides = overrides
+            .as_object()
+            .ok_or("severityOverrides is not an object")?;
+        let list: Vec<Value> = overrides
+            .iter()
+            .filter(|(_, severity)| !severity.is_null())
+            .map(|(check, severity)| serde_json::json!({ "check": check, "severity": severity }))
+            .collect();
+        alpha.insert("severityOverrides".to_string(), Value::Array(list));
+    }
+    Ok((alpha, spec))
+}
+
+/// Rebuild a v1 spec from a v1alpha1 spec plus the stashed v1-only fields.
+fn spec_to_v1(mut spec: Fields, stash: Fields) -> Result<Fields, String> {
+    let mut v1 = stash;
+    for (old, new) in RENAMED_FIELDS {
+        if let Some(value) = spec.remove(old) {
+            v1.insert(new.to_string(), value);
+        }
+    }
+    for field in SHARED_FIELDS {
+        if let Some(value) = spec.remove(field) {
+            v1.insert(field.to_string(), value);
+        }
+    }
+    if let Some(list) = spec.remove("severityOverrides") {
+        let list: Vec<v1alpha1::SeverityOverride> = serde_json::from_value(list)
+            .map_err(|e| format!("invalid v1alpha1 severityOverrides: {e}"))?;
+        let mut overrides = Map::new();
+        for entry in list {
+            if !is_override_check(&entry.check) {
+                return Err(format!(
+                    "unknown check '{}' in severityOverrides",
+                    entry.check
+                ));
+            }
+            overrides.insert(
+                entry.check,
+                serde_json::to_value(entry.severity).map_err(|e| e.to_string())?,
+            );
+        }
+        v1.insert("severityOverrides".to_string(), Value::Object(overrides));
+    }
+    Ok(v1)
+}
+
+/// Whether `check` is a field of the v1 `SeverityOverrides` object.
+fn is_override_check(check: &str) -> bool {
+    schemars::schema_for!(SeverityOverrides)
+        .schema
+        .object
+        .is_some_and(|object| object.properties.contains_key(check))
+}
+
+/// Store `stash` in the conversion-data annotation, or drop the annotation
+/// when there is nothing to keep.
+fn set_conversion_data(object: &mut Value, stash: Fields) -> Result<(), String> {
+    let metadata = object
+        .get_mut("metadata")
+        .and_then(Value::as_object_mut)
+        .ok_or("object has no metadata")?;
+    if stash.is_empty() {
+        remove_annotation(metadata);
+        return Ok(());
+    }
+    let data = serde_json::to_string(&stash).map_err(|e| e.to_string())?;
+    let annotations = metadata
+        .entry("annotations")
+        .or_insert_with(|| Value::Object(Map::new()));
+    if annotations.is_null() {
+        *annotations = Value::Object(Map::new());
+    }
+    annotations
+        .as_object_mut()
+        .ok_or("metadata.annotations is not an object")?
+        .insert(CONVERSION_DATA_ANNOTATION.to_string(), Value::String(data));
+    Ok(())
+}
+
+/// Remove and parse the conversion-data annotation.
+fn take_conversion_data(object: &mut Value) -> Result<Fields, String> {
+    let Some(metadata) = object.get_mut("metadata").and_then(Value::as_object_mut) else {
+        return Ok(Map::new());
+    };
+    match remove_annotation(metadata) {
+        Some(Value::String(data)) => serde_json::from_str(&data)
+            .map_err(|e| format!("invalid {CONVERSION_DATA_ANNOTATION} annotation: {e}")),
+        Some(_) => Err(format!(
+            "{CONVERSION_DATA_ANNOTATION} annotation is not a string"
+        )),
+        None => Ok(Map::new()),
+    }
+}
+
+/// Remove the conversion-data annotation, dropping `annotations` if it ends
+/// up empty.
+fn remove_annotation(metadata: &mut Map<String, Value>) -> Option<Value> {
+    let annotations = metadata.get_mut("annotations")?.as_object_mut()?;
+    let removed = annotations.remove(CONVERSION_DATA_ANNOTATION);
+    if annotations.is_empty() {
+        metadata.remove("annotations");
+    }
+    removed
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const V1: &str = "devops.stochastic.io/v1";
+    const V1ALPHA1: &str = "devops.stochastic.io/v1alpha1";
+
+    fn v1_policy() -> Value {
+        json!({
+            "apiVersion": V1,
+            "kind": "DevOpsPolicy",
+            "metadata": {
+                "name": "web",
+                "namespace": "production",
+                "labels": {"team": "web"},
+                "annotations": {"owner": "platform"}
+            },
+            "spec": {
+                "forbidLatestTag": true,
+                "requireLivenessProbe": true,
+                "maxRestartCount": 3,
+                "forbidPendingDuration": 300,
+                "enforcementMode": "enforce",
+                "severityOverrides": {"latestTag": "critical", "pending": "low"},
+                "requireResources": true,
+                "profiles": {"dev": {"requireResources": false}}
+            },
+            "status": {"healthScore": 91, "violations": 2}
+        })
+    }
+
+    // ── field mapping ──
+
+    #[test]
+    fn test_v1_to_v1alpha1() {
+        let alpha = convert_object(v1_policy(), V1ALPHA1).unwrap();
+        assert_eq!(alpha["apiVersion"], V1ALPHA1);
+        assert_eq!(
+            alpha["spec"],
+            json!({
+                "forbidLatest": true,
+                "requireLivenessProbe": true,
+                "maxRestarts": 3,
+                "pendingTimeoutSeconds": 300,
+                "mode": "enforce",
+                "severityOverrides": [
+                    {"check": "latestTag", "severity": "critical"},
+                    {"check": "pending", "severity": "low"}
+                ]
+            })
+        );
+        assert_eq!(alpha["status"], v1_policy()["status"]);
+
+        let stash: Value = serde_json::from_str(
+            alpha["metadata"]["annotations"][CONVERSION_DATA_ANNOTATION]
+                .as_str()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            stash,
+            json!({"requireResources": true, "profiles": {"dev": {"requireResources": false}}})
+        );
+        assert_eq!(alpha["metadata"]["annotations"]["owner"], "platform");
+
+        // The result deserializes as the typed v1alpha1 resource.
+        let typed: v1alpha1::DevOpsPolicy = serde_json::from_value(alpha).unwrap();
+        assert_eq!(typed.spec.max_restarts, Some(3));
+    }
+
+    #[test]
+    fn test_round_trip_is_lossless() {
+        let alpha = convert_object(v1_policy(), V1ALPHA1).unwrap();
+        assert_eq!(convert_object(alpha, V1).unwrap(), v1_policy());
+
+        let mut bare = v1_policy();
+        bare["metadata"]
+            .as_object_mut()
+            .unwrap()
+            .remove("annotations");
+        bare["spec"] = json!({"forbidLatestTag": true});
+        let alpha = convert_object(bare.clone(), V1ALPHA1).unwrap();
+        assert!(alpha["metadata"].get("annotations").is_none());
+        assert_eq!(convert_object(alpha, V1).unwrap(), bare);
+    }
+
+    #[test]
+    fn test_v1alpha1_to_v1() {
+        let alpha = json!({
+            "apiVersion": V1ALPHA1,
+            "kind": "DevOpsPolicy",
+            "metadata": {"name": "legacy", "namespace": "default"},
+            "spec": {
+                "forbidLatest": false,
+                "mode": "dryRun",
+                "severityOverrides": [{"check": "missingLiveness", "severity": "high"}]
+            }
+        });
+        let v1 = convert_object(alpha, V1).unwrap();
+        let typed: DevOpsPolicy = serde_json::from_value(v1).unwrap();
+        assert_eq!(typed.spec.forbid_latest_tag, Some(false));
+        assert_eq!(
+            typed.spec.enforcement_mode,
+            Some(crate::crd::EnforcementMode::DryRun)
+        );
+        assert_eq!(
+            typed.spec.severity_overrides.unwrap().missing_liveness,
+            Some(crate::crd::Severity::High)
+        );
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        let mut alpha = convert_object(v1_policy(), V1ALPHA1).unwrap();
+        alpha["spec"]["severityOverrides"] = json!([{"check": "bogus", "severity": "high"}]);
+        let err = convert_object(alpha, V1).unwrap_err();
+        assert!(err.contains("bogus"), "{err}");
+
+        let err = convert_object(v1_policy(), "devops.stochastic.io/v2").unwrap_err();
+        assert!(err.contains("v2"), "{err}");
+
+        let mut alpha = convert_object(v1_policy(), V1ALPHA1).unwrap();
+        alpha["metadata"]["annotations"][CONVERSION_DATA_ANNOTATION] = json!("{not json");
+        assert!(convert_object(alpha, V1).is_err());
+    }
+
+    // ── review ──
+
+    fn conversion_review(desired: &str, objects: Vec<Value>) -> ConversionReview {
+        serde_json::from_value(json!({
+            "apiVersion": "apiextensions.k8s.io/v1",
+            "kind": "ConversionReview",
+            "request": {
+                "uid": "705ab4f5-6393-11e8-b7cc-42010a800002",
+                "desiredAPIVersion": desired,
+                "objects": objects
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_review_success() {
+        let response = review(conversion_review(V1ALPHA1, vec![v1_policy(), v1_policy()]));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["kind"], "ConversionReview");
+        assert_eq!(
+            json["response"]["uid"],
+            "705ab4f5-6393-11e8-b7cc-42010a800002"
+        );
+        assert_eq!(json["response"]["result"]["status"], "Success");
+        let converted = json["response"]["convertedObjects"].as_array().unwrap();
+        assert_eq!(converted.len(), 2);
+        assert_eq!(converted[0]["apiVersion"], V1ALPHA1);
+    }
+
+    #[test]
+    fn test_review_failure() {
+        let response = review(conversion_review(
+            "devops.stochastic.io/v2",
+            vec![v1_policy()],
+        ));
+        let json = serde_json::to_value(&response).unwrap();
+        assert_eq!(json["response"]["result"]["status"], "Failure");
+        assert_eq!(json["response"]["result"]["reason"], "ConversionFailed");
+        assert!(
+            json["response"]["convertedObjects"]
+                .as_array()
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    // ── CRD ──
+
+    #[test]
+    fn test_versioned_policy_crd() {
+        let crd = versioned_policy_crd("kube-devops-webhook", "kube-devops", b"ca");
+        let versions: Vec<(&str, bool, bool)> = crd
+            .spec
+            .versions
+            .iter()
+            .map(|v| (v.name.as_str(), v.served, v.storage))
+            .collect();
+        assert_eq!(versions, [("v1", true, true), ("v1alpha1", true, false)]);
+
+        let conversion = crd.spec.conversion.unwrap();
+        assert_eq!(conversion.strategy, "Webhook");
+        let client_config = conversion.webhook.unwrap().client_config.unwrap();
+        let service = client_config.service.unwrap();
+        assert_eq!(service.name, "kube-devops-webhook");
+        assert_eq!(service.namespace, "kube-devops");
+        assert_eq!(service.path.as_deref(), Some(CONVERSION_PATH));
+        assert_eq!(client_config.ca_bundle.unwrap().0, b"ca");
+    }
+}
@@ -1,7 +1,12 @@
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    CustomResourceDefinition, ValidationRule,
+};
 use kube::CustomResource;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+pub mod v1alpha1;
+
 /* ============================= SEVERITY TYPES ============================= */
 
 /// Severity level for policy violations.
@@ -17,6 +22,23 @@ pub enum Severity {
     Low,
 }
 
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    /// Parse a severity name case-insensitively (for CLI flags).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "critical" => Ok(Severity::Critical),
+            "high" => Ok(Severity::High),
+            "medium" => Ok(Severity::Medium),
+            "low" => Ok(Severity::Low),
+            other => Err(format!(
+                "unknown severity '{other}' (expected critical, high, medium, or low)"
+            )),
+        }
+    }
+}
+
 /// Per-check severity overrides.
 ///
 /// When set on a policy, these override the default severity for each check type.
@@ -37,6 +59,90 @@ pub struct SeverityOverrides {
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pending: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_resources: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crash_loop: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_pull_error: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_metadata: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_pdb: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_spread: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unpinned_image: Option<Severity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_policy: Option<Severity>,
+}
+
+/// Per-check health score weights.
+///
+/// Each value is the score penalty per violating pod; unset checks keep the
+/// built-in weight.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoringWeightOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub latest_tag: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub missing_liveness: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub missing_readiness: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub high_restarts: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub pending: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub missing_resources: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub crash_loop: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub image_pull_error: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub missing_metadata: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub missing_pdb: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub no_spread: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub unpinned_image: Option<u32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub pull_policy: Option<u32>,
 }
 
 /// A single violation found during audit evaluation.
@@ -48,6 +154,10 @@ pub struct AuditViolation {
     pub violation_type: String,
     pub severity: Severity,
     pub message: String,
+
+    /// Owning workload (e.g. `Deployment/web`), or `Pod/<name>` for bare pods.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workload: Option<String>,
 }
 
 /* ============================= ENFORCEMENT TYPES ============================= */
@@ -56,11 +166,67 @@ pub struct AuditViolation {
 ///
 /// - `Audit` (default): detect and report violations, never mutate workloads.
 /// - `Enforce`: automatically patch patchable violations on parent workloads.
+/// - `DryRun`: compute the remediations `Enforce` would apply and record them
+///   in `status.plannedRemediations` without patching anything.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum EnforcementMode {
     Audit,
     Enforce,
+    DryRun,
+}
+
+impl EnforcementMode {
+    /// Serialized name, as written in the policy spec.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EnforcementMode::Audit => "audit",
+            EnforcementMode::Enforce => "enforce",
+            EnforcementMode::DryRun => "dryRun",
+        }
+    }
+}
+
+/// Direction of a policy's health score against its recent history.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ScoreTrend {
+    Improving,
+    Stable,
+    Degrading,
+}
+
+impl ScoreTrend {
+    /// Serialized name, as written in the policy status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreTrend::Improving => "improving",
+            ScoreTrend::Stable => "stable",
+            ScoreTrend::Degrading => "degrading",
+        }
+    }
+}
+
+/// A remediation that enforcement would apply to one workload.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedRemediation {
+    /// Target workload, e.g. `Deployment/web`.
+    pub workload: String,
+
+    /// Container patches, in container order.
+    pub actions: Vec<PlannedAction>,
+}
+
+/// A single container patch within a planned remediation.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PlannedAction {
+    /// `injectLivenessProbe`, `injectReadinessProbe`, or `injectResources`.
+    pub action: String,
+
+    /// Container the patch targets.
+    pub container: String,
 }
 
 /// Default probe configuration injected when a container is missing probes.
@@ -69,38 +235,248 @@ pub enum EnforcementMode {
 pub struct DefaultProbeConfig {
     /// TCP port to probe. Falls back to the container's first port, then 8080.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 65535))]
     pub tcp_port: Option<u16>,
 
     /// Seconds before the first probe after container start.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0))]
     pub initial_delay_seconds: Option<i32>,
 
     /// Seconds between consecutive probes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
     pub period_seconds: Option<i32>,
 }
 
+/// Kubernetes resource quantity (`100m`, `128Mi`, `1.5`, `2e3`), the
+/// pattern the API server itself validates quantities against.
+pub const QUANTITY_PATTERN: &str = r"^(\+|-)?(([0-9]+(\.[0-9]*)?)|(\.[0-9]+))(([KMGTPE]i)|[numkMGTPE]|([eE](\+|-)?(([0-9]+(\.[0-9]*)?)|(\.[0-9]+))))?$";
+
 /// Default resource requests and limits injected when a container has none.
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DefaultResourceConfig {
     /// CPU request (e.g. "100m").
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "QUANTITY_PATTERN")]
     pub cpu_request: Option<String>,
 
     /// CPU limit (e.g. "500m").
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "QUANTITY_PATTERN")]
     pub cpu_limit: Option<String>,
 
     /// Memory request (e.g. "128Mi").
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "QUANTITY_PATTERN")]
     pub memory_request: Option<String>,
 
     /// Memory limit (e.g. "256Mi").
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(regex = "QUANTITY_PATTERN")]
     pub memory_limit: Option<String>,
 }
 
+/// Limits for `evictCrashLooping` pod eviction.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EvictionConfig {
+    /// Restart count above which a container's pod is evicted (default 20).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub restart_threshold: Option<i32>,
+
+    /// Minimum seconds between evictions from the same workload (default 600).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_seconds: Option<u64>,
+
+    /// Maximum pods evicted per reconcile cycle (default 1).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub max_evictions_per_cycle: Option<u32>,
+}
+
+/// How long the operator keeps this policy's PolicyAuditResults.
+///
+/// Results beyond either limit are deleted by the operator's periodic
+/// garbage collection, oldest first.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRetention {
+    /// Results kept per policy (default: the operator's `--audit-retention`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub max_count: Option<u32>,
+
+    /// Results older than this many days are deleted (default: no age limit).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub max_age_days: Option<u32>,
+}
+
+/// Lowest health score of each classification; lower scores are
+/// `Critical`.
+///
+/// Unset cut-offs keep the defaults (80, 60, 40). They must descend from
+/// `healthy` to `degraded`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthThresholds {
+    /// Lowest `Healthy` score, which also sets `status.healthy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 100))]
+    pub healthy: Option<u32>,
+
+    /// Lowest `Stable` score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 100))]
+    pub stable: Option<u32>,
+
+    /// Lowest `Degraded` score.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1, max = 100))]
+    pub degraded: Option<u32>,
+}
+
+/// When a policy notifies, and where.
+///
+/// Each event (a violation, a score threshold crossing, a failed remediation)
+/// is sent at most once per `cooldownSeconds`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationConfig {
+    /// Destinations that receive every notification.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<NotificationChannel>>,
+
+    /// Lowest violation severity that notifies (default `high`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_severity: Option<Severity>,
+
+    /// Notify when the health score drops below this value, and again when
+    /// it recovers. Unset disables score notifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(max = 100))]
+    pub score_threshold: Option<u32>,
+
+    /// Notify when an enforce-mode remediation fails (default true).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remediation_failures: Option<bool>,
+
+    /// Seconds before the same event is sent again (default 3600).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown_seconds: Option<u64>,
+}
+
+/// Notification transport.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum NotificationChannelType {
+    /// Slack incoming webhook.
+    Slack,
+    /// JSON `POST` to any HTTP endpoint.
+    Webhook,
+    /// Email through an SMTP relay.
+    Smtp,
+}
+
+impl NotificationChannelType {
+    /// Serialized name, as written in the policy spec.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationChannelType::Slack => "slack",
+            NotificationChannelType::Webhook => "webhook",
+            NotificationChannelType::Smtp => "smtp",
+        }
+    }
+}
+
+/// One notification destination.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationChannel {
+    #[serde(rename = "type")]
+    pub channel_type: NotificationChannelType,
+
+    /// Webhook URL (`slack`, `webhook`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// SMTP relay as `host:port` (`smtp`). Mail is sent without TLS or
+    /// authentication, so point this at an in-cluster relay.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smtp_server: Option<String>,
+
+    /// Sender address (`smtp`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+
+    /// Recipient addresses (`smtp`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub to: Option<Vec<String>>,
+}
+
+/// Namespaces where enforce mode quarantines workloads with critical violations.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct QuarantineConfig {
+    /// Namespaces in which Deployments and StatefulSets with a critical
+    /// violation are scaled to zero until restored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<String>>,
+}
+
+/// Check settings for one environment, layered over the policy when a
+/// namespace selects the profile by label.
+///
+/// Enforcement settings stay policy-wide; a profile only changes which
+/// checks run and how severe their violations are.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_latest_tag: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbidden_tag_patterns: Option<Vec<String>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_tag_pattern: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_liveness_probe: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_readiness_probe: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_resources: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0))]
+    pub max_restart_count: Option<i32>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub forbid_pending_duration: Option<u64>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_crash_loop: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_image_pull_errors: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_pod_disruption_budget: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_spread_constraints: Option<bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity_overrides: Option<SeverityOverrides>,
+}
+
 /* ============================= SPEC ============================= */
 
 /// DevOpsPolicy defines a governance policy for Kubernetes workloads.
@@ -114,7 +490,13 @@ pub struct DefaultResourceConfig {
     kind = "DevOpsPolicy",
     plural = "devopspolicies",
     status = "DevOpsPolicyStatus",
-    namespaced
+    namespaced,
+    printcolumn = r#"{"name":"Score","type":"integer","jsonPath":".status.healthScore"}"#,
+    printcolumn = r#"{"name":"Violations","type":"integer","jsonPath":".status.violations"}"#,
+    printcolumn = r#"{"name":"Classification","type":"string","jsonPath":".status.classification"}"#,
+    printcolumn = r#"{"name":"Trend","type":"string","jsonPath":".status.scoreTrend"}"#,
+    printcolumn = r#"{"name":"Mode","type":"string","jsonPath":".spec.enforcementMode"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
 pub struct DevOpsPolicySpec {
@@ -122,6 +504,31 @@ pub struct DevOpsPolicySpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub forbid_latest_tag: Option<bool>,
 
+    /// Regular expressions matched against each container's image tag
+    /// (e.g. `^dev-`, `snapshot`); a match is a `latest_tag` violation.
+    /// Untagged images are checked as `latest`; digest-pinned images are exempt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbidden_tag_patterns: Option<Vec<String>>,
+
+    /// Regular expression every image tag must match (e.g. `^v\d+\.\d+\.\d+$`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_tag_pattern: Option<String>,
+
+    /// Require every image to be pinned to a sha256 digest
+    /// (`app@sha256:...`). Reported as an `unpinned_image` violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_image_digest: Option<bool>,
+
+    /// Flag containers that pull with `imagePullPolicy: Always`, set or
+    /// defaulted. Reported as a `pull_policy` violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_image_pull_policy_always: Option<bool>,
+
+    /// Flag containers whose pull policy, set or defaulted, is not
+    /// `IfNotPresent`. Reported as a `pull_policy` violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_image_pull_policy_if_not_present: Option<bool>,
+
     /// Require liveness probes on all containers.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub require_liveness_probe: Option<bool>,
@@ -130,15 +537,55 @@ pub struct DevOpsPolicySpec {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub require_readiness_probe: Option<bool>,
 
+    /// Count required probes without a handler, or with `periodSeconds: 1`
+    /// or `failureThreshold: 1`, as missing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_configured_probes: Option<bool>,
+
+    /// Require CPU/memory requests or limits on all containers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_resources: Option<bool>,
+
+    /// Labels every pod must carry, keyed by label key (e.g. `team`). A
+    /// non-empty value is a regular expression the whole label value must
+    /// match; an empty value accepts any value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_labels: Option<std::collections::BTreeMap<String, String>>,
+
+    /// Annotations every pod must carry, matched like `requiredLabels`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_annotations: Option<std::collections::BTreeMap<String, String>>,
+
     /// Maximum allowed restart count before flagging a violation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 0))]
     pub max_restart_count: Option<i32>,
 
     /// Maximum duration (seconds) a pod may remain in Pending phase.
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
     pub forbid_pending_duration: Option<u64>,
 
-    /// Enforcement mode: `audit` (default) or `enforce`.
+    /// Flag containers waiting in `CrashLoopBackOff`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_crash_loop: Option<bool>,
+
+    /// Flag containers waiting in `ImagePullBackOff` or `ErrImagePull`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_image_pull_errors: Option<bool>,
+
+    /// Flag Deployments and StatefulSets running more than one replica
+    /// that no PodDisruptionBudget in their namespace selects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_pod_disruption_budget: Option<bool>,
+
+    /// Flag Deployments and StatefulSets running more than one replica
+    /// whose pod template has neither pod anti-affinity nor
+    /// `topologySpreadConstraints`, so all replicas may share one node.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_spread_constraints: Option<bool>,
+
+    /// Enforcement mode: `audit` (default), `enforce`, or `dryRun`.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enforcement_mode: Option<EnforcementMode>,
 
@@ -153,21 +600,94 @@ pub struct DevOpsPolicySpec {
     /// Per-check severity overrides for violation weighting.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub severity_overrides: Option<SeverityOverrides>,
+
+    /// Per-check health score weights, replacing the built-in defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scoring_weights: Option<ScoringWeightOverrides>,
+
+    /// Score cut-offs of the Healthy, Stable, and Degraded classifications.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_thresholds: Option<HealthThresholds>,
+
+    /// Strip the operator's patched-by annotations from workloads this
+    /// policy remediated when the policy is deleted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cleanup_on_delete: Option<bool>,
+
+    /// Evict pods stuck restarting so their controller recreates them
+    /// (enforce mode only; honours PodDisruptionBudgets).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evict_crash_looping: Option<bool>,
+
+    /// Threshold, cooldown, and per-cycle limit for `evictCrashLooping`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eviction: Option<EvictionConfig>,
+
+    /// Maximum workloads patched per reconcile cycle; the rest are deferred.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub max_remediations_per_cycle: Option<u32>,
+
+    /// Minimum seconds between reconcile cycles that patch workloads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remediation_cooldown_seconds: Option<u64>,
+
+    /// Scale workloads with critical violations to zero in designated
+    /// namespaces (enforce mode only); see `QuarantineRecord`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quarantine: Option<QuarantineConfig>,
+
+    /// Named check profiles (e.g. `dev`, `prod`). A namespace selects one
+    /// with the `profileLabel` label; unlabeled namespaces use the policy as is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profiles: Option<std::collections::BTreeMap<String, PolicyProfile>>,
+
+    /// Namespace label whose value names the profile
+    /// (default `devops.stochastic.io/profile`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_label: Option<String>,
+
+    /// Lowest severity the admission webhook denies; violations below it
+    /// are admitted with a warning. Unset denies every violation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_deny_severity: Option<Severity>,
+
+    /// Timezone for this policy's human-readable timestamps in operator
+    /// output: `UTC`, `local`, or an offset such as `+02:00`. Defaults to the
+    /// operator's `--timezone`; status and audit timestamps stay UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Seconds after which the reconciler evaluates the policy again even
+    /// though it was not edited, so new and changed pods reach the status.
+    /// Defaults to 300.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(range(min = 1))]
+    pub reevaluation_interval: Option<u64>,
+
+    /// Maximum count and age of this policy's PolicyAuditResults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_retention: Option<AuditRetention>,
+
+    /// Slack, webhook, or email notifications for new violations, score
+    /// threshold crossings, and failed remediations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<NotificationConfig>,
 }
 
 /* ============================= STATUS ============================= */
 
 /// DevOpsPolicyStatus reports the observed compliance state.
 ///
-/// Updated by the reconciler on every evaluation cycle.
-#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+/// Patched by the reconciler when an evaluation changes it.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DevOpsPolicyStatus {
     /// The `.metadata.generation` that was last reconciled.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub observed_generation: Option<i64>,
 
-    /// Whether the namespace meets the health threshold (score >= 80).
+    /// Whether the score reaches the `Healthy` cut-off.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub healthy: Option<bool>,
 
@@ -179,7 +699,23 @@ pub struct DevOpsPolicyStatus {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub violations: Option<u32>,
 
-    /// ISO 8601 timestamp of the last evaluation.
+    /// Health classification (Healthy, Stable, Degraded, Critical).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub classification: Option<String>,
+
+    /// Classification cut-offs the evaluation used, defaults included.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_thresholds: Option<HealthThresholds>,
+
+    /// Health score of the previous evaluation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub previous_score: Option<u32>,
+
+    /// Health score against the recent average: improving, stable, or degrading.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_trend: Option<ScoreTrend>,
+
+    /// ISO 8601 timestamp of the last evaluation that changed the status.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub last_evaluated: Option<String>,
 
@@ -198,6 +734,87 @@ pub struct DevOpsPolicyStatus {
     /// Names of workloads that were remediated (e.g. "deployments/web-app").
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub remediated_workloads: Option<Vec<String>>,
+
+    /// Remediations `dryRun` mode would apply; unset in other modes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub planned_remediations: Option<Vec<PlannedRemediation>>,
+}
+
+/* ============================= CLUSTER POLICY CRD ============================= */
+
+/// ClusterDevOpsPolicy applies a governance policy to every namespace.
+///
+/// System namespaces and `excludedNamespaces` are skipped. A namespaced
+/// `DevOpsPolicy` overrides the cluster policy field by field.
+#[derive(CustomResource, Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[kube(
+    group = "devops.stochastic.io",
+    version = "v1",
+    kind = "ClusterDevOpsPolicy",
+    plural = "clusterdevopspolicies",
+    status = "DevOpsPolicyStatus",
+    printcolumn = r#"{"name":"Score","type":"integer","jsonPath":".status.healthScore"}"#,
+    printcolumn = r#"{"name":"Violations","type":"integer","jsonPath":".status.violations"}"#,
+    printcolumn = r#"{"name":"Classification","type":"string","jsonPath":".status.classification"}"#,
+    printcolumn = r#"{"name":"Trend","type":"string","jsonPath":".status.scoreTrend"}"#,
+    printcolumn = r#"{"name":"Mode","type":"string","jsonPath":".spec.enforcementMode"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterDevOpsPolicySpec {
+    /// Namespaces this policy does not apply to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excluded_namespaces: Option<Vec<String>>,
+
+    /// Policy checks, with the same fields as `DevOpsPolicy.spec`.
+    #[serde(flatten)]
+    pub policy: DevOpsPolicySpec,
+}
+
+/* ============================= VALIDATION ============================= */
+
+/// CEL rules the API server checks on the `spec` of every DevOpsPolicy and
+/// ClusterDevOpsPolicy create and update, as `(rule, message)`.
+pub const SPEC_VALIDATIONS: [(&str, &str); 2] = [
+    (
+        "!(has(self.enforcementMode) && self.enforcementMode == 'enforce' \
+         && ((has(self.requireLivenessProbe) && self.requireLivenessProbe) \
+         || (has(self.requireReadinessProbe) && self.requireReadinessProbe))) \
+         || has(self.defaultProbe)",
+        "defaultProbe is required when enforcementMode is enforce and probes are required",
+    ),
+    (
+        "!has(self.profileLabel) || has(self.profiles)",
+        "profileLabel is set but no profiles are defined",
+    ),
+];
+
+/// Add [`SPEC_VALIDATIONS`] as `x-kubernetes-validations` on the `spec`
+/// schema of every version of a DevOpsPolicy or ClusterDevOpsPolicy CRD.
+///
+/// `CustomResourceExt::crd()` has no way to attach CEL rules, so CRDs are
+/// generated through this (see `crd generate`).
+pub fn with_spec_validations(mut crd: CustomResourceDefinition) -> CustomResourceDefinition {
+    for version in &mut crd.spec.versions {
+        let spec = version
+            .schema
+            .as_mut()
+            .and_then(|s| s.open_api_v3_schema.as_mut())
+            .and_then(|s| s.properties.as_mut())
+            .and_then(|p| p.get_mut("spec"));
+        if let Some(spec) = spec {
+            spec.x_kubernetes_validations = Some(
+                SPEC_VALIDATIONS
+                    .iter()
+                    .map(|(rule, message)| ValidationRule {
+                        rule: rule.to_string(),
+                        message: Some(message.to_string()),
+                    })
+                    .collect(),
+            );
+        }
+    }
+    crd
 }
 
 /* ============================= AUDIT RESULT CRD ============================= */
@@ -211,7 +828,12 @@ pub struct DevOpsPolicyStatus {
     version = "v1",
     kind = "PolicyAuditResult",
     plural = "policyauditresults",
-    namespaced
+    namespaced,
+    printcolumn = r#"{"name":"Policy","type":"string","jsonPath":".spec.policyName"}"#,
+    printcolumn = r#"{"name":"Score","type":"integer","jsonPath":".spec.healthScore"}"#,
+    printcolumn = r#"{"name":"Violations","type":"integer","jsonPath":".spec.totalViolations"}"#,
+    printcolumn = r#"{"name":"Classification","type":"string","jsonPath":".spec.classification"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
 )]
 #[serde(rename_all = "camelCase")]
 pub struct PolicyAuditResultSpec {
@@ -234,12 +856,62 @@ pub struct PolicyAuditResultSpec {
     /// Total number of pods evaluated.
     pub total_pods: u32,
 
+    /// Number of workloads evaluated; the health score averages over these.
+    /// Absent on results written by older operators.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_workloads: Option<u32>,
+
     /// Health classification (Healthy, Stable, Degraded, Critical).
     pub classification: String,
 
     /// Detailed violations found during this evaluation.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub violations: Vec<AuditViolation>,
+
+    /// Remediations planned by a `dryRun` policy in this evaluation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub planned_remediations: Option<Vec<PlannedRemediation>>,
+}
+
+/* ============================= QUARANTINE RECORD CRD ============================= */
+
+/// QuarantineRecord remembers a workload the operator scaled to zero.
+///
+/// Created in the workload's namespace (owned by the workload) before it is
+/// scaled down; `enforce restore` scales it back and deletes the record.
+#[derive(CustomResource, Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[kube(
+    group = "devops.stochastic.io",
+    version = "v1",
+    kind = "QuarantineRecord",
+    plural = "quarantinerecords",
+    namespaced,
+    printcolumn = r#"{"name":"Kind","type":"string","jsonPath":".spec.workloadKind"}"#,
+    printcolumn = r#"{"name":"Workload","type":"string","jsonPath":".spec.workloadName"}"#,
+    printcolumn = r#"{"name":"Replicas","type":"integer","jsonPath":".spec.originalReplicas"}"#,
+    printcolumn = r#"{"name":"Policy","type":"string","jsonPath":".spec.policyName"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct QuarantineRecordSpec {
+    /// `Deployment` or `StatefulSet`.
+    pub workload_kind: String,
+
+    /// Name of the quarantined workload.
+    pub workload_name: String,
+
+    /// Policy that quarantined the workload.
+    pub policy_name: String,
+
+    /// Replica count before the workload was scaled to zero.
+    pub original_replicas: i32,
+
+    /// Critical violations that triggered the quarantine.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub violations: Vec<String>,
+
+    /// ISO 8601 timestamp of the quarantine.
+    pub quarantined_at: String,
 }
 
 /* ============================= TESTS ============================= */
@@ -326,6 +998,17 @@ mod tests {
         assert_eq!(spec.default_probe, None);
         assert_eq!(spec.default_resources, None);
         assert_eq!(spec.severity_overrides, None);
+        assert_eq!(spec.require_resources, None);
+        assert_eq!(spec.cleanup_on_delete, None);
+    }
+
+    #[test]
+    fn test_spec_cleanup_on_delete_camel_case() {
+        let spec: DevOpsPolicySpec =
+            serde_json::from_str(r#"{"cleanupOnDelete": true}"#).expect("should deserialize");
+        assert_eq!(spec.cleanup_on_delete, Some(true));
+        let json = serde_json::to_string(&spec).expect("should serialize");
+        assert!(json.contains("cleanupOnDelete"));
     }
 
     #[test]
@@ -335,6 +1018,7 @@ mod tests {
         assert_eq!(status.healthy, None);
         assert_eq!(status.health_score, None);
         assert_eq!(status.violations, None);
+        assert_eq!(status.classification, None);
         assert_eq!(status.last_evaluated, None);
         assert_eq!(status.message, None);
         assert_eq!(status.remediations_applied, None);
@@ -349,11 +1033,16 @@ mod tests {
             healthy: Some(true),
             health_score: Some(87),
             violations: Some(3),
+            classification: Some("Stable".to_string()),
+            health_thresholds: None,
+            previous_score: Some(91),
+            score_trend: Some(ScoreTrend::Degrading),
             last_evaluated: Some("2026-02-22T10:00:00Z".to_string()),
             message: Some("3 violations across 42 pods".to_string()),
             remediations_applied: Some(2),
             remediations_failed: Some(0),
             remediated_workloads: Some(vec!["deployments/web-app".to_string()]),
+            planned_remediations: None,
         };
 
         let json = serde_json::to_string(&status).expect("should serialize");
@@ -364,6 +1053,10 @@ mod tests {
         assert_eq!(deserialized.healthy, Some(true));
         assert_eq!(deserialized.health_score, Some(87));
         assert_eq!(deserialized.violations, Some(3));
+        assert_eq!(deserialized.classification.as_deref(), Some("Stable"));
+        assert!(json.contains(r#""scoreTrend":"degrading""#));
+        assert_eq!(deserialized.previous_score, Some(91));
+        assert_eq!(deserialized.score_trend, Some(ScoreTrend::Degrading));
         assert_eq!(
             deserialized.last_evaluated.as_deref(),
             Some("2026-02-22T10:00:00Z")
@@ -407,6 +1100,14 @@ mod tests {
         assert_eq!(json, r#""enforce""#);
     }
 
+    #[test]
+    fn test_enforcement_mode_dry_run_round_trip() {
+        let mode: EnforcementMode = serde_json::from_str(r#""dryRun""#).unwrap();
+        assert_eq!(mode, EnforcementMode::DryRun);
+        assert_eq!(serde_json::to_string(&mode).unwrap(), r#""dryRun""#);
+        assert_eq!(mode.as_str(), "dryRun");
+    }
+
     #[test]
     fn test_enforcement_mode_deserialize_roundtrip() {
         let json = r#""enforce""#;
@@ -561,6 +1262,13 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_severity_from_str_case_insensitive() {
+        assert_eq!("high".parse::<Severity>(), Ok(Severity::High));
+        assert_eq!("CRITICAL".parse::<Severity>(), Ok(Severity::Critical));
+        assert!("severe".parse::<Severity>().is_err());
+    }
+
     #[test]
     fn test_severity_overrides_partial() {
         let overrides = SeverityOverrides {
@@ -569,6 +1277,14 @@ mod tests {
             missing_readiness: None,
             high_restarts: Some(Severity::High),
             pending: None,
+            missing_resources: None,
+            crash_loop: None,
+            image_pull_error: None,
+            missing_metadata: None,
+            missing_pdb: None,
+            no_spread: None,
+            unpinned_image: None,
+            pull_policy: None,
         };
         let json = serde_json::to_string(&overrides).expect("should serialize");
         assert!(json.contains("latestTag"));
@@ -612,6 +1328,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_spec_min_deny_severity_roundtrip() {
+        let spec: DevOpsPolicySpec =
+            serde_json::from_str(r#"{"forbidLatestTag":true,"minDenySeverity":"high"}"#)
+                .expect("should deserialize");
+        assert_eq!(spec.min_deny_severity, Some(Severity::High));
+        let json = serde_json::to_string(&DevOpsPolicySpec::default()).unwrap();
+        assert!(!json.contains("minDenySeverity"));
+    }
+
     #[test]
     fn test_backward_compat_no_severity_overrides() {
         // JSON from before Step 10 (no severity_overrides) should still deserialize
@@ -646,12 +1372,23 @@ mod tests {
             violation_type: "latest_tag".to_string(),
             severity: Severity::High,
             message: "container 'nginx' uses :latest tag".to_string(),
+            workload: Some("Deployment/web".to_string()),
         };
         let json = serde_json::to_string(&violation).expect("should serialize");
         let deserialized: AuditViolation = serde_json::from_str(&json).expect("should deserialize");
         assert_eq!(deserialized.pod_name, "web-abc123");
         assert_eq!(deserialized.severity, Severity::High);
         assert_eq!(deserialized.violation_type, "latest_tag");
+        assert_eq!(deserialized.workload.as_deref(), Some("Deployment/web"));
+    }
+
+    #[test]
+    fn test_audit_violation_workload_optional() {
+        let json = r#"{"podName":"p","containerName":"c","violationType":"pending","severity":"medium","message":"m"}"#;
+        let violation: AuditViolation = serde_json::from_str(json).expect("should deserialize");
+        assert!(violation.workload.is_none());
+        let out = serde_json::to_string(&violation).unwrap();
+        assert!(!out.contains("workload"));
     }
 
     // ── PolicyAuditResult CRD tests ──
@@ -693,6 +1430,7 @@ mod tests {
             health_score: 85,
             total_violations: 5,
             total_pods: 20,
+            total_workloads: None,
             classification: "Healthy".to_string(),
             violations: vec![AuditViolation {
                 pod_name: "web-pod".to_string(),
@@ -700,7 +1438,9 @@ mod tests {
                 violation_type: "latest_tag".to_string(),
                 severity: Severity::High,
                 message: "uses :latest".to_string(),
+                workload: None,
             }],
+            planned_remediations: None,
         };
 
         let json = serde_json::to_string(&spec).expect("should serialize");
@@ -722,8 +1462,10 @@ mod tests {
             health_score: 100,
             total_violations: 0,
             total_pods: 5,
+            total_workloads: None,
             classification: "Healthy".to_string(),
             violations: vec![],
+            planned_remediations: None,
         };
 
         let json = serde_json::to_string(&spec).expect("should serialize");
@@ -739,8 +1481,10 @@ mod tests {
             health_score: 100,
             total_violations: 0,
             total_pods: 0,
+            total_workloads: None,
             classification: "Healthy".to_string(),
             violations: vec![],
+            planned_remediations: None,
         };
 
         let json = serde_json::to_string(&spec).expect("should serialize");
@@ -754,4 +1498,153 @@ mod tests {
         assert_ne!(policy_crd.spec.names.kind, audit_crd.spec.names.kind);
         assert_ne!(policy_crd.spec.names.plural, audit_crd.spec.names.plural);
     }
+
+    // ── QuarantineRecord CRD ──
+
+    #[test]
+    fn test_quarantine_record_crd_names() {
+        let crd = QuarantineRecord::crd();
+        assert_eq!(crd.spec.scope, "Namespaced");
+        assert_eq!(crd.spec.names.plural, "quarantinerecords");
+        assert_eq!(crd.spec.group, "devops.stochastic.io");
+        let columns: Vec<_> = crd.spec.versions[0]
+            .additional_printer_columns
+            .iter()
+            .flatten()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert!(columns.contains(&"Replicas"));
+    }
+
+    #[test]
+    fn test_quarantine_config_serialization() {
+        let spec = DevOpsPolicySpec {
+            quarantine: Some(QuarantineConfig {
+                namespaces: Some(vec!["sandbox".to_string()]),
+            }),
+            ..Default::default()
+        };
+        let json = serde_json::to_value(&spec).unwrap();
+        assert_eq!(json["quarantine"]["namespaces"][0], "sandbox");
+    }
+
+    // ── ClusterDevOpsPolicy ──
+
+    #[test]
+    fn test_cluster_policy_crd_is_cluster_scoped() {
+        let crd = ClusterDevOpsPolicy::crd();
+        assert_eq!(crd.spec.scope, "Cluster");
+        assert_eq!(crd.spec.names.plural, "clusterdevopspolicies");
+        assert_eq!(crd.spec.group, "devops.stochastic.io");
+    }
+
+    #[test]
+    fn test_cluster_policy_schema_flattens_policy_fields() {
+        let crd = ClusterDevOpsPolicy::crd();
+        let schema = serde_json::to_value(&crd.spec.versions[0].schema).unwrap();
+        let spec = &schema["openAPIV3Schema"]["properties"]["spec"]["properties"];
+        assert!(spec["excludedNamespaces"].is_object());
+        assert!(spec["forbidLatestTag"].is_object());
+        assert!(spec["severityOverrides"].is_object());
+    }
+
+    #[test]
+    fn test_cluster_policy_spec_yaml() {
+        let yaml = r#"
+excludedNamespaces: ["sandbox"]
+forbidLatestTag: true
+enforcementMode: audit
+"#;
+        let spec: ClusterDevOpsPolicySpec = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(spec.excluded_namespaces, Some(vec!["sandbox".to_string()]));
+        assert_eq!(spec.policy.forbid_latest_tag, Some(true));
+        assert_eq!(spec.policy.enforcement_mode, Some(EnforcementMode::Audit));
+
+        let json = serde_json::to_value(&spec).unwrap();
+        assert_eq!(json["forbidLatestTag"], true);
+        assert!(json.get("policy").is_none());
+    }
+
+    // ── Schema validation ──
+
+    /// The v1 `spec` schema of `crd` as JSON.
+    fn spec_schema(crd: &CustomResourceDefinition) -> serde_json::Value {
+        let schema = serde_json::to_value(&crd.spec.versions[0].schema).unwrap();
+        schema["openAPIV3Schema"]["properties"]["spec"].clone()
+    }
+
+    #[test]
+    fn test_quantity_pattern() {
+        let pattern = regex::Regex::new(QUANTITY_PATTERN).unwrap();
+        for valid in ["100m", "128Mi", "1", "0.5", "1.5Gi", "2e3", "1k"] {
+            assert!(pattern.is_match(valid), "{valid} should match");
+        }
+        for invalid in ["", "100 m", "128MB", "one", "1.5.2", "Mi"] {
+            assert!(!pattern.is_match(invalid), "{invalid} should not match");
+        }
+
+        let spec = spec_schema(&DevOpsPolicy::crd());
+        assert_eq!(
+            spec["properties"]["defaultResources"]["properties"]["cpuRequest"]["pattern"],
+            QUANTITY_PATTERN
+        );
+    }
+
+    #[test]
+    fn test_threshold_ranges() {
+        let spec = spec_schema(&DevOpsPolicy::crd());
+        let props = &spec["properties"];
+        assert_eq!(props["maxRestartCount"]["minimum"], 0.0);
+        assert_eq!(props["forbidPendingDuration"]["minimum"], 1.0);
+        assert_eq!(props["maxRemediationsPerCycle"]["minimum"], 1.0);
+        let probe = &props["defaultProbe"]["properties"];
+        assert_eq!(probe["tcpPort"]["minimum"], 1.0);
+        assert_eq!(probe["tcpPort"]["maximum"], 65535.0);
+        assert_eq!(probe["periodSeconds"]["minimum"], 1.0);
+        assert_eq!(
+            props["scoringWeights"]["properties"]["latestTag"]["maximum"],
+            100.0
+        );
+        assert_eq!(
+            props["notifications"]["properties"]["scoreThreshold"]["maximum"],
+            100.0
+        );
+        assert_eq!(
+            props["profiles"]["additionalProperties"]["properties"]["maxRestartCount"]["minimum"],
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_spec_validations_attached() {
+        for crd in [
+            with_spec_validations(DevOpsPolicy::crd()),
+            with_spec_validations(ClusterDevOpsPolicy::crd()),
+        ] {
+            let rules = spec_schema(&crd)["x-kubernetes-validations"].clone();
+            let rules = rules.as_array().expect("CEL rules on spec");
+            assert_eq!(rules.len(), SPEC_VALIDATIONS.len());
+            assert!(
+                rules[0]["rule"]
+                    .as_str()
+                    .unwrap()
+                    .contains("has(self.defaultProbe)")
+            );
+            assert!(rules.iter().all(|r| r["message"].is_string()));
+        }
+        assert!(spec_schema(&DevOpsPolicy::crd())["x-kubernetes-validations"].is_null());
+    }
+
+    #[test]
+    fn test_bundles_satisfy_default_probe_rule() {
+        // Mirrors the first SPEC_VALIDATIONS rule so bundles stay applicable.
+        for bundle in crate::bundles::all_bundles() {
+            let spec = &bundle.spec;
+            let probes = spec.require_liveness_probe == Some(true)
+                || spec.require_readiness_probe == Some(true);
+            if spec.enforcement_mode == Some(EnforcementMode::Enforce) && probes {
+                assert!(spec.default_probe.is_some(), "bundle {}", bundle.name);
+            }
+        }
+    }
 }
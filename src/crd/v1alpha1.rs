@@ -0,0 +1,70 @@
+//! `devops.stochastic.io/v1alpha1` DevOpsPolicy, the schema before v1.
+//!
+//! Served alongside v1 when the CRD is installed with a conversion webhook;
+//! objects are always stored as v1. See `crate::conversion` for the field
+//! mapping.
+
+use kube::CustomResource;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{DevOpsPolicyStatus, EnforcementMode, Severity};
+
+/// One per-check severity override. v1 replaced the list with an object
+/// keyed by check (`SeverityOverrides`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SeverityOverride {
+    /// Check name in camelCase, e.g. `latestTag` or `missingLiveness`.
+    pub check: String,
+    pub severity: Severity,
+}
+
+/// DevOpsPolicy as defined by v1alpha1.
+///
+/// v1 fields without a v1alpha1 equivalent are kept in the
+/// `devops.stochastic.io/conversion-data` annotation while an object is
+/// read at this version.
+#[derive(CustomResource, Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+#[kube(
+    group = "devops.stochastic.io",
+    version = "v1alpha1",
+    kind = "DevOpsPolicy",
+    plural = "devopspolicies",
+    status = "DevOpsPolicyStatus",
+    namespaced,
+    printcolumn = r#"{"name":"Score","type":"integer","jsonPath":".status.healthScore"}"#,
+    printcolumn = r#"{"name":"Violations","type":"integer","jsonPath":".status.violations"}"#,
+    printcolumn = r#"{"name":"Mode","type":"string","jsonPath":".spec.mode"}"#,
+    printcolumn = r#"{"name":"Age","type":"date","jsonPath":".metadata.creationTimestamp"}"#
+)]
+#[serde(rename_all = "camelCase")]
+pub struct DevOpsPolicySpec {
+    /// Forbid container images tagged with `:latest` (v1 `forbidLatestTag`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forbid_latest: Option<bool>,
+
+    /// Require liveness probes on all containers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_liveness_probe: Option<bool>,
+
+    /// Require readiness probes on all containers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub require_readiness_probe: Option<bool>,
+
+    /// Maximum allowed restart count (v1 `maxRestartCount`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_restarts: Option<i32>,
+
+    /// Maximum seconds a pod may stay Pending (v1 `forbidPendingDuration`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_timeout_seconds: Option<u64>,
+
+    /// Enforcement mode (v1 `enforcementMode`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<EnforcementMode>,
+
+    /// Per-check severity overrides as a list (v1 uses an object).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity_overrides: Option<Vec<SeverityOverride>>,
+}
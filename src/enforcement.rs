@@ -1,13 +1,29 @@
+use chrono::{DateTime, Utc};
 use k8s_openapi::api::apps::v1::{DaemonSet, Deployment, StatefulSet};
 use k8s_openapi::api::core::v1::{Container, Pod, Probe, ResourceRequirements, TCPSocketAction};
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use kube::Client;
-use kube::api::{Api, Patch, PatchParams};
-use std::collections::BTreeMap;
+use kube::api::{Api, EvictParams, Patch, PatchParams};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 use tracing::{info, warn};
 
-use crate::crd::{DefaultProbeConfig, DefaultResourceConfig, DevOpsPolicySpec, EnforcementMode};
+use crate::crd::{
+    DefaultProbeConfig, DefaultResourceConfig, DevOpsPolicySpec, EnforcementMode, PlannedAction,
+    PlannedRemediation,
+};
+use crate::governance;
+
+/* ============================= ANNOTATIONS ============================= */
+
+/// Pod template annotation marking a workload as patched by the operator.
+pub const PATCHED_BY_ANNOTATION: &str = "devops.stochastic.io/patched-by";
+
+const PATCHED_BY_VALUE: &str = "kube-devops-operator";
+
+/// Pod template annotation recording which policy last patched the workload.
+pub const PATCHED_BY_POLICY_ANNOTATION: &str = "devops.stochastic.io/patched-by-policy";
 
 /* ============================= TYPES ============================= */
 
@@ -39,6 +55,25 @@ pub enum RemediationAction {
     InjectResources { container_index: usize },
 }
 
+impl RemediationAction {
+    /// camelCase action name used in `plannedRemediations`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RemediationAction::InjectLivenessProbe { .. } => "injectLivenessProbe",
+            RemediationAction::InjectReadinessProbe { .. } => "injectReadinessProbe",
+            RemediationAction::InjectResources { .. } => "injectResources",
+        }
+    }
+
+    pub fn container_index(&self) -> usize {
+        match self {
+            RemediationAction::InjectLivenessProbe { container_index }
+            | RemediationAction::InjectReadinessProbe { container_index }
+            | RemediationAction::InjectResources { container_index } => *container_index,
+        }
+    }
+}
+
 /// A plan describing all remediations for a single workload.
 #[derive(Debug, Clone)]
 pub struct RemediationPlan {
@@ -52,6 +87,30 @@ pub struct RemediationResult {
     pub workload: WorkloadRef,
     pub success: bool,
     pub message: String,
+    /// Pod template fields the patch changed; empty when it failed.
+    pub changes: Vec<FieldChange>,
+}
+
+/// One pod template container field changed by a remediation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub container: String,
+    /// Dotted path below the container, e.g. `resources.limits.cpu`.
+    pub path: String,
+    /// `null` when the field was absent.
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+impl FieldChange {
+    /// One-line form, e.g. `app.resources.limits.cpu: null -> "500m"`.
+    pub fn describe(&self) -> String {
+        format!(
+            "{}.{}: {} -> {}",
+            self.container, self.path, self.before, self.after
+        )
+    }
 }
 
 /* ============================= PROTECTED NAMESPACES ============================= */
@@ -80,6 +139,19 @@ pub fn is_enforcement_enabled(policy: &DevOpsPolicySpec) -> bool {
     matches!(policy.enforcement_mode, Some(EnforcementMode::Enforce))
 }
 
+/// Returns true if the policy has enforcement mode set to DryRun.
+pub fn is_dry_run(policy: &DevOpsPolicySpec) -> bool {
+    matches!(policy.enforcement_mode, Some(EnforcementMode::DryRun))
+}
+
+/// The policy's enforcement mode, defaulting to Audit.
+pub fn effective_mode(policy: &DevOpsPolicySpec) -> EnforcementMode {
+    policy
+        .enforcement_mode
+        .clone()
+        .unwrap_or(EnforcementMode::Audit)
+}
+
 /* ============================= OWNER RESOLUTION ============================= */
 
 /// Attempt to resolve the parent workload from a pod's owner_references.
@@ -219,13 +291,14 @@ pub fn build_default_resources(config: &DefaultResourceConfig) -> ResourceRequir
 /// Determine what remediations are needed for a pod's violations.
 ///
 /// Only patchable violations produce actions:
-/// - Missing liveness/readiness probes → inject default TCP probe
+/// - Missing liveness/readiness probes, or probes `requireConfiguredProbes`
+///   rejects → inject default TCP probe in their place
 /// - Missing resource limits → inject default requests+limits
 ///
 /// Non-patchable violations (`:latest` tag, high restarts, pending) are skipped.
 ///
-/// Returns `None` if no patchable remediation is needed or if the pod
-/// has no resolvable parent workload.
+/// Returns `None` if no patchable remediation is needed, if the pod
+/// has no resolvable parent workload, or if the policy is in audit mode.
 pub fn plan_remediation(pod: &Pod, policy: &DevOpsPolicySpec) -> Option<RemediationPlan> {
     let namespace = pod.metadata.namespace.as_deref().unwrap_or_default();
 
@@ -234,8 +307,8 @@ pub fn plan_remediation(pod: &Pod, policy: &DevOpsPolicySpec) -> Option<Remediat
         return None;
     }
 
-    // Must have enforcement enabled
-    if !is_enforcement_enabled(policy) {
+    // Must have enforcement enabled (dry-run plans without applying)
+    if !is_enforcement_enabled(policy) && !is_dry_run(policy) {
         return None;
     }
 
@@ -251,22 +324,29 @@ pub fn plan_remediation(pod: &Pod, policy: &DevOpsPolicySpec) -> Option<Remediat
     let mut actions = Vec::new();
 
     for (i, container) in containers.iter().enumerate() {
-        // Missing liveness probe (patchable)
-        if policy.require_liveness_probe.unwrap_or(false) && container.liveness_probe.is_none() {
+        // Containers exempted via skip-checks annotation are left untouched
+        let skipped = |check| governance::is_check_skipped(pod, &container.name, check);
+
+        // Missing or misconfigured liveness probe (patchable)
+        if policy.require_liveness_probe.unwrap_or(false)
+            && governance::probe_violation("liveness", container.liveness_probe.as_ref(), policy)
+                .is_some()
+            && !skipped("missing_liveness")
+        {
             actions.push(RemediationAction::InjectLivenessProbe { container_index: i });
         }
 
-        // Missing readiness probe (patchable)
-        if policy.require_readiness_probe.unwrap_or(false) && container.readiness_probe.is_none() {
+        // Missing or misconfigured readiness probe (patchable)
+        if policy.require_readiness_probe.unwrap_or(false)
+            && governance::probe_violation("readiness", container.readiness_probe.as_ref(), policy)
+                .is_some()
+            && !skipped("missing_readiness")
+        {
             actions.push(RemediationAction::InjectReadinessProbe { container_index: i });
         }
 
         // Missing resource requests/limits (patchable)
-        let has_resources = container
-            .resources
-            .as_ref()
-            .is_some_and(|r| r.limits.is_some() || r.requests.is_some());
-        if !has_resources && policy.default_resources.is_some() {
+        if !governance::has_resources(container) && policy.default_resources.is_some() {
             actions.push(RemediationAction::InjectResources { container_index: i });
         }
     }
@@ -278,6 +358,245 @@ pub fn plan_remediation(pod: &Pod, policy: &DevOpsPolicySpec) -> Option<Remediat
     Some(RemediationPlan { workload, actions })
 }
 
+/// Describe a plan for `status.plannedRemediations`, naming containers from `pod`.
+pub fn describe_plan(plan: &RemediationPlan, pod: &Pod) -> PlannedRemediation {
+    let containers = pod.spec.as_ref().map(|s| s.containers.as_slice());
+    PlannedRemediation {
+        workload: format!("{}/{}", plan.workload.kind, plan.workload.name),
+        actions: plan
+            .actions
+            .iter()
+            .map(|a| PlannedAction {
+                action: a.name().to_string(),
+                container: containers
+                    .and_then(|c| c.get(a.container_index()))
+                    .map(|c| c.name.clone())
+                    .unwrap_or_default(),
+            })
+            .collect(),
+    }
+}
+
+/// Plan remediations for a set of pods, once per owning workload.
+///
+/// Used by `dryRun` mode; results are ordered by workload.
+pub fn plan_remediations(pods: &[Pod], policy: &DevOpsPolicySpec) -> Vec<PlannedRemediation> {
+    let mut planned: BTreeMap<String, PlannedRemediation> = BTreeMap::new();
+    for pod in pods {
+        let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
+        if governance::is_system_namespace(ns) {
+            continue;
+        }
+        if let Some(plan) = plan_remediation(pod, policy) {
+            planned
+                .entry(plan.workload.key())
+                .or_insert_with(|| describe_plan(&plan, pod));
+        }
+    }
+    planned.into_values().collect()
+}
+
+/// Plan remediations for a set of pods, once per owning workload, ordered
+/// by workload key. The first pod seen for a workload decides its plan.
+pub fn plan_workload_remediations(pods: &[Pod], policy: &DevOpsPolicySpec) -> Vec<RemediationPlan> {
+    let mut plans: BTreeMap<String, RemediationPlan> = BTreeMap::new();
+    for pod in pods {
+        let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
+        if governance::is_system_namespace(ns) {
+            continue;
+        }
+        if let Some(plan) = plan_remediation(pod, policy) {
+            plans.entry(plan.workload.key()).or_insert(plan);
+        }
+    }
+    plans.into_values().collect()
+}
+
+/* ============================= THROTTLING ============================= */
+
+/// Remediation plans split by `maxRemediationsPerCycle` and
+/// `remediationCooldownSeconds`.
+#[derive(Debug, Clone, Default)]
+pub struct ThrottledPlans {
+    pub apply: Vec<RemediationPlan>,
+    pub deferred: Vec<RemediationPlan>,
+}
+
+/// Whether the policy's remediation cooldown, started by the last cycle that
+/// patched workloads (`last_round`), is still running at `now`.
+pub fn in_remediation_cooldown(
+    policy: &DevOpsPolicySpec,
+    last_round: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> bool {
+    let (Some(cooldown), Some(last)) = (policy.remediation_cooldown_seconds, last_round) else {
+        return false;
+    };
+    let cooldown = chrono::Duration::seconds(i64::try_from(cooldown).unwrap_or(i64::MAX / 1000));
+    now.signed_duration_since(last) < cooldown
+}
+
+/// Split plans into those applied this cycle and those deferred: everything
+/// is deferred during the cooldown, otherwise plans beyond the per-cycle
+/// limit are. Unset limits never throttle.
+pub fn throttle_plans(
+    plans: Vec<RemediationPlan>,
+    policy: &DevOpsPolicySpec,
+    last_round: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> ThrottledPlans {
+    if in_remediation_cooldown(policy, last_round, now) {
+        return ThrottledPlans {
+            apply: Vec::new(),
+            deferred: plans,
+        };
+    }
+    let mut apply = plans;
+    let deferred = match policy.max_remediations_per_cycle {
+        Some(max) if apply.len() > max as usize => apply.split_off(max as usize),
+        _ => Vec::new(),
+    };
+    ThrottledPlans { apply, deferred }
+}
+
+/* ============================= POD EVICTION ============================= */
+
+/// Restart count above which a pod is evicted when `eviction.restartThreshold` is unset.
+pub const DEFAULT_EVICTION_RESTART_THRESHOLD: i32 = 20;
+
+/// Per-workload eviction cooldown when `eviction.cooldownSeconds` is unset.
+pub const DEFAULT_EVICTION_COOLDOWN_SECONDS: u64 = 600;
+
+/// Evictions per reconcile cycle when `eviction.maxEvictionsPerCycle` is unset.
+pub const DEFAULT_MAX_EVICTIONS_PER_CYCLE: u32 = 1;
+
+/// `eviction` settings with defaults applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvictionSettings {
+    pub restart_threshold: i32,
+    pub cooldown_seconds: u64,
+    pub max_per_cycle: u32,
+}
+
+impl EvictionSettings {
+    pub fn from_policy(policy: &DevOpsPolicySpec) -> Self {
+        let config = policy.eviction.clone().unwrap_or_default();
+        Self {
+            restart_threshold: config
+                .restart_threshold
+                .unwrap_or(DEFAULT_EVICTION_RESTART_THRESHOLD),
+            cooldown_seconds: config
+                .cooldown_seconds
+                .unwrap_or(DEFAULT_EVICTION_COOLDOWN_SECONDS),
+            max_per_cycle: config
+                .max_evictions_per_cycle
+                .unwrap_or(DEFAULT_MAX_EVICTIONS_PER_CYCLE),
+        }
+    }
+}
+
+/// A pod selected for eviction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvictionCandidate {
+    pub pod_name: String,
+    pub workload: WorkloadRef,
+    /// Container with the most restarts.
+    pub container: String,
+    pub restarts: i32,
+}
+
+/// Whether the policy opts in to crash-loop eviction.
+pub fn is_eviction_enabled(policy: &DevOpsPolicySpec) -> bool {
+    policy.evict_crash_looping.unwrap_or(false)
+}
+
+/// The container with the most restarts above `threshold`, ignoring
+/// containers exempt from `high_restarts`.
+fn crash_looping_container(pod: &Pod, threshold: i32) -> Option<(String, i32)> {
+    pod.status
+        .as_ref()?
+        .container_statuses
+        .as_ref()?
+        .iter()
+        .filter(|cs| cs.restart_count > threshold)
+        .filter(|cs| !governance::is_check_skipped(pod, &cs.name, "high_restarts"))
+        .max_by_key(|cs| cs.restart_count)
+        .map(|cs| (cs.name.clone(), cs.restart_count))
+}
+
+/// Choose the pods to evict this cycle.
+///
+/// A pod qualifies when a container restarted more than the threshold, it is
+/// owned by a Deployment, StatefulSet, or DaemonSet (so it is recreated), it
+/// is not already terminating, and its namespace is not protected. At most
+/// one pod per workload is chosen, workloads evicted within the cooldown
+/// (`last_evicted`, keyed by [`WorkloadRef::key`]) are skipped, and the pods
+/// with the most restarts go first, up to the per-cycle limit.
+pub fn plan_evictions(
+    pods: &[Pod],
+    policy: &DevOpsPolicySpec,
+    last_evicted: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Vec<EvictionCandidate> {
+    if !is_eviction_enabled(policy) {
+        return Vec::new();
+    }
+    let settings = EvictionSettings::from_policy(policy);
+    let cooldown = chrono::Duration::seconds(
+        i64::try_from(settings.cooldown_seconds).unwrap_or(i64::MAX / 1000),
+    );
+
+    let mut by_workload: BTreeMap<String, EvictionCandidate> = BTreeMap::new();
+    for pod in pods {
+        let namespace = pod.metadata.namespace.as_deref().unwrap_or_default();
+        if is_protected_namespace(namespace) || pod.metadata.deletion_timestamp.is_some() {
+            continue;
+        }
+        let Some((container, restarts)) = crash_looping_container(pod, settings.restart_threshold)
+        else {
+            continue;
+        };
+        let Some(workload) = resolve_owner(pod) else {
+            continue;
+        };
+        let key = workload.key();
+        if last_evicted
+            .get(&key)
+            .is_some_and(|at| now.signed_duration_since(*at) < cooldown)
+        {
+            continue;
+        }
+        let candidate = EvictionCandidate {
+            pod_name: pod.metadata.name.clone().unwrap_or_default(),
+            workload,
+            container,
+            restarts,
+        };
+        match by_workload.get(&key) {
+            Some(existing) if existing.restarts >= candidate.restarts => {}
+            _ => {
+                by_workload.insert(key, candidate);
+            }
+        }
+    }
+
+    let mut candidates: Vec<EvictionCandidate> = by_workload.into_values().collect();
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.restarts));
+    candidates.truncate(settings.max_per_cycle as usize);
+    candidates
+}
+
+/// Describe an eviction for `status.plannedRemediations`.
+pub fn describe_eviction(candidate: &EvictionCandidate) -> PlannedRemediation {
+    PlannedRemediation {
+        workload: format!("{}/{}", candidate.workload.kind, candidate.workload.name),
+        actions: vec![PlannedAction {
+            action: "evictPod".to_string(),
+            container: candidate.container.clone(),
+        }],
+    }
+}
+
 /* ============================= PATCH GENERATION ============================= */
 
 /// Build a JSON strategic-merge patch for a workload's pod template containers.
@@ -315,7 +634,8 @@ pub fn build_container_patches(
                 if let Some(container) = containers.get(*container_index) {
                     let probe = build_default_probe(container, &probe_config);
                     if let Some(patch) = container_patches.get_mut(*container_index) {
-                        patch["livenessProbe"] = serde_json::to_value(&probe).unwrap_or_default();
+                        patch["livenessProbe"] =
+                            replacing_probe(container.liveness_probe.as_ref(), &probe);
                     }
                 }
             }
@@ -323,7 +643,8 @@ pub fn build_container_patches(
                 if let Some(container) = containers.get(*container_index) {
                     let probe = build_default_probe(container, &probe_config);
                     if let Some(patch) = container_patches.get_mut(*container_index) {
-                        patch["readinessProbe"] = serde_json::to_value(&probe).unwrap_or_default();
+                        patch["readinessProbe"] =
+                            replacing_probe(container.readiness_probe.as_ref(), &probe);
                     }
                 }
             }
@@ -341,7 +662,7 @@ pub fn build_container_patches(
             "template": {
                 "metadata": {
                     "annotations": {
-                        "devops.stochastic.io/patched-by": "kube-devops-operator"
+                        PATCHED_BY_ANNOTATION: PATCHED_BY_VALUE
                     }
                 },
                 "spec": {
@@ -352,19 +673,265 @@ pub fn build_container_patches(
     })
 }
 
+/// Strategic-merge value that replaces `current` with `probe`.
+///
+/// Probes merge field by field, so fields only `current` sets are nulled;
+/// otherwise a misconfigured probe's handler or thresholds would survive
+/// next to the default's (and two handlers fail API validation).
+fn replacing_probe(current: Option<&Probe>, probe: &Probe) -> serde_json::Value {
+    let mut value = serde_json::to_value(probe).unwrap_or_default();
+    if let (Some(current), Some(fields)) = (
+        current.and_then(|c| serde_json::to_value(c).ok()),
+        value.as_object_mut(),
+    ) {
+        for key in current.as_object().into_iter().flat_map(|c| c.keys()) {
+            fields.entry(key.clone()).or_insert(serde_json::Value::Null);
+        }
+    }
+    value
+}
+
+/* ============================= PATCH DIFF ============================= */
+
+/// Field-level before/after diff of the container changes in a patch built
+/// by `build_container_patches`, in container order.
+pub fn diff_container_patch(
+    patch: &serde_json::Value,
+    containers: &[Container],
+) -> Vec<FieldChange> {
+    let mut changes = Vec::new();
+    let Some(patched) = patch["spec"]["template"]["spec"]["containers"].as_array() else {
+        return changes;
+    };
+    for entry in patched {
+        let Some(container) = entry["name"]
+            .as_str()
+            .and_then(|name| containers.iter().find(|c| c.name == name))
+        else {
+            continue;
+        };
+        let Some(fields) = entry.as_object() else {
+            continue;
+        };
+        for (field, after) in fields.iter().filter(|(field, _)| *field != "name") {
+            let before = container_field(container, field);
+            diff_values(&container.name, field, &before, after, &mut changes);
+        }
+    }
+    changes
+}
+
+/// Record the leaves that differ between `before` and `after`, descending
+/// into objects (an absent side counts as an empty object).
+fn diff_values(
+    container: &str,
+    path: &str,
+    before: &serde_json::Value,
+    after: &serde_json::Value,
+    out: &mut Vec<FieldChange>,
+) {
+    use serde_json::Value;
+
+    fn as_object<'a>(
+        value: &'a Value,
+        empty: &'a serde_json::Map<String, Value>,
+    ) -> Option<&'a serde_json::Map<String, Value>> {
+        match value {
+            Value::Object(map) => Some(map),
+            Value::Null => Some(empty),
+            _ => None,
+        }
+    }
+
+    let empty = serde_json::Map::new();
+    match (as_object(before, &empty), as_object(after, &empty)) {
+        (Some(b), Some(a)) if !(before.is_null() && after.is_null()) => {
+            let keys: std::collections::BTreeSet<&String> = b.keys().chain(a.keys()).collect();
+            for key in keys {
+                diff_values(
+                    container,
+                    &format!("{path}.{key}"),
+                    b.get(key).unwrap_or(&Value::Null),
+                    a.get(key).unwrap_or(&Value::Null),
+                    out,
+                );
+            }
+        }
+        _ if before != after => out.push(FieldChange {
+            container: container.to_string(),
+            path: path.to_string(),
+            before: before.clone(),
+            after: after.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/* ============================= REMEDIATION BACKUP ============================= */
+
+/// Workload annotation recording container fields as they were before the
+/// operator first patched them; `enforce rollback` restores from it.
+pub const REMEDIATION_BACKUP_ANNOTATION: &str = "devops.stochastic.io/remediation-backup";
+
+/// Pre-remediation container fields, keyed by container name and then by
+/// field (`livenessProbe`, `readinessProbe`, `resources`). `null` records a
+/// field that was absent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RemediationBackup(pub BTreeMap<String, BTreeMap<String, serde_json::Value>>);
+
+impl RemediationBackup {
+    /// Read the backup from workload annotations; `None` when absent or unparseable.
+    pub fn from_annotations(annotations: Option<&BTreeMap<String, String>>) -> Option<Self> {
+        let raw = annotations?.get(REMEDIATION_BACKUP_ANNOTATION)?;
+        serde_json::from_str(raw).ok()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Serialized form stored in `REMEDIATION_BACKUP_ANNOTATION`.
+    pub fn to_annotation_value(&self) -> String {
+        serde_json::to_string(&self.0).unwrap_or_default()
+    }
+}
+
+/// Container field an action overwrites.
+fn action_field(action: &RemediationAction) -> &'static str {
+    match action {
+        RemediationAction::InjectLivenessProbe { .. } => "livenessProbe",
+        RemediationAction::InjectReadinessProbe { .. } => "readinessProbe",
+        RemediationAction::InjectResources { .. } => "resources",
+    }
+}
+
+fn container_field(container: &Container, field: &str) -> serde_json::Value {
+    let value = match field {
+        "livenessProbe" => serde_json::to_value(&container.liveness_probe),
+        "readinessProbe" => serde_json::to_value(&container.readiness_probe),
+        "resources" => serde_json::to_value(&container.resources),
+        _ => return serde_json::Value::Null,
+    };
+    value.unwrap_or_default()
+}
+
+/// Extend `existing` with the current value of every field `actions` will
+/// overwrite. Fields already recorded keep their value, so repeated
+/// remediations never replace the original pre-remediation state.
+pub fn record_backup(
+    existing: Option<RemediationBackup>,
+    actions: &[RemediationAction],
+    containers: &[Container],
+) -> RemediationBackup {
+    let mut backup = existing.unwrap_or_default();
+    for action in actions {
+        let Some(container) = containers.get(action.container_index()) else {
+            continue;
+        };
+        let field = action_field(action);
+        backup
+            .0
+            .entry(container.name.clone())
+            .or_default()
+            .entry(field.to_string())
+            .or_insert_with(|| container_field(container, field));
+    }
+    backup
+}
+
+/// Containers with every backed-up field restored. Fields whose recorded
+/// value cannot be decoded are left as they are.
+pub fn restore_containers(containers: &[Container], backup: &RemediationBackup) -> Vec<Container> {
+    containers
+        .iter()
+        .map(|container| {
+            let mut restored = container.clone();
+            let Some(fields) = backup.0.get(&container.name) else {
+                return restored;
+            };
+            for (field, value) in fields {
+                match field.as_str() {
+                    "livenessProbe" => {
+                        if let Ok(probe) = serde_json::from_value(value.clone()) {
+                            restored.liveness_probe = probe;
+                        }
+                    }
+                    "readinessProbe" => {
+                        if let Ok(probe) = serde_json::from_value(value.clone()) {
+                            restored.readiness_probe = probe;
+                        }
+                    }
+                    "resources" => {
+                        if let Ok(resources) = serde_json::from_value(value.clone()) {
+                            restored.resources = resources;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            restored
+        })
+        .collect()
+}
+
+/// Merge patch reverting a workload to its backup: replaces the pod
+/// template containers with the restored list and removes the backup and
+/// tracking annotations.
+pub fn build_rollback_patch(
+    containers: &[Container],
+    backup: &RemediationBackup,
+) -> serde_json::Value {
+    let restored = restore_containers(containers, backup);
+    serde_json::json!({
+        "metadata": {
+            "annotations": {
+                REMEDIATION_BACKUP_ANNOTATION: null
+            }
+        },
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        PATCHED_BY_ANNOTATION: null,
+                        PATCHED_BY_POLICY_ANNOTATION: null
+                    }
+                },
+                "spec": {
+                    "containers": serde_json::to_value(&restored).unwrap_or_default()
+                }
+            }
+        }
+    })
+}
+
 /* ============================= ASYNC API ============================= */
 
 /// Apply a remediation plan to the cluster by patching the parent workload.
 ///
-/// Patches the workload's pod template with the remediation actions,
-/// then returns a result indicating success or failure.
+/// Patches the workload's pod template with the remediation actions and
+/// records `policy_name` in a tracking annotation, then returns a result
+/// indicating success or failure.
 pub async fn apply_remediation(
     plan: &RemediationPlan,
     client: &Client,
     policy: &DevOpsPolicySpec,
+    policy_name: &str,
 ) -> RemediationResult {
-    let containers = match get_workload_containers(plan, client).await {
-        Ok(c) => c,
+    if !matches!(
+        plan.workload.kind.as_str(),
+        "Deployment" | "StatefulSet" | "DaemonSet"
+    ) {
+        return RemediationResult {
+            workload: plan.workload.clone(),
+            success: false,
+            message: format!("Unsupported workload kind: {}", plan.workload.kind),
+            changes: Vec::new(),
+        };
+    }
+
+    let state = match get_workload_state(&plan.workload, client).await {
+        Ok(s) => s,
         Err(e) => {
             warn!(
                 workload = %plan.workload.key(),
@@ -375,57 +942,29 @@ pub async fn apply_remediation(
                 workload: plan.workload.clone(),
                 success: false,
                 message: format!("Failed to read workload: {e}"),
+                changes: Vec::new(),
             };
         }
     };
 
-    let patch_body = build_container_patches(&plan.actions, &containers, policy);
+    let mut patch_body = build_container_patches(&plan.actions, &state.containers, policy);
+    let changes = diff_container_patch(&patch_body, &state.containers);
+    patch_body["spec"]["template"]["metadata"]["annotations"][PATCHED_BY_POLICY_ANNOTATION] =
+        serde_json::Value::String(policy_name.to_string());
 
-    let result = match plan.workload.kind.as_str() {
-        "Deployment" => {
-            let api: Api<Deployment> = Api::namespaced(client.clone(), &plan.workload.namespace);
-            api.patch(
-                &plan.workload.name,
-                &PatchParams::apply("kube-devops-operator"),
-                &Patch::Strategic(&patch_body),
-            )
-            .await
-            .map(|_| ())
-        }
-        "StatefulSet" => {
-            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &plan.workload.namespace);
-            api.patch(
-                &plan.workload.name,
-                &PatchParams::apply("kube-devops-operator"),
-                &Patch::Strategic(&patch_body),
-            )
-            .await
-            .map(|_| ())
-        }
-        "DaemonSet" => {
-            let api: Api<DaemonSet> = Api::namespaced(client.clone(), &plan.workload.namespace);
-            api.patch(
-                &plan.workload.name,
-                &PatchParams::apply("kube-devops-operator"),
-                &Patch::Strategic(&patch_body),
-            )
-            .await
-            .map(|_| ())
-        }
-        other => {
-            return RemediationResult {
-                workload: plan.workload.clone(),
-                success: false,
-                message: format!("Unsupported workload kind: {other}"),
-            };
-        }
-    };
+    let existing = RemediationBackup::from_annotations(Some(&state.annotations));
+    let backup = record_backup(existing, &plan.actions, &state.containers);
+    patch_body["metadata"]["annotations"][REMEDIATION_BACKUP_ANNOTATION] =
+        serde_json::Value::String(backup.to_annotation_value());
+
+    let result = patch_workload(&plan.workload, client, &Patch::Strategic(&patch_body)).await;
 
     match result {
         Ok(()) => {
             info!(
                 workload = %plan.workload.key(),
                 actions = plan.actions.len(),
+                diff = %serde_json::to_string(&changes).unwrap_or_default(),
                 "remediation_applied"
             );
             RemediationResult {
@@ -436,6 +975,7 @@ pub async fn apply_remediation(
                     plan.actions.len(),
                     plan.workload.key()
                 ),
+                changes,
             }
         }
         Err(e) => {
@@ -448,46 +988,260 @@ pub async fn apply_remediation(
                 workload: plan.workload.clone(),
                 success: false,
                 message: format!("Patch failed: {e}"),
+                changes: Vec::new(),
             }
         }
     }
 }
 
-/// Look up the containers in a workload's pod template spec.
-async fn get_workload_containers(
-    plan: &RemediationPlan,
+/// Outcome of a pod eviction.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EvictionOutcome {
+    Evicted,
+    /// A PodDisruptionBudget does not currently allow the disruption.
+    BlockedByDisruptionBudget,
+    /// The pod is already gone.
+    NotFound,
+    Failed(String),
+}
+
+/// Evict a pod through the Eviction API, which honours PodDisruptionBudgets.
+pub async fn evict_pod(candidate: &EvictionCandidate, client: &Client) -> EvictionOutcome {
+    let api: Api<Pod> = Api::namespaced(client.clone(), &candidate.workload.namespace);
+    match api
+        .evict(&candidate.pod_name, &EvictParams::default())
+        .await
+    {
+        Ok(_) => {
+            info!(
+                pod = %candidate.pod_name,
+                workload = %candidate.workload.key(),
+                restarts = candidate.restarts,
+                "pod_evicted"
+            );
+            EvictionOutcome::Evicted
+        }
+        Err(kube::Error::Api(e)) if e.code == 429 => {
+            info!(
+                pod = %candidate.pod_name,
+                workload = %candidate.workload.key(),
+                "pod_eviction_blocked_by_pdb"
+            );
+            EvictionOutcome::BlockedByDisruptionBudget
+        }
+        Err(kube::Error::Api(e)) if e.code == 404 => EvictionOutcome::NotFound,
+        Err(e) => {
+            warn!(
+                pod = %candidate.pod_name,
+                workload = %candidate.workload.key(),
+                error = %e,
+                "pod_eviction_failed"
+            );
+            EvictionOutcome::Failed(e.to_string())
+        }
+    }
+}
+
+/// Read the backup recorded on a workload, if any.
+pub async fn read_remediation_backup(
+    workload: &WorkloadRef,
+    client: &Client,
+) -> Result<Option<RemediationBackup>, kube::Error> {
+    let state = get_workload_state(workload, client).await?;
+    Ok(RemediationBackup::from_annotations(Some(&state.annotations)).filter(|b| !b.is_empty()))
+}
+
+/// Revert the remediations recorded on a workload and drop its backup.
+///
+/// Returns the backup that was restored, or `None` when the workload carries
+/// no backup. Changing the pod template triggers a rollout.
+pub async fn rollback_remediation(
+    workload: &WorkloadRef,
     client: &Client,
-) -> Result<Vec<Container>, kube::Error> {
-    match plan.workload.kind.as_str() {
+) -> Result<Option<RemediationBackup>, kube::Error> {
+    let state = get_workload_state(workload, client).await?;
+    let Some(backup) =
+        RemediationBackup::from_annotations(Some(&state.annotations)).filter(|b| !b.is_empty())
+    else {
+        return Ok(None);
+    };
+
+    let patch = build_rollback_patch(&state.containers, &backup);
+    patch_workload(workload, client, &Patch::Merge(&patch)).await?;
+    info!(workload = %workload.key(), "remediation_rolled_back");
+    Ok(Some(backup))
+}
+
+/// A workload's pod template containers and its own annotations.
+struct WorkloadState {
+    containers: Vec<Container>,
+    annotations: BTreeMap<String, String>,
+}
+
+/// Look up a workload's pod template containers and annotations.
+async fn get_workload_state(
+    workload: &WorkloadRef,
+    client: &Client,
+) -> Result<WorkloadState, kube::Error> {
+    let (meta, template) = match workload.kind.as_str() {
         "Deployment" => {
-            let api: Api<Deployment> = Api::namespaced(client.clone(), &plan.workload.namespace);
-            let dep = api.get(&plan.workload.name).await?;
-            Ok(dep
-                .spec
-                .and_then(|s| s.template.spec)
-                .map(|s| s.containers)
-                .unwrap_or_default())
+            let api: Api<Deployment> = Api::namespaced(client.clone(), &workload.namespace);
+            let dep = api.get(&workload.name).await?;
+            (dep.metadata, dep.spec.and_then(|s| s.template.spec))
         }
         "StatefulSet" => {
-            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &plan.workload.namespace);
-            let sts = api.get(&plan.workload.name).await?;
-            Ok(sts
-                .spec
-                .and_then(|s| s.template.spec)
-                .map(|s| s.containers)
-                .unwrap_or_default())
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &workload.namespace);
+            let sts = api.get(&workload.name).await?;
+            (sts.metadata, sts.spec.and_then(|s| s.template.spec))
         }
         "DaemonSet" => {
-            let api: Api<DaemonSet> = Api::namespaced(client.clone(), &plan.workload.namespace);
-            let ds = api.get(&plan.workload.name).await?;
-            Ok(ds
-                .spec
-                .and_then(|s| s.template.spec)
-                .map(|s| s.containers)
-                .unwrap_or_default())
+            let api: Api<DaemonSet> = Api::namespaced(client.clone(), &workload.namespace);
+            let ds = api.get(&workload.name).await?;
+            (ds.metadata, ds.spec.and_then(|s| s.template.spec))
+        }
+        _ => (Default::default(), None),
+    };
+    Ok(WorkloadState {
+        containers: template.map(|s| s.containers).unwrap_or_default(),
+        annotations: meta.annotations.unwrap_or_default(),
+    })
+}
+
+/// Patch a Deployment, StatefulSet, or DaemonSet; other kinds are ignored
+/// (callers check the kind first).
+async fn patch_workload(
+    workload: &WorkloadRef,
+    client: &Client,
+    patch: &Patch<&serde_json::Value>,
+) -> Result<(), kube::Error> {
+    let params = PatchParams::apply("kube-devops-operator");
+    match workload.kind.as_str() {
+        "Deployment" => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), &workload.namespace);
+            api.patch(&workload.name, &params, patch).await.map(|_| ())
+        }
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &workload.namespace);
+            api.patch(&workload.name, &params, patch).await.map(|_| ())
+        }
+        "DaemonSet" => {
+            let api: Api<DaemonSet> = Api::namespaced(client.clone(), &workload.namespace);
+            api.patch(&workload.name, &params, patch).await.map(|_| ())
+        }
+        _ => Ok(()),
+    }
+}
+
+/* ============================= ANNOTATION CLEANUP ============================= */
+
+/// Whether a pod template's annotations record a patch by `policy_name`.
+pub fn is_patched_by_policy(
+    template_annotations: Option<&BTreeMap<String, String>>,
+    policy_name: &str,
+) -> bool {
+    template_annotations
+        .and_then(|a| a.get(PATCHED_BY_POLICY_ANNOTATION))
+        .is_some_and(|p| p == policy_name)
+}
+
+/// Merge patch removing the operator's tracking annotations from a pod template.
+pub fn build_annotation_cleanup_patch() -> serde_json::Value {
+    serde_json::json!({
+        "spec": {
+            "template": {
+                "metadata": {
+                    "annotations": {
+                        PATCHED_BY_ANNOTATION: null,
+                        PATCHED_BY_POLICY_ANNOTATION: null
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Strip tracking annotations from every workload in `namespace` that
+/// `policy_name` patched. Returns the number of workloads cleaned up.
+///
+/// Changing the pod template triggers a rollout of the affected workloads.
+pub async fn cleanup_patched_annotations(
+    client: &Client,
+    namespace: &str,
+    policy_name: &str,
+) -> Result<u32, kube::Error> {
+    let deployments =
+        strip_tracking_annotations::<Deployment>(client, namespace, policy_name, |d| {
+            d.spec
+                .as_ref()?
+                .template
+                .metadata
+                .as_ref()?
+                .annotations
+                .as_ref()
+        })
+        .await?;
+    let statefulsets =
+        strip_tracking_annotations::<StatefulSet>(client, namespace, policy_name, |s| {
+            s.spec
+                .as_ref()?
+                .template
+                .metadata
+                .as_ref()?
+                .annotations
+                .as_ref()
+        })
+        .await?;
+    let daemonsets = strip_tracking_annotations::<DaemonSet>(client, namespace, policy_name, |d| {
+        d.spec
+            .as_ref()?
+            .template
+            .metadata
+            .as_ref()?
+            .annotations
+            .as_ref()
+    })
+    .await?;
+
+    Ok(deployments + statefulsets + daemonsets)
+}
+
+async fn strip_tracking_annotations<K>(
+    client: &Client,
+    namespace: &str,
+    policy_name: &str,
+    template_annotations: fn(&K) -> Option<&BTreeMap<String, String>>,
+) -> Result<u32, kube::Error>
+where
+    K: kube::Resource<Scope = k8s_openapi::NamespaceResourceScope>
+        + Clone
+        + serde::de::DeserializeOwned
+        + std::fmt::Debug,
+    K::DynamicType: Default,
+{
+    let api: Api<K> = Api::namespaced(client.clone(), namespace);
+    let patch = build_annotation_cleanup_patch();
+    let mut cleaned = 0;
+
+    for workload in api.list(&Default::default()).await? {
+        if !is_patched_by_policy(template_annotations(&workload), policy_name) {
+            continue;
         }
-        _ => Ok(vec![]),
+        let name = workload.meta().name.clone().unwrap_or_default();
+        api.patch(
+            &name,
+            &PatchParams::apply("kube-devops-operator"),
+            &Patch::Merge(&patch),
+        )
+        .await?;
+        info!(
+            workload = %format!("{}/{namespace}/{name}", K::kind(&Default::default())),
+            policy = %policy_name,
+            "tracking_annotations_removed"
+        );
+        cleaned += 1;
     }
+
+    Ok(cleaned)
 }
 
 /// Resolve the owner of a pod via API lookup (more accurate than offline heuristic).
@@ -747,6 +1501,35 @@ mod tests {
     fn test_enforcement_disabled_when_none() {
         let policy = DevOpsPolicySpec::default();
         assert!(!is_enforcement_enabled(&policy));
+        assert_eq!(effective_mode(&policy), EnforcementMode::Audit);
+    }
+
+    #[test]
+    fn test_dry_run_plans_without_enforcing() {
+        let policy = DevOpsPolicySpec {
+            enforcement_mode: Some(EnforcementMode::DryRun),
+            ..make_enforce_policy()
+        };
+        assert!(!is_enforcement_enabled(&policy));
+        assert!(is_dry_run(&policy));
+
+        let pod = make_pod_with_owner(
+            "web-abc12-xyz",
+            "prod",
+            "img:1.0",
+            "ReplicaSet",
+            "web-abc12",
+            false,
+            true,
+        );
+        let pods = vec![pod.clone(), pod];
+        let planned = plan_remediations(&pods, &policy);
+        assert_eq!(planned.len(), 1);
+        assert_eq!(planned[0].workload, "Deployment/web");
+        assert_eq!(planned[0].actions[0].action, "injectLivenessProbe");
+        assert!(!planned[0].actions[0].container.is_empty());
+
+        assert!(plan_remediations(&pods, &make_audit_policy()).is_empty());
     }
 
     // ── is_protected_namespace ──
@@ -899,6 +1682,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_plan_replaces_unconfigured_probes() {
+        let pod = make_pod_with_owner("p", "prod", "img:1.0", "Deployment", "web", true, true);
+        let probe_actions = |policy: &DevOpsPolicySpec| {
+            plan_remediation(&pod, policy).map_or(0, |p| {
+                p.actions
+                    .iter()
+                    .filter(|a| !matches!(a, RemediationAction::InjectResources { .. }))
+                    .count()
+            })
+        };
+        let mut policy = make_enforce_policy();
+        assert_eq!(probe_actions(&policy), 0);
+
+        // Handler-less probes fail requireConfiguredProbes and are replaced
+        policy.require_configured_probes = Some(true);
+        assert_eq!(probe_actions(&policy), 2);
+    }
+
+    #[test]
+    fn test_plan_respects_skip_checks_annotation() {
+        let mut pod =
+            make_pod_with_owner("p", "prod", "img:1.0", "Deployment", "web", false, false);
+        pod.metadata.annotations = Some(BTreeMap::from([(
+            "devops.stochastic.io/skip-checks.main".to_string(),
+            "missing_liveness, missing_readiness".to_string(),
+        )]));
+        let plan = plan_remediation(&pod, &make_enforce_policy()).unwrap();
+        assert!(
+            plan.actions
+                .iter()
+                .all(|a| matches!(a, RemediationAction::InjectResources { .. }))
+        );
+    }
+
     #[test]
     fn test_plan_missing_resources() {
         let pod = make_pod_with_owner("p", "prod", "img:1.0", "Deployment", "api", true, true);
@@ -1041,6 +1859,34 @@ mod tests {
         assert_eq!(annotation, "kube-devops-operator");
     }
 
+    // ── annotation cleanup ──
+
+    #[test]
+    fn test_is_patched_by_policy() {
+        let annotations = BTreeMap::from([
+            (
+                PATCHED_BY_ANNOTATION.to_string(),
+                "kube-devops-operator".to_string(),
+            ),
+            (
+                PATCHED_BY_POLICY_ANNOTATION.to_string(),
+                "prod-policy".to_string(),
+            ),
+        ]);
+        assert!(is_patched_by_policy(Some(&annotations), "prod-policy"));
+        assert!(!is_patched_by_policy(Some(&annotations), "other-policy"));
+        assert!(!is_patched_by_policy(None, "prod-policy"));
+    }
+
+    #[test]
+    fn test_cleanup_patch_nulls_tracking_annotations() {
+        let patch = build_annotation_cleanup_patch();
+        let annotations = &patch["spec"]["template"]["metadata"]["annotations"];
+        assert!(annotations[PATCHED_BY_ANNOTATION].is_null());
+        assert!(annotations[PATCHED_BY_POLICY_ANNOTATION].is_null());
+        assert_eq!(annotations.as_object().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_patch_includes_liveness_probe() {
         let containers = vec![Container {
@@ -1056,6 +1902,33 @@ mod tests {
         assert_eq!(container_patch["name"], "main");
     }
 
+    #[test]
+    fn test_patch_replaces_misconfigured_probe() {
+        let containers = vec![Container {
+            name: "main".to_string(),
+            liveness_probe: Some(Probe {
+                exec: Some(k8s_openapi::api::core::v1::ExecAction {
+                    command: Some(vec!["true".to_string()]),
+                }),
+                period_seconds: Some(1),
+                failure_threshold: Some(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let actions = vec![RemediationAction::InjectLivenessProbe { container_index: 0 }];
+        let patch = build_container_patches(&actions, &containers, &make_enforce_policy());
+
+        let probe = &patch["spec"]["template"]["spec"]["containers"][0]["livenessProbe"];
+        assert_eq!(probe.get("exec"), Some(&serde_json::Value::Null));
+        assert_eq!(
+            probe.get("failureThreshold"),
+            Some(&serde_json::Value::Null)
+        );
+        assert_eq!(probe["periodSeconds"], 10);
+        assert!(probe["tcpSocket"].is_object());
+    }
+
     #[test]
     fn test_patch_includes_resources() {
         let containers = vec![Container {
@@ -1090,6 +1963,416 @@ mod tests {
         assert!(container_patch.get("resources").is_some());
     }
 
+    // ── throttling ──
+
+    fn make_plans(names: &[&str]) -> Vec<RemediationPlan> {
+        names
+            .iter()
+            .map(|n| RemediationPlan {
+                workload: WorkloadRef {
+                    kind: "Deployment".to_string(),
+                    name: n.to_string(),
+                    namespace: "production".to_string(),
+                },
+                actions: vec![RemediationAction::InjectLivenessProbe { container_index: 0 }],
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_plan_workload_remediations_dedupes_and_orders() {
+        let pods = vec![
+            make_pod_with_owner(
+                "web-1",
+                "production",
+                "nginx:1.25",
+                "ReplicaSet",
+                "web-7d9f8b6c5d",
+                false,
+                true,
+            ),
+            make_pod_with_owner(
+                "api-1",
+                "production",
+                "nginx:1.25",
+                "ReplicaSet",
+                "api-7d9f8b6c5d",
+                false,
+                true,
+            ),
+            make_pod_with_owner(
+                "web-2",
+                "production",
+                "nginx:1.25",
+                "ReplicaSet",
+                "web-7d9f8b6c5d",
+                false,
+                true,
+            ),
+        ];
+        let plans = plan_workload_remediations(&pods, &make_enforce_policy());
+        let names: Vec<&str> = plans.iter().map(|p| p.workload.name.as_str()).collect();
+        assert_eq!(names, vec!["api", "web"]);
+    }
+
+    #[test]
+    fn test_throttle_plans_unlimited_by_default() {
+        let policy = make_enforce_policy();
+        let throttled = throttle_plans(make_plans(&["a", "b", "c"]), &policy, None, at(0));
+        assert_eq!(throttled.apply.len(), 3);
+        assert!(throttled.deferred.is_empty());
+    }
+
+    #[test]
+    fn test_throttle_plans_max_per_cycle() {
+        let policy = DevOpsPolicySpec {
+            max_remediations_per_cycle: Some(2),
+            ..make_enforce_policy()
+        };
+        let throttled = throttle_plans(make_plans(&["a", "b", "c"]), &policy, None, at(0));
+        let applied: Vec<&str> = throttled
+            .apply
+            .iter()
+            .map(|p| p.workload.name.as_str())
+            .collect();
+        assert_eq!(applied, vec!["a", "b"]);
+        assert_eq!(throttled.deferred.len(), 1);
+        assert_eq!(throttled.deferred[0].workload.name, "c");
+    }
+
+    #[test]
+    fn test_throttle_plans_cooldown_defers_everything() {
+        let policy = DevOpsPolicySpec {
+            max_remediations_per_cycle: Some(2),
+            remediation_cooldown_seconds: Some(120),
+            ..make_enforce_policy()
+        };
+        let during = throttle_plans(make_plans(&["a", "b"]), &policy, Some(at(0)), at(60));
+        assert!(during.apply.is_empty());
+        assert_eq!(during.deferred.len(), 2);
+
+        let after = throttle_plans(make_plans(&["a", "b"]), &policy, Some(at(0)), at(120));
+        assert_eq!(after.apply.len(), 2);
+        assert!(!in_remediation_cooldown(&policy, None, at(0)));
+    }
+
+    // ── pod eviction ──
+
+    fn make_crash_looping_pod(name: &str, owner: &str, restarts: i32) -> Pod {
+        let mut pod = make_pod_with_owner(
+            name,
+            "production",
+            "nginx:1.25",
+            "ReplicaSet",
+            &format!("{owner}-7d9f8b6c5d"),
+            true,
+            true,
+        );
+        pod.status
+            .as_mut()
+            .unwrap()
+            .container_statuses
+            .as_mut()
+            .unwrap()[0]
+            .restart_count = restarts;
+        pod
+    }
+
+    fn make_eviction_policy(max_per_cycle: u32) -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            evict_crash_looping: Some(true),
+            eviction: Some(crate::crd::EvictionConfig {
+                restart_threshold: Some(10),
+                cooldown_seconds: Some(300),
+                max_evictions_per_cycle: Some(max_per_cycle),
+            }),
+            ..make_enforce_policy()
+        }
+    }
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_eviction_settings_defaults() {
+        let settings = EvictionSettings::from_policy(&make_enforce_policy());
+        assert_eq!(
+            settings,
+            EvictionSettings {
+                restart_threshold: DEFAULT_EVICTION_RESTART_THRESHOLD,
+                cooldown_seconds: DEFAULT_EVICTION_COOLDOWN_SECONDS,
+                max_per_cycle: DEFAULT_MAX_EVICTIONS_PER_CYCLE,
+            }
+        );
+    }
+
+    #[test]
+    fn test_plan_evictions_disabled_by_default() {
+        let pods = vec![make_crash_looping_pod("web-1", "web", 50)];
+        let plan = plan_evictions(&pods, &make_enforce_policy(), &HashMap::new(), at(0));
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn test_plan_evictions_threshold_and_order() {
+        let pods = vec![
+            make_crash_looping_pod("web-1", "web", 12),
+            make_crash_looping_pod("api-1", "api", 40),
+            make_crash_looping_pod("db-1", "db", 10),
+        ];
+        let plan = plan_evictions(&pods, &make_eviction_policy(5), &HashMap::new(), at(0));
+        let names: Vec<&str> = plan.iter().map(|c| c.pod_name.as_str()).collect();
+        assert_eq!(names, vec!["api-1", "web-1"]);
+        assert_eq!(plan[0].workload.name, "api");
+        assert_eq!(plan[0].container, "main");
+        assert_eq!(plan[0].restarts, 40);
+    }
+
+    #[test]
+    fn test_plan_evictions_one_per_workload_and_cycle_limit() {
+        let pods = vec![
+            make_crash_looping_pod("web-1", "web", 12),
+            make_crash_looping_pod("web-2", "web", 30),
+            make_crash_looping_pod("api-1", "api", 20),
+        ];
+        let plan = plan_evictions(&pods, &make_eviction_policy(5), &HashMap::new(), at(0));
+        assert_eq!(plan.len(), 2);
+        assert_eq!(plan[0].pod_name, "web-2");
+
+        let limited = plan_evictions(&pods, &make_eviction_policy(1), &HashMap::new(), at(0));
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].pod_name, "web-2");
+    }
+
+    #[test]
+    fn test_plan_evictions_respects_cooldown() {
+        let pods = vec![make_crash_looping_pod("web-1", "web", 50)];
+        let policy = make_eviction_policy(1);
+        let last = HashMap::from([("deployment/production/web".to_string(), at(0))]);
+
+        assert!(plan_evictions(&pods, &policy, &last, at(299)).is_empty());
+        assert_eq!(plan_evictions(&pods, &policy, &last, at(300)).len(), 1);
+    }
+
+    #[test]
+    fn test_plan_evictions_skips_unowned_terminating_and_protected() {
+        let policy = make_eviction_policy(5);
+
+        let mut bare = make_crash_looping_pod("bare", "web", 50);
+        bare.metadata.owner_references = None;
+
+        let mut terminating = make_crash_looping_pod("term", "api", 50);
+        terminating.metadata.deletion_timestamp =
+            Some(k8s_openapi::apimachinery::pkg::apis::meta::v1::Time(at(0)));
+
+        let mut protected = make_crash_looping_pod("sys", "dns", 50);
+        protected.metadata.namespace = Some("kube-system".to_string());
+
+        let plan = plan_evictions(
+            &[bare, terminating, protected],
+            &policy,
+            &HashMap::new(),
+            at(0),
+        );
+        assert!(plan.is_empty());
+    }
+
+    #[test]
+    fn test_describe_eviction() {
+        let pods = vec![make_crash_looping_pod("web-1", "web", 50)];
+        let plan = plan_evictions(&pods, &make_eviction_policy(1), &HashMap::new(), at(0));
+        let described = describe_eviction(&plan[0]);
+        assert_eq!(described.workload, "Deployment/web");
+        assert_eq!(described.actions[0].action, "evictPod");
+        assert_eq!(described.actions[0].container, "main");
+    }
+
+    // ── patch diff ──
+
+    #[test]
+    fn test_diff_container_patch_reports_changed_leaves() {
+        let containers = vec![Container {
+            name: "main".to_string(),
+            resources: Some(ResourceRequirements {
+                requests: Some(BTreeMap::from([(
+                    "cpu".to_string(),
+                    Quantity("100m".to_string()),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let actions = vec![RemediationAction::InjectResources { container_index: 0 }];
+        let patch = build_container_patches(&actions, &containers, &DevOpsPolicySpec::default());
+
+        let changes = diff_container_patch(&patch, &containers);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "resources.limits.cpu",
+                "resources.limits.memory",
+                "resources.requests.memory"
+            ]
+        );
+        assert_eq!(
+            changes[0].describe(),
+            r#"main.resources.limits.cpu: null -> "500m""#
+        );
+    }
+
+    #[test]
+    fn test_diff_container_patch_injected_probe() {
+        let containers = vec![Container {
+            name: "main".to_string(),
+            ..Default::default()
+        }];
+        let actions = vec![RemediationAction::InjectLivenessProbe { container_index: 0 }];
+        let patch = build_container_patches(&actions, &containers, &DevOpsPolicySpec::default());
+
+        let changes = diff_container_patch(&patch, &containers);
+        assert!(!changes.is_empty());
+        assert!(changes.iter().all(|c| c.container == "main"
+            && c.path.starts_with("livenessProbe.")
+            && c.before.is_null()));
+        assert!(diff_container_patch(&serde_json::json!({}), &containers).is_empty());
+    }
+
+    // ── remediation backup ──
+
+    fn make_partial_container() -> Container {
+        Container {
+            name: "main".to_string(),
+            readiness_probe: Some(Probe {
+                period_seconds: Some(7),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_backup_captures_prior_fields() {
+        let containers = vec![make_partial_container()];
+        let actions = vec![
+            RemediationAction::InjectLivenessProbe { container_index: 0 },
+            RemediationAction::InjectResources { container_index: 0 },
+        ];
+        let backup = record_backup(None, &actions, &containers);
+
+        let fields = &backup.0["main"];
+        assert_eq!(fields.len(), 2);
+        assert!(fields["livenessProbe"].is_null());
+        assert!(fields["resources"].is_null());
+    }
+
+    #[test]
+    fn test_record_backup_keeps_original_values() {
+        let mut containers = vec![make_partial_container()];
+        let first = record_backup(
+            None,
+            &[RemediationAction::InjectLivenessProbe { container_index: 0 }],
+            &containers,
+        );
+
+        // Second remediation after the probe was injected must not record it
+        containers[0].liveness_probe = Some(Probe::default());
+        let second = record_backup(
+            Some(first),
+            &[
+                RemediationAction::InjectLivenessProbe { container_index: 0 },
+                RemediationAction::InjectResources { container_index: 0 },
+            ],
+            &containers,
+        );
+
+        assert!(second.0["main"]["livenessProbe"].is_null());
+        assert!(second.0["main"].contains_key("resources"));
+    }
+
+    #[test]
+    fn test_backup_annotation_roundtrip() {
+        let backup = record_backup(
+            None,
+            &[RemediationAction::InjectLivenessProbe { container_index: 0 }],
+            &[make_partial_container()],
+        );
+        let annotations = BTreeMap::from([(
+            REMEDIATION_BACKUP_ANNOTATION.to_string(),
+            backup.to_annotation_value(),
+        )]);
+        assert_eq!(
+            RemediationBackup::from_annotations(Some(&annotations)),
+            Some(backup)
+        );
+
+        let garbage = BTreeMap::from([(
+            REMEDIATION_BACKUP_ANNOTATION.to_string(),
+            "not json".to_string(),
+        )]);
+        assert_eq!(RemediationBackup::from_annotations(Some(&garbage)), None);
+        assert_eq!(RemediationBackup::from_annotations(None), None);
+    }
+
+    #[test]
+    fn test_restore_containers_reverts_injected_fields() {
+        let original = make_partial_container();
+        let actions = vec![
+            RemediationAction::InjectLivenessProbe { container_index: 0 },
+            RemediationAction::InjectResources { container_index: 0 },
+        ];
+        let backup = record_backup(None, &actions, std::slice::from_ref(&original));
+
+        let mut patched = original.clone();
+        patched.liveness_probe = Some(build_default_probe(
+            &patched,
+            make_enforce_policy().default_probe.as_ref().unwrap(),
+        ));
+        patched.resources = Some(build_default_resources(
+            make_enforce_policy().default_resources.as_ref().unwrap(),
+        ));
+
+        let restored = restore_containers(&[patched], &backup);
+        assert_eq!(restored, vec![original]);
+    }
+
+    #[test]
+    fn test_restore_containers_leaves_unrecorded_containers() {
+        let sidecar = Container {
+            name: "sidecar".to_string(),
+            liveness_probe: Some(Probe::default()),
+            ..Default::default()
+        };
+        let backup = record_backup(
+            None,
+            &[RemediationAction::InjectLivenessProbe { container_index: 0 }],
+            &[make_partial_container()],
+        );
+        let restored = restore_containers(std::slice::from_ref(&sidecar), &backup);
+        assert_eq!(restored, vec![sidecar]);
+    }
+
+    #[test]
+    fn test_rollback_patch_removes_annotations() {
+        let backup = record_backup(
+            None,
+            &[RemediationAction::InjectLivenessProbe { container_index: 0 }],
+            &[make_partial_container()],
+        );
+        let patch = build_rollback_patch(&[make_partial_container()], &backup);
+
+        assert!(patch["metadata"]["annotations"][REMEDIATION_BACKUP_ANNOTATION].is_null());
+        let template_annotations = &patch["spec"]["template"]["metadata"]["annotations"];
+        assert!(template_annotations[PATCHED_BY_ANNOTATION].is_null());
+        assert!(template_annotations[PATCHED_BY_POLICY_ANNOTATION].is_null());
+
+        let container = &patch["spec"]["template"]["spec"]["containers"][0];
+        assert_eq!(container["name"], "main");
+        assert!(container.get("livenessProbe").is_none());
+        assert_eq!(container["readinessProbe"]["periodSeconds"], 7);
+    }
+
     // ── WorkloadRef ──
 
     #[test]
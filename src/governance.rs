@@ -1,15 +1,64 @@
-use k8s_openapi::api::core::v1::Pod;
-
-use crate::crd::{DevOpsPolicySpec, Severity, SeverityOverrides};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{LazyLock, Mutex};
+
+use chrono::{DateTime, Utc};
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use k8s_openapi::api::core::v1::{Container, Pod, PodSpec, PodTemplateSpec, Probe};
+use k8s_openapi::api::policy::v1::PodDisruptionBudget;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use regex::Regex;
+use serde::Serialize;
+use tracing::warn;
+
+use crate::api;
+use crate::crd::{
+    AuditViolation, DevOpsPolicySpec, HealthThresholds, PolicyAuditResult, ScoreTrend, Severity,
+    SeverityOverrides,
+};
+use crate::enforcement;
+use crate::policy_resolution;
 
 /* ============================= WEIGHTS ============================= */
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct ScoringWeights {
     pub latest_tag: u32,
     pub missing_liveness: u32,
     pub missing_readiness: u32,
     pub high_restarts: u32,
     pub pending: u32,
+    pub missing_resources: u32,
+    pub crash_loop: u32,
+    pub image_pull_error: u32,
+    pub missing_metadata: u32,
+    pub missing_pdb: u32,
+    pub no_spread: u32,
+    pub unpinned_image: u32,
+    pub pull_policy: u32,
+    /// Per-check limits on how many points a check may deduct.
+    pub caps: ScoringCaps,
+}
+
+/// Maximum score points each check may deduct (`None` = uncapped).
+///
+/// Keeps one pathological check, such as thousands of batch pods without
+/// readiness probes, from flooring the score and hiding other regressions.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScoringCaps {
+    pub latest_tag: Option<u32>,
+    pub missing_liveness: Option<u32>,
+    pub missing_readiness: Option<u32>,
+    pub high_restarts: Option<u32>,
+    pub pending: Option<u32>,
+    pub missing_resources: Option<u32>,
+    pub crash_loop: Option<u32>,
+    pub image_pull_error: Option<u32>,
+    pub missing_metadata: Option<u32>,
+    pub missing_pdb: Option<u32>,
+    pub no_spread: Option<u32>,
+    pub unpinned_image: Option<u32>,
+    pub pull_policy: Option<u32>,
 }
 
 impl Default for ScoringWeights {
@@ -20,13 +69,103 @@ impl Default for ScoringWeights {
             missing_readiness: 2,
             high_restarts: 6,
             pending: 4,
+            missing_resources: 2,
+            crash_loop: 6,
+            image_pull_error: 5,
+            missing_metadata: 2,
+            missing_pdb: 3,
+            no_spread: 3,
+            unpinned_image: 3,
+            pull_policy: 2,
+            caps: ScoringCaps::default(),
+        }
+    }
+}
+
+impl ScoringWeights {
+    /// Built-in weights with the policy's `scoringWeights` applied.
+    pub fn for_policy(policy: &DevOpsPolicySpec) -> Self {
+        let mut weights = Self::default();
+        if let Some(o) = &policy.scoring_weights {
+            let set = |weight: &mut u32, value: Option<u32>| {
+                if let Some(value) = value {
+                    *weight = value;
+                }
+            };
+            set(&mut weights.latest_tag, o.latest_tag);
+            set(&mut weights.missing_liveness, o.missing_liveness);
+            set(&mut weights.missing_readiness, o.missing_readiness);
+            set(&mut weights.high_restarts, o.high_restarts);
+            set(&mut weights.pending, o.pending);
+            set(&mut weights.missing_resources, o.missing_resources);
+            set(&mut weights.crash_loop, o.crash_loop);
+            set(&mut weights.image_pull_error, o.image_pull_error);
+            set(&mut weights.missing_metadata, o.missing_metadata);
+            set(&mut weights.missing_pdb, o.missing_pdb);
+            set(&mut weights.no_spread, o.no_spread);
+            set(&mut weights.unpinned_image, o.unpinned_image);
+            set(&mut weights.pull_policy, o.pull_policy);
         }
+        weights
     }
 }
 
 /* ============================= METRICS ============================= */
 
-#[derive(Default, Clone)]
+/// Every violation type, in report order.
+pub const VIOLATION_TYPES: [&str; 13] = [
+    "latest_tag",
+    "missing_liveness",
+    "missing_readiness",
+    "high_restarts",
+    "pending",
+    "missing_resources",
+    "crash_loop",
+    "image_pull_error",
+    "missing_metadata",
+    "missing_pdb",
+    "no_spread",
+    "unpinned_image",
+    "pull_policy",
+];
+
+/// Violation types a policy checks, in report order.
+pub fn enabled_checks(policy: &DevOpsPolicySpec) -> Vec<&'static str> {
+    let enabled = |check: &str| match check {
+        "latest_tag" => {
+            policy.forbid_latest_tag.unwrap_or(false)
+                || policy
+                    .forbidden_tag_patterns
+                    .as_ref()
+                    .is_some_and(|p| !p.is_empty())
+                || policy.required_tag_pattern.is_some()
+        }
+        "missing_liveness" => policy.require_liveness_probe.unwrap_or(false),
+        "missing_readiness" => policy.require_readiness_probe.unwrap_or(false),
+        "high_restarts" => policy.max_restart_count.is_some(),
+        "pending" => policy.forbid_pending_duration.is_some(),
+        "missing_resources" => policy.require_resources.unwrap_or(false),
+        "crash_loop" => policy.forbid_crash_loop.unwrap_or(false),
+        "image_pull_error" => policy.forbid_image_pull_errors.unwrap_or(false),
+        "missing_metadata" => [&policy.required_labels, &policy.required_annotations]
+            .into_iter()
+            .any(|m| m.as_ref().is_some_and(|m| !m.is_empty())),
+        "missing_pdb" => policy.require_pod_disruption_budget.unwrap_or(false),
+        "no_spread" => policy.require_spread_constraints.unwrap_or(false),
+        "unpinned_image" => policy.require_image_digest.unwrap_or(false),
+        "pull_policy" => {
+            policy.forbid_image_pull_policy_always.unwrap_or(false)
+                || policy
+                    .require_image_pull_policy_if_not_present
+                    .unwrap_or(false)
+        }
+        _ => false,
+    };
+    VIOLATION_TYPES.into_iter().filter(|c| enabled(c)).collect()
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PodMetrics {
     pub total_pods: u32,
     pub latest_tag: u32,
@@ -34,6 +173,56 @@ pub struct PodMetrics {
     pub missing_readiness: u32,
     pub high_restarts: u32,
     pub pending: u32,
+    pub missing_resources: u32,
+    pub crash_loop: u32,
+    pub image_pull_error: u32,
+    pub missing_metadata: u32,
+    pub missing_pdb: u32,
+    pub no_spread: u32,
+    pub unpinned_image: u32,
+    pub pull_policy: u32,
+}
+
+impl PodMetrics {
+    /// Count one violation of `violation_type`; unknown types are ignored.
+    pub fn record(&mut self, violation_type: &str) {
+        match violation_type {
+            "latest_tag" => self.latest_tag += 1,
+            "missing_liveness" => self.missing_liveness += 1,
+            "missing_readiness" => self.missing_readiness += 1,
+            "high_restarts" => self.high_restarts += 1,
+            "pending" => self.pending += 1,
+            "missing_resources" => self.missing_resources += 1,
+            "crash_loop" => self.crash_loop += 1,
+            "image_pull_error" => self.image_pull_error += 1,
+            "missing_metadata" => self.missing_metadata += 1,
+            "missing_pdb" => self.missing_pdb += 1,
+            "no_spread" => self.no_spread += 1,
+            "unpinned_image" => self.unpinned_image += 1,
+            "pull_policy" => self.pull_policy += 1,
+            _ => {}
+        }
+    }
+
+    /// Violations of `violation_type` counted so far; 0 for unknown types.
+    pub fn count(&self, violation_type: &str) -> u32 {
+        match violation_type {
+            "latest_tag" => self.latest_tag,
+            "missing_liveness" => self.missing_liveness,
+            "missing_readiness" => self.missing_readiness,
+            "high_restarts" => self.high_restarts,
+            "pending" => self.pending,
+            "missing_resources" => self.missing_resources,
+            "crash_loop" => self.crash_loop,
+            "image_pull_error" => self.image_pull_error,
+            "missing_metadata" => self.missing_metadata,
+            "missing_pdb" => self.missing_pdb,
+            "no_spread" => self.no_spread,
+            "unpinned_image" => self.unpinned_image,
+            "pull_policy" => self.pull_policy,
+            _ => 0,
+        }
+    }
 }
 
 pub fn add_metrics(cluster: &mut PodMetrics, pod: &PodMetrics) {
@@ -43,6 +232,14 @@ pub fn add_metrics(cluster: &mut PodMetrics, pod: &PodMetrics) {
     cluster.missing_readiness += pod.missing_readiness;
     cluster.high_restarts += pod.high_restarts;
     cluster.pending += pod.pending;
+    cluster.missing_resources += pod.missing_resources;
+    cluster.crash_loop += pod.crash_loop;
+    cluster.image_pull_error += pod.image_pull_error;
+    cluster.missing_metadata += pod.missing_metadata;
+    cluster.missing_pdb += pod.missing_pdb;
+    cluster.no_spread += pod.no_spread;
+    cluster.unpinned_image += pod.unpinned_image;
+    cluster.pull_policy += pod.pull_policy;
 }
 
 pub fn subtract_metrics(cluster: &mut PodMetrics, pod: &PodMetrics) {
@@ -56,6 +253,35 @@ pub fn subtract_metrics(cluster: &mut PodMetrics, pod: &PodMetrics) {
         .saturating_sub(pod.missing_readiness);
     cluster.high_restarts = cluster.high_restarts.saturating_sub(pod.high_restarts);
     cluster.pending = cluster.pending.saturating_sub(pod.pending);
+    cluster.missing_resources = cluster
+        .missing_resources
+        .saturating_sub(pod.missing_resources);
+    cluster.crash_loop = cluster.crash_loop.saturating_sub(pod.crash_loop);
+    cluster.image_pull_error = cluster
+        .image_pull_error
+        .saturating_sub(pod.image_pull_error);
+    cluster.missing_metadata = cluster
+        .missing_metadata
+        .saturating_sub(pod.missing_metadata);
+    cluster.missing_pdb = cluster.missing_pdb.saturating_sub(pod.missing_pdb);
+    cluster.no_spread = cluster.no_spread.saturating_sub(pod.no_spread);
+    cluster.unpinned_image = cluster.unpinned_image.saturating_sub(pod.unpinned_image);
+    cluster.pull_policy = cluster.pull_policy.saturating_sub(pod.pull_policy);
+}
+
+/// Rebuild aggregate metrics from a recorded list of audit violations.
+///
+/// Each violation counts once toward its check; restart counts are not
+/// preserved in audit results, so `high_restarts` is one per container.
+pub fn metrics_from_violations(total_pods: u32, violations: &[AuditViolation]) -> PodMetrics {
+    let mut m = PodMetrics {
+        total_pods,
+        ..Default::default()
+    };
+    for v in violations {
+        m.record(&v.violation_type);
+    }
+    m
 }
 
 /* ============================= POD EVALUATION ============================= */
@@ -68,13 +294,14 @@ pub fn evaluate_pod(pod: &Pod) -> PodMetrics {
 
     if let Some(spec) = &pod.spec {
         for c in &spec.containers {
-            if c.image.as_deref().unwrap_or("").ends_with(":latest") {
+            let skipped = |check| is_check_skipped(pod, &c.name, check);
+            if c.image.as_deref().unwrap_or("").ends_with(":latest") && !skipped("latest_tag") {
                 m.latest_tag += 1;
             }
-            if c.liveness_probe.is_none() {
+            if c.liveness_probe.is_none() && !skipped("missing_liveness") {
                 m.missing_liveness += 1;
             }
-            if c.readiness_probe.is_none() {
+            if c.readiness_probe.is_none() && !skipped("missing_readiness") {
                 m.missing_readiness += 1;
             }
         }
@@ -83,7 +310,7 @@ pub fn evaluate_pod(pod: &Pod) -> PodMetrics {
     if let Some(status) = &pod.status {
         if let Some(container_statuses) = &status.container_statuses {
             for cs in container_statuses {
-                if cs.restart_count > 3 {
+                if cs.restart_count > 3 && !is_check_skipped(pod, &cs.name, "high_restarts") {
                     let capped = (cs.restart_count.max(0) as u32).min(5);
                     m.high_restarts += capped;
                 }
@@ -95,6 +322,10 @@ pub fn evaluate_pod(pod: &Pod) -> PodMetrics {
         }
     }
 
+    for failure in flagged_waiting_failures(pod, None) {
+        m.record(failure.violation_type);
+    }
+
     m
 }
 
@@ -103,21 +334,407 @@ pub fn detect_violations(pod: &Pod) -> Vec<&'static str> {
 
     if let Some(spec) = &pod.spec {
         for c in &spec.containers {
-            if c.image.as_deref().unwrap_or("").ends_with(":latest") {
+            let skipped = |check| is_check_skipped(pod, &c.name, check);
+            if c.image.as_deref().unwrap_or("").ends_with(":latest") && !skipped("latest_tag") {
                 violations.push("latest_tag");
             }
-            if c.liveness_probe.is_none() {
+            if c.liveness_probe.is_none() && !skipped("missing_liveness") {
                 violations.push("missing_liveness");
             }
-            if c.readiness_probe.is_none() {
+            if c.readiness_probe.is_none() && !skipped("missing_readiness") {
                 violations.push("missing_readiness");
             }
         }
     }
 
+    for failure in flagged_waiting_failures(pod, None) {
+        violations.push(failure.violation_type);
+    }
+
+    violations
+}
+
+/// [`detect_violations`] with structured detail, at default severities.
+pub fn detect_violations_detailed_builtin(pod: &Pod) -> Vec<ViolationDetail> {
+    let pod_name = pod
+        .metadata
+        .name
+        .clone()
+        .unwrap_or_else(|| "unknown".to_string());
+    let namespace = pod
+        .metadata
+        .namespace
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    let detail = |violation_type: &str, container: &str, message: String| ViolationDetail {
+        violation_type: violation_type.to_string(),
+        severity: default_severity(violation_type),
+        pod_name: pod_name.clone(),
+        namespace: namespace.clone(),
+        container_name: container.to_string(),
+        message,
+    };
+    let mut violations = Vec::new();
+
+    if let Some(spec) = &pod.spec {
+        for c in &spec.containers {
+            let skipped = |check| is_check_skipped(pod, &c.name, check);
+            if c.image.as_deref().unwrap_or("").ends_with(":latest") && !skipped("latest_tag") {
+                let message = format!("container '{}' uses the latest tag", c.name);
+                violations.push(detail("latest_tag", &c.name, message));
+            }
+            if c.liveness_probe.is_none() && !skipped("missing_liveness") {
+                let message = format!("container '{}' missing liveness probe", c.name);
+                violations.push(detail("missing_liveness", &c.name, message));
+            }
+            if c.readiness_probe.is_none() && !skipped("missing_readiness") {
+                let message = format!("container '{}' missing readiness probe", c.name);
+                violations.push(detail("missing_readiness", &c.name, message));
+            }
+        }
+    }
+
+    for failure in flagged_waiting_failures(pod, None) {
+        violations.push(detail(
+            failure.violation_type,
+            failure.container,
+            failure.message(),
+        ));
+    }
+
+    violations
+}
+
+/* ============================= CONTAINER EXEMPTIONS ============================= */
+
+/// Pod annotation prefix for per-container check exemptions.
+///
+/// `devops.stochastic.io/skip-checks.<container>: missing_readiness,latest_tag`
+/// exempts one container from the listed checks; `*` exempts it from all
+/// container-level checks. Pod-level checks (`pending`) cannot be skipped.
+pub const SKIP_CHECKS_ANNOTATION_PREFIX: &str = "devops.stochastic.io/skip-checks.";
+
+/// Whether `check` is exempted for `container` via a skip-checks annotation.
+pub fn is_check_skipped(pod: &Pod, container: &str, check: &str) -> bool {
+    let key = format!("{SKIP_CHECKS_ANNOTATION_PREFIX}{container}");
+    pod.metadata
+        .annotations
+        .as_ref()
+        .and_then(|a| a.get(&key))
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(str::trim)
+                .any(|c| c == "*" || c == check)
+        })
+}
+
+/// Whether a container declares any resource requests or limits.
+pub fn has_resources(container: &Container) -> bool {
+    container
+        .resources
+        .as_ref()
+        .is_some_and(|r| r.limits.is_some() || r.requests.is_some())
+}
+
+/* ============================= PROBES ============================= */
+
+/// Why a container's `kind` (`liveness` or `readiness`) probe fails the
+/// policy: it is missing or, with `requireConfiguredProbes`, it cannot
+/// detect anything (no handler) or acts on noise (`periodSeconds: 1`,
+/// `failureThreshold: 1`).
+///
+/// Whether the probe is required at all is up to the caller.
+pub fn probe_violation(
+    kind: &str,
+    probe: Option<&Probe>,
+    policy: &DevOpsPolicySpec,
+) -> Option<String> {
+    let Some(probe) = probe else {
+        return Some(format!("missing {kind} probe"));
+    };
+    if !policy.require_configured_probes.unwrap_or(false) {
+        return None;
+    }
+    let has_handler = probe.http_get.is_some()
+        || probe.tcp_socket.is_some()
+        || probe.exec.is_some()
+        || probe.grpc.is_some();
+    if !has_handler {
+        Some(format!(
+            "{kind} probe has no httpGet, tcpSocket, exec, or grpc handler"
+        ))
+    } else if probe.period_seconds.is_some_and(|p| p <= 1) {
+        Some(format!("{kind} probe runs every second (periodSeconds: 1)"))
+    } else if probe.failure_threshold == Some(1) {
+        Some(format!(
+            "{kind} probe acts on a single failure (failureThreshold: 1)"
+        ))
+    } else {
+        None
+    }
+}
+
+/* ============================= IMAGE TAGS ============================= */
+
+/// Tag of a container image reference.
+///
+/// Untagged images resolve to `latest`, as the runtime pulls them;
+/// digest-pinned images (`app@sha256:...`) have no tag to check.
+pub fn image_tag(image: &str) -> Option<&str> {
+    if image.contains('@') {
+        return None;
+    }
+    let name = image.rsplit('/').next().unwrap_or(image);
+    Some(name.split_once(':').map_or("latest", |(_, tag)| tag))
+}
+
+/// Why `image` fails the policy's tag rules (`forbidLatestTag`,
+/// `forbiddenTagPatterns`, `requiredTagPattern`), or `None` if it passes.
+///
+/// The reason completes "container 'x' ...". Invalid patterns are logged
+/// once and otherwise ignored.
+pub fn image_tag_violation(image: &str, policy: &DevOpsPolicySpec) -> Option<String> {
+    if policy.forbid_latest_tag.unwrap_or(false) && image.ends_with(":latest") {
+        return Some("uses :latest tag".to_string());
+    }
+    let tag = image_tag(image)?;
+    for pattern in policy.forbidden_tag_patterns.iter().flatten() {
+        if policy_pattern(pattern).is_some_and(|re| re.is_match(tag)) {
+            return Some(format!(
+                "image tag '{tag}' matches forbidden pattern '{pattern}'"
+            ));
+        }
+    }
+    if let Some(pattern) = &policy.required_tag_pattern
+        && policy_pattern(pattern).is_some_and(|re| !re.is_match(tag))
+    {
+        return Some(format!(
+            "image tag '{tag}' does not match required pattern '{pattern}'"
+        ));
+    }
+    None
+}
+
+/// Whether `image` is pinned to a `sha256` digest.
+pub fn is_digest_pinned(image: &str) -> bool {
+    image.rsplit_once("@sha256:").is_some_and(|(_, digest)| {
+        digest.len() == 64 && digest.bytes().all(|b| b.is_ascii_hexdigit())
+    })
+}
+
+/// Pull policy the kubelet applies: the container's own, or the API
+/// server's default (`Always` for `:latest` and untagged images,
+/// `IfNotPresent` otherwise) for templates that leave it unset.
+pub fn effective_pull_policy(container: &Container) -> &str {
+    if let Some(policy) = container.image_pull_policy.as_deref() {
+        return policy;
+    }
+    match image_tag(container.image.as_deref().unwrap_or("")) {
+        Some("latest") => "Always",
+        _ => "IfNotPresent",
+    }
+}
+
+/// Every way a container's image fails the policy's image rules, as
+/// `(violation type, reason)` pairs: the tag rules of [`image_tag_violation`]
+/// (`latest_tag`), `requireImageDigest` (`unpinned_image`), and the pull
+/// policy rules (`pull_policy`).
+pub fn image_violations(
+    container: &Container,
+    policy: &DevOpsPolicySpec,
+) -> Vec<(&'static str, String)> {
+    let image = container.image.as_deref().unwrap_or("");
+    let mut violations = Vec::new();
+    if let Some(reason) = image_tag_violation(image, policy) {
+        violations.push(("latest_tag", reason));
+    }
+    if policy.require_image_digest.unwrap_or(false) && !is_digest_pinned(image) {
+        violations.push((
+            "unpinned_image",
+            format!("image '{image}' is not pinned to a sha256 digest"),
+        ));
+    }
+    let pull_policy = effective_pull_policy(container);
+    if policy.forbid_image_pull_policy_always.unwrap_or(false) && pull_policy == "Always" {
+        violations.push(("pull_policy", "uses imagePullPolicy Always".to_string()));
+    } else if policy
+        .require_image_pull_policy_if_not_present
+        .unwrap_or(false)
+        && pull_policy != "IfNotPresent"
+    {
+        violations.push((
+            "pull_policy",
+            format!("uses imagePullPolicy {pull_policy}, IfNotPresent required"),
+        ));
+    }
     violations
 }
 
+/// Compiled policy regex (tag or metadata pattern), cached across
+/// evaluations; `None` if invalid.
+fn policy_pattern(pattern: &str) -> Option<Regex> {
+    static CACHE: LazyLock<Mutex<HashMap<String, Option<Regex>>>> = LazyLock::new(Default::default);
+    let mut cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    cache
+        .entry(pattern.to_string())
+        .or_insert_with(|| {
+            Regex::new(pattern)
+                .inspect_err(|e| warn!(pattern, error = %e, "invalid_policy_pattern"))
+                .ok()
+        })
+        .clone()
+}
+
+/* ============================= PENDING AGE ============================= */
+
+/// When the pod became Pending: its creation time, falling back to the
+/// kubelet's `startTime` and then the `PodScheduled` condition.
+pub fn pending_since(pod: &Pod) -> Option<DateTime<Utc>> {
+    if let Some(created) = &pod.metadata.creation_timestamp {
+        return Some(created.0);
+    }
+    let status = pod.status.as_ref()?;
+    status.start_time.as_ref().map(|t| t.0).or_else(|| {
+        status
+            .conditions
+            .iter()
+            .flatten()
+            .find(|c| c.type_ == "PodScheduled")
+            .and_then(|c| c.last_transition_time.as_ref())
+            .map(|t| t.0)
+    })
+}
+
+/// Why the pod violates `forbidPendingDuration` at `now`, or `None`.
+///
+/// A pod is flagged once it has been Pending longer than the limit. A pod
+/// without any timestamp cannot be aged and is flagged while Pending.
+pub fn pending_violation(
+    pod: &Pod,
+    policy: &DevOpsPolicySpec,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    let limit = policy.forbid_pending_duration?;
+    let status = pod.status.as_ref()?;
+    if status.phase.as_deref() != Some("Pending") {
+        return None;
+    }
+    let Some(since) = pending_since(pod) else {
+        return Some("pod is in Pending phase".to_string());
+    };
+    let age = now.signed_duration_since(since).num_seconds().max(0) as u64;
+    (age > limit).then(|| format!("pod has been Pending for {age}s (limit {limit}s)"))
+}
+
+/* ============================= REQUIRED METADATA ============================= */
+
+/// Why the pod's labels and annotations fail `requiredLabels` and
+/// `requiredAnnotations`, naming each offending key, or `None`.
+///
+/// A non-empty required value is a regular expression the whole value must
+/// match. Invalid patterns are logged once and otherwise ignored.
+pub fn metadata_violation(pod: &Pod, policy: &DevOpsPolicySpec) -> Option<String> {
+    let requirements = [
+        ("label", &policy.required_labels, &pod.metadata.labels),
+        (
+            "annotation",
+            &policy.required_annotations,
+            &pod.metadata.annotations,
+        ),
+    ];
+    let mut reasons = Vec::new();
+    for (kind, required, present) in requirements {
+        for (key, pattern) in required.iter().flatten() {
+            match present.as_ref().and_then(|m| m.get(key)) {
+                None => reasons.push(format!("missing required {kind} '{key}'")),
+                Some(value)
+                    if !pattern.is_empty()
+                        && policy_pattern(&format!("^(?:{pattern})$"))
+                            .is_some_and(|re| !re.is_match(value)) =>
+                {
+                    reasons.push(format!(
+                        "required {kind} '{key}' has value '{value}', expected '{pattern}'"
+                    ));
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    (!reasons.is_empty()).then(|| format!("pod {}", reasons.join(", ")))
+}
+
+/* ============================= CONTAINER STATE ============================= */
+
+/// A container waiting for a reason the state checks flag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaitingFailure<'a> {
+    pub container: &'a str,
+    /// `crash_loop` or `image_pull_error`.
+    pub violation_type: &'static str,
+    /// `state.waiting.reason`, e.g. `ImagePullBackOff`.
+    pub reason: &'a str,
+    pub image: &'a str,
+}
+
+impl WaitingFailure<'_> {
+    pub fn message(&self) -> String {
+        match self.violation_type {
+            "crash_loop" => format!("container '{}' is in {}", self.container, self.reason),
+            _ => format!(
+                "container '{}' cannot pull image '{}' ({})",
+                self.container, self.image, self.reason
+            ),
+        }
+    }
+}
+
+/// Containers, init containers included, waiting in `CrashLoopBackOff`
+/// (`crash_loop`) or `ImagePullBackOff`/`ErrImagePull` (`image_pull_error`).
+pub fn waiting_failures(pod: &Pod) -> Vec<WaitingFailure<'_>> {
+    let Some(status) = &pod.status else {
+        return Vec::new();
+    };
+    status
+        .init_container_statuses
+        .iter()
+        .chain(status.container_statuses.iter())
+        .flatten()
+        .filter_map(|cs| {
+            let reason = cs.state.as_ref()?.waiting.as_ref()?.reason.as_deref()?;
+            let violation_type = match reason {
+                "CrashLoopBackOff" => "crash_loop",
+                "ImagePullBackOff" | "ErrImagePull" => "image_pull_error",
+                _ => return None,
+            };
+            Some(WaitingFailure {
+                container: &cs.name,
+                violation_type,
+                reason,
+                image: &cs.image,
+            })
+        })
+        .collect()
+}
+
+/// Waiting failures the policy checks (all of them without a policy),
+/// minus containers exempted by skip-checks.
+fn flagged_waiting_failures<'a>(
+    pod: &'a Pod,
+    policy: Option<&DevOpsPolicySpec>,
+) -> Vec<WaitingFailure<'a>> {
+    waiting_failures(pod)
+        .into_iter()
+        .filter(|f| {
+            policy.is_none_or(|p| match f.violation_type {
+                "crash_loop" => p.forbid_crash_loop.unwrap_or(false),
+                _ => p.forbid_image_pull_errors.unwrap_or(false),
+            })
+        })
+        .filter(|f| !is_check_skipped(pod, f.container, f.violation_type))
+        .collect()
+}
+
 /* ============================= NAMESPACE FILTER ============================= */
 
 pub fn is_system_namespace(ns: &str) -> bool {
@@ -132,17 +749,109 @@ pub fn is_system_namespace(ns: &str) -> bool {
 /* ============================= SCORING ============================= */
 
 pub fn calculate_health_score(metrics: &PodMetrics) -> u32 {
+    calculate_health_score_with_weights(metrics, &ScoringWeights::default())
+}
+
+/// Calculate health score using custom per-check weights.
+pub fn calculate_health_score_with_weights(metrics: &PodMetrics, weights: &ScoringWeights) -> u32 {
+    weighted_score(metrics, weights, |_| 1)
+}
+
+/// Score `metrics`, scaling each check's penalty by `multiplier(check)` and
+/// limiting it to the check's cap.
+fn weighted_score(
+    metrics: &PodMetrics,
+    weights: &ScoringWeights,
+    multiplier: impl Fn(&str) -> u32,
+) -> u32 {
     if metrics.total_pods == 0 {
         return 100;
     }
 
-    let weights = ScoringWeights::default();
-
-    let raw = (metrics.latest_tag * weights.latest_tag)
-        + (metrics.missing_liveness * weights.missing_liveness)
-        + (metrics.missing_readiness * weights.missing_readiness)
-        + (metrics.high_restarts * weights.high_restarts)
-        + (metrics.pending * weights.pending);
+    let caps = &weights.caps;
+    let checks = [
+        (
+            "latest_tag",
+            metrics.latest_tag,
+            weights.latest_tag,
+            caps.latest_tag,
+        ),
+        (
+            "missing_liveness",
+            metrics.missing_liveness,
+            weights.missing_liveness,
+            caps.missing_liveness,
+        ),
+        (
+            "missing_readiness",
+            metrics.missing_readiness,
+            weights.missing_readiness,
+            caps.missing_readiness,
+        ),
+        (
+            "high_restarts",
+            metrics.high_restarts,
+            weights.high_restarts,
+            caps.high_restarts,
+        ),
+        ("pending", metrics.pending, weights.pending, caps.pending),
+        (
+            "missing_resources",
+            metrics.missing_resources,
+            weights.missing_resources,
+            caps.missing_resources,
+        ),
+        (
+            "crash_loop",
+            metrics.crash_loop,
+            weights.crash_loop,
+            caps.crash_loop,
+        ),
+        (
+            "image_pull_error",
+            metrics.image_pull_error,
+            weights.image_pull_error,
+            caps.image_pull_error,
+        ),
+        (
+            "missing_metadata",
+            metrics.missing_metadata,
+            weights.missing_metadata,
+            caps.missing_metadata,
+        ),
+        (
+            "missing_pdb",
+            metrics.missing_pdb,
+            weights.missing_pdb,
+            caps.missing_pdb,
+        ),
+        (
+            "no_spread",
+            metrics.no_spread,
+            weights.no_spread,
+            caps.no_spread,
+        ),
+        (
+            "unpinned_image",
+            metrics.unpinned_image,
+            weights.unpinned_image,
+            caps.unpinned_image,
+        ),
+        (
+            "pull_policy",
+            metrics.pull_policy,
+            weights.pull_policy,
+            caps.pull_policy,
+        ),
+    ];
+    let raw: u32 = checks
+        .into_iter()
+        .map(|(check, count, weight, cap)| {
+            let penalty = count * weight * multiplier(check);
+            // Caps are in score points, i.e. per pod
+            cap.map_or(penalty, |cap| penalty.min(cap * metrics.total_pods))
+        })
+        .sum();
 
     let per_pod = raw / metrics.total_pods;
     let capped = per_pod.min(100);
@@ -150,15 +859,108 @@ pub fn calculate_health_score(metrics: &PodMetrics) -> u32 {
     100 - capped
 }
 
+/// Classification with the default cut-offs (80, 60, 40).
 pub fn classify_health(score: u32) -> &'static str {
-    match score {
-        80..=100 => "Healthy",
-        60..=79 => "Stable",
-        40..=59 => "Degraded",
-        _ => "Critical",
+    ClassificationThresholds::default().classify(score)
+}
+
+/// Lowest score of each health classification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClassificationThresholds {
+    pub healthy: u32,
+    pub stable: u32,
+    pub degraded: u32,
+}
+
+impl Default for ClassificationThresholds {
+    fn default() -> Self {
+        Self {
+            healthy: api::HEALTHY_SCORE_THRESHOLD,
+            stable: 60,
+            degraded: 40,
+        }
+    }
+}
+
+impl ClassificationThresholds {
+    /// Default cut-offs with the policy's `healthThresholds` applied.
+    pub fn for_policy(policy: &DevOpsPolicySpec) -> Self {
+        let defaults = Self::default();
+        let Some(o) = &policy.health_thresholds else {
+            return defaults;
+        };
+        Self {
+            healthy: o.healthy.unwrap_or(defaults.healthy),
+            stable: o.stable.unwrap_or(defaults.stable),
+            degraded: o.degraded.unwrap_or(defaults.degraded),
+        }
+    }
+
+    pub fn classify(&self, score: u32) -> &'static str {
+        if score >= self.healthy {
+            api::CLASSIFICATION_HEALTHY
+        } else if score >= self.stable {
+            api::CLASSIFICATION_STABLE
+        } else if score >= self.degraded {
+            api::CLASSIFICATION_DEGRADED
+        } else {
+            api::CLASSIFICATION_CRITICAL
+        }
+    }
+
+    /// Whether the cut-offs descend strictly from `healthy` to `degraded`.
+    pub fn is_ordered(&self) -> bool {
+        self.healthy > self.stable && self.stable > self.degraded
+    }
+}
+
+impl From<ClassificationThresholds> for HealthThresholds {
+    fn from(t: ClassificationThresholds) -> Self {
+        Self {
+            healthy: Some(t.healthy),
+            stable: Some(t.stable),
+            degraded: Some(t.degraded),
+        }
     }
 }
 
+/* ============================= SCORE TREND ============================= */
+
+/// Number of recent scores averaged as the trend baseline.
+pub const TREND_WINDOW: usize = 5;
+
+/// Scores within this many points of the baseline count as stable.
+pub const TREND_TOLERANCE: u32 = 2;
+
+/// Health scores of `policy_name`'s audit results, oldest first.
+pub fn score_history(results: &[PolicyAuditResult], policy_name: &str) -> Vec<u32> {
+    let mut history: Vec<&PolicyAuditResult> = results
+        .iter()
+        .filter(|r| r.spec.policy_name == policy_name)
+        .collect();
+    // Timestamps are RFC 3339 in UTC, so they order lexicographically
+    history.sort_by(|a, b| a.spec.timestamp.cmp(&b.spec.timestamp));
+    history.iter().map(|r| r.spec.health_score).collect()
+}
+
+/// Compare `current` with the mean of the last [`TREND_WINDOW`] scores in
+/// `history` (oldest first). `None` without history.
+pub fn score_trend(history: &[u32], current: u32) -> Option<ScoreTrend> {
+    let recent = &history[history.len().saturating_sub(TREND_WINDOW)..];
+    if recent.is_empty() {
+        return None;
+    }
+    let baseline = recent.iter().sum::<u32>() as f64 / recent.len() as f64;
+    let delta = current as f64 - baseline;
+    Some(if delta > TREND_TOLERANCE as f64 {
+        ScoreTrend::Improving
+    } else if delta < -(TREND_TOLERANCE as f64) {
+        ScoreTrend::Degrading
+    } else {
+        ScoreTrend::Stable
+    })
+}
+
 /* ============================= POLICY-AWARE EVALUATION ============================= */
 
 /// Evaluate a pod against a specific DevOpsPolicy.
@@ -166,6 +968,16 @@ pub fn classify_health(score: u32) -> &'static str {
 /// Only checks that the policy explicitly enables are counted.
 /// Omitted fields (`None`) are treated as disabled (not checked).
 pub fn evaluate_pod_with_policy(pod: &Pod, policy: &DevOpsPolicySpec) -> PodMetrics {
+    evaluate_pod_with_policy_at(pod, policy, Utc::now())
+}
+
+/// [`evaluate_pod_with_policy`], aging Pending pods against `now`.
+pub fn evaluate_pod_with_policy_at(
+    pod: &Pod,
+    policy: &DevOpsPolicySpec,
+    now: DateTime<Utc>,
+) -> PodMetrics {
+    let policy = &*policy_resolution::apply_workload_overrides(policy, pod);
     let mut m = PodMetrics {
         total_pods: 1,
         ..Default::default()
@@ -175,17 +987,30 @@ pub fn evaluate_pod_with_policy(pod: &Pod, policy: &DevOpsPolicySpec) -> PodMetr
 
     if let Some(spec) = &pod.spec {
         for c in &spec.containers {
-            if policy.forbid_latest_tag.unwrap_or(false)
-                && c.image.as_deref().unwrap_or("").ends_with(":latest")
-            {
-                m.latest_tag += 1;
+            let skipped = |check| is_check_skipped(pod, &c.name, check);
+            for (check, _) in image_violations(c, policy) {
+                if !skipped(check) {
+                    m.record(check);
+                }
             }
-            if policy.require_liveness_probe.unwrap_or(false) && c.liveness_probe.is_none() {
+            if policy.require_liveness_probe.unwrap_or(false)
+                && probe_violation("liveness", c.liveness_probe.as_ref(), policy).is_some()
+                && !skipped("missing_liveness")
+            {
                 m.missing_liveness += 1;
             }
-            if policy.require_readiness_probe.unwrap_or(false) && c.readiness_probe.is_none() {
+            if policy.require_readiness_probe.unwrap_or(false)
+                && probe_violation("readiness", c.readiness_probe.as_ref(), policy).is_some()
+                && !skipped("missing_readiness")
+            {
                 m.missing_readiness += 1;
             }
+            if policy.require_resources.unwrap_or(false)
+                && !has_resources(c)
+                && !skipped("missing_resources")
+            {
+                m.missing_resources += 1;
+            }
         }
     }
 
@@ -194,25 +1019,36 @@ pub fn evaluate_pod_with_policy(pod: &Pod, policy: &DevOpsPolicySpec) -> PodMetr
             && let Some(container_statuses) = &status.container_statuses
         {
             for cs in container_statuses {
-                if cs.restart_count > restart_threshold {
+                if cs.restart_count > restart_threshold
+                    && !is_check_skipped(pod, &cs.name, "high_restarts")
+                {
                     let capped = (cs.restart_count.max(0) as u32).min(5);
                     m.high_restarts += capped;
                 }
             }
         }
 
-        if policy.forbid_pending_duration.is_some() && status.phase.as_deref() == Some("Pending") {
+        if pending_violation(pod, policy, now).is_some() {
             m.pending += 1;
         }
     }
 
+    for failure in flagged_waiting_failures(pod, Some(policy)) {
+        m.record(failure.violation_type);
+    }
+
+    if metadata_violation(pod, policy).is_some() {
+        m.missing_metadata += 1;
+    }
+
     m
 }
 
 /* ============================= SEVERITY-AWARE SCORING ============================= */
 
 /// Detailed violation with severity, pod name, and container info.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ViolationDetail {
     pub violation_type: String,
     pub severity: Severity,
@@ -230,10 +1066,56 @@ pub fn default_severity(violation_type: &str) -> Severity {
         "missing_readiness" => Severity::Low,
         "high_restarts" => Severity::Critical,
         "pending" => Severity::Medium,
+        "missing_resources" => Severity::Medium,
+        "crash_loop" => Severity::Critical,
+        "image_pull_error" => Severity::High,
+        "missing_metadata" => Severity::Medium,
+        "missing_pdb" => Severity::Medium,
+        "no_spread" => Severity::Medium,
+        "unpinned_image" => Severity::Medium,
+        "pull_policy" => Severity::Low,
         _ => Severity::Medium,
     }
 }
 
+/// How to fix a violation type, in one sentence for reports.
+pub fn remediation_hint(violation_type: &str) -> &'static str {
+    match violation_type {
+        "latest_tag" => "Pin the image to an immutable version tag or digest instead of :latest.",
+        "missing_liveness" => "Add a livenessProbe so the kubelet restarts containers that hang.",
+        "missing_readiness" => "Add a readinessProbe so Services only route traffic to ready pods.",
+        "high_restarts" => {
+            "Read the previous container's logs (kubectl logs --previous) and fix the cause of the restarts."
+        }
+        "pending" => {
+            "Check the pod's events for scheduling failures: insufficient resources, node selectors, taints, or unbound volumes."
+        }
+        "missing_resources" => "Set CPU and memory requests and limits on every container.",
+        "crash_loop" => {
+            "Inspect the exit code and kubectl logs --previous; fix the crash or the container command."
+        }
+        "image_pull_error" => {
+            "Check that the image and tag exist and that imagePullSecrets grant access to the registry."
+        }
+        "missing_metadata" => {
+            "Add the required labels and annotations to the workload's pod template metadata."
+        }
+        "missing_pdb" => {
+            "Create a PodDisruptionBudget selecting the workload's pods so drains and upgrades keep replicas available."
+        }
+        "no_spread" => {
+            "Add topologySpreadConstraints or pod anti-affinity so replicas land on different nodes or zones."
+        }
+        "unpinned_image" => {
+            "Reference the image by digest (image@sha256:...) so every node runs the same build."
+        }
+        "pull_policy" => {
+            "Set imagePullPolicy to IfNotPresent so pods start from the node's cached image."
+        }
+        _ => "See the violation message for details.",
+    }
+}
+
 /// Return the scoring multiplier for a severity level.
 pub fn severity_multiplier(severity: &Severity) -> u32 {
     match severity {
@@ -253,6 +1135,14 @@ pub fn effective_severity(violation_type: &str, overrides: Option<&SeverityOverr
             "missing_readiness" => &ovr.missing_readiness,
             "high_restarts" => &ovr.high_restarts,
             "pending" => &ovr.pending,
+            "missing_resources" => &ovr.missing_resources,
+            "crash_loop" => &ovr.crash_loop,
+            "image_pull_error" => &ovr.image_pull_error,
+            "missing_metadata" => &ovr.missing_metadata,
+            "missing_pdb" => &ovr.missing_pdb,
+            "no_spread" => &ovr.no_spread,
+            "unpinned_image" => &ovr.unpinned_image,
+            "pull_policy" => &ovr.pull_policy,
             _ => &None,
         };
         if let Some(s) = specific {
@@ -267,36 +1157,32 @@ pub fn calculate_health_score_with_severity(
     metrics: &PodMetrics,
     overrides: Option<&SeverityOverrides>,
 ) -> u32 {
-    if metrics.total_pods == 0 {
-        return 100;
-    }
-
-    let weights = ScoringWeights::default();
-
-    let raw = (metrics.latest_tag
-        * weights.latest_tag
-        * severity_multiplier(&effective_severity("latest_tag", overrides)))
-        + (metrics.missing_liveness
-            * weights.missing_liveness
-            * severity_multiplier(&effective_severity("missing_liveness", overrides)))
-        + (metrics.missing_readiness
-            * weights.missing_readiness
-            * severity_multiplier(&effective_severity("missing_readiness", overrides)))
-        + (metrics.high_restarts
-            * weights.high_restarts
-            * severity_multiplier(&effective_severity("high_restarts", overrides)))
-        + (metrics.pending
-            * weights.pending
-            * severity_multiplier(&effective_severity("pending", overrides)));
-
-    let per_pod = raw / metrics.total_pods;
-    let capped = per_pod.min(100);
+    calculate_health_score_with_weights_and_severity(metrics, &ScoringWeights::default(), overrides)
+}
 
-    100 - capped
+/// Calculate health score with custom weights and severity multipliers.
+pub fn calculate_health_score_with_weights_and_severity(
+    metrics: &PodMetrics,
+    weights: &ScoringWeights,
+    overrides: Option<&SeverityOverrides>,
+) -> u32 {
+    weighted_score(metrics, weights, |check| {
+        severity_multiplier(&effective_severity(check, overrides))
+    })
 }
 
 /// Detect policy violations with full structured detail.
 pub fn detect_violations_detailed(pod: &Pod, policy: &DevOpsPolicySpec) -> Vec<ViolationDetail> {
+    detect_violations_detailed_at(pod, policy, Utc::now())
+}
+
+/// [`detect_violations_detailed`], aging Pending pods against `now`.
+pub fn detect_violations_detailed_at(
+    pod: &Pod,
+    policy: &DevOpsPolicySpec,
+    now: DateTime<Utc>,
+) -> Vec<ViolationDetail> {
+    let policy = &*policy_resolution::apply_workload_overrides(policy, pod);
     let mut violations = Vec::new();
 
     let pod_name = pod
@@ -317,36 +1203,58 @@ pub fn detect_violations_detailed(pod: &Pod, policy: &DevOpsPolicySpec) -> Vec<V
 
     if let Some(spec) = &pod.spec {
         for c in &spec.containers {
-            if policy.forbid_latest_tag.unwrap_or(false)
-                && c.image.as_deref().unwrap_or("").ends_with(":latest")
-            {
+            let skipped = |check| is_check_skipped(pod, &c.name, check);
+            for (check, reason) in image_violations(c, policy) {
+                if skipped(check) {
+                    continue;
+                }
                 violations.push(ViolationDetail {
-                    violation_type: "latest_tag".to_string(),
-                    severity: effective_severity("latest_tag", overrides),
+                    violation_type: check.to_string(),
+                    severity: effective_severity(check, overrides),
                     pod_name: pod_name.clone(),
                     namespace: namespace.clone(),
                     container_name: c.name.clone(),
-                    message: format!("container '{}' uses :latest tag", c.name),
+                    message: format!("container '{}' {reason}", c.name),
                 });
             }
-            if policy.require_liveness_probe.unwrap_or(false) && c.liveness_probe.is_none() {
+            if policy.require_liveness_probe.unwrap_or(false)
+                && !skipped("missing_liveness")
+                && let Some(reason) = probe_violation("liveness", c.liveness_probe.as_ref(), policy)
+            {
                 violations.push(ViolationDetail {
                     violation_type: "missing_liveness".to_string(),
                     severity: effective_severity("missing_liveness", overrides),
                     pod_name: pod_name.clone(),
                     namespace: namespace.clone(),
                     container_name: c.name.clone(),
-                    message: format!("container '{}' missing liveness probe", c.name),
+                    message: format!("container '{}' {reason}", c.name),
                 });
             }
-            if policy.require_readiness_probe.unwrap_or(false) && c.readiness_probe.is_none() {
+            if policy.require_readiness_probe.unwrap_or(false)
+                && !skipped("missing_readiness")
+                && let Some(reason) =
+                    probe_violation("readiness", c.readiness_probe.as_ref(), policy)
+            {
                 violations.push(ViolationDetail {
                     violation_type: "missing_readiness".to_string(),
                     severity: effective_severity("missing_readiness", overrides),
                     pod_name: pod_name.clone(),
                     namespace: namespace.clone(),
                     container_name: c.name.clone(),
-                    message: format!("container '{}' missing readiness probe", c.name),
+                    message: format!("container '{}' {reason}", c.name),
+                });
+            }
+            if policy.require_resources.unwrap_or(false)
+                && !has_resources(c)
+                && !skipped("missing_resources")
+            {
+                violations.push(ViolationDetail {
+                    violation_type: "missing_resources".to_string(),
+                    severity: effective_severity("missing_resources", overrides),
+                    pod_name: pod_name.clone(),
+                    namespace: namespace.clone(),
+                    container_name: c.name.clone(),
+                    message: format!("container '{}' missing resource requests/limits", c.name),
                 });
             }
         }
@@ -357,7 +1265,9 @@ pub fn detect_violations_detailed(pod: &Pod, policy: &DevOpsPolicySpec) -> Vec<V
             && let Some(container_statuses) = &status.container_statuses
         {
             for cs in container_statuses {
-                if cs.restart_count > restart_threshold {
+                if cs.restart_count > restart_threshold
+                    && !is_check_skipped(pod, &cs.name, "high_restarts")
+                {
                     violations.push(ViolationDetail {
                         violation_type: "high_restarts".to_string(),
                         severity: effective_severity("high_restarts", overrides),
@@ -373,18 +1283,40 @@ pub fn detect_violations_detailed(pod: &Pod, policy: &DevOpsPolicySpec) -> Vec<V
             }
         }
 
-        if policy.forbid_pending_duration.is_some() && status.phase.as_deref() == Some("Pending") {
+        if let Some(message) = pending_violation(pod, policy, now) {
             violations.push(ViolationDetail {
                 violation_type: "pending".to_string(),
                 severity: effective_severity("pending", overrides),
                 pod_name: pod_name.clone(),
                 namespace: namespace.clone(),
                 container_name: String::new(),
-                message: "pod is in Pending phase".to_string(),
+                message,
             });
         }
     }
 
+    for failure in flagged_waiting_failures(pod, Some(policy)) {
+        violations.push(ViolationDetail {
+            violation_type: failure.violation_type.to_string(),
+            severity: effective_severity(failure.violation_type, overrides),
+            pod_name: pod_name.clone(),
+            namespace: namespace.clone(),
+            container_name: failure.container.to_string(),
+            message: failure.message(),
+        });
+    }
+
+    if let Some(message) = metadata_violation(pod, policy) {
+        violations.push(ViolationDetail {
+            violation_type: "missing_metadata".to_string(),
+            severity: effective_severity("missing_metadata", overrides),
+            pod_name: pod_name.clone(),
+            namespace: namespace.clone(),
+            container_name: String::new(),
+            message,
+        });
+    }
+
     violations
 }
 
@@ -394,23 +1326,46 @@ pub fn detect_violations_detailed(pod: &Pod, policy: &DevOpsPolicySpec) -> Vec<V
 ///
 /// Returns a list of violation labels only for checks the policy has turned on.
 pub fn detect_violations_with_policy(pod: &Pod, policy: &DevOpsPolicySpec) -> Vec<&'static str> {
+    detect_violations_with_policy_at(pod, policy, Utc::now())
+}
+
+/// [`detect_violations_with_policy`], aging Pending pods against `now`.
+pub fn detect_violations_with_policy_at(
+    pod: &Pod,
+    policy: &DevOpsPolicySpec,
+    now: DateTime<Utc>,
+) -> Vec<&'static str> {
+    let policy = &*policy_resolution::apply_workload_overrides(policy, pod);
     let mut violations = Vec::new();
 
     let restart_threshold = policy.max_restart_count.unwrap_or(i32::MAX);
 
     if let Some(spec) = &pod.spec {
         for c in &spec.containers {
-            if policy.forbid_latest_tag.unwrap_or(false)
-                && c.image.as_deref().unwrap_or("").ends_with(":latest")
-            {
-                violations.push("latest_tag");
+            let skipped = |check| is_check_skipped(pod, &c.name, check);
+            for (check, _) in image_violations(c, policy) {
+                if !skipped(check) {
+                    violations.push(check);
+                }
             }
-            if policy.require_liveness_probe.unwrap_or(false) && c.liveness_probe.is_none() {
+            if policy.require_liveness_probe.unwrap_or(false)
+                && probe_violation("liveness", c.liveness_probe.as_ref(), policy).is_some()
+                && !skipped("missing_liveness")
+            {
                 violations.push("missing_liveness");
             }
-            if policy.require_readiness_probe.unwrap_or(false) && c.readiness_probe.is_none() {
+            if policy.require_readiness_probe.unwrap_or(false)
+                && probe_violation("readiness", c.readiness_probe.as_ref(), policy).is_some()
+                && !skipped("missing_readiness")
+            {
                 violations.push("missing_readiness");
             }
+            if policy.require_resources.unwrap_or(false)
+                && !has_resources(c)
+                && !skipped("missing_resources")
+            {
+                violations.push("missing_resources");
+            }
         }
     }
 
@@ -419,52 +1374,450 @@ pub fn detect_violations_with_policy(pod: &Pod, policy: &DevOpsPolicySpec) -> Ve
             && let Some(container_statuses) = &status.container_statuses
         {
             for cs in container_statuses {
-                if cs.restart_count > restart_threshold {
+                if cs.restart_count > restart_threshold
+                    && !is_check_skipped(pod, &cs.name, "high_restarts")
+                {
                     violations.push("high_restarts");
                 }
             }
         }
 
-        if policy.forbid_pending_duration.is_some() && status.phase.as_deref() == Some("Pending") {
+        if pending_violation(pod, policy, now).is_some() {
             violations.push("pending");
         }
     }
 
+    for failure in flagged_waiting_failures(pod, Some(policy)) {
+        violations.push(failure.violation_type);
+    }
+
+    if metadata_violation(pod, policy).is_some() {
+        violations.push("missing_metadata");
+    }
+
     violations
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use k8s_openapi::api::core::v1::{Container, ContainerStatus, Pod, PodSpec, PodStatus, Probe};
-    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+/* ============================= WORKLOAD EVALUATION ============================= */
 
-    fn make_test_pod(
-        name: &str,
-        namespace: &str,
-        image: &str,
-        has_liveness: bool,
-        has_readiness: bool,
-        restart_count: i32,
-        phase: &str,
-    ) -> Pod {
-        let probes =
-            |has: bool| -> Option<Probe> { if has { Some(Probe::default()) } else { None } };
+/// Governance result for one workload, with all of its replicas evaluated together.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadEvaluation {
+    /// `Kind/name` of the owning workload, or `Pod/<name>` for bare pods.
+    pub workload: String,
+    pub namespace: String,
+    /// Number of replicas (pods) observed for this workload.
+    pub replicas: u32,
+    /// Metrics for the workload as a single unit (`total_pods == 1`).
+    pub metrics: PodMetrics,
+    /// Violations, reported once per workload and container.
+    pub violations: Vec<ViolationDetail>,
+}
 
-        Pod {
-            metadata: ObjectMeta {
-                name: Some(name.to_string()),
-                namespace: Some(namespace.to_string()),
-                ..Default::default()
-            },
-            spec: Some(PodSpec {
-                containers: vec![Container {
-                    name: "main".to_string(),
-                    image: Some(image.to_string()),
-                    liveness_probe: probes(has_liveness),
-                    readiness_probe: probes(has_readiness),
-                    ..Default::default()
-                }],
+/// Label identifying the workload a pod belongs to (`Deployment/web`, `Job/migrate`).
+pub fn workload_label(pod: &Pod) -> String {
+    if let Some(owner) = enforcement::resolve_owner(pod) {
+        return format!("{}/{}", owner.kind, owner.name);
+    }
+    let job_owner = pod
+        .metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .find(|o| o.kind == "Job");
+    match job_owner {
+        Some(job) => format!("Job/{}", job.name),
+        None => format!("Pod/{}", pod.metadata.name.as_deref().unwrap_or("unknown")),
+    }
+}
+
+/// Evaluate a workload's pod template once against a policy.
+///
+/// Templates carry no runtime status, so only static checks (image tag,
+/// probes) can fire. Works for any Deployment, StatefulSet, DaemonSet,
+/// Job, or CronJob template.
+pub fn evaluate_workload(template: &PodTemplateSpec, policy: &DevOpsPolicySpec) -> PodMetrics {
+    evaluate_pod_with_policy(&pod_from_template(template), policy)
+}
+
+/// Detect policy violations in a workload's pod template.
+pub fn detect_workload_violations(
+    name: &str,
+    namespace: &str,
+    template: &PodTemplateSpec,
+    policy: &DevOpsPolicySpec,
+) -> Vec<ViolationDetail> {
+    let mut pod = pod_from_template(template);
+    pod.metadata.name = Some(name.to_string());
+    pod.metadata.namespace = Some(namespace.to_string());
+    detect_violations_detailed(&pod, policy)
+}
+
+fn pod_from_template(template: &PodTemplateSpec) -> Pod {
+    Pod {
+        metadata: template.metadata.clone().unwrap_or_default(),
+        spec: template.spec.clone(),
+        status: None,
+    }
+}
+
+/// Group pods by owning workload and evaluate each workload once.
+///
+/// A violation present on every replica counts once instead of once per
+/// replica. Pods in system namespaces are skipped. Results are ordered by
+/// namespace, then workload.
+pub fn evaluate_workloads(pods: &[Pod], policy: &DevOpsPolicySpec) -> Vec<WorkloadEvaluation> {
+    evaluate_workloads_with_jobs(pods, &[], policy, Utc::now())
+}
+
+/// [`evaluate_workloads`], grouping pods of the Jobs in `jobs` that a CronJob
+/// created under `CronJob/<name>`, the label [`evaluate_batch_workloads`]
+/// gives the CronJob's template, so every run of a CronJob is one workload.
+/// Pending pods are aged against `now`.
+pub fn evaluate_workloads_with_jobs(
+    pods: &[Pod],
+    jobs: &[Job],
+    policy: &DevOpsPolicySpec,
+    now: DateTime<Utc>,
+) -> Vec<WorkloadEvaluation> {
+    let cron_job_children: HashMap<(&str, &str), &str> = jobs
+        .iter()
+        .filter_map(|j| {
+            let cron_job = j
+                .metadata
+                .owner_references
+                .iter()
+                .flatten()
+                .find(|o| o.kind == "CronJob")?;
+            let ns = j.metadata.namespace.as_deref().unwrap_or_default();
+            Some(((ns, j.metadata.name.as_deref()?), cron_job.name.as_str()))
+        })
+        .collect();
+
+    let mut groups: BTreeMap<(String, String), Vec<&Pod>> = BTreeMap::new();
+    for pod in pods {
+        let ns = pod.metadata.namespace.as_deref().unwrap_or_default();
+        if is_system_namespace(ns) {
+            continue;
+        }
+        let mut label = workload_label(pod);
+        if let Some(cron_job) = label
+            .strip_prefix("Job/")
+            .and_then(|job| cron_job_children.get(&(ns, job)))
+        {
+            label = format!("CronJob/{cron_job}");
+        }
+        groups.entry((ns.to_string(), label)).or_default().push(pod);
+    }
+
+    groups
+        .into_iter()
+        .map(|((namespace, workload), replicas)| {
+            let mut metrics = PodMetrics {
+                total_pods: 1,
+                ..Default::default()
+            };
+            let mut violations = Vec::new();
+            let mut seen = HashSet::new();
+
+            for pod in &replicas {
+                let m = evaluate_pod_with_policy_at(pod, policy, now);
+                metrics.latest_tag = metrics.latest_tag.max(m.latest_tag);
+                metrics.missing_liveness = metrics.missing_liveness.max(m.missing_liveness);
+                metrics.missing_readiness = metrics.missing_readiness.max(m.missing_readiness);
+                metrics.high_restarts = metrics.high_restarts.max(m.high_restarts);
+                metrics.pending = metrics.pending.max(m.pending);
+                metrics.missing_resources = metrics.missing_resources.max(m.missing_resources);
+                metrics.crash_loop = metrics.crash_loop.max(m.crash_loop);
+                metrics.image_pull_error = metrics.image_pull_error.max(m.image_pull_error);
+                metrics.missing_metadata = metrics.missing_metadata.max(m.missing_metadata);
+                metrics.missing_pdb = metrics.missing_pdb.max(m.missing_pdb);
+                metrics.no_spread = metrics.no_spread.max(m.no_spread);
+                metrics.unpinned_image = metrics.unpinned_image.max(m.unpinned_image);
+                metrics.pull_policy = metrics.pull_policy.max(m.pull_policy);
+
+                for v in detect_violations_detailed_at(pod, policy, now) {
+                    if seen.insert((v.violation_type.clone(), v.container_name.clone())) {
+                        violations.push(v);
+                    }
+                }
+            }
+
+            WorkloadEvaluation {
+                workload,
+                namespace,
+                replicas: replicas.len() as u32,
+                metrics,
+                violations,
+            }
+        })
+        .collect()
+}
+
+/* ============================= BATCH WORKLOADS ============================= */
+
+/// Evaluate Jobs and CronJobs through their pod templates.
+///
+/// Scheduled or not-yet-started batch workloads have no running pods, so
+/// template evaluation is the only way to catch them. Jobs created by a
+/// CronJob are skipped; the CronJob's template already covers them.
+pub fn evaluate_batch_workloads(
+    jobs: &[Job],
+    cron_jobs: &[CronJob],
+    policy: &DevOpsPolicySpec,
+) -> Vec<WorkloadEvaluation> {
+    let job_templates = jobs
+        .iter()
+        .filter(|j| {
+            !j.metadata
+                .owner_references
+                .iter()
+                .flatten()
+                .any(|o| o.kind == "CronJob")
+        })
+        .filter_map(|j| Some(("Job", &j.metadata, &j.spec.as_ref()?.template)));
+
+    let cron_templates = cron_jobs.iter().filter_map(|c| {
+        let template = &c.spec.as_ref()?.job_template.spec.as_ref()?.template;
+        Some(("CronJob", &c.metadata, template))
+    });
+
+    job_templates
+        .chain(cron_templates)
+        .filter_map(|(kind, meta, template)| {
+            let name = meta.name.as_deref()?;
+            let namespace = meta.namespace.as_deref().unwrap_or("default");
+            if is_system_namespace(namespace) {
+                return None;
+            }
+            Some(WorkloadEvaluation {
+                workload: format!("{kind}/{name}"),
+                namespace: namespace.to_string(),
+                replicas: 0,
+                metrics: evaluate_workload(template, policy),
+                violations: detect_workload_violations(name, namespace, template, policy),
+            })
+        })
+        .collect()
+}
+
+/// Append template evaluations for workloads not already covered by live pods.
+///
+/// Running pods carry runtime state (restarts, pending), so their evaluation
+/// wins over the template's when both exist.
+pub fn merge_template_evaluations(
+    workloads: &mut Vec<WorkloadEvaluation>,
+    templates: Vec<WorkloadEvaluation>,
+) {
+    for t in templates {
+        let covered = workloads
+            .iter()
+            .any(|w| w.namespace == t.namespace && w.workload == t.workload);
+        if !covered {
+            workloads.push(t);
+        }
+    }
+}
+
+/* ============================= REPLICATED WORKLOADS ============================= */
+
+/// Whether `selector` selects pods labelled `labels`.
+///
+/// An empty selector selects every pod; an unknown operator selects none.
+pub fn selector_matches(selector: &LabelSelector, labels: &BTreeMap<String, String>) -> bool {
+    let labels_match = selector
+        .match_labels
+        .iter()
+        .flatten()
+        .all(|(k, v)| labels.get(k) == Some(v));
+    let expressions_match = selector.match_expressions.iter().flatten().all(|e| {
+        let value = labels.get(&e.key);
+        let listed = || value.is_some_and(|v| e.values.iter().flatten().any(|x| x == v));
+        match e.operator.as_str() {
+            "In" => listed(),
+            "NotIn" => !listed(),
+            "Exists" => value.is_some(),
+            "DoesNotExist" => value.is_none(),
+            _ => false,
+        }
+    });
+    labels_match && expressions_match
+}
+
+/// Whether a pod spec spreads its replicas: it declares
+/// `topologySpreadConstraints` or any pod anti-affinity term.
+pub fn has_spread(spec: &PodSpec) -> bool {
+    let anti_affinity = spec
+        .affinity
+        .as_ref()
+        .and_then(|a| a.pod_anti_affinity.as_ref())
+        .is_some_and(|a| {
+            a.required_during_scheduling_ignored_during_execution
+                .as_ref()
+                .is_some_and(|t| !t.is_empty())
+                || a.preferred_during_scheduling_ignored_during_execution
+                    .as_ref()
+                    .is_some_and(|t| !t.is_empty())
+        });
+    anti_affinity
+        || spec
+            .topology_spread_constraints
+            .as_ref()
+            .is_some_and(|c| !c.is_empty())
+}
+
+/// Check Deployments and StatefulSets that run more than one replica:
+/// `missing_pdb` when no PodDisruptionBudget in their namespace selects
+/// their pod template, `no_spread` when the template neither spreads nor
+/// repels its replicas ([`has_spread`]).
+///
+/// Each check runs only where the workload's effective policy (workload
+/// overrides included) enables it. Workloads in system namespaces are
+/// skipped. Merge the results with [`merge_workload_violations`].
+pub fn evaluate_replicated_workloads(
+    deployments: &[Deployment],
+    stateful_sets: &[StatefulSet],
+    pdbs: &[PodDisruptionBudget],
+    policy: &DevOpsPolicySpec,
+) -> Vec<WorkloadEvaluation> {
+    let deployment_templates = deployments.iter().filter_map(|d| {
+        let spec = d.spec.as_ref()?;
+        Some(("Deployment", &d.metadata, spec.replicas, &spec.template))
+    });
+    let stateful_set_templates = stateful_sets.iter().filter_map(|s| {
+        let spec = s.spec.as_ref()?;
+        Some(("StatefulSet", &s.metadata, spec.replicas, &spec.template))
+    });
+
+    deployment_templates
+        .chain(stateful_set_templates)
+        .filter_map(|(kind, meta, replicas, template)| {
+            let name = meta.name.as_deref()?;
+            let namespace = meta.namespace.as_deref().unwrap_or("default");
+            // The API server defaults replicas to 1
+            let replicas = replicas.unwrap_or(1);
+            if is_system_namespace(namespace) || replicas <= 1 {
+                return None;
+            }
+            let pod = pod_from_template(template);
+            let policy = policy_resolution::apply_workload_overrides(policy, &pod);
+            let workload = format!("{kind}/{name}");
+            let mut metrics = PodMetrics {
+                total_pods: 1,
+                ..Default::default()
+            };
+            let mut violations = Vec::new();
+            let mut flag = |violation_type: &str, message: String| {
+                metrics.record(violation_type);
+                violations.push(ViolationDetail {
+                    violation_type: violation_type.to_string(),
+                    severity: effective_severity(violation_type, policy.severity_overrides.as_ref()),
+                    pod_name: name.to_string(),
+                    namespace: namespace.to_string(),
+                    container_name: String::new(),
+                    message,
+                });
+            };
+
+            if policy.require_pod_disruption_budget.unwrap_or(false) {
+                let labels = pod.metadata.labels.clone().unwrap_or_default();
+                let covered = pdbs.iter().any(|pdb| {
+                    pdb.metadata.namespace.as_deref() == Some(namespace)
+                        && pdb
+                            .spec
+                            .as_ref()
+                            .and_then(|s| s.selector.as_ref())
+                            .is_some_and(|s| selector_matches(s, &labels))
+                });
+                if !covered {
+                    flag(
+                        "missing_pdb",
+                        format!(
+                            "{workload} runs {replicas} replicas with no PodDisruptionBudget selecting its pods"
+                        ),
+                    );
+                }
+            }
+            if policy.require_spread_constraints.unwrap_or(false)
+                && !pod.spec.as_ref().is_some_and(has_spread)
+            {
+                flag(
+                    "no_spread",
+                    format!(
+                        "{workload} runs {replicas} replicas with no pod anti-affinity or topologySpreadConstraints"
+                    ),
+                );
+            }
+
+            (!violations.is_empty()).then(|| WorkloadEvaluation {
+                workload,
+                namespace: namespace.to_string(),
+                replicas: 0,
+                metrics,
+                violations,
+            })
+        })
+        .collect()
+}
+
+/// Add workload-level evaluations, such as [`evaluate_replicated_workloads`],
+/// to the workloads evaluated from pods.
+///
+/// Violations join the workload's existing entry; a workload without live
+/// pods gets its own entry.
+pub fn merge_workload_violations(
+    workloads: &mut Vec<WorkloadEvaluation>,
+    extra: Vec<WorkloadEvaluation>,
+) {
+    for e in extra {
+        match workloads
+            .iter_mut()
+            .find(|w| w.namespace == e.namespace && w.workload == e.workload)
+        {
+            Some(w) => {
+                for v in &e.violations {
+                    w.metrics.record(&v.violation_type);
+                }
+                w.violations.extend(e.violations);
+            }
+            None => workloads.push(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::api::core::v1::{Container, ContainerStatus, Pod, PodSpec, PodStatus, Probe};
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+    fn make_test_pod(
+        name: &str,
+        namespace: &str,
+        image: &str,
+        has_liveness: bool,
+        has_readiness: bool,
+        restart_count: i32,
+        phase: &str,
+    ) -> Pod {
+        let probes =
+            |has: bool| -> Option<Probe> { if has { Some(Probe::default()) } else { None } };
+
+        Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some(namespace.to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec {
+                containers: vec![Container {
+                    name: "main".to_string(),
+                    image: Some(image.to_string()),
+                    liveness_probe: probes(has_liveness),
+                    readiness_probe: probes(has_readiness),
+                    ..Default::default()
+                }],
                 ..Default::default()
             }),
             status: Some(PodStatus {
@@ -694,6 +2047,51 @@ mod tests {
         assert_eq!(cluster.missing_liveness, 1);
     }
 
+    #[test]
+    fn test_metrics_count_matches_record() {
+        let mut metrics = PodMetrics::default();
+        for violation_type in VIOLATION_TYPES {
+            metrics.record(violation_type);
+            assert_eq!(metrics.count(violation_type), 1, "{violation_type}");
+        }
+        metrics.record("unknown");
+        assert_eq!(metrics.count("unknown"), 0);
+    }
+
+    #[test]
+    fn test_enabled_checks() {
+        assert!(enabled_checks(&DevOpsPolicySpec::default()).is_empty());
+        let policy = DevOpsPolicySpec {
+            required_tag_pattern: Some("^v".to_string()),
+            require_readiness_probe: Some(true),
+            require_liveness_probe: Some(false),
+            max_restart_count: Some(3),
+            forbid_crash_loop: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            enabled_checks(&policy),
+            [
+                "latest_tag",
+                "missing_readiness",
+                "high_restarts",
+                "crash_loop"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_violation_type_has_remediation_hint() {
+        let fallback = remediation_hint("unknown");
+        for violation_type in VIOLATION_TYPES {
+            assert_ne!(
+                remediation_hint(violation_type),
+                fallback,
+                "{violation_type}"
+            );
+        }
+    }
+
     #[test]
     fn test_subtract_metrics_basic() {
         let mut cluster = PodMetrics {
@@ -735,6 +2133,14 @@ mod tests {
             missing_readiness: 1,
             high_restarts: 2,
             pending: 1,
+            missing_resources: 1,
+            crash_loop: 1,
+            image_pull_error: 1,
+            missing_metadata: 1,
+            missing_pdb: 1,
+            no_spread: 1,
+            unpinned_image: 1,
+            pull_policy: 1,
         };
         add_metrics(&mut cluster, &pod);
         subtract_metrics(&mut cluster, &pod);
@@ -744,6 +2150,14 @@ mod tests {
         assert_eq!(cluster.missing_readiness, 0);
         assert_eq!(cluster.high_restarts, 0);
         assert_eq!(cluster.pending, 0);
+        assert_eq!(cluster.missing_resources, 0);
+        assert_eq!(cluster.crash_loop, 0);
+        assert_eq!(cluster.image_pull_error, 0);
+        assert_eq!(cluster.missing_metadata, 0);
+        assert_eq!(cluster.missing_pdb, 0);
+        assert_eq!(cluster.no_spread, 0);
+        assert_eq!(cluster.unpinned_image, 0);
+        assert_eq!(cluster.pull_policy, 0);
     }
 
     // ── calculate_health_score ──
@@ -773,6 +2187,14 @@ mod tests {
             missing_readiness: 1,
             high_restarts: 5,
             pending: 1,
+            missing_resources: 0,
+            crash_loop: 0,
+            image_pull_error: 0,
+            missing_metadata: 0,
+            missing_pdb: 0,
+            no_spread: 0,
+            unpinned_image: 0,
+            pull_policy: 0,
         };
         let score = calculate_health_score(&m);
         // raw = 5+3+2+30+4 = 44, per_pod = 44, capped = 44 → 100-44 = 56
@@ -789,6 +2211,14 @@ mod tests {
             missing_readiness: 10,
             high_restarts: 10,
             pending: 10,
+            missing_resources: 10,
+            crash_loop: 10,
+            image_pull_error: 10,
+            missing_metadata: 10,
+            missing_pdb: 10,
+            no_spread: 10,
+            unpinned_image: 10,
+            pull_policy: 10,
         };
         let score = calculate_health_score(&m);
         assert_eq!(score, 0);
@@ -846,6 +2276,41 @@ mod tests {
         assert_eq!(classify_health(0), "Critical");
     }
 
+    #[test]
+    fn test_classify_with_policy_thresholds() {
+        let policy = DevOpsPolicySpec {
+            health_thresholds: Some(HealthThresholds {
+                healthy: Some(90),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let thresholds = ClassificationThresholds::for_policy(&policy);
+        assert_eq!(
+            thresholds,
+            ClassificationThresholds {
+                healthy: 90,
+                stable: 60,
+                degraded: 40
+            }
+        );
+        assert_eq!(thresholds.classify(90), "Healthy");
+        assert_eq!(thresholds.classify(85), "Stable");
+        assert_eq!(thresholds.classify(40), "Degraded");
+        assert!(thresholds.is_ordered());
+        assert_eq!(
+            ClassificationThresholds::for_policy(&DevOpsPolicySpec::default()),
+            ClassificationThresholds::default()
+        );
+
+        let inverted = ClassificationThresholds {
+            healthy: 50,
+            stable: 60,
+            degraded: 40,
+        };
+        assert!(!inverted.is_ordered());
+    }
+
     // ── defaults ──
 
     #[test]
@@ -910,6 +2375,76 @@ mod tests {
         assert_eq!(m.pending, 0);
     }
 
+    // ── Configured probes ──
+
+    fn tcp_probe(period_seconds: Option<i32>, failure_threshold: Option<i32>) -> Probe {
+        Probe {
+            tcp_socket: Some(k8s_openapi::api::core::v1::TCPSocketAction {
+                port: k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::Int(8080),
+                ..Default::default()
+            }),
+            period_seconds,
+            failure_threshold,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_probe_violation() {
+        let lenient = all_enabled_policy();
+        let strict = DevOpsPolicySpec {
+            require_configured_probes: Some(true),
+            ..all_enabled_policy()
+        };
+        let empty = Probe::default();
+        assert_eq!(
+            probe_violation("liveness", None, &lenient).as_deref(),
+            Some("missing liveness probe")
+        );
+        assert_eq!(probe_violation("liveness", Some(&empty), &lenient), None);
+        assert_eq!(
+            probe_violation("liveness", Some(&empty), &strict).as_deref(),
+            Some("liveness probe has no httpGet, tcpSocket, exec, or grpc handler")
+        );
+        assert_eq!(
+            probe_violation("readiness", Some(&tcp_probe(Some(1), None)), &strict).as_deref(),
+            Some("readiness probe runs every second (periodSeconds: 1)")
+        );
+        assert_eq!(
+            probe_violation("liveness", Some(&tcp_probe(Some(10), Some(1))), &strict).as_deref(),
+            Some("liveness probe acts on a single failure (failureThreshold: 1)")
+        );
+        assert_eq!(
+            probe_violation("liveness", Some(&tcp_probe(Some(10), Some(3))), &strict),
+            None
+        );
+    }
+
+    #[test]
+    fn test_policy_eval_require_configured_probes() {
+        let policy = DevOpsPolicySpec {
+            require_configured_probes: Some(true),
+            ..all_enabled_policy()
+        };
+        // make_test_pod's probes are `Probe::default()`, with no handler
+        let pod = make_test_pod("p", "default", "nginx:1.25", true, true, 0, "Running");
+        assert_eq!(
+            evaluate_pod_with_policy(&pod, &all_enabled_policy()).missing_liveness,
+            0
+        );
+        let m = evaluate_pod_with_policy(&pod, &policy);
+        assert_eq!((m.missing_liveness, m.missing_readiness), (1, 1));
+        assert_eq!(
+            detect_violations_with_policy(&pod, &policy),
+            ["missing_liveness", "missing_readiness"]
+        );
+        let details = detect_violations_detailed(&pod, &policy);
+        assert_eq!(
+            details[0].message,
+            "container 'main' liveness probe has no httpGet, tcpSocket, exec, or grpc handler"
+        );
+    }
+
     #[test]
     fn test_policy_eval_only_latest_tag_enabled() {
         let policy = DevOpsPolicySpec {
@@ -1134,6 +2669,14 @@ mod tests {
             missing_readiness: Some(Severity::Low),
             high_restarts: Some(Severity::Low),
             pending: Some(Severity::Low),
+            missing_resources: Some(Severity::Low),
+            crash_loop: Some(Severity::Low),
+            image_pull_error: Some(Severity::Low),
+            missing_metadata: Some(Severity::Low),
+            missing_pdb: Some(Severity::Low),
+            no_spread: Some(Severity::Low),
+            unpinned_image: Some(Severity::Low),
+            pull_policy: Some(Severity::Low),
         };
         let with_all_low = calculate_health_score_with_severity(&m, Some(&overrides));
         // With all Low (multiplier=1), it should match the base score
@@ -1188,6 +2731,21 @@ mod tests {
         assert_eq!(details[0].container_name, "main");
     }
 
+    #[test]
+    fn test_detect_violations_detailed_builtin_matches_types() {
+        let pod = make_test_pod("web-1", "prod", "nginx:latest", false, true, 0, "Running");
+        let details = detect_violations_detailed_builtin(&pod);
+        let types: Vec<&str> = details.iter().map(|d| d.violation_type.as_str()).collect();
+        assert_eq!(types, detect_violations(&pod));
+        assert_eq!(details[0].severity, Severity::High);
+        assert_eq!(details[0].pod_name, "web-1");
+        assert_eq!(details[0].container_name, "main");
+        assert_eq!(
+            details[1].message,
+            "container 'main' missing liveness probe"
+        );
+    }
+
     #[test]
     fn test_detect_violations_detailed_empty_policy() {
         let pod = make_test_pod("p", "default", "nginx:latest", false, false, 10, "Pending");
@@ -1230,4 +2788,1220 @@ mod tests {
         assert_eq!(details[0].violation_type, "pending");
         assert!(details[0].container_name.is_empty());
     }
+
+    // ── custom weights / audit metrics ──
+
+    #[test]
+    fn test_weights_for_policy_override_defaults() {
+        assert_eq!(
+            ScoringWeights::for_policy(&DevOpsPolicySpec::default()),
+            ScoringWeights::default()
+        );
+        let policy = DevOpsPolicySpec {
+            scoring_weights: Some(crate::crd::ScoringWeightOverrides {
+                missing_liveness: Some(10),
+                latest_tag: Some(0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let weights = ScoringWeights::for_policy(&policy);
+        assert_eq!(weights.missing_liveness, 10);
+        assert_eq!(weights.latest_tag, 0);
+        assert_eq!(weights.pending, ScoringWeights::default().pending);
+    }
+
+    #[test]
+    fn test_custom_weights_default_matches_plain_score() {
+        let m = PodMetrics {
+            total_pods: 2,
+            latest_tag: 1,
+            missing_liveness: 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            calculate_health_score_with_weights(&m, &ScoringWeights::default()),
+            calculate_health_score(&m)
+        );
+    }
+
+    #[test]
+    fn test_custom_weights_change_score() {
+        let m = PodMetrics {
+            total_pods: 1,
+            latest_tag: 1,
+            ..Default::default()
+        };
+        let heavy = ScoringWeights {
+            latest_tag: 50,
+            ..Default::default()
+        };
+        assert_eq!(calculate_health_score_with_weights(&m, &heavy), 50);
+    }
+
+    #[test]
+    fn test_caps_limit_one_check() {
+        let m = PodMetrics {
+            total_pods: 10,
+            missing_readiness: 10,
+            latest_tag: 1,
+            ..Default::default()
+        };
+        let heavy = ScoringWeights {
+            missing_readiness: 50,
+            ..Default::default()
+        };
+        // 10 × 50 + 5 = 505 / 10 = 50 points
+        assert_eq!(calculate_health_score_with_weights(&m, &heavy), 50);
+
+        let capped = ScoringWeights {
+            caps: ScoringCaps {
+                missing_readiness: Some(10),
+                ..Default::default()
+            },
+            ..heavy
+        };
+        // readiness capped at 10 points (100 raw), latest_tag still counts
+        assert_eq!(calculate_health_score_with_weights(&m, &capped), 90);
+        // The cap bounds the multiplied penalty; latest_tag (High) doubles to 10
+        assert_eq!(
+            calculate_health_score_with_weights_and_severity(&m, &capped, None),
+            89
+        );
+    }
+
+    #[test]
+    fn test_caps_default_is_uncapped() {
+        let m = PodMetrics {
+            total_pods: 1,
+            high_restarts: 20,
+            ..Default::default()
+        };
+        assert_eq!(calculate_health_score(&m), 0);
+        assert_eq!(ScoringWeights::default().caps, ScoringCaps::default());
+    }
+
+    #[test]
+    fn test_weights_and_severity_default_matches_severity_score() {
+        let m = PodMetrics {
+            total_pods: 1,
+            latest_tag: 1,
+            high_restarts: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            calculate_health_score_with_weights_and_severity(&m, &ScoringWeights::default(), None),
+            calculate_health_score_with_severity(&m, None)
+        );
+    }
+
+    #[test]
+    fn test_metrics_from_violations() {
+        let v = |t: &str| AuditViolation {
+            pod_name: "p".to_string(),
+            container_name: "c".to_string(),
+            violation_type: t.to_string(),
+            severity: default_severity(t),
+            message: String::new(),
+            workload: None,
+        };
+        let violations = vec![
+            v("latest_tag"),
+            v("latest_tag"),
+            v("missing_readiness"),
+            v("pending"),
+            v("unknown"),
+        ];
+        let m = metrics_from_violations(4, &violations);
+        assert_eq!(m.total_pods, 4);
+        assert_eq!(m.latest_tag, 2);
+        assert_eq!(m.missing_readiness, 1);
+        assert_eq!(m.pending, 1);
+        assert_eq!(m.missing_liveness, 0);
+    }
+
+    // ── Container exemptions ──
+
+    fn with_skip(mut pod: Pod, container: &str, checks: &str) -> Pod {
+        pod.metadata.annotations = Some(std::collections::BTreeMap::from([(
+            format!("{SKIP_CHECKS_ANNOTATION_PREFIX}{container}"),
+            checks.to_string(),
+        )]));
+        pod
+    }
+
+    #[test]
+    fn test_is_check_skipped() {
+        let pod = with_skip(
+            make_test_pod("p", "default", "app:1.0", true, true, 0, "Running"),
+            "main",
+            "missing_readiness, latest_tag",
+        );
+        assert!(is_check_skipped(&pod, "main", "missing_readiness"));
+        assert!(is_check_skipped(&pod, "main", "latest_tag"));
+        assert!(!is_check_skipped(&pod, "main", "missing_liveness"));
+        assert!(!is_check_skipped(&pod, "sidecar", "missing_readiness"));
+
+        let all = with_skip(pod, "main", "*");
+        assert!(is_check_skipped(&all, "main", "high_restarts"));
+    }
+
+    #[test]
+    fn test_skip_checks_honored_by_evaluation() {
+        let pod = with_skip(
+            make_test_pod("p", "default", "app:latest", false, false, 10, "Running"),
+            "main",
+            "missing_readiness,high_restarts",
+        );
+        let m = evaluate_pod_with_policy(&pod, &all_enabled_policy());
+        assert_eq!(m.latest_tag, 1);
+        assert_eq!(m.missing_liveness, 1);
+        assert_eq!(m.missing_readiness, 0);
+        assert_eq!(m.high_restarts, 0);
+
+        let detailed = detect_violations_detailed(&pod, &all_enabled_policy());
+        let types: Vec<&str> = detailed.iter().map(|v| v.violation_type.as_str()).collect();
+        assert_eq!(types, vec!["latest_tag", "missing_liveness"]);
+
+        let labels = detect_violations_with_policy(&pod, &all_enabled_policy());
+        assert_eq!(labels, vec!["latest_tag", "missing_liveness"]);
+
+        assert_eq!(evaluate_pod(&pod).missing_readiness, 0);
+        assert_eq!(
+            detect_violations(&pod),
+            vec!["latest_tag", "missing_liveness"]
+        );
+    }
+
+    #[test]
+    fn test_skip_checks_does_not_exempt_pending() {
+        let pod = with_skip(
+            make_test_pod("p", "default", "app:1.0", true, true, 0, "Pending"),
+            "main",
+            "*",
+        );
+        assert_eq!(
+            evaluate_pod_with_policy(&pod, &all_enabled_policy()).pending,
+            1
+        );
+    }
+
+    // ── Workload evaluation ──
+
+    fn owned_by(mut pod: Pod, kind: &str, owner: &str) -> Pod {
+        pod.metadata.owner_references = Some(vec![
+            k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference {
+                kind: kind.to_string(),
+                name: owner.to_string(),
+                ..Default::default()
+            },
+        ]);
+        pod
+    }
+
+    #[test]
+    fn test_workload_label() {
+        let bare = make_test_pod("debug", "default", "busybox:1.36", true, true, 0, "Running");
+        assert_eq!(workload_label(&bare), "Pod/debug");
+
+        let replica = owned_by(bare.clone(), "ReplicaSet", "web-5d4f8b9c7f");
+        assert_eq!(workload_label(&replica), "Deployment/web");
+
+        let member = owned_by(bare, "StatefulSet", "db");
+        assert_eq!(workload_label(&member), "StatefulSet/db");
+    }
+
+    #[test]
+    fn test_evaluate_workloads_dedupes_replicas() {
+        let pods: Vec<Pod> = (0..3)
+            .map(|i| {
+                owned_by(
+                    make_test_pod(
+                        &format!("web-{i}"),
+                        "prod",
+                        "nginx:latest",
+                        false,
+                        true,
+                        0,
+                        "Running",
+                    ),
+                    "Deployment",
+                    "web",
+                )
+            })
+            .collect();
+
+        let workloads = evaluate_workloads(&pods, &all_enabled_policy());
+        assert_eq!(workloads.len(), 1);
+
+        let web = &workloads[0];
+        assert_eq!(web.workload, "Deployment/web");
+        assert_eq!(web.namespace, "prod");
+        assert_eq!(web.replicas, 3);
+        assert_eq!(web.metrics.total_pods, 1);
+        assert_eq!(web.metrics.latest_tag, 1);
+        assert_eq!(web.metrics.missing_liveness, 1);
+        assert_eq!(web.violations.len(), 2);
+    }
+
+    #[test]
+    fn test_evaluate_workloads_keeps_replica_specific_violations() {
+        let healthy = make_test_pod("db-0", "prod", "postgres:16", true, true, 0, "Running");
+        let crashing = make_test_pod("db-1", "prod", "postgres:16", true, true, 9, "Running");
+        let expected = evaluate_pod_with_policy(&crashing, &all_enabled_policy()).high_restarts;
+        let pods = vec![
+            owned_by(healthy, "StatefulSet", "db"),
+            owned_by(crashing, "StatefulSet", "db"),
+        ];
+
+        let workloads = evaluate_workloads(&pods, &all_enabled_policy());
+        assert_eq!(workloads.len(), 1);
+        assert!(expected > 0);
+        assert_eq!(workloads[0].metrics.high_restarts, expected);
+        assert_eq!(workloads[0].violations[0].violation_type, "high_restarts");
+    }
+
+    #[test]
+    fn test_evaluate_workloads_skips_system_namespaces() {
+        let pods = vec![
+            make_test_pod(
+                "coredns",
+                "kube-system",
+                "coredns:latest",
+                false,
+                false,
+                0,
+                "Running",
+            ),
+            make_test_pod("app", "default", "app:1.0", true, true, 0, "Running"),
+        ];
+        let workloads = evaluate_workloads(&pods, &all_enabled_policy());
+        assert_eq!(workloads.len(), 1);
+        assert_eq!(workloads[0].workload, "Pod/app");
+    }
+
+    #[test]
+    fn test_evaluate_workload_template() {
+        use k8s_openapi::api::core::v1::PodTemplateSpec;
+
+        let template = PodTemplateSpec {
+            metadata: None,
+            spec: make_test_pod("x", "default", "nginx:latest", true, false, 0, "Running").spec,
+        };
+        let metrics = evaluate_workload(&template, &all_enabled_policy());
+        assert_eq!(metrics.total_pods, 1);
+        assert_eq!(metrics.latest_tag, 1);
+        assert_eq!(metrics.missing_readiness, 1);
+
+        let violations =
+            detect_workload_violations("web", "prod", &template, &all_enabled_policy());
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].pod_name, "web");
+        assert_eq!(violations[0].namespace, "prod");
+    }
+
+    // ── Batch workloads ──
+
+    fn job_spec(image: &str) -> k8s_openapi::api::batch::v1::JobSpec {
+        k8s_openapi::api::batch::v1::JobSpec {
+            template: PodTemplateSpec {
+                metadata: None,
+                spec: make_test_pod("x", "default", image, false, false, 0, "Running").spec,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn batch_meta(name: &str, namespace: &str) -> ObjectMeta {
+        ObjectMeta {
+            name: Some(name.to_string()),
+            namespace: Some(namespace.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_evaluate_batch_workloads() {
+        use k8s_openapi::api::batch::v1::{CronJobSpec, JobTemplateSpec};
+
+        let job = Job {
+            metadata: batch_meta("migrate", "prod"),
+            spec: Some(job_spec("migrate:latest")),
+            status: None,
+        };
+        let cron = CronJob {
+            metadata: batch_meta("nightly", "prod"),
+            spec: Some(CronJobSpec {
+                schedule: "0 0 * * *".to_string(),
+                job_template: JobTemplateSpec {
+                    metadata: None,
+                    spec: Some(job_spec("report:1.0")),
+                },
+                ..Default::default()
+            }),
+            status: None,
+        };
+
+        let evals = evaluate_batch_workloads(&[job], &[cron], &all_enabled_policy());
+        assert_eq!(evals.len(), 2);
+
+        assert_eq!(evals[0].workload, "Job/migrate");
+        assert_eq!(evals[0].replicas, 0);
+        assert_eq!(evals[0].metrics.total_pods, 1);
+        assert_eq!(evals[0].metrics.latest_tag, 1);
+        assert_eq!(evals[0].violations[0].pod_name, "migrate");
+
+        assert_eq!(evals[1].workload, "CronJob/nightly");
+        assert_eq!(evals[1].metrics.latest_tag, 0);
+        assert_eq!(evals[1].metrics.missing_liveness, 1);
+    }
+
+    #[test]
+    fn test_evaluate_batch_skips_cronjob_children_and_system_namespaces() {
+        let mut child = Job {
+            metadata: batch_meta("nightly-29000", "prod"),
+            spec: Some(job_spec("report:1.0")),
+            status: None,
+        };
+        child.metadata.owner_references = Some(vec![
+            k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference {
+                kind: "CronJob".to_string(),
+                name: "nightly".to_string(),
+                ..Default::default()
+            },
+        ]);
+        let system = Job {
+            metadata: batch_meta("backup", "kube-system"),
+            spec: Some(job_spec("backup:latest")),
+            status: None,
+        };
+        assert!(evaluate_batch_workloads(&[child, system], &[], &all_enabled_policy()).is_empty());
+    }
+
+    #[test]
+    fn test_merge_template_evaluations_prefers_live_pods() {
+        let pod = owned_by(
+            make_test_pod(
+                "migrate-x",
+                "prod",
+                "migrate:latest",
+                true,
+                true,
+                9,
+                "Running",
+            ),
+            "Job",
+            "migrate",
+        );
+        assert_eq!(workload_label(&pod), "Job/migrate");
+
+        let mut workloads = evaluate_workloads(&[pod], &all_enabled_policy());
+        let job = Job {
+            metadata: batch_meta("migrate", "prod"),
+            spec: Some(job_spec("migrate:latest")),
+            status: None,
+        };
+        let other = Job {
+            metadata: batch_meta("seed", "prod"),
+            spec: Some(job_spec("seed:1.0")),
+            status: None,
+        };
+        merge_template_evaluations(
+            &mut workloads,
+            evaluate_batch_workloads(&[job, other], &[], &all_enabled_policy()),
+        );
+
+        assert_eq!(workloads.len(), 2);
+        assert_eq!(workloads[0].replicas, 1);
+        assert!(workloads[0].metrics.high_restarts > 0);
+        assert_eq!(workloads[1].workload, "Job/seed");
+    }
+
+    #[test]
+    fn test_cronjob_pods_cover_the_cronjob_template() {
+        use k8s_openapi::api::batch::v1::{CronJobSpec, JobTemplateSpec};
+
+        let cron = CronJob {
+            metadata: batch_meta("nightly", "prod"),
+            spec: Some(CronJobSpec {
+                schedule: "0 0 * * *".to_string(),
+                job_template: JobTemplateSpec {
+                    metadata: None,
+                    spec: Some(job_spec("report:latest")),
+                },
+                ..Default::default()
+            }),
+            status: None,
+        };
+        let child = |name: &str| {
+            let mut job = Job {
+                metadata: batch_meta(name, "prod"),
+                spec: Some(job_spec("report:latest")),
+                status: None,
+            };
+            job.metadata.owner_references = Some(vec![
+                k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference {
+                    kind: "CronJob".to_string(),
+                    name: "nightly".to_string(),
+                    ..Default::default()
+                },
+            ]);
+            job
+        };
+        let pod = |name: &str, job: &str| {
+            owned_by(
+                make_test_pod(name, "prod", "report:latest", true, true, 0, "Running"),
+                "Job",
+                job,
+            )
+        };
+        let jobs = [child("nightly-29000"), child("nightly-29001")];
+        let pods = [
+            pod("nightly-29000-a", "nightly-29000"),
+            pod("nightly-29001-b", "nightly-29001"),
+        ];
+
+        let policy = all_enabled_policy();
+        let mut workloads = evaluate_workloads_with_jobs(&pods, &jobs, &policy, Utc::now());
+        merge_template_evaluations(
+            &mut workloads,
+            evaluate_batch_workloads(&jobs, &[cron], &policy),
+        );
+
+        // Both runs and the template are one CronJob workload
+        assert_eq!(workloads.len(), 1);
+        assert_eq!(workloads[0].workload, "CronJob/nightly");
+        assert_eq!(workloads[0].replicas, 2);
+        assert_eq!(workloads[0].metrics.latest_tag, 1);
+    }
+
+    // ── Replicated workloads ──
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn deployment(name: &str, replicas: Option<i32>, app: &str) -> Deployment {
+        Deployment {
+            metadata: batch_meta(name, "prod"),
+            spec: Some(k8s_openapi::api::apps::v1::DeploymentSpec {
+                replicas,
+                template: PodTemplateSpec {
+                    metadata: Some(ObjectMeta {
+                        labels: Some(labels(&[("app", app)])),
+                        ..Default::default()
+                    }),
+                    spec: None,
+                },
+                ..Default::default()
+            }),
+            status: None,
+        }
+    }
+
+    fn pdb(namespace: &str, selector: LabelSelector) -> PodDisruptionBudget {
+        PodDisruptionBudget {
+            metadata: batch_meta("budget", namespace),
+            spec: Some(k8s_openapi::api::policy::v1::PodDisruptionBudgetSpec {
+                selector: Some(selector),
+                ..Default::default()
+            }),
+            status: None,
+        }
+    }
+
+    #[test]
+    fn test_selector_matches() {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelectorRequirement;
+        let pod = labels(&[("app", "web"), ("tier", "frontend")]);
+        let expression = |operator: &str, values: &[&str]| LabelSelector {
+            match_expressions: Some(vec![LabelSelectorRequirement {
+                key: "tier".to_string(),
+                operator: operator.to_string(),
+                values: Some(values.iter().map(|v| v.to_string()).collect()),
+            }]),
+            ..Default::default()
+        };
+
+        assert!(selector_matches(&LabelSelector::default(), &pod));
+        assert!(selector_matches(
+            &LabelSelector {
+                match_labels: Some(labels(&[("app", "web")])),
+                ..Default::default()
+            },
+            &pod
+        ));
+        assert!(!selector_matches(
+            &LabelSelector {
+                match_labels: Some(labels(&[("app", "api")])),
+                ..Default::default()
+            },
+            &pod
+        ));
+        assert!(selector_matches(
+            &expression("In", &["frontend", "edge"]),
+            &pod
+        ));
+        assert!(!selector_matches(&expression("NotIn", &["frontend"]), &pod));
+        assert!(selector_matches(&expression("Exists", &[]), &pod));
+        assert!(!selector_matches(&expression("DoesNotExist", &[]), &pod));
+        assert!(!selector_matches(&expression("Gt", &["1"]), &pod));
+    }
+
+    #[test]
+    fn test_evaluate_replicated_workloads() {
+        let policy = DevOpsPolicySpec {
+            require_pod_disruption_budget: Some(true),
+            ..Default::default()
+        };
+        let deployments = [
+            deployment("web", Some(3), "web"),
+            deployment("api", Some(2), "api"),
+            deployment("worker", None, "worker"),
+        ];
+        let budgets = [
+            pdb(
+                "prod",
+                LabelSelector {
+                    match_labels: Some(labels(&[("app", "web")])),
+                    ..Default::default()
+                },
+            ),
+            // Same selector, other namespace
+            pdb(
+                "staging",
+                LabelSelector {
+                    match_labels: Some(labels(&[("app", "api")])),
+                    ..Default::default()
+                },
+            ),
+        ];
+
+        let evals = evaluate_replicated_workloads(&deployments, &[], &budgets, &policy);
+        assert_eq!(evals.len(), 1);
+        assert_eq!(evals[0].workload, "Deployment/api");
+        assert_eq!(evals[0].metrics.missing_pdb, 1);
+        assert_eq!(evals[0].violations[0].violation_type, "missing_pdb");
+        assert_eq!(
+            evals[0].violations[0].message,
+            "Deployment/api runs 2 replicas with no PodDisruptionBudget selecting its pods"
+        );
+        assert_eq!(enabled_checks(&policy), ["missing_pdb"]);
+
+        assert!(
+            evaluate_replicated_workloads(&deployments, &[], &budgets, &Default::default())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_evaluate_replicated_workloads_spread() {
+        use k8s_openapi::api::core::v1::{
+            Affinity, PodAffinityTerm, PodAntiAffinity, TopologySpreadConstraint,
+        };
+        let policy = DevOpsPolicySpec {
+            require_spread_constraints: Some(true),
+            ..Default::default()
+        };
+        let with_spec = |name: &str, spec: PodSpec| {
+            let mut d = deployment(name, Some(3), name);
+            d.spec.as_mut().unwrap().template.spec = Some(spec);
+            d
+        };
+        let deployments = [
+            with_spec("packed", PodSpec::default()),
+            with_spec(
+                "zonal",
+                PodSpec {
+                    topology_spread_constraints: Some(vec![TopologySpreadConstraint {
+                        max_skew: 1,
+                        topology_key: "topology.kubernetes.io/zone".to_string(),
+                        when_unsatisfiable: "ScheduleAnyway".to_string(),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+            ),
+            with_spec(
+                "repelled",
+                PodSpec {
+                    affinity: Some(Affinity {
+                        pod_anti_affinity: Some(PodAntiAffinity {
+                            required_during_scheduling_ignored_during_execution: Some(vec![
+                                PodAffinityTerm {
+                                    topology_key: "kubernetes.io/hostname".to_string(),
+                                    ..Default::default()
+                                },
+                            ]),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+            ),
+            deployment("singleton", Some(1), "singleton"),
+        ];
+
+        let evals = evaluate_replicated_workloads(&deployments, &[], &[], &policy);
+        assert_eq!(evals.len(), 1);
+        assert_eq!(evals[0].workload, "Deployment/packed");
+        assert_eq!(evals[0].metrics.no_spread, 1);
+        assert_eq!(evals[0].metrics.missing_pdb, 0);
+        assert_eq!(
+            evals[0].violations[0].message,
+            "Deployment/packed runs 3 replicas with no pod anti-affinity or topologySpreadConstraints"
+        );
+        assert_eq!(enabled_checks(&policy), ["no_spread"]);
+
+        // Both checks report on the same workload
+        let both = DevOpsPolicySpec {
+            require_pod_disruption_budget: Some(true),
+            ..policy
+        };
+        let evals = evaluate_replicated_workloads(&deployments[..1], &[], &[], &both);
+        assert_eq!(evals[0].violations.len(), 2);
+        assert_eq!(evals[0].metrics.missing_pdb, 1);
+    }
+
+    #[test]
+    fn test_merge_workload_violations() {
+        let pod = owned_by(
+            make_test_pod(
+                "api-5d4f8b9c7f-x",
+                "prod",
+                "api:1.0",
+                true,
+                true,
+                0,
+                "Running",
+            ),
+            "ReplicaSet",
+            "api-5d4f8b9c7f",
+        );
+        let policy = DevOpsPolicySpec {
+            require_pod_disruption_budget: Some(true),
+            ..Default::default()
+        };
+        let mut workloads = evaluate_workloads(&[pod], &policy);
+        let deployments = [
+            deployment("api", Some(2), "api"),
+            deployment("idle", Some(2), "idle"),
+        ];
+        merge_workload_violations(
+            &mut workloads,
+            evaluate_replicated_workloads(&deployments, &[], &[], &policy),
+        );
+
+        assert_eq!(workloads.len(), 2);
+        assert_eq!(workloads[0].workload, "Deployment/api");
+        assert_eq!(workloads[0].replicas, 1);
+        assert_eq!(workloads[0].metrics.missing_pdb, 1);
+        assert_eq!(workloads[0].violations.len(), 1);
+        assert_eq!(workloads[1].workload, "Deployment/idle");
+        assert_eq!(workloads[1].replicas, 0);
+    }
+
+    // ── Missing resources ──
+
+    #[test]
+    fn test_missing_resources_only_when_required() {
+        let pod = make_test_pod("p", "default", "app:1.0", true, true, 0, "Running");
+        assert_eq!(
+            evaluate_pod_with_policy(&pod, &all_enabled_policy()).missing_resources,
+            0
+        );
+
+        let policy = DevOpsPolicySpec {
+            require_resources: Some(true),
+            ..Default::default()
+        };
+        let m = evaluate_pod_with_policy(&pod, &policy);
+        assert_eq!(m.missing_resources, 1);
+        assert_eq!(calculate_health_score(&m), 98);
+
+        let detailed = detect_violations_detailed(&pod, &policy);
+        assert_eq!(detailed.len(), 1);
+        assert_eq!(detailed[0].violation_type, "missing_resources");
+        assert_eq!(detailed[0].severity, Severity::Medium);
+        assert_eq!(
+            detect_violations_with_policy(&pod, &policy),
+            vec!["missing_resources"]
+        );
+    }
+
+    #[test]
+    fn test_missing_resources_satisfied_by_requests() {
+        use k8s_openapi::api::core::v1::ResourceRequirements;
+        use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+
+        let mut pod = make_test_pod("p", "default", "app:1.0", true, true, 0, "Running");
+        pod.spec.as_mut().unwrap().containers[0].resources = Some(ResourceRequirements {
+            requests: Some(std::collections::BTreeMap::from([(
+                "cpu".to_string(),
+                Quantity("100m".to_string()),
+            )])),
+            ..Default::default()
+        });
+        let policy = DevOpsPolicySpec {
+            require_resources: Some(true),
+            ..Default::default()
+        };
+        assert!(detect_violations_detailed(&pod, &policy).is_empty());
+    }
+
+    // ── image tag patterns ──
+
+    fn tag_policy(forbidden: &[&str], required: Option<&str>) -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            forbidden_tag_patterns: Some(forbidden.iter().map(|p| p.to_string()).collect()),
+            required_tag_pattern: required.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_image_tag() {
+        assert_eq!(image_tag("nginx:1.25"), Some("1.25"));
+        assert_eq!(image_tag("registry:5000/team/app:dev-3"), Some("dev-3"));
+        assert_eq!(image_tag("registry:5000/team/app"), Some("latest"));
+        assert_eq!(image_tag("app@sha256:abc"), None);
+        assert_eq!(image_tag("app:1.0@sha256:abc"), None);
+    }
+
+    #[test]
+    fn test_forbidden_tag_patterns() {
+        let policy = tag_policy(&["latest", "^dev-", "snapshot"], None);
+        assert_eq!(
+            image_tag_violation("app:dev-42", &policy).as_deref(),
+            Some("image tag 'dev-42' matches forbidden pattern '^dev-'")
+        );
+        assert!(image_tag_violation("app:1.0-SNAPSHOT", &policy).is_none());
+        assert!(image_tag_violation("app:1.0-snapshot", &policy).is_some());
+        assert!(image_tag_violation("app", &policy).is_some());
+        assert!(image_tag_violation("app:v1.2.3", &policy).is_none());
+        assert!(image_tag_violation("app@sha256:abc", &policy).is_none());
+    }
+
+    #[test]
+    fn test_required_tag_pattern() {
+        let policy = tag_policy(&[], Some(r"^v\d+\.\d+\.\d+$"));
+        assert!(image_tag_violation("app:v1.2.3", &policy).is_none());
+        assert_eq!(
+            image_tag_violation("app:main", &policy).as_deref(),
+            Some("image tag 'main' does not match required pattern '^v\\d+\\.\\d+\\.\\d+$'")
+        );
+    }
+
+    #[test]
+    fn test_invalid_tag_pattern_is_ignored() {
+        let policy = tag_policy(&["(unclosed", "^dev-"], Some("[bad"));
+        assert!(image_tag_violation("app:v1", &policy).is_none());
+        assert!(image_tag_violation("app:dev-1", &policy).is_some());
+    }
+
+    #[test]
+    fn test_tag_patterns_report_as_latest_tag() {
+        let pod = make_test_pod("p", "default", "app:dev-7", true, true, 0, "Running");
+        let policy = tag_policy(&["^dev-"], None);
+        let detailed = detect_violations_detailed(&pod, &policy);
+        assert_eq!(detailed.len(), 1);
+        assert_eq!(detailed[0].violation_type, "latest_tag");
+        assert_eq!(
+            detailed[0].message,
+            "container 'main' image tag 'dev-7' matches forbidden pattern '^dev-'"
+        );
+        assert_eq!(
+            detect_violations_with_policy(&pod, &policy),
+            vec!["latest_tag"]
+        );
+        assert_eq!(evaluate_pod_with_policy(&pod, &policy).latest_tag, 1);
+    }
+
+    #[test]
+    fn test_image_digest_and_pull_policy() {
+        let digest = format!("app@sha256:{}", "a".repeat(64));
+        assert!(is_digest_pinned(&digest));
+        assert!(is_digest_pinned(&format!(
+            "app:1.0@sha256:{}",
+            "0".repeat(64)
+        )));
+        assert!(!is_digest_pinned("app@sha256:abc"));
+        assert!(!is_digest_pinned("app:1.0"));
+
+        let container = |image: &str, pull_policy: Option<&str>| Container {
+            name: "main".to_string(),
+            image: Some(image.to_string()),
+            image_pull_policy: pull_policy.map(str::to_string),
+            ..Default::default()
+        };
+        assert_eq!(effective_pull_policy(&container("app", None)), "Always");
+        assert_eq!(
+            effective_pull_policy(&container("app:1.0", None)),
+            "IfNotPresent"
+        );
+        assert_eq!(
+            effective_pull_policy(&container(&digest, None)),
+            "IfNotPresent"
+        );
+        assert_eq!(
+            effective_pull_policy(&container("app:1.0", Some("Never"))),
+            "Never"
+        );
+
+        let policy = DevOpsPolicySpec {
+            require_image_digest: Some(true),
+            ..Default::default()
+        };
+        assert!(image_violations(&container(&digest, None), &policy).is_empty());
+        assert_eq!(
+            image_violations(&container("app:1.0", None), &policy),
+            [(
+                "unpinned_image",
+                "image 'app:1.0' is not pinned to a sha256 digest".to_string()
+            )]
+        );
+        assert_eq!(enabled_checks(&policy), ["unpinned_image"]);
+
+        let policy = DevOpsPolicySpec {
+            forbid_image_pull_policy_always: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            image_violations(&container("app:1.0", Some("Always")), &policy),
+            [("pull_policy", "uses imagePullPolicy Always".to_string())]
+        );
+        assert!(image_violations(&container("app:1.0", Some("Never")), &policy).is_empty());
+
+        let policy = DevOpsPolicySpec {
+            require_image_pull_policy_if_not_present: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            image_violations(&container("app:1.0", Some("Never")), &policy),
+            [(
+                "pull_policy",
+                "uses imagePullPolicy Never, IfNotPresent required".to_string()
+            )]
+        );
+        assert!(image_violations(&container("app:1.0", None), &policy).is_empty());
+        assert_eq!(enabled_checks(&policy), ["pull_policy"]);
+
+        // Each rule reports under its own type, alongside a tag violation
+        let policy = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            require_image_digest: Some(true),
+            forbid_image_pull_policy_always: Some(true),
+            ..Default::default()
+        };
+        let types: Vec<_> = image_violations(&container("app:latest", None), &policy)
+            .into_iter()
+            .map(|(check, _)| check)
+            .collect();
+        assert_eq!(types, ["latest_tag", "unpinned_image", "pull_policy"]);
+    }
+
+    // ── required metadata ──
+
+    #[test]
+    fn test_metadata_violation_names_keys() {
+        let policy = DevOpsPolicySpec {
+            required_labels: Some(BTreeMap::from([
+                ("team".to_string(), String::new()),
+                ("env".to_string(), "prod|staging".to_string()),
+            ])),
+            required_annotations: Some(BTreeMap::from([(
+                "cost-center".to_string(),
+                String::new(),
+            )])),
+            ..Default::default()
+        };
+        let mut pod = make_test_pod("p", "default", "app:1.0", true, true, 0, "Running");
+        pod.metadata.labels = Some(BTreeMap::from([("env".to_string(), "dev".to_string())]));
+        assert_eq!(
+            metadata_violation(&pod, &policy).as_deref(),
+            Some(
+                "pod required label 'env' has value 'dev', expected 'prod|staging', \
+                 missing required label 'team', missing required annotation 'cost-center'"
+            )
+        );
+
+        let detailed = detect_violations_detailed(&pod, &policy);
+        assert_eq!(detailed.len(), 1);
+        assert_eq!(detailed[0].violation_type, "missing_metadata");
+        assert_eq!(detailed[0].severity, Severity::Medium);
+        assert_eq!(evaluate_pod_with_policy(&pod, &policy).missing_metadata, 1);
+        assert_eq!(enabled_checks(&policy), ["missing_metadata"]);
+
+        // The pattern must match the whole value
+        let labels = pod.metadata.labels.as_mut().unwrap();
+        labels.insert("env".to_string(), "prod".to_string());
+        labels.insert("team".to_string(), "payments".to_string());
+        assert!(
+            metadata_violation(&pod, &policy)
+                .unwrap()
+                .ends_with("'cost-center'")
+        );
+        pod.metadata.annotations = Some(BTreeMap::from([(
+            "cost-center".to_string(),
+            "cc-42".to_string(),
+        )]));
+        assert!(metadata_violation(&pod, &policy).is_none());
+        pod.metadata
+            .labels
+            .as_mut()
+            .unwrap()
+            .insert("env".to_string(), "prod-eu".to_string());
+        assert!(metadata_violation(&pod, &policy).is_some());
+    }
+
+    // ── pending age ──
+
+    fn pending_pod(created_secs_ago: Option<i64>) -> Pod {
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+        let mut pod = make_test_pod("p", "default", "app:1.0", true, true, 0, "Pending");
+        pod.metadata.creation_timestamp =
+            created_secs_ago.map(|secs| Time(Utc::now() - chrono::Duration::seconds(secs)));
+        pod
+    }
+
+    fn pending_policy() -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            forbid_pending_duration: Some(300),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pending_within_limit_is_not_flagged() {
+        let pod = pending_pod(Some(60));
+        assert!(pending_violation(&pod, &pending_policy(), Utc::now()).is_none());
+        assert!(detect_violations_with_policy(&pod, &pending_policy()).is_empty());
+        assert_eq!(evaluate_pod_with_policy(&pod, &pending_policy()).pending, 0);
+    }
+
+    #[test]
+    fn test_pending_past_limit_is_flagged_with_age() {
+        let pod = pending_pod(Some(600));
+        let details = detect_violations_detailed(&pod, &pending_policy());
+        assert_eq!(details.len(), 1);
+        assert_eq!(details[0].violation_type, "pending");
+        assert!(
+            details[0]
+                .message
+                .starts_with("pod has been Pending for 60")
+        );
+        assert!(details[0].message.ends_with("(limit 300s)"));
+        assert_eq!(evaluate_pod_with_policy(&pod, &pending_policy()).pending, 1);
+    }
+
+    #[test]
+    fn test_pending_age_falls_back_to_start_time_and_conditions() {
+        use k8s_openapi::api::core::v1::PodCondition;
+        use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+
+        let now = Utc::now();
+        let mut pod = pending_pod(None);
+        assert_eq!(
+            pending_violation(&pod, &pending_policy(), now).as_deref(),
+            Some("pod is in Pending phase")
+        );
+
+        let scheduled = now - chrono::Duration::seconds(400);
+        pod.status.as_mut().unwrap().conditions = Some(vec![PodCondition {
+            type_: "PodScheduled".to_string(),
+            status: "True".to_string(),
+            last_transition_time: Some(Time(scheduled)),
+            ..Default::default()
+        }]);
+        assert_eq!(pending_since(&pod), Some(scheduled));
+
+        let started = now - chrono::Duration::seconds(100);
+        pod.status.as_mut().unwrap().start_time = Some(Time(started));
+        assert_eq!(pending_since(&pod), Some(started));
+        assert!(pending_violation(&pod, &pending_policy(), now).is_none());
+    }
+
+    #[test]
+    fn test_policy_evaluators_age_pending_pods_against_now() {
+        let pod = pending_pod(Some(60));
+        let later = Utc::now() + chrono::Duration::seconds(600);
+        let policy = pending_policy();
+        assert_eq!(evaluate_pod_with_policy_at(&pod, &policy, later).pending, 1);
+        assert_eq!(
+            detect_violations_with_policy_at(&pod, &policy, later),
+            ["pending"]
+        );
+        assert_eq!(detect_violations_detailed_at(&pod, &policy, later).len(), 1);
+    }
+
+    #[test]
+    fn test_running_pod_is_never_pending_violation() {
+        let mut pod = pending_pod(Some(3600));
+        pod.status.as_mut().unwrap().phase = Some("Running".to_string());
+        assert!(pending_violation(&pod, &pending_policy(), Utc::now()).is_none());
+    }
+
+    // ── container waiting state ──
+
+    fn waiting_pod(main_reason: &str, init_reason: Option<&str>) -> Pod {
+        use k8s_openapi::api::core::v1::{ContainerState, ContainerStateWaiting};
+        let waiting = |name: &str, image: &str, reason: &str| ContainerStatus {
+            name: name.to_string(),
+            image: image.to_string(),
+            state: Some(ContainerState {
+                waiting: Some(ContainerStateWaiting {
+                    reason: Some(reason.to_string()),
+                    message: None,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut pod = make_test_pod("p", "default", "app:1.0", true, true, 4, "Running");
+        let status = pod.status.as_mut().unwrap();
+        status.container_statuses = Some(vec![waiting("main", "app:1.0", main_reason)]);
+        status.init_container_statuses =
+            init_reason.map(|reason| vec![waiting("init", "registry.local/init:2", reason)]);
+        pod
+    }
+
+    fn state_policy() -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            forbid_crash_loop: Some(true),
+            forbid_image_pull_errors: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_waiting_failures_classify_reasons() {
+        let pod = waiting_pod("CrashLoopBackOff", Some("ErrImagePull"));
+        let failures = waiting_failures(&pod);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].container, "init");
+        assert_eq!(failures[0].violation_type, "image_pull_error");
+        assert_eq!(
+            failures[0].message(),
+            "container 'init' cannot pull image 'registry.local/init:2' (ErrImagePull)"
+        );
+        assert_eq!(failures[1].violation_type, "crash_loop");
+        assert_eq!(
+            failures[1].message(),
+            "container 'main' is in CrashLoopBackOff"
+        );
+
+        assert!(waiting_failures(&waiting_pod("ContainerCreating", None)).is_empty());
+        assert_eq!(
+            waiting_failures(&waiting_pod("ImagePullBackOff", None))[0].violation_type,
+            "image_pull_error"
+        );
+    }
+
+    #[test]
+    fn test_waiting_failures_with_policy() {
+        let pod = waiting_pod("CrashLoopBackOff", Some("ImagePullBackOff"));
+        let m = evaluate_pod_with_policy(&pod, &state_policy());
+        assert_eq!(m.crash_loop, 1);
+        assert_eq!(m.image_pull_error, 1);
+        assert_eq!(
+            detect_violations_with_policy(&pod, &state_policy()),
+            vec!["image_pull_error", "crash_loop"]
+        );
+
+        let details = detect_violations_detailed(&pod, &state_policy());
+        assert_eq!(details.len(), 2);
+        assert_eq!(details[0].container_name, "init");
+        assert_eq!(details[0].severity, Severity::High);
+        assert_eq!(details[1].container_name, "main");
+        assert_eq!(details[1].severity, Severity::Critical);
+
+        // Checks are off unless the policy enables them
+        let m = evaluate_pod_with_policy(&pod, &DevOpsPolicySpec::default());
+        assert_eq!(m.crash_loop + m.image_pull_error, 0);
+    }
+
+    #[test]
+    fn test_waiting_failures_builtin_and_skip_checks() {
+        let pod = waiting_pod("CrashLoopBackOff", None);
+        assert_eq!(evaluate_pod(&pod).crash_loop, 1);
+        assert!(detect_violations(&pod).contains(&"crash_loop"));
+
+        let pod = with_skip(pod, "main", "crash_loop");
+        assert_eq!(evaluate_pod(&pod).crash_loop, 0);
+        assert!(detect_violations_detailed(&pod, &state_policy()).is_empty());
+    }
+
+    #[test]
+    fn test_waiting_failure_severity_override_and_weight() {
+        let pod = waiting_pod("ImagePullBackOff", None);
+        let policy = DevOpsPolicySpec {
+            severity_overrides: Some(SeverityOverrides {
+                image_pull_error: Some(Severity::Low),
+                missing_metadata: Some(Severity::Low),
+                missing_pdb: Some(Severity::Low),
+                no_spread: Some(Severity::Low),
+                unpinned_image: Some(Severity::Low),
+                pull_policy: Some(Severity::Low),
+                ..Default::default()
+            }),
+            ..state_policy()
+        };
+        let details = detect_violations_detailed(&pod, &policy);
+        assert_eq!(details[0].severity, Severity::Low);
+
+        let m = PodMetrics {
+            total_pods: 1,
+            crash_loop: 1,
+            ..Default::default()
+        };
+        assert!(calculate_health_score(&m) < 100);
+    }
+
+    // ── score trend ──
+
+    fn audit(policy: &str, timestamp: &str, health_score: u32) -> PolicyAuditResult {
+        PolicyAuditResult::new(
+            &format!("{policy}-{health_score}"),
+            crate::crd::PolicyAuditResultSpec {
+                policy_name: policy.to_string(),
+                cluster_name: None,
+                timestamp: timestamp.to_string(),
+                health_score,
+                total_violations: 0,
+                total_pods: 1,
+                total_workloads: None,
+                classification: classify_health(health_score).to_string(),
+                violations: Vec::new(),
+                planned_remediations: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_score_history_orders_by_timestamp() {
+        let results = vec![
+            audit("web", "2026-03-01T12:00:00Z", 70),
+            audit("other", "2026-03-01T11:00:00Z", 10),
+            audit("web", "2026-03-01T10:00:00Z", 90),
+        ];
+        assert_eq!(score_history(&results, "web"), vec![90, 70]);
+        assert!(score_history(&results, "missing").is_empty());
+    }
+
+    #[test]
+    fn test_score_trend() {
+        assert_eq!(score_trend(&[], 80), None);
+        assert_eq!(score_trend(&[80, 82], 83), Some(ScoreTrend::Stable));
+        assert_eq!(score_trend(&[70, 72], 80), Some(ScoreTrend::Improving));
+        assert_eq!(score_trend(&[90], 85), Some(ScoreTrend::Degrading));
+        // Only the last TREND_WINDOW scores form the baseline
+        assert_eq!(
+            score_trend(&[0, 0, 90, 90, 90, 90, 90], 90),
+            Some(ScoreTrend::Stable)
+        );
+    }
 }
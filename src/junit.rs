@@ -0,0 +1,234 @@
+//! JUnit XML reports, so CI servers (Jenkins, GitLab) show governance
+//! results in their test UI.
+//!
+//! Each policy check becomes a test case and its violations become the
+//! case's failure. Reports carry no timings; governance checks are not
+//! timed tests.
+
+use crate::admission::severity_rank;
+use crate::governance::ViolationDetail;
+
+/* ============================= TYPES ============================= */
+
+/// A group of test cases, e.g. one namespace or one manifest file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestSuite {
+    pub name: String,
+    pub cases: Vec<TestCase>,
+}
+
+/// One check against one subject. Passes when `failure` is unset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCase {
+    pub classname: String,
+    pub name: String,
+    pub failure: Option<Failure>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    pub message: String,
+    /// The highest severity among the violations.
+    pub kind: String,
+    /// One line per violation.
+    pub text: String,
+}
+
+impl TestSuite {
+    pub fn failures(&self) -> usize {
+        self.cases.iter().filter(|c| c.failure.is_some()).count()
+    }
+}
+
+/* ============================= BUILDING ============================= */
+
+/// Test case for `check` under `classname`, failing with every violation of
+/// that type in `violations`.
+pub fn check_case<'a>(
+    classname: &str,
+    check: &str,
+    violations: impl IntoIterator<Item = &'a ViolationDetail>,
+) -> TestCase {
+    let matching: Vec<&ViolationDetail> = violations
+        .into_iter()
+        .filter(|v| v.violation_type == check)
+        .collect();
+    let failure = matching
+        .iter()
+        .max_by_key(|v| severity_rank(&v.severity))
+        .map(|worst| Failure {
+            message: format!("{} violation(s)", matching.len()),
+            kind: format!("{:?}", worst.severity).to_lowercase(),
+            text: matching
+                .iter()
+                .map(|v| {
+                    format!(
+                        "{}/{} [{}]: {}\n",
+                        v.namespace, v.pod_name, v.container_name, v.message
+                    )
+                })
+                .collect(),
+        });
+    TestCase {
+        classname: classname.to_string(),
+        name: check.to_string(),
+        failure,
+    }
+}
+
+/// `checks` followed by any other violation types found (enabled by a
+/// workload override, say), so no violation goes unreported.
+pub fn checks_with_found<'a>(
+    checks: &[&'a str],
+    violations: impl IntoIterator<Item = &'a ViolationDetail>,
+) -> Vec<&'a str> {
+    let mut all = checks.to_vec();
+    for v in violations {
+        if !all.contains(&v.violation_type.as_str()) {
+            all.push(&v.violation_type);
+        }
+    }
+    all
+}
+
+/* ============================= RENDERING ============================= */
+
+/// Render suites as a `<testsuites>` document named `name`.
+pub fn render(name: &str, suites: &[TestSuite]) -> String {
+    let tests: usize = suites.iter().map(|s| s.cases.len()).sum();
+    let failures: usize = suites.iter().map(TestSuite::failures).sum();
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out.push_str(&format!(
+        "<testsuites name=\"{}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"0\">\n",
+        escape(name)
+    ));
+    for suite in suites {
+        out.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"0\" skipped=\"0\">\n",
+            escape(&suite.name),
+            suite.cases.len(),
+            suite.failures()
+        ));
+        for case in &suite.cases {
+            let attrs = format!(
+                "classname=\"{}\" name=\"{}\"",
+                escape(&case.classname),
+                escape(&case.name)
+            );
+            match &case.failure {
+                None => out.push_str(&format!("    <testcase {attrs}/>\n")),
+                Some(failure) => {
+                    out.push_str(&format!("    <testcase {attrs}>\n"));
+                    out.push_str(&format!(
+                        "      <failure message=\"{}\" type=\"{}\">{}</failure>\n",
+                        escape(&failure.message),
+                        escape(&failure.kind),
+                        escape(&failure.text)
+                    ));
+                    out.push_str("    </testcase>\n");
+                }
+            }
+        }
+        out.push_str("  </testsuite>\n");
+    }
+    out.push_str("</testsuites>\n");
+    out
+}
+
+/// Escape text for XML content and attribute values.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters are not allowed in XML 1.0
+            c if c.is_control() && !matches!(c, '\n' | '\t' | '\r') => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance;
+
+    fn violation(pod: &str, violation_type: &str) -> ViolationDetail {
+        ViolationDetail {
+            violation_type: violation_type.to_string(),
+            severity: governance::default_severity(violation_type),
+            pod_name: pod.to_string(),
+            namespace: "prod".to_string(),
+            container_name: "main".to_string(),
+            message: format!("container 'main' {violation_type}"),
+        }
+    }
+
+    #[test]
+    fn test_check_case() {
+        let violations = [
+            violation("web", "latest_tag"),
+            violation("api", "latest_tag"),
+            violation("web", "missing_liveness"),
+        ];
+        let case = check_case("prod", "latest_tag", &violations);
+        let failure = case.failure.unwrap();
+        assert_eq!(failure.message, "2 violation(s)");
+        assert_eq!(failure.kind, "high");
+        assert_eq!(
+            failure.text,
+            "prod/web [main]: container 'main' latest_tag\nprod/api [main]: container 'main' latest_tag\n"
+        );
+        assert!(
+            check_case("prod", "crash_loop", &violations)
+                .failure
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_checks_with_found() {
+        let violations = [violation("web", "pending"), violation("web", "latest_tag")];
+        assert_eq!(
+            checks_with_found(&["latest_tag"], &violations),
+            ["latest_tag", "pending"]
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let violations = [violation("web<1>", "latest_tag")];
+        let suites = [TestSuite {
+            name: "prod".to_string(),
+            cases: vec![
+                check_case("prod", "latest_tag", &violations),
+                check_case("prod", "missing_liveness", &violations),
+            ],
+        }];
+        let xml = render("kube-devops analyze", &suites);
+        assert_eq!(
+            xml,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"kube-devops analyze\" tests=\"2\" failures=\"1\" errors=\"0\">\n\
+             \x20 <testsuite name=\"prod\" tests=\"2\" failures=\"1\" errors=\"0\" skipped=\"0\">\n\
+             \x20   <testcase classname=\"prod\" name=\"latest_tag\">\n\
+             \x20     <failure message=\"1 violation(s)\" type=\"high\">prod/web&lt;1&gt; [main]: container &apos;main&apos; latest_tag\n</failure>\n\
+             \x20   </testcase>\n\
+             \x20   <testcase classname=\"prod\" name=\"missing_liveness\"/>\n\
+             \x20 </testsuite>\n\
+             </testsuites>\n"
+        );
+    }
+
+    #[test]
+    fn test_escape_drops_control_characters() {
+        assert_eq!(escape("a\u{1b}[31mb\tc"), "a[31mb\tc");
+    }
+}
@@ -1,6 +1,18 @@
 pub mod admission;
+pub mod api;
 pub mod bundles;
+pub mod clock;
+pub mod conversion;
 pub mod crd;
 pub mod enforcement;
 pub mod governance;
+pub mod junit;
+pub mod lint;
 pub mod multi_cluster;
+pub mod notifications;
+pub mod output;
+pub mod policy_diff;
+pub mod policy_resolution;
+pub mod policy_validation;
+pub mod quarantine;
+pub mod rbac;
@@ -0,0 +1,308 @@
+use k8s_openapi::api::core::v1::{Pod, PodSpec};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use serde::Serialize;
+
+use crate::admission::build_admission_policy_for_validation;
+use crate::crd::DevOpsPolicySpec;
+use crate::governance::{self, ViolationDetail};
+use crate::output;
+
+/* ============================= TYPES ============================= */
+
+/// A workload manifest found in a local YAML file.
+#[derive(Debug, Clone)]
+pub struct ManifestWorkload {
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    /// Synthetic pod built from the workload's pod template.
+    pub pod: Pod,
+}
+
+/// Violations found for one workload manifest.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LintFinding {
+    pub file: String,
+    pub kind: String,
+    pub name: String,
+    pub namespace: String,
+    pub violations: Vec<ViolationDetail>,
+}
+
+/* ============================= MANIFEST PARSING ============================= */
+
+/// Location of the pod spec within a manifest of the given kind.
+fn pod_spec_pointer(kind: &str) -> Option<&'static str> {
+    match kind {
+        "Pod" => Some("/spec"),
+        "Deployment" | "StatefulSet" | "DaemonSet" | "ReplicaSet" | "Job" => {
+            Some("/spec/template/spec")
+        }
+        "CronJob" => Some("/spec/jobTemplate/spec/template/spec"),
+        _ => None,
+    }
+}
+
+/// Extract workloads from a multi-document YAML string.
+///
+/// Documents of unsupported kinds (Services, ConfigMaps, ...) are skipped.
+pub fn parse_workloads(content: &str) -> anyhow::Result<Vec<ManifestWorkload>> {
+    let mut workloads = Vec::new();
+
+    for value in output::yaml_documents(content)? {
+        let json = serde_json::to_value(&value)?;
+
+        let kind = json["kind"].as_str().unwrap_or_default().to_string();
+        let Some(pointer) = pod_spec_pointer(&kind) else {
+            continue;
+        };
+        let Some(spec_value) = json.pointer(pointer) else {
+            continue;
+        };
+
+        let name = json["metadata"]["name"]
+            .as_str()
+            .unwrap_or("unnamed")
+            .to_string();
+        let namespace = json["metadata"]["namespace"]
+            .as_str()
+            .unwrap_or("default")
+            .to_string();
+        let spec: PodSpec = serde_json::from_value(spec_value.clone())
+            .map_err(|e| anyhow::anyhow!("{kind}/{name}: invalid pod spec: {e}"))?;
+
+        // Template annotations carry per-container skip-checks exemptions
+        let annotations_pointer = format!(
+            "{}metadata/annotations",
+            pointer.strip_suffix("spec").unwrap_or_default()
+        );
+        let annotations = json
+            .pointer(&annotations_pointer)
+            .and_then(|a| serde_json::from_value(a.clone()).ok());
+
+        let pod = Pod {
+            metadata: ObjectMeta {
+                name: Some(name.clone()),
+                namespace: Some(namespace.clone()),
+                annotations,
+                ..Default::default()
+            },
+            spec: Some(spec),
+            status: None,
+        };
+
+        workloads.push(ManifestWorkload {
+            kind,
+            name,
+            namespace,
+            pod,
+        });
+    }
+
+    Ok(workloads)
+}
+
+/// Load a policy spec from a YAML file containing a `DevOpsPolicy` document.
+///
+/// A bare spec (without `apiVersion`/`kind`) is also accepted.
+pub fn parse_policy(content: &str) -> anyhow::Result<DevOpsPolicySpec> {
+    for value in output::yaml_documents(content)? {
+        match value.get("kind").and_then(|k| k.as_str()) {
+            Some("DevOpsPolicy") => {
+                let spec = value.get("spec").cloned().unwrap_or_default();
+                return Ok(serde_yaml::from_value(spec)?);
+            }
+            Some(_) => continue,
+            None => return Ok(serde_yaml::from_value(value)?),
+        }
+    }
+    anyhow::bail!("No DevOpsPolicy found in policy file")
+}
+
+/* ============================= LINTING ============================= */
+
+/// Evaluate manifest workloads against a policy.
+///
+/// Manifests have no runtime status, so runtime-only checks (restart count,
+/// pending duration) are disabled, matching admission-time validation.
+pub fn lint_workloads(
+    file: &str,
+    workloads: &[ManifestWorkload],
+    policy: &DevOpsPolicySpec,
+) -> Vec<LintFinding> {
+    let static_policy = build_admission_policy_for_validation(policy);
+
+    workloads
+        .iter()
+        .map(|w| LintFinding {
+            file: file.to_string(),
+            kind: w.kind.clone(),
+            name: w.name.clone(),
+            namespace: w.namespace.clone(),
+            violations: governance::detect_violations_detailed(&w.pod, &static_policy),
+        })
+        .collect()
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFESTS: &str = r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+  namespace: prod
+spec:
+  selector:
+    matchLabels: {app: web}
+  template:
+    metadata:
+      labels: {app: web}
+    spec:
+      containers:
+        - name: nginx
+          image: nginx:latest
+---
+apiVersion: v1
+kind: Service
+metadata:
+  name: web
+spec:
+  ports: [{port: 80}]
+---
+apiVersion: batch/v1
+kind: CronJob
+metadata:
+  name: nightly
+spec:
+  schedule: "0 0 * * *"
+  jobTemplate:
+    spec:
+      template:
+        spec:
+          restartPolicy: OnFailure
+          containers:
+            - name: job
+              image: busybox:1.36
+              livenessProbe: {exec: {command: ["true"]}}
+              readinessProbe: {exec: {command: ["true"]}}
+---
+apiVersion: v1
+kind: Pod
+metadata:
+  name: debug
+spec:
+  containers:
+    - name: shell
+      image: busybox:1.36
+"#;
+
+    fn strict_policy() -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            require_liveness_probe: Some(true),
+            require_readiness_probe: Some(true),
+            max_restart_count: Some(3),
+            forbid_pending_duration: Some(300),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_workloads_supported_kinds() {
+        let workloads = parse_workloads(MANIFESTS).unwrap();
+        let kinds: Vec<&str> = workloads.iter().map(|w| w.kind.as_str()).collect();
+        assert_eq!(kinds, vec!["Deployment", "CronJob", "Pod"]);
+        assert_eq!(workloads[0].namespace, "prod");
+        assert_eq!(workloads[1].namespace, "default");
+    }
+
+    #[test]
+    fn test_parse_workloads_invalid_pod_spec() {
+        let yaml = "kind: Pod\nmetadata: {name: x}\nspec: {containers: 5}\n";
+        assert!(parse_workloads(yaml).is_err());
+    }
+
+    #[test]
+    fn test_lint_reports_template_violations() {
+        let workloads = parse_workloads(MANIFESTS).unwrap();
+        let findings = lint_workloads("app.yaml", &workloads, &strict_policy());
+
+        let deploy = &findings[0];
+        assert_eq!(deploy.file, "app.yaml");
+        assert_eq!(deploy.violations.len(), 3);
+        assert_eq!(deploy.violations[0].violation_type, "latest_tag");
+
+        let cronjob = &findings[1];
+        assert!(cronjob.violations.is_empty());
+
+        let pod = &findings[2];
+        assert_eq!(pod.violations.len(), 2);
+    }
+
+    #[test]
+    fn test_lint_skips_runtime_checks() {
+        let workloads = parse_workloads(MANIFESTS).unwrap();
+        let findings = lint_workloads("app.yaml", &workloads, &strict_policy());
+        assert!(
+            findings
+                .iter()
+                .flat_map(|f| &f.violations)
+                .all(|v| v.violation_type != "pending" && v.violation_type != "high_restarts")
+        );
+    }
+
+    #[test]
+    fn test_lint_honors_template_skip_checks() {
+        let yaml = r#"
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: web
+spec:
+  template:
+    metadata:
+      annotations:
+        devops.stochastic.io/skip-checks.agent: missing_liveness,missing_readiness
+    spec:
+      containers:
+        - name: agent
+          image: vendor/agent:2.1
+"#;
+        let workloads = parse_workloads(yaml).unwrap();
+        let findings = lint_workloads("app.yaml", &workloads, &strict_policy());
+        assert!(findings[0].violations.is_empty());
+    }
+
+    #[test]
+    fn test_parse_policy_from_custom_resource() {
+        let yaml = r#"
+apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: p
+spec:
+  forbidLatestTag: true
+"#;
+        let spec = parse_policy(yaml).unwrap();
+        assert_eq!(spec.forbid_latest_tag, Some(true));
+        assert_eq!(spec.require_liveness_probe, None);
+    }
+
+    #[test]
+    fn test_parse_policy_bare_spec() {
+        let spec = parse_policy("requireLivenessProbe: true\n").unwrap();
+        assert_eq!(spec.require_liveness_probe, Some(true));
+    }
+
+    #[test]
+    fn test_parse_policy_missing() {
+        let yaml = "apiVersion: v1\nkind: ConfigMap\nmetadata: {name: x}\n";
+        assert!(parse_policy(yaml).is_err());
+    }
+}
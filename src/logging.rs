@@ -0,0 +1,170 @@
+//! Log output: line format, destination, and filter of the fmt layer.
+
+use std::fs::OpenOptions;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing::Subscriber;
+use tracing_subscriber::filter::Directive;
+use tracing_subscriber::fmt::writer::BoxMakeWriter;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{EnvFilter, Layer, fmt};
+
+/* ============================= SETTINGS ============================= */
+
+/// Log line format. `json` suits in-cluster log collectors; `pretty` and
+/// `compact` are for reading logs in a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum LogFormat {
+    #[default]
+    Json,
+    Pretty,
+    Compact,
+}
+
+/// Resolved logging settings, see `Settings::logging`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LoggingSettings {
+    /// Base filter when `RUST_LOG` is unset.
+    pub(crate) level: String,
+    /// Directives applied on top of the base filter (or `RUST_LOG`).
+    pub(crate) filters: Vec<String>,
+    pub(crate) format: LogFormat,
+    /// Append logs here instead of writing them to stdout.
+    pub(crate) file: Option<PathBuf>,
+}
+
+impl Default for LoggingSettings {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+            filters: Vec::new(),
+            format: LogFormat::Json,
+            file: None,
+        }
+    }
+}
+
+/// Parse one `target=level` override.
+pub(crate) fn parse_directive(directive: &str) -> Result<Directive> {
+    directive
+        .parse()
+        .with_context(|| format!("'{directive}' is not a valid log filter"))
+}
+
+/* ============================= LAYERS ============================= */
+
+impl LoggingSettings {
+    /// `RUST_LOG` (or `level`), with `filters` overriding per target.
+    pub(crate) fn filter(&self) -> Result<EnvFilter> {
+        let base =
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(&self.level));
+        self.filters.iter().try_fold(base, |filter, directive| {
+            Ok(filter.add_directive(parse_directive(directive)?))
+        })
+    }
+
+    /// The fmt layer for `format`, writing to `file` or stdout. Colours are
+    /// only used on a terminal.
+    pub(crate) fn layer<S>(&self) -> Result<Box<dyn Layer<S> + Send + Sync>>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let (writer, ansi) = match &self.file {
+            Some(path) => {
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| format!("Failed to open log file {}", path.display()))?;
+                (BoxMakeWriter::new(Mutex::new(file)), false)
+            }
+            None => (
+                BoxMakeWriter::new(std::io::stdout),
+                std::io::stdout().is_terminal(),
+            ),
+        };
+        let layer = fmt::layer()
+            .with_writer(writer)
+            .with_ansi(ansi)
+            .with_target(false);
+        Ok(match self.format {
+            LogFormat::Json => layer.json().with_current_span(true).boxed(),
+            LogFormat::Pretty => layer.pretty().boxed(),
+            LogFormat::Compact => layer.compact().boxed(),
+        })
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn test_parse_directive() {
+        assert!(parse_directive("kube=warn").is_ok());
+        assert!(parse_directive("kube_devops::commands::reconcile=debug").is_ok());
+        let err = parse_directive("kube=[").unwrap_err();
+        assert!(err.to_string().contains("kube=["));
+    }
+
+    #[test]
+    fn test_filters_override_level() {
+        let settings = LoggingSettings {
+            level: "info".to_string(),
+            filters: vec!["kube=warn".to_string(), "hyper=error".to_string()],
+            ..Default::default()
+        };
+        let filter = settings.filter().unwrap().to_string();
+        assert!(filter.contains("kube=warn"), "{filter}");
+        assert!(filter.contains("hyper=error"), "{filter}");
+
+        let invalid = LoggingSettings {
+            filters: vec!["kube=[".to_string()],
+            ..Default::default()
+        };
+        assert!(invalid.filter().is_err());
+    }
+
+    #[test]
+    fn test_log_file_is_appended() {
+        let path = std::env::temp_dir().join(format!("kube-devops-log-{}.log", std::process::id()));
+        std::fs::write(&path, "existing\n").unwrap();
+        let settings = LoggingSettings {
+            format: LogFormat::Compact,
+            file: Some(path.clone()),
+            ..Default::default()
+        };
+        let layer = settings.layer::<tracing_subscriber::Registry>().unwrap();
+        let subscriber = tracing_subscriber::Registry::default().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(policy = "web", "status_updated");
+        });
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(contents.starts_with("existing\n"));
+        assert!(contents.contains("status_updated"));
+        assert!(contents.contains("policy=\"web\""));
+    }
+
+    #[test]
+    fn test_log_file_open_error() {
+        let settings = LoggingSettings {
+            file: Some(PathBuf::from("/nonexistent/dir/kube-devops.log")),
+            ..Default::default()
+        };
+        let err = settings
+            .layer::<tracing_subscriber::Registry>()
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("/nonexistent/dir/kube-devops.log"));
+    }
+}
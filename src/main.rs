@@ -1,19 +1,26 @@
 use clap::Parser;
 use tracing_subscriber::prelude::*;
-use tracing_subscriber::{EnvFilter, fmt};
 
 mod cli;
 mod commands;
+mod logging;
+mod otlp;
+mod settings;
 
 use cli::{
-    Cli, Commands, CrdAction, DeployAction, MultiClusterAction, ObservabilityAction, PolicyAction,
-    WebhookAction,
+    AuditAction, Cli, Commands, CrdAction, DeployAction, DevtoolsAction, EnforceAction,
+    MultiClusterAction, ObservabilityAction, PolicyAction, ScoreAction, WebhookAction,
+};
+use commands::watch::NamespaceFilter;
+use settings::{
+    ClientConfig, LoggingConfig, ReconcileConfig, Settings, WatchConfig, WebhookConfig,
 };
 
 /// Wrap an async command so Ctrl+C produces a clean shutdown message.
 ///
-/// Used for short-lived commands (check, list, analyze, crd install) that
-/// make API calls which may hang when the cluster is unreachable.
+/// Used for short-lived commands (check, list, analyze, crd install) whose
+/// API calls may take a while to time out and retry against an unreachable
+/// cluster.
 /// Long-running commands (watch, reconcile) handle Ctrl+C internally.
 async fn interruptible<F: std::future::Future<Output = anyhow::Result<()>>>(
     task: F,
@@ -29,36 +36,259 @@ async fn interruptible<F: std::future::Future<Output = anyhow::Result<()>>>(
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    let settings = match &cli.config {
+        Some(path) => Settings::load(path)?,
+        None => Settings::default(),
+    };
+
+    let (otlp_layer, otlp_exporter) = match cli.otlp_endpoint.as_deref() {
+        Some(endpoint) => {
+            let (layer, exporter) = otlp::init(endpoint)?;
+            (Some(layer), Some(exporter))
+        }
+        None => (None, None),
+    };
+
+    let logging = settings.logging(&LoggingConfig {
+        level: None,
+        filters: cli.log_filter.clone(),
+        format: cli.log_format,
+        file: cli.log_file.clone(),
+    });
     tracing_subscriber::registry()
-        .with(
-            fmt::layer()
-                .json()
-                .with_current_span(true)
-                .with_target(false),
-        )
-        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with(logging.layer()?)
+        .with(otlp_layer)
+        .with(logging.filter()?)
         .init();
 
-    let cli = Cli::parse();
+    let result = dispatch(cli, settings).await;
+    if let Some(exporter) = otlp_exporter {
+        exporter.shutdown().await;
+    }
+    result
+}
+
+async fn dispatch(cli: Cli, settings: Settings) -> anyhow::Result<()> {
+    let client = settings.client(&ClientConfig {
+        request_timeout_seconds: cli.request_timeout,
+        max_retries: cli.max_retries,
+        ..Default::default()
+    });
+    commands::client::configure(
+        commands::client::Connection {
+            kubeconfig: cli.kubeconfig.clone(),
+            context: cli.context.clone(),
+            namespace: cli.namespace.clone(),
+        },
+        client,
+    );
+    // Namespaced commands that need one fall back to `default`, like kubectl
+    let namespace = || {
+        cli.namespace
+            .clone()
+            .unwrap_or_else(|| "default".to_string())
+    };
+    // generate-load and clean-load keep their pods out of real namespaces
+    let load_namespace = || {
+        cli.namespace
+            .clone()
+            .unwrap_or_else(|| commands::devtools::LOAD_NAMESPACE.to_string())
+    };
 
     match cli.command {
         // Instant, synchronous — no Ctrl+C handling needed
-        Commands::Version => commands::version::run()?,
+        Commands::Version {
+            check_update: false,
+            ..
+        } => commands::version::run()?,
         Commands::Crd {
-            action: CrdAction::Generate,
-        } => commands::crd::generate()?,
+            action:
+                CrdAction::Generate {
+                    conversion_ca_bundle_path,
+                    conversion_service_name,
+                },
+        } => {
+            let conversion = conversion_ca_bundle_path
+                .map(|path| {
+                    commands::crd::ConversionWebhook::load(
+                        &conversion_service_name,
+                        &namespace(),
+                        &path,
+                    )
+                })
+                .transpose()?;
+            commands::crd::generate(conversion.as_ref())?
+        }
+        Commands::Lint { path, policy } => {
+            commands::lint::run(&path, policy.as_deref(), cli.output)?
+        }
 
         // Long-running — handle Ctrl+C internally with their own shutdown logic
-        Commands::Watch => commands::watch::run().await?,
-        Commands::Reconcile => commands::reconcile::run().await?,
+        Commands::Watch {
+            all_namespaces,
+            include_namespaces,
+            exclude_namespaces,
+            pod_detail_capacity,
+            resync_interval,
+        } => {
+            let flags = WatchConfig {
+                namespace: cli.namespace.clone(),
+                namespaces: NamespaceFilter {
+                    include: include_namespaces,
+                    exclude: exclude_namespaces,
+                },
+                pod_detail_capacity: pod_detail_capacity.map(|n| n as usize),
+                resync_interval_seconds: resync_interval,
+                ..Default::default()
+            };
+            let mut watch = settings.watch(&flags);
+            if all_namespaces {
+                watch.namespace = None;
+            }
+            commands::watch::run(cli.read_only, watch).await?
+        }
+        Commands::Reconcile {
+            requeue_interval,
+            metrics_addr,
+            audit_retention,
+            audit_gc_interval,
+            list_page_size,
+            pod_event_debounce,
+        } => {
+            let flags = ReconcileConfig {
+                requeue_interval_seconds: requeue_interval,
+                metrics_addr,
+                audit_retention: audit_retention.map(|n| n as usize),
+                audit_gc_interval_seconds: audit_gc_interval,
+                list_page_size,
+                pod_event_debounce_seconds: pod_event_debounce,
+                audit_sink: None,
+            };
+            commands::reconcile::run(cli.read_only, cli.timezone, settings.reconcile(&flags))
+                .await?
+        }
 
         // Short-lived async — wrap with interruptible for graceful Ctrl+C
-        Commands::Check => interruptible(commands::check::run()).await?,
-        Commands::List { resource } => interruptible(commands::list::run(resource)).await?,
-        Commands::Analyze => interruptible(commands::analyze::run()).await?,
+        Commands::Version {
+            check_update: true,
+            release_url,
+            offline,
+            release_file,
+        } => {
+            interruptible(commands::version::check_update(
+                &release_url,
+                offline,
+                release_file.as_deref(),
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::Check {
+            verbose,
+            operator_namespace,
+        } => {
+            let options = commands::check::CheckOptions {
+                verbose,
+                operator_namespace,
+            };
+            interruptible(commands::check::run(cli.output, &options)).await?
+        }
+        Commands::Doctor { operator_namespace } => {
+            interruptible(commands::doctor::run(&operator_namespace, cli.output)).await?
+        }
+        Commands::List {
+            resource,
+            selector,
+            field_selector,
+            wide,
+        } => {
+            let options = commands::list::ListOptions {
+                selector,
+                field_selector,
+                wide,
+            };
+            interruptible(commands::list::run(
+                resource,
+                cli.namespace.as_deref(),
+                &options,
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::Analyze { trend: true, .. } => {
+            interruptible(commands::analyze::run_trend(cli.output)).await?
+        }
+        Commands::Analyze {
+            fail_on,
+            min_score,
+            severity,
+            violation_type,
+            top,
+            sort,
+            policy_file,
+            bundle,
+            report,
+            ..
+        } => {
+            let gate = commands::analyze::Gate { fail_on, min_score };
+            let filter = commands::analyze::AnalyzeFilter {
+                namespace: cli.namespace.clone(),
+                severity,
+                violation_types: violation_type,
+                top: top.map(|n| n as usize),
+                sort,
+            };
+            let policy =
+                commands::analyze::AnalysisPolicy::load(policy_file.as_deref(), bundle.as_deref())?;
+            interruptible(commands::analyze::run(
+                cli.output,
+                &gate,
+                &filter,
+                policy.as_ref(),
+                report.as_deref(),
+            ))
+            .await?
+        }
+        Commands::Crd {
+            action:
+                CrdAction::Install {
+                    conversion_ca_bundle_path,
+                    conversion_service_name,
+                },
+        } => {
+            if cli.read_only {
+                anyhow::bail!("crd install writes CRDs; it cannot run with --read-only");
+            }
+            let conversion = conversion_ca_bundle_path
+                .map(|path| {
+                    commands::crd::ConversionWebhook::load(
+                        &conversion_service_name,
+                        &namespace(),
+                        &path,
+                    )
+                })
+                .transpose()?;
+            interruptible(commands::crd::install(conversion.as_ref())).await?
+        }
         Commands::Crd {
-            action: CrdAction::Install,
-        } => interruptible(commands::crd::install()).await?,
+            action: CrdAction::MigrateStorage,
+        } => {
+            if cli.read_only {
+                anyhow::bail!(
+                    "crd migrate-storage rewrites objects; it cannot run with --read-only"
+                );
+            }
+            interruptible(commands::crd::migrate_storage()).await?
+        }
+        Commands::Crd {
+            action: CrdAction::Uninstall { purge },
+        } => {
+            if cli.read_only {
+                anyhow::bail!("crd uninstall deletes CRDs; it cannot run with --read-only");
+            }
+            interruptible(commands::crd::uninstall(purge)).await?
+        }
 
         // Webhook subcommands
         Commands::Webhook {
@@ -67,25 +297,56 @@ async fn main() -> anyhow::Result<()> {
                     addr,
                     tls_cert,
                     tls_key,
+                    failure_mode,
+                    timeout_seconds,
+                    min_deny_severity,
                 },
-        } => commands::webhook::serve(&addr, &tls_cert, &tls_key).await?,
+        } => {
+            let flags = WebhookConfig {
+                addr,
+                tls_cert,
+                tls_key,
+                failure_mode,
+                timeout_seconds,
+                min_deny_severity,
+            };
+            commands::webhook::serve(settings.webhook_serve(&flags), cli.read_only).await?
+        }
         Commands::Webhook {
             action:
                 WebhookAction::CertGenerate {
                     service_name,
-                    namespace,
                     output_dir,
                     ip_sans,
                 },
-        } => commands::webhook::generate_certs(&service_name, &namespace, &output_dir, &ip_sans)?,
+        } => commands::webhook::generate_certs(&service_name, &namespace(), &output_dir, &ip_sans)?,
         Commands::Webhook {
             action:
                 WebhookAction::InstallConfig {
                     service_name,
-                    namespace,
                     ca_bundle_path,
+                    failure_mode,
+                    timeout_seconds,
+                    apply,
+                    dry_run,
                 },
-        } => commands::webhook::install_config(&service_name, &namespace, &ca_bundle_path)?,
+        } => {
+            if cli.read_only && apply && !dry_run {
+                anyhow::bail!(
+                    "webhook install-config --apply writes to the cluster; it cannot run with --read-only"
+                );
+            }
+            interruptible(commands::webhook::install_config(
+                &service_name,
+                &namespace(),
+                &ca_bundle_path,
+                failure_mode,
+                timeout_seconds,
+                apply,
+                dry_run,
+            ))
+            .await?
+        }
 
         // Observability subcommands
         Commands::Observability {
@@ -99,55 +360,207 @@ async fn main() -> anyhow::Result<()> {
             print!("{}", commands::observability::generate_service_monitors())
         }
         Commands::Observability {
-            action: ObservabilityAction::GenerateDashboard,
+            action:
+                ObservabilityAction::GenerateDashboard {
+                    healthy_threshold,
+                    stable_threshold,
+                    degraded_threshold,
+                },
         } => {
+            let defaults = kube_devops::governance::ClassificationThresholds::default();
+            let thresholds = kube_devops::governance::ClassificationThresholds {
+                healthy: healthy_threshold.unwrap_or(defaults.healthy),
+                stable: stable_threshold.unwrap_or(defaults.stable),
+                degraded: degraded_threshold.unwrap_or(defaults.degraded),
+            };
+            if !thresholds.is_ordered() {
+                anyhow::bail!(
+                    "Dashboard thresholds must descend from healthy to degraded, got {}/{}/{}",
+                    thresholds.healthy,
+                    thresholds.stable,
+                    thresholds.degraded
+                );
+            }
             print!(
                 "{}",
-                commands::observability::generate_grafana_dashboard_configmap()
+                commands::observability::generate_grafana_dashboard_configmap(thresholds)
             )
         }
-
-        // Deploy subcommands
-        Commands::Deploy {
-            action: DeployAction::GenerateAll,
-        } => {
-            print!("{}", commands::deploy::generate_all())
-        }
-        Commands::Deploy {
-            action: DeployAction::GenerateRbac,
+        Commands::Observability {
+            action:
+                ObservabilityAction::GenerateAlerts {
+                    score_threshold,
+                    denial_rate,
+                },
         } => {
-            print!("{}", commands::deploy::generate_rbac())
+            let defaults = commands::observability::AlertThresholds::default();
+            let thresholds = commands::observability::AlertThresholds {
+                score: score_threshold.unwrap_or(defaults.score),
+                denial_rate: denial_rate.unwrap_or(defaults.denial_rate),
+            };
+            print!(
+                "{}",
+                commands::observability::generate_alert_rules(thresholds)
+            )
         }
+
+        // Deploy subcommands
         Commands::Deploy {
-            action: DeployAction::GenerateDeployments,
+            action,
+            image,
+            replicas,
+            cpu_request,
+            memory_request,
+            cpu_limit,
+            memory_limit,
         } => {
-            print!("{}", commands::deploy::generate_deployments())
+            let opts = commands::deploy::DeployOptions {
+                namespace: cli
+                    .namespace
+                    .clone()
+                    .unwrap_or_else(|| commands::deploy::DEFAULT_NAMESPACE.to_string()),
+                image,
+                replicas,
+                resources: commands::deploy::Resources {
+                    cpu_request,
+                    memory_request,
+                    cpu_limit,
+                    memory_limit,
+                },
+            };
+            match action {
+                DeployAction::GenerateAll => print!("{}", commands::deploy::generate_all(&opts)),
+                DeployAction::GenerateRbac => print!("{}", commands::deploy::generate_rbac(&opts)),
+                DeployAction::GenerateDeployments => {
+                    print!("{}", commands::deploy::generate_deployments(&opts))
+                }
+                DeployAction::Diff => interruptible(commands::deploy::diff(&opts)).await?,
+                DeployAction::Apply { component, prune } => {
+                    if cli.read_only {
+                        anyhow::bail!(
+                            "deploy apply writes to the cluster; it cannot run with --read-only"
+                        );
+                    }
+                    interruptible(commands::deploy::apply(&opts, component, prune)).await?
+                }
+                DeployAction::GenerateKustomize { output_dir } => {
+                    commands::deploy::generate_kustomize(&opts, &output_dir)?
+                }
+            }
         }
 
         // Policy subcommands
         Commands::Policy {
-            action: PolicyAction::BundleList,
-        } => commands::policy::bundle_list()?,
+            action:
+                PolicyAction::BundleList {
+                    bundle_dir,
+                    bundle_configmap,
+                },
+        } => {
+            let bundles = commands::policy::load_bundles(
+                bundle_dir.as_deref(),
+                bundle_configmap.as_deref(),
+                &namespace(),
+            )
+            .await?;
+            commands::policy::bundle_list(&bundles)?
+        }
         Commands::Policy {
-            action: PolicyAction::BundleShow { name },
-        } => commands::policy::bundle_show(&name)?,
+            action:
+                PolicyAction::BundleShow {
+                    name,
+                    bundle_dir,
+                    bundle_configmap,
+                },
+        } => {
+            let bundles = commands::policy::load_bundles(
+                bundle_dir.as_deref(),
+                bundle_configmap.as_deref(),
+                &namespace(),
+            )
+            .await?;
+            commands::policy::bundle_show(&bundles, &name)?
+        }
         Commands::Policy {
             action:
                 PolicyAction::BundleApply {
                     name,
-                    namespace,
                     policy_name,
+                    bundle_dir,
+                    bundle_configmap,
                 },
-        } => commands::policy::bundle_apply(&name, &namespace, &policy_name)?,
+        } => {
+            let bundles = commands::policy::load_bundles(
+                bundle_dir.as_deref(),
+                bundle_configmap.as_deref(),
+                &namespace(),
+            )
+            .await?;
+            commands::policy::bundle_apply(&bundles, &name, &namespace(), &policy_name)?
+        }
         Commands::Policy {
-            action: PolicyAction::Export { namespace },
-        } => interruptible(commands::policy::export(&namespace)).await?,
+            action: PolicyAction::BundleCreate { policy_name },
+        } => interruptible(commands::policy::bundle_create(&namespace(), &policy_name)).await?,
         Commands::Policy {
-            action: PolicyAction::Import { file, dry_run },
-        } => interruptible(commands::policy::import(&file, dry_run)).await?,
+            action: PolicyAction::Export,
+        } => interruptible(commands::policy::export(&namespace())).await?,
+        Commands::Policy {
+            action:
+                PolicyAction::Import {
+                    file,
+                    dry_run,
+                    prune,
+                },
+        } => {
+            if cli.read_only && !dry_run {
+                anyhow::bail!(
+                    "policy import writes DevOpsPolicies; it cannot run with --read-only"
+                );
+            }
+            interruptible(commands::policy::import(&file, dry_run, prune)).await?
+        }
         Commands::Policy {
             action: PolicyAction::Diff { file },
-        } => interruptible(commands::policy::diff(&file)).await?,
+        } => interruptible(commands::policy::diff(&file, cli.output)).await?,
+        Commands::Policy {
+            action:
+                PolicyAction::DriftWatch {
+                    file,
+                    interval,
+                    addr,
+                    revert,
+                },
+        } => {
+            if revert && cli.read_only {
+                anyhow::bail!(
+                    "policy drift-watch --revert writes DevOpsPolicies; it cannot run with --read-only"
+                );
+            }
+            commands::drift::drift_watch(&file, &interval, addr, revert).await?
+        }
+        Commands::Policy {
+            action: PolicyAction::Effective,
+        } => interruptible(commands::policy::effective(&namespace(), cli.output)).await?,
+        Commands::Policy {
+            action: PolicyAction::Validate { file },
+        } => commands::policy::validate(&file, cli.output)?,
+        Commands::Policy {
+            action:
+                PolicyAction::Canary {
+                    file,
+                    duration,
+                    interval,
+                },
+        } => {
+            interruptible(commands::canary::canary(
+                &file,
+                cli.namespace.as_deref(),
+                &duration,
+                &interval,
+                cli.output,
+            ))
+            .await?
+        }
 
         // Multi-cluster subcommands
         Commands::MultiCluster {
@@ -159,12 +572,240 @@ async fn main() -> anyhow::Result<()> {
                     contexts,
                     bundle,
                     per_cluster,
+                    compact,
+                    fail_below_score,
+                    fail_below_cluster_score,
+                    compare,
+                    last_run_file,
                 },
         } => {
+            let thresholds = commands::multi_cluster::Thresholds {
+                fail_below_score,
+                fail_below_cluster_score,
+            };
             interruptible(commands::multi_cluster::analyze(
                 contexts,
                 bundle,
                 per_cluster,
+                compact,
+                &thresholds,
+                compare.then_some(last_run_file.as_path()),
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::MultiCluster {
+            action:
+                MultiClusterAction::Apply {
+                    file,
+                    contexts,
+                    dry_run,
+                },
+        } => {
+            if cli.read_only && !dry_run {
+                anyhow::bail!(
+                    "multi-cluster apply writes policies; it cannot run with --read-only"
+                );
+            }
+            interruptible(commands::multi_cluster::apply(
+                &file, contexts, dry_run, cli.output,
+            ))
+            .await?
+        }
+        Commands::MultiCluster {
+            action: MultiClusterAction::Watch { contexts, addr },
+        } => {
+            let watch = settings.watch(&WatchConfig {
+                addr,
+                ..Default::default()
+            });
+            commands::multi_cluster::watch(contexts, watch).await?
+        }
+
+        // Score subcommands
+        Commands::Score {
+            action: None,
+            all_namespaces,
+            below,
+        } => {
+            let namespace = (!all_namespaces).then(namespace);
+            interruptible(commands::score::score_namespaces(
+                namespace.as_deref(),
+                below,
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::Score {
+            action:
+                Some(ScoreAction::Simulate {
+                    weights,
+                    severity,
+                    caps,
+                    from_audit,
+                    policy,
+                }),
+            ..
+        } => {
+            let mut weights = match weights.as_deref() {
+                Some(w) => commands::score::parse_weights(w)?,
+                None => kube_devops::governance::ScoringWeights::default(),
+            };
+            if let Some(caps) = caps.as_deref() {
+                weights.caps = commands::score::parse_caps(caps)?;
+            }
+            let proposal = commands::score::Proposal {
+                weights,
+                severity: severity
+                    .as_deref()
+                    .map(commands::score::parse_severity)
+                    .transpose()?,
+            };
+            interruptible(commands::score::simulate_command(
+                &proposal,
+                from_audit.as_deref(),
+                &namespace(),
+                policy.as_deref(),
+                cli.output,
+            ))
+            .await?
+        }
+
+        // Enforce subcommands
+        Commands::Enforce {
+            action: EnforceAction::Rollback { workload, dry_run },
+        } => {
+            if cli.read_only && !dry_run {
+                anyhow::bail!("enforce rollback patches workloads; it cannot run with --read-only");
+            }
+            interruptible(commands::enforce::rollback(
+                &workload,
+                &namespace(),
+                dry_run,
+            ))
+            .await?
+        }
+        Commands::Enforce {
+            action: EnforceAction::Restore { workload, dry_run },
+        } => {
+            if cli.read_only && !dry_run {
+                anyhow::bail!("enforce restore scales workloads; it cannot run with --read-only");
+            }
+            interruptible(commands::enforce::restore(&workload, &namespace(), dry_run)).await?
+        }
+
+        // Devtools subcommands
+        Commands::Devtools {
+            action:
+                DevtoolsAction::GenerateLoad {
+                    pods,
+                    violation_rate,
+                    mix,
+                    kwok,
+                    concurrency,
+                    seed,
+                    dry_run,
+                },
+        } => {
+            if cli.read_only && !dry_run {
+                anyhow::bail!("generate-load creates pods; it cannot run with --read-only");
+            }
+            let opts = commands::devtools::LoadOptions {
+                pods,
+                violation_rate,
+                mix: commands::devtools::parse_mix(mix.as_deref())?,
+                namespace: load_namespace(),
+                kwok,
+                concurrency,
+                seed,
+            };
+            interruptible(commands::devtools::generate_load(&opts, dry_run)).await?
+        }
+        Commands::Devtools {
+            action: DevtoolsAction::CleanLoad,
+        } => {
+            if cli.read_only {
+                anyhow::bail!("clean-load deletes pods; it cannot run with --read-only");
+            }
+            interruptible(commands::devtools::clean_load(&load_namespace())).await?
+        }
+
+        // Audit subcommands
+        Commands::Audit {
+            action:
+                AuditAction::Search {
+                    violation_type,
+                    severity,
+                    pod_prefix,
+                    policy,
+                    latest,
+                },
+        } => {
+            let filter = commands::audit::AuditFilter {
+                violation_type,
+                severity,
+                pod_prefix,
+                policy,
+                latest,
+            };
+            interruptible(commands::audit::search_command(
+                cli.namespace.as_deref(),
+                &filter,
+                cli.timezone,
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::Audit {
+            action:
+                AuditAction::List {
+                    policy,
+                    severity,
+                    since,
+                    until,
+                },
+        } => {
+            let now = chrono::Utc::now();
+            let bound = |value: Option<String>| {
+                value
+                    .map(|v| commands::audit::parse_time_bound(&v, now))
+                    .transpose()
+            };
+            let filter = commands::audit::ListFilter {
+                policy,
+                severity,
+                since: bound(since)?,
+                until: bound(until)?,
+            };
+            interruptible(commands::audit::list_command(
+                cli.namespace.as_deref(),
+                &filter,
+                cli.timezone,
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::Audit {
+            action: AuditAction::Show { name, severity },
+        } => {
+            interruptible(commands::audit::show_command(
+                &namespace(),
+                &name,
+                severity.as_ref(),
+                cli.timezone,
+                cli.output,
+            ))
+            .await?
+        }
+        Commands::Audit {
+            action: AuditAction::Diff { from, to },
+        } => {
+            interruptible(commands::audit::diff_command(
+                &namespace(),
+                &from,
+                &to,
+                cli.timezone,
+                cli.output,
             ))
             .await?
         }
@@ -1,10 +1,15 @@
+use std::path::Path;
+
+use serde::Serialize;
+
 use crate::crd::DevOpsPolicySpec;
 use crate::governance::{self, ViolationDetail};
 
 /* ============================= TYPES ============================= */
 
 /// Evaluation result for a single cluster.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ClusterEvaluation {
     pub context_name: String,
     pub health_score: u32,
@@ -15,7 +20,8 @@ pub struct ClusterEvaluation {
 }
 
 /// Aggregated report across multiple clusters.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct MultiClusterReport {
     pub clusters: Vec<ClusterEvaluation>,
     pub aggregate_score: u32,
@@ -24,15 +30,27 @@ pub struct MultiClusterReport {
 
 /* ============================= KUBECONFIG UTILITIES ============================= */
 
+/// Read the kubeconfig at `path`, or from `KUBECONFIG` / `~/.kube/config`
+/// when it is `None`.
+pub fn read_kubeconfig(path: Option<&Path>) -> anyhow::Result<kube::config::Kubeconfig> {
+    Ok(match path {
+        Some(path) => kube::config::Kubeconfig::read_from(path)?,
+        None => kube::config::Kubeconfig::read()?,
+    })
+}
+
 /// List available kubeconfig contexts.
-pub fn list_contexts() -> anyhow::Result<Vec<String>> {
-    let kubeconfig = kube::config::Kubeconfig::read()?;
+pub fn list_contexts(kubeconfig: Option<&Path>) -> anyhow::Result<Vec<String>> {
+    let kubeconfig = read_kubeconfig(kubeconfig)?;
     Ok(kubeconfig.contexts.iter().map(|c| c.name.clone()).collect())
 }
 
 /// Create a kube Client for a specific kubeconfig context.
-pub async fn client_for_context(context: &str) -> anyhow::Result<kube::Client> {
-    let kubeconfig = kube::config::Kubeconfig::read()?;
+pub async fn client_for_context(
+    kubeconfig: Option<&Path>,
+    context: &str,
+) -> anyhow::Result<kube::Client> {
+    let kubeconfig = read_kubeconfig(kubeconfig)?;
     let config = kube::Config::from_custom_kubeconfig(
         kubeconfig,
         &kube::config::KubeConfigOptions {
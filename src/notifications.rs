@@ -0,0 +1,551 @@
+//! Policy notifications.
+//!
+//! The operator turns each reconcile into notification events — new
+//! violations at or above `notifications.minSeverity`, health score threshold
+//! crossings, and failed remediations — and drops events already sent within
+//! the policy's cooldown. This module decides *what* to send and renders it;
+//! delivery to Slack, webhooks, and SMTP lives in the operator.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::admission::severity_rank;
+use crate::crd::{NotificationChannel, NotificationChannelType, NotificationConfig, Severity};
+use crate::governance::WorkloadEvaluation;
+
+/* ============================= CONSTANTS ============================= */
+
+/// Seconds before the same event is sent again, unless the policy sets
+/// `notifications.cooldownSeconds`.
+pub const DEFAULT_COOLDOWN_SECONDS: u64 = 3600;
+
+/* ============================= EVENTS ============================= */
+
+/// Something a policy notifies about.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum NotificationEvent {
+    /// A violation at or above the policy's minimum severity.
+    Violation {
+        workload: String,
+        container: String,
+        violation_type: String,
+        severity: Severity,
+        message: String,
+    },
+    /// The health score dropped below `scoreThreshold`.
+    ScoreBelowThreshold {
+        previous: Option<u32>,
+        score: u32,
+        threshold: u32,
+    },
+    /// The health score recovered to `scoreThreshold` or above.
+    ScoreRecovered {
+        previous: u32,
+        score: u32,
+        threshold: u32,
+    },
+    /// An enforce-mode patch failed.
+    RemediationFailed { workload: String, error: String },
+}
+
+impl NotificationEvent {
+    /// Identity used for the cooldown: the same violation on the same
+    /// workload and container is one event, whatever its pod.
+    pub fn fingerprint(&self) -> String {
+        match self {
+            Self::Violation {
+                workload,
+                container,
+                violation_type,
+                ..
+            } => format!("violation/{workload}/{container}/{violation_type}"),
+            Self::ScoreBelowThreshold { threshold, .. } => format!("score-below/{threshold}"),
+            Self::ScoreRecovered { threshold, .. } => format!("score-recovered/{threshold}"),
+            Self::RemediationFailed { workload, .. } => format!("remediation-failed/{workload}"),
+        }
+    }
+
+    /// One-line description.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Violation {
+                workload,
+                container,
+                violation_type,
+                severity,
+                message,
+            } => format!(
+                "[{}] {workload} ({container}) {violation_type}: {message}",
+                severity_label(severity)
+            ),
+            Self::ScoreBelowThreshold {
+                previous,
+                score,
+                threshold,
+            } => match previous {
+                Some(previous) => format!(
+                    "Health score dropped from {previous} to {score} (threshold {threshold})"
+                ),
+                None => format!("Health score is {score}, below threshold {threshold}"),
+            },
+            Self::ScoreRecovered {
+                previous,
+                score,
+                threshold,
+            } => {
+                format!("Health score recovered from {previous} to {score} (threshold {threshold})")
+            }
+            Self::RemediationFailed { workload, error } => {
+                format!("Remediation of {workload} failed: {error}")
+            }
+        }
+    }
+}
+
+fn severity_label(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "CRITICAL",
+        Severity::High => "HIGH",
+        Severity::Medium => "MEDIUM",
+        Severity::Low => "LOW",
+    }
+}
+
+/// Violations at or above `notifications.minSeverity` (default `high`),
+/// one per workload, container, and type.
+pub fn violation_events(
+    config: &NotificationConfig,
+    workloads: &[WorkloadEvaluation],
+) -> Vec<NotificationEvent> {
+    let min_rank = severity_rank(config.min_severity.as_ref().unwrap_or(&Severity::High));
+    workloads
+        .iter()
+        .flat_map(|w| {
+            w.violations
+                .iter()
+                .filter(move |v| severity_rank(&v.severity) >= min_rank)
+                .map(|v| NotificationEvent::Violation {
+                    workload: w.workload.clone(),
+                    container: v.container_name.clone(),
+                    violation_type: v.violation_type.clone(),
+                    severity: v.severity.clone(),
+                    message: v.message.clone(),
+                })
+        })
+        .collect()
+}
+
+/// A threshold crossing between `previous` and `score`. Without a previous
+/// score, a score already below the threshold counts as a drop.
+pub fn score_event(
+    config: &NotificationConfig,
+    previous: Option<u32>,
+    score: u32,
+) -> Option<NotificationEvent> {
+    let threshold = config.score_threshold?;
+    match previous {
+        Some(previous) if previous >= threshold && score < threshold => {
+            Some(NotificationEvent::ScoreBelowThreshold {
+                previous: Some(previous),
+                score,
+                threshold,
+            })
+        }
+        Some(previous) if previous < threshold && score >= threshold => {
+            Some(NotificationEvent::ScoreRecovered {
+                previous,
+                score,
+                threshold,
+            })
+        }
+        None if score < threshold => Some(NotificationEvent::ScoreBelowThreshold {
+            previous: None,
+            score,
+            threshold,
+        }),
+        _ => None,
+    }
+}
+
+/// Failed remediations as `(workload, error)`, unless
+/// `notifications.remediationFailures` is `false`.
+pub fn remediation_failure_events(
+    config: &NotificationConfig,
+    failures: &[(String, String)],
+) -> Vec<NotificationEvent> {
+    if config.remediation_failures == Some(false) {
+        return Vec::new();
+    }
+    failures
+        .iter()
+        .map(|(workload, error)| NotificationEvent::RemediationFailed {
+            workload: workload.clone(),
+            error: error.clone(),
+        })
+        .collect()
+}
+
+/* ============================= COOLDOWN ============================= */
+
+/// When each policy's events may be sent again, by event fingerprint.
+#[derive(Debug, Default)]
+pub struct NotificationCooldown {
+    quiet_until: HashMap<String, DateTime<Utc>>,
+}
+
+impl NotificationCooldown {
+    /// Keep the events of `policy_key` that are not cooling down, and start
+    /// a `cooldown_seconds` cooldown for each of them at `now`.
+    pub fn admit(
+        &mut self,
+        policy_key: &str,
+        events: Vec<NotificationEvent>,
+        cooldown_seconds: u64,
+        now: DateTime<Utc>,
+    ) -> Vec<NotificationEvent> {
+        let until = now
+            .checked_add_signed(seconds(cooldown_seconds))
+            .unwrap_or(DateTime::<Utc>::MAX_UTC);
+        events
+            .into_iter()
+            .filter(|event| {
+                let key = format!("{policy_key}/{}", event.fingerprint());
+                let due = self.quiet_until.get(&key).is_none_or(|quiet| now >= *quiet);
+                if due {
+                    self.quiet_until.insert(key, until);
+                }
+                due
+            })
+            .collect()
+    }
+
+    /// Forget cooldowns that have ended.
+    pub fn prune(&mut self, now: DateTime<Utc>) {
+        self.quiet_until.retain(|_, quiet| now < *quiet);
+    }
+}
+
+/// `secs` as a chrono duration, saturating instead of panicking.
+fn seconds(secs: u64) -> chrono::Duration {
+    i64::try_from(secs)
+        .ok()
+        .and_then(chrono::Duration::try_seconds)
+        .unwrap_or(chrono::Duration::MAX)
+}
+
+/* ============================= RENDERING ============================= */
+
+/// A batch of events for one policy, as sent to every channel.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Notification {
+    pub policy: String,
+    pub namespace: String,
+    pub health_score: u32,
+    pub timestamp: String,
+    pub events: Vec<NotificationEvent>,
+}
+
+impl Notification {
+    /// Subject line, e.g. `kube-devops: prod/web-policy (score 62, 3 events)`.
+    pub fn subject(&self) -> String {
+        format!(
+            "kube-devops: {}/{} (score {}, {} event{})",
+            self.namespace,
+            self.policy,
+            self.health_score,
+            self.events.len(),
+            if self.events.len() == 1 { "" } else { "s" }
+        )
+    }
+
+    /// Plain-text body, one event per line.
+    pub fn text(&self) -> String {
+        let mut text = self.subject();
+        text.push('\n');
+        for event in &self.events {
+            text.push_str("\n- ");
+            text.push_str(&event.describe());
+        }
+        text
+    }
+
+    /// Slack incoming-webhook payload.
+    pub fn slack_payload(&self) -> serde_json::Value {
+        let lines: Vec<String> = self
+            .events
+            .iter()
+            .map(|e| format!("• {}", e.describe()))
+            .collect();
+        serde_json::json!({
+            "text": self.subject(),
+            "blocks": [
+                {
+                    "type": "section",
+                    "text": { "type": "mrkdwn", "text": format!("*{}*", self.subject()) }
+                },
+                {
+                    "type": "section",
+                    "text": { "type": "mrkdwn", "text": lines.join("\n") }
+                }
+            ]
+        })
+    }
+}
+
+/* ============================= VALIDATION ============================= */
+
+/// Problems with a policy's notification channels, e.g. a Slack channel
+/// without `url`. Empty when every channel can be delivered to.
+pub fn channel_errors(config: &NotificationConfig) -> Vec<String> {
+    config
+        .channels
+        .iter()
+        .flatten()
+        .enumerate()
+        .flat_map(|(index, channel)| {
+            missing_fields(channel)
+                .into_iter()
+                .map(move |field| format!("notifications.channels[{index}]: {field} is required"))
+        })
+        .collect()
+}
+
+/// Channels with every field their type needs.
+pub fn deliverable_channels(config: &NotificationConfig) -> Vec<&NotificationChannel> {
+    config
+        .channels
+        .iter()
+        .flatten()
+        .filter(|channel| missing_fields(channel).is_empty())
+        .collect()
+}
+
+fn missing_fields(channel: &NotificationChannel) -> Vec<&'static str> {
+    let blank = |value: &Option<String>| value.as_deref().is_none_or(str::is_empty);
+    match channel.channel_type {
+        NotificationChannelType::Slack | NotificationChannelType::Webhook => {
+            if blank(&channel.url) {
+                vec!["url"]
+            } else {
+                vec![]
+            }
+        }
+        NotificationChannelType::Smtp => {
+            let mut missing = Vec::new();
+            if blank(&channel.smtp_server) {
+                missing.push("smtpServer");
+            }
+            if blank(&channel.from) {
+                missing.push("from");
+            }
+            if channel.to.as_ref().is_none_or(Vec::is_empty) {
+                missing.push("to");
+            }
+            missing
+        }
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::governance::{PodMetrics, ViolationDetail};
+
+    fn workload(name: &str, violations: &[(&str, Severity)]) -> WorkloadEvaluation {
+        WorkloadEvaluation {
+            workload: name.to_string(),
+            namespace: "prod".to_string(),
+            replicas: 2,
+            metrics: PodMetrics::default(),
+            violations: violations
+                .iter()
+                .map(|(violation_type, severity)| ViolationDetail {
+                    violation_type: violation_type.to_string(),
+                    severity: severity.clone(),
+                    pod_name: format!("{name}-abc"),
+                    namespace: "prod".to_string(),
+                    container_name: "app".to_string(),
+                    message: format!("{violation_type} found"),
+                })
+                .collect(),
+        }
+    }
+
+    fn config(threshold: Option<u32>) -> NotificationConfig {
+        NotificationConfig {
+            score_threshold: threshold,
+            ..Default::default()
+        }
+    }
+
+    // ── Events ──
+
+    #[test]
+    fn test_violation_events_respect_min_severity() {
+        let workloads = [
+            workload(
+                "Deployment/web",
+                &[
+                    ("crash_loop", Severity::Critical),
+                    ("missing_liveness", Severity::Medium),
+                ],
+            ),
+            workload("Deployment/api", &[("latest_tag", Severity::High)]),
+        ];
+        let events = violation_events(&config(None), &workloads);
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0].fingerprint(),
+            "violation/Deployment/web/app/crash_loop"
+        );
+
+        let critical_only = NotificationConfig {
+            min_severity: Some(Severity::Critical),
+            ..Default::default()
+        };
+        assert_eq!(violation_events(&critical_only, &workloads).len(), 1);
+    }
+
+    #[test]
+    fn test_score_event_on_crossings_only() {
+        let cfg = config(Some(70));
+        assert!(matches!(
+            score_event(&cfg, Some(80), 60),
+            Some(NotificationEvent::ScoreBelowThreshold { score: 60, .. })
+        ));
+        assert!(matches!(
+            score_event(&cfg, Some(60), 75),
+            Some(NotificationEvent::ScoreRecovered { previous: 60, .. })
+        ));
+        assert_eq!(score_event(&cfg, Some(60), 55), None);
+        assert_eq!(score_event(&cfg, Some(90), 85), None);
+        assert!(score_event(&cfg, None, 40).is_some());
+        assert_eq!(score_event(&config(None), Some(90), 10), None);
+    }
+
+    #[test]
+    fn test_remediation_failures_can_be_disabled() {
+        let failures = vec![("Deployment/web".to_string(), "forbidden".to_string())];
+        assert_eq!(
+            remediation_failure_events(&config(None), &failures)[0].describe(),
+            "Remediation of Deployment/web failed: forbidden"
+        );
+        let disabled = NotificationConfig {
+            remediation_failures: Some(false),
+            ..Default::default()
+        };
+        assert!(remediation_failure_events(&disabled, &failures).is_empty());
+    }
+
+    // ── Cooldown ──
+
+    #[test]
+    fn test_cooldown_suppresses_repeats() {
+        let events = violation_events(
+            &config(None),
+            &[workload(
+                "Deployment/web",
+                &[("crash_loop", Severity::Critical)],
+            )],
+        );
+        let start = Utc::now();
+        let mut cooldown = NotificationCooldown::default();
+        assert_eq!(
+            cooldown.admit("prod/p", events.clone(), 600, start).len(),
+            1
+        );
+
+        let soon = start + chrono::Duration::seconds(30);
+        assert!(
+            cooldown
+                .admit("prod/p", events.clone(), 600, soon)
+                .is_empty()
+        );
+        assert_eq!(
+            cooldown
+                .admit("prod/other", events.clone(), 600, soon)
+                .len(),
+            1,
+            "cooldown is per policy"
+        );
+
+        let later = start + chrono::Duration::seconds(600);
+        assert_eq!(cooldown.admit("prod/p", events, 600, later).len(), 1);
+
+        cooldown.prune(later + chrono::Duration::seconds(600));
+        assert!(cooldown.quiet_until.is_empty());
+    }
+
+    // ── Rendering and validation ──
+
+    #[test]
+    fn test_notification_rendering() {
+        let notification = Notification {
+            policy: "web-policy".to_string(),
+            namespace: "prod".to_string(),
+            health_score: 62,
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+            events: remediation_failure_events(
+                &config(None),
+                &[("Deployment/web".to_string(), "conflict".to_string())],
+            ),
+        };
+        assert_eq!(
+            notification.subject(),
+            "kube-devops: prod/web-policy (score 62, 1 event)"
+        );
+        assert!(
+            notification
+                .text()
+                .contains("- Remediation of Deployment/web")
+        );
+        assert_eq!(
+            notification.slack_payload()["text"],
+            "kube-devops: prod/web-policy (score 62, 1 event)"
+        );
+        let json = serde_json::to_value(&notification).unwrap();
+        assert_eq!(json["events"][0]["kind"], "remediationFailed");
+        assert_eq!(json["healthScore"], 62);
+    }
+
+    #[test]
+    fn test_channel_errors() {
+        let config = NotificationConfig {
+            channels: Some(vec![
+                NotificationChannel {
+                    channel_type: NotificationChannelType::Slack,
+                    url: Some("https://hooks.slack.com/services/x".to_string()),
+                    smtp_server: None,
+                    from: None,
+                    to: None,
+                },
+                NotificationChannel {
+                    channel_type: NotificationChannelType::Smtp,
+                    url: None,
+                    smtp_server: Some("mail:25".to_string()),
+                    from: None,
+                    to: Some(vec![]),
+                },
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            channel_errors(&config),
+            [
+                "notifications.channels[1]: from is required",
+                "notifications.channels[1]: to is required",
+            ]
+        );
+        assert_eq!(deliverable_channels(&config).len(), 1);
+    }
+}
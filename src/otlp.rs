@@ -0,0 +1,551 @@
+//! OTLP trace export.
+//!
+//! A `tracing` layer that turns closed spans into OTLP/HTTP JSON and posts
+//! them in batches to a collector. Trace context follows the span tree: a
+//! span without a parent starts a new trace, every other span joins its
+//! parent's.
+
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_json::{Value, json};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Span, Subscriber};
+use tracing_subscriber::Registry;
+use tracing_subscriber::layer::{Context as LayerContext, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/* ============================= CONFIG ============================= */
+
+/// Path appended to `--otlp-endpoint`, as for `OTEL_EXPORTER_OTLP_ENDPOINT`.
+const TRACES_PATH: &str = "/v1/traces";
+
+/// Spans buffered between the layer and the exporter; further spans are
+/// dropped rather than blocking the traced code.
+const QUEUE_CAPACITY: usize = 4096;
+
+/// Spans per export request.
+const MAX_BATCH: usize = 512;
+
+/// How often a partial batch is flushed.
+const EXPORT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Timeout for one export request, and for the final flush on shutdown.
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Span events kept per span; reconcile cycles can log a lot.
+const MAX_EVENTS: usize = 32;
+
+/// OTLP `SpanKind` values.
+const SPAN_KIND_INTERNAL: u8 = 1;
+const SPAN_KIND_SERVER: u8 = 2;
+const SPAN_KIND_CLIENT: u8 = 3;
+
+/// OTLP `StatusCode.STATUS_CODE_ERROR`.
+const STATUS_ERROR: u8 = 2;
+
+/* ============================= SPAN DATA ============================= */
+
+/// Trace state stored in each span's registry extensions.
+#[derive(Debug, Clone)]
+struct SpanData {
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+    parent_span_id: Option<[u8; 8]>,
+    name: &'static str,
+    kind: u8,
+    start: SystemTime,
+    attributes: Vec<(String, String)>,
+    events: Vec<SpanEvent>,
+    error: bool,
+}
+
+#[derive(Debug, Clone)]
+struct SpanEvent {
+    time: SystemTime,
+    name: String,
+    attributes: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+struct FinishedSpan {
+    data: SpanData,
+    end: SystemTime,
+}
+
+/// Collects span fields and event fields as string attributes. `message`
+/// becomes the event name; `otel.kind` sets the span kind.
+#[derive(Default)]
+struct FieldVisitor {
+    attributes: Vec<(String, String)>,
+    message: Option<String>,
+    kind: Option<u8>,
+}
+
+impl FieldVisitor {
+    fn insert(&mut self, field: &Field, value: String) {
+        match field.name() {
+            "message" => self.message = Some(value),
+            "otel.kind" => self.kind = span_kind(&value),
+            name => set_attribute(&mut self.attributes, name, value),
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, format!("{value:?}"));
+    }
+}
+
+fn set_attribute(attributes: &mut Vec<(String, String)>, key: &str, value: String) {
+    match attributes.iter_mut().find(|(k, _)| k == key) {
+        Some(existing) => existing.1 = value,
+        None => attributes.push((key.to_string(), value)),
+    }
+}
+
+fn span_kind(value: &str) -> Option<u8> {
+    match value {
+        "internal" => Some(SPAN_KIND_INTERNAL),
+        "server" => Some(SPAN_KIND_SERVER),
+        "client" => Some(SPAN_KIND_CLIENT),
+        _ => None,
+    }
+}
+
+/* ============================= LAYER ============================= */
+
+/// Records spans and hands closed ones to the exporter task.
+pub(crate) struct OtlpLayer {
+    sender: mpsc::Sender<FinishedSpan>,
+    rng: SystemRandom,
+}
+
+impl OtlpLayer {
+    fn random<const N: usize>(&self) -> [u8; N] {
+        let mut bytes = [0u8; N];
+        // SystemRandom only fails if the OS has no entropy source at all
+        let _ = self.rng.fill(&mut bytes);
+        bytes
+    }
+}
+
+impl<S> Layer<S> for OtlpLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let parent = span.parent().and_then(|parent| {
+            let extensions = parent.extensions();
+            extensions
+                .get::<SpanData>()
+                .map(|data| (data.trace_id, data.span_id))
+        });
+        let (trace_id, parent_span_id) = match parent {
+            Some((trace_id, span_id)) => (trace_id, Some(span_id)),
+            None => (self.random(), None),
+        };
+
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        let data = SpanData {
+            trace_id,
+            span_id: self.random(),
+            parent_span_id,
+            name: attrs.metadata().name(),
+            kind: visitor.kind.unwrap_or(SPAN_KIND_INTERNAL),
+            start: SystemTime::now(),
+            attributes: visitor.attributes,
+            events: Vec::new(),
+            error: false,
+        };
+        span.extensions_mut().insert(data);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(data) = extensions.get_mut::<SpanData>() {
+            let mut visitor = FieldVisitor {
+                attributes: std::mem::take(&mut data.attributes),
+                ..Default::default()
+            };
+            values.record(&mut visitor);
+            data.attributes = visitor.attributes;
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.event_span(event) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        let Some(data) = extensions.get_mut::<SpanData>() else {
+            return;
+        };
+        let level = *event.metadata().level();
+        // Level orders by verbosity, so this is WARN and ERROR
+        if level <= Level::WARN {
+            data.error = true;
+        }
+        if data.events.len() >= MAX_EVENTS {
+            return;
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        set_attribute(&mut visitor.attributes, "level", level.to_string());
+        data.events.push(SpanEvent {
+            time: SystemTime::now(),
+            name: visitor
+                .message
+                .unwrap_or_else(|| event.metadata().name().to_string()),
+            attributes: visitor.attributes,
+        });
+    }
+
+    fn on_close(&self, id: Id, ctx: LayerContext<'_, S>) {
+        let Some(span) = ctx.span(&id) else {
+            return;
+        };
+        if let Some(data) = span.extensions_mut().remove::<SpanData>() {
+            // A full queue means the collector is down or slow; drop the span
+            let _ = self.sender.try_send(FinishedSpan {
+                data,
+                end: SystemTime::now(),
+            });
+        }
+    }
+}
+
+/// Record the span's trace id in its `trace_id` field so JSON log lines
+/// emitted inside it carry the id. The span must declare
+/// `trace_id = tracing::field::Empty`; without OTLP export this is a no-op.
+pub(crate) fn traced(span: Span) -> Span {
+    let trace_id = span
+        .with_subscriber(|(id, dispatch)| {
+            let registry = dispatch.downcast_ref::<Registry>()?;
+            let span = registry.span(id)?;
+            let extensions = span.extensions();
+            extensions.get::<SpanData>().map(|data| hex(&data.trace_id))
+        })
+        .flatten();
+    if let Some(trace_id) = trace_id {
+        span.record("trace_id", trace_id);
+    }
+    span
+}
+
+/* ============================= EXPORTER ============================= */
+
+/// Handle to the background exporter; `shutdown` flushes pending spans.
+pub(crate) struct OtlpExporter {
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl OtlpExporter {
+    pub(crate) async fn shutdown(self) {
+        let _ = self.shutdown.send(());
+        let _ = tokio::time::timeout(EXPORT_TIMEOUT, self.task).await;
+    }
+}
+
+/// Build the layer and start the exporter task posting to `endpoint`.
+/// The service name comes from `OTEL_SERVICE_NAME` (default `kube-devops`).
+pub(crate) fn init(endpoint: &str) -> Result<(OtlpLayer, OtlpExporter)> {
+    let url = traces_url(endpoint);
+    reqwest::Url::parse(&url).with_context(|| format!("Invalid OTLP endpoint {endpoint}"))?;
+    let client = reqwest::Client::builder()
+        .user_agent(concat!("kube-devops/", env!("CARGO_PKG_VERSION")))
+        .timeout(EXPORT_TIMEOUT)
+        .build()?;
+    let service_name =
+        std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "kube-devops".to_string());
+
+    let (sender, receiver) = mpsc::channel(QUEUE_CAPACITY);
+    let (shutdown, shutdown_rx) = oneshot::channel();
+    let task = tokio::spawn(run_exporter(
+        client,
+        url,
+        service_name,
+        receiver,
+        shutdown_rx,
+    ));
+
+    let layer = OtlpLayer {
+        sender,
+        rng: SystemRandom::new(),
+    };
+    Ok((layer, OtlpExporter { shutdown, task }))
+}
+
+fn traces_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    if endpoint.ends_with(TRACES_PATH) {
+        endpoint.to_string()
+    } else {
+        format!("{endpoint}{TRACES_PATH}")
+    }
+}
+
+async fn run_exporter(
+    client: reqwest::Client,
+    url: String,
+    service_name: String,
+    mut receiver: mpsc::Receiver<FinishedSpan>,
+    mut shutdown: oneshot::Receiver<()>,
+) {
+    let mut batch = Vec::with_capacity(MAX_BATCH);
+    let mut ticker = tokio::time::interval(EXPORT_INTERVAL);
+
+    loop {
+        tokio::select! {
+            Some(span) = receiver.recv() => {
+                batch.push(span);
+                if batch.len() < MAX_BATCH {
+                    continue;
+                }
+            }
+            _ = ticker.tick() => {}
+            _ = &mut shutdown => {
+                while let Ok(span) = receiver.try_recv() {
+                    batch.push(span);
+                }
+                for chunk in batch.chunks(MAX_BATCH) {
+                    export(&client, &url, &service_name, chunk).await;
+                }
+                return;
+            }
+        }
+        if !batch.is_empty() {
+            export(&client, &url, &service_name, &batch).await;
+            batch.clear();
+        }
+    }
+}
+
+/// Post one batch. Failures are reported on stderr: a tracing event here
+/// would be exported in turn.
+async fn export(client: &reqwest::Client, url: &str, service_name: &str, spans: &[FinishedSpan]) {
+    let result = client
+        .post(url)
+        .json(&export_request(service_name, spans))
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status);
+    if let Err(e) = result {
+        eprintln!(
+            "OTLP export of {} spans failed: {}",
+            spans.len(),
+            e.without_url()
+        );
+    }
+}
+
+/* ============================= ENCODING ============================= */
+
+/// OTLP/HTTP JSON `ExportTraceServiceRequest` for one batch.
+fn export_request(service_name: &str, spans: &[FinishedSpan]) -> Value {
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": attributes(&[("service.name".to_string(), service_name.to_string())]),
+            },
+            "scopeSpans": [{
+                "scope": {
+                    "name": "kube-devops",
+                    "version": env!("CARGO_PKG_VERSION"),
+                },
+                "spans": spans.iter().map(encode_span).collect::<Vec<_>>(),
+            }],
+        }],
+    })
+}
+
+fn encode_span(span: &FinishedSpan) -> Value {
+    let data = &span.data;
+    let mut value = json!({
+        "traceId": hex(&data.trace_id),
+        "spanId": hex(&data.span_id),
+        "name": data.name,
+        "kind": data.kind,
+        "startTimeUnixNano": unix_nanos(data.start),
+        "endTimeUnixNano": unix_nanos(span.end),
+        "attributes": attributes(&data.attributes),
+        "events": data.events.iter().map(|event| json!({
+            "timeUnixNano": unix_nanos(event.time),
+            "name": event.name,
+            "attributes": attributes(&event.attributes),
+        })).collect::<Vec<_>>(),
+    });
+    if let Some(parent) = &data.parent_span_id {
+        value["parentSpanId"] = json!(hex(parent));
+    }
+    if data.error {
+        value["status"] = json!({ "code": STATUS_ERROR });
+    }
+    value
+}
+
+fn attributes(attributes: &[(String, String)]) -> Vec<Value> {
+    attributes
+        .iter()
+        .map(|(key, value)| json!({ "key": key, "value": { "stringValue": value } }))
+        .collect()
+}
+
+/// OTLP JSON encodes 64-bit integers as strings.
+fn unix_nanos(time: SystemTime) -> String {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos()
+        .to_string()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::prelude::*;
+
+    fn test_layer() -> (OtlpLayer, mpsc::Receiver<FinishedSpan>) {
+        let (sender, receiver) = mpsc::channel(16);
+        let layer = OtlpLayer {
+            sender,
+            rng: SystemRandom::new(),
+        };
+        (layer, receiver)
+    }
+
+    // ── encoding ──
+
+    #[test]
+    fn test_traces_url() {
+        assert_eq!(
+            traces_url("http://collector:4318"),
+            "http://collector:4318/v1/traces"
+        );
+        assert_eq!(
+            traces_url("http://collector:4318/"),
+            "http://collector:4318/v1/traces"
+        );
+        assert_eq!(
+            traces_url("http://collector:4318/v1/traces"),
+            "http://collector:4318/v1/traces"
+        );
+    }
+
+    #[test]
+    fn test_hex_and_nanos() {
+        assert_eq!(hex(&[0x00, 0x0f, 0xab]), "000fab");
+        assert_eq!(
+            unix_nanos(UNIX_EPOCH + Duration::from_millis(1500)),
+            "1500000000"
+        );
+    }
+
+    #[test]
+    fn test_export_request_shape() {
+        let span = FinishedSpan {
+            data: SpanData {
+                trace_id: [1; 16],
+                span_id: [2; 8],
+                parent_span_id: Some([3; 8]),
+                name: "reconcile",
+                kind: SPAN_KIND_INTERNAL,
+                start: UNIX_EPOCH + Duration::from_secs(1),
+                attributes: vec![("policy".to_string(), "web".to_string())],
+                events: vec![],
+                error: true,
+            },
+            end: UNIX_EPOCH + Duration::from_secs(2),
+        };
+        let request = export_request("kube-devops", &[span]);
+        let resource = &request["resourceSpans"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][0]["value"]["stringValue"],
+            "kube-devops"
+        );
+        let encoded = &resource["scopeSpans"][0]["spans"][0];
+        assert_eq!(encoded["traceId"], "01".repeat(16));
+        assert_eq!(encoded["spanId"], "02".repeat(8));
+        assert_eq!(encoded["parentSpanId"], "03".repeat(8));
+        assert_eq!(encoded["startTimeUnixNano"], "1000000000");
+        assert_eq!(encoded["endTimeUnixNano"], "2000000000");
+        assert_eq!(encoded["attributes"][0]["key"], "policy");
+        assert_eq!(encoded["status"]["code"], 2);
+    }
+
+    // ── layer ──
+
+    #[test]
+    fn test_layer_propagates_trace_context() {
+        let (layer, mut receiver) = test_layer();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let root = tracing::info_span!("reconcile", policy = "web", otel.kind = "server");
+            root.in_scope(|| {
+                tracing::info_span!("list_pods", namespace = "prod").in_scope(|| {
+                    tracing::warn!(pods = 3, "slow_listing");
+                });
+            });
+        });
+
+        let child = receiver.try_recv().unwrap();
+        let root = receiver.try_recv().unwrap();
+        assert_eq!(child.data.name, "list_pods");
+        assert_eq!(root.data.name, "reconcile");
+        assert_eq!(child.data.trace_id, root.data.trace_id);
+        assert_eq!(child.data.parent_span_id, Some(root.data.span_id));
+        assert_eq!(root.data.parent_span_id, None);
+        assert_eq!(root.data.kind, SPAN_KIND_SERVER);
+        assert!(root.data.attributes.iter().all(|(k, _)| k != "otel.kind"));
+
+        assert!(child.data.error);
+        assert_eq!(child.data.events[0].name, "slow_listing");
+        assert!(
+            child.data.events[0]
+                .attributes
+                .contains(&("pods".to_string(), "3".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_traced_records_trace_id() {
+        let (layer, mut receiver) = test_layer();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            let span = traced(tracing::info_span!(
+                "reconcile",
+                trace_id = tracing::field::Empty
+            ));
+            drop(span);
+        });
+
+        let span = receiver.try_recv().unwrap();
+        assert_eq!(
+            span.data.attributes,
+            vec![("trace_id".to_string(), hex(&span.data.trace_id))]
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::io::IsTerminal;
+
+use serde::Serialize;
+
+/* ============================= FORMAT ============================= */
+
+/// Output format for command results.
+///
+/// `table` is the human-readable default; `json` and `yaml` emit a single
+/// structured document on stdout so CI pipelines can consume it. `junit` is
+/// a JUnit XML report, rendered only by the commands that check policies.
+#[cfg_attr(feature = "operator", derive(clap::ValueEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+    Junit,
+}
+
+impl OutputFormat {
+    /// Whether this format is machine-readable (suppresses decorative text).
+    pub fn is_structured(self) -> bool {
+        !matches!(self, OutputFormat::Table)
+    }
+}
+
+/* ============================= RENDERING ============================= */
+
+/// Serialize a value in the given structured format.
+///
+/// Returns an error for `Table`, which each command renders itself.
+pub fn render<T: Serialize>(value: &T, format: OutputFormat) -> anyhow::Result<String> {
+    match format {
+        OutputFormat::Json => Ok(format!("{}\n", serde_json::to_string_pretty(value)?)),
+        OutputFormat::Yaml => Ok(serde_yaml::to_string(value)?),
+        OutputFormat::Table => anyhow::bail!("table output is rendered by the command itself"),
+        OutputFormat::Junit => anyhow::bail!("junit output is only supported by analyze and lint"),
+    }
+}
+
+/// Print a value in the given structured format.
+pub fn print_structured<T: Serialize>(value: &T, format: OutputFormat) -> anyhow::Result<()> {
+    print!("{}", render(value, format)?);
+    Ok(())
+}
+
+/* ============================= PARSING ============================= */
+
+/// The documents of a multi-document YAML string, split by the YAML parser
+/// (so `---` inside a value is not a separator); empty documents are skipped.
+pub fn yaml_documents(content: &str) -> anyhow::Result<Vec<serde_yaml::Value>> {
+    use serde::Deserialize;
+
+    let mut documents = Vec::new();
+    for doc in serde_yaml::Deserializer::from_str(content) {
+        let value = serde_yaml::Value::deserialize(doc)?;
+        if !value.is_null() {
+            documents.push(value);
+        }
+    }
+    Ok(documents)
+}
+
+/* ============================= COLOR ============================= */
+
+/// ANSI colors for human-readable output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+}
+
+impl Color {
+    fn code(self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Yellow => "33",
+        }
+    }
+}
+
+/// Whether to colorize stdout: it is a terminal and `NO_COLOR` is unset.
+pub fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+/// Wrap `text` in the color's escape codes when `enabled`.
+pub fn paint(text: &str, color: Color, enabled: bool) -> String {
+    if enabled {
+        format!("\x1b[{}m{text}\x1b[0m", color.code())
+    } else {
+        text.to_string()
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_yaml_documents() {
+        let docs =
+            yaml_documents("---\nname: a---b\n---\n---\n# only a comment\n---\nname: c\n").unwrap();
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0]["name"], "a---b");
+        assert_eq!(docs[1]["name"], "c");
+        assert!(yaml_documents("").unwrap().is_empty());
+        assert!(yaml_documents("name: [").is_err());
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Sample {
+        health_score: u32,
+        classification: String,
+    }
+
+    fn sample() -> Sample {
+        Sample {
+            health_score: 85,
+            classification: "Healthy".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_is_table() {
+        assert_eq!(OutputFormat::default(), OutputFormat::Table);
+        assert!(!OutputFormat::Table.is_structured());
+        assert!(OutputFormat::Json.is_structured());
+        assert!(OutputFormat::Yaml.is_structured());
+        assert!(OutputFormat::Junit.is_structured());
+    }
+
+    #[test]
+    fn test_render_json() {
+        let out = render(&sample(), OutputFormat::Json).unwrap();
+        let v: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(v["healthScore"], 85);
+        assert_eq!(v["classification"], "Healthy");
+    }
+
+    #[test]
+    fn test_render_yaml() {
+        let out = render(&sample(), OutputFormat::Yaml).unwrap();
+        let v: serde_yaml::Value = serde_yaml::from_str(&out).unwrap();
+        assert_eq!(v["healthScore"], 85);
+    }
+
+    #[test]
+    fn test_render_table_is_error() {
+        assert!(render(&sample(), OutputFormat::Table).is_err());
+        assert!(render(&sample(), OutputFormat::Junit).is_err());
+    }
+
+    #[test]
+    fn test_paint() {
+        assert_eq!(paint("+ a", Color::Green, false), "+ a");
+        assert_eq!(paint("- a", Color::Red, true), "\x1b[31m- a\x1b[0m");
+    }
+}
@@ -0,0 +1,404 @@
+//! Three-way policy diff with server-side apply semantics.
+//!
+//! `policy import` applies each policy with server-side apply as the
+//! [`CLI_FIELD_MANAGER`] field manager. What that changes depends on three
+//! inputs: the spec in the file, the live spec, and the fields the CLI
+//! applied last time (recorded in `managedFields`, since server-side apply
+//! keeps no last-applied copy). A field in the file is set; a field the CLI
+//! applied before but the file no longer lists is removed, unless another
+//! manager also owns it; fields only other managers own are left alone; and
+//! changing a field another manager owns is a conflict the import reports
+//! instead of applying.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ManagedFieldsEntry;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Field manager `policy import` applies as.
+pub const CLI_FIELD_MANAGER: &str = "kube-devops-cli";
+
+/* ============================= TYPES ============================= */
+
+/// What importing the file does to one spec field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// Set by the file, absent from the live policy.
+    Add,
+    /// Set by the file to a different value than the live one.
+    Change,
+    /// Applied by the CLI before, no longer in the file, owned by no one else.
+    Remove,
+    /// Set by the file to a different value than another manager owns.
+    Conflict,
+}
+
+/// One field-level difference, with the path relative to the policy
+/// (`spec.defaultProbe.tcpPort`).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub path: String,
+    pub kind: ChangeKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub live: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<Value>,
+    /// Whether the live value was applied by [`CLI_FIELD_MANAGER`].
+    pub last_applied: bool,
+    /// Other field managers that own the field.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub owners: Vec<String>,
+}
+
+/// Overall result of diffing one policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffStatus {
+    /// Not in the cluster; import creates it.
+    New,
+    /// Import changes at least one field.
+    Changed,
+    /// Import changes nothing.
+    Unchanged,
+    /// The live policy could not be read.
+    Error,
+}
+
+/// Diff of one policy from the file against the cluster.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyDiff {
+    pub namespace: String,
+    pub name: String,
+    pub status: DiffStatus,
+    pub changes: Vec<FieldChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PolicyDiff {
+    /// Diff a file spec against the live spec and its `managedFields`, or
+    /// against nothing when the policy does not exist yet.
+    pub fn compare(
+        namespace: &str,
+        name: &str,
+        file: &Value,
+        live: Option<(&Value, &[ManagedFieldsEntry])>,
+    ) -> Self {
+        let (status, changes) = match live {
+            None => (DiffStatus::New, Vec::new()),
+            Some((live, managed_fields)) => {
+                let changes = three_way_diff(file, live, managed_fields);
+                let status = if changes.is_empty() {
+                    DiffStatus::Unchanged
+                } else {
+                    DiffStatus::Changed
+                };
+                (status, changes)
+            }
+        };
+        PolicyDiff {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            status,
+            changes,
+            error: None,
+        }
+    }
+
+    /// A policy whose live state could not be read.
+    pub fn failed(namespace: &str, name: &str, error: String) -> Self {
+        PolicyDiff {
+            namespace: namespace.to_string(),
+            name: name.to_string(),
+            status: DiffStatus::Error,
+            changes: Vec::new(),
+            error: Some(error),
+        }
+    }
+
+    /// Number of conflicting fields, which make `policy import` fail.
+    pub fn conflicts(&self) -> usize {
+        self.changes
+            .iter()
+            .filter(|c| c.kind == ChangeKind::Conflict)
+            .count()
+    }
+}
+
+/* ============================= DIFF ============================= */
+
+/// A field path, one segment per object key.
+type Path = Vec<String>;
+
+/// Field-level changes importing `file` (a spec) makes to `live` (the live
+/// spec, owned according to `managed_fields`), ordered by path.
+pub fn three_way_diff(
+    file: &Value,
+    live: &Value,
+    managed_fields: &[ManagedFieldsEntry],
+) -> Vec<FieldChange> {
+    let mut file_leaves = BTreeMap::new();
+    leaves(file, &mut vec!["spec".to_string()], &mut file_leaves);
+    let mut live_leaves = BTreeMap::new();
+    leaves(live, &mut vec!["spec".to_string()], &mut live_leaves);
+
+    let mut applied = BTreeSet::new();
+    let mut others: BTreeMap<String, BTreeSet<Path>> = BTreeMap::new();
+    for entry in managed_fields {
+        let Some(fields) = &entry.fields_v1 else {
+            continue;
+        };
+        let manager = entry.manager.clone().unwrap_or_default();
+        let mut owned = BTreeSet::new();
+        owned_paths(&fields.0, &mut Vec::new(), &mut owned);
+        if manager == CLI_FIELD_MANAGER && entry.operation.as_deref() == Some("Apply") {
+            applied.extend(owned);
+        } else {
+            others.entry(manager).or_default().extend(owned);
+        }
+    }
+    let owners_of = |path: &Path| -> Vec<String> {
+        others
+            .iter()
+            .filter(|(_, owned)| owns(owned, path))
+            .map(|(manager, _)| manager.clone())
+            .collect()
+    };
+
+    let paths: BTreeSet<&Path> = file_leaves.keys().chain(live_leaves.keys()).collect();
+    let mut changes = Vec::new();
+    for path in paths {
+        let file_value = file_leaves.get(path);
+        let live_value = live_leaves.get(path);
+        let last_applied = owns(&applied, path);
+        let owners = owners_of(path);
+        let kind = match (live_value, file_value) {
+            (Some(live), Some(file)) if live == file => continue,
+            (None, Some(_)) => ChangeKind::Add,
+            (Some(_), Some(_)) if owners.is_empty() => ChangeKind::Change,
+            (Some(_), Some(_)) => ChangeKind::Conflict,
+            (Some(_), None) if last_applied && owners.is_empty() => ChangeKind::Remove,
+            _ => continue,
+        };
+        changes.push(FieldChange {
+            path: path.join("."),
+            kind,
+            live: live_value.cloned(),
+            file: file_value.cloned(),
+            last_applied,
+            owners,
+        });
+    }
+    changes
+}
+
+/// Leaf values of `value` by path. Arrays are atomic (the CRD declares no
+/// list types), and an empty object is a leaf of its own.
+fn leaves(value: &Value, path: &mut Path, out: &mut BTreeMap<Path, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                path.push(key.clone());
+                leaves(child, path, out);
+                path.pop();
+            }
+        }
+        Value::Null => {}
+        _ => {
+            out.insert(path.clone(), value.clone());
+        }
+    }
+}
+
+/// Paths a `fieldsV1` set owns: every `f:<name>` entry without owned
+/// children. List entries (`k:`, `v:`, `i:`) own their list as a whole.
+fn owned_paths(fields: &Value, path: &mut Path, out: &mut BTreeSet<Path>) {
+    let Some(map) = fields.as_object() else {
+        return;
+    };
+    let mut has_children = false;
+    for (key, child) in map {
+        if let Some(name) = key.strip_prefix("f:") {
+            has_children = true;
+            path.push(name.to_string());
+            owned_paths(child, path, out);
+            path.pop();
+        } else if key != "." {
+            has_children = true;
+            out.insert(path.clone());
+        }
+    }
+    if !has_children && !path.is_empty() {
+        out.insert(path.clone());
+    }
+}
+
+/// Whether `owned` covers `path`: the path itself, an ancestor (an atomic
+/// value), or a descendant (part of the value).
+fn owns(owned: &BTreeSet<Path>, path: &Path) -> bool {
+    owned
+        .iter()
+        .any(|o| path.starts_with(o) || o.starts_with(path))
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::FieldsV1;
+    use serde_json::json;
+
+    fn entry(manager: &str, operation: &str, fields: Value) -> ManagedFieldsEntry {
+        ManagedFieldsEntry {
+            manager: Some(manager.to_string()),
+            operation: Some(operation.to_string()),
+            fields_v1: Some(FieldsV1(fields)),
+            ..Default::default()
+        }
+    }
+
+    fn summary(changes: &[FieldChange]) -> Vec<(&str, ChangeKind)> {
+        changes.iter().map(|c| (c.path.as_str(), c.kind)).collect()
+    }
+
+    #[test]
+    fn test_owned_paths() {
+        let mut owned = BTreeSet::new();
+        owned_paths(
+            &json!({"f:metadata": {"f:labels": {".": {}, "f:team": {}}},
+                    "f:spec": {"f:defaultProbe": {".": {}, "f:tcpPort": {}},
+                               "f:forbiddenTagPatterns": {},
+                               "f:list": {"k:{\"name\":\"a\"}": {}}}}),
+            &mut Vec::new(),
+            &mut owned,
+        );
+        let owned: Vec<String> = owned.iter().map(|p| p.join(".")).collect();
+        assert_eq!(
+            owned,
+            [
+                "metadata.labels.team",
+                "spec.defaultProbe.tcpPort",
+                "spec.forbiddenTagPatterns",
+                "spec.list",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_without_managed_fields() {
+        let live = json!({"maxRestartCount": 3, "forbidLatestTag": true, "requireResources": true});
+        let file = json!({"maxRestartCount": 5, "forbidLatestTag": true, "defaultProbe": {"tcpPort": 8080}});
+        let changes = three_way_diff(&file, &live, &[]);
+        // requireResources is not the CLI's, so import leaves it alone.
+        assert_eq!(
+            summary(&changes),
+            [
+                ("spec.defaultProbe.tcpPort", ChangeKind::Add),
+                ("spec.maxRestartCount", ChangeKind::Change),
+            ]
+        );
+        assert_eq!(changes[1].live, Some(json!(3)));
+        assert_eq!(changes[1].file, Some(json!(5)));
+        assert!(!changes[1].last_applied);
+    }
+
+    #[test]
+    fn test_diff_removes_fields_the_cli_applied() {
+        let live = json!({
+            "maxRestartCount": 3,
+            "requireResources": true,
+            "defaultProbe": {"tcpPort": 8080},
+            "enforcementMode": "audit",
+        });
+        let file = json!({"maxRestartCount": 3, "enforcementMode": "enforce"});
+        let managed = [
+            entry(
+                CLI_FIELD_MANAGER,
+                "Apply",
+                json!({"f:spec": {"f:maxRestartCount": {}, "f:requireResources": {},
+                                  "f:defaultProbe": {".": {}, "f:tcpPort": {}}}}),
+            ),
+            entry(
+                "kubectl-edit",
+                "Update",
+                json!({"f:spec": {"f:enforcementMode": {}, "f:defaultProbe": {"f:tcpPort": {}}}}),
+            ),
+        ];
+        let changes = three_way_diff(&file, &live, &managed);
+        // defaultProbe.tcpPort is co-owned by kubectl-edit, so it stays.
+        assert_eq!(
+            summary(&changes),
+            [
+                ("spec.enforcementMode", ChangeKind::Conflict),
+                ("spec.requireResources", ChangeKind::Remove),
+            ]
+        );
+        assert_eq!(changes[0].owners, ["kubectl-edit"]);
+        assert!(changes[1].last_applied);
+        assert_eq!(changes[1].file, None);
+    }
+
+    #[test]
+    fn test_diff_atomic_lists_and_maps() {
+        let live = json!({
+            "forbiddenTagPatterns": ["^dev-"],
+            "profiles": {"dev": {"maxRestartCount": 10}, "prod": {"maxRestartCount": 3}},
+        });
+        let file = json!({
+            "forbiddenTagPatterns": ["^dev-", "snapshot"],
+            "profiles": {"dev": {"maxRestartCount": 10}},
+        });
+        let managed = [entry(
+            CLI_FIELD_MANAGER,
+            "Apply",
+            json!({"f:spec": {"f:forbiddenTagPatterns": {},
+                              "f:profiles": {"f:dev": {"f:maxRestartCount": {}},
+                                             "f:prod": {"f:maxRestartCount": {}}}}}),
+        )];
+        let changes = three_way_diff(&file, &live, &managed);
+        assert_eq!(
+            summary(&changes),
+            [
+                ("spec.forbiddenTagPatterns", ChangeKind::Change),
+                ("spec.profiles.prod.maxRestartCount", ChangeKind::Remove),
+            ]
+        );
+        assert_eq!(changes[0].file, Some(json!(["^dev-", "snapshot"])));
+    }
+
+    #[test]
+    fn test_identical_specs_have_no_changes() {
+        let spec = json!({"forbidLatestTag": true, "profiles": {}});
+        assert!(three_way_diff(&spec, &spec, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_policy_diff_status() {
+        let file = json!({"maxRestartCount": 5});
+        let new = PolicyDiff::compare("prod", "web", &file, None);
+        assert_eq!(new.status, DiffStatus::New);
+        let same = PolicyDiff::compare("prod", "web", &file, Some((&file, &[])));
+        assert_eq!(same.status, DiffStatus::Unchanged);
+
+        let live = json!({"maxRestartCount": 3});
+        let managed = [entry(
+            "kubectl-edit",
+            "Update",
+            json!({"f:spec": {"f:maxRestartCount": {}}}),
+        )];
+        let changed = PolicyDiff::compare("prod", "web", &file, Some((&live, &managed)));
+        assert_eq!(changed.status, DiffStatus::Changed);
+        assert_eq!(changed.conflicts(), 1);
+
+        let json = serde_json::to_value(&changed).unwrap();
+        assert_eq!(json["status"], "changed");
+        assert_eq!(json["changes"][0]["kind"], "conflict");
+        assert_eq!(json["changes"][0]["owners"][0], "kubectl-edit");
+        assert_eq!(json["changes"][0]["lastApplied"], false);
+    }
+}
@@ -0,0 +1,643 @@
+//! Effective policy resolution.
+//!
+//! Three layers combine into the policy a workload is evaluated against,
+//! lowest precedence first:
+//!
+//! 1. `ClusterDevOpsPolicy` resources covering the namespace, in name order
+//!    (a later name overrides an earlier one).
+//! 2. The namespace's `DevOpsPolicy`.
+//! 3. The profile the namespace selects by label from the merged
+//!    `profiles` (see [`apply_profile`]).
+//! 4. `devops.stochastic.io/override.<field>` annotations on the workload's
+//!    pod template, limited to the check fields in [`OVERRIDABLE_FIELDS`].
+//!
+//! Every field set in a higher layer wins. Nested objects merge field by
+//! field; lists and scalars are replaced.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::Pod;
+use serde::Serialize;
+
+use crate::crd::{ClusterDevOpsPolicy, ClusterDevOpsPolicySpec, DevOpsPolicy, DevOpsPolicySpec};
+use crate::governance::is_system_namespace;
+
+/* ============================= CONSTANTS ============================= */
+
+/// Namespace label naming the profile, unless the policy sets `profileLabel`.
+pub const DEFAULT_PROFILE_LABEL: &str = "devops.stochastic.io/profile";
+
+/// Pod annotation prefix for per-workload policy overrides.
+///
+/// `devops.stochastic.io/override.maxRestartCount: "10"` raises the restart
+/// threshold for one workload without touching the namespace policy.
+pub const WORKLOAD_OVERRIDE_ANNOTATION_PREFIX: &str = "devops.stochastic.io/override.";
+
+/// Spec fields a workload annotation may override.
+///
+/// Enforcement mode, remediation defaults, and severities stay policy-wide.
+pub const OVERRIDABLE_FIELDS: [&str; 10] = [
+    "forbidLatestTag",
+    "requireLivenessProbe",
+    "requireReadinessProbe",
+    "requireResources",
+    "maxRestartCount",
+    "forbidPendingDuration",
+    "forbidCrashLoop",
+    "forbidImagePullErrors",
+    "requirePodDisruptionBudget",
+    "requireSpreadConstraints",
+];
+
+/* ============================= MERGING ============================= */
+
+/// Overlay `overlay` on `base`: every field set in `overlay` wins.
+///
+/// Nested objects (`severityOverrides`, `scoringWeights`, `defaultProbe`,
+/// `defaultResources`) merge field by field; lists and scalars are replaced.
+pub fn merge_policy_specs(base: &DevOpsPolicySpec, overlay: &DevOpsPolicySpec) -> DevOpsPolicySpec {
+    fn merge(base: &mut serde_json::Value, overlay: serde_json::Value) {
+        match (base, overlay) {
+            (serde_json::Value::Object(b), serde_json::Value::Object(o)) => {
+                for (key, value) in o {
+                    merge(b.entry(key).or_insert(serde_json::Value::Null), value);
+                }
+            }
+            (b, o) => *b = o,
+        }
+    }
+
+    let (Ok(mut merged), Ok(top)) = (serde_json::to_value(base), serde_json::to_value(overlay))
+    else {
+        return overlay.clone();
+    };
+    merge(&mut merged, top);
+    serde_json::from_value(merged).unwrap_or_else(|_| overlay.clone())
+}
+
+/* ============================= CLUSTER AND NAMESPACE ============================= */
+
+/// Whether a cluster policy covers `namespace`.
+///
+/// System namespaces are never covered, matching namespaced evaluation.
+pub fn cluster_policy_applies(spec: &ClusterDevOpsPolicySpec, namespace: &str) -> bool {
+    !is_system_namespace(namespace)
+        && !spec
+            .excluded_namespaces
+            .as_ref()
+            .is_some_and(|excluded| excluded.iter().any(|ns| ns == namespace))
+}
+
+/// Live cluster policies covering `namespace`, lowest precedence first.
+fn applicable_cluster_policies<'a>(
+    namespace: &str,
+    cluster_policies: &'a [ClusterDevOpsPolicy],
+) -> Vec<&'a ClusterDevOpsPolicy> {
+    let mut applicable: Vec<&ClusterDevOpsPolicy> = cluster_policies
+        .iter()
+        .filter(|p| p.metadata.deletion_timestamp.is_none())
+        .filter(|p| cluster_policy_applies(&p.spec, namespace))
+        .collect();
+    applicable.sort_by(|a, b| a.metadata.name.cmp(&b.metadata.name));
+    applicable
+}
+
+/// Resolve the policy in effect for `namespace`.
+///
+/// Cluster policies covering the namespace are applied in name order (later
+/// names override earlier ones), then the namespaced policy overrides them.
+/// Returns `None` when no policy applies.
+pub fn resolve_effective_policy(
+    namespace: &str,
+    cluster_policies: &[ClusterDevOpsPolicy],
+    namespaced: Option<&DevOpsPolicySpec>,
+) -> Option<DevOpsPolicySpec> {
+    applicable_cluster_policies(namespace, cluster_policies)
+        .into_iter()
+        .map(|p| &p.spec.policy)
+        .chain(namespaced)
+        .fold(None, |acc, spec| match acc {
+            Some(base) => Some(merge_policy_specs(&base, spec)),
+            None => Some(spec.clone()),
+        })
+}
+
+/// An effective policy with the layer that set each top-level field.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedPolicy {
+    pub spec: DevOpsPolicySpec,
+    /// camelCase field name → `Kind/name` of the layer that set it last.
+    pub sources: BTreeMap<String, String>,
+}
+
+/// Like [`resolve_effective_policy`], but records where each field came from.
+pub fn explain_effective_policy(
+    namespace: &str,
+    cluster_policies: &[ClusterDevOpsPolicy],
+    namespaced: Option<&DevOpsPolicy>,
+) -> Option<ResolvedPolicy> {
+    let cluster_layers = applicable_cluster_policies(namespace, cluster_policies)
+        .into_iter()
+        .map(|p| {
+            (
+                format!("ClusterDevOpsPolicy/{}", name_of(&p.metadata)),
+                &p.spec.policy,
+            )
+        });
+    let namespaced_layer =
+        namespaced.map(|p| (format!("DevOpsPolicy/{}", name_of(&p.metadata)), &p.spec));
+
+    let mut resolved: Option<ResolvedPolicy> = None;
+    for (source, spec) in cluster_layers.chain(namespaced_layer) {
+        let mut next = match resolved {
+            Some(r) => ResolvedPolicy {
+                spec: merge_policy_specs(&r.spec, spec),
+                sources: r.sources,
+            },
+            None => ResolvedPolicy {
+                spec: spec.clone(),
+                sources: BTreeMap::new(),
+            },
+        };
+        if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(spec) {
+            for field in fields.keys() {
+                next.sources.insert(field.clone(), source.clone());
+            }
+        }
+        resolved = Some(next);
+    }
+    resolved
+}
+
+fn name_of(meta: &k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta) -> &str {
+    meta.name.as_deref().unwrap_or("unnamed")
+}
+
+/* ============================= PROFILES ============================= */
+
+/// Whether the policy defines any profiles, i.e. whether namespace labels
+/// are needed to evaluate it.
+pub fn has_profiles(spec: &DevOpsPolicySpec) -> bool {
+    spec.profiles.as_ref().is_some_and(|p| !p.is_empty())
+}
+
+/// Name of the profile a namespace selects, when the policy defines it.
+pub fn selected_profile<'a>(
+    spec: &'a DevOpsPolicySpec,
+    namespace_labels: Option<&BTreeMap<String, String>>,
+) -> Option<&'a str> {
+    let label = spec
+        .profile_label
+        .as_deref()
+        .unwrap_or(DEFAULT_PROFILE_LABEL);
+    let value = namespace_labels?.get(label)?;
+    spec.profiles
+        .as_ref()?
+        .get_key_value(value)
+        .map(|(name, _)| name.as_str())
+}
+
+/// Layer the profile a namespace selects over the policy.
+///
+/// A label naming a profile the policy does not define leaves the policy
+/// unchanged.
+pub fn apply_profile(
+    spec: &DevOpsPolicySpec,
+    namespace_labels: Option<&BTreeMap<String, String>>,
+) -> DevOpsPolicySpec {
+    match profile_overlay(spec, namespace_labels) {
+        Some((_, overlay)) => merge_policy_specs(spec, &overlay),
+        None => spec.clone(),
+    }
+}
+
+/// Like [`apply_profile`], but records `Profile/<name>` as the source of
+/// each field the profile sets.
+pub fn explain_profile(
+    resolved: ResolvedPolicy,
+    namespace_labels: Option<&BTreeMap<String, String>>,
+) -> ResolvedPolicy {
+    let Some((name, overlay)) = profile_overlay(&resolved.spec, namespace_labels) else {
+        return resolved;
+    };
+    let mut sources = resolved.sources;
+    if let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(&overlay) {
+        for field in fields.keys() {
+            sources.insert(field.clone(), format!("Profile/{name}"));
+        }
+    }
+    ResolvedPolicy {
+        spec: merge_policy_specs(&resolved.spec, &overlay),
+        sources,
+    }
+}
+
+/// The selected profile as a spec overlay. Profile fields share their
+/// names with the spec, so the conversion goes through JSON.
+fn profile_overlay(
+    spec: &DevOpsPolicySpec,
+    namespace_labels: Option<&BTreeMap<String, String>>,
+) -> Option<(String, DevOpsPolicySpec)> {
+    let name = selected_profile(spec, namespace_labels)?;
+    let profile = spec.profiles.as_ref()?.get(name)?;
+    let overlay = serde_json::to_value(profile)
+        .and_then(serde_json::from_value)
+        .ok()?;
+    Some((name.to_string(), overlay))
+}
+
+/* ============================= WORKLOAD OVERRIDES ============================= */
+
+/// Policy overrides parsed from a workload's annotations.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkloadOverrides {
+    /// Overlay containing only the overridden fields.
+    pub spec: DevOpsPolicySpec,
+    /// Fields overridden, in name order.
+    pub fields: Vec<String>,
+    /// Annotations that were ignored, with the reason.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub invalid: Vec<String>,
+}
+
+impl WorkloadOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// Parse `devops.stochastic.io/override.<field>` annotations.
+///
+/// Values are read as JSON scalars (`true`, `10`), so `"false"` disables a
+/// check. Unknown fields and values of the wrong type are reported in
+/// `invalid` and otherwise ignored.
+pub fn workload_overrides(annotations: Option<&BTreeMap<String, String>>) -> WorkloadOverrides {
+    let mut overrides = WorkloadOverrides::default();
+    let mut fields = serde_json::Map::new();
+
+    for (key, raw) in annotations.into_iter().flatten() {
+        let Some(field) = key.strip_prefix(WORKLOAD_OVERRIDE_ANNOTATION_PREFIX) else {
+            continue;
+        };
+        if !OVERRIDABLE_FIELDS.contains(&field) {
+            overrides
+                .invalid
+                .push(format!("{field}: not overridable per workload"));
+            continue;
+        }
+        let value = serde_json::from_str(raw.trim())
+            .unwrap_or_else(|_| serde_json::Value::String(raw.clone()));
+        let single = serde_json::json!({ field: value });
+        if let Err(e) = serde_json::from_value::<DevOpsPolicySpec>(single) {
+            overrides.invalid.push(format!("{field}: {e}"));
+            continue;
+        }
+        fields.insert(field.to_string(), value);
+        overrides.fields.push(field.to_string());
+    }
+
+    if let Ok(spec) = serde_json::from_value(serde_json::Value::Object(fields)) {
+        overrides.spec = spec;
+    }
+    overrides
+}
+
+/// The policy a pod is evaluated against after its workload overrides.
+///
+/// Borrows `policy` unchanged when the pod carries no override annotations.
+pub fn apply_workload_overrides<'a>(
+    policy: &'a DevOpsPolicySpec,
+    pod: &Pod,
+) -> Cow<'a, DevOpsPolicySpec> {
+    let annotations = pod.metadata.annotations.as_ref();
+    let has_overrides = annotations.is_some_and(|a| {
+        a.keys()
+            .any(|k| k.starts_with(WORKLOAD_OVERRIDE_ANNOTATION_PREFIX))
+    });
+    if !has_overrides {
+        return Cow::Borrowed(policy);
+    }
+
+    let overrides = workload_overrides(annotations);
+    if overrides.is_empty() {
+        Cow::Borrowed(policy)
+    } else {
+        Cow::Owned(merge_policy_specs(policy, &overrides.spec))
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{Severity, SeverityOverrides};
+
+    fn cluster_policy(name: &str, spec: ClusterDevOpsPolicySpec) -> ClusterDevOpsPolicy {
+        let mut policy = ClusterDevOpsPolicy::new(name, spec);
+        policy.metadata.name = Some(name.to_string());
+        policy
+    }
+
+    fn annotations(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| {
+                (
+                    format!("{WORKLOAD_OVERRIDE_ANNOTATION_PREFIX}{k}"),
+                    v.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    // ── Profiles ──
+
+    fn profiled_policy() -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            forbid_latest_tag: Some(false),
+            max_restart_count: Some(10),
+            profiles: Some(BTreeMap::from([(
+                "prod".to_string(),
+                crate::crd::PolicyProfile {
+                    forbid_latest_tag: Some(true),
+                    require_liveness_probe: Some(true),
+                    ..Default::default()
+                },
+            )])),
+            ..Default::default()
+        }
+    }
+
+    fn labels(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_apply_profile_selected_by_label() {
+        let spec = profiled_policy();
+        let prod = labels(&[(DEFAULT_PROFILE_LABEL, "prod")]);
+        assert_eq!(selected_profile(&spec, Some(&prod)), Some("prod"));
+
+        let effective = apply_profile(&spec, Some(&prod));
+        assert_eq!(effective.forbid_latest_tag, Some(true));
+        assert_eq!(effective.require_liveness_probe, Some(true));
+        assert_eq!(effective.max_restart_count, Some(10));
+    }
+
+    #[test]
+    fn test_apply_profile_unknown_or_missing_label() {
+        let spec = profiled_policy();
+        let staging = labels(&[(DEFAULT_PROFILE_LABEL, "staging")]);
+        assert!(selected_profile(&spec, Some(&staging)).is_none());
+        assert_eq!(
+            apply_profile(&spec, Some(&staging)).forbid_latest_tag,
+            Some(false)
+        );
+        assert_eq!(apply_profile(&spec, None).forbid_latest_tag, Some(false));
+    }
+
+    #[test]
+    fn test_apply_profile_custom_label() {
+        let spec = DevOpsPolicySpec {
+            profile_label: Some("env".to_string()),
+            ..profiled_policy()
+        };
+        let env = labels(&[("env", "prod")]);
+        assert_eq!(
+            apply_profile(&spec, Some(&env)).forbid_latest_tag,
+            Some(true)
+        );
+        let default_label = labels(&[(DEFAULT_PROFILE_LABEL, "prod")]);
+        assert!(selected_profile(&spec, Some(&default_label)).is_none());
+    }
+
+    #[test]
+    fn test_cluster_profiles_reach_namespaced_policy() {
+        let clusters = vec![cluster_policy(
+            "org",
+            ClusterDevOpsPolicySpec {
+                policy: profiled_policy(),
+                ..Default::default()
+            },
+        )];
+        let namespaced = DevOpsPolicySpec {
+            max_restart_count: Some(3),
+            ..Default::default()
+        };
+        let merged = resolve_effective_policy("payments", &clusters, Some(&namespaced)).unwrap();
+        let prod = labels(&[(DEFAULT_PROFILE_LABEL, "prod")]);
+        let effective = apply_profile(&merged, Some(&prod));
+        assert_eq!(effective.forbid_latest_tag, Some(true));
+        assert_eq!(effective.max_restart_count, Some(3));
+    }
+
+    #[test]
+    fn test_explain_profile_records_source() {
+        let resolved = ResolvedPolicy {
+            spec: profiled_policy(),
+            sources: BTreeMap::from([(
+                "forbidLatestTag".to_string(),
+                "DevOpsPolicy/p".to_string(),
+            )]),
+        };
+        let prod = labels(&[(DEFAULT_PROFILE_LABEL, "prod")]);
+        let explained = explain_profile(resolved, Some(&prod));
+        assert_eq!(explained.spec.forbid_latest_tag, Some(true));
+        assert_eq!(explained.sources["forbidLatestTag"], "Profile/prod");
+        assert_eq!(explained.sources["requireLivenessProbe"], "Profile/prod");
+    }
+
+    // ── Cluster and namespace ──
+
+    #[test]
+    fn test_cluster_policy_applies_skips_system_and_excluded() {
+        let spec = ClusterDevOpsPolicySpec {
+            excluded_namespaces: Some(vec!["sandbox".to_string()]),
+            ..Default::default()
+        };
+        assert!(cluster_policy_applies(&spec, "production"));
+        assert!(!cluster_policy_applies(&spec, "sandbox"));
+        assert!(!cluster_policy_applies(&spec, "kube-system"));
+    }
+
+    #[test]
+    fn test_merge_policy_specs_overlay_wins() {
+        let base = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            max_restart_count: Some(3),
+            severity_overrides: Some(SeverityOverrides {
+                latest_tag: Some(Severity::Critical),
+                high_restarts: Some(Severity::High),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let overlay = DevOpsPolicySpec {
+            forbid_latest_tag: Some(false),
+            require_liveness_probe: Some(true),
+            severity_overrides: Some(SeverityOverrides {
+                latest_tag: Some(Severity::Low),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let merged = merge_policy_specs(&base, &overlay);
+        assert_eq!(merged.forbid_latest_tag, Some(false));
+        assert_eq!(merged.require_liveness_probe, Some(true));
+        assert_eq!(merged.max_restart_count, Some(3));
+        let overrides = merged.severity_overrides.unwrap();
+        assert_eq!(overrides.latest_tag, Some(Severity::Low));
+        assert_eq!(overrides.high_restarts, Some(Severity::High));
+    }
+
+    #[test]
+    fn test_resolve_effective_policy_precedence() {
+        let clusters = vec![
+            cluster_policy(
+                "b-strict",
+                ClusterDevOpsPolicySpec {
+                    policy: DevOpsPolicySpec {
+                        max_restart_count: Some(1),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ),
+            cluster_policy(
+                "a-base",
+                ClusterDevOpsPolicySpec {
+                    policy: DevOpsPolicySpec {
+                        forbid_latest_tag: Some(true),
+                        max_restart_count: Some(5),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ),
+        ];
+        let namespaced = DevOpsPolicySpec {
+            forbid_latest_tag: Some(false),
+            ..Default::default()
+        };
+
+        let cluster_only = resolve_effective_policy("web", &clusters, None).unwrap();
+        assert_eq!(cluster_only.forbid_latest_tag, Some(true));
+        assert_eq!(cluster_only.max_restart_count, Some(1));
+
+        let effective = resolve_effective_policy("web", &clusters, Some(&namespaced)).unwrap();
+        assert_eq!(effective.forbid_latest_tag, Some(false));
+        assert_eq!(effective.max_restart_count, Some(1));
+    }
+
+    #[test]
+    fn test_resolve_effective_policy_excluded_namespace() {
+        let clusters = vec![cluster_policy(
+            "base",
+            ClusterDevOpsPolicySpec {
+                excluded_namespaces: Some(vec!["legacy".to_string()]),
+                policy: DevOpsPolicySpec {
+                    forbid_latest_tag: Some(true),
+                    ..Default::default()
+                },
+            },
+        )];
+        assert!(resolve_effective_policy("legacy", &clusters, None).is_none());
+        assert!(resolve_effective_policy("kube-system", &clusters, None).is_none());
+
+        let namespaced = DevOpsPolicySpec {
+            require_resources: Some(true),
+            ..Default::default()
+        };
+        let effective = resolve_effective_policy("legacy", &clusters, Some(&namespaced)).unwrap();
+        assert_eq!(effective.forbid_latest_tag, None);
+        assert_eq!(effective.require_resources, Some(true));
+    }
+
+    #[test]
+    fn test_explain_effective_policy_sources() {
+        let clusters = vec![cluster_policy(
+            "baseline",
+            ClusterDevOpsPolicySpec {
+                policy: DevOpsPolicySpec {
+                    forbid_latest_tag: Some(true),
+                    max_restart_count: Some(5),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )];
+        let namespaced = crate::api::new_policy(
+            "web",
+            "team",
+            DevOpsPolicySpec {
+                max_restart_count: Some(10),
+                ..Default::default()
+            },
+        );
+
+        let resolved = explain_effective_policy("web", &clusters, Some(&namespaced)).unwrap();
+        assert_eq!(resolved.spec.max_restart_count, Some(10));
+        assert_eq!(
+            resolved.sources.get("forbidLatestTag").map(String::as_str),
+            Some("ClusterDevOpsPolicy/baseline")
+        );
+        assert_eq!(
+            resolved.sources.get("maxRestartCount").map(String::as_str),
+            Some("DevOpsPolicy/team")
+        );
+        assert!(explain_effective_policy("web", &[], None).is_none());
+    }
+
+    // ── Workload overrides ──
+
+    #[test]
+    fn test_workload_overrides_parses_scalars() {
+        let a = annotations(&[("forbidLatestTag", "false"), ("maxRestartCount", "10")]);
+        let overrides = workload_overrides(Some(&a));
+        assert_eq!(overrides.spec.forbid_latest_tag, Some(false));
+        assert_eq!(overrides.spec.max_restart_count, Some(10));
+        assert_eq!(overrides.fields.len(), 2);
+        assert!(overrides.invalid.is_empty());
+    }
+
+    #[test]
+    fn test_workload_overrides_rejects_policy_wide_and_bad_values() {
+        let a = annotations(&[
+            ("enforcementMode", "audit"),
+            ("maxRestartCount", "lots"),
+            ("requireResources", "true"),
+        ]);
+        let overrides = workload_overrides(Some(&a));
+        assert_eq!(overrides.fields, vec!["requireResources"]);
+        assert_eq!(overrides.invalid.len(), 2);
+        assert!(overrides.spec.enforcement_mode.is_none());
+        assert!(overrides.spec.max_restart_count.is_none());
+    }
+
+    #[test]
+    fn test_apply_workload_overrides() {
+        let policy = DevOpsPolicySpec {
+            forbid_latest_tag: Some(true),
+            require_liveness_probe: Some(true),
+            ..Default::default()
+        };
+        let mut pod = Pod::default();
+        assert!(matches!(
+            apply_workload_overrides(&policy, &pod),
+            Cow::Borrowed(_)
+        ));
+
+        pod.metadata.annotations = Some(annotations(&[("forbidLatestTag", "false")]));
+        let effective = apply_workload_overrides(&policy, &pod);
+        assert_eq!(effective.forbid_latest_tag, Some(false));
+        assert_eq!(effective.require_liveness_probe, Some(true));
+    }
+}
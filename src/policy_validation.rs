@@ -0,0 +1,841 @@
+//! Offline validation of DevOpsPolicy and ClusterDevOpsPolicy manifests.
+//!
+//! A manifest is checked against the OpenAPI schema of the generated CRD
+//! (types, ranges, patterns, enums, unknown fields), then against the
+//! cross-field rules: the CEL rules in [`SPEC_VALIDATIONS`], plus warnings
+//! for settings that contradict each other or have no effect. Nothing here
+//! talks to a cluster, so `policy validate` can run in CI before `kubectl
+//! apply`.
+
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::{
+    JSONSchemaProps, JSONSchemaPropsOrArray, JSONSchemaPropsOrBool,
+};
+use kube::CustomResourceExt;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::{API_GROUP, API_VERSION};
+use crate::clock::Timezone;
+use crate::conversion;
+use crate::crd::{
+    ClusterDevOpsPolicy, ClusterDevOpsPolicySpec, DevOpsPolicy, DevOpsPolicySpec, EnforcementMode,
+    QUANTITY_PATTERN, SPEC_VALIDATIONS, v1alpha1,
+};
+use crate::governance::ClassificationThresholds;
+use crate::output;
+
+/* ============================= TYPES ============================= */
+
+/// How serious a validation issue is. Errors would be rejected by the API
+/// server or break evaluation; warnings are accepted but likely mistakes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueLevel {
+    Error,
+    Warning,
+}
+
+/// One problem found in a policy manifest.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyIssue {
+    pub level: IssueLevel,
+    /// Dotted path of the offending field, e.g. `spec.defaultProbe.tcpPort`.
+    pub field: String,
+    pub message: String,
+}
+
+/// Validation result for one policy document of a file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyValidation {
+    pub kind: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub issues: Vec<PolicyIssue>,
+}
+
+impl PolicyValidation {
+    pub fn errors(&self) -> usize {
+        self.count(IssueLevel::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(IssueLevel::Warning)
+    }
+
+    fn count(&self, level: IssueLevel) -> usize {
+        self.issues.iter().filter(|i| i.level == level).count()
+    }
+}
+
+fn error(field: impl Into<String>, message: impl Into<String>) -> PolicyIssue {
+    PolicyIssue {
+        level: IssueLevel::Error,
+        field: field.into(),
+        message: message.into(),
+    }
+}
+
+fn warning(field: impl Into<String>, message: impl Into<String>) -> PolicyIssue {
+    PolicyIssue {
+        level: IssueLevel::Warning,
+        field: field.into(),
+        message: message.into(),
+    }
+}
+
+/* ============================= MANIFESTS ============================= */
+
+/// Validate every DevOpsPolicy and ClusterDevOpsPolicy in a multi-document
+/// YAML file; other kinds are skipped.
+pub fn validate_manifests(content: &str) -> anyhow::Result<Vec<PolicyValidation>> {
+    let mut results = Vec::new();
+    for value in output::yaml_documents(content)? {
+        let manifest = serde_json::to_value(&value)?;
+        let kind = manifest["kind"].as_str().unwrap_or_default();
+        if kind != "DevOpsPolicy" && kind != "ClusterDevOpsPolicy" {
+            continue;
+        }
+        results.push(PolicyValidation {
+            kind: kind.to_string(),
+            name: manifest["metadata"]["name"]
+                .as_str()
+                .unwrap_or("unnamed")
+                .to_string(),
+            namespace: manifest["metadata"]["namespace"]
+                .as_str()
+                .map(str::to_string),
+            issues: validate_manifest(&manifest),
+        });
+    }
+    Ok(results)
+}
+
+/// Validate one DevOpsPolicy or ClusterDevOpsPolicy manifest.
+///
+/// `v1alpha1` DevOpsPolicies are checked against the v1alpha1 schema, then
+/// converted to `v1` the way the conversion webhook would for the
+/// cross-field rules, which name the v1 fields.
+pub fn validate_manifest(manifest: &Value) -> Vec<PolicyIssue> {
+    let v1 = format!("{API_GROUP}/{API_VERSION}");
+    let kind = manifest["kind"].as_str().unwrap_or_default();
+    let api_version = manifest["apiVersion"].as_str().unwrap_or_default();
+    let Some(schema) = spec_schema(kind, api_version) else {
+        let found = if api_version.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("'{api_version}'")
+        };
+        return vec![error("apiVersion", format!("expected {v1}, found {found}"))];
+    };
+
+    let mut issues = Vec::new();
+    if manifest["metadata"]["name"]
+        .as_str()
+        .is_none_or(str::is_empty)
+    {
+        issues.push(error("metadata.name", "is required"));
+    }
+    if kind == "ClusterDevOpsPolicy" && manifest["metadata"].get("namespace").is_some() {
+        issues.push(warning(
+            "metadata.namespace",
+            "ClusterDevOpsPolicy is cluster-scoped; the namespace is ignored",
+        ));
+    }
+
+    let Some(spec) = manifest.get("spec") else {
+        issues.push(error("spec", "is required"));
+        return issues;
+    };
+    check_schema("spec", spec, &schema, &mut issues);
+    if issues.iter().any(|i| i.level == IssueLevel::Error) {
+        // Cross-field rules assume a well-typed spec.
+        return issues;
+    }
+
+    let spec = match conversion::convert_object(manifest.clone(), &v1) {
+        Ok(converted) => converted["spec"].clone(),
+        Err(e) => {
+            issues.push(error("spec", format!("cannot convert to {v1}: {e}")));
+            return issues;
+        }
+    };
+    let typed = if kind == "ClusterDevOpsPolicy" {
+        serde_json::from_value::<ClusterDevOpsPolicySpec>(spec).map(|s| s.policy)
+    } else {
+        serde_json::from_value::<DevOpsPolicySpec>(spec)
+    };
+    match typed {
+        Ok(spec) => issues.extend(validate_spec(&spec)),
+        Err(e) => issues.push(error("spec", e.to_string())),
+    }
+    issues
+}
+
+/// Validate a bare v1 DevOpsPolicy `spec`, as found in a bundle file: the
+/// schema check, then the cross-field rules.
+pub fn validate_spec_value(spec: &Value) -> Vec<PolicyIssue> {
+    let mut issues = Vec::new();
+    if let Some(schema) = spec_schema("DevOpsPolicy", &format!("{API_GROUP}/{API_VERSION}")) {
+        check_schema("spec", spec, &schema, &mut issues);
+    }
+    if issues.iter().any(|i| i.level == IssueLevel::Error) {
+        return issues;
+    }
+    match serde_json::from_value::<DevOpsPolicySpec>(spec.clone()) {
+        Ok(spec) => issues.extend(validate_spec(&spec)),
+        Err(e) => issues.push(error("spec", e.to_string())),
+    }
+    issues
+}
+
+/// The `spec` schema of the generated CRD for `kind` at `api_version`, or
+/// `None` if that version is not served.
+fn spec_schema(kind: &str, api_version: &str) -> Option<JSONSchemaProps> {
+    let (group, version) = api_version.split_once('/')?;
+    if group != API_GROUP {
+        return None;
+    }
+    let crd = match (kind, version) {
+        ("DevOpsPolicy", API_VERSION) => DevOpsPolicy::crd(),
+        ("DevOpsPolicy", "v1alpha1") => v1alpha1::DevOpsPolicy::crd(),
+        ("ClusterDevOpsPolicy", API_VERSION) => ClusterDevOpsPolicy::crd(),
+        _ => return None,
+    };
+    crd.spec
+        .versions
+        .into_iter()
+        .find(|v| v.name == version)
+        .and_then(|v| v.schema?.open_api_v3_schema?.properties?.remove("spec"))
+}
+
+/* ============================= SCHEMA ============================= */
+
+/// Check `value` against a structural CRD schema, the subset kube-derive
+/// generates: types, `nullable`, `enum`, numeric ranges, string patterns,
+/// required and unknown properties, and array items.
+fn check_schema(
+    path: &str,
+    value: &Value,
+    schema: &JSONSchemaProps,
+    issues: &mut Vec<PolicyIssue>,
+) {
+    if value.is_null() {
+        if !schema.nullable.unwrap_or(false) {
+            issues.push(error(path, "must not be null"));
+        }
+        return;
+    }
+
+    if let Some(expected) = schema.type_.as_deref() {
+        let matches = match expected {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" => value.is_boolean(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            _ => true,
+        };
+        if !matches {
+            issues.push(error(
+                path,
+                format!("expected {expected}, found {}", json_type(value)),
+            ));
+            return;
+        }
+    }
+
+    if let Some(allowed) = &schema.enum_
+        && !allowed.iter().any(|a| a.0 == *value)
+    {
+        let allowed: Vec<String> = allowed.iter().map(|a| a.0.to_string()).collect();
+        issues.push(error(
+            path,
+            format!("{value} is not one of {}", allowed.join(", ")),
+        ));
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(min) = schema.minimum
+            && number < min
+        {
+            issues.push(error(
+                path,
+                format!("{value} is below the minimum of {min}"),
+            ));
+        }
+        if let Some(max) = schema.maximum
+            && number > max
+        {
+            issues.push(error(
+                path,
+                format!("{value} is above the maximum of {max}"),
+            ));
+        }
+    }
+
+    if let (Some(pattern), Some(text)) = (&schema.pattern, value.as_str())
+        && Regex::new(pattern).is_ok_and(|re| !re.is_match(text))
+    {
+        let expected = if pattern == QUANTITY_PATTERN {
+            "a Kubernetes quantity such as 100m or 128Mi".to_string()
+        } else {
+            format!("a value matching {pattern}")
+        };
+        issues.push(error(path, format!("'{text}' is not {expected}")));
+    }
+
+    if let Some(object) = value.as_object() {
+        for field in schema.required.iter().flatten() {
+            if !object.contains_key(field) {
+                issues.push(error(format!("{path}.{field}"), "is required"));
+            }
+        }
+        let properties = schema.properties.as_ref();
+        for (key, child) in object {
+            let child_path = format!("{path}.{key}");
+            if let Some(child_schema) = properties.and_then(|p| p.get(key)) {
+                check_schema(&child_path, child, child_schema, issues);
+                continue;
+            }
+            match &schema.additional_properties {
+                Some(JSONSchemaPropsOrBool::Schema(child_schema)) => {
+                    check_schema(&child_path, child, child_schema, issues);
+                }
+                Some(JSONSchemaPropsOrBool::Bool(true)) => {}
+                _ if schema.x_kubernetes_preserve_unknown_fields == Some(true) => {}
+                _ => {
+                    let known = properties.into_iter().flat_map(|p| p.keys());
+                    let message = match closest(key, known) {
+                        Some(suggestion) => format!("unknown field, did you mean '{suggestion}'?"),
+                        None => "unknown field".to_string(),
+                    };
+                    issues.push(error(child_path, message));
+                }
+            }
+        }
+    }
+
+    if let (Some(items), Some(JSONSchemaPropsOrArray::Schema(item_schema))) =
+        (value.as_array(), &schema.items)
+    {
+        for (i, item) in items.iter().enumerate() {
+            check_schema(&format!("{path}[{i}]"), item, item_schema, issues);
+        }
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// The known field closest to a misspelled `key`, if it is a plausible typo
+/// or a truncation (`cooldown` for `cooldownSeconds`).
+fn closest<'a>(key: &str, known: impl Iterator<Item = &'a String>) -> Option<&'a str> {
+    let key = key.to_lowercase();
+    known
+        .map(|candidate| {
+            let lower = candidate.to_lowercase();
+            let distance = if lower.starts_with(&key) {
+                1
+            } else {
+                edit_distance(&key, &lower)
+            };
+            (distance, candidate)
+        })
+        .filter(|(distance, _)| *distance <= 3.max(key.len() / 4))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/* ============================= CROSS-FIELD RULES ============================= */
+
+/// Cross-field checks on a parsed spec.
+///
+/// The errors mirror [`SPEC_VALIDATIONS`] (which the API server enforces
+/// through CEL) and catch values the schema cannot, such as invalid tag
+/// regexes. Warnings flag settings that contradict each other or have no
+/// effect in the configured enforcement mode.
+pub fn validate_spec(spec: &DevOpsPolicySpec) -> Vec<PolicyIssue> {
+    let mut issues = Vec::new();
+    let enforce = spec.enforcement_mode == Some(EnforcementMode::Enforce);
+    let probes_required = spec.require_liveness_probe.unwrap_or(false)
+        || spec.require_readiness_probe.unwrap_or(false);
+
+    // CEL rules
+    if enforce && probes_required && spec.default_probe.is_none() {
+        issues.push(error("spec.defaultProbe", SPEC_VALIDATIONS[0].1));
+    }
+    if spec.profile_label.is_some() && spec.profiles.is_none() {
+        issues.push(error("spec.profileLabel", SPEC_VALIDATIONS[1].1));
+    }
+
+    // Values the schema cannot check
+    check_tag_patterns(
+        "spec",
+        spec.forbidden_tag_patterns.as_deref(),
+        spec.required_tag_pattern.as_deref(),
+        &mut issues,
+    );
+    for (name, profile) in spec.profiles.iter().flatten() {
+        check_tag_patterns(
+            &format!("spec.profiles.{name}"),
+            profile.forbidden_tag_patterns.as_deref(),
+            profile.required_tag_pattern.as_deref(),
+            &mut issues,
+        );
+    }
+    let metadata = [
+        ("requiredLabels", &spec.required_labels),
+        ("requiredAnnotations", &spec.required_annotations),
+    ];
+    for (field, required) in metadata {
+        for (key, pattern) in required.iter().flatten() {
+            if let Err(e) = Regex::new(pattern) {
+                issues.push(error(
+                    format!("spec.{field}.{key}"),
+                    format!("invalid regular expression: {}", regex_error(&e)),
+                ));
+            }
+        }
+    }
+    if let Some(timezone) = &spec.timezone
+        && let Err(e) = timezone.parse::<Timezone>()
+    {
+        issues.push(error("spec.timezone", e));
+    }
+    let thresholds = ClassificationThresholds::for_policy(spec);
+    if spec.health_thresholds.is_some() && !thresholds.is_ordered() {
+        issues.push(error(
+            "spec.healthThresholds",
+            format!(
+                "must descend from healthy to degraded, got healthy {}, stable {}, degraded {} (defaults included)",
+                thresholds.healthy, thresholds.stable, thresholds.degraded
+            ),
+        ));
+    }
+
+    // Contradictory or ineffective settings
+    if enforce && spec.require_resources.unwrap_or(false) && spec.default_resources.is_none() {
+        issues.push(warning(
+            "spec.defaultResources",
+            "enforce mode reports missing resources but cannot remediate them without defaultResources",
+        ));
+    }
+    if spec.default_probe.is_some() && !probes_required {
+        issues.push(warning(
+            "spec.defaultProbe",
+            "has no effect unless requireLivenessProbe or requireReadinessProbe is true",
+        ));
+    }
+    if spec.require_configured_probes.unwrap_or(false) && !probes_required {
+        issues.push(warning(
+            "spec.requireConfiguredProbes",
+            "has no effect unless requireLivenessProbe or requireReadinessProbe is true",
+        ));
+    }
+    if spec.eviction.is_some() && !spec.evict_crash_looping.unwrap_or(false) {
+        issues.push(warning(
+            "spec.eviction",
+            "has no effect unless evictCrashLooping is true",
+        ));
+    }
+    if !enforce {
+        let enforce_only = [
+            ("evictCrashLooping", spec.evict_crash_looping == Some(true)),
+            ("quarantine", spec.quarantine.is_some()),
+            (
+                "maxRemediationsPerCycle",
+                spec.max_remediations_per_cycle.is_some(),
+            ),
+            (
+                "remediationCooldownSeconds",
+                spec.remediation_cooldown_seconds.is_some(),
+            ),
+        ];
+        let mode = spec
+            .enforcement_mode
+            .as_ref()
+            .map_or("audit", EnforcementMode::as_str);
+        for (field, set) in enforce_only {
+            if set {
+                issues.push(warning(
+                    format!("spec.{field}"),
+                    format!("has no effect in {mode} mode; set enforcementMode: enforce"),
+                ));
+            }
+        }
+    }
+    issues
+}
+
+/// The last line of a regex error, which names the problem; the lines
+/// before it only repeat the pattern.
+fn regex_error(e: &regex::Error) -> String {
+    let message = e.to_string();
+    let last = message.lines().last().unwrap_or_default();
+    last.strip_prefix("error: ").unwrap_or(last).to_string()
+}
+
+fn check_tag_patterns(
+    path: &str,
+    forbidden: Option<&[String]>,
+    required: Option<&str>,
+    issues: &mut Vec<PolicyIssue>,
+) {
+    for (i, pattern) in forbidden.into_iter().flatten().enumerate() {
+        if let Err(e) = Regex::new(pattern) {
+            issues.push(error(
+                format!("{path}.forbiddenTagPatterns[{i}]"),
+                format!("invalid regular expression: {}", regex_error(&e)),
+            ));
+        }
+    }
+    if let Some(pattern) = required
+        && let Err(e) = Regex::new(pattern)
+    {
+        issues.push(error(
+            format!("{path}.requiredTagPattern"),
+            format!("invalid regular expression: {}", regex_error(&e)),
+        ));
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn manifest(spec: Value) -> Value {
+        json!({
+            "apiVersion": "devops.stochastic.io/v1",
+            "kind": "DevOpsPolicy",
+            "metadata": {"name": "web", "namespace": "prod"},
+            "spec": spec,
+        })
+    }
+
+    fn fields(issues: &[PolicyIssue], level: IssueLevel) -> Vec<&str> {
+        issues
+            .iter()
+            .filter(|i| i.level == level)
+            .map(|i| i.field.as_str())
+            .collect()
+    }
+
+    // ── schema ──
+
+    #[test]
+    fn test_valid_policy_has_no_issues() {
+        let issues = validate_manifest(&manifest(json!({
+            "forbidLatestTag": true,
+            "requireLivenessProbe": true,
+            "enforcementMode": "enforce",
+            "defaultProbe": {"tcpPort": 8080},
+            "defaultResources": {"cpuRequest": "100m", "memoryLimit": "256Mi"},
+            "severityOverrides": {"latestTag": "critical"},
+            "profiles": {"dev": {"maxRestartCount": 10}},
+        })));
+        assert!(issues.is_empty(), "{issues:?}");
+    }
+
+    #[test]
+    fn test_schema_errors() {
+        let issues = validate_manifest(&manifest(json!({
+            "forbidLatestTag": "yes",
+            "maxRestartCount": -1,
+            "enforcementMode": "strict",
+            "defaultProbe": {"tcpPort": 70000},
+            "defaultResources": {"cpuRequest": "100MB"},
+            "profiles": {"dev": {"forbidPendingDuration": 0}},
+            "forbiddenTagPatterns": ["^dev-", 3],
+        })));
+        assert_eq!(
+            fields(&issues, IssueLevel::Error),
+            [
+                "spec.defaultProbe.tcpPort",
+                "spec.defaultResources.cpuRequest",
+                "spec.enforcementMode",
+                "spec.forbidLatestTag",
+                "spec.forbiddenTagPatterns[1]",
+                "spec.maxRestartCount",
+                "spec.profiles.dev.forbidPendingDuration",
+            ]
+        );
+        let message = |field: &str| {
+            issues
+                .iter()
+                .find(|i| i.field == field)
+                .map(|i| i.message.clone())
+                .unwrap()
+        };
+        assert_eq!(
+            message("spec.forbidLatestTag"),
+            "expected boolean, found string"
+        );
+        assert!(message("spec.defaultResources.cpuRequest").contains("Kubernetes quantity"));
+        assert!(message("spec.enforcementMode").contains("\"enforce\""));
+        assert!(message("spec.defaultProbe.tcpPort").contains("maximum of 65535"));
+    }
+
+    #[test]
+    fn test_unknown_fields_suggest_known_ones() {
+        let issues = validate_manifest(&manifest(json!({
+            "requireLivenessProbes": true,
+            "eviction": {"cooldown": 60},
+            "somethingElse": 1,
+        })));
+        let messages: Vec<(&str, &str)> = issues
+            .iter()
+            .map(|i| (i.field.as_str(), i.message.as_str()))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    "spec.eviction.cooldown",
+                    "unknown field, did you mean 'cooldownSeconds'?"
+                ),
+                (
+                    "spec.requireLivenessProbes",
+                    "unknown field, did you mean 'requireLivenessProbe'?"
+                ),
+                ("spec.somethingElse", "unknown field"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_manifest_errors() {
+        let mut wrong_version = manifest(json!({}));
+        wrong_version["apiVersion"] = json!("devops.stochastic.io/v2");
+        let issues = validate_manifest(&wrong_version);
+        assert_eq!(issues[0].field, "apiVersion");
+        assert!(issues[0].message.contains("'devops.stochastic.io/v2'"));
+
+        let missing = json!({"apiVersion": "devops.stochastic.io/v1", "kind": "DevOpsPolicy"});
+        assert_eq!(
+            fields(&validate_manifest(&missing), IssueLevel::Error),
+            ["metadata.name", "spec"]
+        );
+
+        let cluster = json!({
+            "apiVersion": "devops.stochastic.io/v1",
+            "kind": "ClusterDevOpsPolicy",
+            "metadata": {"name": "all", "namespace": "prod"},
+            "spec": {"excludedNamespaces": ["kube-system"], "forbidLatestTag": true},
+        });
+        let issues = validate_manifest(&cluster);
+        assert_eq!(fields(&issues, IssueLevel::Error), Vec::<&str>::new());
+        assert_eq!(fields(&issues, IssueLevel::Warning), ["metadata.namespace"]);
+    }
+
+    #[test]
+    fn test_v1alpha1_checked_at_its_own_version() {
+        let old = json!({
+            "apiVersion": "devops.stochastic.io/v1alpha1",
+            "kind": "DevOpsPolicy",
+            "metadata": {"name": "old"},
+            "spec": {"mode": "enforce", "requireLivenessProbe": true, "maxRestarts": 3},
+        });
+        // Converted to v1 for the cross-field rules.
+        assert_eq!(
+            fields(&validate_manifest(&old), IssueLevel::Error),
+            ["spec.defaultProbe"]
+        );
+
+        let mut v1_field = old.clone();
+        v1_field["spec"] = json!({"maxRestartCount": 3});
+        assert_eq!(
+            fields(&validate_manifest(&v1_field), IssueLevel::Error),
+            ["spec.maxRestartCount"]
+        );
+    }
+
+    // ── cross-field rules ──
+
+    #[test]
+    fn test_cel_rules_mirrored() {
+        let spec = DevOpsPolicySpec {
+            require_readiness_probe: Some(true),
+            enforcement_mode: Some(EnforcementMode::Enforce),
+            profile_label: Some("env".to_string()),
+            ..Default::default()
+        };
+        let issues = validate_spec(&spec);
+        assert_eq!(
+            fields(&issues, IssueLevel::Error),
+            ["spec.defaultProbe", "spec.profileLabel"]
+        );
+        assert_eq!(issues[0].message, SPEC_VALIDATIONS[0].1);
+        assert_eq!(issues[1].message, SPEC_VALIDATIONS[1].1);
+
+        // Audit and dry-run fall back to reporting, so no defaults are needed.
+        let audit = DevOpsPolicySpec {
+            enforcement_mode: Some(EnforcementMode::DryRun),
+            profile_label: None,
+            ..spec
+        };
+        assert!(validate_spec(&audit).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        let spec = DevOpsPolicySpec {
+            forbidden_tag_patterns: Some(vec!["^dev-".to_string(), "(dev".to_string()]),
+            required_tag_pattern: Some("[v".to_string()),
+            timezone: Some("Europe/Berlin".to_string()),
+            health_thresholds: Some(crate::crd::HealthThresholds {
+                healthy: Some(50),
+                ..Default::default()
+            }),
+            profiles: Some(
+                [(
+                    "prod".to_string(),
+                    crate::crd::PolicyProfile {
+                        required_tag_pattern: Some("(".to_string()),
+                        ..Default::default()
+                    },
+                )]
+                .into(),
+            ),
+            ..Default::default()
+        };
+        let issues = validate_spec(&spec);
+        assert_eq!(
+            fields(&issues, IssueLevel::Error),
+            [
+                "spec.forbiddenTagPatterns[1]",
+                "spec.requiredTagPattern",
+                "spec.profiles.prod.requiredTagPattern",
+                "spec.timezone",
+                "spec.healthThresholds",
+            ]
+        );
+        assert_eq!(
+            issues[0].message,
+            "invalid regular expression: unclosed group"
+        );
+    }
+
+    #[test]
+    fn test_invalid_required_metadata_pattern() {
+        let spec = DevOpsPolicySpec {
+            required_labels: Some(
+                [
+                    ("team".to_string(), String::new()),
+                    ("env".to_string(), "prod|(staging".to_string()),
+                ]
+                .into(),
+            ),
+            required_annotations: Some([("owner".to_string(), "[a-z]+".to_string())].into()),
+            ..Default::default()
+        };
+        let issues = validate_spec(&spec);
+        assert_eq!(
+            fields(&issues, IssueLevel::Error),
+            ["spec.requiredLabels.env"]
+        );
+    }
+
+    #[test]
+    fn test_contradictory_settings_warn() {
+        let spec = DevOpsPolicySpec {
+            enforcement_mode: Some(EnforcementMode::Audit),
+            default_probe: Some(crate::crd::DefaultProbeConfig {
+                tcp_port: Some(8080),
+                initial_delay_seconds: None,
+                period_seconds: None,
+            }),
+            require_configured_probes: Some(true),
+            eviction: Some(Default::default()),
+            quarantine: Some(Default::default()),
+            max_remediations_per_cycle: Some(5),
+            ..Default::default()
+        };
+        let issues = validate_spec(&spec);
+        assert_eq!(fields(&issues, IssueLevel::Error), Vec::<&str>::new());
+        assert_eq!(
+            fields(&issues, IssueLevel::Warning),
+            [
+                "spec.defaultProbe",
+                "spec.requireConfiguredProbes",
+                "spec.eviction",
+                "spec.quarantine",
+                "spec.maxRemediationsPerCycle",
+            ]
+        );
+        assert!(issues[3].message.contains("in audit mode"));
+
+        let enforce = DevOpsPolicySpec {
+            enforcement_mode: Some(EnforcementMode::Enforce),
+            require_resources: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            fields(&validate_spec(&enforce), IssueLevel::Warning),
+            ["spec.defaultResources"]
+        );
+    }
+
+    #[test]
+    fn test_validate_manifests_skips_other_kinds() {
+        let content = r#"
+apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: settings
+---
+apiVersion: devops.stochastic.io/v1
+kind: DevOpsPolicy
+metadata:
+  name: web
+  namespace: prod
+spec:
+  forbidLatestTag: true
+---
+apiVersion: devops.stochastic.io/v1
+kind: ClusterDevOpsPolicy
+metadata:
+  name: all
+spec:
+  maxRestartCount: -5
+"#;
+        let results = validate_manifests(content).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "web");
+        assert_eq!(results[0].namespace.as_deref(), Some("prod"));
+        assert_eq!(results[0].errors(), 0);
+        assert_eq!(results[1].kind, "ClusterDevOpsPolicy");
+        assert_eq!(results[1].errors(), 1);
+    }
+}
@@ -0,0 +1,483 @@
+//! Scale-to-zero quarantine.
+//!
+//! In enforce mode, a policy with `quarantine.namespaces` scales Deployments
+//! and StatefulSets that carry a critical violation to zero. A
+//! `QuarantineRecord` in the workload's namespace keeps the original replica
+//! count. Restoring scales the workload back, deletes the record, and marks
+//! the workload approved so it is not quarantined again until the approval
+//! annotation is removed.
+
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference};
+use kube::Client;
+use kube::api::{Api, Patch, PatchParams, PostParams};
+use tracing::{info, warn};
+
+use crate::crd::{DevOpsPolicySpec, QuarantineRecord, QuarantineRecordSpec, Severity};
+use crate::enforcement::{self, WorkloadRef};
+use crate::governance::WorkloadEvaluation;
+
+/* ============================= CONSTANTS ============================= */
+
+/// Workload annotation set by `enforce restore`; approved workloads are
+/// never quarantined again. Its value is the approval timestamp.
+pub const QUARANTINE_APPROVED_ANNOTATION: &str = "devops.stochastic.io/quarantine-approved";
+
+const FIELD_MANAGER: &str = "kube-devops-operator";
+
+/* ============================= PLANNING ============================= */
+
+/// A workload to scale to zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarantineCandidate {
+    pub workload: WorkloadRef,
+    /// Critical violations, as `violation_type (container)`.
+    pub violations: Vec<String>,
+}
+
+/// Whether the policy quarantines workloads in `namespace`.
+///
+/// Requires enforce mode and a namespace listed in `quarantine.namespaces`;
+/// protected namespaces are never quarantined.
+pub fn is_quarantine_namespace(policy: &DevOpsPolicySpec, namespace: &str) -> bool {
+    enforcement::is_enforcement_enabled(policy)
+        && !enforcement::is_protected_namespace(namespace)
+        && policy
+            .quarantine
+            .as_ref()
+            .and_then(|q| q.namespaces.as_ref())
+            .is_some_and(|namespaces| namespaces.iter().any(|ns| ns == namespace))
+}
+
+/// Parse a `Kind/name` workload label into a workload that can be scaled.
+fn scalable_workload(label: &str, namespace: &str) -> Option<WorkloadRef> {
+    let (kind, name) = label.split_once('/')?;
+    matches!(kind, "Deployment" | "StatefulSet").then(|| WorkloadRef {
+        kind: kind.to_string(),
+        name: name.to_string(),
+        namespace: namespace.to_string(),
+    })
+}
+
+/// Workloads with at least one critical violation in a quarantine namespace.
+///
+/// Only Deployments and StatefulSets can be scaled; other workloads are
+/// left to the regular remediations.
+pub fn plan_quarantines(
+    workloads: &[WorkloadEvaluation],
+    policy: &DevOpsPolicySpec,
+) -> Vec<QuarantineCandidate> {
+    workloads
+        .iter()
+        .filter(|w| is_quarantine_namespace(policy, &w.namespace))
+        .filter_map(|w| {
+            let mut violations: Vec<String> = w
+                .violations
+                .iter()
+                .filter(|v| v.severity == Severity::Critical)
+                .map(|v| format!("{} ({})", v.violation_type, v.container_name))
+                .collect();
+            violations.dedup();
+            if violations.is_empty() {
+                return None;
+            }
+            Some(QuarantineCandidate {
+                workload: scalable_workload(&w.workload, &w.namespace)?,
+                violations,
+            })
+        })
+        .collect()
+}
+
+/// Name of the record for a workload: `<kind>-<name>`.
+pub fn record_name(workload: &WorkloadRef) -> String {
+    format!("{}-{}", workload.kind.to_lowercase(), workload.name)
+}
+
+/// Build the record for a quarantine. With `owner_uid` the record is owned
+/// by the workload, so deleting the workload removes it.
+pub fn build_record(
+    candidate: &QuarantineCandidate,
+    policy_name: &str,
+    original_replicas: i32,
+    owner_uid: Option<&str>,
+    quarantined_at: &str,
+) -> QuarantineRecord {
+    let workload = &candidate.workload;
+    QuarantineRecord {
+        metadata: ObjectMeta {
+            name: Some(record_name(workload)),
+            namespace: Some(workload.namespace.clone()),
+            owner_references: owner_uid.map(|uid| {
+                vec![OwnerReference {
+                    api_version: "apps/v1".to_string(),
+                    kind: workload.kind.clone(),
+                    name: workload.name.clone(),
+                    uid: uid.to_string(),
+                    ..Default::default()
+                }]
+            }),
+            ..Default::default()
+        },
+        spec: QuarantineRecordSpec {
+            workload_kind: workload.kind.clone(),
+            workload_name: workload.name.clone(),
+            policy_name: policy_name.to_string(),
+            original_replicas,
+            violations: candidate.violations.clone(),
+            quarantined_at: quarantined_at.to_string(),
+        },
+    }
+}
+
+/// Whether a workload's annotations approve it to run despite violations.
+pub fn is_quarantine_approved(annotations: Option<&BTreeMap<String, String>>) -> bool {
+    annotations.is_some_and(|a| a.contains_key(QUARANTINE_APPROVED_ANNOTATION))
+}
+
+/// Merge patch scaling a workload to zero.
+pub fn build_scale_down_patch() -> serde_json::Value {
+    serde_json::json!({ "spec": { "replicas": 0 } })
+}
+
+/// Merge patch restoring a workload's replicas and recording the approval.
+pub fn build_restore_patch(replicas: i32, approved_at: &str) -> serde_json::Value {
+    serde_json::json!({
+        "metadata": {
+            "annotations": {
+                QUARANTINE_APPROVED_ANNOTATION: approved_at
+            }
+        },
+        "spec": { "replicas": replicas }
+    })
+}
+
+/* ============================= ASYNC API ============================= */
+
+/// Outcome of quarantining one workload.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuarantineOutcome {
+    Quarantined {
+        original_replicas: i32,
+    },
+    /// A record already exists; the workload stays scaled down.
+    AlreadyQuarantined,
+    /// The workload carries the approval annotation.
+    Approved,
+    /// The workload already runs zero replicas.
+    AlreadyScaledDown,
+    Failed(String),
+}
+
+/// Replicas, annotations, and UID of a Deployment or StatefulSet.
+struct ScaleState {
+    replicas: i32,
+    annotations: BTreeMap<String, String>,
+    uid: Option<String>,
+}
+
+async fn get_scale_state(
+    workload: &WorkloadRef,
+    client: &Client,
+) -> Result<ScaleState, kube::Error> {
+    let (meta, replicas) = match workload.kind.as_str() {
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &workload.namespace);
+            let sts = api.get(&workload.name).await?;
+            (sts.metadata, sts.spec.and_then(|s| s.replicas))
+        }
+        _ => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), &workload.namespace);
+            let dep = api.get(&workload.name).await?;
+            (dep.metadata, dep.spec.and_then(|s| s.replicas))
+        }
+    };
+    Ok(ScaleState {
+        // Both kinds default to one replica when unset
+        replicas: replicas.unwrap_or(1),
+        annotations: meta.annotations.unwrap_or_default(),
+        uid: meta.uid,
+    })
+}
+
+async fn patch_scalable(
+    workload: &WorkloadRef,
+    client: &Client,
+    patch: &serde_json::Value,
+) -> Result<(), kube::Error> {
+    let params = PatchParams::apply(FIELD_MANAGER);
+    match workload.kind.as_str() {
+        "StatefulSet" => {
+            let api: Api<StatefulSet> = Api::namespaced(client.clone(), &workload.namespace);
+            api.patch(&workload.name, &params, &Patch::Merge(patch))
+                .await
+                .map(|_| ())
+        }
+        _ => {
+            let api: Api<Deployment> = Api::namespaced(client.clone(), &workload.namespace);
+            api.patch(&workload.name, &params, &Patch::Merge(patch))
+                .await
+                .map(|_| ())
+        }
+    }
+}
+
+/// Scale a workload to zero, recording its replica count first.
+///
+/// The record is created before scaling so the original count is never
+/// lost; it is deleted again if the scale-down fails.
+pub async fn quarantine_workload(
+    candidate: &QuarantineCandidate,
+    client: &Client,
+    policy_name: &str,
+    quarantined_at: &str,
+) -> QuarantineOutcome {
+    let workload = &candidate.workload;
+    let records: Api<QuarantineRecord> = Api::namespaced(client.clone(), &workload.namespace);
+    let name = record_name(workload);
+
+    match records.get_opt(&name).await {
+        Ok(Some(_)) => return QuarantineOutcome::AlreadyQuarantined,
+        Ok(None) => {}
+        Err(e) => return QuarantineOutcome::Failed(format!("Failed to read record: {e}")),
+    }
+
+    let state = match get_scale_state(workload, client).await {
+        Ok(s) => s,
+        Err(e) => return QuarantineOutcome::Failed(format!("Failed to read workload: {e}")),
+    };
+    if is_quarantine_approved(Some(&state.annotations)) {
+        return QuarantineOutcome::Approved;
+    }
+    if state.replicas == 0 {
+        return QuarantineOutcome::AlreadyScaledDown;
+    }
+
+    let record = build_record(
+        candidate,
+        policy_name,
+        state.replicas,
+        state.uid.as_deref(),
+        quarantined_at,
+    );
+    if let Err(e) = records.create(&PostParams::default(), &record).await {
+        return QuarantineOutcome::Failed(format!("Failed to create record: {e}"));
+    }
+
+    if let Err(e) = patch_scalable(workload, client, &build_scale_down_patch()).await {
+        if let Err(cleanup) = records.delete(&name, &Default::default()).await {
+            warn!(record = %name, error = %cleanup, "quarantine_record_cleanup_failed");
+        }
+        return QuarantineOutcome::Failed(format!("Scale-down failed: {e}"));
+    }
+
+    info!(
+        workload = %workload.key(),
+        replicas = state.replicas,
+        policy = %policy_name,
+        "workload_quarantined"
+    );
+    QuarantineOutcome::Quarantined {
+        original_replicas: state.replicas,
+    }
+}
+
+/// Read the record for a quarantined workload, if any.
+pub async fn get_record(
+    workload: &WorkloadRef,
+    client: &Client,
+) -> Result<Option<QuarantineRecord>, kube::Error> {
+    let records: Api<QuarantineRecord> = Api::namespaced(client.clone(), &workload.namespace);
+    records.get_opt(&record_name(workload)).await
+}
+
+/// Scale a quarantined workload back to its recorded replicas, approve it,
+/// and delete the record. Returns the record, or `None` when the workload
+/// is not quarantined.
+pub async fn restore_workload(
+    workload: &WorkloadRef,
+    client: &Client,
+    approved_at: &str,
+) -> Result<Option<QuarantineRecord>, kube::Error> {
+    let Some(record) = get_record(workload, client).await? else {
+        return Ok(None);
+    };
+
+    let patch = build_restore_patch(record.spec.original_replicas, approved_at);
+    patch_scalable(workload, client, &patch).await?;
+
+    let records: Api<QuarantineRecord> = Api::namespaced(client.clone(), &workload.namespace);
+    records
+        .delete(&record_name(workload), &Default::default())
+        .await?;
+
+    info!(
+        workload = %workload.key(),
+        replicas = record.spec.original_replicas,
+        "workload_restored"
+    );
+    Ok(Some(record))
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crd::{EnforcementMode, QuarantineConfig};
+    use crate::governance::{PodMetrics, ViolationDetail};
+
+    fn make_policy(namespaces: &[&str]) -> DevOpsPolicySpec {
+        DevOpsPolicySpec {
+            enforcement_mode: Some(EnforcementMode::Enforce),
+            quarantine: Some(QuarantineConfig {
+                namespaces: Some(namespaces.iter().map(|n| n.to_string()).collect()),
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn violation(violation_type: &str, severity: Severity) -> ViolationDetail {
+        ViolationDetail {
+            violation_type: violation_type.to_string(),
+            severity,
+            pod_name: "web-abc".to_string(),
+            namespace: "untrusted".to_string(),
+            container_name: "app".to_string(),
+            message: String::new(),
+        }
+    }
+
+    fn evaluation(
+        workload: &str,
+        namespace: &str,
+        violations: Vec<ViolationDetail>,
+    ) -> WorkloadEvaluation {
+        WorkloadEvaluation {
+            workload: workload.to_string(),
+            namespace: namespace.to_string(),
+            replicas: 1,
+            metrics: PodMetrics::default(),
+            violations,
+        }
+    }
+
+    #[test]
+    fn test_is_quarantine_namespace() {
+        let policy = make_policy(&["untrusted", "kube-system"]);
+        assert!(is_quarantine_namespace(&policy, "untrusted"));
+        assert!(!is_quarantine_namespace(&policy, "production"));
+        assert!(!is_quarantine_namespace(&policy, "kube-system"));
+
+        let audit = DevOpsPolicySpec {
+            enforcement_mode: Some(EnforcementMode::Audit),
+            ..make_policy(&["untrusted"])
+        };
+        assert!(!is_quarantine_namespace(&audit, "untrusted"));
+        assert!(!is_quarantine_namespace(
+            &DevOpsPolicySpec::default(),
+            "untrusted"
+        ));
+    }
+
+    #[test]
+    fn test_plan_quarantines_requires_critical_violation() {
+        let policy = make_policy(&["untrusted"]);
+        let workloads = vec![
+            evaluation(
+                "Deployment/web",
+                "untrusted",
+                vec![
+                    violation("latest_tag", Severity::Critical),
+                    violation("missing_liveness", Severity::High),
+                ],
+            ),
+            evaluation(
+                "Deployment/api",
+                "untrusted",
+                vec![violation("missing_liveness", Severity::High)],
+            ),
+            evaluation(
+                "Deployment/other",
+                "production",
+                vec![violation("latest_tag", Severity::Critical)],
+            ),
+        ];
+        let plan = plan_quarantines(&workloads, &policy);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].workload.name, "web");
+        assert_eq!(plan[0].violations, vec!["latest_tag (app)"]);
+    }
+
+    #[test]
+    fn test_plan_quarantines_only_scalable_kinds() {
+        let policy = make_policy(&["untrusted"]);
+        let critical = || vec![violation("latest_tag", Severity::Critical)];
+        let workloads = vec![
+            evaluation("StatefulSet/db", "untrusted", critical()),
+            evaluation("DaemonSet/agent", "untrusted", critical()),
+            evaluation("Pod/bare", "untrusted", critical()),
+            evaluation("Job/migrate", "untrusted", critical()),
+        ];
+        let plan = plan_quarantines(&workloads, &policy);
+        assert_eq!(plan.len(), 1);
+        assert_eq!(plan[0].workload.kind, "StatefulSet");
+    }
+
+    #[test]
+    fn test_build_record_owned_by_workload() {
+        let candidate = QuarantineCandidate {
+            workload: WorkloadRef {
+                kind: "Deployment".to_string(),
+                name: "web".to_string(),
+                namespace: "untrusted".to_string(),
+            },
+            violations: vec!["latest_tag (app)".to_string()],
+        };
+        let record = build_record(
+            &candidate,
+            "strict",
+            3,
+            Some("uid-1"),
+            "2026-01-01T00:00:00Z",
+        );
+
+        assert_eq!(record.metadata.name.as_deref(), Some("deployment-web"));
+        assert_eq!(record.metadata.namespace.as_deref(), Some("untrusted"));
+        let owner = &record.metadata.owner_references.as_ref().unwrap()[0];
+        assert_eq!(
+            (owner.kind.as_str(), owner.uid.as_str()),
+            ("Deployment", "uid-1")
+        );
+        assert_eq!(record.spec.original_replicas, 3);
+        assert_eq!(record.spec.policy_name, "strict");
+
+        let unowned = build_record(&candidate, "strict", 3, None, "2026-01-01T00:00:00Z");
+        assert!(unowned.metadata.owner_references.is_none());
+    }
+
+    #[test]
+    fn test_patches() {
+        assert_eq!(build_scale_down_patch()["spec"]["replicas"], 0);
+
+        let restore = build_restore_patch(4, "2026-01-01T00:00:00Z");
+        assert_eq!(restore["spec"]["replicas"], 4);
+        assert_eq!(
+            restore["metadata"]["annotations"][QUARANTINE_APPROVED_ANNOTATION],
+            "2026-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn test_is_quarantine_approved() {
+        let approved = BTreeMap::from([(
+            QUARANTINE_APPROVED_ANNOTATION.to_string(),
+            "2026-01-01T00:00:00Z".to_string(),
+        )]);
+        assert!(is_quarantine_approved(Some(&approved)));
+        assert!(!is_quarantine_approved(Some(&BTreeMap::new())));
+        assert!(!is_quarantine_approved(None));
+    }
+}
@@ -0,0 +1,492 @@
+use std::collections::BTreeSet;
+
+use k8s_openapi::api::authorization::v1::{
+    ResourceAttributes, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec,
+};
+use kube::Client;
+use kube::api::{Api, PostParams};
+use tracing::warn;
+
+/* ============================= FEATURES ============================= */
+
+/// Workload patching (enforce mode) and `cleanupOnDelete`.
+pub const FEATURE_ENFORCEMENT: &str = "enforcement";
+
+/// Creating and pruning `PolicyAuditResult` resources.
+pub const FEATURE_AUDIT_RESULTS: &str = "audit-results";
+
+/// Job / CronJob template evaluation.
+pub const FEATURE_BATCH_WORKLOADS: &str = "batch-workloads";
+
+/// Checks on multi-replica Deployments and StatefulSets
+/// (`requirePodDisruptionBudget`, `requireSpreadConstraints`).
+pub const FEATURE_REPLICATED_WORKLOADS: &str = "replicated-workloads";
+
+/// `ClusterDevOpsPolicy` resolution and reconciliation.
+pub const FEATURE_CLUSTER_POLICIES: &str = "cluster-policies";
+
+/// Scale-to-zero quarantine (`quarantine.namespaces`).
+pub const FEATURE_QUARANTINE: &str = "quarantine";
+
+/// Namespace label lookup for policy `profiles`.
+pub const FEATURE_PROFILES: &str = "profiles";
+
+/// Crash-loop pod eviction (`evictCrashLooping`).
+pub const FEATURE_EVICTION: &str = "pod-eviction";
+
+/// Kubernetes Events recording remediation diffs on patched workloads.
+pub const FEATURE_EVENTS: &str = "events";
+
+/// Policy-aware evaluation in the watch controller (built-in checks otherwise).
+pub const FEATURE_POLICIES: &str = "policies";
+
+/* ============================= TYPES ============================= */
+
+/// A single permission a component needs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permission {
+    pub verb: &'static str,
+    pub group: &'static str,
+    pub resource: &'static str,
+    pub subresource: Option<&'static str>,
+    /// Namespace for namespaced checks; `None` checks cluster-wide access.
+    pub namespace: Option<String>,
+    /// Optional feature disabled when this permission is missing.
+    /// `None` means the component cannot run without it.
+    pub feature: Option<&'static str>,
+}
+
+impl Permission {
+    const fn required(verb: &'static str, group: &'static str, resource: &'static str) -> Self {
+        Self {
+            verb,
+            group,
+            resource,
+            subresource: None,
+            namespace: None,
+            feature: None,
+        }
+    }
+
+    const fn optional(
+        verb: &'static str,
+        group: &'static str,
+        resource: &'static str,
+        feature: &'static str,
+    ) -> Self {
+        Self {
+            verb,
+            group,
+            resource,
+            subresource: None,
+            namespace: None,
+            feature: Some(feature),
+        }
+    }
+
+    /// Human-readable form, e.g. `patch apps/deployments`.
+    pub fn describe(&self) -> String {
+        let group = if self.group.is_empty() {
+            "core"
+        } else {
+            self.group
+        };
+        let sub = self
+            .subresource
+            .map(|s| format!("/{s}"))
+            .unwrap_or_default();
+        let ns = self
+            .namespace
+            .as_ref()
+            .map(|n| format!(" (namespace {n})"))
+            .unwrap_or_default();
+        format!("{} {group}/{}{sub}{ns}", self.verb, self.resource)
+    }
+}
+
+/// Outcome of checking a set of permissions.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RbacReport {
+    /// Permissions the component cannot run without.
+    pub missing_required: Vec<String>,
+    /// Optional permissions that are missing.
+    pub missing_optional: Vec<String>,
+    /// Features disabled because of missing optional permissions.
+    pub degraded_features: BTreeSet<&'static str>,
+}
+
+impl RbacReport {
+    /// Whether a feature's permissions were all granted.
+    pub fn feature_enabled(&self, feature: &str) -> bool {
+        !self.degraded_features.contains(feature)
+    }
+
+    /// One-line startup status.
+    pub fn summary(&self) -> String {
+        if !self.missing_required.is_empty() {
+            format!("FAIL ({} missing)", self.missing_required.len())
+        } else if self.degraded_features.is_empty() {
+            "OK".to_string()
+        } else {
+            let features: Vec<&str> = self.degraded_features.iter().copied().collect();
+            format!("degraded ({} disabled)", features.join(", "))
+        }
+    }
+
+    /// Fail with the list of missing permissions when any required one is absent.
+    pub fn ensure_required(&self) -> anyhow::Result<()> {
+        if self.missing_required.is_empty() {
+            return Ok(());
+        }
+        let list: Vec<String> = self
+            .missing_required
+            .iter()
+            .map(|p| format!("  - {p}"))
+            .collect();
+        anyhow::bail!(
+            "Missing required RBAC permissions:\n{}\nGrant them in the ClusterRole (see 'deploy rbac').",
+            list.join("\n")
+        )
+    }
+}
+
+/* ============================= COMPONENT PERMISSIONS ============================= */
+
+/// Permissions needed by the watch controller. With `namespace`, pods and
+/// DevOpsPolicies are checked in that namespace only.
+pub fn watch_permissions(read_only: bool, namespace: Option<&str>) -> Vec<Permission> {
+    let scoped = |perm: Permission| Permission {
+        namespace: namespace.map(str::to_string),
+        ..perm
+    };
+    let mut perms = vec![
+        scoped(Permission::required("list", "", "pods")),
+        scoped(Permission::required("watch", "", "pods")),
+    ];
+    for verb in ["list", "watch"] {
+        perms.push(scoped(Permission::optional(
+            verb,
+            "devops.stochastic.io",
+            "devopspolicies",
+            FEATURE_POLICIES,
+        )));
+        perms.push(Permission::optional(
+            verb,
+            "devops.stochastic.io",
+            "clusterdevopspolicies",
+            FEATURE_CLUSTER_POLICIES,
+        ));
+    }
+    if !read_only {
+        for verb in ["get", "create", "update"] {
+            perms.push(Permission {
+                namespace: Some("kube-devops".to_string()),
+                ..Permission::required(verb, "coordination.k8s.io", "leases")
+            });
+        }
+    }
+    perms
+}
+
+/// Permissions needed by the reconcile operator.
+pub fn reconcile_permissions(read_only: bool) -> Vec<Permission> {
+    let mut perms = vec![
+        Permission::required("list", "devops.stochastic.io", "devopspolicies"),
+        Permission::required("watch", "devops.stochastic.io", "devopspolicies"),
+        Permission::required("list", "", "pods"),
+        Permission::required("watch", "", "pods"),
+        Permission::optional("list", "batch", "jobs", FEATURE_BATCH_WORKLOADS),
+        Permission::optional("list", "batch", "cronjobs", FEATURE_BATCH_WORKLOADS),
+        Permission::optional("list", "apps", "deployments", FEATURE_REPLICATED_WORKLOADS),
+        Permission::optional("list", "apps", "statefulsets", FEATURE_REPLICATED_WORKLOADS),
+        Permission::optional(
+            "list",
+            "policy",
+            "poddisruptionbudgets",
+            FEATURE_REPLICATED_WORKLOADS,
+        ),
+        Permission::optional(
+            "list",
+            "devops.stochastic.io",
+            "clusterdevopspolicies",
+            FEATURE_CLUSTER_POLICIES,
+        ),
+        Permission::optional(
+            "watch",
+            "devops.stochastic.io",
+            "clusterdevopspolicies",
+            FEATURE_CLUSTER_POLICIES,
+        ),
+        Permission::optional("get", "", "namespaces", FEATURE_PROFILES),
+        Permission::optional("list", "", "namespaces", FEATURE_PROFILES),
+    ];
+    if read_only {
+        return perms;
+    }
+
+    // Finalizers and status
+    perms.push(Permission::required(
+        "patch",
+        "devops.stochastic.io",
+        "devopspolicies",
+    ));
+    perms.push(Permission {
+        subresource: Some("status"),
+        ..Permission::required("patch", "devops.stochastic.io", "devopspolicies")
+    });
+    perms.push(Permission {
+        subresource: Some("status"),
+        ..Permission::optional(
+            "patch",
+            "devops.stochastic.io",
+            "clusterdevopspolicies",
+            FEATURE_CLUSTER_POLICIES,
+        )
+    });
+
+    for verb in ["create", "list", "delete"] {
+        perms.push(Permission::optional(
+            verb,
+            "devops.stochastic.io",
+            "policyauditresults",
+            FEATURE_AUDIT_RESULTS,
+        ));
+    }
+    for resource in ["deployments", "statefulsets", "daemonsets"] {
+        for verb in ["get", "list", "patch"] {
+            perms.push(Permission::optional(
+                verb,
+                "apps",
+                resource,
+                FEATURE_ENFORCEMENT,
+            ));
+        }
+    }
+    for verb in ["get", "create", "delete"] {
+        perms.push(Permission::optional(
+            verb,
+            "devops.stochastic.io",
+            "quarantinerecords",
+            FEATURE_QUARANTINE,
+        ));
+    }
+    perms.push(Permission {
+        subresource: Some("eviction"),
+        ..Permission::optional("create", "", "pods", FEATURE_EVICTION)
+    });
+    perms.push(Permission::optional(
+        "create",
+        "events.k8s.io",
+        "events",
+        FEATURE_EVENTS,
+    ));
+    perms
+}
+
+/// Permissions needed by the admission webhook.
+pub fn webhook_permissions() -> Vec<Permission> {
+    vec![
+        Permission::required("list", "devops.stochastic.io", "devopspolicies"),
+        Permission::required("watch", "devops.stochastic.io", "devopspolicies"),
+        Permission::optional(
+            "list",
+            "devops.stochastic.io",
+            "clusterdevopspolicies",
+            FEATURE_CLUSTER_POLICIES,
+        ),
+        Permission::optional(
+            "watch",
+            "devops.stochastic.io",
+            "clusterdevopspolicies",
+            FEATURE_CLUSTER_POLICIES,
+        ),
+        Permission::optional("list", "", "namespaces", FEATURE_PROFILES),
+        Permission::optional("watch", "", "namespaces", FEATURE_PROFILES),
+    ]
+}
+
+/* ============================= EVALUATION ============================= */
+
+/// Build a report from `(permission, allowed)` pairs.
+pub fn build_report(results: &[(Permission, bool)]) -> RbacReport {
+    let mut report = RbacReport::default();
+    for (perm, allowed) in results {
+        if *allowed {
+            continue;
+        }
+        match perm.feature {
+            None => report.missing_required.push(perm.describe()),
+            Some(feature) => {
+                report.missing_optional.push(perm.describe());
+                report.degraded_features.insert(feature);
+            }
+        }
+    }
+    report
+}
+
+/// Check every permission with a `SelfSubjectAccessReview`.
+pub async fn verify(client: &Client, permissions: &[Permission]) -> anyhow::Result<RbacReport> {
+    let api: Api<SelfSubjectAccessReview> = Api::all(client.clone());
+
+    let checks = permissions.iter().map(|perm| {
+        let api = api.clone();
+        async move {
+            let review = SelfSubjectAccessReview {
+                spec: SelfSubjectAccessReviewSpec {
+                    resource_attributes: Some(ResourceAttributes {
+                        verb: Some(perm.verb.to_string()),
+                        group: Some(perm.group.to_string()),
+                        resource: Some(perm.resource.to_string()),
+                        subresource: perm.subresource.map(str::to_string),
+                        namespace: perm.namespace.clone(),
+                        ..Default::default()
+                    }),
+                    non_resource_attributes: None,
+                },
+                ..Default::default()
+            };
+            let response = api.create(&PostParams::default(), &review).await?;
+            let allowed = response.status.is_some_and(|s| s.allowed);
+            Ok::<_, kube::Error>((perm.clone(), allowed))
+        }
+    });
+
+    let results: Vec<(Permission, bool)> = futures::future::try_join_all(checks)
+        .await
+        .map_err(|e| anyhow::anyhow!("SelfSubjectAccessReview failed: {e}"))?;
+
+    Ok(build_report(&results))
+}
+
+/// Verify permissions at startup, printing the status line.
+///
+/// Fails fast when a required permission is missing; missing optional
+/// permissions are logged and reported through the returned feature flags.
+pub async fn startup_check(
+    client: &Client,
+    permissions: &[Permission],
+) -> anyhow::Result<RbacReport> {
+    print!("  RBAC permissions ............ ");
+    let report = match verify(client, permissions).await {
+        Ok(report) => report,
+        Err(e) => {
+            println!("FAIL");
+            return Err(e);
+        }
+    };
+    println!("{}", report.summary());
+
+    for missing in &report.missing_optional {
+        warn!(permission = %missing, "rbac_permission_missing");
+    }
+    report.ensure_required()?;
+    Ok(report)
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe() {
+        let p = Permission {
+            subresource: Some("status"),
+            ..Permission::required("patch", "devops.stochastic.io", "devopspolicies")
+        };
+        assert_eq!(
+            p.describe(),
+            "patch devops.stochastic.io/devopspolicies/status"
+        );
+
+        let lease = Permission {
+            namespace: Some("kube-devops".to_string()),
+            ..Permission::required("create", "coordination.k8s.io", "leases")
+        };
+        assert_eq!(
+            lease.describe(),
+            "create coordination.k8s.io/leases (namespace kube-devops)"
+        );
+        assert_eq!(
+            Permission::required("list", "", "pods").describe(),
+            "list core/pods"
+        );
+    }
+
+    #[test]
+    fn test_read_only_needs_no_write_verbs() {
+        let write_verbs = ["create", "patch", "update", "delete"];
+        for perms in [reconcile_permissions(true), watch_permissions(true, None)] {
+            assert!(perms.iter().all(|p| !write_verbs.contains(&p.verb)));
+        }
+        assert!(
+            reconcile_permissions(false)
+                .iter()
+                .any(|p| p.subresource == Some("status"))
+        );
+    }
+
+    #[test]
+    fn test_watch_permissions_scoped_to_namespace() {
+        let perms = watch_permissions(true, Some("team-a"));
+        let pods = perms.iter().find(|p| p.resource == "pods").unwrap();
+        assert_eq!(pods.describe(), "list core/pods (namespace team-a)");
+        let cluster = perms
+            .iter()
+            .find(|p| p.resource == "clusterdevopspolicies")
+            .unwrap();
+        assert_eq!(cluster.namespace, None);
+        assert_eq!(cluster.feature, Some(FEATURE_CLUSTER_POLICIES));
+        assert!(
+            watch_permissions(true, None)
+                .iter()
+                .all(|p| p.namespace.is_none())
+        );
+    }
+
+    #[test]
+    fn test_build_report_all_allowed() {
+        let results: Vec<_> = reconcile_permissions(false)
+            .into_iter()
+            .map(|p| (p, true))
+            .collect();
+        let report = build_report(&results);
+        assert_eq!(report, RbacReport::default());
+        assert_eq!(report.summary(), "OK");
+        assert!(report.ensure_required().is_ok());
+    }
+
+    #[test]
+    fn test_build_report_degrades_optional_features() {
+        let results: Vec<_> = reconcile_permissions(false)
+            .into_iter()
+            .map(|p| {
+                let allowed = p.group != "apps" || p.feature != Some(FEATURE_ENFORCEMENT);
+                (p, allowed)
+            })
+            .collect();
+        let report = build_report(&results);
+        assert!(report.missing_required.is_empty());
+        assert_eq!(report.missing_optional.len(), 9);
+        assert!(!report.feature_enabled(FEATURE_ENFORCEMENT));
+        assert!(report.feature_enabled(FEATURE_AUDIT_RESULTS));
+        assert_eq!(report.summary(), "degraded (enforcement disabled)");
+    }
+
+    #[test]
+    fn test_build_report_missing_required_fails() {
+        let results = vec![
+            (Permission::required("list", "", "pods"), false),
+            (
+                Permission::optional("list", "batch", "jobs", FEATURE_BATCH_WORKLOADS),
+                true,
+            ),
+        ];
+        let report = build_report(&results);
+        assert_eq!(report.summary(), "FAIL (1 missing)");
+        let err = report.ensure_required().unwrap_err().to_string();
+        assert!(err.contains("  - list core/pods"));
+    }
+}
@@ -0,0 +1,530 @@
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use tracing_subscriber::EnvFilter;
+
+use kube_devops::admission::FailureMode;
+use kube_devops::crd::Severity;
+
+use crate::commands::audit_sink::AuditSinkConfig;
+use crate::commands::client::ClientSettings;
+use crate::commands::reconcile::ReconcileSettings;
+use crate::commands::watch::{LeaseSettings, NamespaceFilter, WatchSettings};
+use crate::commands::webhook::ServeSettings;
+use crate::logging::{self, LogFormat, LoggingSettings};
+
+/* ============================= FILE FORMAT ============================= */
+
+/// Settings file passed with `--config`, covering the long-running commands.
+///
+/// Every field is optional. Command-line flags (and their environment
+/// variables) take precedence; unset values keep the built-in defaults.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct Settings {
+    #[serde(default)]
+    pub(crate) client: ClientConfig,
+    #[serde(default)]
+    pub(crate) logging: LoggingConfig,
+    #[serde(default)]
+    pub(crate) watch: WatchConfig,
+    #[serde(default)]
+    pub(crate) reconcile: ReconcileConfig,
+    #[serde(default)]
+    pub(crate) webhook: WebhookConfig,
+}
+
+/// Kubernetes API client settings of every command; also built from the
+/// global flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct ClientConfig {
+    pub(crate) connect_timeout_seconds: Option<u64>,
+    pub(crate) request_timeout_seconds: Option<u64>,
+    pub(crate) max_retries: Option<u32>,
+    pub(crate) breaker_threshold: Option<u32>,
+    pub(crate) breaker_cooldown_seconds: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct LoggingConfig {
+    /// `tracing` filter directive, e.g. `info,kube=warn`. `RUST_LOG` wins.
+    pub(crate) level: Option<String>,
+    /// Per-target overrides applied on top of `level` or `RUST_LOG`.
+    #[serde(default)]
+    pub(crate) filters: Vec<String>,
+    pub(crate) format: Option<LogFormat>,
+    pub(crate) file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct WatchConfig {
+    pub(crate) addr: Option<SocketAddr>,
+    /// Watch only this namespace.
+    pub(crate) namespace: Option<String>,
+    #[serde(default)]
+    pub(crate) namespaces: NamespaceFilter,
+    pub(crate) pod_detail_capacity: Option<usize>,
+    pub(crate) resync_interval_seconds: Option<u64>,
+    #[serde(default)]
+    pub(crate) lease: LeaseConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct LeaseConfig {
+    pub(crate) name: Option<String>,
+    pub(crate) namespace: Option<String>,
+    pub(crate) duration_seconds: Option<i32>,
+    pub(crate) renew_interval_seconds: Option<u64>,
+    pub(crate) retry_interval_seconds: Option<u64>,
+}
+
+/// `reconcile` settings; also built from the command-line flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct ReconcileConfig {
+    pub(crate) requeue_interval_seconds: Option<u64>,
+    pub(crate) metrics_addr: Option<SocketAddr>,
+    pub(crate) audit_retention: Option<usize>,
+    pub(crate) audit_gc_interval_seconds: Option<u64>,
+    pub(crate) list_page_size: Option<u32>,
+    pub(crate) pod_event_debounce_seconds: Option<u64>,
+    /// Long-term storage each PolicyAuditResult is also exported to.
+    pub(crate) audit_sink: Option<AuditSinkConfig>,
+}
+
+/// `webhook serve` settings; also built from the command-line flags.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct WebhookConfig {
+    pub(crate) addr: Option<SocketAddr>,
+    pub(crate) tls_cert: Option<String>,
+    pub(crate) tls_key: Option<String>,
+    pub(crate) failure_mode: Option<FailureMode>,
+    pub(crate) timeout_seconds: Option<u32>,
+    pub(crate) min_deny_severity: Option<Severity>,
+}
+
+/* ============================= LOADING ============================= */
+
+impl Settings {
+    /// Read and validate a YAML settings file.
+    pub(crate) fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let settings: Self = serde_yaml::from_str(&text)
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+        settings
+            .validate()
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+        Ok(settings)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let Some(level) = &self.logging.level {
+            EnvFilter::try_new(level)
+                .with_context(|| format!("logging.level '{level}' is not a valid filter"))?;
+        }
+        for directive in &self.logging.filters {
+            logging::parse_directive(directive).context("Invalid logging.filters entry")?;
+        }
+        let client = &self.client;
+        for (name, value) in [
+            ("connectTimeoutSeconds", client.connect_timeout_seconds),
+            ("requestTimeoutSeconds", client.request_timeout_seconds),
+            ("breakerThreshold", client.breaker_threshold.map(u64::from)),
+            ("breakerCooldownSeconds", client.breaker_cooldown_seconds),
+        ] {
+            if value == Some(0) {
+                anyhow::bail!("client.{name} must be at least 1");
+            }
+        }
+        if self.watch.pod_detail_capacity == Some(0) {
+            anyhow::bail!("watch.podDetailCapacity must be at least 1");
+        }
+        if self.watch.resync_interval_seconds == Some(0) {
+            anyhow::bail!("watch.resyncIntervalSeconds must be at least 1");
+        }
+        if self.reconcile.requeue_interval_seconds == Some(0) {
+            anyhow::bail!("reconcile.requeueIntervalSeconds must be at least 1");
+        }
+        if self.reconcile.audit_retention == Some(0) {
+            anyhow::bail!("reconcile.auditRetention must be at least 1");
+        }
+        if self.reconcile.audit_gc_interval_seconds == Some(0) {
+            anyhow::bail!("reconcile.auditGcIntervalSeconds must be at least 1");
+        }
+        if self.reconcile.list_page_size == Some(0) {
+            anyhow::bail!("reconcile.listPageSize must be at least 1");
+        }
+        if let Some(sink) = &self.reconcile.audit_sink {
+            sink.validate()?;
+        }
+        if let Some(timeout) = self.webhook.timeout_seconds
+            && !(1..=30).contains(&timeout)
+        {
+            anyhow::bail!("webhook.timeoutSeconds must be between 1 and 30, got {timeout}");
+        }
+        let lease = self.watch(&WatchConfig::default()).lease;
+        if lease.duration_seconds < 1 || lease.retry_interval.is_zero() {
+            anyhow::bail!("watch.lease durations must be at least 1 second");
+        }
+        if lease.renew_interval.is_zero()
+            || lease.renew_interval.as_secs() >= lease.duration_seconds as u64
+        {
+            anyhow::bail!(
+                "watch.lease.renewIntervalSeconds must be between 1 and durationSeconds ({}) - 1",
+                lease.duration_seconds
+            );
+        }
+        Ok(())
+    }
+}
+
+/* ============================= RESOLUTION ============================= */
+
+impl Settings {
+    /// API client settings: `flags`, then the file, then the defaults.
+    pub(crate) fn client(&self, flags: &ClientConfig) -> ClientSettings {
+        let defaults = ClientSettings::default();
+        let file = &self.client;
+        let secs =
+            |value: Option<u64>, default: Duration| value.map_or(default, Duration::from_secs);
+        ClientSettings {
+            connect_timeout: secs(
+                flags
+                    .connect_timeout_seconds
+                    .or(file.connect_timeout_seconds),
+                defaults.connect_timeout,
+            ),
+            request_timeout: secs(
+                flags
+                    .request_timeout_seconds
+                    .or(file.request_timeout_seconds),
+                defaults.request_timeout,
+            ),
+            max_retries: flags
+                .max_retries
+                .or(file.max_retries)
+                .unwrap_or(defaults.max_retries),
+            breaker_threshold: flags
+                .breaker_threshold
+                .or(file.breaker_threshold)
+                .unwrap_or(defaults.breaker_threshold),
+            breaker_cooldown: secs(
+                flags
+                    .breaker_cooldown_seconds
+                    .or(file.breaker_cooldown_seconds),
+                defaults.breaker_cooldown,
+            ),
+        }
+    }
+
+    /// Logging settings: `flags`, then the file, then the defaults.
+    /// Filter overrides from both are kept, flags last so they win.
+    pub(crate) fn logging(&self, flags: &LoggingConfig) -> LoggingSettings {
+        let defaults = LoggingSettings::default();
+        let file = &self.logging;
+        LoggingSettings {
+            level: flags
+                .level
+                .clone()
+                .or_else(|| file.level.clone())
+                .unwrap_or(defaults.level),
+            filters: file.filters.iter().chain(&flags.filters).cloned().collect(),
+            format: flags.format.or(file.format).unwrap_or(defaults.format),
+            file: flags.file.clone().or_else(|| file.file.clone()),
+        }
+    }
+
+    /// Watch settings: `flags` (namespace scope and lists), then the file,
+    /// then the defaults. Non-empty flag lists replace the file's.
+    pub(crate) fn watch(&self, flags: &WatchConfig) -> WatchSettings {
+        let defaults = WatchSettings::default();
+        let file = &self.watch;
+        let lease = &file.lease;
+        let list = |flag: &Vec<String>, file: &Vec<String>| {
+            if flag.is_empty() { file } else { flag }.clone()
+        };
+        let secs =
+            |value: Option<u64>, default: Duration| value.map_or(default, Duration::from_secs);
+        WatchSettings {
+            addr: flags.addr.or(file.addr).unwrap_or(defaults.addr),
+            namespace: flags.namespace.clone().or_else(|| file.namespace.clone()),
+            namespaces: NamespaceFilter {
+                include: list(&flags.namespaces.include, &file.namespaces.include),
+                exclude: list(&flags.namespaces.exclude, &file.namespaces.exclude),
+            },
+            pod_detail_capacity: flags
+                .pod_detail_capacity
+                .or(file.pod_detail_capacity)
+                .unwrap_or(defaults.pod_detail_capacity),
+            resync_interval: secs(
+                flags
+                    .resync_interval_seconds
+                    .or(file.resync_interval_seconds),
+                defaults.resync_interval,
+            ),
+            lease: LeaseSettings {
+                name: lease.name.clone().unwrap_or(defaults.lease.name),
+                namespace: lease.namespace.clone().unwrap_or(defaults.lease.namespace),
+                duration_seconds: lease
+                    .duration_seconds
+                    .unwrap_or(defaults.lease.duration_seconds),
+                renew_interval: secs(lease.renew_interval_seconds, defaults.lease.renew_interval),
+                retry_interval: secs(lease.retry_interval_seconds, defaults.lease.retry_interval),
+            },
+        }
+    }
+
+    /// Reconcile settings: `flags`, then the file, then the defaults.
+    pub(crate) fn reconcile(&self, flags: &ReconcileConfig) -> ReconcileSettings {
+        let defaults = ReconcileSettings::default();
+        let file = &self.reconcile;
+        ReconcileSettings {
+            requeue_interval: flags
+                .requeue_interval_seconds
+                .or(file.requeue_interval_seconds)
+                .map_or(defaults.requeue_interval, Duration::from_secs),
+            metrics_addr: flags
+                .metrics_addr
+                .or(file.metrics_addr)
+                .unwrap_or(defaults.metrics_addr),
+            audit_retention: flags
+                .audit_retention
+                .or(file.audit_retention)
+                .unwrap_or(defaults.audit_retention),
+            audit_gc_interval: flags
+                .audit_gc_interval_seconds
+                .or(file.audit_gc_interval_seconds)
+                .map_or(defaults.audit_gc_interval, Duration::from_secs),
+            list_page_size: flags
+                .list_page_size
+                .or(file.list_page_size)
+                .unwrap_or(defaults.list_page_size),
+            pod_event_debounce: flags
+                .pod_event_debounce_seconds
+                .or(file.pod_event_debounce_seconds)
+                .map_or(defaults.pod_event_debounce, Duration::from_secs),
+            audit_sink: flags.audit_sink.clone().or_else(|| file.audit_sink.clone()),
+        }
+    }
+
+    /// Webhook settings: `flags`, then the file, then the defaults.
+    pub(crate) fn webhook_serve(&self, flags: &WebhookConfig) -> ServeSettings {
+        let defaults = ServeSettings::default();
+        let file = &self.webhook;
+        ServeSettings {
+            addr: flags.addr.or(file.addr).unwrap_or(defaults.addr),
+            tls_cert: flags
+                .tls_cert
+                .clone()
+                .or_else(|| file.tls_cert.clone())
+                .unwrap_or(defaults.tls_cert),
+            tls_key: flags
+                .tls_key
+                .clone()
+                .or_else(|| file.tls_key.clone())
+                .unwrap_or(defaults.tls_key),
+            failure_mode: flags
+                .failure_mode
+                .or(file.failure_mode)
+                .unwrap_or(defaults.failure_mode),
+            timeout_seconds: flags
+                .timeout_seconds
+                .or(file.timeout_seconds)
+                .unwrap_or(defaults.timeout_seconds),
+            min_deny_severity: flags
+                .min_deny_severity
+                .clone()
+                .or_else(|| file.min_deny_severity.clone()),
+        }
+    }
+}
+
+/* ============================= TESTS ============================= */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+client:
+  requestTimeoutSeconds: 10
+  maxRetries: 5
+logging:
+  level: info,kube=warn
+  filters: [hyper=error]
+  format: compact
+watch:
+  addr: 0.0.0.0:18080
+  namespaces:
+    exclude: [sandbox]
+  podDetailCapacity: 2000
+  resyncIntervalSeconds: 300
+  lease:
+    namespace: ops
+    durationSeconds: 30
+    renewIntervalSeconds: 10
+reconcile:
+  requeueIntervalSeconds: 60
+  auditRetention: 25
+  auditGcIntervalSeconds: 600
+  podEventDebounceSeconds: 10
+  auditSink:
+    type: s3
+    bucket: compliance-audits
+    region: eu-west-1
+    prefix: kube-devops/
+webhook:
+  failureMode: closed
+  minDenySeverity: high
+"#;
+
+    fn parse(yaml: &str) -> Result<Settings> {
+        let settings: Settings = serde_yaml::from_str(yaml)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    #[test]
+    fn test_empty_file_keeps_defaults() {
+        let settings = parse("{}").unwrap();
+        assert_eq!(
+            settings.logging(&LoggingConfig::default()),
+            LoggingSettings::default()
+        );
+        let watch = settings.watch(&WatchConfig::default());
+        assert_eq!(watch.addr, WatchSettings::default().addr);
+        assert_eq!(watch.lease.name, "kube-devops-leader");
+        assert_eq!(watch.pod_detail_capacity, 10_000);
+        assert_eq!(watch.resync_interval, Duration::from_secs(600));
+        let reconcile = settings.reconcile(&ReconcileConfig::default());
+        assert_eq!(reconcile.requeue_interval, Duration::from_secs(30));
+        assert_eq!(reconcile.audit_retention, 10);
+        assert_eq!(reconcile.audit_gc_interval, Duration::from_secs(300));
+        assert_eq!(reconcile.list_page_size, 500);
+        assert_eq!(reconcile.pod_event_debounce, Duration::from_secs(5));
+        assert!(reconcile.audit_sink.is_none());
+        assert_eq!(
+            settings.client(&ClientConfig::default()),
+            ClientSettings::default()
+        );
+    }
+
+    #[test]
+    fn test_file_values_apply() {
+        let settings = parse(SAMPLE).unwrap();
+        let logging = settings.logging(&LoggingConfig::default());
+        assert_eq!(logging.level, "info,kube=warn");
+        assert_eq!(logging.filters, vec!["hyper=error".to_string()]);
+        assert_eq!(logging.format, LogFormat::Compact);
+
+        let watch = settings.watch(&WatchConfig::default());
+        assert_eq!(watch.addr.port(), 18080);
+        assert!(!watch.namespaces.allows("sandbox"));
+        assert_eq!(watch.pod_detail_capacity, 2000);
+        assert_eq!(watch.resync_interval, Duration::from_secs(300));
+        assert_eq!(watch.lease.namespace, "ops");
+        assert_eq!(watch.lease.duration_seconds, 30);
+        assert_eq!(watch.lease.renew_interval, Duration::from_secs(10));
+        assert_eq!(watch.lease.retry_interval, Duration::from_secs(5));
+
+        let webhook = settings.webhook_serve(&WebhookConfig::default());
+        assert_eq!(webhook.failure_mode, FailureMode::Closed);
+        assert_eq!(webhook.min_deny_severity, Some(Severity::High));
+        assert_eq!(webhook.tls_cert, "tls.crt");
+    }
+
+    #[test]
+    fn test_flags_take_precedence() {
+        let settings = parse(SAMPLE).unwrap();
+        let client = settings.client(&ClientConfig {
+            request_timeout_seconds: Some(60),
+            ..Default::default()
+        });
+        assert_eq!(client.request_timeout, Duration::from_secs(60));
+        assert_eq!(client.max_retries, 5);
+        assert_eq!(client.breaker_threshold, 5);
+
+        let reconcile = settings.reconcile(&ReconcileConfig {
+            requeue_interval_seconds: Some(15),
+            ..Default::default()
+        });
+        assert_eq!(reconcile.requeue_interval, Duration::from_secs(15));
+        assert_eq!(reconcile.audit_retention, 25);
+        assert_eq!(reconcile.audit_gc_interval, Duration::from_secs(600));
+        assert_eq!(reconcile.pod_event_debounce, Duration::from_secs(10));
+        let sink = reconcile.audit_sink.unwrap();
+        assert_eq!(sink.describe(), "s3://compliance-audits/kube-devops/");
+
+        let logging = settings.logging(&LoggingConfig {
+            filters: vec!["kube=debug".to_string()],
+            format: Some(LogFormat::Pretty),
+            ..Default::default()
+        });
+        assert_eq!(logging.level, "info,kube=warn");
+        assert_eq!(logging.filters, vec!["hyper=error", "kube=debug"]);
+        assert_eq!(logging.format, LogFormat::Pretty);
+
+        let watch = settings.watch(&WatchConfig {
+            namespace: Some("team-a".to_string()),
+            namespaces: NamespaceFilter {
+                include: vec![],
+                exclude: vec!["scratch".to_string()],
+            },
+            ..Default::default()
+        });
+        assert_eq!(watch.namespace.as_deref(), Some("team-a"));
+        assert!(watch.namespaces.allows("sandbox"));
+        assert!(!watch.namespaces.allows("scratch"));
+        assert_eq!(watch.addr.port(), 18080);
+
+        let webhook = settings.webhook_serve(&WebhookConfig {
+            failure_mode: Some(FailureMode::Open),
+            ..Default::default()
+        });
+        assert_eq!(webhook.failure_mode, FailureMode::Open);
+        assert_eq!(webhook.min_deny_severity, Some(Severity::High));
+    }
+
+    #[test]
+    fn test_unknown_fields_are_rejected() {
+        let err = parse("reconcile:\n  requeueInterval: 60\n").unwrap_err();
+        assert!(err.to_string().contains("requeueInterval"));
+    }
+
+    #[test]
+    fn test_invalid_values_are_rejected() {
+        for yaml in [
+            "client:\n  requestTimeoutSeconds: 0\n",
+            "client:\n  breakerThreshold: 0\n",
+            "watch:\n  podDetailCapacity: 0\n",
+            "watch:\n  resyncIntervalSeconds: 0\n",
+            "reconcile:\n  requeueIntervalSeconds: 0\n",
+            "reconcile:\n  auditRetention: 0\n",
+            "reconcile:\n  auditGcIntervalSeconds: 0\n",
+            "reconcile:\n  listPageSize: 0\n",
+            "reconcile:\n  auditSink:\n    type: gcs\n",
+            "webhook:\n  timeoutSeconds: 45\n",
+            "watch:\n  lease:\n    durationSeconds: 5\n    renewIntervalSeconds: 5\n",
+            "logging:\n  level: 'info,=['\n",
+            "logging:\n  filters: ['kube=[']\n",
+            "logging:\n  format: xml\n",
+        ] {
+            assert!(parse(yaml).is_err(), "{yaml} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_load_reports_path() {
+        let err = Settings::load(Path::new("/nonexistent/kube-devops.yaml")).unwrap_err();
+        assert!(err.to_string().contains("/nonexistent/kube-devops.yaml"));
+    }
+}
@@ -296,3 +296,47 @@ fn test_enforcement_multiple_workload_types() {
     let ds_plan = enforcement::plan_remediation(&ds_pod, &policy).unwrap();
     assert_eq!(ds_plan.workload.kind, "DaemonSet");
 }
+
+// ── Throttling ──
+
+#[test]
+fn test_enforcement_throttle_spreads_rollouts_across_cycles() {
+    let policy = DevOpsPolicySpec {
+        max_remediations_per_cycle: Some(2),
+        remediation_cooldown_seconds: Some(60),
+        ..enforce_policy()
+    };
+    // Five non-compliant StatefulSets, two pods each
+    let pods: Vec<_> = (0..10)
+        .map(|i| {
+            make_test_pod_with_owner(
+                &format!("pod-{i}"),
+                "prod",
+                "nginx:1.25",
+                "StatefulSet",
+                &format!("app-{}", i % 5),
+                false,
+                false,
+            )
+        })
+        .collect();
+
+    let plans = enforcement::plan_workload_remediations(&pods, &policy);
+    assert_eq!(plans.len(), 5, "one plan per workload");
+
+    let t0 = chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+    let first = enforcement::throttle_plans(plans.clone(), &policy, None, t0);
+    assert_eq!(first.apply.len(), 2);
+    assert_eq!(first.deferred.len(), 3);
+
+    // Next cycle inside the cooldown patches nothing
+    let soon = t0 + chrono::Duration::seconds(30);
+    let second = enforcement::throttle_plans(plans.clone(), &policy, Some(t0), soon);
+    assert!(second.apply.is_empty());
+    assert_eq!(second.deferred.len(), 5);
+
+    // After the cooldown the next batch goes out
+    let later = t0 + chrono::Duration::seconds(60);
+    let third = enforcement::throttle_plans(plans, &policy, Some(t0), later);
+    assert_eq!(third.apply.len(), 2);
+}
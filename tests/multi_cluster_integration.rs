@@ -69,6 +69,7 @@ fn test_bundle_evaluate_audit_pipeline() {
         health_score: eval.health_score,
         total_violations: eval.total_violations,
         total_pods: eval.total_pods,
+        total_workloads: None,
         classification: eval.classification.clone(),
         violations: eval
             .violations
@@ -79,8 +80,10 @@ fn test_bundle_evaluate_audit_pipeline() {
                 violation_type: v.violation_type.clone(),
                 severity: v.severity.clone(),
                 message: v.message.clone(),
+                workload: None,
             })
             .collect(),
+        planned_remediations: None,
     };
 
     assert_eq!(audit_spec.policy_name, "restricted-policy");
@@ -203,6 +206,7 @@ fn test_audit_result_construction_from_evaluation() {
         health_score: eval.health_score,
         total_violations: eval.total_violations,
         total_pods: eval.total_pods,
+        total_workloads: None,
         classification: eval.classification.clone(),
         violations: eval
             .violations
@@ -213,8 +217,10 @@ fn test_audit_result_construction_from_evaluation() {
                 violation_type: v.violation_type.clone(),
                 severity: v.severity.clone(),
                 message: v.message.clone(),
+                workload: None,
             })
             .collect(),
+        planned_remediations: None,
     };
 
     // Verify serialization works
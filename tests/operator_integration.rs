@@ -1,8 +1,11 @@
 mod common;
 
 use common::make_test_pod;
-use kube_devops::crd::{DevOpsPolicySpec, DevOpsPolicyStatus};
+use kube_devops::crd::{
+    ClusterDevOpsPolicy, ClusterDevOpsPolicySpec, DevOpsPolicySpec, DevOpsPolicyStatus,
+};
 use kube_devops::governance;
+use kube_devops::policy_resolution;
 
 // ══════════════════════════════════════════════════════════════════
 // Operator integration tests (no cluster required)
@@ -59,11 +62,16 @@ fn simulate_reconcile(
         healthy: Some(healthy),
         health_score: Some(health_score),
         violations: Some(total_violations),
+        classification: Some(classification.to_string()),
+        health_thresholds: None,
+        previous_score: None,
+        score_trend: None,
         last_evaluated: Some("2026-02-22T00:00:00Z".to_string()),
         message: Some(message),
         remediations_applied: None,
         remediations_failed: None,
         remediated_workloads: None,
+        planned_remediations: None,
     }
 }
 
@@ -225,6 +233,70 @@ fn test_custom_restart_threshold() {
     assert_eq!(status_high.violations, Some(0));
 }
 
+// ── Cluster policy resolution ──
+
+#[test]
+fn test_namespaced_policy_overrides_cluster_policy() {
+    let pods = vec![make_test_pod(
+        "a",
+        "prod",
+        "nginx:latest",
+        true,
+        true,
+        0,
+        "Running",
+    )];
+    let cluster = vec![ClusterDevOpsPolicy::new(
+        "baseline",
+        ClusterDevOpsPolicySpec {
+            excluded_namespaces: Some(vec!["sandbox".to_string()]),
+            policy: all_enabled_policy(),
+        },
+    )];
+
+    // Cluster policy alone flags the latest tag
+    let inherited = policy_resolution::resolve_effective_policy("prod", &cluster, None).unwrap();
+    assert_eq!(simulate_reconcile(&pods, &inherited).violations, Some(1));
+
+    // Namespaced policy turns the check off for this namespace only
+    let relaxed = DevOpsPolicySpec {
+        forbid_latest_tag: Some(false),
+        ..empty_policy()
+    };
+    let effective =
+        policy_resolution::resolve_effective_policy("prod", &cluster, Some(&relaxed)).unwrap();
+    assert_eq!(effective.require_liveness_probe, Some(true));
+    assert_eq!(simulate_reconcile(&pods, &effective).violations, Some(0));
+
+    // Excluded namespaces get no policy at all
+    assert!(policy_resolution::resolve_effective_policy("sandbox", &cluster, None).is_none());
+}
+
+#[test]
+fn test_workload_annotation_overrides_namespace_policy() {
+    let mut legacy = make_test_pod("legacy", "prod", "app:latest", true, true, 6, "Running");
+    legacy.metadata.annotations = Some(
+        [
+            ("devops.stochastic.io/override.forbidLatestTag", "false"),
+            ("devops.stochastic.io/override.maxRestartCount", "10"),
+        ]
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect(),
+    );
+    let strict = make_test_pod("strict", "prod", "app:latest", true, true, 6, "Running");
+
+    // The annotated pod is exempt; its neighbour still violates both checks
+    assert_eq!(
+        simulate_reconcile(std::slice::from_ref(&legacy), &all_enabled_policy()).violations,
+        Some(0)
+    );
+    assert_eq!(
+        simulate_reconcile(&[strict], &all_enabled_policy()).violations,
+        Some(2)
+    );
+}
+
 // ── Status message format ──
 
 #[test]